@@ -64,6 +64,11 @@ private struct KeychainSecretResultPayload: Encodable {
   let error: String?
 }
 
+private struct TrashItemResultPayload: Encodable {
+  let trashedPath: String?
+  let error: String?
+}
+
 private func bridgeString(_ value: String) -> UnsafeMutablePointer<CChar>? {
   strdup(value)
 }
@@ -598,6 +603,26 @@ public func syncwatcher_delete_keychain_secret(_ payloadPtr: UnsafePointer<CChar
   }
 }
 
+@_cdecl("syncwatcher_trash_item")
+public func syncwatcher_trash_item(_ pathPtr: UnsafePointer<CChar>?) -> UnsafeMutablePointer<CChar>? {
+  guard let pathPtr else {
+    return bridgeString(#"{"error":"Missing path."}"#)
+  }
+
+  let url = URL(fileURLWithPath: String(cString: pathPtr))
+  do {
+    var resultingURL: NSURL?
+    try FileManager.default.trashItem(at: url, resultingItemURL: &resultingURL)
+    return bridgeString(encodeJSON(TrashItemResultPayload(trashedPath: resultingURL?.path, error: nil)))
+  } catch {
+    let payload = TrashItemResultPayload(
+      trashedPath: nil,
+      error: "Failed to move item to Trash: \(error.localizedDescription)"
+    )
+    return bridgeString(encodeJSON(payload))
+  }
+}
+
 @_cdecl("syncwatcher_free_bridge_string")
 public func syncwatcher_free_bridge_string(_ value: UnsafeMutablePointer<CChar>?) {
   guard let value else {
@@ -71,6 +71,15 @@ pub struct NetworkMountExecutionResult {
     pub error: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrashItemResult {
+    #[serde(default)]
+    pub trashed_path: Option<String>,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
 #[cfg(target_os = "macos")]
 unsafe extern "C" {
     fn syncwatcher_storekit_get_supporter_status(product_id: *const c_char) -> *mut c_char;
@@ -83,6 +92,7 @@ unsafe extern "C" {
     fn syncwatcher_store_keychain_secret(payload: *const c_char) -> *mut c_char;
     fn syncwatcher_read_keychain_secret(payload: *const c_char) -> *mut c_char;
     fn syncwatcher_delete_keychain_secret(payload: *const c_char) -> *mut c_char;
+    fn syncwatcher_trash_item(path: *const c_char) -> *mut c_char;
     fn syncwatcher_free_bridge_string(value: *mut c_char);
 }
 
@@ -275,6 +285,23 @@ pub fn delete_keychain_secret(_payload_json: &str) -> Result<(), String> {
     Err("Keychain secret storage is only available on macOS".to_string())
 }
 
+/// `NSFileManager trashItemAtURL`로 항목을 휴지통으로 옮깁니다.
+#[cfg(target_os = "macos")]
+pub fn trash_item(path: &str) -> Result<TrashItemResult, String> {
+    let response = call_string_bridge(path, |value| unsafe { syncwatcher_trash_item(value) })?;
+    let payload: TrashItemResult = serde_json::from_str(&response)
+        .map_err(|error| format!("Failed to decode trash result: {error}"))?;
+    if let Some(error) = payload.error.clone() {
+        return Err(error);
+    }
+    Ok(payload)
+}
+
+#[cfg(not(target_os = "macos"))]
+pub fn trash_item(_path: &str) -> Result<TrashItemResult, String> {
+    Err("Moving items to the Trash is only available on macOS".to_string())
+}
+
 #[cfg(not(target_os = "macos"))]
 pub fn resolve_security_scoped_bookmark(_bookmark: &str) -> Result<BookmarkResolveResult, String> {
     Err("Security-scoped bookmarks are only available on macOS".to_string())
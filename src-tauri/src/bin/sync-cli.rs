@@ -2,7 +2,8 @@ use clap::Parser;
 use indicatif::{ProgressBar, ProgressStyle};
 use std::path::PathBuf;
 
-use syncwatcher_lib::sync_engine::{FileDiffKind, SyncEngine, SyncOptions};
+use syncwatcher_lib::sync_engine::{FileDiffKind, SyncEngine, SyncOptions, TargetLayout};
+use syncwatcher_lib::system_integration::classify_system_target;
 use syncwatcher_lib::{format_bytes, format_number};
 
 #[derive(Parser)]
@@ -26,6 +27,103 @@ struct Cli {
 
     #[arg(long)]
     verify: bool,
+
+    /// Only sync files modified at or after this ISO date/time (e.g. 2024-05-01 or RFC 3339)
+    #[arg(long, value_parser = parse_iso_date_ms)]
+    modified_after: Option<i64>,
+
+    /// Only sync files modified at or before this ISO date/time (e.g. 2024-05-31 or RFC 3339)
+    #[arg(long, value_parser = parse_iso_date_ms)]
+    modified_before: Option<i64>,
+
+    /// Store files with these extensions gzip-compressed as `<name>.gz` (comma-separated, e.g. log,txt)
+    #[arg(long, value_delimiter = ',')]
+    compress_ext: Vec<String>,
+
+    /// Only update files that already exist on the target; never add new ones
+    #[arg(long)]
+    update_existing_only: bool,
+
+    /// Rename target files whose names differ from the source only in case instead of copying
+    #[arg(long)]
+    reconcile_case_drift: bool,
+
+    /// With --verify, re-hash only this fraction (0.0-1.0) of copied files; large files are always verified
+    #[arg(long, default_value_t = 1.0)]
+    verify_sample_rate: f64,
+
+    /// Skip target files that are read-only or immutable instead of overwriting them
+    #[arg(long)]
+    respect_readonly_target: bool,
+
+    /// Skip files whose full target path would exceed this many bytes
+    #[arg(long)]
+    max_target_path_len: Option<usize>,
+
+    /// Leave zero-byte source files out of the sync
+    #[arg(long)]
+    skip_empty_files: bool,
+
+    /// Scan directories with a parallel walker (faster on large trees)
+    #[arg(long)]
+    parallel_scan: bool,
+
+    /// Copy macOS file flags (hidden, locked, archived) to the target
+    #[arg(long)]
+    preserve_flags: bool,
+
+    /// Stop at the first file that fails to copy or verify
+    #[arg(long)]
+    stop_on_first_error: bool,
+
+    /// Stop copying after this many seconds and report what is left
+    #[arg(long)]
+    max_duration_secs: Option<u64>,
+
+    /// Place files under date folders from their modified time (e.g. "%Y/%m/%d")
+    #[arg(long, value_name = "PATTERN")]
+    date_tree: Option<String>,
+
+    /// Merge `._name` AppleDouble sidecars into `name` as extended attributes (macOS)
+    #[arg(long)]
+    merge_appledouble: bool,
+
+    /// Hard-link files whose content matches one already copied in this run (checksum mode)
+    #[arg(long)]
+    dedup_identical: bool,
+
+    /// Read each chunk back from the target right after writing it (slow; catches failing media)
+    #[arg(long)]
+    read_after_write: bool,
+
+    /// Don't copy permissions or modification times when the target is a network mount
+    #[arg(long)]
+    skip_metadata_on_network: bool,
+
+    /// Keep interrupted large-file copies and continue them on the next run if the source is unchanged
+    #[arg(long)]
+    resume_partial: bool,
+
+    /// Allow syncing onto `/` or a system volume (refused by default)
+    #[arg(long)]
+    allow_system_target: bool,
+}
+
+fn parse_iso_date_ms(value: &str) -> Result<i64, String> {
+    if let Ok(date_time) = chrono::DateTime::parse_from_rfc3339(value) {
+        return Ok(date_time.timestamp_millis());
+    }
+    if let Ok(date_time) = chrono::NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S") {
+        return Ok(date_time.and_utc().timestamp_millis());
+    }
+    if let Ok(date) = chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        if let Some(date_time) = date.and_hms_opt(0, 0, 0) {
+            return Ok(date_time.and_utc().timestamp_millis());
+        }
+    }
+    Err(format!(
+        "Invalid date '{value}': expected YYYY-MM-DD, YYYY-MM-DDTHH:MM:SS, or RFC 3339"
+    ))
 }
 
 #[tokio::main]
@@ -85,6 +183,14 @@ async fn main() -> anyhow::Result<()> {
         anyhow::bail!("Source directory does not exist: {source:?}");
     }
 
+    if !cli.allow_system_target {
+        if let Some(kind) = classify_system_target(&target) {
+            anyhow::bail!(
+                "Target is a system volume ({kind:?}): {target:?}. Pass --allow-system-target to sync onto it anyway"
+            );
+        }
+    }
+
     let engine = SyncEngine::new(source.clone(), target.clone());
 
     let options = SyncOptions {
@@ -93,8 +199,53 @@ async fn main() -> anyhow::Result<()> {
         preserve_times: true,
         verify_after_copy: cli.verify,
         exclude_patterns: Vec::new(),
+        modified_after: cli.modified_after,
+        modified_before: cli.modified_before,
+        compress: !cli.compress_ext.is_empty(),
+        compress_extensions: cli.compress_ext.clone(),
+        update_existing_only: cli.update_existing_only,
+        reconcile_case_drift: cli.reconcile_case_drift,
+        verify_sample_rate: cli.verify_sample_rate,
+        verify_sample_seed: 0,
+        respect_readonly_target: cli.respect_readonly_target,
+        max_target_path_len: cli.max_target_path_len,
+        skip_empty_files: cli.skip_empty_files,
+        parallel_scan: cli.parallel_scan,
+        preserve_flags: cli.preserve_flags,
+        stop_on_first_error: cli.stop_on_first_error,
+        max_duration: cli.max_duration_secs.map(std::time::Duration::from_secs),
+        target_layout: cli
+            .date_tree
+            .clone()
+            .map(|pattern| TargetLayout::DateTree { pattern }),
+        merge_appledouble: cli.merge_appledouble,
+        dedup_identical: cli.dedup_identical,
+        flag_equal_mtime_content_diff: false,
+        read_after_write: cli.read_after_write,
+        verify_existing_after_sync: false,
+        skip_metadata_on_network: cli.skip_metadata_on_network,
+        resume_partial_copies: cli.resume_partial,
+        collect_skip_reasons: false,
+        source_subdirs: None,
     };
 
+    if !(0.0..=1.0).contains(&options.verify_sample_rate) {
+        anyhow::bail!("--verify-sample-rate must be between 0.0 and 1.0");
+    }
+
+    if let (Some(after), Some(before)) = (options.modified_after, options.modified_before) {
+        if after > before {
+            anyhow::bail!("--modified-after must not be later than --modified-before");
+        }
+    }
+
+    if cli.max_duration_secs == Some(0) {
+        anyhow::bail!("--max-duration-secs must be greater than 0");
+    }
+    if let Some(layout) = &options.target_layout {
+        layout.validate().map_err(anyhow::Error::msg)?;
+    }
+
     if cli.dry_run {
         println!("🔍 Dry-run mode - comparing directories...");
         println!("   Source: {source:?}");
@@ -125,10 +276,12 @@ async fn main() -> anyhow::Result<()> {
                         let icon = match diff.kind {
                             FileDiffKind::New => "➕",
                             FileDiffKind::Modified => "🔄",
+                            FileDiffKind::CaseRename => "🔤",
                         };
                         let action = match diff.kind {
                             FileDiffKind::New => "NEW",
                             FileDiffKind::Modified => "MODIFIED",
+                            FileDiffKind::CaseRename => "CASE RENAME",
                         };
                         println!(
                             "   {} {:?} - {} ({})",
@@ -141,6 +294,17 @@ async fn main() -> anyhow::Result<()> {
                 } else {
                     println!("✅ Directories are in sync!");
                 }
+
+                if !dry_run.path_too_long.is_empty() {
+                    println!();
+                    println!("⚠️  Target paths too long (will be skipped):");
+                    for diagnostic in &dry_run.path_too_long {
+                        println!(
+                            "   {:?} - {} > {} bytes",
+                            diagnostic.path, diagnostic.target_path_len, diagnostic.limit
+                        );
+                    }
+                }
             }
             Err(e) => {
                 eprintln!("❌ Error during dry-run: {:#}", e);
@@ -193,17 +357,69 @@ async fn main() -> anyhow::Result<()> {
                 println!("📊 Results:");
                 println!("   Files copied: {}", format_number(result.files_copied));
                 println!("   Bytes copied: {}", format_bytes(result.bytes_copied));
+                if result.compressed_files > 0 {
+                    println!(
+                        "   Compressed: {} files ({} -> {})",
+                        format_number(result.compressed_files),
+                        format_bytes(result.compressed_original_bytes),
+                        format_bytes(result.compressed_stored_bytes)
+                    );
+                }
+                if result.files_verify_sampled_out > 0 {
+                    println!(
+                        "   Verified: {} files ({} sampled out)",
+                        format_number(result.files_verified),
+                        format_number(result.files_verify_sampled_out)
+                    );
+                }
+                if result.empty_files_skipped > 0 {
+                    println!(
+                        "   Empty files skipped: {}",
+                        format_number(result.empty_files_skipped)
+                    );
+                }
+                if result.appledouble_merged > 0 {
+                    println!(
+                        "   AppleDouble sidecars merged: {}",
+                        format_number(result.appledouble_merged)
+                    );
+                }
+                if result.dedup_linked > 0 {
+                    println!(
+                        "   Duplicates linked: {} ({} saved)",
+                        format_number(result.dedup_linked),
+                        format_bytes(result.dedup_bytes_saved)
+                    );
+                }
+                for warning in &result.warnings {
+                    println!("   ⚠️  {warning}");
+                }
                 if !result.errors.is_empty() {
                     println!("   Errors: {}", result.errors.len());
                     for error in &result.errors {
                         let kind_str = match error.kind {
                             syncwatcher_lib::sync_engine::types::SyncErrorKind::CopyFailed => "Copy Failed",
                             syncwatcher_lib::sync_engine::types::SyncErrorKind::VerificationFailed => "Verification Failed",
+                            syncwatcher_lib::sync_engine::types::SyncErrorKind::SourceDisconnected => "Source Disconnected",
+                            syncwatcher_lib::sync_engine::types::SyncErrorKind::TargetReadOnly => "Target Read-Only",
+                            syncwatcher_lib::sync_engine::types::SyncErrorKind::PathTooLong => "Path Too Long",
+                            syncwatcher_lib::sync_engine::types::SyncErrorKind::FlagsNotPreserved => "Flags Not Preserved",
+                            syncwatcher_lib::sync_engine::types::SyncErrorKind::LayoutCollision => "Layout Collision",
                             syncwatcher_lib::sync_engine::types::SyncErrorKind::Other => "Error",
                         };
                         eprintln!("   ⚠️  [{}] {:?}: {}", kind_str, error.path, error.message);
                     }
                 }
+                if let Some(path) = &result.stopped_on_error {
+                    eprintln!("   ⛔ Stopped on first error at {:?}", path);
+                    std::process::exit(1);
+                }
+                if result.time_limit_reached {
+                    println!(
+                        "   ⏱️  Time limit reached: {} files ({} bytes) remaining",
+                        result.remaining_files, result.remaining_bytes
+                    );
+                }
             }
             Err(e) => {
                 pb.abandon_with_message("❌ Synchronization failed!");
@@ -0,0 +1,184 @@
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tempfile::NamedTempFile;
+
+pub const DEFAULT_CAPACITY_SAMPLE_INTERVAL_SECS: u64 = 15 * 60;
+pub const MIN_CAPACITY_SAMPLE_INTERVAL_SECS: u64 = 60;
+/// About two weeks of samples per volume at the default interval for a handful of targets.
+pub const DEFAULT_CAPACITY_HISTORY_MAX_SAMPLES: usize = 10_000;
+
+const CAPACITY_HISTORY_FILE_NAME: &str = "state/capacity-history.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CapacitySample {
+    pub timestamp: DateTime<Utc>,
+    pub mount_point: PathBuf,
+    pub available_bytes: u64,
+    pub total_bytes: u64,
+}
+
+/// Rolling on-disk buffer of volume free-space samples, oldest first.
+#[derive(Debug)]
+pub struct CapacityHistoryStore {
+    file_path: PathBuf,
+    max_samples: usize,
+    write_lock: std::sync::Mutex<()>,
+}
+
+impl CapacityHistoryStore {
+    pub fn new(app_support_dir: PathBuf) -> Self {
+        Self::with_max_samples(app_support_dir, DEFAULT_CAPACITY_HISTORY_MAX_SAMPLES)
+    }
+
+    pub fn with_max_samples(app_support_dir: PathBuf, max_samples: usize) -> Self {
+        Self {
+            file_path: app_support_dir.join(CAPACITY_HISTORY_FILE_NAME),
+            max_samples: max_samples.max(1),
+            write_lock: std::sync::Mutex::new(()),
+        }
+    }
+
+    pub fn file_path(&self) -> &Path {
+        &self.file_path
+    }
+
+    pub fn read_samples(&self) -> Result<Vec<CapacitySample>, String> {
+        match fs::read_to_string(&self.file_path) {
+            Ok(raw) if raw.trim().is_empty() => Ok(Vec::new()),
+            Ok(raw) => serde_json::from_str(&raw)
+                .map_err(|error| format!("Failed to parse capacity history: {error}")),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(error) => Err(format!("Failed to read capacity history: {error}")),
+        }
+    }
+
+    /// Appends `samples` and drops the oldest entries beyond the retention bound.
+    pub fn append_samples(&self, samples: &[CapacitySample]) -> Result<(), String> {
+        if samples.is_empty() {
+            return Ok(());
+        }
+        let _guard = self
+            .write_lock
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let mut history = self.read_samples()?;
+        history.extend_from_slice(samples);
+        if history.len() > self.max_samples {
+            let overflow = history.len() - self.max_samples;
+            history.drain(..overflow);
+        }
+        self.write_json_atomic(&history)
+    }
+
+    /// Samples for `mount_point` taken at or after `since` (all of them when `None`), oldest first.
+    pub fn query(
+        &self,
+        mount_point: &Path,
+        since: Option<DateTime<Utc>>,
+    ) -> Result<Vec<CapacitySample>, String> {
+        Ok(self
+            .read_samples()?
+            .into_iter()
+            .filter(|sample| sample.mount_point == mount_point)
+            .filter(|sample| since.is_none_or(|since| sample.timestamp >= since))
+            .collect())
+    }
+
+    fn write_json_atomic(&self, samples: &[CapacitySample]) -> Result<(), String> {
+        let bytes = serde_json::to_vec(samples)
+            .map_err(|error| format!("Failed to serialize capacity history: {error}"))?;
+        let Some(parent) = self.file_path.parent() else {
+            return Err("Capacity history path has no parent".to_string());
+        };
+        fs::create_dir_all(parent)
+            .map_err(|error| format!("Failed to prepare capacity history dir: {error}"))?;
+        let mut temp = NamedTempFile::new_in(parent)
+            .map_err(|error| format!("Failed to create temp capacity history file: {error}"))?;
+        temp.write_all(&bytes)
+            .map_err(|error| format!("Failed to write temp capacity history file: {error}"))?;
+        temp.flush()
+            .map_err(|error| format!("Failed to flush temp capacity history file: {error}"))?;
+        temp.persist(&self.file_path)
+            .map_err(|error| format!("Failed to persist capacity history file: {}", error.error))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use tempfile::tempdir;
+
+    fn sample(mount_point: &str, minutes: i64, available_bytes: u64) -> CapacitySample {
+        CapacitySample {
+            timestamp: DateTime::parse_from_rfc3339("2026-03-28T00:00:00Z")
+                .expect("timestamp should parse")
+                .with_timezone(&Utc)
+                + Duration::minutes(minutes),
+            mount_point: PathBuf::from(mount_point),
+            available_bytes,
+            total_bytes: 1_000,
+        }
+    }
+
+    #[test]
+    fn append_samples_drops_oldest_beyond_retention() {
+        let temp = tempdir().expect("tempdir should exist");
+        let store = CapacityHistoryStore::with_max_samples(temp.path().to_path_buf(), 3);
+
+        for minutes in 0..5 {
+            store
+                .append_samples(&[sample("/Volumes/Archive", minutes, 900 - minutes as u64)])
+                .expect("append should succeed");
+        }
+
+        let history = store.read_samples().expect("history should load");
+        assert_eq!(history.len(), 3);
+        assert_eq!(history[0], sample("/Volumes/Archive", 2, 898));
+        assert_eq!(history[2], sample("/Volumes/Archive", 4, 896));
+    }
+
+    #[test]
+    fn query_filters_by_mount_point_and_since() {
+        let temp = tempdir().expect("tempdir should exist");
+        let store = CapacityHistoryStore::new(temp.path().to_path_buf());
+        store
+            .append_samples(&[
+                sample("/Volumes/Archive", 0, 900),
+                sample("/Volumes/Other", 5, 500),
+                sample("/Volumes/Archive", 10, 800),
+                sample("/Volumes/Archive", 20, 700),
+            ])
+            .expect("append should succeed");
+
+        let all = store
+            .query(Path::new("/Volumes/Archive"), None)
+            .expect("query should succeed");
+        assert_eq!(all.len(), 3);
+
+        let recent = store
+            .query(
+                Path::new("/Volumes/Archive"),
+                Some(sample("/", 10, 0).timestamp),
+            )
+            .expect("query should succeed");
+        assert_eq!(
+            recent,
+            vec![
+                sample("/Volumes/Archive", 10, 800),
+                sample("/Volumes/Archive", 20, 700)
+            ]
+        );
+
+        assert!(store
+            .query(Path::new("/Volumes/Missing"), None)
+            .expect("query should succeed")
+            .is_empty());
+    }
+}
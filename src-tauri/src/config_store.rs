@@ -3,6 +3,7 @@ use std::fs;
 use std::io::{Read, Write};
 use std::path::{Component, Path, PathBuf};
 
+use chrono::NaiveTime;
 use reqwest::Url;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -10,8 +11,10 @@ use tauri::Manager;
 use tauri_plugin_autostart::ManagerExt as _;
 use tempfile::NamedTempFile;
 
+use crate::capacity_history;
 use crate::distribution;
 use crate::input_validation;
+use crate::license_validation;
 use crate::recurring::{
     normalize_recurring_schedules, validate_guided_preset_compatible_schedules,
     validate_strict_recurring_schedule_ids, RecurringScheduleRecord,
@@ -27,6 +30,10 @@ pub const CONTROL_PLANE_SOCKET_FILE_NAME: &str = "syncwatcher-mcp.sock";
 
 const APP_SUPPORT_DIR_OVERRIDE_ENV: &str = "SYNCWATCHER_APP_SUPPORT_DIR";
 const DEFAULT_MAX_LOG_LINES: u32 = 10_000;
+/// Watch tasks beyond this many stay unwatched so FSEvents descriptors are not exhausted.
+pub const DEFAULT_MAX_WATCHERS: u32 = 64;
+pub const DEFAULT_PROGRESS_EMIT_MS: u64 = 100;
+pub const DEFAULT_LOG_FLUSH_MS: u64 = 200;
 const SYSTEM_DEFAULTS_SET_ID: &str = "system-defaults";
 const GIT_SET_ID: &str = "git";
 const PROGRAM_SET_ID: &str = "program";
@@ -98,6 +105,41 @@ pub struct StoredSettings {
     pub mcp_enabled: bool,
     #[serde(default)]
     pub mcp_auth_token: Option<String>,
+    #[serde(default = "default_max_watchers")]
+    pub max_watchers: u32,
+    /// Combined copy rate across all concurrent syncs in bytes/sec; 0 means unlimited.
+    #[serde(default)]
+    pub global_max_bytes_per_sec: u64,
+    /// Queue an initial sync for every watch task when the app starts.
+    #[serde(default = "default_bootstrap_initial_sync")]
+    pub bootstrap_initial_sync: bool,
+    /// Log every copied file instead of only milestones and errors.
+    #[serde(default)]
+    pub verbose_file_logging: bool,
+    /// Minimum interval between sync progress events in milliseconds.
+    #[serde(default = "default_progress_emit_ms")]
+    pub progress_emit_ms: u64,
+    /// Interval between sync log batch flushes in milliseconds.
+    #[serde(default = "default_log_flush_ms")]
+    pub log_flush_ms: u64,
+    /// Local (start, end) window during which watch and scheduled syncs wait; an end before start wraps midnight.
+    #[serde(default)]
+    pub quiet_hours: Option<(NaiveTime, NaiveTime)>,
+    /// File and directory names whose changes alone never trigger a watch sync; unset uses the built-in list.
+    #[serde(default)]
+    pub watch_noise_names: Option<Vec<String>>,
+    /// Orphan scans fail when the source has fewer files than this fraction (0.0–1.0) of the target.
+    #[serde(default)]
+    pub orphan_min_source_fraction: f64,
+    /// Seconds between target-volume free-space samples.
+    #[serde(default = "default_capacity_sample_interval_secs")]
+    pub capacity_sample_interval_secs: u64,
+    /// License API request timeout in seconds.
+    #[serde(default = "default_license_http_timeout_secs")]
+    pub license_http_timeout_secs: u64,
+    /// Explicit proxy URL for license API calls; unset follows HTTPS_PROXY/NO_PROXY.
+    #[serde(default)]
+    pub license_http_proxy: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -114,6 +156,20 @@ pub struct SettingsSnapshot {
     pub is_registered: bool,
     pub launch_at_login: bool,
     pub mcp_enabled: bool,
+    pub max_watchers: u32,
+    pub global_max_bytes_per_sec: u64,
+    pub bootstrap_initial_sync: bool,
+    pub verbose_file_logging: bool,
+    pub progress_emit_ms: u64,
+    pub log_flush_ms: u64,
+    /// Serialized as `["HH:MM:SS", "HH:MM:SS"]`.
+    #[schemars(with = "Option<(String, String)>")]
+    pub quiet_hours: Option<(NaiveTime, NaiveTime)>,
+    pub watch_noise_names: Option<Vec<String>>,
+    pub orphan_min_source_fraction: f64,
+    pub capacity_sample_interval_secs: u64,
+    pub license_http_timeout_secs: u64,
+    pub license_http_proxy: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -128,6 +184,22 @@ pub struct SettingsPatch {
     pub max_log_lines: Option<u32>,
     pub close_action: Option<CloseAction>,
     pub mcp_enabled: Option<bool>,
+    pub max_watchers: Option<u32>,
+    pub global_max_bytes_per_sec: Option<u64>,
+    pub bootstrap_initial_sync: Option<bool>,
+    pub verbose_file_logging: Option<bool>,
+    pub progress_emit_ms: Option<u64>,
+    pub log_flush_ms: Option<u64>,
+    /// `null` clears the window; omitting the field keeps it.
+    #[serde(default, deserialize_with = "deserialize_present")]
+    pub quiet_hours: Option<Option<(NaiveTime, NaiveTime)>>,
+    /// `null` restores the built-in list; omitting the field keeps it.
+    #[serde(default, deserialize_with = "deserialize_present")]
+    pub watch_noise_names: Option<Option<Vec<String>>>,
+    pub orphan_min_source_fraction: Option<f64>,
+    pub capacity_sample_interval_secs: Option<u64>,
+    pub license_http_timeout_secs: Option<u64>,
+    pub license_http_proxy: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Default)]
@@ -171,6 +243,43 @@ impl SettingsPatch {
         if let Some(mcp_enabled) = self.mcp_enabled {
             settings.mcp_enabled = mcp_enabled;
         }
+        if let Some(max_watchers) = self.max_watchers {
+            settings.max_watchers = max_watchers;
+        }
+        if let Some(global_max_bytes_per_sec) = self.global_max_bytes_per_sec {
+            settings.global_max_bytes_per_sec = global_max_bytes_per_sec;
+        }
+        if let Some(bootstrap_initial_sync) = self.bootstrap_initial_sync {
+            settings.bootstrap_initial_sync = bootstrap_initial_sync;
+        }
+        if let Some(verbose_file_logging) = self.verbose_file_logging {
+            settings.verbose_file_logging = verbose_file_logging;
+        }
+        if let Some(progress_emit_ms) = self.progress_emit_ms {
+            settings.progress_emit_ms = progress_emit_ms;
+        }
+        if let Some(log_flush_ms) = self.log_flush_ms {
+            settings.log_flush_ms = log_flush_ms;
+        }
+        if let Some(quiet_hours) = self.quiet_hours {
+            settings.quiet_hours = quiet_hours;
+        }
+        if let Some(watch_noise_names) = self.watch_noise_names.clone() {
+            settings.watch_noise_names = watch_noise_names;
+        }
+        if let Some(orphan_min_source_fraction) = self.orphan_min_source_fraction {
+            settings.orphan_min_source_fraction = orphan_min_source_fraction;
+        }
+        if let Some(capacity_sample_interval_secs) = self.capacity_sample_interval_secs {
+            settings.capacity_sample_interval_secs = capacity_sample_interval_secs;
+        }
+        if let Some(license_http_timeout_secs) = self.license_http_timeout_secs {
+            settings.license_http_timeout_secs = license_http_timeout_secs;
+        }
+        if let Some(license_http_proxy) = self.license_http_proxy.clone() {
+            settings.license_http_proxy =
+                normalize_trimmed_optional_string(Some(license_http_proxy));
+        }
     }
 }
 
@@ -186,6 +295,18 @@ impl From<McpSettingsPatch> for SettingsPatch {
             max_log_lines: None,
             close_action: value.close_action,
             mcp_enabled: value.mcp_enabled,
+            max_watchers: None,
+            global_max_bytes_per_sec: None,
+            bootstrap_initial_sync: None,
+            verbose_file_logging: None,
+            progress_emit_ms: None,
+            log_flush_ms: None,
+            quiet_hours: None,
+            watch_noise_names: None,
+            orphan_min_source_fraction: None,
+            capacity_sample_interval_secs: None,
+            license_http_timeout_secs: None,
+            license_http_proxy: None,
         }
     }
 }
@@ -227,6 +348,41 @@ pub struct SyncTaskRecord {
     pub source_identity: Option<SourceIdentitySnapshot>,
     #[serde(default)]
     pub recurring_schedules: Vec<RecurringScheduleRecord>,
+    /// When false the task keeps its settings but is skipped by watch, schedules and the initial sync.
+    #[serde(default = "default_task_enabled")]
+    pub enabled: bool,
+    #[serde(default)]
+    pub notifications: TaskNotificationPrefs,
+    /// Tasks in the same group never sync at the same time; unset groups by target device.
+    #[serde(default)]
+    pub resource_group: Option<String>,
+    /// Upper bound in seconds on how long a continuous watch event stream can delay a sync.
+    #[serde(default)]
+    pub max_coalesce_secs: Option<u64>,
+    /// Upper bound in seconds on the copy phase of runs that do not pass their own limit,
+    /// including the run re-queued after a watch sync stops at the limit.
+    #[serde(default)]
+    pub max_duration_secs: Option<u64>,
+}
+
+/// Which sync outcomes of a task raise a system notification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TaskNotificationPrefs {
+    pub on_conflict: bool,
+    pub on_complete: bool,
+    pub on_error: bool,
+}
+
+impl Default for TaskNotificationPrefs {
+    /// Conflicts only, matching the behavior before per-task preferences existed.
+    fn default() -> Self {
+        Self {
+            on_conflict: true,
+            on_complete: false,
+            on_error: false,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Default, PartialEq, Eq)]
@@ -314,6 +470,13 @@ pub struct UpdateSyncTaskRequest {
     pub source_sub_path: Option<String>,
     pub source_identity: Option<SourceIdentitySnapshot>,
     pub recurring_schedules: Option<Vec<RecurringScheduleRecord>>,
+    pub notifications: Option<TaskNotificationPrefs>,
+    /// An empty string clears the group.
+    pub resource_group: Option<String>,
+    /// 0 restores the default cap.
+    pub max_coalesce_secs: Option<u64>,
+    /// 0 removes the limit.
+    pub max_duration_secs: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
@@ -481,7 +644,7 @@ impl ConfigStore {
 
     pub fn save_settings(&self, settings: &StoredSettings) -> Result<(), ConfigStoreError> {
         self.ensure_config_dir()?;
-        self.write_yaml_atomic(&self.settings_file_path(), settings)
+        self.save_yaml(&self.settings_file_path(), settings)
     }
 
     pub fn reset_settings(&self) -> Result<StoredSettings, ConfigStoreError> {
@@ -509,7 +672,7 @@ impl ConfigStore {
 
     pub fn save_tasks(&self, tasks: &[SyncTaskRecord]) -> Result<(), ConfigStoreError> {
         self.ensure_config_dir()?;
-        self.write_yaml_atomic(&self.tasks_file_path(), tasks)
+        self.save_yaml(&self.tasks_file_path(), tasks)
     }
 
     pub fn load_exclusion_sets(&self) -> Result<Vec<ExclusionSetRecord>, ConfigStoreError> {
@@ -524,7 +687,7 @@ impl ConfigStore {
 
     pub fn save_exclusion_sets(&self, sets: &[ExclusionSetRecord]) -> Result<(), ConfigStoreError> {
         self.ensure_config_dir()?;
-        self.write_yaml_atomic(&self.exclusion_sets_file_path(), sets)
+        self.save_yaml(&self.exclusion_sets_file_path(), sets)
     }
 
     pub fn write_raw_file_at_path(
@@ -606,6 +769,55 @@ impl ConfigStore {
         self.write_yaml_atomic_bytes(path, yaml.as_bytes())
     }
 
+    /// Explicit save: writes `path` and refreshes its `.bak` copy. Default creation and load-time
+    /// normalization use `write_yaml_atomic`/`write_if_changed`, so they never replace the
+    /// last-known-good backup of a file that was found empty or corrupt.
+    fn save_yaml<T>(&self, path: &Path, value: &T) -> Result<(), ConfigStoreError>
+    where
+        T: Serialize + ?Sized,
+    {
+        let yaml =
+            serde_yaml::to_string(value).map_err(|error| ConfigStoreError::ValidationError {
+                message: format!("Failed to serialize config: {error}"),
+            })?;
+        self.write_yaml_atomic_bytes(path, yaml.as_bytes())?;
+        self.write_last_known_good(path, yaml.as_bytes());
+        Ok(())
+    }
+
+    /// Best-effort `.bak` copy of the content just saved, used to restore a corrupted file.
+    fn write_last_known_good(&self, path: &Path, bytes: &[u8]) {
+        let _ = self.write_yaml_atomic_bytes(&last_known_good_path(path), bytes);
+    }
+
+    /// Parses each config YAML file. With `restore_from_backup`, corrupt files that have a
+    /// last-known-good copy are moved aside and replaced by it; otherwise they are only reported.
+    pub fn check_config_file_integrity(
+        &self,
+        restore_from_backup: bool,
+    ) -> Vec<PersistedFileIntegrity> {
+        vec![
+            check_persisted_file(
+                SETTINGS_FILE_NAME,
+                &self.settings_file_path(),
+                |raw| serde_yaml::from_str::<StoredSettings>(raw).map(|_| ()),
+                restore_from_backup,
+            ),
+            check_persisted_file(
+                TASKS_FILE_NAME,
+                &self.tasks_file_path(),
+                |raw| serde_yaml::from_str::<Vec<SyncTaskRecord>>(raw).map(|_| ()),
+                restore_from_backup,
+            ),
+            check_persisted_file(
+                EXCLUSION_SETS_FILE_NAME,
+                &self.exclusion_sets_file_path(),
+                |raw| serde_yaml::from_str::<Vec<ExclusionSetRecord>>(raw).map(|_| ()),
+                restore_from_backup,
+            ),
+        ]
+    }
+
     fn write_yaml_atomic_bytes(&self, path: &Path, bytes: &[u8]) -> Result<(), ConfigStoreError> {
         let Some(parent) = path.parent() else {
             return Err(ConfigStoreError::IoError {
@@ -641,6 +853,105 @@ impl ConfigStore {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PersistedFileStatus {
+    Valid,
+    Corrupt,
+    Missing,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersistedFileIntegrity {
+    pub name: String,
+    pub path: String,
+    pub status: PersistedFileStatus,
+    #[serde(default)]
+    pub error: Option<String>,
+    /// Where a corrupt file was moved (`<file>.corrupt`).
+    #[serde(default)]
+    pub corrupt_backup_path: Option<String>,
+    /// A parseable `<file>.bak` exists for this file.
+    #[serde(default)]
+    pub backup_available: bool,
+    #[serde(default)]
+    pub restored_from_backup: bool,
+}
+
+pub fn last_known_good_path(path: &Path) -> PathBuf {
+    let mut backup = path.as_os_str().to_os_string();
+    backup.push(".bak");
+    PathBuf::from(backup)
+}
+
+fn corrupt_backup_path(path: &Path) -> PathBuf {
+    let mut backup = path.as_os_str().to_os_string();
+    backup.push(".corrupt");
+    PathBuf::from(backup)
+}
+
+/// Classifies one persisted file. Empty content counts as corrupt: a successful save never
+/// writes an empty file, so it indicates a partial write. A corrupt file is only moved to
+/// `.corrupt` when it is restored from its backup in the same step; otherwise it stays in place
+/// so the next load does not recreate defaults over it.
+pub fn check_persisted_file<E: std::fmt::Display>(
+    name: &str,
+    path: &Path,
+    parse: impl Fn(&str) -> Result<(), E>,
+    restore_from_backup: bool,
+) -> PersistedFileIntegrity {
+    let backup_path = last_known_good_path(path);
+    let backup_available = fs::read_to_string(&backup_path)
+        .map(|raw| !raw.trim().is_empty() && parse(&raw).is_ok())
+        .unwrap_or(false);
+    let mut report = PersistedFileIntegrity {
+        name: name.to_string(),
+        path: path.to_string_lossy().to_string(),
+        status: PersistedFileStatus::Valid,
+        error: None,
+        corrupt_backup_path: None,
+        backup_available,
+        restored_from_backup: false,
+    };
+
+    let parse_error = match fs::read_to_string(path) {
+        Ok(raw) if raw.trim().is_empty() => "File is empty".to_string(),
+        Ok(raw) => match parse(&raw) {
+            Ok(()) => return report,
+            Err(error) => error.to_string(),
+        },
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+            report.status = PersistedFileStatus::Missing;
+            return report;
+        }
+        Err(error) => format!("Failed to read file: {error}"),
+    };
+
+    report.status = PersistedFileStatus::Corrupt;
+    report.error = Some(parse_error);
+    if !(restore_from_backup && backup_available) {
+        return report;
+    }
+
+    let corrupt_path = corrupt_backup_path(path);
+    match fs::rename(path, &corrupt_path) {
+        Ok(()) => {
+            report.corrupt_backup_path = Some(corrupt_path.to_string_lossy().to_string());
+        }
+        Err(error) => {
+            report.error = Some(format!(
+                "{}; failed to move aside: {error}",
+                report.error.unwrap_or_default()
+            ));
+            return report;
+        }
+    }
+
+    report.restored_from_backup = fs::copy(&backup_path, path).is_ok();
+    report
+}
+
 pub(crate) fn normalize_network_mount_relative_subpath(
     value: &str,
 ) -> Result<String, ConfigStoreError> {
@@ -766,6 +1077,18 @@ pub async fn settings_snapshot_from_store(
                 .map_err(|error| error.to_string()),
         ),
         mcp_enabled: settings.mcp_enabled,
+        max_watchers: settings.max_watchers,
+        global_max_bytes_per_sec: settings.global_max_bytes_per_sec,
+        bootstrap_initial_sync: settings.bootstrap_initial_sync,
+        verbose_file_logging: settings.verbose_file_logging,
+        progress_emit_ms: settings.progress_emit_ms,
+        log_flush_ms: settings.log_flush_ms,
+        quiet_hours: settings.quiet_hours,
+        watch_noise_names: settings.watch_noise_names,
+        orphan_min_source_fraction: settings.orphan_min_source_fraction,
+        capacity_sample_interval_secs: settings.capacity_sample_interval_secs,
+        license_http_timeout_secs: settings.license_http_timeout_secs,
+        license_http_proxy: settings.license_http_proxy,
     })
 }
 
@@ -807,6 +1130,42 @@ pub fn apply_settings_patch(mut settings: StoredSettings, patch: SettingsPatch)
     if let Some(mcp_enabled) = patch.mcp_enabled {
         settings.mcp_enabled = mcp_enabled;
     }
+    if let Some(max_watchers) = patch.max_watchers {
+        settings.max_watchers = max_watchers;
+    }
+    if let Some(global_max_bytes_per_sec) = patch.global_max_bytes_per_sec {
+        settings.global_max_bytes_per_sec = global_max_bytes_per_sec;
+    }
+    if let Some(bootstrap_initial_sync) = patch.bootstrap_initial_sync {
+        settings.bootstrap_initial_sync = bootstrap_initial_sync;
+    }
+    if let Some(verbose_file_logging) = patch.verbose_file_logging {
+        settings.verbose_file_logging = verbose_file_logging;
+    }
+    if let Some(progress_emit_ms) = patch.progress_emit_ms {
+        settings.progress_emit_ms = progress_emit_ms;
+    }
+    if let Some(log_flush_ms) = patch.log_flush_ms {
+        settings.log_flush_ms = log_flush_ms;
+    }
+    if let Some(quiet_hours) = patch.quiet_hours {
+        settings.quiet_hours = quiet_hours;
+    }
+    if let Some(watch_noise_names) = patch.watch_noise_names {
+        settings.watch_noise_names = watch_noise_names;
+    }
+    if let Some(orphan_min_source_fraction) = patch.orphan_min_source_fraction {
+        settings.orphan_min_source_fraction = orphan_min_source_fraction;
+    }
+    if let Some(capacity_sample_interval_secs) = patch.capacity_sample_interval_secs {
+        settings.capacity_sample_interval_secs = capacity_sample_interval_secs;
+    }
+    if let Some(license_http_timeout_secs) = patch.license_http_timeout_secs {
+        settings.license_http_timeout_secs = license_http_timeout_secs;
+    }
+    if let Some(license_http_proxy) = patch.license_http_proxy {
+        settings.license_http_proxy = normalize_trimmed_optional_string(Some(license_http_proxy));
+    }
     settings
 }
 
@@ -882,6 +1241,11 @@ pub fn build_sync_task_record(
         source_sub_path: request.source_sub_path,
         source_identity: request.source_identity,
         recurring_schedules: request.recurring_schedules,
+        enabled: true,
+        notifications: TaskNotificationPrefs::default(),
+        resource_group: None,
+        max_coalesce_secs: None,
+        max_duration_secs: None,
     })?;
 
     validate_strict_recurring_schedule_ids(&task.recurring_schedules)
@@ -969,6 +1333,23 @@ pub fn apply_sync_task_update(
             .recurring_schedules
             .clone()
             .unwrap_or(task.recurring_schedules),
+        enabled: task.enabled,
+        notifications: update.notifications.unwrap_or(task.notifications),
+        resource_group: if update.resource_group.is_some() {
+            normalize_trimmed_optional_string(update.resource_group.clone())
+        } else {
+            task.resource_group
+        },
+        max_coalesce_secs: match update.max_coalesce_secs {
+            Some(0) => None,
+            Some(secs) => Some(secs),
+            None => task.max_coalesce_secs,
+        },
+        max_duration_secs: match update.max_duration_secs {
+            Some(0) => None,
+            Some(secs) => Some(secs),
+            None => task.max_duration_secs,
+        },
     };
     if update.source_identity.is_none() && source_changed {
         next.source_identity = None;
@@ -1158,6 +1539,10 @@ fn default_verify_after_copy() -> bool {
     true
 }
 
+fn default_task_enabled() -> bool {
+    true
+}
+
 fn default_network_mount_enabled() -> bool {
     true
 }
@@ -1166,6 +1551,39 @@ fn default_max_log_lines() -> u32 {
     DEFAULT_MAX_LOG_LINES
 }
 
+fn default_max_watchers() -> u32 {
+    DEFAULT_MAX_WATCHERS
+}
+
+fn default_bootstrap_initial_sync() -> bool {
+    true
+}
+
+/// Lets a patch tell an explicit `null` apart from a missing field.
+fn deserialize_present<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    T::deserialize(deserializer).map(Some)
+}
+
+fn default_progress_emit_ms() -> u64 {
+    DEFAULT_PROGRESS_EMIT_MS
+}
+
+fn default_log_flush_ms() -> u64 {
+    DEFAULT_LOG_FLUSH_MS
+}
+
+fn default_capacity_sample_interval_secs() -> u64 {
+    capacity_history::DEFAULT_CAPACITY_SAMPLE_INTERVAL_SECS
+}
+
+fn default_license_http_timeout_secs() -> u64 {
+    license_validation::DEFAULT_LICENSE_HTTP_TIMEOUT_SECS
+}
+
 fn default_data_unit_system() -> DataUnitSystem {
     DataUnitSystem::Binary
 }
@@ -1182,6 +1600,18 @@ fn default_settings() -> StoredSettings {
         close_action: CloseAction::Quit,
         mcp_enabled: false,
         mcp_auth_token: None,
+        max_watchers: DEFAULT_MAX_WATCHERS,
+        global_max_bytes_per_sec: 0,
+        bootstrap_initial_sync: true,
+        verbose_file_logging: false,
+        progress_emit_ms: DEFAULT_PROGRESS_EMIT_MS,
+        log_flush_ms: DEFAULT_LOG_FLUSH_MS,
+        quiet_hours: None,
+        watch_noise_names: None,
+        orphan_min_source_fraction: 0.0,
+        capacity_sample_interval_secs: capacity_history::DEFAULT_CAPACITY_SAMPLE_INTERVAL_SECS,
+        license_http_timeout_secs: license_validation::DEFAULT_LICENSE_HTTP_TIMEOUT_SECS,
+        license_http_proxy: None,
     }
 }
 
@@ -1644,6 +2074,111 @@ mod tests {
         }
     }
 
+    #[test]
+    fn load_after_empty_or_corrupt_file_keeps_last_known_good_backup() {
+        let dir = tempdir().expect("tempdir");
+        let store = ConfigStore::from_config_dir(dir.path().to_path_buf());
+        let sets = vec![ExclusionSetRecord {
+            id: "studio".to_string(),
+            name: "Studio".to_string(),
+            patterns: vec!["*.tmp".to_string()],
+        }];
+        store.save_exclusion_sets(&sets).expect("sets should save");
+        let sets_path = store.exclusion_sets_file_path();
+        let backup_path = last_known_good_path(&sets_path);
+        let saved = fs::read_to_string(&backup_path).expect("backup should be written");
+
+        // A partial write leaves an empty file; loading fills in defaults but keeps the backup.
+        fs::write(&sets_path, "").expect("truncate");
+        let loaded = store.load_exclusion_sets().expect("defaults should load");
+        assert_ne!(loaded.len(), 1);
+        assert_eq!(
+            fs::read_to_string(&backup_path).expect("backup kept"),
+            saved
+        );
+
+        fs::write(&sets_path, "- id: [unterminated").expect("corrupt write");
+        store.check_config_file_integrity(false);
+        assert!(store.load_exclusion_sets().is_err());
+        assert_eq!(
+            fs::read_to_string(&backup_path).expect("backup kept"),
+            saved
+        );
+    }
+
+    #[test]
+    fn save_writes_last_known_good_backup() {
+        let dir = tempdir().expect("tempdir");
+        let store = ConfigStore::from_config_dir(dir.path().to_path_buf());
+        let sets = default_exclusion_sets();
+
+        store.save_exclusion_sets(&sets).expect("sets should save");
+
+        let saved = fs::read_to_string(store.exclusion_sets_file_path()).expect("saved file");
+        let backup = fs::read_to_string(last_known_good_path(&store.exclusion_sets_file_path()))
+            .expect("backup should be written");
+        assert_eq!(saved, backup);
+    }
+
+    #[test]
+    fn check_config_file_integrity_moves_corrupt_file_aside_only_when_restoring() {
+        let dir = tempdir().expect("tempdir");
+        let store = ConfigStore::from_config_dir(dir.path().to_path_buf());
+        store
+            .save_exclusion_sets(&default_exclusion_sets())
+            .expect("sets should save");
+        let sets_path = store.exclusion_sets_file_path();
+        fs::write(&sets_path, "- id: [unterminated").expect("corrupt write");
+
+        let reports = store.check_config_file_integrity(false);
+        let sets_report = reports
+            .iter()
+            .find(|report| report.name == EXCLUSION_SETS_FILE_NAME)
+            .expect("exclusion sets should be reported");
+        assert_eq!(sets_report.status, PersistedFileStatus::Corrupt);
+        assert!(sets_report.backup_available);
+        assert!(!sets_report.restored_from_backup);
+        assert_eq!(sets_report.corrupt_backup_path, None);
+        assert_eq!(
+            fs::read_to_string(&sets_path).expect("corrupt file stays in place"),
+            "- id: [unterminated"
+        );
+
+        let reports = store.check_config_file_integrity(true);
+
+        let sets_report = reports
+            .iter()
+            .find(|report| report.name == EXCLUSION_SETS_FILE_NAME)
+            .expect("exclusion sets should be reported");
+        assert_eq!(sets_report.status, PersistedFileStatus::Corrupt);
+        assert!(sets_report.backup_available);
+        assert!(sets_report.restored_from_backup);
+        let corrupt_path = sets_report
+            .corrupt_backup_path
+            .as_ref()
+            .expect("corrupt copy path should be reported");
+        assert_eq!(
+            fs::read_to_string(corrupt_path).expect("corrupt copy kept"),
+            "- id: [unterminated"
+        );
+        let restored_ids: Vec<String> = store
+            .load_exclusion_sets()
+            .expect("restored sets should load")
+            .into_iter()
+            .map(|set| set.id)
+            .collect();
+        let default_ids: Vec<String> = default_exclusion_sets()
+            .into_iter()
+            .map(|set| set.id)
+            .collect();
+        assert_eq!(restored_ids, default_ids);
+        let settings_report = reports
+            .iter()
+            .find(|report| report.name == SETTINGS_FILE_NAME)
+            .expect("settings should be reported");
+        assert_eq!(settings_report.status, PersistedFileStatus::Missing);
+    }
+
     #[test]
     fn normalize_network_mount_rejects_parent_relative_subpath() {
         let mut mount = build_network_mount("smb://nas.local/share", Some("user"));
@@ -1688,6 +2223,11 @@ mod tests {
             source_sub_path: Some("DCIM".to_string()),
             source_identity: None,
             recurring_schedules: Vec::new(),
+            enabled: true,
+            notifications: TaskNotificationPrefs::default(),
+            resource_group: None,
+            max_coalesce_secs: None,
+            max_duration_secs: None,
         })
         .expect("task should normalize");
 
@@ -1870,6 +2410,11 @@ mod tests {
             source_sub_path: None,
             source_identity: None,
             recurring_schedules: Vec::new(),
+            enabled: true,
+            notifications: TaskNotificationPrefs::default(),
+            resource_group: None,
+            max_coalesce_secs: None,
+            max_duration_secs: None,
         }];
 
         store
@@ -1927,6 +2472,11 @@ mod tests {
                 checksum_mode: false,
                 retention_count: 20,
             }],
+            enabled: true,
+            notifications: TaskNotificationPrefs::default(),
+            resource_group: None,
+            max_coalesce_secs: None,
+            max_duration_secs: None,
         }];
 
         store
@@ -1964,6 +2514,11 @@ mod tests {
             source_sub_path: None,
             source_identity: None,
             recurring_schedules: Vec::new(),
+            enabled: true,
+            notifications: TaskNotificationPrefs::default(),
+            resource_group: None,
+            max_coalesce_secs: None,
+            max_duration_secs: None,
         }];
 
         store
@@ -2022,6 +2577,11 @@ mod tests {
             source_sub_path: None,
             source_identity: None,
             recurring_schedules: Vec::new(),
+            enabled: true,
+            notifications: TaskNotificationPrefs::default(),
+            resource_group: None,
+            max_coalesce_secs: None,
+            max_duration_secs: None,
         }];
 
         store
@@ -2107,6 +2667,11 @@ mod tests {
             source_sub_path: None,
             source_identity: None,
             recurring_schedules: Vec::new(),
+            enabled: true,
+            notifications: TaskNotificationPrefs::default(),
+            resource_group: None,
+            max_coalesce_secs: None,
+            max_duration_secs: None,
         })
         .expect("task should normalize");
 
@@ -2152,6 +2717,11 @@ mod tests {
             source_sub_path: None,
             source_identity: None,
             recurring_schedules: Vec::new(),
+            enabled: true,
+            notifications: TaskNotificationPrefs::default(),
+            resource_group: None,
+            max_coalesce_secs: None,
+            max_duration_secs: None,
         })
         .expect("task should normalize");
 
@@ -2197,6 +2767,11 @@ mod tests {
             source_sub_path: None,
             source_identity: None,
             recurring_schedules: Vec::new(),
+            enabled: true,
+            notifications: TaskNotificationPrefs::default(),
+            resource_group: None,
+            max_coalesce_secs: None,
+            max_duration_secs: None,
         })
         .expect("task should normalize");
 
@@ -2260,6 +2835,11 @@ mod tests {
                 checksum_mode: false,
                 retention_count: 20,
             }],
+            enabled: true,
+            notifications: TaskNotificationPrefs::default(),
+            resource_group: None,
+            max_coalesce_secs: None,
+            max_duration_secs: None,
         })
         .expect("task should normalize");
 
@@ -2328,4 +2908,26 @@ mod tests {
         assert_ne!(initial_token, next_token);
         assert!(next_token.starts_with("swmcp_"));
     }
+
+    #[test]
+    fn settings_patch_sets_keeps_and_clears_quiet_hours() {
+        let mut settings = default_settings();
+        let window = (
+            NaiveTime::from_hms_opt(22, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(6, 30, 0).unwrap(),
+        );
+
+        let set: SettingsPatch =
+            serde_json::from_str(r#"{"quietHours":["22:00:00","06:30:00"]}"#).unwrap();
+        set.apply_to(&mut settings);
+        assert_eq!(settings.quiet_hours, Some(window));
+
+        let untouched: SettingsPatch = serde_json::from_str(r#"{"language":"ko"}"#).unwrap();
+        untouched.apply_to(&mut settings);
+        assert_eq!(settings.quiet_hours, Some(window));
+
+        let cleared: SettingsPatch = serde_json::from_str(r#"{"quietHours":null}"#).unwrap();
+        cleared.apply_to(&mut settings);
+        assert_eq!(settings.quiet_hours, None);
+    }
 }
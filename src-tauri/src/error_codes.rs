@@ -7,3 +7,9 @@ pub const ERR_WATCH_START_FAILED: &str = "ERR_WATCH_START_FAILED";
 
 /// Watch 중지 실패
 pub const ERR_WATCH_STOP_FAILED: &str = "ERR_WATCH_STOP_FAILED";
+
+/// 시스템/부트 볼륨을 타겟으로 하는 동기화·삭제 차단
+pub const ERR_SYSTEM_TARGET_BLOCKED: &str = "ERR_SYSTEM_TARGET_BLOCKED";
+
+/// 소스가 비어 있거나 타겟에 비해 파일이 너무 적어 orphan 목록/삭제를 거부
+pub const ERR_SUSPICIOUS_EMPTY_SOURCE: &str = "ERR_SUSPICIOUS_EMPTY_SOURCE";
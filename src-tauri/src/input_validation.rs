@@ -10,7 +10,6 @@ use anyhow::{bail, Result};
 /// Ensures patterns are safe, properly formatted, and within reasonable limits.
 pub fn validate_exclude_patterns(patterns: &[String]) -> Result<()> {
     const MAX_PATTERNS: usize = 300;
-    const MAX_PATTERN_LENGTH: usize = 255;
 
     if patterns.len() > MAX_PATTERNS {
         bail!(
@@ -21,38 +20,139 @@ pub fn validate_exclude_patterns(patterns: &[String]) -> Result<()> {
     }
 
     for pattern in patterns {
-        let trimmed = pattern.trim();
+        validate_exclude_pattern(pattern)?;
+    }
 
-        if trimmed.is_empty() {
-            continue;
-        }
+    Ok(())
+}
 
-        if trimmed.len() > MAX_PATTERN_LENGTH {
-            bail!(
-                "Pattern too long: '{}' ({} chars, max: {})",
-                &trimmed[..20.min(trimmed.len())],
-                trimmed.len(),
-                MAX_PATTERN_LENGTH
-            );
-        }
+/// Validate a single exclude pattern
+///
+/// Applies the per-pattern checks of `validate_exclude_patterns` (length, traversal,
+/// control characters, glob syntax). Blank patterns are accepted because they are skipped.
+pub fn validate_exclude_pattern(pattern: &str) -> Result<()> {
+    const MAX_PATTERN_LENGTH: usize = 255;
 
-        // Check for dangerous patterns
-        if trimmed.contains("..") {
-            bail!("Pattern contains path traversal: '{}'", trimmed);
-        }
+    let trimmed = pattern.trim();
 
-        if trimmed.contains('\0') || trimmed.contains('\n') || trimmed.contains('\r') {
-            bail!("Pattern contains control characters");
-        }
+    if trimmed.is_empty() {
+        return Ok(());
+    }
 
-        // Validate glob syntax
-        globset::Glob::new(trimmed)
+    if trimmed.len() > MAX_PATTERN_LENGTH {
+        bail!(
+            "Pattern too long: '{}' ({} chars, max: {})",
+            &trimmed[..20.min(trimmed.len())],
+            trimmed.len(),
+            MAX_PATTERN_LENGTH
+        );
+    }
+
+    // Check for dangerous patterns
+    if trimmed.contains("..") {
+        bail!("Pattern contains path traversal: '{}'", trimmed);
+    }
+
+    if trimmed.contains('\0') || trimmed.contains('\n') || trimmed.contains('\r') {
+        bail!("Pattern contains control characters");
+    }
+
+    // Validate glob syntax of every brace alternative
+    for expanded in expand_glob_braces(trimmed)? {
+        globset::Glob::new(&expanded)
             .map_err(|e| anyhow::anyhow!("Invalid glob pattern '{}': {}", trimmed, e))?;
     }
 
     Ok(())
 }
 
+/// Maximum number of globs a single pattern may expand into
+const MAX_BRACE_EXPANSIONS: usize = 64;
+
+/// Expand `{a,b}` alternatives into separate globs
+///
+/// `*.{jpg,png}` becomes `*.jpg` and `*.png`, so callers can derive the anchored and
+/// `**/`-prefixed variants per alternative instead of for the braced pattern as a whole.
+/// Groups may nest. Braces inside `[...]` classes or escaped with `\` stay literal.
+/// Unbalanced braces are rejected.
+pub fn expand_glob_braces(pattern: &str) -> Result<Vec<String>> {
+    let mut expanded = Vec::new();
+    expand_glob_braces_into(pattern, pattern, &mut expanded)?;
+    Ok(expanded)
+}
+
+fn expand_glob_braces_into(original: &str, pattern: &str, out: &mut Vec<String>) -> Result<()> {
+    let Some((open, close, commas)) = find_top_level_brace_group(original, pattern)? else {
+        if out.len() >= MAX_BRACE_EXPANSIONS {
+            bail!(
+                "Pattern '{}' expands to more than {} alternatives",
+                original,
+                MAX_BRACE_EXPANSIONS
+            );
+        }
+        out.push(pattern.to_string());
+        return Ok(());
+    };
+
+    let prefix = &pattern[..open];
+    let suffix = &pattern[close + 1..];
+    let mut start = open + 1;
+    for end in commas.into_iter().chain(std::iter::once(close)) {
+        let alternative = &pattern[start..end];
+        expand_glob_braces_into(original, &format!("{prefix}{alternative}{suffix}"), out)?;
+        start = end + 1;
+    }
+    Ok(())
+}
+
+/// Byte offsets of the first top-level `{`, its matching `}`, and the commas between them
+fn find_top_level_brace_group(
+    original: &str,
+    pattern: &str,
+) -> Result<Option<(usize, usize, Vec<usize>)>> {
+    let bytes = pattern.as_bytes();
+    let mut depth = 0usize;
+    let mut open = None;
+    let mut commas = Vec::new();
+    let mut in_class = false;
+    let mut index = 0;
+
+    while index < bytes.len() {
+        match bytes[index] {
+            b'\\' => {
+                index += 2;
+                continue;
+            }
+            b'[' if !in_class => in_class = true,
+            b']' if in_class => in_class = false,
+            _ if in_class => {}
+            b'{' => {
+                if depth == 0 {
+                    open = Some(index);
+                }
+                depth += 1;
+            }
+            b'}' => {
+                if depth == 0 {
+                    bail!("Unbalanced '}}' in pattern '{}'", original);
+                }
+                depth -= 1;
+                if depth == 0 {
+                    return Ok(open.map(|open| (open, index, commas)));
+                }
+            }
+            b',' if depth == 1 => commas.push(index),
+            _ => {}
+        }
+        index += 1;
+    }
+
+    if depth > 0 {
+        bail!("Unclosed '{{' in pattern '{}'", original);
+    }
+    Ok(None)
+}
+
 /// Validate and sanitize task ID
 ///
 /// Ensures task IDs are safe and well-formed.
@@ -186,6 +286,41 @@ mod tests {
         assert!(validate_exclude_patterns(&patterns).is_ok()); // Empty patterns are skipped
     }
 
+    #[test]
+    fn test_validate_exclude_pattern_single() {
+        assert!(validate_exclude_pattern("*.tmp").is_ok());
+        assert!(validate_exclude_pattern("   ").is_ok());
+        assert!(validate_exclude_pattern("../etc").is_err());
+        assert!(validate_exclude_pattern("a\nb").is_err());
+        assert!(validate_exclude_pattern("[abc").is_err());
+    }
+
+    #[test]
+    fn test_expand_glob_braces() {
+        assert_eq!(
+            expand_glob_braces("**/*.{jpg,png,raw}").unwrap(),
+            vec!["**/*.jpg", "**/*.png", "**/*.raw"]
+        );
+        assert_eq!(
+            expand_glob_braces("{a,b{c,d}}/x").unwrap(),
+            vec!["a/x", "bc/x", "bd/x"]
+        );
+        assert_eq!(expand_glob_braces("plain").unwrap(), vec!["plain"]);
+        assert_eq!(expand_glob_braces("[{]").unwrap(), vec!["[{]"]);
+        assert_eq!(expand_glob_braces(r"\{x\}").unwrap(), vec![r"\{x\}"]);
+    }
+
+    #[test]
+    fn test_validate_exclude_patterns_rejects_malformed_braces() {
+        let unclosed = validate_exclude_patterns(&["*.{jpg,png".to_string()]);
+        assert!(unclosed.unwrap_err().to_string().contains("Unclosed '{'"));
+
+        let stray = validate_exclude_patterns(&["*.jpg}".to_string()]);
+        assert!(stray.unwrap_err().to_string().contains("Unbalanced '}'"));
+
+        assert!(validate_exclude_patterns(&["*.{jpg,png}".to_string()]).is_ok());
+    }
+
     #[test]
     fn test_validate_task_id_valid() {
         assert!(validate_task_id("task-123").is_ok());
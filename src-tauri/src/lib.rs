@@ -1,4 +1,5 @@
 pub mod apple_bridge;
+pub mod capacity_history;
 pub mod config_store;
 pub mod control_plane;
 pub mod distribution;
@@ -21,9 +22,11 @@ pub mod watcher;
 #[cfg(test)]
 mod lib_tests;
 
-use chrono::Utc;
+use capacity_history::{CapacityHistoryStore, CapacitySample};
+use chrono::{NaiveTime, Utc};
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::ffi::OsStr;
+use std::hash::{Hash, Hasher};
 use std::path::{Component, Path, PathBuf};
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex as StdMutex};
@@ -38,12 +41,16 @@ use tokio::sync::{Mutex, Notify, RwLock};
 use tokio_util::sync::CancellationToken;
 
 use sync_engine::{
+    delta::DeltaTransferStats,
     types::{
-        DeleteOrphanResult, DryRunPhase, DryRunProgress, DryRunSummary, FileDiff, OrphanFile,
-        SyncFileEntry, SyncProgressPhase, SyncResult, TargetNewerConflictCandidate,
-        TargetPreflightInfo, TargetPreflightKind,
+        DeleteOrphanResult, DriftReport, DryRunPhase, DryRunProgress, DryRunSummary, FileDiff,
+        FileDiffKind, OrphanDeletionSummary, OrphanFile, OrphanSummary, RecopyFileResult,
+        ScanMemoryEstimate, SyncError, SyncErrorKind, SyncFileEntry, SyncFileStatus, SyncProgress,
+        SyncProgressPhase, SyncResult, TargetLayout, TargetLayoutPreview,
+        TargetNewerConflictCandidate, TargetPreflightInfo, TargetPreflightKind,
+        DEFAULT_SCAN_MEMORY_WARN_BYTES,
     },
-    DryRunResult, SyncEngine, SyncOptions,
+    BandwidthLimiter, DryRunResult, SyncEngine, SyncOptions, SyncSnapshot,
 };
 use system_integration::DiskMonitor;
 
@@ -52,14 +59,16 @@ use config_store::{
     settings_snapshot_from_store, validate_exclusion_sets, AppSettings, ConfigStore,
     ConfigStoreChangedEvent, ConfigStoreError, DeleteResultEnvelope, ExclusionSetEnvelope,
     ExclusionSetRecord, ExclusionSetsEnvelope, McpSettingsPatch, NetworkMountRecord,
-    NewSyncTaskRecord, SettingsEnvelope, SourceIdentitySnapshot, SyncTaskEnvelope, SyncTaskRecord,
-    SyncTasksEnvelope, UpdateSettingsPayload, UpdateSyncTaskRequest,
+    NewSyncTaskRecord, PersistedFileIntegrity, PersistedFileStatus, SettingsEnvelope,
+    SourceIdentitySnapshot, SyncTaskEnvelope, SyncTaskRecord, SyncTasksEnvelope,
+    TaskNotificationPrefs, UpdateSettingsPayload, UpdateSyncTaskRequest,
 };
 use control_plane::{ControlPlaneHandle, ControlPlaneRequest, ControlPlaneResponse};
 use distribution::{AppStoreUpdateCheckResult, DistributionInfo};
 use license::generate_licenses_report;
 use logging::{
-    add_log, get_system_logs, get_task_logs, LogCategory, LogManager, DEFAULT_MAX_LOG_LINES,
+    add_log, get_log_category_counts, get_system_logs, get_task_logs, LogCategory, LogManager,
+    SyncRun, DEFAULT_MAX_LOG_LINES,
 };
 use mcp_jobs::{McpJobKind, McpJobProgress, McpJobRecord, McpJobRegistry, McpJobStatus};
 use network_mount::{NetworkMountCapturePayload, NetworkMountRole};
@@ -71,7 +80,10 @@ use recurring::{
 };
 use security_scoped::{CapturedPathAccess, LegacyImportStatus, SecurityScopedAccessManager};
 
-use watcher::{WatchEvent, WatcherManager};
+use watcher::{
+    WatchEvent, WatchNoiseDecision, WatchNoiseTracker, WatchPauseReason, WatchSystemLimits,
+    WatcherDiagnostics, WatcherManager,
+};
 
 #[derive(Debug, Clone, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -112,6 +124,8 @@ struct SyncProgressStateInner {
     last_log_key: String,
     log_buffer: Vec<logging::LogEntry>,
     last_log_emit_time: Instant,
+    last_milestone_files: u64,
+    last_milestone_time: Instant,
 }
 
 impl SyncProgressStateInner {
@@ -121,19 +135,53 @@ impl SyncProgressStateInner {
             last_log_key: String::new(),
             log_buffer: Vec::with_capacity(50), // Buffer size 50
             last_log_emit_time: Instant::now(),
+            last_milestone_files: 0,
+            last_milestone_time: Instant::now(),
         }
     }
 }
 
+const SYNC_MILESTONE_FILE_INTERVAL: u64 = 1_000;
+const SYNC_MILESTONE_TIME_INTERVAL: Duration = Duration::from_secs(120);
+
+/// 파일 단위 복사 로그(FileCopied)는 verbose 모드에서만 남깁니다. 그 외 카테고리는 항상 기록합니다.
+fn should_log_file_entry(verbose_file_logging: bool, category: &LogCategory) -> bool {
+    verbose_file_logging || *category != LogCategory::FileCopied
+}
+
+/// 긴 동기화 중 "진행 중" 마일스톤 로그를 남길지 결정합니다.
+/// 마지막 마일스톤 이후 파일 수가 늘었고, 1,000개 이상 진행했거나 2분이 지났을 때만 true입니다.
+fn should_log_sync_milestone(
+    processed_files: u64,
+    last_milestone_files: u64,
+    since_last_milestone: Duration,
+) -> bool {
+    if processed_files <= last_milestone_files {
+        return false;
+    }
+    processed_files - last_milestone_files >= SYNC_MILESTONE_FILE_INTERVAL
+        || since_last_milestone >= SYNC_MILESTONE_TIME_INTERVAL
+}
+
 #[derive(Clone)]
 struct SyncProgressState {
     inner: Arc<Mutex<SyncProgressStateInner>>,
+    verbose_file_logging: bool,
+    progress_emit_interval: Duration,
+    log_flush_interval: Duration,
 }
 
 impl SyncProgressState {
-    fn new() -> Self {
+    fn new(
+        verbose_file_logging: bool,
+        progress_emit_interval: Duration,
+        log_flush_interval: Duration,
+    ) -> Self {
         Self {
             inner: Arc::new(Mutex::new(SyncProgressStateInner::new())),
+            verbose_file_logging,
+            progress_emit_interval,
+            log_flush_interval,
         }
     }
 
@@ -156,7 +204,7 @@ impl SyncProgressState {
     fn should_emit_progress(&self) -> bool {
         let state = self.inner.try_lock();
         if let Ok(mut state) = state {
-            if state.last_emit_time.elapsed() >= Duration::from_millis(100) {
+            if state.last_emit_time.elapsed() >= self.progress_emit_interval {
                 state.last_emit_time = Instant::now();
                 true
             } else {
@@ -168,14 +216,33 @@ impl SyncProgressState {
         }
     }
 
+    fn take_milestone(&self, processed_files: u64) -> bool {
+        let state = self.inner.try_lock();
+        if let Ok(mut state) = state {
+            if should_log_sync_milestone(
+                processed_files,
+                state.last_milestone_files,
+                state.last_milestone_time.elapsed(),
+            ) {
+                state.last_milestone_files = processed_files;
+                state.last_milestone_time = Instant::now();
+                true
+            } else {
+                false
+            }
+        } else {
+            false
+        }
+    }
+
     fn add_log(&self, entry: logging::LogEntry) -> Option<Vec<logging::LogEntry>> {
         let state = self.inner.try_lock();
         if let Ok(mut state) = state {
             state.log_buffer.push(entry);
 
-            // Flush if buffer full or time elapsed (200ms)
+            // Flush if buffer full or the flush interval elapsed
             if state.log_buffer.len() >= 50
-                || state.last_log_emit_time.elapsed() >= Duration::from_millis(200)
+                || state.last_log_emit_time.elapsed() >= self.log_flush_interval
             {
                 state.last_log_emit_time = Instant::now();
                 let batch = std::mem::replace(&mut state.log_buffer, Vec::with_capacity(50));
@@ -438,6 +505,8 @@ struct DryRunCandidatePathSnapshot {
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct DryRunArtifactCandidate {
     relative_path: PathBuf,
+    /// 타겟 레이아웃이 적용된 경우 `relative_path`와 다른 소스 쪽 상대 경로
+    source_relative_path: PathBuf,
     source: DryRunCandidatePathSnapshot,
     target: DryRunCandidatePathSnapshot,
 }
@@ -453,6 +522,7 @@ struct DryRunTaskConfigSnapshot {
 #[derive(Debug, Clone)]
 struct DryRunArtifact {
     config: DryRunTaskConfigSnapshot,
+    run_options: SyncRunOptions,
     source_root: DryRunRootSnapshot,
     target_root: DryRunRootSnapshot,
     result: DryRunResult,
@@ -460,6 +530,64 @@ struct DryRunArtifact {
     candidates: Vec<DryRunArtifactCandidate>,
 }
 
+/// `prepare_orphan_deletion`이 발급한 토큰에 묶인 삭제 선택
+#[derive(Debug, Clone)]
+struct PendingOrphanDeletion {
+    task_id: String,
+    target: PathBuf,
+    /// 정렬·중복 제거된 선택 경로
+    paths: Vec<String>,
+    expires_at: Instant,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct InProgressSyncFile {
+    source_path: PathBuf,
+    target_path: PathBuf,
+    compressed: bool,
+}
+
+/// 이번 실행에서 복사를 끝낸 타깃 파일 (cancel_and_rollback용). 다음 실행이 시작되면 초기화되고, 취소 없이 끝나면 비워짐
+#[derive(Debug, Clone, Default)]
+struct SyncRunCopiedFiles {
+    /// 실행 전에는 없던 파일
+    new_files: Vec<PathBuf>,
+    /// 기존 내용을 덮어쓴 파일 (이전 버전이 없어 되돌릴 수 없음)
+    overwritten_files: Vec<PathBuf>,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SyncRollbackResult {
+    cancelled: bool,
+    removed_files: Vec<String>,
+    kept_overwritten_files: Vec<String>,
+    failures: Vec<String>,
+    incomplete_file_removed: Option<String>,
+    warnings: Vec<String>,
+}
+
+/// 최근 구간의 처리 바이트로 전송 속도를 계산하는 태스크별 상태
+#[derive(Debug, Clone)]
+struct SyncRateTracker {
+    window_start: Instant,
+    window_start_bytes: u64,
+    bytes_per_sec: u64,
+    processed_bytes: u64,
+    total_bytes: u64,
+}
+
+const SYNC_RATE_WINDOW: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ActiveSyncRate {
+    task_id: String,
+    bytes_per_sec: u64,
+    processed_bytes: u64,
+    total_bytes: u64,
+}
+
 #[derive(Clone)]
 pub struct AppState {
     config_store: Arc<ConfigStore>,
@@ -476,20 +604,36 @@ pub struct AppState {
     dry_running_tasks: Arc<RwLock<HashSet<String>>>,
     /// task별 최신 dry-run 재사용 artifact
     dry_run_artifacts: Arc<RwLock<HashMap<String, DryRunArtifact>>>,
+    /// orphan 삭제 확인 토큰 (token -> 준비된 삭제 선택), 1회용이며 만료됨
+    orphan_deletion_tokens: Arc<RwLock<HashMap<String, PendingOrphanDeletion>>>,
     /// 파일 시스템 감시 매니저
     watcher_manager: Arc<RwLock<WatcherManager>>,
     /// 프론트엔드에서 전달된 최신 런타임 설정
     runtime_config: Arc<RwLock<RuntimeConfigPayload>>,
     /// 현재 동기화 실행 중인 태스크 집합 (중복 실행 방지)
     syncing_tasks: Arc<RwLock<HashSet<String>>>,
+    /// 리소스 그룹별 실행 중인 런타임 태스크 (같은 그룹은 한 번에 하나만 실행)
+    syncing_resource_groups: Arc<RwLock<HashMap<String, String>>>,
     /// 런타임 동기화 대기 큐
     runtime_sync_queue: Arc<RwLock<VecDeque<String>>>,
     /// 큐에 올라간 태스크 집합 (중복 enqueue 방지)
     queued_sync_tasks: Arc<RwLock<HashSet<String>>>,
+    /// 앱 시작 시 초기 동기화로 큐에 올라간 태스크 집합 (cancel_bootstrap_syncs 대상)
+    runtime_bootstrap_queued_tasks: Arc<RwLock<HashSet<String>>>,
+    /// 태스크별 마지막 성공 동기화 소요 시간 (큐 ETA 추정용, 메모리에만 유지)
+    last_sync_durations: Arc<RwLock<HashMap<String, Duration>>>,
+    /// 모든 동시 동기화가 함께 쓰는 전역 대역폭 버킷 (RuntimeSettings.global_max_bytes_per_sec)
+    global_bandwidth_limiter: Arc<BandwidthLimiter>,
     /// syncing 중 추가 변경이 감지된 태스크 집합 (1회 재실행 보장)
     runtime_pending_sync_tasks: Arc<RwLock<HashSet<String>>>,
+    /// stop_watch_graceful이 진행 중인 태스크 집합 (새 enqueue 차단)
+    runtime_stopping_tasks: Arc<RwLock<HashSet<String>>>,
     /// 런타임 큐 디스패처 실행 여부
     runtime_dispatcher_running: Arc<Mutex<bool>>,
+    /// 런타임 큐 디스패처 세대 (force_restart_dispatcher 이후 이전 루프는 스스로 종료)
+    runtime_dispatcher_generation: Arc<AtomicU64>,
+    /// 디스패처가 마지막으로 태스크를 꺼내 실행한 시각
+    runtime_dispatcher_last_dispatch_at: Arc<StdMutex<Option<chrono::DateTime<Utc>>>>,
     /// 런타임 큐 디스패처 재평가 알림
     runtime_dispatcher_wakeup: Arc<Notify>,
     /// 런타임 동기화 슬롯 해제 알림
@@ -516,6 +660,8 @@ pub struct AppState {
     recurring_schedule_history_store: Arc<RecurringScheduleHistoryStore>,
     /// 정기 실행 스케줄러 재평가 알림
     recurring_scheduler_wakeup: Arc<Notify>,
+    /// 타겟 볼륨 여유 공간 추이 저장소
+    capacity_history_store: Arc<CapacityHistoryStore>,
     /// 로컬 MCP control plane listener 상태
     control_plane_handle: Arc<Mutex<Option<ControlPlaneHandle>>>,
     /// MCP 장기 작업 상태 저장소
@@ -524,6 +670,18 @@ pub struct AppState {
     mcp_job_seq: Arc<AtomicU64>,
     /// App Sandbox security-scoped bookmark 활성화 상태
     security_scoped_access_manager: Arc<SecurityScopedAccessManager>,
+    /// task별 마지막으로 쓰기 시작했지만 완료되지 않은 타깃 파일 (취소 후 정리용)
+    sync_in_progress_files: Arc<StdMutex<HashMap<String, InProgressSyncFile>>>,
+    /// 태스크별 최근 watch 배치 노이즈 기록
+    watch_noise_trackers: Arc<StdMutex<HashMap<String, WatchNoiseTracker>>>,
+    /// 태스크별 마지막 실행에서 복사한 파일 (cancel_and_rollback용)
+    sync_run_copied_files: Arc<StdMutex<HashMap<String, SyncRunCopiedFiles>>>,
+    /// 복사 중인 태스크별 전송 속도 (get_active_sync_rates 폴링용)
+    sync_transfer_rates: Arc<StdMutex<HashMap<String, SyncRateTracker>>>,
+    /// 태스크 누적 통계 파일의 읽기-수정-쓰기 직렬화 락
+    task_lifetime_stats_lock: Arc<Mutex<()>>,
+    /// 태스크별 마지막 실패 파일 목록의 읽기-수정-쓰기 직렬화 락
+    task_failed_files_lock: Arc<Mutex<()>>,
 }
 
 #[derive(Default)]
@@ -567,11 +725,168 @@ struct RuntimeConfigPayload {
     settings: RuntimeSettings,
 }
 
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Default)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct RuntimeSettings {
     #[serde(default = "default_data_unit_system")]
     data_unit_system: DataUnitSystem,
+    /// 파일 단위 상세 로그 on/off. 설정 파일에 저장되며 runtime_set_config 페이로드로는 바뀌지 않음
+    #[serde(skip)]
+    verbose_file_logging: bool,
+    /// 동시에 감시할 watch 태스크 수 상한. 비어 있으면 DEFAULT_MAX_WATCHERS
+    #[serde(default)]
+    max_watchers: Option<usize>,
+    /// 모든 동시 동기화를 합친 최대 전송 속도 (bytes/sec). 0이면 제한 없음
+    #[serde(default)]
+    global_max_bytes_per_sec: u64,
+    /// 앱 시작 시 모든 watch 태스크에 초기 동기화를 큐잉할지 여부
+    #[serde(default = "default_bootstrap_initial_sync")]
+    bootstrap_initial_sync: bool,
+    /// 동기화 진행률 이벤트 최소 간격(ms). 비어 있으면 config_store::DEFAULT_PROGRESS_EMIT_MS
+    #[serde(default)]
+    progress_emit_ms: Option<u64>,
+    /// 동기화 로그 배치 flush 간격(ms). 비어 있으면 config_store::DEFAULT_LOG_FLUSH_MS
+    #[serde(default)]
+    log_flush_ms: Option<u64>,
+    /// watch/예약 동기화를 보류할 로컬 시간대 (시작, 끝). 끝이 시작보다 이르면 자정을 넘는 구간
+    #[serde(default)]
+    quiet_hours: Option<(NaiveTime, NaiveTime)>,
+    /// 전체 알림 on/off. 꺼져 있으면 태스크별 알림 설정과 관계없이 보내지 않음
+    #[serde(default = "default_notifications_enabled")]
+    notifications_enabled: bool,
+    /// 이 이름의 파일·디렉터리만 바뀐 watch 배치는 동기화하지 않음. 비어 있으면 DEFAULT_WATCH_NOISE_NAMES
+    #[serde(default)]
+    watch_noise_names: Option<Vec<String>>,
+    /// 소스 파일 수가 타겟의 이 비율(0.0–1.0)보다 적으면 orphan 목록을 거부. 비어 있으면 소스가 0개일 때만
+    #[serde(default)]
+    orphan_min_source_fraction: Option<f64>,
+    /// 타겟 볼륨 여유 공간 샘플링 간격(초). 비어 있으면 DEFAULT_CAPACITY_SAMPLE_INTERVAL_SECS
+    #[serde(default)]
+    capacity_sample_interval_secs: Option<u64>,
+    /// 라이선스 API 요청 제한 시간(초). 비어 있으면 DEFAULT_LICENSE_HTTP_TIMEOUT_SECS
+    #[serde(default)]
+    license_http_timeout_secs: Option<u64>,
+    /// 라이선스 API 요청에 쓸 프록시 URL. 비어 있으면 HTTPS_PROXY/NO_PROXY 환경 변수를 따름
+    #[serde(default)]
+    license_http_proxy: Option<String>,
+}
+
+impl Default for RuntimeSettings {
+    fn default() -> Self {
+        Self {
+            data_unit_system: default_data_unit_system(),
+            verbose_file_logging: false,
+            max_watchers: None,
+            global_max_bytes_per_sec: 0,
+            bootstrap_initial_sync: default_bootstrap_initial_sync(),
+            progress_emit_ms: None,
+            log_flush_ms: None,
+            quiet_hours: None,
+            notifications_enabled: default_notifications_enabled(),
+            watch_noise_names: None,
+            orphan_min_source_fraction: None,
+            capacity_sample_interval_secs: None,
+            license_http_timeout_secs: None,
+            license_http_proxy: None,
+        }
+    }
+}
+
+const PROGRESS_EMIT_MS_BOUNDS: (u64, u64) = (16, 2_000);
+const LOG_FLUSH_MS_BOUNDS: (u64, u64) = (50, 5_000);
+
+fn default_bootstrap_initial_sync() -> bool {
+    true
+}
+
+fn default_notifications_enabled() -> bool {
+    true
+}
+
+impl RuntimeSettings {
+    fn effective_orphan_min_source_fraction(&self) -> f64 {
+        self.orphan_min_source_fraction.unwrap_or(0.0)
+    }
+
+    fn effective_max_watchers(&self) -> usize {
+        self.max_watchers
+            .unwrap_or(config_store::DEFAULT_MAX_WATCHERS as usize)
+    }
+
+    /// 범위를 벗어난 값은 가장 가까운 경계로 맞춥니다 (너무 짧으면 webview가 이벤트에 잠김).
+    fn effective_progress_emit_interval(&self) -> Duration {
+        let (min, max) = PROGRESS_EMIT_MS_BOUNDS;
+        Duration::from_millis(
+            self.progress_emit_ms
+                .unwrap_or(config_store::DEFAULT_PROGRESS_EMIT_MS)
+                .clamp(min, max),
+        )
+    }
+
+    fn effective_watch_noise_names(&self) -> Vec<String> {
+        match &self.watch_noise_names {
+            Some(names) => names.clone(),
+            None => watcher::DEFAULT_WATCH_NOISE_NAMES
+                .iter()
+                .map(|name| name.to_string())
+                .collect(),
+        }
+    }
+
+    fn license_http_config(&self) -> license_validation::LicenseHttpConfig {
+        license_validation::LicenseHttpConfig {
+            timeout_secs: self.license_http_timeout_secs,
+            proxy_url: self.license_http_proxy.clone(),
+        }
+    }
+
+    /// 너무 짧은 간격은 MIN_CAPACITY_SAMPLE_INTERVAL_SECS로 올립니다 (매번 볼륨 목록을 다시 읽음).
+    fn effective_capacity_sample_interval(&self) -> Duration {
+        Duration::from_secs(
+            self.capacity_sample_interval_secs
+                .unwrap_or(capacity_history::DEFAULT_CAPACITY_SAMPLE_INTERVAL_SECS)
+                .max(capacity_history::MIN_CAPACITY_SAMPLE_INTERVAL_SECS),
+        )
+    }
+
+    fn effective_log_flush_interval(&self) -> Duration {
+        let (min, max) = LOG_FLUSH_MS_BOUNDS;
+        Duration::from_millis(
+            self.log_flush_ms
+                .unwrap_or(config_store::DEFAULT_LOG_FLUSH_MS)
+                .clamp(min, max),
+        )
+    }
+}
+
+const QUIET_HOURS_DEFERRED_REASON: &str = "Deferred: quiet hours";
+
+/// 시작과 끝이 같으면 빈 구간으로 봅니다. 끝이 시작보다 이르면 자정을 넘는 구간입니다.
+fn is_within_quiet_hours(window: (NaiveTime, NaiveTime), now: NaiveTime) -> bool {
+    let (start, end) = window;
+    if start <= end {
+        start <= now && now < end
+    } else {
+        now >= start || now < end
+    }
+}
+
+/// 조용한 시간 구간 안이면 구간이 끝날 때까지 남은 시간을, 아니면 None을 돌려줍니다.
+fn quiet_hours_remaining(window: (NaiveTime, NaiveTime), now: NaiveTime) -> Option<Duration> {
+    if !is_within_quiet_hours(window, now) {
+        return None;
+    }
+
+    let mut remaining = window.1.signed_duration_since(now);
+    if remaining <= chrono::Duration::zero() {
+        remaining += chrono::Duration::days(1);
+    }
+    remaining.to_std().ok()
+}
+
+async fn current_quiet_hours_remaining(state: &AppState) -> Option<Duration> {
+    let window = state.runtime_config.read().await.settings.quiet_hours?;
+    quiet_hours_remaining(window, chrono::Local::now().time())
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -591,6 +906,54 @@ struct RuntimeSyncTask {
     verify_after_copy: bool,
     #[serde(default)]
     exclusion_sets: Vec<String>,
+    /// 같은 그룹의 태스크는 동시에 실행되지 않음. 비어 있으면 타겟 볼륨 기준으로 계산
+    #[serde(default)]
+    resource_group: Option<String>,
+    /// watch 이벤트가 계속 들어와도 첫 이벤트 후 이 시간(초) 안에 동기화를 트리거. 비어 있으면 기본값
+    #[serde(default)]
+    max_coalesce_secs: Option<u64>,
+    /// 복사 단계 최대 실행 시간 (초). 실행 옵션에 제한이 없으면 이 값을 씀
+    #[serde(default)]
+    max_duration_secs: Option<u64>,
+    /// 이 태스크의 동기화 결과 중 어떤 것을 알림으로 보낼지
+    #[serde(default)]
+    notifications: TaskNotificationPrefs,
+    /// false면 설정은 유지한 채 watch·예약 실행·초기 동기화에서 제외
+    #[serde(default = "default_task_enabled")]
+    enabled: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SyncNotificationKind {
+    Conflict,
+    Completed,
+    Failed,
+}
+
+/// 동기화 결과에 대해 보낼 알림을 고릅니다. 전역 알림이 꺼져 있으면 아무것도 보내지 않습니다.
+/// `failed`는 중단·실패로 끝난 경우이며, 취소된 동기화는 호출하지 않습니다.
+fn select_sync_notifications(
+    notifications_enabled: bool,
+    prefs: &TaskNotificationPrefs,
+    failed: bool,
+    conflict_count: usize,
+) -> Vec<SyncNotificationKind> {
+    if !notifications_enabled {
+        return Vec::new();
+    }
+
+    let mut kinds = Vec::new();
+    if failed {
+        if prefs.on_error {
+            kinds.push(SyncNotificationKind::Failed);
+        }
+    } else if prefs.on_complete {
+        kinds.push(SyncNotificationKind::Completed);
+    }
+    if conflict_count > 0 && prefs.on_conflict {
+        kinds.push(SyncNotificationKind::Conflict);
+    }
+    kinds
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -602,6 +965,35 @@ struct RuntimeExclusionSet {
     patterns: Vec<String>,
 }
 
+/// 제외 패턴 하나의 검사 결과. 순서는 입력 패턴 순서와 같음
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ExclusionPatternValidity {
+    pattern: String,
+    valid: bool,
+    error: Option<String>,
+}
+
+fn validate_exclusion_set_internal(set: &RuntimeExclusionSet) -> Vec<ExclusionPatternValidity> {
+    set.patterns
+        .iter()
+        .map(
+            |pattern| match input_validation::validate_exclude_pattern(pattern) {
+                Ok(()) => ExclusionPatternValidity {
+                    pattern: pattern.clone(),
+                    valid: true,
+                    error: None,
+                },
+                Err(error) => ExclusionPatternValidity {
+                    pattern: pattern.clone(),
+                    valid: false,
+                    error: Some(error.to_string()),
+                },
+            },
+        )
+        .collect()
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, schemars::JsonSchema, Default)]
 #[serde(rename_all = "camelCase")]
 struct RuntimeState {
@@ -733,6 +1125,15 @@ struct RuntimeAutoUnmountRequestEvent {
     reason: String,
 }
 
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SyncSourceDisconnectedEvent {
+    task_id: String,
+    source: String,
+    files_copied: u64,
+    bytes_copied: u64,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum TaskOperationKind {
     Sync,
@@ -867,6 +1268,8 @@ enum ConflictItemStatus {
     ForceCopied,
     SafeCopied,
     Skipped,
+    /// 검토 후 타겟을 그대로 유지하기로 명시적으로 결정한 항목 (단순 skip과 구분)
+    KeptTarget,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -889,6 +1292,10 @@ struct TargetNewerConflictItem {
     status: ConflictItemStatus,
     note: Option<String>,
     resolved_at_unix_ms: Option<i64>,
+    /// RenameThenCopy가 타겟을 백업 이름으로 옮긴 뒤 복사를 끝내지 못한 경우의 백업 경로.
+    /// 재시도 시 두 번째 백업을 만들지 않고 이 백업을 그대로 사용함
+    #[serde(default)]
+    renamed_backup_path: Option<String>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -902,6 +1309,12 @@ struct ConflictReviewSession {
     origin: ConflictSessionOrigin,
     created_at_unix_ms: i64,
     items: Vec<TargetNewerConflictItem>,
+    /// idempotency key별로 이미 적용된 resolve_conflict_items 결과 (재시도 시 그대로 반환)
+    #[serde(default)]
+    applied_resolutions: HashMap<String, ConflictResolutionResult>,
+    /// 사용자가 붙인 세션 이름 (예: "Camera A 오전 촬영")
+    #[serde(default)]
+    label: Option<String>,
 }
 
 #[derive(Debug, Clone, serde::Serialize)]
@@ -914,9 +1327,11 @@ struct ConflictSessionSummary {
     target_root: String,
     origin: ConflictSessionOrigin,
     created_at_unix_ms: i64,
+    label: Option<String>,
     total_count: usize,
     pending_count: usize,
     resolved_count: usize,
+    kept_target_count: usize,
 }
 
 #[derive(Debug, Clone, serde::Serialize)]
@@ -929,9 +1344,11 @@ struct ConflictSessionDetail {
     target_root: String,
     origin: ConflictSessionOrigin,
     created_at_unix_ms: i64,
+    label: Option<String>,
     total_count: usize,
     pending_count: usize,
     resolved_count: usize,
+    kept_target_count: usize,
     items: Vec<TargetNewerConflictItem>,
 }
 
@@ -960,6 +1377,7 @@ enum ConflictResolutionAction {
     ForceCopy,
     RenameThenCopy,
     Skip,
+    KeepTarget,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -969,14 +1387,14 @@ struct ConflictResolutionRequest {
     action: ConflictResolutionAction,
 }
 
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct ConflictResolutionFailure {
     item_id: String,
     message: String,
 }
 
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct ConflictResolutionResult {
     session_id: String,
@@ -986,6 +1404,17 @@ struct ConflictResolutionResult {
     failures: Vec<ConflictResolutionFailure>,
 }
 
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ConflictItemRefreshResult {
+    item_id: String,
+    changed: bool,
+    source_changed: bool,
+    target_changed: bool,
+    source: ConflictFileInfo,
+    target: ConflictFileInfo,
+}
+
 #[derive(Debug, Clone, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 struct CloseConflictReviewSessionResult {
@@ -1006,6 +1435,191 @@ struct SyncExecutionResult {
     recurring_history_detail_entries: Vec<RecurringScheduleHistoryDetailEntry>,
 }
 
+/// Per-run engine options that callers may pass alongside the task configuration.
+/// They are not persisted on the task, so they only apply to manual runs (`start_sync`,
+/// `sync_dry_run` and the orphan/export commands); watch, scheduled and MCP runs use the
+/// defaults. A reused Dry Run keeps the options it was run with.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SyncRunOptions {
+    #[serde(default)]
+    modified_after: Option<i64>,
+    #[serde(default)]
+    modified_before: Option<i64>,
+    #[serde(default)]
+    compress: bool,
+    #[serde(default)]
+    compress_extensions: Vec<String>,
+    #[serde(default)]
+    update_existing_only: bool,
+    #[serde(default)]
+    reconcile_case_drift: bool,
+    /// 검증 샘플링 비율 (0.0–1.0). 미지정이면 모두 검증합니다.
+    #[serde(default)]
+    verify_sample_rate: Option<f64>,
+    #[serde(default)]
+    respect_readonly_target: bool,
+    /// 타겟 전체 경로 길이 상한 (바이트). 초과하는 파일은 Dry Run에서 표시되고 동기화에서 건너뜁니다.
+    #[serde(default)]
+    max_target_path_len: Option<usize>,
+    /// 0바이트 소스 파일을 비교 대상에서 제외 (카메라 placeholder 파일이 타겟을 덮어쓰지 않도록)
+    #[serde(default)]
+    skip_empty_files: bool,
+    /// 병렬 디렉터리 탐색 (jwalk). 대용량 트리에서 스캔 속도 향상
+    #[serde(default)]
+    parallel_scan: bool,
+    /// macOS 파일 플래그 (숨김/잠금 등 BSD flags)를 타겟에 복사
+    #[serde(default)]
+    preserve_flags: bool,
+    /// 첫 파일 오류에서 복사를 중단 (기본은 오류를 모으고 계속 진행)
+    #[serde(default)]
+    stop_on_first_error: bool,
+    /// 복사 단계 최대 실행 시간 (초). 초과하면 파일 경계에서 멈추고 남은 분량을 보고합니다.
+    #[serde(default)]
+    max_duration_secs: Option<u64>,
+    /// 소스 구조를 그대로 복제하지 않고 파일별 타겟 위치를 다시 정함 (예: 수정일 기준 YYYY/MM/DD)
+    #[serde(default)]
+    target_layout: Option<TargetLayout>,
+    /// 소스 최상위에서 이 이름의 폴더만 동기화 (예: DCIM, CLIPS). 타겟 탐색·orphan 목록도 같은 폴더로 한정
+    #[serde(default)]
+    source_subdirs: Option<Vec<String>>,
+    /// `._name` AppleDouble 파일을 따로 복사하지 않고 `name`의 확장 속성으로 합침 (macOS)
+    #[serde(default)]
+    merge_appledouble: bool,
+    /// 체크섬 모드에서 같은 실행 안에 이미 복사된 파일과 내용이 같은 파일을 하드링크로 연결
+    #[serde(default)]
+    dedup_identical: bool,
+    /// 체크섬 모드에서 수정 시각은 같은데 내용이 다른 파일을 덮어쓰지 않고 충돌로 보고
+    #[serde(default)]
+    flag_equal_mtime_content_diff: bool,
+    /// 청크를 쓸 때마다 타겟에서 다시 읽어 비교 (verify_after_copy와 별개, 느리지만 불량 매체를 즉시 감지)
+    #[serde(default)]
+    read_after_write: bool,
+    /// 타겟이 네트워크 마운트이면 권한/수정 시각 보존을 건너뜀 (서버가 관리, 파일마다 실패/지연 방지)
+    #[serde(default)]
+    skip_metadata_on_network: bool,
+    /// 큰 파일 복사가 중단되면 임시 파일을 남겨 두고, 다음 실행에서 소스가 그대로면 이어서 복사
+    #[serde(default)]
+    resume_partial_copies: bool,
+    /// 소스가 비어 보여도(반쯤 마운트된 카드 등) orphan 목록/미러 삭제 미리보기를 허용
+    #[serde(default)]
+    allow_empty_source: bool,
+    /// 체크섬 모드에서 마지막 성공 동기화 스냅샷과 크기/수정 시각이 같은 파일은 해시를 건너뜀.
+    /// 스냅샷이 없으면 전체 해시로 진행하고, 깨끗이 끝난 실행마다 스냅샷을 갱신
+    #[serde(default)]
+    checksum_changed_only: bool,
+    /// `checksum_changed_only`여도 저장된 스냅샷을 믿지 않고 모든 파일을 다시 해시 (비트 부패 의심 시).
+    /// 깨끗이 끝난 동기화는 새로 계산한 값으로 스냅샷을 갱신
+    #[serde(default)]
+    bypass_checksum_cache: bool,
+    /// Dry Run 전용: 타겟 orphan 목록도 함께 수집 (타겟을 한 번 더 탐색함)
+    #[serde(default)]
+    include_orphans: bool,
+    /// Dry Run 전용: 복사하지 않는 파일마다 이유(제외, 최신, 타겟이 더 새로움 등)를 수집
+    #[serde(default)]
+    collect_skip_reasons: bool,
+    /// `/`나 시스템 볼륨을 타겟으로 하는 동기화를 허용 (기본은 ERR_SYSTEM_TARGET_BLOCKED로 거부)
+    #[serde(default)]
+    allow_system_target: bool,
+}
+
+impl SyncRunOptions {
+    fn validate(&self) -> Result<(), String> {
+        if let (Some(after), Some(before)) = (self.modified_after, self.modified_before) {
+            if after > before {
+                return Err("modifiedAfter must not be later than modifiedBefore".to_string());
+            }
+        }
+        if self.compress
+            && self
+                .compress_extensions
+                .iter()
+                .all(|extension| extension.trim_start_matches('.').trim().is_empty())
+        {
+            return Err("compressExtensions must list at least one extension".to_string());
+        }
+        if let Some(rate) = self.verify_sample_rate {
+            if !(0.0..=1.0).contains(&rate) {
+                return Err("verifySampleRate must be between 0.0 and 1.0".to_string());
+            }
+        }
+        if self.max_target_path_len == Some(0) {
+            return Err("maxTargetPathLen must be greater than 0".to_string());
+        }
+        if self.max_duration_secs == Some(0) {
+            return Err("maxDurationSecs must be greater than 0".to_string());
+        }
+        if let Some(layout) = &self.target_layout {
+            layout.validate()?;
+        }
+        if let Some(subdirs) = &self.source_subdirs {
+            sync_engine::types::validate_source_subdirs(subdirs)?;
+        }
+        Ok(())
+    }
+
+    /// orphan 목록·삭제가 한정되는 타겟 최상위 폴더. 타겟 레이아웃이 있으면 타겟 전체가 대상입니다.
+    fn orphan_scope(&self) -> Option<&[String]> {
+        SyncEngine::target_subdirs(self.source_subdirs.as_deref(), self.target_layout.as_ref())
+    }
+
+    fn apply_to(&self, options: &mut SyncOptions) {
+        options.modified_after = self.modified_after;
+        options.modified_before = self.modified_before;
+        options.compress = self.compress;
+        options.compress_extensions = self.compress_extensions.clone();
+        options.update_existing_only = self.update_existing_only;
+        options.reconcile_case_drift = self.reconcile_case_drift;
+        if let Some(rate) = self.verify_sample_rate {
+            options.verify_sample_rate = rate;
+        }
+        options.respect_readonly_target = self.respect_readonly_target;
+        options.max_target_path_len = self.max_target_path_len;
+        options.skip_empty_files = self.skip_empty_files;
+        options.parallel_scan = self.parallel_scan;
+        options.preserve_flags = self.preserve_flags;
+        options.stop_on_first_error = self.stop_on_first_error;
+        options.max_duration = self.max_duration_secs.map(Duration::from_secs);
+        options.target_layout = self.target_layout.clone();
+        options.source_subdirs = self.source_subdirs.clone();
+        options.merge_appledouble = self.merge_appledouble;
+        options.dedup_identical = self.dedup_identical;
+        options.flag_equal_mtime_content_diff = self.flag_equal_mtime_content_diff;
+        options.read_after_write = self.read_after_write;
+        options.skip_metadata_on_network = self.skip_metadata_on_network;
+        options.resume_partial_copies = self.resume_partial_copies;
+        options.collect_skip_reasons = self.collect_skip_reasons;
+    }
+
+    /// 스냅샷 경로는 타겟 레이아웃 없이 소스와 같은 상대 경로일 때만 맞습니다.
+    fn uses_sync_snapshot(&self, checksum_mode: bool) -> bool {
+        checksum_mode && self.checksum_changed_only && self.target_layout.is_none()
+    }
+
+    /// 비교에서 저장된 스냅샷을 믿어도 되는지. `bypass_checksum_cache`면 읽지 않고 갱신만 합니다.
+    fn trusts_sync_snapshot(&self, checksum_mode: bool) -> bool {
+        self.uses_sync_snapshot(checksum_mode) && !self.bypass_checksum_cache
+    }
+}
+
+fn build_sync_options(
+    checksum_mode: bool,
+    verify_after_copy: bool,
+    exclude_patterns: Vec<String>,
+    run_options: &SyncRunOptions,
+) -> SyncOptions {
+    let mut options = SyncOptions {
+        checksum_mode,
+        preserve_permissions: true,
+        preserve_times: true,
+        verify_after_copy,
+        exclude_patterns,
+        ..SyncOptions::default()
+    };
+    run_options.apply_to(&mut options);
+    options
+}
+
 #[derive(Debug, Clone)]
 struct SyncExecutionFailure {
     error_detail: String,
@@ -1028,6 +1642,7 @@ struct ValidatedRuntimeTask {
     source_key: String,
     target_key: String,
     watch_mode: bool,
+    resource_group: String,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
@@ -1088,15 +1703,48 @@ struct ConflictPreviewPayload {
     target_text: Option<String>,
     source_truncated: bool,
     target_truncated: bool,
+    /// 소스 파일 앞부분의 매직 바이트로 판별한 MIME (판별 불가면 None)
+    mime: Option<String>,
+}
+
+/// 충돌 항목의 변경 구간. 줄 번호는 1부터 시작합니다.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ConflictTextDiffHunk {
+    source_start: usize,
+    source_lines: Vec<String>,
+    target_start: usize,
+    target_lines: Vec<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ConflictTextDiffPayload {
+    hunks: Vec<ConflictTextDiffHunk>,
+    /// `max_lines`에 걸려 이후 구간을 생략했는지 여부
+    hunks_truncated: bool,
+    source_truncated: bool,
+    target_truncated: bool,
 }
 
+const CONFLICT_PREVIEW_DEFAULT_BYTES: usize = 64 * 1024;
+const CONFLICT_PREVIEW_MAX_BYTES: usize = 512 * 1024;
+
 const RUNTIME_SYNC_MAX_CONCURRENCY: usize = 2;
+const MAX_WATCHERS_UPPER_BOUND: u32 = 1024;
+const WATCHER_LIMIT_REACHED_REASON: &str = "Watcher limit reached";
+/// 복사 청크(64KB) 하나가 1초 이상 걸리지 않도록 하는 최소 전역 대역폭
+const MIN_GLOBAL_MAX_BYTES_PER_SEC: u64 = 64 * 1024;
 const RUNTIME_DOWNSTREAM_SETTLE_WINDOW: Duration = Duration::from_millis(500);
 
 fn default_verify_after_copy() -> bool {
     true
 }
 
+fn default_task_enabled() -> bool {
+    true
+}
+
 fn default_data_unit_system() -> DataUnitSystem {
     DataUnitSystem::Binary
 }
@@ -1175,6 +1823,11 @@ fn to_runtime_task_record(task: &SyncTaskRecord) -> RuntimeSyncTask {
         auto_unmount: task.auto_unmount && is_uuid_source(&task.source, task.source_type.clone()),
         verify_after_copy: task.verify_after_copy,
         exclusion_sets: task.exclusion_sets.clone(),
+        resource_group: task.resource_group.clone(),
+        max_coalesce_secs: task.max_coalesce_secs,
+        max_duration_secs: task.max_duration_secs,
+        notifications: task.notifications,
+        enabled: task.enabled,
     }
 }
 
@@ -1189,9 +1842,28 @@ fn to_runtime_exclusion_set_record(set: &ExclusionSetRecord) -> RuntimeExclusion
 fn to_runtime_settings_record(settings: &AppSettings) -> RuntimeSettings {
     RuntimeSettings {
         data_unit_system: settings.data_unit_system,
+        verbose_file_logging: settings.verbose_file_logging,
+        max_watchers: Some(settings.max_watchers as usize),
+        global_max_bytes_per_sec: settings.global_max_bytes_per_sec,
+        bootstrap_initial_sync: settings.bootstrap_initial_sync,
+        progress_emit_ms: Some(settings.progress_emit_ms),
+        log_flush_ms: Some(settings.log_flush_ms),
+        quiet_hours: settings.quiet_hours,
+        notifications_enabled: settings.notifications,
+        watch_noise_names: settings.watch_noise_names.clone(),
+        orphan_min_source_fraction: Some(settings.orphan_min_source_fraction),
+        capacity_sample_interval_secs: Some(settings.capacity_sample_interval_secs),
+        license_http_timeout_secs: Some(settings.license_http_timeout_secs),
+        license_http_proxy: settings.license_http_proxy.clone(),
     }
 }
 
+/// runtime_set_config 페이로드에는 verbose_file_logging이 없으므로 현재 값을 유지합니다.
+fn replace_runtime_config(config: &mut RuntimeConfigPayload, mut payload: RuntimeConfigPayload) {
+    payload.settings.verbose_file_logging = config.settings.verbose_file_logging;
+    *config = payload;
+}
+
 fn validate_settings_record(settings: &AppSettings) -> Result<(), String> {
     if settings.language.trim().is_empty() {
         return Err("Settings.language cannot be empty".to_string());
@@ -1201,23 +1873,75 @@ fn validate_settings_record(settings: &AppSettings) -> Result<(), String> {
         return Err("Settings.maxLogLines must be between 100 and 100000".to_string());
     }
 
-    Ok(())
-}
-
-fn validate_sync_task_records(tasks: &[SyncTaskRecord]) -> Result<(), String> {
-    for task in tasks {
-        if task.name.trim().is_empty() {
-            return Err("Task name cannot be empty".to_string());
-        }
+    if !(1..=MAX_WATCHERS_UPPER_BOUND).contains(&settings.max_watchers) {
+        return Err(format!(
+            "Settings.maxWatchers must be between 1 and {MAX_WATCHERS_UPPER_BOUND}"
+        ));
     }
 
-    let runtime_tasks: Vec<RuntimeSyncTask> = tasks.iter().map(to_runtime_task_record).collect();
-    validate_runtime_tasks(&runtime_tasks)
-}
+    if settings.global_max_bytes_per_sec != 0
+        && settings.global_max_bytes_per_sec < MIN_GLOBAL_MAX_BYTES_PER_SEC
+    {
+        return Err(format!(
+            "Settings.globalMaxBytesPerSec must be 0 (unlimited) or at least {MIN_GLOBAL_MAX_BYTES_PER_SEC}"
+        ));
+    }
 
-fn config_store_error_to_string(error: config_store::ConfigStoreError) -> String {
-    error.to_tauri_error_string()
-}
+    let (min, max) = PROGRESS_EMIT_MS_BOUNDS;
+    if !(min..=max).contains(&settings.progress_emit_ms) {
+        return Err(format!(
+            "Settings.progressEmitMs must be between {min} and {max}"
+        ));
+    }
+
+    let (min, max) = LOG_FLUSH_MS_BOUNDS;
+    if !(min..=max).contains(&settings.log_flush_ms) {
+        return Err(format!(
+            "Settings.logFlushMs must be between {min} and {max}"
+        ));
+    }
+
+    if !(0.0..=1.0).contains(&settings.orphan_min_source_fraction) {
+        return Err("Settings.orphanMinSourceFraction must be between 0 and 1".to_string());
+    }
+
+    if settings.capacity_sample_interval_secs < capacity_history::MIN_CAPACITY_SAMPLE_INTERVAL_SECS
+    {
+        return Err(format!(
+            "Settings.capacitySampleIntervalSecs must be at least {}",
+            capacity_history::MIN_CAPACITY_SAMPLE_INTERVAL_SECS
+        ));
+    }
+
+    if settings.license_http_timeout_secs < license_validation::MIN_LICENSE_HTTP_TIMEOUT_SECS {
+        return Err(format!(
+            "Settings.licenseHttpTimeoutSecs must be at least {}",
+            license_validation::MIN_LICENSE_HTTP_TIMEOUT_SECS
+        ));
+    }
+
+    if let Some(proxy) = settings.license_http_proxy.as_deref() {
+        reqwest::Url::parse(proxy)
+            .map_err(|error| format!("Settings.licenseHttpProxy is not a valid URL: {error}"))?;
+    }
+
+    Ok(())
+}
+
+fn validate_sync_task_records(tasks: &[SyncTaskRecord]) -> Result<(), String> {
+    for task in tasks {
+        if task.name.trim().is_empty() {
+            return Err("Task name cannot be empty".to_string());
+        }
+    }
+
+    let runtime_tasks: Vec<RuntimeSyncTask> = tasks.iter().map(to_runtime_task_record).collect();
+    validate_runtime_tasks(&runtime_tasks)
+}
+
+fn config_store_error_to_string(error: config_store::ConfigStoreError) -> String {
+    error.to_tauri_error_string()
+}
 
 async fn current_settings_snapshot(
     app: &tauri::AppHandle,
@@ -1387,6 +2111,7 @@ pub(crate) fn progress_phase_to_log_category(
 ) -> Option<LogCategory> {
     match phase {
         sync_engine::types::SyncProgressPhase::Copying => Some(LogCategory::FileCopied),
+        sync_engine::types::SyncProgressPhase::Deleting => Some(LogCategory::FileDeleted),
         _ => None,
     }
 }
@@ -1699,7 +2424,9 @@ fn handle_live_sync_progress(
     progress: &sync_engine::types::SyncProgress,
 ) {
     if let Some(current) = &progress.current_file {
-        if let Some(category) = progress_phase_to_log_category(&progress.phase) {
+        if let Some(category) = progress_phase_to_log_category(&progress.phase)
+            .filter(|category| should_log_file_entry(progress_state.verbose_file_logging, category))
+        {
             if progress_state.should_update_file(&category, current) {
                 let now = chrono::Utc::now().to_rfc3339();
                 let message = match &category {
@@ -1735,6 +2462,24 @@ fn handle_live_sync_progress(
     }
 
     let should_emit = progress_state.should_emit_progress();
+    if should_emit
+        && progress.phase == SyncProgressPhase::Copying
+        && progress.processed_files < progress.total_files
+        && progress_state.take_milestone(progress.processed_files)
+    {
+        log_manager.log_with_category(
+            "info",
+            &format!(
+                "Sync progress: {}/{} files, {} / {}",
+                format_number(progress.processed_files),
+                format_number(progress.total_files),
+                format_bytes(progress.processed_bytes),
+                format_bytes(progress.total_bytes)
+            ),
+            Some(task_id_for_log.to_string()),
+            LogCategory::Other,
+        );
+    }
     if should_emit || progress.processed_files == progress.total_files {
         let event = sync_progress_event(task_id_for_progress, progress_origin, progress);
         emit_sync_progress(app_for_progress, &event);
@@ -1754,6 +2499,22 @@ fn mcp_progress_from_dry_run(progress: &DryRunProgress) -> McpJobProgress {
     }
 }
 
+fn emit_sync_source_disconnected(
+    app: &tauri::AppHandle,
+    task_id: &str,
+    source: &str,
+    files_copied: u64,
+    bytes_copied: u64,
+) {
+    let event = SyncSourceDisconnectedEvent {
+        task_id: task_id.to_string(),
+        source: source.to_string(),
+        files_copied,
+        bytes_copied,
+    };
+    let _ = app.emit("sync-source-disconnected", &event);
+}
+
 fn emit_runtime_auto_unmount_request(
     app: &tauri::AppHandle,
     task_id: &str,
@@ -1917,12 +2678,17 @@ async fn build_dry_run_artifact(
             continue;
         }
 
+        let source_relative_path = diff
+            .source_path
+            .clone()
+            .unwrap_or_else(|| diff.path.clone());
         candidates.push(DryRunArtifactCandidate {
             relative_path: diff.path.clone(),
             source: capture_dry_run_candidate_path_snapshot(
-                &canonical_source_root.join(&diff.path),
+                &canonical_source_root.join(&source_relative_path),
             )
             .await?,
+            source_relative_path,
             target: capture_dry_run_candidate_path_snapshot(&target_snapshot_root.join(&diff.path))
                 .await?,
         });
@@ -1933,12 +2699,18 @@ async fn build_dry_run_artifact(
             continue;
         }
 
+        let source_relative_path = conflict
+            .source_path
+            .strip_prefix(&canonical_source_root)
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|_| conflict.path.clone());
         candidates.push(DryRunArtifactCandidate {
             relative_path: conflict.path.clone(),
             source: capture_dry_run_candidate_path_snapshot(
-                &canonical_source_root.join(&conflict.path),
+                &canonical_source_root.join(&source_relative_path),
             )
             .await?,
+            source_relative_path,
             target: capture_dry_run_candidate_path_snapshot(
                 &target_snapshot_root.join(&conflict.path),
             )
@@ -1953,6 +2725,7 @@ async fn build_dry_run_artifact(
             checksum_mode,
             exclude_patterns: exclude_patterns.to_vec(),
         },
+        run_options: SyncRunOptions::default(),
         source_root,
         target_root,
         result: result.clone(),
@@ -2000,7 +2773,7 @@ async fn validate_dry_run_artifact(
             return Err(SYNC_CANCELLED_BY_USER_ERROR.to_string());
         }
         let current_source = capture_dry_run_candidate_path_snapshot(
-            &canonical_source_root.join(&candidate.relative_path),
+            &canonical_source_root.join(&candidate.source_relative_path),
         )
         .await?;
         let current_target = capture_dry_run_candidate_path_snapshot(
@@ -2043,9 +2816,15 @@ fn to_conflict_summary(session: &ConflictReviewSession) -> ConflictSessionSummar
         target_root: session.target_root.clone(),
         origin: session.origin.clone(),
         created_at_unix_ms: session.created_at_unix_ms,
+        label: session.label.clone(),
         total_count,
         pending_count,
         resolved_count: total_count.saturating_sub(pending_count),
+        kept_target_count: session
+            .items
+            .iter()
+            .filter(|item| item.status == ConflictItemStatus::KeptTarget)
+            .count(),
     }
 }
 
@@ -2059,9 +2838,11 @@ fn to_conflict_detail(session: &ConflictReviewSession) -> ConflictSessionDetail
         target_root: summary.target_root,
         origin: summary.origin,
         created_at_unix_ms: summary.created_at_unix_ms,
+        label: summary.label,
         total_count: summary.total_count,
         pending_count: summary.pending_count,
         resolved_count: summary.resolved_count,
+        kept_target_count: summary.kept_target_count,
         items: session.items.clone(),
     }
 }
@@ -2109,6 +2890,7 @@ fn build_conflict_item(
         status: ConflictItemStatus::Pending,
         note: None,
         resolved_at_unix_ms: None,
+        renamed_backup_path: None,
     }
 }
 
@@ -2194,6 +2976,7 @@ fn conflict_resolution_success_log_details(
             ("success", "renamed target then copied source")
         }
         ConflictResolutionAction::Skip => ("warning", "skipped for this run"),
+        ConflictResolutionAction::KeepTarget => ("info", "kept target after review"),
     }
 }
 
@@ -2202,6 +2985,7 @@ fn conflict_resolution_failure_action_text(action: &ConflictResolutionAction) ->
         ConflictResolutionAction::ForceCopy => "force copy source over target",
         ConflictResolutionAction::RenameThenCopy => "rename target then copy source",
         ConflictResolutionAction::Skip => "skip for this run",
+        ConflictResolutionAction::KeepTarget => "keep target",
     }
 }
 
@@ -2362,6 +3146,7 @@ async fn reject_symlink_destination_under_root(root: &Path, target: &Path) -> Re
     Ok(())
 }
 
+/// `target`이 `source` 이름에 `.gz`를 붙인 압축 사본이면 원본을 다시 압축해서 씁니다.
 async fn copy_file_preserve_under_root(
     source: &Path,
     target_root: &Path,
@@ -2388,9 +3173,27 @@ async fn copy_file_preserve_under_root(
         .open(target)
         .await
         .map_err(|e| format!("Failed to open target file: {e}"))?;
-    tokio::io::copy(&mut source_file, &mut target_file)
+    if is_compressed_copy_path(source, target) {
+        let mut source_file = source_file.into_std().await;
+        let target_file = target_file.into_std().await;
+        tokio::task::spawn_blocking(move || -> std::io::Result<()> {
+            use std::io::Write;
+
+            let mut encoder = flate2::write::GzEncoder::new(
+                std::io::BufWriter::new(target_file),
+                flate2::Compression::default(),
+            );
+            std::io::copy(&mut source_file, &mut encoder)?;
+            encoder.finish()?.flush()
+        })
         .await
+        .map_err(|e| format!("Failed to copy file: {e}"))?
         .map_err(|e| format!("Failed to copy file: {e}"))?;
+    } else {
+        tokio::io::copy(&mut source_file, &mut target_file)
+            .await
+            .map_err(|e| format!("Failed to copy file: {e}"))?;
+    }
 
     let meta = tokio::fs::metadata(source)
         .await
@@ -2406,6 +3209,15 @@ async fn copy_file_preserve_under_root(
     Ok(())
 }
 
+fn is_compressed_copy_path(source: &Path, target: &Path) -> bool {
+    let (Some(source_name), Some(target_name)) = (source.file_name(), target.file_name()) else {
+        return false;
+    };
+    let mut compressed_name = source_name.to_os_string();
+    compressed_name.push(".gz");
+    target_name == compressed_name
+}
+
 fn preview_kind_for_path(path: &str) -> &'static str {
     let ext = Path::new(path)
         .extension()
@@ -2440,6 +3252,77 @@ fn preview_kind_for_path(path: &str) -> &'static str {
     }
 }
 
+const CONTENT_SNIFF_BYTES: usize = 512;
+
+/// 파일 앞부분의 매직 바이트로 미리보기 종류와 MIME을 추정합니다.
+fn sniff_preview_content(head: &[u8]) -> Option<(&'static str, &'static str)> {
+    const MAGIC: &[(&[u8], &str, &str)] = &[
+        (b"\x89PNG\r\n\x1a\n", "image", "image/png"),
+        (b"\xff\xd8\xff", "image", "image/jpeg"),
+        (b"GIF87a", "image", "image/gif"),
+        (b"GIF89a", "image", "image/gif"),
+        (b"BM", "image", "image/bmp"),
+        (b"II*\x00", "image", "image/tiff"),
+        (b"MM\x00*", "image", "image/tiff"),
+        (b"\x1a\x45\xdf\xa3", "video", "video/x-matroska"),
+        (b"%PDF-", "document", "application/pdf"),
+        (b"PK\x03\x04", "other", "application/zip"),
+    ];
+
+    if let Some((_, kind, mime)) = MAGIC.iter().find(|(magic, _, _)| head.starts_with(magic)) {
+        return Some((kind, mime));
+    }
+    if head.len() >= 12 && head.starts_with(b"RIFF") {
+        match &head[8..12] {
+            b"WEBP" => return Some(("image", "image/webp")),
+            b"AVI " => return Some(("video", "video/x-msvideo")),
+            _ => {}
+        }
+    }
+    if head.len() >= 12 && &head[4..8] == b"ftyp" {
+        return match &head[8..12] {
+            b"heic" | b"heix" | b"mif1" => Some(("image", "image/heic")),
+            b"qt  " => Some(("video", "video/quicktime")),
+            _ => Some(("video", "video/mp4")),
+        };
+    }
+
+    // 잘린 마지막 UTF-8 문자는 허용. NUL이 있으면 바이너리로 취급
+    let looks_like_text = !head.is_empty()
+        && !head.contains(&0)
+        && match std::str::from_utf8(head) {
+            Ok(_) => true,
+            Err(error) => error.error_len().is_none(),
+        };
+    looks_like_text.then_some(("text", "text/plain"))
+}
+
+/// 확장자로 먼저 추정하고, 확장자가 없거나 모르는 경우·텍스트인데 내용이 바이너리인 경우 내용 판별로 덮어씁니다.
+fn resolve_preview_kind(path: &str, head: &[u8]) -> (&'static str, Option<&'static str>) {
+    let by_extension = preview_kind_for_path(path);
+    let sniffed = sniff_preview_content(head);
+    let mime = sniffed.map(|(_, mime)| mime);
+
+    match (by_extension, sniffed) {
+        ("other" | "text", Some((kind, _))) => (kind, mime),
+        ("text", None) if !head.is_empty() => ("other", mime),
+        (kind, _) => (kind, mime),
+    }
+}
+
+async fn read_preview_head(path: &str) -> Vec<u8> {
+    let Ok(file) = tokio::fs::File::open(path).await else {
+        return Vec::new();
+    };
+    let mut head = Vec::with_capacity(CONTENT_SNIFF_BYTES);
+    let _ = tokio::io::AsyncReadExt::read_to_end(
+        &mut tokio::io::AsyncReadExt::take(file, CONTENT_SNIFF_BYTES as u64),
+        &mut head,
+    )
+    .await;
+    head
+}
+
 fn allow_asset_preview_file(app: &tauri::AppHandle, path: &str) -> Result<(), String> {
     let path = Path::new(path);
     app.asset_protocol_scope()
@@ -2582,7 +3465,43 @@ enum ResolvePathWithUuidOutcome {
     },
 }
 
+/// 레거시 `[UUID:]` 토큰은 디스크 UUID를 먼저, 없으면 볼륨 UUID로 찾습니다.
+fn find_volume_for_uuid<'a>(
+    volumes: &'a [system_integration::VolumeInfo],
+    token_type: UuidTokenType,
+    uuid: &str,
+) -> Option<&'a system_integration::VolumeInfo> {
+    let by_disk = || {
+        volumes
+            .iter()
+            .find(|v| v.disk_uuid.as_deref() == Some(uuid))
+    };
+    let by_volume = || {
+        volumes
+            .iter()
+            .find(|v| v.volume_uuid.as_deref() == Some(uuid))
+    };
+    match token_type {
+        UuidTokenType::Disk => by_disk(),
+        UuidTokenType::Volume => by_volume(),
+        UuidTokenType::Legacy => by_disk().or_else(by_volume),
+    }
+}
+
 fn resolve_path_with_uuid_outcome(path_str: &str) -> Result<ResolvePathWithUuidOutcome, String> {
+    resolve_path_with_uuid_outcome_with(path_str, || {
+        DiskMonitor::new().list_volumes().map_err(|e| e.to_string())
+    })
+}
+
+/// 볼륨 목록은 UUID 토큰일 때만 `load_volumes`로 읽습니다.
+fn resolve_path_with_uuid_outcome_with<F>(
+    path_str: &str,
+    load_volumes: F,
+) -> Result<ResolvePathWithUuidOutcome, String>
+where
+    F: FnOnce() -> Result<Vec<system_integration::VolumeInfo>, String>,
+{
     if !has_uuid_source_prefix(path_str) {
         return Ok(ResolvePathWithUuidOutcome::Resolved(PathBuf::from(
             path_str,
@@ -2603,31 +3522,9 @@ fn resolve_path_with_uuid_outcome(path_str: &str) -> Result<ResolvePathWithUuidO
         normalized_sub_path
     );
 
-    let monitor = DiskMonitor::new();
-    let volumes = monitor.list_volumes().map_err(|e| e.to_string())?;
-
-    let volume = match parsed.token_type {
-        UuidTokenType::Disk => volumes
-            .iter()
-            .find(|v| v.disk_uuid.as_deref() == Some(parsed.uuid))
-            .cloned(),
-        UuidTokenType::Volume => volumes
-            .iter()
-            .find(|v| v.volume_uuid.as_deref() == Some(parsed.uuid))
-            .cloned(),
-        UuidTokenType::Legacy => volumes
-            .iter()
-            .find(|v| v.disk_uuid.as_deref() == Some(parsed.uuid))
-            .cloned()
-            .or_else(|| {
-                volumes
-                    .iter()
-                    .find(|v| v.volume_uuid.as_deref() == Some(parsed.uuid))
-                    .cloned()
-            }),
-    };
+    let volumes = load_volumes()?;
 
-    let Some(volume) = volume else {
+    let Some(volume) = find_volume_for_uuid(&volumes, parsed.token_type, parsed.uuid) else {
         return Ok(ResolvePathWithUuidOutcome::UuidNotMounted {
             token_type: parsed.token_type,
             uuid: parsed.uuid.to_string(),
@@ -3164,6 +4061,53 @@ fn resolve_runtime_exclude_patterns(
     resolved_patterns
 }
 
+/// 두 태스크의 실제 제외 패턴 비교 결과. 각 목록은 해당 태스크의 해석 순서를 따름
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TaskExcludeComparison {
+    only_a: Vec<String>,
+    only_b: Vec<String>,
+    common: Vec<String>,
+}
+
+/// 엔진의 루트 메타데이터 디렉터리 무시는 모든 태스크에 똑같이 적용되므로 비교 대상이 아님
+fn compare_task_excludes_internal(
+    task_id_a: &str,
+    task_id_b: &str,
+    config: &RuntimeConfigPayload,
+) -> Result<TaskExcludeComparison, String> {
+    let resolve = |task_id: &str| {
+        config
+            .tasks
+            .iter()
+            .find(|task| task.id == task_id)
+            .map(|task| resolve_runtime_exclude_patterns(task, &config.exclusion_sets))
+            .ok_or_else(|| format!("Task not found: {task_id}"))
+    };
+    let patterns_a = resolve(task_id_a)?;
+    let patterns_b = resolve(task_id_b)?;
+    let set_a: HashSet<&str> = patterns_a.iter().map(String::as_str).collect();
+    let set_b: HashSet<&str> = patterns_b.iter().map(String::as_str).collect();
+
+    Ok(TaskExcludeComparison {
+        only_a: patterns_a
+            .iter()
+            .filter(|pattern| !set_b.contains(pattern.as_str()))
+            .cloned()
+            .collect(),
+        only_b: patterns_b
+            .iter()
+            .filter(|pattern| !set_a.contains(pattern.as_str()))
+            .cloned()
+            .collect(),
+        common: patterns_a
+            .iter()
+            .filter(|pattern| set_b.contains(pattern.as_str()))
+            .cloned()
+            .collect(),
+    })
+}
+
 #[derive(Debug, Clone)]
 struct DueRecurringSchedule {
     task: SyncTaskRecord,
@@ -3357,6 +4301,7 @@ async fn run_due_recurring_schedule(
         None,
         None,
         None,
+        SyncRunOptions::default(),
     )
     .await;
 
@@ -3467,6 +4412,30 @@ fn compute_due_recurring_schedules(
     Ok((due, next_fire))
 }
 
+/// 조용한 시간이면 예약 동기화를 구간이 끝날 때까지 붙잡아 둡니다. 설정이 바뀌면 다시 확인합니다.
+async fn wait_for_quiet_hours_end(task_id: &str, app: &tauri::AppHandle, state: &AppState) {
+    let mut deferred = false;
+    while let Some(remaining) = current_quiet_hours_remaining(state).await {
+        if !deferred {
+            deferred = true;
+            eprintln!("[RecurringSchedule] {QUIET_HOURS_DEFERRED_REASON} (task {task_id})");
+            emit_runtime_sync_queue_state(
+                app,
+                task_id,
+                true,
+                Some(QUIET_HOURS_DEFERRED_REASON.to_string()),
+            );
+        }
+        tokio::select! {
+            _ = tokio::time::sleep(remaining) => {}
+            _ = state.runtime_dispatcher_wakeup.notified() => {}
+        }
+    }
+    if deferred {
+        emit_runtime_sync_queue_state(app, task_id, false, None);
+    }
+}
+
 async fn recurring_scheduler_loop(app: tauri::AppHandle, state: AppState) {
     let mut last_dispatched: HashMap<String, String> = HashMap::new();
 
@@ -3487,6 +4456,13 @@ async fn recurring_scheduler_loop(app: tauri::AppHandle, state: AppState) {
             }
         };
 
+        // 비활성화된 태스크의 예약 실행은 건너뜀
+        let disabled_task_ids = runtime_disabled_task_ids(&state.runtime_config.read().await.tasks);
+        let tasks: Vec<SyncTaskRecord> = tasks
+            .into_iter()
+            .filter(|task| !disabled_task_ids.contains(&task.id))
+            .collect();
+
         prune_recurring_dispatch_state(&tasks, &mut last_dispatched);
 
         let (due_schedules, next_fire) =
@@ -3508,6 +4484,7 @@ async fn recurring_scheduler_loop(app: tauri::AppHandle, state: AppState) {
                 let app_for_task = app.clone();
                 let state_for_task = state.clone();
                 tauri::async_runtime::spawn(async move {
+                    wait_for_quiet_hours_end(&due.task.id, &app_for_task, &state_for_task).await;
                     run_due_recurring_schedule(
                         due.task,
                         due.schedule,
@@ -3587,6 +4564,23 @@ fn ensure_non_overlapping_paths(source: &Path, target: &Path) -> Result<(), Stri
     Ok(())
 }
 
+/// `/`, `/System/Volumes/*`, 숨김 시스템 마운트 등을 타겟으로 하는 동기화·삭제를 막습니다.
+/// 사용자가 `allow_system_target`을 명시한 경우에만 허용합니다.
+fn ensure_target_not_system_volume(target: &Path, allow_system_target: bool) -> Result<(), String> {
+    if allow_system_target {
+        return Ok(());
+    }
+    match system_integration::classify_system_target(target) {
+        Some(kind) => Err(format!(
+            "{}:Target is a system volume ({:?}) and is blocked unless allowSystemTarget is set. target='{}'",
+            error_codes::ERR_SYSTEM_TARGET_BLOCKED,
+            kind,
+            target.display()
+        )),
+        None => Ok(()),
+    }
+}
+
 fn mounted_volume_root(path: &Path) -> Option<PathBuf> {
     let mut components = path.components();
 
@@ -3611,6 +4605,39 @@ fn mounted_volume_root(path: &Path) -> Option<PathBuf> {
     }
 }
 
+fn resolve_runtime_resource_group(explicit_group: Option<&str>, target_key: &str) -> String {
+    if let Some(group) = explicit_group
+        .map(str::trim)
+        .filter(|group| !group.is_empty())
+    {
+        return group.to_string();
+    }
+
+    // 명시 그룹이 없으면 타겟 볼륨 단위로 묶음. /Volumes 밖은 실제 장치(st_dev) 기준
+    let target = Path::new(target_key);
+    if let Some(root) = mounted_volume_root(target) {
+        return path_key_for_compare(&root);
+    }
+    target_device_id(target)
+        .map(|device| format!("dev:{device}"))
+        .unwrap_or_else(|| "/".to_string())
+}
+
+/// 아직 만들어지지 않은 타겟도 묶을 수 있도록 가장 가까운 기존 상위 경로의 st_dev를 씀
+fn target_device_id(path: &Path) -> Option<u64> {
+    use std::os::unix::fs::MetadataExt;
+
+    path.ancestors()
+        .find_map(|ancestor| std::fs::metadata(ancestor).ok())
+        .map(|metadata| metadata.dev())
+}
+
+fn runtime_task_resource_group(task: &RuntimeSyncTask) -> String {
+    let target_key = resolved_path_key(&task.target)
+        .unwrap_or_else(|_| path_key_for_compare(Path::new(&task.target)));
+    resolve_runtime_resource_group(task.resource_group.as_deref(), &target_key)
+}
+
 fn mounted_volume_roots() -> Result<HashSet<String>, String> {
     DiskMonitor::new()
         .list_volumes()
@@ -3874,6 +4901,10 @@ fn find_runtime_task_validation_issue(
         validated_tasks.push(ValidatedRuntimeTask {
             id: task.id.clone(),
             name: task.name.clone(),
+            resource_group: resolve_runtime_resource_group(
+                task.resource_group.as_deref(),
+                &target_key,
+            ),
             source_key,
             target_key,
             watch_mode: task.watch_mode,
@@ -4026,6 +5057,10 @@ fn build_validated_runtime_tasks(
         validated_tasks.push(ValidatedRuntimeTask {
             id: task.id.clone(),
             name: task.name.clone(),
+            resource_group: resolve_runtime_resource_group(
+                task.resource_group.as_deref(),
+                &target_key,
+            ),
             source_key,
             target_key,
             watch_mode: task.watch_mode,
@@ -4139,15 +5174,25 @@ fn validate_runtime_tasks(tasks: &[RuntimeSyncTask]) -> Result<(), String> {
 fn runtime_desired_watch_sources(tasks: &[RuntimeSyncTask]) -> HashMap<String, String> {
     tasks
         .iter()
-        .filter(|task| task.watch_mode)
+        .filter(|task| task.watch_mode && task.enabled)
         .map(|task| (task.id.clone(), task.source.clone()))
         .collect()
 }
 
+/// Watch task ids (in task order) beyond the first `max_watchers`; these stay unwatched.
+fn runtime_watch_tasks_over_limit(tasks: &[RuntimeSyncTask], max_watchers: usize) -> Vec<String> {
+    tasks
+        .iter()
+        .filter(|task| task.watch_mode && task.enabled)
+        .skip(max_watchers)
+        .map(|task| task.id.clone())
+        .collect()
+}
+
 fn runtime_watch_bootstrap_task_ids(tasks: &[RuntimeSyncTask]) -> Vec<String> {
     tasks
         .iter()
-        .filter(|task| task.watch_mode)
+        .filter(|task| task.watch_mode && task.enabled)
         .map(|task| task.id.clone())
         .collect()
 }
@@ -4202,10 +5247,55 @@ fn runtime_find_watch_task<'a>(
 ) -> Option<&'a RuntimeSyncTask> {
     tasks
         .iter()
-        .find(|task| task.id == task_id && task.watch_mode)
+        .find(|task| task.id == task_id && task.watch_mode && task.enabled)
+}
+
+fn runtime_disabled_task_ids(tasks: &[RuntimeSyncTask]) -> HashSet<String> {
+    tasks
+        .iter()
+        .filter(|task| !task.enabled)
+        .map(|task| task.id.clone())
+        .collect()
+}
+
+/// 런타임 설정에서 태스크의 enabled 값을 바꿉니다. 값이 실제로 바뀌었으면 true
+/// 설정 파일에 저장된 태스크도 함께 바꿔 설정을 다시 적용해도 유지되게 함
+async fn set_task_enabled_internal(
+    task_id: &str,
+    enabled: bool,
+    state: &AppState,
+) -> Result<bool, String> {
+    let mut config = state.runtime_config.write().await;
+    let task = config
+        .tasks
+        .iter_mut()
+        .find(|task| task.id == task_id)
+        .ok_or_else(|| format!("Task not found: {task_id}"))?;
+
+    let mut stored_tasks = state
+        .config_store
+        .load_tasks()
+        .map_err(config_store_error_to_string)?;
+    if let Some(stored) = stored_tasks.iter_mut().find(|stored| stored.id == task_id) {
+        if stored.enabled != enabled {
+            stored.enabled = enabled;
+            state
+                .config_store
+                .save_tasks(&stored_tasks)
+                .map_err(config_store_error_to_string)?;
+        }
+    }
+
+    let changed = task.enabled != enabled;
+    task.enabled = enabled;
+    Ok(changed)
 }
 
 async fn is_runtime_watch_task_active(task_id: &str, state: &AppState) -> bool {
+    if state.runtime_stopping_tasks.read().await.contains(task_id) {
+        return false;
+    }
+
     let managed = {
         let sources = state.runtime_watch_sources.read().await;
         sources.contains_key(task_id)
@@ -4229,6 +5319,7 @@ enum RuntimeSyncAcquireResult {
     Acquired,
     AlreadySyncing,
     CapacityReached,
+    ResourceGroupBusy,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -4236,20 +5327,35 @@ enum RuntimeSyncEnqueueResult {
     Enqueued,
     AlreadyQueued,
     DeferredWhileSyncing,
+    /// stop_watch_graceful이 감시를 멈추는 중이라 받지 않음
+    Stopping,
 }
 
-async fn acquire_runtime_sync_slot(task_id: &str, state: &AppState) -> RuntimeSyncAcquireResult {
+async fn acquire_runtime_sync_slot(
+    task_id: &str,
+    resource_group: &str,
+    state: &AppState,
+) -> RuntimeSyncAcquireResult {
     let mut syncing = state.syncing_tasks.write().await;
 
     if syncing.contains(task_id) {
         return RuntimeSyncAcquireResult::AlreadySyncing;
     }
 
+    let mut resource_groups = state.syncing_resource_groups.write().await;
+    if resource_groups
+        .get(resource_group)
+        .is_some_and(|holder| holder != task_id)
+    {
+        return RuntimeSyncAcquireResult::ResourceGroupBusy;
+    }
+
     if syncing.len() >= RUNTIME_SYNC_MAX_CONCURRENCY {
         return RuntimeSyncAcquireResult::CapacityReached;
     }
 
     syncing.insert(task_id.to_string());
+    resource_groups.insert(resource_group.to_string(), task_id.to_string());
     RuntimeSyncAcquireResult::Acquired
 }
 
@@ -4258,6 +5364,10 @@ async fn release_sync_slot(task_id: &str, state: &AppState) {
         let mut syncing = state.syncing_tasks.write().await;
         syncing.remove(task_id)
     };
+    {
+        let mut resource_groups = state.syncing_resource_groups.write().await;
+        resource_groups.retain(|_, holder| holder != task_id);
+    }
 
     if removed {
         state.runtime_dispatcher_wakeup.notify_waiters();
@@ -4335,6 +5445,11 @@ async fn remove_queued_runtime_watch_task(
         } else {
             let mut queue = state.runtime_sync_queue.write().await;
             queue.retain(|queued_task_id| queued_task_id != task_id);
+            state
+                .runtime_bootstrap_queued_tasks
+                .write()
+                .await
+                .remove(task_id);
             true
         }
     };
@@ -4438,6 +5553,10 @@ async fn enqueue_runtime_sync_task_internal(
     task_id: &str,
     state: &AppState,
 ) -> RuntimeSyncEnqueueResult {
+    if state.runtime_stopping_tasks.read().await.contains(task_id) {
+        return RuntimeSyncEnqueueResult::Stopping;
+    }
+
     {
         let syncing = state.syncing_tasks.read().await;
         if syncing.contains(task_id) {
@@ -4448,6 +5567,13 @@ async fn enqueue_runtime_sync_task_internal(
         }
     }
 
+    // 초기 동기화로 대기 중이던 태스크도 이제는 실제 변경 때문에 큐에 있는 것
+    state
+        .runtime_bootstrap_queued_tasks
+        .write()
+        .await
+        .remove(task_id);
+
     let mut queued_set = state.queued_sync_tasks.write().await;
     if !queued_set.insert(task_id.to_string()) {
         return RuntimeSyncEnqueueResult::AlreadyQueued;
@@ -4480,6 +5606,11 @@ async fn dequeue_runtime_sync_task(state: &AppState) -> Option<String> {
     let next = queue.pop_front();
     if let Some(task_id) = &next {
         queued_set.remove(task_id);
+        state
+            .runtime_bootstrap_queued_tasks
+            .write()
+            .await
+            .remove(task_id);
     }
     next
 }
@@ -4500,6 +5631,12 @@ async fn remove_runtime_sync_task_state(task_id: &str, state: &AppState) {
         settle.remove(task_id);
     }
 
+    state
+        .runtime_bootstrap_queued_tasks
+        .write()
+        .await
+        .remove(task_id);
+
     let mut queued = state.queued_sync_tasks.write().await;
     let mut queue = state.runtime_sync_queue.write().await;
     queued.remove(task_id);
@@ -4598,6 +5735,21 @@ fn schedule_runtime_watch_reconcile_for_volume_change(app: tauri::AppHandle, sta
     });
 }
 
+fn runtime_tasks_blocked_by_resource_group(
+    validated_tasks: &[ValidatedRuntimeTask],
+    syncing_resource_groups: &HashMap<String, String>,
+) -> HashSet<String> {
+    validated_tasks
+        .iter()
+        .filter(|task| {
+            syncing_resource_groups
+                .get(&task.resource_group)
+                .is_some_and(|holder| holder != &task.id)
+        })
+        .map(|task| task.id.clone())
+        .collect()
+}
+
 fn select_runtime_dispatch_candidate(
     queue: &VecDeque<String>,
     queued_set: &HashSet<String>,
@@ -4606,6 +5758,7 @@ fn select_runtime_dispatch_candidate(
     source_keys: &HashMap<String, String>,
     active_producers: &HashMap<String, RuntimeActiveProducer>,
     settle_until: &HashMap<String, Instant>,
+    resource_group_blocked: &HashSet<String>,
     now: Instant,
 ) -> RuntimeDispatchSelection {
     let mut next_deadline: Option<Instant> = None;
@@ -4615,6 +5768,10 @@ fn select_runtime_dispatch_candidate(
             continue;
         };
 
+        if resource_group_blocked.contains(task_id) {
+            continue;
+        }
+
         let blocked_by_upstream =
             watch_upstreams
                 .get(task_id)
@@ -4667,6 +5824,11 @@ async fn dequeue_runtime_sync_task_by_id(task_id: &str, state: &AppState) -> boo
         return false;
     }
 
+    state
+        .runtime_bootstrap_queued_tasks
+        .write()
+        .await
+        .remove(task_id);
     let mut queue = state.runtime_sync_queue.write().await;
     queue.retain(|queued_task_id| queued_task_id != task_id);
     true
@@ -4674,21 +5836,30 @@ async fn dequeue_runtime_sync_task_by_id(task_id: &str, state: &AppState) -> boo
 
 fn schedule_runtime_sync_dispatcher(app: tauri::AppHandle, state: AppState) {
     tauri::async_runtime::spawn(async move {
-        let should_start = {
+        let generation = {
             let mut running = state.runtime_dispatcher_running.lock().await;
             if *running {
-                false
+                None
             } else {
                 *running = true;
-                true
+                Some(state.runtime_dispatcher_generation.load(Ordering::SeqCst))
             }
         };
 
-        if !should_start {
+        let Some(generation) = generation else {
             return;
-        }
+        };
+        let is_current =
+            || state.runtime_dispatcher_generation.load(Ordering::SeqCst) == generation;
+
+        let mut quiet_hours_announced: HashSet<String> = HashSet::new();
 
         loop {
+            // force_restart_dispatcher로 교체된 루프는 새 디스패처와 중복 실행되지 않도록 즉시 종료
+            if !is_current() {
+                return;
+            }
+
             let runtime_config = {
                 let config = state.runtime_config.read().await;
                 config.clone()
@@ -4712,6 +5883,31 @@ fn schedule_runtime_sync_dispatcher(app: tauri::AppHandle, state: AppState) {
             if !has_queued {
                 break;
             }
+
+            // 큐에는 watch 트리거만 들어오므로 조용한 시간에는 전부 보류. 수동 동기화는 큐를 거치지 않음
+            let quiet_hours_left = runtime_config
+                .settings
+                .quiet_hours
+                .and_then(|window| quiet_hours_remaining(window, chrono::Local::now().time()));
+            if let Some(remaining) = quiet_hours_left {
+                for task_id in &queue {
+                    if quiet_hours_announced.insert(task_id.clone()) {
+                        emit_runtime_sync_queue_state(
+                            &app,
+                            task_id,
+                            true,
+                            Some(QUIET_HOURS_DEFERRED_REASON.to_string()),
+                        );
+                    }
+                }
+                tokio::select! {
+                    _ = tokio::time::sleep(remaining) => {}
+                    _ = state.runtime_dispatcher_wakeup.notified() => {}
+                }
+                continue;
+            }
+            quiet_hours_announced.clear();
+
             let queued_set = {
                 let queued_set = state.queued_sync_tasks.read().await;
                 queued_set.clone()
@@ -4729,6 +5925,10 @@ fn schedule_runtime_sync_dispatcher(app: tauri::AppHandle, state: AppState) {
                 let settle = state.runtime_chain_settle_until.read().await;
                 settle.clone()
             };
+            let resource_group_blocked = {
+                let resource_groups = state.syncing_resource_groups.read().await;
+                runtime_tasks_blocked_by_resource_group(&validated_tasks, &resource_groups)
+            };
 
             if should_wait_for_runtime_slot(has_queued, current_syncing) {
                 state.runtime_dispatcher_wakeup.notified().await;
@@ -4743,6 +5943,7 @@ fn schedule_runtime_sync_dispatcher(app: tauri::AppHandle, state: AppState) {
                 &source_keys,
                 &active_producers,
                 &settle_until,
+                &resource_group_blocked,
                 Instant::now(),
             );
 
@@ -4759,10 +5960,11 @@ fn schedule_runtime_sync_dispatcher(app: tauri::AppHandle, state: AppState) {
                 continue;
             };
 
-            if !dequeue_runtime_sync_task_by_id(&task_id, &state).await {
+            if !is_current() || !dequeue_runtime_sync_task_by_id(&task_id, &state).await {
                 continue;
             }
 
+            *state.runtime_dispatcher_last_dispatch_at.lock().unwrap() = Some(Utc::now());
             emit_runtime_sync_queue_state(&app, &task_id, false, None);
 
             let app_for_sync = app.clone();
@@ -4774,7 +5976,10 @@ fn schedule_runtime_sync_dispatcher(app: tauri::AppHandle, state: AppState) {
 
         {
             let mut running = state.runtime_dispatcher_running.lock().await;
-            *running = false;
+            if !is_current() {
+                return;
+            }
+            *running = false;
         }
 
         let has_queued = {
@@ -4796,6 +6001,45 @@ fn should_reschedule_runtime_dispatcher(has_queued: bool) -> bool {
     has_queued
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DispatcherState {
+    running: bool,
+    queue_length: usize,
+    syncing_count: usize,
+    last_dispatch_at: Option<chrono::DateTime<Utc>>,
+}
+
+async fn get_dispatcher_state_internal(state: &AppState) -> DispatcherState {
+    let running = *state.runtime_dispatcher_running.lock().await;
+    let queue_length = state.runtime_sync_queue.read().await.len();
+    let syncing_count = state.syncing_tasks.read().await.len();
+    let last_dispatch_at = *state.runtime_dispatcher_last_dispatch_at.lock().unwrap();
+
+    DispatcherState {
+        running,
+        queue_length,
+        syncing_count,
+        last_dispatch_at,
+    }
+}
+
+/// 멈춘 디스패처를 버리고 running 플래그를 초기화합니다. 큐에 작업이 있어 새 디스패처를 띄워야 하면 true.
+async fn force_reset_runtime_dispatcher(state: &AppState) -> bool {
+    {
+        let mut running = state.runtime_dispatcher_running.lock().await;
+        // 세대를 올려 기존 루프가 다음 반복에서 빠져나가게 하고, 대기 중이면 깨움
+        state
+            .runtime_dispatcher_generation
+            .fetch_add(1, Ordering::SeqCst);
+        *running = false;
+    }
+    state.runtime_dispatcher_wakeup.notify_waiters();
+
+    let has_queued = !state.runtime_sync_queue.read().await.is_empty();
+    should_reschedule_runtime_dispatcher(has_queued)
+}
+
 #[cfg(test)]
 mod runtime_dispatcher_tests {
     use super::{
@@ -4852,6 +6096,75 @@ async fn runtime_get_state_internal(state: &AppState) -> RuntimeState {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+enum TaskQueueStatus {
+    Queued,
+    Running,
+    NotQueued,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TaskQueuePosition {
+    task_id: String,
+    status: TaskQueueStatus,
+    /// 0부터 시작하는 큐 인덱스 (Queued일 때만)
+    position: Option<usize>,
+    tasks_ahead: Option<usize>,
+    /// 앞선 태스크들의 마지막 소요 시간 합을 동시 실행 슬롯 수로 나눈 대략적인 시작 예상 시간.
+    /// 앞선 태스크 중 기록이 없는 것이 있으면 None.
+    eta_to_start_ms: Option<u64>,
+}
+
+/// 런타임 큐는 FIFO이므로 큐 인덱스가 곧 디스패치 순서임 (리소스 그룹/settle 대기로 일시적으로 밀릴 수 있음)
+fn compute_task_queue_position(
+    task_id: &str,
+    queue: &VecDeque<String>,
+    syncing_tasks: &HashSet<String>,
+    last_sync_durations: &HashMap<String, Duration>,
+) -> TaskQueuePosition {
+    let Some(position) = queue.iter().position(|queued| queued == task_id) else {
+        let status = if syncing_tasks.contains(task_id) {
+            TaskQueueStatus::Running
+        } else {
+            TaskQueueStatus::NotQueued
+        };
+        return TaskQueuePosition {
+            task_id: task_id.to_string(),
+            status,
+            position: None,
+            tasks_ahead: None,
+            eta_to_start_ms: None,
+        };
+    };
+
+    let eta_to_start_ms = queue
+        .iter()
+        .take(position)
+        .map(|ahead| last_sync_durations.get(ahead).copied())
+        .sum::<Option<Duration>>()
+        .map(|total| {
+            let per_slot = total / RUNTIME_SYNC_MAX_CONCURRENCY as u32;
+            u64::try_from(per_slot.as_millis()).unwrap_or(u64::MAX)
+        });
+
+    TaskQueuePosition {
+        task_id: task_id.to_string(),
+        status: TaskQueueStatus::Queued,
+        position: Some(position),
+        tasks_ahead: Some(position),
+        eta_to_start_ms,
+    }
+}
+
+async fn get_task_queue_position_internal(task_id: &str, state: &AppState) -> TaskQueuePosition {
+    let queue = state.runtime_sync_queue.read().await.clone();
+    let syncing_tasks = state.syncing_tasks.read().await.clone();
+    let last_sync_durations = state.last_sync_durations.read().await.clone();
+    compute_task_queue_position(task_id, &queue, &syncing_tasks, &last_sync_durations)
+}
+
 async fn set_auto_unmount_session_disabled_internal(
     task_id: &str,
     disabled: bool,
@@ -5026,12 +6339,9 @@ async fn sync_control_plane_listener(
     }
 }
 
-async fn apply_canonical_config_to_runtime(
-    app: tauri::AppHandle,
-    state: AppState,
-) -> Result<RuntimeState, String> {
-    let _apply_guard = state.runtime_config_apply_lock.clone().lock_owned().await;
-    let (settings, tasks, exclusion_sets) = load_canonical_config(&state).await?;
+/// 설정 파일을 읽어 검증한 뒤 런타임 설정 형태로 바꿉니다.
+async fn load_canonical_runtime_payload(state: &AppState) -> Result<RuntimeConfigPayload, String> {
+    let (settings, tasks, exclusion_sets) = load_canonical_config(state).await?;
 
     validate_settings_record(&settings)?;
     validate_sync_task_records(&tasks)?;
@@ -5039,20 +6349,34 @@ async fn apply_canonical_config_to_runtime(
         input_validation::validate_exclude_patterns(&set.patterns).map_err(|e| e.to_string())?;
     }
 
-    let valid_task_ids: HashSet<String> = tasks.iter().map(|task| task.id.clone()).collect();
-    let payload = RuntimeConfigPayload {
+    Ok(RuntimeConfigPayload {
         tasks: tasks.iter().map(to_runtime_task_record).collect(),
         exclusion_sets: exclusion_sets
             .iter()
             .map(to_runtime_exclusion_set_record)
             .collect(),
         settings: to_runtime_settings_record(&settings),
-    };
+    })
+}
+
+async fn apply_canonical_config_to_runtime(
+    app: tauri::AppHandle,
+    state: AppState,
+) -> Result<RuntimeState, String> {
+    let _apply_guard = state.runtime_config_apply_lock.clone().lock_owned().await;
+    let payload = load_canonical_runtime_payload(&state).await?;
+    let valid_task_ids: HashSet<String> =
+        payload.tasks.iter().map(|task| task.id.clone()).collect();
 
     {
         let mut config = state.runtime_config.write().await;
         *config = payload;
+        state
+            .global_bandwidth_limiter
+            .set_bytes_per_sec(config.settings.global_max_bytes_per_sec);
     }
+    // 조용한 시간 설정이 바뀌었을 수 있으므로 보류 중인 디스패처를 깨움
+    state.runtime_dispatcher_wakeup.notify_waiters();
     prune_auto_unmount_session_disabled_tasks(&valid_task_ids, &state).await;
 
     reconcile_runtime_watchers(app.clone(), state.clone()).await?;
@@ -5211,6 +6535,8 @@ async fn create_conflict_review_session<R: tauri::Runtime>(
                 },
                 created_at_unix_ms: unix_now_ms(),
                 items: incoming_items,
+                applied_resolutions: HashMap::new(),
+                label: None,
             };
             let added_count = session.items.len();
             sessions.insert(session_id.clone(), session);
@@ -5299,7 +6625,73 @@ async fn maybe_notify_conflict_for_watch(
     }
 }
 
-async fn execute_sync_internal(
+async fn task_notification_settings(
+    task_id: &str,
+    state: &AppState,
+) -> (bool, TaskNotificationPrefs) {
+    let config = state.runtime_config.read().await;
+    let prefs = config
+        .tasks
+        .iter()
+        .find(|task| task.id == task_id)
+        .map(|task| task.notifications)
+        .unwrap_or_default();
+    (config.settings.notifications_enabled, prefs)
+}
+
+async fn notify_sync_outcome(
+    app: &tauri::AppHandle,
+    task_id: &str,
+    task_name: &str,
+    sync_origin: SyncOrigin,
+    failed: bool,
+    conflict_count: usize,
+    state: &AppState,
+) {
+    let (enabled, prefs) = task_notification_settings(task_id, state).await;
+    for kind in select_sync_notifications(enabled, &prefs, failed, conflict_count) {
+        match kind {
+            SyncNotificationKind::Conflict => {
+                if sync_origin == SyncOrigin::Watch {
+                    maybe_notify_conflict_for_watch(app, task_name, conflict_count).await;
+                }
+            }
+            SyncNotificationKind::Completed => {
+                show_sync_notification(app, &format!("Sync completed: '{task_name}'."));
+            }
+            SyncNotificationKind::Failed => {
+                show_sync_notification(
+                    app,
+                    &format!("Sync failed: '{task_name}'. Check the task log for details."),
+                );
+            }
+        }
+    }
+}
+
+fn show_sync_notification(app: &tauri::AppHandle, body: &str) {
+    #[cfg(target_os = "macos")]
+    {
+        use tauri_plugin_notification::NotificationExt;
+        let _ = app
+            .notification()
+            .builder()
+            .title("SyncWatcher")
+            .body(body)
+            .show();
+    }
+    #[cfg(not(target_os = "macos"))]
+    {
+        let _ = (app, body);
+    }
+}
+
+const INCOMPLETE_SYNC_MARKER_DIR_NAME: &str = "incomplete_syncs";
+
+/// 동기화 시작 시 기록하고 종료 시 지우는 마커. 앱이 중간에 죽으면 남아서 재시작 후 재개 대상이 됩니다.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IncompleteSyncMarker {
     task_id: String,
     task_name: String,
     source: PathBuf,
@@ -5307,2519 +6699,5743 @@ async fn execute_sync_internal(
     checksum_mode: bool,
     verify_after_copy: bool,
     exclude_patterns: Vec<String>,
-    app: tauri::AppHandle,
-    state: AppState,
-    sync_slot_pre_acquired: bool,
-    sync_origin: SyncOrigin,
-    external_cancel_token: Option<CancellationToken>,
-    mcp_job_id: Option<String>,
-    file_batch_channel: Option<Channel<SyncFileBatchEvent>>,
-    prepared_dry_run_artifact: Option<DryRunArtifact>,
-) -> Result<SyncExecutionResult, SyncExecutionFailure> {
-    if !sync_slot_pre_acquired && !acquire_sync_slot(&task_id, &state).await {
-        return Err(SyncExecutionFailure::new(
-            "Task is already syncing".to_string(),
-        ));
+    #[serde(default)]
+    options: SyncRunOptions,
+    started_at: i64,
+}
+
+fn incomplete_sync_marker_path(task_id: &str, state: &AppState) -> PathBuf {
+    state
+        .config_store
+        .config_dir()
+        .join(INCOMPLETE_SYNC_MARKER_DIR_NAME)
+        .join(format!("{task_id}.json"))
+}
+
+async fn write_incomplete_sync_marker(
+    marker: &IncompleteSyncMarker,
+    state: &AppState,
+) -> Result<(), String> {
+    let path = incomplete_sync_marker_path(&marker.task_id, state);
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent)
+            .await
+            .map_err(|e| format!("Failed to create marker directory: {e}"))?;
     }
-    try_acquire_task_operation(&task_id, TaskOperationKind::Sync, &state)
+    let content = serde_json::to_vec_pretty(marker).map_err(|e| e.to_string())?;
+    tokio::fs::write(&path, content)
         .await
-        .map_err(SyncExecutionFailure::new)?;
-    if sync_origin == SyncOrigin::Manual {
-        emit_runtime_sync_state(&app, &task_id, true, sync_event_origin(sync_origin), None);
+        .map_err(|e| format!("Failed to write sync marker: {e}"))
+}
+
+async fn clear_incomplete_sync_marker(task_id: &str, state: &AppState) {
+    let path = incomplete_sync_marker_path(task_id, state);
+    if let Err(error) = tokio::fs::remove_file(&path).await {
+        if error.kind() != std::io::ErrorKind::NotFound {
+            eprintln!(
+                "[Sync] Failed to clear sync marker {}: {error}",
+                path.display()
+            );
+        }
     }
+}
 
-    let recurring_history_detail_entries = Arc::new(StdMutex::new(Vec::new()));
-    let sync_result = async {
-        let requested_source = source.to_string_lossy().to_string();
-        let requested_target = target.to_string_lossy().to_string();
-        maybe_activate_task_path_access(&task_id, &state)
-            .await
-            .map_err(SyncExecutionFailure::new)?;
-        let (source, target) = ensure_task_paths_available(&task_id, &source, &target, &state)
-            .await
-            .map_err(SyncExecutionFailure::new)?;
-        ensure_non_overlapping_paths(&source, &target).map_err(SyncExecutionFailure::new)?;
+/// 남아 있는 마커를 시작 시각 순으로 반환합니다. 읽을 수 없는 마커는 건너뜁니다.
+async fn list_incomplete_syncs_internal(state: &AppState) -> Vec<IncompleteSyncMarker> {
+    let dir = state
+        .config_store
+        .config_dir()
+        .join(INCOMPLETE_SYNC_MARKER_DIR_NAME);
+    let Ok(mut entries) = tokio::fs::read_dir(&dir).await else {
+        return Vec::new();
+    };
 
-        // Validate all inputs
-        input_validation::validate_task_id(&task_id)
-            .map_err(|e| e.to_string())
-            .map_err(SyncExecutionFailure::new)?;
-        input_validation::validate_path_argument(source.to_str().unwrap_or(""))
-            .map_err(|e| e.to_string())
-            .map_err(SyncExecutionFailure::new)?;
-        input_validation::validate_path_argument(target.to_str().unwrap_or(""))
-            .map_err(|e| e.to_string())
-            .map_err(SyncExecutionFailure::new)?;
-        input_validation::validate_exclude_patterns(&exclude_patterns)
-            .map_err(|e| e.to_string())
-            .map_err(SyncExecutionFailure::new)?;
-        let target_key = resolved_path_key(target.to_str().unwrap_or(""))
-            .map_err(SyncExecutionFailure::new)?;
-        let producer_id = runtime_sync_producer_id(&task_id, sync_origin);
-        register_runtime_producer(
-            producer_id.clone(),
-            runtime_sync_origin_producer_kind(sync_origin),
-            target_key.clone(),
-            &state,
-        )
-        .await;
-        let sync_result = async {
-            // 취소 토큰 생성 및 등록
-            let cancel_token = CancellationToken::new();
-            if let Some(external_cancel_token) = external_cancel_token {
-                let forward_cancel = cancel_token.clone();
-                tauri::async_runtime::spawn(async move {
-                    external_cancel_token.cancelled().await;
-                    forward_cancel.cancel();
-                });
-            }
-            {
-                let mut tokens = state.cancel_tokens.write().await;
-                tokens.insert(task_id.clone(), cancel_token.clone());
-            }
+    let mut markers = Vec::new();
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        let path = entry.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+            continue;
+        }
+        let Ok(content) = tokio::fs::read(&path).await else {
+            continue;
+        };
+        match serde_json::from_slice::<IncompleteSyncMarker>(&content) {
+            Ok(marker) => markers.push(marker),
+            Err(error) => eprintln!(
+                "[Sync] Ignoring unreadable sync marker {}: {error}",
+                path.display()
+            ),
+        }
+    }
+    markers.sort_by(|a, b| {
+        a.started_at
+            .cmp(&b.started_at)
+            .then_with(|| a.task_id.cmp(&b.task_id))
+    });
+    markers
+}
 
-            let engine = SyncEngine::new(source.clone(), target.clone());
-            let options = SyncOptions {
-                checksum_mode,
-                preserve_permissions: true,
-                preserve_times: true,
-                verify_after_copy,
-                exclude_patterns,
-            };
+const TASK_LIFETIME_STATS_DIR_NAME: &str = "task_stats";
 
-            // 동기화 실행 (취소 토큰과 함께)
-            let task_id_clone = task_id.clone();
-            let progress_state = SyncProgressState::new();
-            let sync_live_state = SyncLiveState::new();
-            let log_manager = state.log_manager.clone();
-            let task_id_for_log = task_id.clone();
-            let app_for_log = app.clone(); // For log events
-            let recurring_history_detail_entries_for_progress =
-                recurring_history_detail_entries.clone();
-            let mcp_jobs = state.mcp_jobs.clone();
-            let mcp_job_id_for_progress = mcp_job_id.clone();
-            let sync_event_origin_value = sync_event_origin(sync_origin);
-            let task_id_for_file_batch = task_id.clone();
-            let app_for_file_batch = app.clone();
-            let file_batch_channel_for_file_batch = file_batch_channel.clone();
-            let sync_event_origin_for_file_batch = sync_event_origin_value.clone();
-            let sync_live_state_for_file_batch = sync_live_state.clone();
-            let task_id_for_progress = task_id.clone();
-            let app_for_progress = app.clone();
-            let mcp_jobs_for_progress = mcp_jobs.clone();
-            let sync_event_origin_for_progress = sync_event_origin_value.clone();
+/// 태스크 설정 이후 누적 동기화 통계. 실패한 실행도 실행 횟수와 오류 수에 포함됩니다.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TaskLifetimeStats {
+    task_id: String,
+    total_runs: u64,
+    total_files_copied: u64,
+    total_bytes_copied: u64,
+    total_errors: u64,
+    first_seen_at: Option<i64>,
+    last_run_at: Option<i64>,
+}
 
-            let operation_result = async {
-                let (dry_run_plan, target_newer_conflicts) =
-                    if let Some(artifact) = prepared_dry_run_artifact.as_ref() {
-                    let validating_progress = sync_engine::types::SyncProgress {
-                        phase: SyncProgressPhase::ValidatingDryRun,
-                        current_file: Some("Validating cached Dry Run...".to_string()),
-                        total_files: artifact.candidates.len() as u64,
-                        processed_files: 0,
-                        total_bytes: 0,
-                        processed_bytes: 0,
-                        bytes_copied_current_file: 0,
-                        current_file_total_bytes: 0,
-                    };
-                    let validating_event = sync_progress_event(
-                        &task_id,
-                        sync_event_origin_value.clone(),
-                        &validating_progress,
-                    );
-                    emit_sync_progress(&app, &validating_event);
-                    try_update_mcp_progress_from_sync_event(
-                        &mcp_jobs,
-                        mcp_job_id.as_deref(),
-                        &validating_event,
-                    );
+fn task_lifetime_stats_path(task_id: &str, state: &AppState) -> PathBuf {
+    state
+        .config_store
+        .config_dir()
+        .join(TASK_LIFETIME_STATS_DIR_NAME)
+        .join(format!("{task_id}.json"))
+}
 
-                    validate_dry_run_artifact(
-                        artifact,
-                        &requested_source,
-                        &requested_target,
-                        &source,
-                        &target,
-                        checksum_mode,
-                        &options.exclude_patterns,
-                        Some(&cancel_token),
-                    )
-                    .await
-                    .map_err(SyncExecutionFailure::new)?;
+async fn read_task_lifetime_stats(task_id: &str, state: &AppState) -> TaskLifetimeStats {
+    let path = task_lifetime_stats_path(task_id, state);
+    tokio::fs::read(&path)
+        .await
+        .ok()
+        .and_then(|content| serde_json::from_slice(&content).ok())
+        .unwrap_or_else(|| TaskLifetimeStats {
+            task_id: task_id.to_string(),
+            ..TaskLifetimeStats::default()
+        })
+}
 
-                    let validated_progress = sync_engine::types::SyncProgress {
-                        phase: SyncProgressPhase::ValidatingDryRun,
-                        current_file: Some("Cached Dry Run validated".to_string()),
-                        total_files: artifact.candidates.len() as u64,
-                        processed_files: artifact.candidates.len() as u64,
-                        total_bytes: 0,
-                        processed_bytes: 0,
-                        bytes_copied_current_file: 0,
-                        current_file_total_bytes: 0,
-                    };
-                    let validated_event = sync_progress_event(
-                        &task_id,
-                        sync_event_origin_value.clone(),
-                        &validated_progress,
-                    );
-                    emit_sync_progress(&app, &validated_event);
-                    try_update_mcp_progress_from_sync_event(
-                        &mcp_jobs,
-                        mcp_job_id.as_deref(),
-                        &validated_event,
-                    );
+/// 실행 하나의 결과를 누적합니다. 읽기-수정-쓰기 전체를 `task_lifetime_stats_lock`으로 묶어
+/// 동시에 끝나는 다른 태스크의 기록이나 초기화와 섞이지 않게 합니다.
+async fn record_task_lifetime_stats(
+    task_id: &str,
+    result: &Result<SyncExecutionResult, SyncExecutionFailure>,
+    state: &AppState,
+) -> Result<TaskLifetimeStats, String> {
+    input_validation::validate_task_id(task_id).map_err(|e| e.to_string())?;
+    let _guard = state.task_lifetime_stats_lock.lock().await;
 
-                        (artifact.result.clone(), artifact.target_newer_conflicts.clone())
-                    } else {
-                        let progress_state_for_plan = progress_state.clone();
-                        let log_manager_for_plan = log_manager.clone();
-                        let recurring_entries_for_plan =
-                            recurring_history_detail_entries_for_progress.clone();
-                        let task_id_for_log_plan = task_id_for_log.clone();
-                        let task_id_for_progress_plan = task_id_for_progress.clone();
-                        let app_for_log_plan = app_for_log.clone();
-                        let app_for_progress_plan = app_for_progress.clone();
-                        let mcp_jobs_for_plan = mcp_jobs_for_progress.clone();
-                        let mcp_job_id_for_plan = mcp_job_id_for_progress.clone();
-                        let sync_origin_for_plan = sync_event_origin_for_progress.clone();
-                        engine
-                            .prepare_sync_plan_with_progress_and_cancel(
-                                &options,
-                                cancel_token.clone(),
-                                move |progress| {
-                                    handle_live_sync_progress(
-                                        &progress_state_for_plan,
-                                        &task_id_for_log_plan,
-                                        &app_for_log_plan,
-                                        &log_manager_for_plan,
-                                        &recurring_entries_for_plan,
-                                        &task_id_for_progress_plan,
-                                        sync_origin_for_plan.clone(),
-                                        &app_for_progress_plan,
-                                        &mcp_jobs_for_plan,
-                                        mcp_job_id_for_plan.as_deref(),
-                                        &progress,
-                                    );
-                                },
-                                |_, _| {},
-                            )
-                            .await
-                            .map_err(|error| format!("{:#}", error))
-                            .map_err(SyncExecutionFailure::new)?
-                    };
+    let mut stats = read_task_lifetime_stats(task_id, state).await;
+    let now = unix_now_ms();
+    stats.total_runs += 1;
+    match result {
+        Ok(execution) => {
+            stats.total_files_copied += execution.sync_result.files_copied;
+            stats.total_bytes_copied += execution.sync_result.bytes_copied;
+            stats.total_errors += execution.sync_result.errors.len() as u64;
+        }
+        Err(_) => stats.total_errors += 1,
+    }
+    stats.first_seen_at.get_or_insert(now);
+    stats.last_run_at = Some(now);
 
-                if cancel_token.is_cancelled() {
-                    return Err(SyncExecutionFailure::new(
-                        SYNC_CANCELLED_BY_USER_ERROR.to_string(),
-                    ));
-                }
+    let content = serde_json::to_vec_pretty(&stats).map_err(|e| e.to_string())?;
+    state
+        .config_store
+        .write_raw_file_at_path(&task_lifetime_stats_path(task_id, state), content)
+        .map_err(config_store_error_to_string)?;
+    Ok(stats)
+}
 
-                let target_preflight = preflight_target_path(&target, true)
-                    .await
-                    .map_err(SyncExecutionFailure::new)?;
+async fn get_task_lifetime_stats_internal(
+    task_id: &str,
+    state: &AppState,
+) -> Result<TaskLifetimeStats, String> {
+    input_validation::validate_task_id(task_id).map_err(|e| e.to_string())?;
+    let _guard = state.task_lifetime_stats_lock.lock().await;
+    Ok(read_task_lifetime_stats(task_id, state).await)
+}
 
-                state.log_manager.log_with_category(
-                    "info",
-                    "Sync started",
-                    Some(task_id.clone()),
-                    LogCategory::SyncStarted,
-                );
-                if target_preflight.kind == TargetPreflightKind::CreatedDirectory {
-                    state.log_manager.log_with_category(
-                        "info",
-                        &format!("Target directory created: {}", target_preflight.path),
-                        Some(task_id.clone()),
-                        LogCategory::Other,
-                    );
-                }
+async fn reset_task_stats_internal(
+    task_id: &str,
+    state: &AppState,
+) -> Result<TaskLifetimeStats, String> {
+    input_validation::validate_task_id(task_id).map_err(|e| e.to_string())?;
+    let _guard = state.task_lifetime_stats_lock.lock().await;
+    let path = task_lifetime_stats_path(task_id, state);
+    match tokio::fs::remove_file(&path).await {
+        Ok(()) => {}
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => {}
+        Err(error) => return Err(format!("Failed to reset task stats: {error}")),
+    }
+    Ok(TaskLifetimeStats {
+        task_id: task_id.to_string(),
+        ..TaskLifetimeStats::default()
+    })
+}
 
-                let copy_result = tokio::select! {
-                    res = engine.sync_files_from_dry_run(&dry_run_plan, &options, {
-                        let progress_state_for_copy = progress_state.clone();
-                        let log_manager_for_copy = log_manager.clone();
-                        let recurring_entries_for_copy = recurring_history_detail_entries_for_progress.clone();
-                        let task_id_for_log_copy = task_id_for_log.clone();
-                        let task_id_for_progress_copy = task_id_for_progress.clone();
-                        let app_for_log_copy = app_for_log.clone();
-                        let app_for_progress_copy = app_for_progress.clone();
-                        let mcp_jobs_for_copy = mcp_jobs_for_progress.clone();
-                        let mcp_job_id_for_copy = mcp_job_id_for_progress.clone();
-                        let sync_origin_for_copy = sync_event_origin_for_progress.clone();
-                        move |progress| {
-                            handle_live_sync_progress(
-                                &progress_state_for_copy,
-                                &task_id_for_log_copy,
-                                &app_for_log_copy,
-                                &log_manager_for_copy,
-                                &recurring_entries_for_copy,
-                                &task_id_for_progress_copy,
-                                sync_origin_for_copy.clone(),
-                                &app_for_progress_copy,
-                                &mcp_jobs_for_copy,
-                                mcp_job_id_for_copy.as_deref(),
-                                &progress,
-                            );
-                        }
-                    }, move |entry| {
-                        let now = Instant::now();
-                        if let Some(entries) = sync_live_state_for_file_batch.record_entry(entry, now) {
-                            let event = SyncFileBatchEvent {
-                                task_id: task_id_for_file_batch.clone(),
-                                origin: sync_event_origin_for_file_batch.clone(),
-                                entries,
-                            };
-                            emit_sync_file_batch(
-                                &app_for_file_batch,
-                                file_batch_channel_for_file_batch.as_ref(),
-                                &event,
-                            );
-                        }
-                    }) => {
-                        if let Some(entries) = sync_live_state.flush_pending_entries() {
-                            let event = SyncFileBatchEvent {
-                                task_id: task_id.clone(),
-                                origin: sync_event_origin_value.clone(),
-                                entries,
-                            };
-                            emit_sync_file_batch(&app, file_batch_channel.as_ref(), &event);
-                        }
-                        if let Some(batch) = progress_state.flush_logs() {
-                            capture_recurring_schedule_detail_entries(
-                                &recurring_history_detail_entries_for_progress,
-                                &batch,
-                            );
-                            log_manager.log_batch_entries(
-                                batch,
-                                Some(task_id.clone()),
-                                Some(&app),
-                            );
-                        }
-                        res
-                    },
-                    _ = cancel_token.cancelled() => {
-                        if let Some(entries) = sync_live_state.flush_pending_entries() {
-                            let event = SyncFileBatchEvent {
-                                task_id: task_id.clone(),
-                                origin: sync_event_origin_value.clone(),
-                                entries,
-                            };
-                            emit_sync_file_batch(&app, file_batch_channel.as_ref(), &event);
-                        }
-                        if let Some(batch) = progress_state.flush_logs() {
-                            capture_recurring_schedule_detail_entries(
-                                &recurring_history_detail_entries_for_progress,
-                                &batch,
-                            );
-                            log_manager.log_batch_entries(
-                                batch,
-                                Some(task_id.clone()),
-                                Some(&app),
-                            );
-                        }
-                        Err(anyhow::anyhow!(SYNC_CANCELLED_BY_USER_ERROR))
-                    }
-                };
+const TASK_FAILED_FILES_DIR_NAME: &str = "task_failed_files";
+const TASK_SYNC_SNAPSHOT_DIR_NAME: &str = "task_sync_snapshots";
 
-                match &copy_result {
-                    Ok(res) => {
-                        let unit_system = state.runtime_config.read().await.settings.data_unit_system;
-                        let msg = format!(
-                            "Sync completed.\nCopied: {} files\nData transferred: {}",
-                            format_number(res.files_copied),
-                            format_bytes_with_unit(res.bytes_copied, unit_system)
-                        );
-                        state.log_manager.log_with_category(
-                            "success",
-                            &msg,
-                            Some(task_id.clone()),
-                            LogCategory::SyncCompleted,
-                        );
+fn task_sync_snapshot_path(task_id: &str, state: &AppState) -> PathBuf {
+    state
+        .config_store
+        .config_dir()
+        .join(TASK_SYNC_SNAPSHOT_DIR_NAME)
+        .join(format!("{task_id}.json"))
+}
 
-                        let conflict_session_id = create_conflict_review_session(
-                            &task_id,
-                            &task_name,
-                            &source,
-                            &target,
-                            &target_newer_conflicts,
-                            sync_origin,
-                            &state,
-                            &app,
-                        )
-                        .await;
-                        if conflict_session_id.is_some() && sync_origin == SyncOrigin::Watch {
-                            maybe_notify_conflict_for_watch(&app, &task_name, target_newer_conflicts.len())
-                                .await;
-                        }
+/// 저장된 스냅샷이 없거나 읽을 수 없으면 `None` (전체 해시로 진행).
+async fn read_task_sync_snapshot(task_id: &str, state: &AppState) -> Option<SyncSnapshot> {
+    input_validation::validate_task_id(task_id).ok()?;
+    let content = tokio::fs::read(task_sync_snapshot_path(task_id, state))
+        .await
+        .ok()?;
+    serde_json::from_slice(&content).ok()
+}
 
-                        emit_sync_session_finished(
-                            &app,
-                            &SyncSessionFinishedEvent {
-                                task_id: task_id.clone(),
-                                origin: sync_event_origin_value,
-                                status: "completed".to_string(),
-                                files_copied: res.files_copied,
-                                bytes_copied: res.bytes_copied,
-                                errors: res.errors.clone(),
-                                conflict_count: target_newer_conflicts.len(),
-                                has_pending_conflicts: !target_newer_conflicts.is_empty(),
-                                target_preflight: Some(target_preflight.clone()),
-                                reason: None,
-                            },
-                        );
+async fn write_task_sync_snapshot(
+    task_id: &str,
+    snapshot: &SyncSnapshot,
+    state: &AppState,
+) -> Result<(), String> {
+    input_validation::validate_task_id(task_id).map_err(|e| e.to_string())?;
+    let content = serde_json::to_vec(snapshot).map_err(|e| e.to_string())?;
+    state
+        .config_store
+        .write_raw_file_at_path(&task_sync_snapshot_path(task_id, state), content)
+        .map_err(config_store_error_to_string)
+}
 
-                        Ok(SyncExecutionResult {
-                            sync_result: res.clone(),
-                            conflict_session_id,
-                            conflict_count: target_newer_conflicts.len(),
-                            has_pending_conflicts: !target_newer_conflicts.is_empty(),
-                            target_preflight: Some(target_preflight.clone()),
-                            recurring_history_detail_entries: snapshot_recurring_schedule_detail_entries(
-                                &recurring_history_detail_entries,
-                            ),
-                        })
-                    }
-                    Err(e) => {
-                        let err_text = format!("{:#}", e);
-                        if err_text.contains("cancelled by user")
-                            || err_text.contains(SYNC_CANCELLED_BY_USER_ERROR)
-                        {
-                            emit_sync_session_finished(
-                                &app,
-                                &SyncSessionFinishedEvent {
-                                    task_id: task_id.clone(),
-                                    origin: sync_event_origin_value,
-                                    status: "cancelled".to_string(),
-                                    files_copied: 0,
-                                    bytes_copied: 0,
-                                    errors: Vec::new(),
-                                    conflict_count: 0,
-                                    has_pending_conflicts: false,
-                                    target_preflight: Some(target_preflight.clone()),
-                                    reason: Some(SYNC_CANCELLED_BY_USER_ERROR.to_string()),
-                                },
-                            );
-                            emit_task_log_with_recurring_detail(
-                                &state.log_manager,
-                                Some(&recurring_history_detail_entries),
-                                "warning",
-                                "Sync cancelled by user",
-                                Some(task_id.clone()),
-                                LogCategory::SyncError,
-                                Some(&app),
-                            );
-                            Err(SyncExecutionFailure {
-                                error_detail: SYNC_CANCELLED_BY_USER_ERROR.to_string(),
-                                recurring_history_detail_entries: snapshot_recurring_schedule_detail_entries(
-                                    &recurring_history_detail_entries,
-                                ),
-                            })
-                        } else {
-                            emit_sync_session_finished(
-                                &app,
-                                &SyncSessionFinishedEvent {
-                                    task_id: task_id.clone(),
-                                    origin: sync_event_origin_value,
-                                    status: "failed".to_string(),
-                                    files_copied: 0,
-                                    bytes_copied: 0,
-                                    errors: Vec::new(),
-                                    conflict_count: 0,
-                                    has_pending_conflicts: false,
-                                    target_preflight: Some(target_preflight.clone()),
-                                    reason: Some(err_text.clone()),
-                                },
-                            );
-                            let msg = format!("Sync failed: {err_text}");
-                            emit_task_log_with_recurring_detail(
-                                &state.log_manager,
-                                Some(&recurring_history_detail_entries),
-                                "error",
-                                &msg,
-                                Some(task_id.clone()),
-                                LogCategory::SyncError,
-                                Some(&app),
-                            );
-                            Err(SyncExecutionFailure {
-                                error_detail: err_text,
-                                recurring_history_detail_entries: snapshot_recurring_schedule_detail_entries(
-                                    &recurring_history_detail_entries,
-                                ),
-                            })
-                        }
-                    }
+/// 태스크의 마지막 동기화에서 실패한 파일 목록. 다음 동기화가 끝나면 그 결과로 교체됩니다.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TaskFailedFiles {
+    task_id: String,
+    errors: Vec<SyncError>,
+    recorded_at: Option<i64>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RetryFailedFilesResult {
+    retried: Vec<RecopyFileResult>,
+    remaining_errors: Vec<SyncError>,
+}
+
+fn task_failed_files_path(task_id: &str, state: &AppState) -> PathBuf {
+    state
+        .config_store
+        .config_dir()
+        .join(TASK_FAILED_FILES_DIR_NAME)
+        .join(format!("{task_id}.json"))
+}
+
+async fn read_task_failed_files(task_id: &str, state: &AppState) -> TaskFailedFiles {
+    let path = task_failed_files_path(task_id, state);
+    tokio::fs::read(&path)
+        .await
+        .ok()
+        .and_then(|content| serde_json::from_slice(&content).ok())
+        .unwrap_or_else(|| TaskFailedFiles {
+            task_id: task_id.to_string(),
+            ..TaskFailedFiles::default()
+        })
+}
+
+/// 실패 목록이 비면 파일을 지웁니다.
+async fn write_task_failed_files(failed: &TaskFailedFiles, state: &AppState) -> Result<(), String> {
+    let path = task_failed_files_path(&failed.task_id, state);
+    if failed.errors.is_empty() {
+        return match tokio::fs::remove_file(&path).await {
+            Ok(()) => Ok(()),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(error) => Err(format!("Failed to clear failed files: {error}")),
+        };
+    }
+    let content = serde_json::to_vec_pretty(failed).map_err(|e| e.to_string())?;
+    state
+        .config_store
+        .write_raw_file_at_path(&path, content)
+        .map_err(config_store_error_to_string)
+}
+
+/// 완료된 실행의 오류 목록으로 교체합니다. 실행 자체가 실패하면 이전 목록을 유지합니다.
+async fn record_task_failed_files(
+    task_id: &str,
+    result: &Result<SyncExecutionResult, SyncExecutionFailure>,
+    state: &AppState,
+) -> Result<(), String> {
+    let Ok(execution) = result else {
+        return Ok(());
+    };
+    input_validation::validate_task_id(task_id).map_err(|e| e.to_string())?;
+    let _guard = state.task_failed_files_lock.lock().await;
+    write_task_failed_files(
+        &TaskFailedFiles {
+            task_id: task_id.to_string(),
+            errors: execution.sync_result.errors.clone(),
+            recorded_at: Some(unix_now_ms()),
+        },
+        state,
+    )
+    .await
+}
+
+/// 파일 단위 재복사로 복구될 수 있는 오류인지. 경로 길이/읽기 전용/플래그/레이아웃 충돌 오류는 재시도해도
+/// 결과가 같으므로 목록에 그대로 남깁니다.
+fn is_retryable_sync_error(error: &SyncError) -> bool {
+    matches!(
+        error.kind,
+        SyncErrorKind::CopyFailed
+            | SyncErrorKind::VerificationFailed
+            | SyncErrorKind::SourceDisconnected
+            | SyncErrorKind::Other
+    ) && error
+        .path
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)))
+}
+
+/// 마지막 실행에서 실패한 파일만 태스크의 현재 source/target으로 다시 복사합니다.
+/// 성공한 항목은 실패 목록에서 지우고, 다시 실패한 항목은 새 오류로 갱신합니다.
+async fn retry_failed_files_internal(
+    task_id: &str,
+    state: &AppState,
+) -> Result<RetryFailedFilesResult, String> {
+    let task = load_sync_task_by_id(task_id, state).await?;
+    try_acquire_task_operation(task_id, TaskOperationKind::Sync, state).await?;
+
+    let result: Result<RetryFailedFilesResult, String> = async {
+        let _guard = state.task_failed_files_lock.lock().await;
+        let mut failed = read_task_failed_files(task_id, state).await;
+        if !failed.errors.iter().any(is_retryable_sync_error) {
+            return Ok(RetryFailedFilesResult {
+                retried: Vec::new(),
+                remaining_errors: failed.errors,
+            });
+        }
+
+        let source = resolve_path_with_uuid(&task.source)?;
+        let target = resolve_path_with_uuid(&task.target)?;
+        ensure_non_overlapping_paths(&source, &target)?;
+        let options = build_sync_options(
+            task.checksum_mode,
+            task.verify_after_copy,
+            Vec::new(),
+            &SyncRunOptions::default(),
+        );
+        let engine = SyncEngine::new(source, target);
+
+        let mut retried = Vec::new();
+        let mut remaining_errors = Vec::new();
+        for error in std::mem::take(&mut failed.errors) {
+            if !is_retryable_sync_error(&error) {
+                remaining_errors.push(error);
+                continue;
+            }
+            let outcome = engine
+                .recopy_file(&error.path, &options)
+                .await
+                .unwrap_or_else(|copy_error| RecopyFileResult {
+                    path: error.path.clone(),
+                    success: false,
+                    bytes_copied: 0,
+                    verified: false,
+                    error: Some(format!("{:#}", copy_error)),
+                });
+            if !outcome.success {
+                remaining_errors.push(SyncError {
+                    path: error.path,
+                    message: outcome.error.clone().unwrap_or_default(),
+                    kind: if outcome.verified {
+                        SyncErrorKind::VerificationFailed
+                    } else {
+                        SyncErrorKind::CopyFailed
+                    },
+                });
+            }
+            retried.push(outcome);
+        }
+
+        failed.errors = remaining_errors.clone();
+        failed.recorded_at = Some(unix_now_ms());
+        write_task_failed_files(&failed, state).await?;
+        Ok(RetryFailedFilesResult {
+            retried,
+            remaining_errors,
+        })
+    }
+    .await;
+
+    release_task_operation(task_id, state).await;
+
+    let result = result?;
+    let recovered = result
+        .retried
+        .iter()
+        .filter(|outcome| outcome.success)
+        .count();
+    state.log_manager.log_with_category(
+        "info",
+        &format!(
+            "Retried failed files: {} recovered, {} still failing",
+            recovered,
+            result.remaining_errors.len()
+        ),
+        Some(task_id.to_string()),
+        LogCategory::Other,
+    );
+    Ok(result)
+}
+
+/// 실행 옵션에 시간 제한이 없으면 태스크에 저장된 제한을 씁니다.
+/// watch·예약·MCP 실행과 시간 제한으로 다시 큐에 넣은 실행도 같은 제한을 따릅니다.
+async fn with_task_max_duration(
+    mut run_options: SyncRunOptions,
+    task_id: &str,
+    state: &AppState,
+) -> SyncRunOptions {
+    if run_options.max_duration_secs.is_none() {
+        let config = state.runtime_config.read().await;
+        run_options.max_duration_secs = config
+            .tasks
+            .iter()
+            .find(|task| task.id == task_id)
+            .and_then(|task| task.max_duration_secs)
+            .filter(|secs| *secs > 0);
+    }
+    run_options
+}
+
+async fn execute_sync_internal(
+    task_id: String,
+    task_name: String,
+    source: PathBuf,
+    target: PathBuf,
+    checksum_mode: bool,
+    verify_after_copy: bool,
+    exclude_patterns: Vec<String>,
+    app: tauri::AppHandle,
+    state: AppState,
+    sync_slot_pre_acquired: bool,
+    sync_origin: SyncOrigin,
+    external_cancel_token: Option<CancellationToken>,
+    mcp_job_id: Option<String>,
+    file_batch_channel: Option<Channel<SyncFileBatchEvent>>,
+    prepared_dry_run_artifact: Option<DryRunArtifact>,
+    run_options: SyncRunOptions,
+) -> Result<SyncExecutionResult, SyncExecutionFailure> {
+    let run_options = with_task_max_duration(run_options, &task_id, &state).await;
+    if !sync_slot_pre_acquired && !acquire_sync_slot(&task_id, &state).await {
+        return Err(SyncExecutionFailure::new(
+            "Task is already syncing".to_string(),
+        ));
+    }
+    try_acquire_task_operation(&task_id, TaskOperationKind::Sync, &state)
+        .await
+        .map_err(SyncExecutionFailure::new)?;
+    if sync_origin == SyncOrigin::Manual {
+        emit_runtime_sync_state(&app, &task_id, true, sync_event_origin(sync_origin), None);
+    }
+
+    let sync_started_at = Instant::now();
+    let recurring_history_detail_entries = Arc::new(StdMutex::new(Vec::new()));
+    let sync_result = async {
+        let requested_source = source.to_string_lossy().to_string();
+        let requested_target = target.to_string_lossy().to_string();
+        maybe_activate_task_path_access(&task_id, &state)
+            .await
+            .map_err(SyncExecutionFailure::new)?;
+        let (source, target) = ensure_task_paths_available(&task_id, &source, &target, &state)
+            .await
+            .map_err(SyncExecutionFailure::new)?;
+        ensure_non_overlapping_paths(&source, &target).map_err(SyncExecutionFailure::new)?;
+        ensure_target_not_system_volume(&target, run_options.allow_system_target)
+            .map_err(SyncExecutionFailure::new)?;
+
+        // Validate all inputs
+        input_validation::validate_task_id(&task_id)
+            .map_err(|e| e.to_string())
+            .map_err(SyncExecutionFailure::new)?;
+        input_validation::validate_path_argument(source.to_str().unwrap_or(""))
+            .map_err(|e| e.to_string())
+            .map_err(SyncExecutionFailure::new)?;
+        input_validation::validate_path_argument(target.to_str().unwrap_or(""))
+            .map_err(|e| e.to_string())
+            .map_err(SyncExecutionFailure::new)?;
+        input_validation::validate_exclude_patterns(&exclude_patterns)
+            .map_err(|e| e.to_string())
+            .map_err(SyncExecutionFailure::new)?;
+        run_options.validate().map_err(SyncExecutionFailure::new)?;
+        let target_key = resolved_path_key(target.to_str().unwrap_or(""))
+            .map_err(SyncExecutionFailure::new)?;
+        let marker = IncompleteSyncMarker {
+            task_id: task_id.clone(),
+            task_name: task_name.clone(),
+            source: source.clone(),
+            target: target.clone(),
+            checksum_mode,
+            verify_after_copy,
+            exclude_patterns: exclude_patterns.clone(),
+            options: run_options.clone(),
+            started_at: unix_now_ms(),
+        };
+        if let Err(error) = write_incomplete_sync_marker(&marker, &state).await {
+            eprintln!("[Sync] {error}");
+        }
+        let producer_id = runtime_sync_producer_id(&task_id, sync_origin);
+        register_runtime_producer(
+            producer_id.clone(),
+            runtime_sync_origin_producer_kind(sync_origin),
+            target_key.clone(),
+            &state,
+        )
+        .await;
+        let sync_result = async {
+            // 취소 토큰 생성 및 등록
+            let cancel_token = CancellationToken::new();
+            if let Some(external_cancel_token) = external_cancel_token {
+                let forward_cancel = cancel_token.clone();
+                tauri::async_runtime::spawn(async move {
+                    external_cancel_token.cancelled().await;
+                    forward_cancel.cancel();
+                });
+            }
+            {
+                let mut tokens = state.cancel_tokens.write().await;
+                tokens.insert(task_id.clone(), cancel_token.clone());
+            }
+            state
+                .sync_run_copied_files
+                .lock()
+                .unwrap()
+                .insert(task_id.clone(), SyncRunCopiedFiles::default());
+
+            let use_sync_snapshot = run_options.uses_sync_snapshot(checksum_mode);
+            let mut engine = SyncEngine::new(source.clone(), target.clone())
+                .with_bandwidth_limiter(state.global_bandwidth_limiter.clone());
+            if run_options.trusts_sync_snapshot(checksum_mode) {
+                if let Some(snapshot) = read_task_sync_snapshot(&task_id, &state).await {
+                    engine = engine.with_trusted_snapshot(Arc::new(snapshot));
                 }
             }
-            .await;
+            let options = build_sync_options(
+                checksum_mode,
+                verify_after_copy,
+                exclude_patterns,
+                &run_options,
+            );
+
+            // 동기화 실행 (취소 토큰과 함께)
+            let task_id_clone = task_id.clone();
+            let progress_state = {
+                let settings = &state.runtime_config.read().await.settings;
+                SyncProgressState::new(
+                    settings.verbose_file_logging,
+                    settings.effective_progress_emit_interval(),
+                    settings.effective_log_flush_interval(),
+                )
+            };
+            let sync_live_state = SyncLiveState::new();
+            let log_manager = state.log_manager.clone();
+            let task_id_for_log = task_id.clone();
+            let app_for_log = app.clone(); // For log events
+            let recurring_history_detail_entries_for_progress =
+                recurring_history_detail_entries.clone();
+            let mcp_jobs = state.mcp_jobs.clone();
+            let mcp_job_id_for_progress = mcp_job_id.clone();
+            let sync_event_origin_value = sync_event_origin(sync_origin);
+            let task_id_for_file_batch = task_id.clone();
+            let in_progress_tracker = Arc::new(InProgressFileTracker::new(
+                state.sync_in_progress_files.clone(),
+                task_id.clone(),
+                source.clone(),
+                target.clone(),
+                options.clone(),
+            ));
+            let in_progress_tracker_for_file_batch = in_progress_tracker.clone();
+            let run_copied_files_for_file_batch = state.sync_run_copied_files.clone();
+            let target_for_file_batch = target.clone();
+            let options_for_file_batch = options.clone();
+            let app_for_file_batch = app.clone();
+            let file_batch_channel_for_file_batch = file_batch_channel.clone();
+            let sync_event_origin_for_file_batch = sync_event_origin_value.clone();
+            let sync_live_state_for_file_batch = sync_live_state.clone();
+            let task_id_for_progress = task_id.clone();
+            let app_for_progress = app.clone();
+            let mcp_jobs_for_progress = mcp_jobs.clone();
+            let sync_event_origin_for_progress = sync_event_origin_value.clone();
+
+            let operation_result = async {
+                let (dry_run_plan, target_newer_conflicts) =
+                    if let Some(artifact) = prepared_dry_run_artifact.as_ref() {
+                    let validating_progress = sync_engine::types::SyncProgress {
+                        phase: SyncProgressPhase::ValidatingDryRun,
+                        current_file: Some("Validating cached Dry Run...".to_string()),
+                        total_files: artifact.candidates.len() as u64,
+                        processed_files: 0,
+                        total_bytes: 0,
+                        processed_bytes: 0,
+                        bytes_copied_current_file: 0,
+                        current_file_total_bytes: 0,
+                    };
+                    let validating_event = sync_progress_event(
+                        &task_id,
+                        sync_event_origin_value.clone(),
+                        &validating_progress,
+                    );
+                    emit_sync_progress(&app, &validating_event);
+                    try_update_mcp_progress_from_sync_event(
+                        &mcp_jobs,
+                        mcp_job_id.as_deref(),
+                        &validating_event,
+                    );
+
+                    validate_dry_run_artifact(
+                        artifact,
+                        &requested_source,
+                        &requested_target,
+                        &source,
+                        &target,
+                        checksum_mode,
+                        &options.exclude_patterns,
+                        Some(&cancel_token),
+                    )
+                    .await
+                    .map_err(SyncExecutionFailure::new)?;
+
+                    let validated_progress = sync_engine::types::SyncProgress {
+                        phase: SyncProgressPhase::ValidatingDryRun,
+                        current_file: Some("Cached Dry Run validated".to_string()),
+                        total_files: artifact.candidates.len() as u64,
+                        processed_files: artifact.candidates.len() as u64,
+                        total_bytes: 0,
+                        processed_bytes: 0,
+                        bytes_copied_current_file: 0,
+                        current_file_total_bytes: 0,
+                    };
+                    let validated_event = sync_progress_event(
+                        &task_id,
+                        sync_event_origin_value.clone(),
+                        &validated_progress,
+                    );
+                    emit_sync_progress(&app, &validated_event);
+                    try_update_mcp_progress_from_sync_event(
+                        &mcp_jobs,
+                        mcp_job_id.as_deref(),
+                        &validated_event,
+                    );
+
+                        (artifact.result.clone(), artifact.target_newer_conflicts.clone())
+                    } else {
+                        let progress_state_for_plan = progress_state.clone();
+                        let log_manager_for_plan = log_manager.clone();
+                        let recurring_entries_for_plan =
+                            recurring_history_detail_entries_for_progress.clone();
+                        let task_id_for_log_plan = task_id_for_log.clone();
+                        let task_id_for_progress_plan = task_id_for_progress.clone();
+                        let app_for_log_plan = app_for_log.clone();
+                        let app_for_progress_plan = app_for_progress.clone();
+                        let mcp_jobs_for_plan = mcp_jobs_for_progress.clone();
+                        let mcp_job_id_for_plan = mcp_job_id_for_progress.clone();
+                        let sync_origin_for_plan = sync_event_origin_for_progress.clone();
+                        engine
+                            .prepare_sync_plan_with_progress_and_cancel(
+                                &options,
+                                cancel_token.clone(),
+                                move |progress| {
+                                    handle_live_sync_progress(
+                                        &progress_state_for_plan,
+                                        &task_id_for_log_plan,
+                                        &app_for_log_plan,
+                                        &log_manager_for_plan,
+                                        &recurring_entries_for_plan,
+                                        &task_id_for_progress_plan,
+                                        sync_origin_for_plan.clone(),
+                                        &app_for_progress_plan,
+                                        &mcp_jobs_for_plan,
+                                        mcp_job_id_for_plan.as_deref(),
+                                        &progress,
+                                    );
+                                },
+                                |_, _| {},
+                            )
+                            .await
+                            .map_err(|error| format!("{:#}", error))
+                            .map_err(SyncExecutionFailure::new)?
+                    };
+
+                if cancel_token.is_cancelled() {
+                    return Err(SyncExecutionFailure::new(
+                        SYNC_CANCELLED_BY_USER_ERROR.to_string(),
+                    ));
+                }
+                in_progress_tracker.set_plan(&dry_run_plan.diffs);
+
+                let target_preflight = preflight_target_path(&target, true)
+                    .await
+                    .map_err(SyncExecutionFailure::new)?;
+
+                state.log_manager.log_with_category(
+                    "info",
+                    "Sync started",
+                    Some(task_id.clone()),
+                    LogCategory::SyncStarted,
+                );
+                if target_preflight.kind == TargetPreflightKind::CreatedDirectory {
+                    state.log_manager.log_with_category(
+                        "info",
+                        &format!("Target directory created: {}", target_preflight.path),
+                        Some(task_id.clone()),
+                        LogCategory::Other,
+                    );
+                }
+
+                let copy_result = tokio::select! {
+                    res = engine.sync_files_from_dry_run(&dry_run_plan, &options, {
+                        let progress_state_for_copy = progress_state.clone();
+                        let log_manager_for_copy = log_manager.clone();
+                        let recurring_entries_for_copy = recurring_history_detail_entries_for_progress.clone();
+                        let task_id_for_log_copy = task_id_for_log.clone();
+                        let task_id_for_progress_copy = task_id_for_progress.clone();
+                        let app_for_log_copy = app_for_log.clone();
+                        let app_for_progress_copy = app_for_progress.clone();
+                        let mcp_jobs_for_copy = mcp_jobs_for_progress.clone();
+                        let mcp_job_id_for_copy = mcp_job_id_for_progress.clone();
+                        let sync_origin_for_copy = sync_event_origin_for_progress.clone();
+                        let transfer_rates_for_copy = state.sync_transfer_rates.clone();
+                        let task_id_for_rate = task_id.clone();
+                        let in_progress_tracker_for_copy = in_progress_tracker.clone();
+                        move |progress| {
+                            record_sync_rate_sample(
+                                &transfer_rates_for_copy,
+                                &task_id_for_rate,
+                                progress.processed_bytes,
+                                progress.total_bytes,
+                                Instant::now(),
+                            );
+                            in_progress_tracker_for_copy.on_progress(&progress);
+                            handle_live_sync_progress(
+                                &progress_state_for_copy,
+                                &task_id_for_log_copy,
+                                &app_for_log_copy,
+                                &log_manager_for_copy,
+                                &recurring_entries_for_copy,
+                                &task_id_for_progress_copy,
+                                sync_origin_for_copy.clone(),
+                                &app_for_progress_copy,
+                                &mcp_jobs_for_copy,
+                                mcp_job_id_for_copy.as_deref(),
+                                &progress,
+                            );
+                        }
+                    }, move |entry| {
+                        in_progress_tracker_for_file_batch.on_file_finished();
+                        if entry.status == SyncFileStatus::Copied {
+                            record_sync_run_copied_file(
+                                &run_copied_files_for_file_batch,
+                                &task_id_for_file_batch,
+                                &entry.kind,
+                                target_for_file_batch.join(SyncEngine::stored_relative_path(
+                                    &options_for_file_batch,
+                                    &entry.path,
+                                )),
+                            );
+                        }
+                        let now = Instant::now();
+                        if let Some(entries) = sync_live_state_for_file_batch.record_entry(entry, now) {
+                            let event = SyncFileBatchEvent {
+                                task_id: task_id_for_file_batch.clone(),
+                                origin: sync_event_origin_for_file_batch.clone(),
+                                entries,
+                            };
+                            emit_sync_file_batch(
+                                &app_for_file_batch,
+                                file_batch_channel_for_file_batch.as_ref(),
+                                &event,
+                            );
+                        }
+                    }) => {
+                        if let Some(entries) = sync_live_state.flush_pending_entries() {
+                            let event = SyncFileBatchEvent {
+                                task_id: task_id.clone(),
+                                origin: sync_event_origin_value.clone(),
+                                entries,
+                            };
+                            emit_sync_file_batch(&app, file_batch_channel.as_ref(), &event);
+                        }
+                        if let Some(batch) = progress_state.flush_logs() {
+                            capture_recurring_schedule_detail_entries(
+                                &recurring_history_detail_entries_for_progress,
+                                &batch,
+                            );
+                            log_manager.log_batch_entries(
+                                batch,
+                                Some(task_id.clone()),
+                                Some(&app),
+                            );
+                        }
+                        res
+                    },
+                    _ = cancel_token.cancelled() => {
+                        if let Some(entries) = sync_live_state.flush_pending_entries() {
+                            let event = SyncFileBatchEvent {
+                                task_id: task_id.clone(),
+                                origin: sync_event_origin_value.clone(),
+                                entries,
+                            };
+                            emit_sync_file_batch(&app, file_batch_channel.as_ref(), &event);
+                        }
+                        if let Some(batch) = progress_state.flush_logs() {
+                            capture_recurring_schedule_detail_entries(
+                                &recurring_history_detail_entries_for_progress,
+                                &batch,
+                            );
+                            log_manager.log_batch_entries(
+                                batch,
+                                Some(task_id.clone()),
+                                Some(&app),
+                            );
+                        }
+                        Err(anyhow::anyhow!(SYNC_CANCELLED_BY_USER_ERROR))
+                    }
+                };
+                clear_sync_rate(&state.sync_transfer_rates, &task_id);
+
+                match &copy_result {
+                    Ok(res) => {
+                        let unit_system = state.runtime_config.read().await.settings.data_unit_system;
+                        let source_disconnected = res.source_disconnected();
+                        for warning in &res.warnings {
+                            state.log_manager.log_with_category(
+                                "warning",
+                                warning,
+                                Some(task_id.clone()),
+                                LogCategory::Other,
+                            );
+                        }
+                        if source_disconnected {
+                            let msg = format!(
+                                "Source disconnected during sync; remaining files were skipped.\nCopied before disconnect: {} files\nData transferred: {}",
+                                format_number(res.files_copied),
+                                format_bytes_with_unit(res.bytes_copied, unit_system)
+                            );
+                            state.log_manager.log_with_category(
+                                "error",
+                                &msg,
+                                Some(task_id.clone()),
+                                LogCategory::SyncError,
+                            );
+                            emit_sync_source_disconnected(
+                                &app,
+                                &task_id,
+                                &source.to_string_lossy(),
+                                res.files_copied,
+                                res.bytes_copied,
+                            );
+                        } else if let Some(stopped_path) = &res.stopped_on_error {
+                            let detail = res
+                                .errors
+                                .last()
+                                .map(|error| error.message.as_str())
+                                .unwrap_or("unknown error");
+                            let msg = format!(
+                                "Sync stopped on first error at '{}': {}\nCopied before stop: {} files\nData transferred: {}",
+                                stopped_path.display(),
+                                detail,
+                                format_number(res.files_copied),
+                                format_bytes_with_unit(res.bytes_copied, unit_system)
+                            );
+                            state.log_manager.log_with_category(
+                                "error",
+                                &msg,
+                                Some(task_id.clone()),
+                                LogCategory::SyncError,
+                            );
+                        } else if res.time_limit_reached {
+                            let msg = format!(
+                                "Sync time limit reached; remaining files were deferred.\nCopied: {} files\nData transferred: {}\nRemaining: {} files ({})",
+                                format_number(res.files_copied),
+                                format_bytes_with_unit(res.bytes_copied, unit_system),
+                                format_number(res.remaining_files),
+                                format_bytes_with_unit(res.remaining_bytes, unit_system)
+                            );
+                            state.log_manager.log_with_category(
+                                "warning",
+                                &msg,
+                                Some(task_id.clone()),
+                                LogCategory::SyncCompleted,
+                            );
+                        } else {
+                            let msg = format!(
+                                "Sync completed.\nCopied: {} files\nData transferred: {}",
+                                format_number(res.files_copied),
+                                format_bytes_with_unit(res.bytes_copied, unit_system)
+                            );
+                            state.log_manager.log_with_category(
+                                "success",
+                                &msg,
+                                Some(task_id.clone()),
+                                LogCategory::SyncCompleted,
+                            );
+                        }
+
+                        if use_sync_snapshot
+                            && res.errors.is_empty()
+                            && !source_disconnected
+                            && res.stopped_on_error.is_none()
+                            && !res.time_limit_reached
+                        {
+                            let snapshot_result = match engine
+                                .capture_sync_snapshot(&options.exclude_patterns)
+                                .await
+                            {
+                                Ok(snapshot) => {
+                                    write_task_sync_snapshot(&task_id, &snapshot, &state).await
+                                }
+                                Err(error) => Err(format!("{error:#}")),
+                            };
+                            if let Err(error) = snapshot_result {
+                                eprintln!("[Sync] Failed to record sync snapshot: {error}");
+                            }
+                        }
+
+                        let conflict_session_id = create_conflict_review_session(
+                            &task_id,
+                            &task_name,
+                            &source,
+                            &target,
+                            &target_newer_conflicts,
+                            sync_origin,
+                            &state,
+                            &app,
+                        )
+                        .await;
+                        notify_sync_outcome(
+                            &app,
+                            &task_id,
+                            &task_name,
+                            sync_origin,
+                            source_disconnected || res.stopped_on_error.is_some(),
+                            if conflict_session_id.is_some() {
+                                target_newer_conflicts.len()
+                            } else {
+                                0
+                            },
+                            &state,
+                        )
+                        .await;
+
+                        emit_sync_session_finished(
+                            &app,
+                            &SyncSessionFinishedEvent {
+                                task_id: task_id.clone(),
+                                origin: sync_event_origin_value,
+                                status: "completed".to_string(),
+                                files_copied: res.files_copied,
+                                bytes_copied: res.bytes_copied,
+                                errors: res.errors.clone(),
+                                conflict_count: target_newer_conflicts.len(),
+                                has_pending_conflicts: !target_newer_conflicts.is_empty(),
+                                target_preflight: Some(target_preflight.clone()),
+                                reason: if source_disconnected {
+                                    Some("source-disconnected".to_string())
+                                } else if res.stopped_on_error.is_some() {
+                                    Some("stopped-on-error".to_string())
+                                } else if res.time_limit_reached {
+                                    Some("time-limit-reached".to_string())
+                                } else {
+                                    None
+                                },
+                            },
+                        );
+
+                        Ok(SyncExecutionResult {
+                            sync_result: res.clone(),
+                            conflict_session_id,
+                            conflict_count: target_newer_conflicts.len(),
+                            has_pending_conflicts: !target_newer_conflicts.is_empty(),
+                            target_preflight: Some(target_preflight.clone()),
+                            recurring_history_detail_entries: snapshot_recurring_schedule_detail_entries(
+                                &recurring_history_detail_entries,
+                            ),
+                        })
+                    }
+                    Err(e) => {
+                        let err_text = format!("{:#}", e);
+                        if err_text.contains("cancelled by user")
+                            || err_text.contains(SYNC_CANCELLED_BY_USER_ERROR)
+                        {
+                            emit_sync_session_finished(
+                                &app,
+                                &SyncSessionFinishedEvent {
+                                    task_id: task_id.clone(),
+                                    origin: sync_event_origin_value,
+                                    status: "cancelled".to_string(),
+                                    files_copied: 0,
+                                    bytes_copied: 0,
+                                    errors: Vec::new(),
+                                    conflict_count: 0,
+                                    has_pending_conflicts: false,
+                                    target_preflight: Some(target_preflight.clone()),
+                                    reason: Some(SYNC_CANCELLED_BY_USER_ERROR.to_string()),
+                                },
+                            );
+                            emit_task_log_with_recurring_detail(
+                                &state.log_manager,
+                                Some(&recurring_history_detail_entries),
+                                "warning",
+                                "Sync cancelled by user",
+                                Some(task_id.clone()),
+                                LogCategory::SyncError,
+                                Some(&app),
+                            );
+                            Err(SyncExecutionFailure {
+                                error_detail: SYNC_CANCELLED_BY_USER_ERROR.to_string(),
+                                recurring_history_detail_entries: snapshot_recurring_schedule_detail_entries(
+                                    &recurring_history_detail_entries,
+                                ),
+                            })
+                        } else {
+                            emit_sync_session_finished(
+                                &app,
+                                &SyncSessionFinishedEvent {
+                                    task_id: task_id.clone(),
+                                    origin: sync_event_origin_value,
+                                    status: "failed".to_string(),
+                                    files_copied: 0,
+                                    bytes_copied: 0,
+                                    errors: Vec::new(),
+                                    conflict_count: 0,
+                                    has_pending_conflicts: false,
+                                    target_preflight: Some(target_preflight.clone()),
+                                    reason: Some(err_text.clone()),
+                                },
+                            );
+                            notify_sync_outcome(
+                                &app,
+                                &task_id,
+                                &task_name,
+                                sync_origin,
+                                true,
+                                0,
+                                &state,
+                            )
+                            .await;
+                            let msg = format!("Sync failed: {err_text}");
+                            emit_task_log_with_recurring_detail(
+                                &state.log_manager,
+                                Some(&recurring_history_detail_entries),
+                                "error",
+                                &msg,
+                                Some(task_id.clone()),
+                                LogCategory::SyncError,
+                                Some(&app),
+                            );
+                            Err(SyncExecutionFailure {
+                                error_detail: err_text,
+                                recurring_history_detail_entries: snapshot_recurring_schedule_detail_entries(
+                                    &recurring_history_detail_entries,
+                                ),
+                            })
+                        }
+                    }
+                }
+            }
+            .await;
+
+            finish_sync_run_tracking(&task_id_clone, &cancel_token, &state).await;
+
+            operation_result
+        }
+        .await;
+        clear_incomplete_sync_marker(&task_id, &state).await;
+
+        finish_runtime_producer(
+            &producer_id,
+            &target_key,
+            sync_result.is_ok(),
+            Some(&app),
+            &state,
+        )
+        .await;
+
+        sync_result
+    }
+    .await;
+
+    let completion_reason = sync_result
+        .as_ref()
+        .err()
+        .map(|error| error.error_detail.clone());
+    if sync_result.is_ok() {
+        let mut durations = state.last_sync_durations.write().await;
+        durations.insert(task_id.clone(), sync_started_at.elapsed());
+    }
+    if let Err(error) = record_task_lifetime_stats(&task_id, &sync_result, &state).await {
+        eprintln!("[Sync] Failed to record task stats: {error}");
+    }
+    if let Err(error) = record_task_failed_files(&task_id, &sync_result, &state).await {
+        eprintln!("[Sync] Failed to record failed files: {error}");
+    }
+    release_task_operation(&task_id, &state).await;
+    release_sync_slot(&task_id, &state).await;
+    let time_limit_reached = sync_result
+        .as_ref()
+        .is_ok_and(|execution| execution.sync_result.time_limit_reached);
+    // 시간 제한으로 멈춘 watch 태스크는 남은 분량을 위해 런타임 큐에 다시 넣습니다.
+    // 재실행은 태스크에 저장된 제한을 다시 적용합니다 (`with_task_max_duration`).
+    if time_limit_reached && is_runtime_watch_task_active(&task_id, &state).await {
+        let enqueue_result = enqueue_runtime_sync_task(
+            &task_id,
+            &app,
+            &state,
+            Some("Re-queued after reaching the sync time limit".to_string()),
+        )
+        .await;
+        if enqueue_result == RuntimeSyncEnqueueResult::Enqueued {
+            schedule_runtime_sync_dispatcher(app.clone(), state.clone());
+        }
+    }
+    if sync_origin == SyncOrigin::Manual {
+        emit_runtime_sync_state(
+            &app,
+            &task_id,
+            false,
+            sync_event_origin(sync_origin),
+            completion_reason,
+        );
+    }
+    sync_result
+}
+
+async fn runtime_sync_task(task_id: String, app: tauri::AppHandle, state: AppState) {
+    let runtime_config = {
+        let config = state.runtime_config.read().await;
+        config.clone()
+    };
+
+    let task = runtime_find_watch_task(&runtime_config.tasks, &task_id).cloned();
+
+    let Some(task) = task else {
+        return;
+    };
+
+    if !is_runtime_watch_task_active(&task.id, &state).await {
+        return;
+    }
+
+    let resource_group = runtime_task_resource_group(&task);
+    match acquire_runtime_sync_slot(&task.id, &resource_group, &state).await {
+        RuntimeSyncAcquireResult::Acquired => {}
+        RuntimeSyncAcquireResult::AlreadySyncing => {
+            let mut pending = state.runtime_pending_sync_tasks.write().await;
+            pending.insert(task.id.clone());
+            return;
+        }
+        acquire_result @ (RuntimeSyncAcquireResult::CapacityReached
+        | RuntimeSyncAcquireResult::ResourceGroupBusy) => {
+            let reason = if acquire_result == RuntimeSyncAcquireResult::ResourceGroupBusy {
+                format!("Waiting for resource group '{resource_group}'")
+            } else {
+                "Waiting for available sync slot".to_string()
+            };
+            let enqueue_result =
+                enqueue_runtime_sync_task(&task.id, &app, &state, Some(reason)).await;
+
+            if enqueue_result == RuntimeSyncEnqueueResult::Enqueued {
+                schedule_runtime_sync_dispatcher(app.clone(), state.clone());
+            }
+            return;
+        }
+    }
+
+    emit_runtime_sync_state(
+        &app,
+        &task.id,
+        true,
+        sync_event_origin(SyncOrigin::Watch),
+        None,
+    );
+
+    if let Err(error) = activate_task_path_access(&task.id, &state).await {
+        state.log_manager.log(
+            "error",
+            &format!("Sandbox access failed: {error}"),
+            Some(task.id.clone()),
+        );
+        emit_runtime_sync_state(
+            &app,
+            &task.id,
+            false,
+            sync_event_origin(SyncOrigin::Watch),
+            Some(error),
+        );
+        schedule_runtime_sync_dispatcher(app, state);
+        return;
+    }
+
+    let exclude_patterns = resolve_runtime_exclude_patterns(&task, &runtime_config.exclusion_sets);
+    let result = execute_sync_internal(
+        task.id.clone(),
+        task.name.clone(),
+        PathBuf::from(task.source.clone()),
+        PathBuf::from(task.target.clone()),
+        task.checksum_mode,
+        task.verify_after_copy,
+        exclude_patterns,
+        app.clone(),
+        state.clone(),
+        true,
+        SyncOrigin::Watch,
+        None,
+        None,
+        None,
+        None,
+        SyncRunOptions::default(),
+    )
+    .await;
+
+    if let Ok(exec_result) = &result {
+        let auto_unmount_session_disabled =
+            is_auto_unmount_session_disabled_internal(&task.id, &state).await;
+        let effective_auto_unmount = task.auto_unmount && !auto_unmount_session_disabled;
+
+        match decide_runtime_auto_unmount(
+            effective_auto_unmount,
+            exec_result.has_pending_conflicts,
+            exec_result.sync_result.files_copied,
+        ) {
+            RuntimeAutoUnmountDecision::SkipDisabled => {
+                if task.auto_unmount && auto_unmount_session_disabled {
+                    state.log_manager.log_with_category(
+                        "info",
+                        "Auto unmount skipped for this session (user declined)",
+                        Some(task.id.clone()),
+                        LogCategory::Other,
+                    );
+                }
+            }
+            RuntimeAutoUnmountDecision::SkipDueConflicts => {
+                state.log_manager.log_with_category(
+                    "warning",
+                    "Auto unmount skipped because conflict review is pending",
+                    Some(task.id.clone()),
+                    LogCategory::Other,
+                );
+            }
+            RuntimeAutoUnmountDecision::RequestConfirmation => {
+                state.log_manager.log_with_category(
+                    "info",
+                    "Auto unmount deferred: no files were copied, waiting for user confirmation",
+                    Some(task.id.clone()),
+                    LogCategory::Other,
+                );
+                emit_runtime_auto_unmount_request(
+                    &app,
+                    &task.id,
+                    &task.name,
+                    &task.source,
+                    exec_result.sync_result.files_copied,
+                    exec_result.sync_result.bytes_copied,
+                    "zero-copy",
+                );
+            }
+            RuntimeAutoUnmountDecision::UnmountNow => {
+                if let Ok(source_path) = resolve_path_with_uuid(&task.source) {
+                    if let Err(err) = DiskMonitor::unmount_volume(&source_path) {
+                        state.log_manager.log(
+                            "warning",
+                            &format!("Auto unmount failed: {}", err),
+                            Some(task.id.clone()),
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    let reason = result.err().map(|error| error.error_detail);
+    emit_runtime_sync_state(
+        &app,
+        &task.id,
+        false,
+        sync_event_origin(SyncOrigin::Watch),
+        reason,
+    );
+
+    let should_replay = take_runtime_pending_sync_task(&task.id, &state).await;
+    if should_replay && is_runtime_watch_task_active(&task.id, &state).await {
+        let replay_result = enqueue_runtime_sync_task(
+            &task.id,
+            &app,
+            &state,
+            Some("Replay once for watch events detected during sync".to_string()),
+        )
+        .await;
+        if replay_result == RuntimeSyncEnqueueResult::Enqueued {
+            schedule_runtime_sync_dispatcher(app.clone(), state.clone());
+        }
+    }
+
+    schedule_runtime_sync_dispatcher(app, state);
+}
+
+/// .DS_Store 같은 메타데이터 파일만 바뀐 배치면 동기화를 건너뜁니다. 계속 반복되면 제외 패턴 추가를 제안합니다.
+async fn should_suppress_noisy_watch_batch(
+    task_id: &str,
+    paths: &[PathBuf],
+    state: &AppState,
+) -> bool {
+    let noise_names = state
+        .runtime_config
+        .read()
+        .await
+        .settings
+        .effective_watch_noise_names();
+    let noise_only = watcher::is_noise_only_batch(paths, &noise_names);
+    let decision = state
+        .watch_noise_trackers
+        .lock()
+        .unwrap()
+        .entry(task_id.to_string())
+        .or_default()
+        .observe(noise_only, Instant::now());
+
+    match decision {
+        WatchNoiseDecision::Trigger => false,
+        WatchNoiseDecision::Suppress { suggest_exclusion } => {
+            if suggest_exclusion {
+                state.log_manager.log_with_category(
+                    "warning",
+                    &format!(
+                        "Watch events keep touching only Finder/Spotlight metadata ({}); add them to an exclusion set to stop this noise.",
+                        noise_names.join(", ")
+                    ),
+                    Some(task_id.to_string()),
+                    LogCategory::Other,
+                );
+            }
+            true
+        }
+    }
+}
+
+async fn start_watch_internal(
+    task_id: String,
+    source_path: PathBuf,
+    app: tauri::AppHandle,
+    state: AppState,
+    runtime_owned: bool,
+) -> Result<PathBuf, String> {
+    activate_task_path_access(&task_id, &state).await?;
+    let source_path =
+        resolve_path_with_uuid(source_path.to_str().unwrap_or("")).map_err(|e| e.to_string())?;
+
+    // Validate inputs
+    input_validation::validate_task_id(&task_id).map_err(|e| e.to_string())?;
+    input_validation::validate_path_argument(source_path.to_str().unwrap_or(""))
+        .map_err(|e| e.to_string())?;
+
+    let max_coalesce = {
+        let config = state.runtime_config.read().await;
+        config
+            .tasks
+            .iter()
+            .find(|task| task.id == task_id)
+            .and_then(|task| task.max_coalesce_secs)
+            .filter(|secs| *secs > 0)
+            .unwrap_or(watcher::DEFAULT_MAX_COALESCE_SECS)
+    };
+    let task_id_clone = task_id.clone();
+    let app_clone = app.clone();
+    let state_clone = state.clone();
+
+    let mut manager = state.watcher_manager.write().await;
+    manager
+        .start_watching_with_max_coalesce(
+            task_id.clone(),
+            source_path.clone(),
+            Duration::from_secs(max_coalesce),
+            move |event| {
+                // 변경 감지 시 프론트엔드에 이벤트 전송
+                let watch_event = WatchEvent::from_notify_event(task_id_clone.clone(), &event);
+                let _ = app_clone.emit("watch-event", &watch_event);
+
+                if runtime_owned {
+                    let app_for_sync = app_clone.clone();
+                    let state_for_sync = state_clone.clone();
+                    let task_id_for_sync = task_id_clone.clone();
+                    let changed_paths = event.paths.clone();
+                    tauri::async_runtime::spawn(async move {
+                        if should_suppress_noisy_watch_batch(
+                            &task_id_for_sync,
+                            &changed_paths,
+                            &state_for_sync,
+                        )
+                        .await
+                        {
+                            return;
+                        }
+                        let enqueue_result = enqueue_runtime_sync_task(
+                            &task_id_for_sync,
+                            &app_for_sync,
+                            &state_for_sync,
+                            Some("Triggered by watch event".to_string()),
+                        )
+                        .await;
+
+                        if enqueue_result == RuntimeSyncEnqueueResult::Enqueued {
+                            schedule_runtime_sync_dispatcher(app_for_sync, state_for_sync);
+                        }
+                    });
+                }
+            },
+        )
+        .map_err(|e| format!("{}:{}", error_codes::ERR_WATCH_START_FAILED, e))?;
+
+    state.log_manager.log_with_category(
+        "info",
+        &format!("Watch started: {}", source_path.display()),
+        Some(task_id),
+        LogCategory::WatchStarted,
+    );
+
+    Ok(source_path)
+}
+
+async fn reconcile_runtime_watchers(app: tauri::AppHandle, state: AppState) -> Result<(), String> {
+    let runtime_config = {
+        let config = state.runtime_config.read().await;
+        config.clone()
+    };
+
+    // Validate payload before applying runtime changes.
+    validate_runtime_tasks(&runtime_config.tasks)?;
+    for set in &runtime_config.exclusion_sets {
+        input_validation::validate_exclude_patterns(&set.patterns).map_err(|e| e.to_string())?;
+    }
+
+    let mut desired = runtime_desired_watch_sources(&runtime_config.tasks);
+    let over_limit = runtime_watch_tasks_over_limit(
+        &runtime_config.tasks,
+        runtime_config.settings.effective_max_watchers(),
+    );
+    for task_id in &over_limit {
+        desired.remove(task_id);
+    }
+    if !over_limit.is_empty() {
+        state.log_manager.log(
+            "warning",
+            &format!(
+                "{WATCHER_LIMIT_REACHED_REASON}: {} watch task(s) left unwatched (limit {})",
+                over_limit.len(),
+                runtime_config.settings.effective_max_watchers()
+            ),
+            None,
+        );
+    }
+
+    let watching_now: HashSet<String> = {
+        let manager = state.watcher_manager.read().await;
+        manager.get_watching_tasks().into_iter().collect()
+    };
+    let watching_source_paths = {
+        let manager = state.watcher_manager.read().await;
+        manager.get_watching_task_sources()
+    };
+
+    let managed_sources = {
+        let sources = state.runtime_watch_sources.read().await;
+        sources.clone()
+    };
+    let allow_restarted_watch_bootstrap = state
+        .runtime_initial_watch_bootstrapped
+        .load(Ordering::SeqCst);
+    let restart_task_ids = runtime_watch_restart_task_ids(
+        &desired,
+        &managed_sources,
+        &watching_now,
+        &watching_source_paths,
+    );
+    let mut restarted_watch_task_ids = Vec::new();
+
+    // Start or restart desired watchers.
+    for task_id in restart_task_ids {
+        let Some(source) = desired.get(&task_id) else {
+            continue;
+        };
+        match start_watch_internal(
+            task_id.clone(),
+            PathBuf::from(source),
+            app.clone(),
+            state.clone(),
+            true,
+        )
+        .await
+        {
+            Ok(_) => {
+                {
+                    let mut sources = state.runtime_watch_sources.write().await;
+                    sources.insert(task_id.clone(), source.clone());
+                }
+                if allow_restarted_watch_bootstrap {
+                    restarted_watch_task_ids.push(task_id.clone());
+                }
+                emit_runtime_watch_state(&app, &task_id, true, None);
+            }
+            Err(err) => {
+                {
+                    let mut sources = state.runtime_watch_sources.write().await;
+                    sources.remove(&task_id);
+                }
+                emit_runtime_watch_state(&app, &task_id, false, Some(err));
+            }
+        }
+    }
+
+    if allow_restarted_watch_bootstrap && {
+        let enqueued_task_ids =
+            enqueue_runtime_watch_bootstrap_tasks(&restarted_watch_task_ids, &state).await;
+        if enqueued_task_ids.is_empty() {
+            false
+        } else {
+            emit_runtime_watch_bootstrap_queue_state(
+                &enqueued_task_ids,
+                "Initial sync after watch activation",
+                &app,
+            );
+            true
+        }
+    } {
+        schedule_runtime_sync_dispatcher(app.clone(), state.clone());
+    }
+
+    // Stop watchers no longer managed by runtime config.
+    for task_id in managed_sources.keys() {
+        if desired.contains_key(task_id) {
+            continue;
+        }
+
+        let stop_result = {
+            let mut manager = state.watcher_manager.write().await;
+            manager
+                .stop_watching(task_id)
+                .map_err(|e| format!("{}:{}", error_codes::ERR_WATCH_STOP_FAILED, e))
+        };
+
+        match stop_result {
+            Ok(()) => {
+                state.log_manager.log_with_category(
+                    "info",
+                    "Watch stopped",
+                    Some(task_id.clone()),
+                    LogCategory::WatchStopped,
+                );
+                {
+                    let mut sources = state.runtime_watch_sources.write().await;
+                    sources.remove(task_id);
+                }
+                {
+                    remove_runtime_sync_task_state(task_id, &state).await;
+                }
+                emit_runtime_sync_queue_state(
+                    &app,
+                    task_id,
+                    false,
+                    Some("Watch task removed".to_string()),
+                );
+                let reason = over_limit
+                    .contains(task_id)
+                    .then(|| WATCHER_LIMIT_REACHED_REASON.to_string());
+                emit_runtime_watch_state(&app, task_id, false, reason);
+            }
+            Err(err) => {
+                emit_runtime_watch_state(&app, task_id, true, Some(err));
+            }
+        }
+    }
+
+    for task_id in &over_limit {
+        if !managed_sources.contains_key(task_id) {
+            emit_runtime_watch_state(
+                &app,
+                task_id,
+                false,
+                Some(WATCHER_LIMIT_REACHED_REASON.to_string()),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// 앱 시작 직후 watch 태스크 초기 동기화를 큐잉하고, 나중에 취소할 수 있도록 표시함.
+/// `bootstrap_initial_sync`가 꺼져 있으면 아무것도 큐잉하지 않음
+async fn enqueue_initial_runtime_watch_bootstrap_tasks(
+    runtime_config: &RuntimeConfigPayload,
+    state: &AppState,
+) -> Vec<String> {
+    if !runtime_config.settings.bootstrap_initial_sync {
+        return Vec::new();
+    }
+
+    let enqueued_task_ids = enqueue_runtime_watch_bootstrap_tasks(
+        &runtime_watch_bootstrap_task_ids(&runtime_config.tasks),
+        state,
+    )
+    .await;
+    state
+        .runtime_bootstrap_queued_tasks
+        .write()
+        .await
+        .extend(enqueued_task_ids.iter().cloned());
+    enqueued_task_ids
+}
+
+/// 아직 대기 중인 초기 동기화만 큐에서 제거함. 실행 중이거나 watch 이벤트로 큐잉된 항목은 유지
+async fn cancel_bootstrap_syncs_internal(state: &AppState) -> Vec<String> {
+    let bootstrap_task_ids =
+        std::mem::take(&mut *state.runtime_bootstrap_queued_tasks.write().await);
+    if bootstrap_task_ids.is_empty() {
+        return Vec::new();
+    }
+
+    let mut queued = state.queued_sync_tasks.write().await;
+    let mut queue = state.runtime_sync_queue.write().await;
+    let mut cancelled = Vec::new();
+    queue.retain(|task_id| {
+        if bootstrap_task_ids.contains(task_id) {
+            queued.remove(task_id);
+            cancelled.push(task_id.clone());
+            false
+        } else {
+            true
+        }
+    });
+    cancelled
+}
+
+async fn enqueue_initial_runtime_watch_syncs(app: tauri::AppHandle, state: AppState) {
+    let runtime_config = {
+        let config = state.runtime_config.read().await;
+        config.clone()
+    };
+
+    let enqueued_task_ids =
+        enqueue_initial_runtime_watch_bootstrap_tasks(&runtime_config, &state).await;
+
+    if !enqueued_task_ids.is_empty() {
+        emit_runtime_watch_bootstrap_queue_state(
+            &enqueued_task_ids,
+            "Initial sync after runtime initialization",
+            &app,
+        );
+        schedule_runtime_sync_dispatcher(app, state);
+    }
+}
+
+#[tauri::command]
+async fn get_app_config_dir(state: tauri::State<'_, AppState>) -> Result<String, String> {
+    let config_dir = state.config_store.config_dir().to_path_buf();
+    tokio::fs::create_dir_all(&config_dir)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(config_dir.to_string_lossy().to_string())
+}
+
+#[tauri::command]
+async fn join_paths(path1: String, path2: String) -> Result<String, String> {
+    use std::path::Path;
+    let p1 = Path::new(&path1);
+    let p2 = Path::new(&path2);
+    p1.join(p2)
+        .to_str()
+        .ok_or_else(|| "Invalid path".to_string())
+        .map(|s| s.to_string())
+}
+
+#[tauri::command]
+fn greet(name: &str) -> String {
+    format!("Hello, {name}! You've been greeted from Rust!")
+}
+
+#[tauri::command]
+fn get_app_version() -> String {
+    env!("CARGO_PKG_VERSION").to_string()
+}
+
+#[tauri::command]
+#[allow(dead_code)]
+async fn get_app_data_dir(app: tauri::AppHandle) -> Result<String, String> {
+    config_store::app_support_dir_for_app(&app)
+        .map(|p| p.to_string_lossy().to_string())
+        .map_err(config_store_error_to_string)
+}
+
+#[tauri::command]
+async fn get_settings(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> Result<SettingsEnvelope, String> {
+    let settings = current_settings_snapshot(&app, state.inner()).await?;
+    Ok(SettingsEnvelope { settings })
+}
+
+#[tauri::command]
+async fn get_mcp_stdio_config_example(
+    state: tauri::State<'_, AppState>,
+) -> Result<McpStdioConfigExample, String> {
+    let command = std::env::current_exe()
+        .map_err(|error| format!("Failed to resolve current executable path: {error}"))?;
+    let auth_token = current_mcp_auth_token(state.inner())?;
+    Ok(build_mcp_stdio_config_example(command, auth_token))
+}
+
+#[tauri::command]
+async fn regenerate_mcp_auth_token(
+    state: tauri::State<'_, AppState>,
+) -> Result<McpStdioConfigExample, String> {
+    let command = std::env::current_exe()
+        .map_err(|error| format!("Failed to resolve current executable path: {error}"))?;
+    let settings = state
+        .config_store
+        .regenerate_mcp_auth_token()
+        .map_err(config_store_error_to_string)?;
+    let auth_token = settings.mcp_auth_token.ok_or_else(|| {
+        "Failed to regenerate MCP auth token. Try again from SyncWatcher Settings.".to_string()
+    })?;
+    Ok(build_mcp_stdio_config_example(command, auth_token))
+}
+
+#[tauri::command]
+async fn get_distribution_info(app: tauri::AppHandle) -> Result<DistributionInfo, String> {
+    Ok(distribution::distribution_info(
+        &app,
+        security_scoped::legacy_import_available(&app),
+    ))
+}
+
+#[tauri::command]
+async fn get_supporter_status(app: tauri::AppHandle) -> Result<supporter::SupporterStatus, String> {
+    supporter::get_supporter_status_for_app(app).await
+}
+
+#[tauri::command]
+async fn refresh_supporter_status(
+    app: tauri::AppHandle,
+) -> Result<supporter::SupporterStatus, String> {
+    supporter::refresh_supporter_status_for_app(app).await
+}
+
+#[tauri::command]
+async fn get_owner_license_debug_snapshot(
+    app: tauri::AppHandle,
+) -> Result<OwnerLicenseDebugSnapshot, String> {
+    let access = owner_license_debug_access_for_app(&app)?;
+    let app_support_dir =
+        config_store::app_support_dir_for_app(&app).map_err(config_store_error_to_string)?;
+    let distribution =
+        distribution::distribution_info(&app, security_scoped::legacy_import_available(&app));
+    let cached_supporter_status = supporter::get_supporter_status_for_app(app.clone()).await?;
+    let cached_license_status = license_validation::get_license_status(app.clone()).await?;
+    let license_state_path = license_validation::debug_license_state_path(&app)?;
+    let cached_license_state = license_validation::debug_load_license_state(&app);
+    let refresh_supporter_status =
+        match supporter::refresh_supporter_status_for_app(app.clone()).await {
+            Ok(status) => OwnerLicenseRefreshSnapshot {
+                ok: true,
+                status: Some(status),
+                error: None,
+            },
+            Err(error) => OwnerLicenseRefreshSnapshot {
+                ok: false,
+                status: None,
+                error: Some(error),
+            },
+        };
+
+    Ok(OwnerLicenseDebugSnapshot {
+        app_support_dir: app_support_dir.to_string_lossy().into_owned(),
+        marker_path: access.marker_path.to_string_lossy().into_owned(),
+        license_state_path: license_state_path.to_string_lossy().into_owned(),
+        distribution,
+        cached_supporter_status,
+        cached_license_status,
+        cached_license_state,
+        refresh_supporter_status,
+    })
+}
+
+#[tauri::command]
+async fn purchase_lifetime_supporter(
+    app: tauri::AppHandle,
+) -> Result<supporter::SupporterPurchaseResponse, String> {
+    supporter::purchase_supporter_for_app(app).await
+}
+
+#[tauri::command]
+async fn restore_lifetime_supporter(
+    app: tauri::AppHandle,
+) -> Result<supporter::SupporterPurchaseResponse, String> {
+    supporter::restore_supporter_for_app(app).await
+}
+
+#[tauri::command]
+async fn capture_path_access(
+    app: tauri::AppHandle,
+    path: String,
+) -> Result<CapturedPathAccess, String> {
+    security_scoped::capture_path_access(&app, path)
+}
+
+#[tauri::command]
+async fn capture_network_mount(path: String) -> Result<Option<NetworkMountCapturePayload>, String> {
+    network_mount::capture_from_path(&path)
+}
+
+#[tauri::command]
+async fn import_legacy_channel_data(app: tauri::AppHandle) -> Result<LegacyImportStatus, String> {
+    let result = security_scoped::import_legacy_channel_data(&app)?;
+    if result.imported {
+        emit_config_store_changed(&app, &["settings", "syncTasks", "exclusionSets"]);
+        let state = app.state::<AppState>();
+        let _ = apply_canonical_config_to_runtime(app.clone(), state.inner().clone()).await?;
+    }
+    Ok(result)
+}
+
+#[tauri::command]
+async fn check_app_store_update(
+    app: tauri::AppHandle,
+) -> Result<AppStoreUpdateCheckResult, String> {
+    Ok(distribution::check_app_store_update(&app).await)
+}
+
+#[tauri::command]
+async fn set_launch_at_login(
+    enabled: bool,
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> Result<SettingsEnvelope, String> {
+    if enabled {
+        app.autolaunch()
+            .enable()
+            .map_err(|error| format!("Failed to enable launch at login: {error}"))?;
+    } else {
+        app.autolaunch()
+            .disable()
+            .map_err(|error| format!("Failed to disable launch at login: {error}"))?;
+    }
+
+    let settings = current_settings_snapshot(&app, state.inner()).await?;
+    Ok(SettingsEnvelope { settings })
+}
+
+#[tauri::command]
+async fn update_settings(
+    updates: UpdateSettingsPayload,
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> Result<SettingsEnvelope, String> {
+    let mut settings = state
+        .config_store
+        .load_settings()
+        .map_err(config_store_error_to_string)?;
+    updates.apply_to(&mut settings);
+    validate_settings_record(&settings)?;
+    state
+        .config_store
+        .save_settings(&settings)
+        .map_err(config_store_error_to_string)?;
+    emit_config_store_changed(&app, &["settings"]);
+    let _ = apply_canonical_config_to_runtime(app.clone(), state.inner().clone()).await?;
+    sync_control_plane_listener(app.clone(), state.inner().clone(), settings.mcp_enabled).await?;
+    let settings = current_settings_snapshot(&app, state.inner()).await?;
+    Ok(SettingsEnvelope { settings })
+}
+
+#[tauri::command]
+async fn reset_settings(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> Result<SettingsEnvelope, String> {
+    app.autolaunch()
+        .disable()
+        .map_err(|error| format!("Failed to disable launch at login: {error}"))?;
+    state
+        .config_store
+        .reset_settings()
+        .map_err(config_store_error_to_string)?;
+    emit_config_store_changed(&app, &["settings"]);
+    let _ = apply_canonical_config_to_runtime(app.clone(), state.inner().clone()).await?;
+    sync_control_plane_listener(app.clone(), state.inner().clone(), false).await?;
+    let settings = current_settings_snapshot(&app, state.inner()).await?;
+    Ok(SettingsEnvelope { settings })
+}
+
+#[tauri::command]
+async fn list_exclusion_sets(
+    state: tauri::State<'_, AppState>,
+) -> Result<ExclusionSetsEnvelope, String> {
+    let sets = state
+        .config_store
+        .load_exclusion_sets()
+        .map_err(config_store_error_to_string)?;
+    Ok(ExclusionSetsEnvelope { sets })
+}
+
+#[tauri::command]
+async fn create_exclusion_set(
+    set: ExclusionSetRecord,
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> Result<ExclusionSetEnvelope, String> {
+    input_validation::validate_task_id(&set.id).map_err(|e| e.to_string())?;
+    input_validation::validate_exclude_patterns(&set.patterns).map_err(|e| e.to_string())?;
+
+    let mut sets = state
+        .config_store
+        .load_exclusion_sets()
+        .map_err(config_store_error_to_string)?;
+    if sets.iter().any(|candidate| candidate.id == set.id) {
+        return Err(format!("Exclusion set already exists: {}", set.id));
+    }
+    sets.push(set.clone());
+    validate_exclusion_sets(&sets).map_err(config_store_error_to_string)?;
+    state
+        .config_store
+        .save_exclusion_sets(&sets)
+        .map_err(config_store_error_to_string)?;
+    emit_config_store_changed(&app, &["exclusionSets"]);
+    let _ = apply_canonical_config_to_runtime(app.clone(), state.inner().clone()).await?;
+    Ok(ExclusionSetEnvelope { set })
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ExclusionSetUpdatePayload {
+    name: Option<String>,
+    patterns: Option<Vec<String>>,
+}
+
+#[tauri::command]
+async fn update_exclusion_set(
+    id: String,
+    updates: ExclusionSetUpdatePayload,
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> Result<ExclusionSetEnvelope, String> {
+    input_validation::validate_task_id(&id).map_err(|e| e.to_string())?;
+    if let Some(patterns) = &updates.patterns {
+        input_validation::validate_exclude_patterns(patterns).map_err(|e| e.to_string())?;
+    }
+
+    let mut sets = state
+        .config_store
+        .load_exclusion_sets()
+        .map_err(config_store_error_to_string)?;
+    let Some(existing_index) = sets.iter().position(|set| set.id == id) else {
+        return Err(format!("Exclusion set not found: {id}"));
+    };
+    let mut set = sets[existing_index].clone();
+    if let Some(name) = updates.name {
+        set.name = name;
+    }
+    if let Some(patterns) = updates.patterns {
+        set.patterns = patterns;
+    }
+    sets[existing_index] = set.clone();
+    validate_exclusion_sets(&sets).map_err(config_store_error_to_string)?;
+    state
+        .config_store
+        .save_exclusion_sets(&sets)
+        .map_err(config_store_error_to_string)?;
+    emit_config_store_changed(&app, &["exclusionSets"]);
+    let _ = apply_canonical_config_to_runtime(app.clone(), state.inner().clone()).await?;
+    Ok(ExclusionSetEnvelope { set })
+}
+
+/// 저장소에서 세트를 지우고, 실제로 지워졌는지 돌려줌 (없는 id면 저장하지 않음)
+fn remove_exclusion_set_internal(id: &str, state: &AppState) -> Result<bool, String> {
+    let mut sets = state
+        .config_store
+        .load_exclusion_sets()
+        .map_err(config_store_error_to_string)?;
+    let before_len = sets.len();
+    sets.retain(|set| set.id != id);
+    let deleted = sets.len() != before_len;
+    if deleted {
+        state
+            .config_store
+            .save_exclusion_sets(&sets)
+            .map_err(config_store_error_to_string)?;
+    }
+    Ok(deleted)
+}
+
+#[tauri::command]
+async fn delete_exclusion_set(
+    id: String,
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> Result<DeleteResultEnvelope, String> {
+    input_validation::validate_task_id(&id).map_err(|e| e.to_string())?;
+    let deleted = remove_exclusion_set_internal(&id, state.inner())?;
+    if deleted {
+        emit_config_store_changed(&app, &["exclusionSets"]);
+        let _ = apply_canonical_config_to_runtime(app.clone(), state.inner().clone()).await?;
+    }
+    Ok(DeleteResultEnvelope { deleted })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ExclusionSetTaskReference {
+    task_id: String,
+    task_name: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GuardedExclusionSetDeleteResult {
+    deleted: bool,
+    /// 삭제 시점에 이 세트를 참조하던 태스크 (강제 삭제 시에도 채워짐)
+    referenced_by: Vec<ExclusionSetTaskReference>,
+}
+
+async fn tasks_using_exclusion_set_internal(
+    set_id: &str,
+    state: &AppState,
+) -> Vec<ExclusionSetTaskReference> {
+    let config = state.runtime_config.read().await;
+    config
+        .tasks
+        .iter()
+        .filter(|task| task.exclusion_sets.iter().any(|id| id == set_id))
+        .map(|task| ExclusionSetTaskReference {
+            task_id: task.id.clone(),
+            task_name: task.name.clone(),
+        })
+        .collect()
+}
+
+/// 사용 중인 세트는 `force`가 아니면 삭제하지 않고 참조 태스크만 돌려줌
+async fn delete_exclusion_set_guarded_internal(
+    id: &str,
+    force: bool,
+    state: &AppState,
+) -> Result<GuardedExclusionSetDeleteResult, String> {
+    input_validation::validate_task_id(id).map_err(|e| e.to_string())?;
+    let referenced_by = tasks_using_exclusion_set_internal(id, state).await;
+    if !referenced_by.is_empty() && !force {
+        return Ok(GuardedExclusionSetDeleteResult {
+            deleted: false,
+            referenced_by,
+        });
+    }
+
+    Ok(GuardedExclusionSetDeleteResult {
+        deleted: remove_exclusion_set_internal(id, state)?,
+        referenced_by,
+    })
+}
+
+#[tauri::command]
+async fn tasks_using_exclusion_set(
+    set_id: String,
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<ExclusionSetTaskReference>, String> {
+    input_validation::validate_task_id(&set_id).map_err(|e| e.to_string())?;
+    Ok(tasks_using_exclusion_set_internal(&set_id, state.inner()).await)
+}
+
+#[tauri::command]
+async fn delete_exclusion_set_guarded(
+    id: String,
+    force: Option<bool>,
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> Result<GuardedExclusionSetDeleteResult, String> {
+    let result =
+        delete_exclusion_set_guarded_internal(&id, force.unwrap_or(false), state.inner()).await?;
+    if result.deleted {
+        emit_config_store_changed(&app, &["exclusionSets"]);
+        let _ = apply_canonical_config_to_runtime(app.clone(), state.inner().clone()).await?;
+    }
+    Ok(result)
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PersistedStateIntegrityReport {
+    files: Vec<PersistedFileIntegrity>,
+}
+
+/// 설정 YAML과 라이선스 상태 파일을 파싱해 상태를 보고. 요청 시 손상된 설정 파일을 `.corrupt`로 옮기고
+/// `.bak`(마지막 정상 저장본)에서 복원하며, 복원하지 않는 파일은 그대로 둠. 라이선스 상태는 백업이 없음.
+fn check_persisted_state_integrity_internal(
+    config_store: &ConfigStore,
+    license_state_path: &Path,
+    restore_from_backup: bool,
+) -> PersistedStateIntegrityReport {
+    let mut files = config_store.check_config_file_integrity(restore_from_backup);
+    files.push(config_store::check_persisted_file(
+        "license_state.json",
+        license_state_path,
+        |raw| serde_json::from_str::<license_validation::LicenseState>(raw).map(|_| ()),
+        false,
+    ));
+    PersistedStateIntegrityReport { files }
+}
+
+#[tauri::command]
+async fn check_persisted_state_integrity(
+    restore_from_backup: Option<bool>,
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> Result<PersistedStateIntegrityReport, String> {
+    let license_state_path = license_validation::license_state_path(&app)?;
+    let report = check_persisted_state_integrity_internal(
+        &state.config_store,
+        &license_state_path,
+        restore_from_backup.unwrap_or(false),
+    );
+    let corrupt_count = report
+        .files
+        .iter()
+        .filter(|file| file.status == PersistedFileStatus::Corrupt)
+        .count();
+    if corrupt_count > 0 {
+        state.log_manager.log_with_category(
+            "warning",
+            &format!("Found {corrupt_count} corrupt persisted file(s)"),
+            None,
+            LogCategory::Other,
+        );
+    }
+    if report.files.iter().any(|file| file.restored_from_backup) {
+        emit_config_store_changed(&app, &["settings", "syncTasks", "exclusionSets"]);
+        let _ = apply_canonical_config_to_runtime(app.clone(), state.inner().clone()).await?;
+    }
+    Ok(report)
+}
+
+#[tauri::command]
+async fn reset_exclusion_sets(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> Result<ExclusionSetsEnvelope, String> {
+    let sets = default_exclusion_set_records();
+    validate_exclusion_sets(&sets).map_err(config_store_error_to_string)?;
+    state
+        .config_store
+        .save_exclusion_sets(&sets)
+        .map_err(config_store_error_to_string)?;
+    emit_config_store_changed(&app, &["exclusionSets"]);
+    let _ = apply_canonical_config_to_runtime(app.clone(), state.inner().clone()).await?;
+    Ok(ExclusionSetsEnvelope { sets })
+}
+
+async fn export_exclusion_sets_internal(path: &Path, state: &AppState) -> Result<usize, String> {
+    input_validation::validate_path_argument(&path.to_string_lossy()).map_err(|e| e.to_string())?;
+    let sets = state
+        .config_store
+        .load_exclusion_sets()
+        .map_err(config_store_error_to_string)?;
+    let content = serde_json::to_string_pretty(&sets).map_err(|e| e.to_string())?;
+    tokio::fs::write(path, content)
+        .await
+        .map_err(|e| format!("Failed to write exclusion sets: {e}"))?;
+    Ok(sets.len())
+}
 
-            {
-                let mut tokens = state.cancel_tokens.write().await;
-                tokens.remove(&task_id_clone);
-            }
+/// `merge`가 true이면 id 기준으로 합치고(가져온 쪽 우선, 기존 순서 유지), false이면 전체를 교체합니다.
+fn merge_imported_exclusion_sets(
+    existing: Vec<ExclusionSetRecord>,
+    imported: Vec<ExclusionSetRecord>,
+    merge: bool,
+) -> Vec<ExclusionSetRecord> {
+    if !merge {
+        return imported;
+    }
 
-            operation_result
+    let mut merged = existing;
+    for set in imported {
+        match merged.iter_mut().find(|candidate| candidate.id == set.id) {
+            Some(candidate) => *candidate = set,
+            None => merged.push(set),
         }
-        .await;
+    }
+    merged
+}
 
-        finish_runtime_producer(
-            &producer_id,
-            &target_key,
-            sync_result.is_ok(),
-            Some(&app),
-            &state,
-        )
-        .await;
+/// `sets`에 없는 세트를 참조하는 태스크를 `세트 id (태스크 이름)` 형식으로 돌려줍니다.
+async fn dangling_exclusion_set_references(
+    sets: &[ExclusionSetRecord],
+    state: &AppState,
+) -> Vec<String> {
+    let config = state.runtime_config.read().await;
+    config
+        .tasks
+        .iter()
+        .flat_map(|task| {
+            task.exclusion_sets
+                .iter()
+                .filter(|id| !sets.iter().any(|set| &set.id == *id))
+                .map(move |id| format!("{id} ({})", task.name))
+        })
+        .collect()
+}
 
-        sync_result
+async fn import_exclusion_sets_internal(
+    path: &Path,
+    merge: bool,
+    state: &AppState,
+) -> Result<Vec<ExclusionSetRecord>, String> {
+    input_validation::validate_path_argument(&path.to_string_lossy()).map_err(|e| e.to_string())?;
+    let content = tokio::fs::read_to_string(path)
+        .await
+        .map_err(|e| format!("Failed to read exclusion sets: {e}"))?;
+    let imported: Vec<ExclusionSetRecord> =
+        serde_json::from_str(&content).map_err(|e| format!("Invalid exclusion sets file: {e}"))?;
+
+    let mut seen_ids = HashSet::new();
+    for set in &imported {
+        input_validation::validate_task_id(&set.id).map_err(|e| e.to_string())?;
+        input_validation::validate_exclude_patterns(&set.patterns)
+            .map_err(|e| format!("Invalid exclusion set '{}': {e}", set.name))?;
+        if !seen_ids.insert(set.id.as_str()) {
+            return Err(format!("Duplicate exclusion set id in import: {}", set.id));
+        }
     }
-    .await;
 
-    let completion_reason = sync_result
-        .as_ref()
-        .err()
-        .map(|error| error.error_detail.clone());
-    release_task_operation(&task_id, &state).await;
-    release_sync_slot(&task_id, &state).await;
-    if sync_origin == SyncOrigin::Manual {
-        emit_runtime_sync_state(
-            &app,
-            &task_id,
-            false,
-            sync_event_origin(sync_origin),
-            completion_reason,
-        );
+    let existing = state
+        .config_store
+        .load_exclusion_sets()
+        .map_err(config_store_error_to_string)?;
+    let sets = merge_imported_exclusion_sets(existing, imported, merge);
+    if !merge {
+        // 교체 후에도 태스크가 참조하는 세트가 남아 있어야 함
+        let dangling = dangling_exclusion_set_references(&sets, state).await;
+        if !dangling.is_empty() {
+            return Err(format!(
+                "Import would remove exclusion sets still used by tasks: {}",
+                dangling.join(", ")
+            ));
+        }
     }
-    sync_result
+    validate_exclusion_sets(&sets).map_err(config_store_error_to_string)?;
+    state
+        .config_store
+        .save_exclusion_sets(&sets)
+        .map_err(config_store_error_to_string)?;
+    Ok(sets)
 }
 
-async fn runtime_sync_task(task_id: String, app: tauri::AppHandle, state: AppState) {
-    let runtime_config = {
-        let config = state.runtime_config.read().await;
-        config.clone()
+#[tauri::command]
+async fn export_exclusion_sets(
+    path: String,
+    state: tauri::State<'_, AppState>,
+) -> Result<usize, String> {
+    export_exclusion_sets_internal(Path::new(&path), state.inner()).await
+}
+
+#[tauri::command]
+async fn import_exclusion_sets(
+    path: String,
+    merge: bool,
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> Result<ExclusionSetsEnvelope, String> {
+    let sets = import_exclusion_sets_internal(Path::new(&path), merge, state.inner()).await?;
+    emit_config_store_changed(&app, &["exclusionSets"]);
+    let _ = apply_canonical_config_to_runtime(app.clone(), state.inner().clone()).await?;
+    Ok(ExclusionSetsEnvelope { sets })
+}
+
+/// `export_config`/`import_config`가 주고받는 파일 형식. 설정 저장소의 레코드를 그대로 담습니다.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ConfigExportFile {
+    settings: AppSettings,
+    #[serde(default)]
+    tasks: Vec<SyncTaskRecord>,
+    #[serde(default)]
+    exclusion_sets: Vec<ExclusionSetRecord>,
+}
+
+/// 저장된 설정(태스크·제외 세트·설정)을 YAML로 내보냅니다. 라이선스와 MCP 인증 토큰은 포함되지 않고,
+/// UUID 토큰 소스(`[DISK_UUID:...]/...`)는 그대로 기록되어 다른 기기에서도 같은 디스크를 찾습니다.
+async fn export_config_internal(path: &Path, state: &AppState) -> Result<usize, String> {
+    input_validation::validate_path_argument(&path.to_string_lossy()).map_err(|e| e.to_string())?;
+    let (mut settings, tasks, exclusion_sets) = load_canonical_config(state).await?;
+    settings.mcp_auth_token = None;
+    let file = ConfigExportFile {
+        settings,
+        tasks,
+        exclusion_sets,
     };
+    let content = serde_yaml::to_string(&file).map_err(|e| e.to_string())?;
+    tokio::fs::write(path, content)
+        .await
+        .map_err(|e| format!("Failed to write config: {e}"))?;
+    Ok(file.tasks.len())
+}
+
+/// `merge`가 true이면 태스크·제외 세트를 id 기준으로 합치고(가져온 쪽 우선) 현재 설정값을 유지하며,
+/// false이면 가져온 설정으로 전체를 교체합니다. 교체할 때도 MCP 인증 토큰과 상태 저장 위치는
+/// 이 기기의 값을 유지합니다.
+fn merge_imported_config(
+    existing: ConfigExportFile,
+    imported: ConfigExportFile,
+    merge: bool,
+) -> ConfigExportFile {
+    if !merge {
+        let mut replaced = imported;
+        replaced.settings.mcp_auth_token = existing.settings.mcp_auth_token;
+        replaced.settings.state_location = existing.settings.state_location;
+        replaced.settings.state_location_bookmark = existing.settings.state_location_bookmark;
+        return replaced;
+    }
+
+    let mut merged = existing;
+    for task in imported.tasks {
+        match merged
+            .tasks
+            .iter_mut()
+            .find(|candidate| candidate.id == task.id)
+        {
+            Some(candidate) => *candidate = task,
+            None => merged.tasks.push(task),
+        }
+    }
+    for set in imported.exclusion_sets {
+        match merged
+            .exclusion_sets
+            .iter_mut()
+            .find(|candidate| candidate.id == set.id)
+        {
+            Some(candidate) => *candidate = set,
+            None => merged.exclusion_sets.push(set),
+        }
+    }
+    merged
+}
 
-    let task = runtime_find_watch_task(&runtime_config.tasks, &task_id).cloned();
+/// 가져온 설정을 검증한 뒤 설정 저장소에 기록합니다. 런타임 반영은 호출하는 쪽에서
+/// `apply_canonical_config_to_runtime`으로 합니다.
+async fn import_config_internal(
+    path: &Path,
+    merge: bool,
+    state: &AppState,
+) -> Result<ConfigExportFile, String> {
+    input_validation::validate_path_argument(&path.to_string_lossy()).map_err(|e| e.to_string())?;
+    let content = tokio::fs::read_to_string(path)
+        .await
+        .map_err(|e| format!("Failed to read config: {e}"))?;
+    let imported: ConfigExportFile =
+        serde_yaml::from_str(&content).map_err(|e| format!("Invalid config file: {e}"))?;
 
-    let Some(task) = task else {
-        return;
+    let mut seen_ids = HashSet::new();
+    for task in &imported.tasks {
+        if !seen_ids.insert(task.id.as_str()) {
+            return Err(format!("Duplicate task id in import: {}", task.id));
+        }
+    }
+    validate_sync_task_records(&imported.tasks).map_err(|e| format!("Invalid config file: {e}"))?;
+    validate_exclusion_sets(&imported.exclusion_sets).map_err(config_store_error_to_string)?;
+
+    let (settings, tasks, exclusion_sets) = load_canonical_config(state).await?;
+    let existing = ConfigExportFile {
+        settings,
+        tasks,
+        exclusion_sets,
     };
+    let config = merge_imported_config(existing, imported, merge);
+    validate_settings_record(&config.settings).map_err(|e| format!("Invalid config file: {e}"))?;
+    validate_sync_task_records(&config.tasks)
+        .map_err(|e| format!("Imported tasks conflict with existing tasks: {e}"))?;
+    let missing_sets: Vec<String> = config
+        .tasks
+        .iter()
+        .flat_map(|task| {
+            task.exclusion_sets
+                .iter()
+                .filter(|id| !config.exclusion_sets.iter().any(|set| &set.id == *id))
+                .map(move |id| format!("{id} ({})", task.name))
+        })
+        .collect();
+    if !missing_sets.is_empty() {
+        return Err(format!(
+            "Imported tasks use exclusion sets missing from the config: {}",
+            missing_sets.join(", ")
+        ));
+    }
 
-    if !is_runtime_watch_task_active(&task.id, &state).await {
-        return;
+    state
+        .config_store
+        .save_exclusion_sets(&config.exclusion_sets)
+        .map_err(config_store_error_to_string)?;
+    state
+        .config_store
+        .save_tasks(&config.tasks)
+        .map_err(config_store_error_to_string)?;
+    state
+        .config_store
+        .save_settings(&config.settings)
+        .map_err(config_store_error_to_string)?;
+    Ok(config)
+}
+
+#[tauri::command]
+async fn export_config(path: String, state: tauri::State<'_, AppState>) -> Result<usize, String> {
+    export_config_internal(Path::new(&path), state.inner()).await
+}
+
+#[tauri::command]
+async fn import_config(
+    path: String,
+    merge: bool,
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> Result<RuntimeState, String> {
+    import_config_internal(Path::new(&path), merge, state.inner()).await?;
+    emit_config_store_changed(&app, &["settings", "syncTasks", "exclusionSets"]);
+    apply_canonical_config_to_runtime(app, state.inner().clone()).await
+}
+
+#[tauri::command]
+async fn read_yaml_file(path: String, state: tauri::State<'_, AppState>) -> Result<String, String> {
+    let (_, path) = validate_legacy_config_store_file_path(Path::new(&path), &state.config_store)?;
+    tokio::fs::read_to_string(&path)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn write_yaml_file(
+    path: String,
+    content: String,
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> Result<(), String> {
+    let (_, path) = validate_legacy_config_store_file_path(Path::new(&path), &state.config_store)?;
+    tokio::fs::write(&path, content)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    apply_config_write_side_effects(&path, app, state.inner().clone()).await
+}
+
+#[tauri::command]
+async fn read_config_store_file(
+    scope: String,
+    state: tauri::State<'_, AppState>,
+) -> Result<String, String> {
+    let scope = ConfigStoreFileScope::parse(&scope)?;
+    let path = scope.file_path(&state.config_store);
+    match tokio::fs::read_to_string(&path).await {
+        Ok(content) => Ok(content),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(String::new()),
+        Err(error) => Err(error.to_string()),
     }
+}
 
-    match acquire_runtime_sync_slot(&task.id, &state).await {
-        RuntimeSyncAcquireResult::Acquired => {}
-        RuntimeSyncAcquireResult::AlreadySyncing => {
-            let mut pending = state.runtime_pending_sync_tasks.write().await;
-            pending.insert(task.id.clone());
-            return;
+#[tauri::command]
+async fn repair_config_store_file(
+    scope: String,
+    content: String,
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> Result<(), String> {
+    let scope = ConfigStoreFileScope::parse(&scope)?;
+    validate_repaired_config_store_file(scope, &content)?;
+    let content = canonicalize_repaired_config_store_file(scope, &content)?;
+
+    let path = scope.file_path(&state.config_store);
+    state
+        .config_store
+        .write_raw_file_at_path(&path, content.as_bytes())
+        .map_err(config_store_error_to_string)?;
+
+    emit_config_store_changed(&app, &[scope.event_scope()]);
+    let _ = apply_canonical_config_to_runtime(app.clone(), state.inner().clone()).await?;
+
+    if scope == ConfigStoreFileScope::Settings {
+        let settings = state
+            .config_store
+            .load_settings()
+            .map_err(config_store_error_to_string)?;
+        sync_control_plane_listener(app.clone(), state.inner().clone(), settings.mcp_enabled)
+            .await?;
+    }
+
+    Ok(())
+}
+
+async fn sync_dry_run_internal(
+    app: Option<AppHandle>,
+    diff_batch_channel: Option<Channel<DryRunDiffBatchEvent>>,
+    task_id: String,
+    source: PathBuf,
+    target: PathBuf,
+    checksum_mode: bool,
+    exclude_patterns: Vec<String>,
+    run_options: SyncRunOptions,
+    state: &AppState,
+    external_cancel_token: Option<CancellationToken>,
+    mcp_job_id: Option<String>,
+) -> Result<DryRunResult, String> {
+    try_acquire_task_operation(&task_id, TaskOperationKind::DryRun, state).await?;
+    clear_dry_run_artifact(&task_id, state).await;
+    let requested_source = source.to_string_lossy().to_string();
+    let requested_target = target.to_string_lossy().to_string();
+
+    let result: Result<DryRunResult, String> = async {
+        maybe_activate_task_path_access(&task_id, state).await?;
+        let (source, target) =
+            ensure_task_paths_available(&task_id, &source, &target, state).await?;
+        ensure_non_overlapping_paths(&source, &target)?;
+
+        input_validation::validate_task_id(&task_id).map_err(|e| e.to_string())?;
+        input_validation::validate_path_argument(source.to_str().unwrap_or(""))
+            .map_err(|e| e.to_string())?;
+        input_validation::validate_path_argument(target.to_str().unwrap_or(""))
+            .map_err(|e| e.to_string())?;
+        input_validation::validate_exclude_patterns(&exclude_patterns).map_err(|e| e.to_string())?;
+        run_options.validate()?;
+        let target_preflight = preflight_target_path(&target, false).await?;
+
+        {
+            let mut running = state.dry_running_tasks.write().await;
+            running.insert(task_id.clone());
+        }
+        if let Some(app_handle) = app.as_ref() {
+            emit_runtime_dry_run_state(
+                app_handle,
+                &task_id,
+                true,
+                Some("Dry run started".to_string()),
+            );
+        }
+
+        state
+            .log_manager
+            .log("info", "Dry run started", Some(task_id.clone()));
+        if target_preflight.kind == TargetPreflightKind::WillCreateDirectory {
+            state.log_manager.log(
+                "warning",
+                &format!(
+                    "Target directory does not exist yet; dry run is previewing an empty target: {}",
+                    target_preflight.path
+                ),
+                Some(task_id.clone()),
+            );
         }
-        RuntimeSyncAcquireResult::CapacityReached => {
-            let enqueue_result = enqueue_runtime_sync_task(
-                &task.id,
-                &app,
-                &state,
-                Some("Waiting for available sync slot".to_string()),
-            )
-            .await;
 
-            if enqueue_result == RuntimeSyncEnqueueResult::Enqueued {
-                schedule_runtime_sync_dispatcher(app.clone(), state.clone());
+        let min_source_fraction = state
+            .runtime_config
+            .read()
+            .await
+            .settings
+            .effective_orphan_min_source_fraction();
+        let mut engine = SyncEngine::new(source.clone(), target.clone())
+            .with_allow_empty_source(run_options.allow_empty_source)
+            .with_min_source_fraction(min_source_fraction);
+        if run_options.trusts_sync_snapshot(checksum_mode) {
+            if let Some(snapshot) = read_task_sync_snapshot(&task_id, state).await {
+                engine = engine.with_trusted_snapshot(Arc::new(snapshot));
             }
-            return;
         }
-    }
+        let options = build_sync_options(checksum_mode, false, exclude_patterns, &run_options);
 
-    emit_runtime_sync_state(
-        &app,
-        &task.id,
-        true,
-        sync_event_origin(SyncOrigin::Watch),
-        None,
-    );
+        let cancel_token = CancellationToken::new();
+        if let Some(external_cancel_token) = external_cancel_token {
+            let forward_cancel = cancel_token.clone();
+            tauri::async_runtime::spawn(async move {
+                external_cancel_token.cancelled().await;
+                forward_cancel.cancel();
+            });
+        }
 
-    if let Err(error) = activate_task_path_access(&task.id, &state).await {
-        state.log_manager.log(
-            "error",
-            &format!("Sandbox access failed: {error}"),
-            Some(task.id.clone()),
-        );
-        emit_runtime_sync_state(
-            &app,
-            &task.id,
-            false,
-            sync_event_origin(SyncOrigin::Watch),
-            Some(error),
-        );
-        schedule_runtime_sync_dispatcher(app, state);
-        return;
-    }
+        {
+            let mut tokens = state.dry_run_cancel_tokens.write().await;
+            tokens.insert(task_id.clone(), cancel_token.clone());
+        }
 
-    let exclude_patterns = resolve_runtime_exclude_patterns(&task, &runtime_config.exclusion_sets);
-    let result = execute_sync_internal(
-        task.id.clone(),
-        task.name.clone(),
-        PathBuf::from(task.source.clone()),
-        PathBuf::from(task.target.clone()),
-        task.checksum_mode,
-        task.verify_after_copy,
-        exclude_patterns,
-        app.clone(),
-        state.clone(),
-        true,
-        SyncOrigin::Watch,
-        None,
-        None,
-        None,
-        None,
-    )
-    .await;
+        let live_state = DryRunLiveState::new();
+        let progress_target_preflight = target_preflight.clone();
+        let diff_target_preflight = target_preflight.clone();
+        let diff_batch_channel_for_progress = diff_batch_channel.clone();
 
-    if let Ok(exec_result) = &result {
-        let auto_unmount_session_disabled =
-            is_auto_unmount_session_disabled_internal(&task.id, &state).await;
-        let effective_auto_unmount = task.auto_unmount && !auto_unmount_session_disabled;
+        let progress_state = live_state.clone();
+        let progress_app = app.clone();
+        let progress_jobs = state.mcp_jobs.clone();
+        let progress_job_id = mcp_job_id.clone();
+        let progress_task_id = task_id.clone();
+        let progress_emit = move |progress: DryRunProgress| {
+            let now = Instant::now();
+            let (maybe_progress, maybe_batch) = progress_state.record_progress(progress.clone(), now);
 
-        match decide_runtime_auto_unmount(
-            effective_auto_unmount,
-            exec_result.has_pending_conflicts,
-            exec_result.sync_result.files_copied,
-        ) {
-            RuntimeAutoUnmountDecision::SkipDisabled => {
-                if task.auto_unmount && auto_unmount_session_disabled {
-                    state.log_manager.log_with_category(
-                        "info",
-                        "Auto unmount skipped for this session (user declined)",
-                        Some(task.id.clone()),
-                        LogCategory::Other,
+            if let Some((diffs, batch_progress)) = maybe_batch {
+                if let Some(app_handle) = progress_app.as_ref() {
+                    let event = DryRunDiffBatchEvent {
+                        task_id: progress_task_id.clone(),
+                        phase: batch_progress.phase,
+                        message: batch_progress.message.clone(),
+                        summary: batch_progress.summary.clone(),
+                        diffs,
+                        target_preflight: Some(progress_target_preflight.clone()),
+                    };
+                    emit_dry_run_diff_batch(
+                        app_handle,
+                        diff_batch_channel_for_progress.as_ref(),
+                        &event,
                     );
                 }
-            }
-            RuntimeAutoUnmountDecision::SkipDueConflicts => {
-                state.log_manager.log_with_category(
-                    "warning",
-                    "Auto unmount skipped because conflict review is pending",
-                    Some(task.id.clone()),
-                    LogCategory::Other,
-                );
-            }
-            RuntimeAutoUnmountDecision::RequestConfirmation => {
-                state.log_manager.log_with_category(
-                    "info",
-                    "Auto unmount deferred: no files were copied, waiting for user confirmation",
-                    Some(task.id.clone()),
-                    LogCategory::Other,
-                );
-                emit_runtime_auto_unmount_request(
-                    &app,
-                    &task.id,
-                    &task.name,
-                    &task.source,
-                    exec_result.sync_result.files_copied,
-                    exec_result.sync_result.bytes_copied,
-                    "zero-copy",
-                );
-            }
-            RuntimeAutoUnmountDecision::UnmountNow => {
-                if let Ok(source_path) = resolve_path_with_uuid(&task.source) {
-                    if let Err(err) = DiskMonitor::unmount_volume(&source_path) {
-                        state.log_manager.log(
-                            "warning",
-                            &format!("Auto unmount failed: {}", err),
-                            Some(task.id.clone()),
-                        );
-                    }
+
+                if let Some(job_id) = progress_job_id.as_deref() {
+                    progress_jobs.try_update_progress(
+                        job_id,
+                        mcp_progress_from_dry_run(&batch_progress),
+                        unix_now_ms(),
+                    );
                 }
             }
-        }
-    }
-
-    let reason = result.err().map(|error| error.error_detail);
-    emit_runtime_sync_state(
-        &app,
-        &task.id,
-        false,
-        sync_event_origin(SyncOrigin::Watch),
-        reason,
-    );
-
-    let should_replay = take_runtime_pending_sync_task(&task.id, &state).await;
-    if should_replay && is_runtime_watch_task_active(&task.id, &state).await {
-        let replay_result = enqueue_runtime_sync_task(
-            &task.id,
-            &app,
-            &state,
-            Some("Replay once for watch events detected during sync".to_string()),
-        )
-        .await;
-        if replay_result == RuntimeSyncEnqueueResult::Enqueued {
-            schedule_runtime_sync_dispatcher(app.clone(), state.clone());
-        }
-    }
-
-    schedule_runtime_sync_dispatcher(app, state);
-}
-
-async fn start_watch_internal(
-    task_id: String,
-    source_path: PathBuf,
-    app: tauri::AppHandle,
-    state: AppState,
-    runtime_owned: bool,
-) -> Result<PathBuf, String> {
-    activate_task_path_access(&task_id, &state).await?;
-    let source_path =
-        resolve_path_with_uuid(source_path.to_str().unwrap_or("")).map_err(|e| e.to_string())?;
 
-    // Validate inputs
-    input_validation::validate_task_id(&task_id).map_err(|e| e.to_string())?;
-    input_validation::validate_path_argument(source_path.to_str().unwrap_or(""))
-        .map_err(|e| e.to_string())?;
+            if let Some(emitted_progress) = maybe_progress {
+                if let Some(app_handle) = progress_app.as_ref() {
+                    let event = dry_run_progress_event(&progress_task_id, &emitted_progress);
+                    emit_dry_run_progress(app_handle, &event);
+                }
 
-    let task_id_clone = task_id.clone();
-    let app_clone = app.clone();
-    let state_clone = state.clone();
+                if let Some(job_id) = progress_job_id.as_deref() {
+                    progress_jobs.try_update_progress(
+                        job_id,
+                        mcp_progress_from_dry_run(&emitted_progress),
+                        unix_now_ms(),
+                    );
+                }
+            }
+        };
 
-    let mut manager = state.watcher_manager.write().await;
-    manager
-        .start_watching(task_id.clone(), source_path.clone(), move |event| {
-            // 변경 감지 시 프론트엔드에 이벤트 전송
-            let watch_event = WatchEvent::from_notify_event(task_id_clone.clone(), &event);
-            let _ = app_clone.emit("watch-event", &watch_event);
-
-            if runtime_owned {
-                let app_for_sync = app_clone.clone();
-                let state_for_sync = state_clone.clone();
-                let task_id_for_sync = task_id_clone.clone();
-                tauri::async_runtime::spawn(async move {
-                    let enqueue_result = enqueue_runtime_sync_task(
-                        &task_id_for_sync,
-                        &app_for_sync,
-                        &state_for_sync,
-                        Some("Triggered by watch event".to_string()),
-                    )
-                    .await;
+        let diff_state = live_state.clone();
+        let diff_app = app.clone();
+        let diff_batch_channel_for_diff = diff_batch_channel.clone();
+        let diff_jobs = state.mcp_jobs.clone();
+        let diff_job_id = mcp_job_id.clone();
+        let diff_task_id = task_id.clone();
+        let diff_emit = move |diff: FileDiff, progress: DryRunProgress| {
+            let now = Instant::now();
+            if let Some((diffs, batch_progress)) = diff_state.record_diff(diff, progress, now) {
+                if let Some(app_handle) = diff_app.as_ref() {
+                    let event = DryRunDiffBatchEvent {
+                        task_id: diff_task_id.clone(),
+                        phase: batch_progress.phase,
+                        message: batch_progress.message.clone(),
+                        summary: batch_progress.summary.clone(),
+                        diffs,
+                        target_preflight: Some(diff_target_preflight.clone()),
+                    };
+                    emit_dry_run_diff_batch(
+                        app_handle,
+                        diff_batch_channel_for_diff.as_ref(),
+                        &event,
+                    );
+                }
 
-                    if enqueue_result == RuntimeSyncEnqueueResult::Enqueued {
-                        schedule_runtime_sync_dispatcher(app_for_sync, state_for_sync);
-                    }
-                });
+                if let Some(job_id) = diff_job_id.as_deref() {
+                    diff_jobs.try_update_progress(
+                        job_id,
+                        mcp_progress_from_dry_run(&batch_progress),
+                        unix_now_ms(),
+                    );
+                }
             }
-        })
-        .map_err(|e| format!("{}:{}", error_codes::ERR_WATCH_START_FAILED, e))?;
-
-    state.log_manager.log_with_category(
-        "info",
-        &format!("Watch started: {}", source_path.display()),
-        Some(task_id),
-        LogCategory::WatchStarted,
-    );
+        };
 
-    Ok(source_path)
-}
+        let result = match engine
+            .dry_run_with_progress_and_conflicts(
+                &options,
+                cancel_token.clone(),
+                progress_emit,
+                diff_emit,
+            )
+            .await
+        {
+            Ok((mut dry_run, conflicts)) if run_options.include_orphans => engine
+                .find_orphan_files_with_options(&options, Some(cancel_token.clone()))
+                .await
+                .map(|orphans| {
+                    dry_run.orphan_summary = Some(OrphanSummary::from_orphans(&orphans));
+                    dry_run.orphans = Some(orphans);
+                    (dry_run, conflicts)
+                }),
+            other => other,
+        }
+        .map_err(|e| format!("{:#}", e));
 
-async fn reconcile_runtime_watchers(app: tauri::AppHandle, state: AppState) -> Result<(), String> {
-    let runtime_config = {
-        let config = state.runtime_config.read().await;
-        config.clone()
-    };
+        {
+            let mut tokens = state.dry_run_cancel_tokens.write().await;
+            tokens.remove(&task_id);
+        }
 
-    // Validate payload before applying runtime changes.
-    validate_runtime_tasks(&runtime_config.tasks)?;
-    for set in &runtime_config.exclusion_sets {
-        input_validation::validate_exclude_patterns(&set.patterns).map_err(|e| e.to_string())?;
-    }
+        let (result, target_newer_conflicts) = match result {
+            Ok(result) => result,
+            Err(err) => {
+                if let Some(final_progress) = live_state.latest_progress() {
+                    if let Some((diffs, batch_progress)) =
+                        live_state.flush_pending_diffs(final_progress.clone())
+                    {
+                        if let Some(app_handle) = app.as_ref() {
+                            let event = DryRunDiffBatchEvent {
+                                task_id: task_id.clone(),
+                                phase: batch_progress.phase,
+                                message: batch_progress.message.clone(),
+                                summary: batch_progress.summary.clone(),
+                                diffs,
+                                target_preflight: Some(target_preflight.clone()),
+                            };
+                            emit_dry_run_diff_batch(
+                                app_handle,
+                                diff_batch_channel.as_ref(),
+                                &event,
+                            );
+                        }
 
-    let desired = runtime_desired_watch_sources(&runtime_config.tasks);
+                        if let Some(job_id) = mcp_job_id.as_deref() {
+                            state.mcp_jobs.try_update_progress(
+                                job_id,
+                                mcp_progress_from_dry_run(&batch_progress),
+                                unix_now_ms(),
+                            );
+                        }
+                    }
 
-    let watching_now: HashSet<String> = {
-        let manager = state.watcher_manager.read().await;
-        manager.get_watching_tasks().into_iter().collect()
-    };
-    let watching_source_paths = {
-        let manager = state.watcher_manager.read().await;
-        manager.get_watching_task_sources()
-    };
+                    if let Some(app_handle) = app.as_ref() {
+                        let event = dry_run_progress_event(&task_id, &final_progress);
+                        emit_dry_run_progress(app_handle, &event);
+                    }
 
-    let managed_sources = {
-        let sources = state.runtime_watch_sources.read().await;
-        sources.clone()
-    };
-    let allow_restarted_watch_bootstrap = state
-        .runtime_initial_watch_bootstrapped
-        .load(Ordering::SeqCst);
-    let restart_task_ids = runtime_watch_restart_task_ids(
-        &desired,
-        &managed_sources,
-        &watching_now,
-        &watching_source_paths,
-    );
-    let mut restarted_watch_task_ids = Vec::new();
+                    if let Some(job_id) = mcp_job_id.as_deref() {
+                        state.mcp_jobs.try_update_progress(
+                            job_id,
+                            mcp_progress_from_dry_run(&final_progress),
+                            unix_now_ms(),
+                        );
+                    }
+                }
 
-    // Start or restart desired watchers.
-    for task_id in restart_task_ids {
-        let Some(source) = desired.get(&task_id) else {
-            continue;
+                return Err(err);
+            }
         };
-        match start_watch_internal(
-            task_id.clone(),
-            PathBuf::from(source),
-            app.clone(),
-            state.clone(),
-            true,
-        )
-        .await
-        {
-            Ok(_) => {
-                {
-                    let mut sources = state.runtime_watch_sources.write().await;
-                    sources.insert(task_id.clone(), source.clone());
+
+        if let Some(final_progress) = live_state.latest_progress().or_else(|| {
+            Some(DryRunProgress {
+                phase: DryRunPhase::Comparing,
+                message: "Dry run completed".to_string(),
+                current: result.total_files as u64,
+                total: result.total_files as u64,
+                processed_bytes: result.bytes_to_copy,
+                total_bytes: result.bytes_to_copy,
+                summary: DryRunSummary {
+                    total_files: result.total_files,
+                    files_to_copy: result.files_to_copy,
+                    files_modified: result.files_modified,
+                    bytes_to_copy: result.bytes_to_copy,
+                },
+            })
+        }) {
+            if let Some((diffs, batch_progress)) = live_state.flush_pending_diffs(final_progress.clone()) {
+                if let Some(app_handle) = app.as_ref() {
+                    let event = DryRunDiffBatchEvent {
+                        task_id: task_id.clone(),
+                        phase: batch_progress.phase,
+                        message: batch_progress.message.clone(),
+                        summary: batch_progress.summary.clone(),
+                        diffs,
+                        target_preflight: Some(target_preflight.clone()),
+                    };
+                    emit_dry_run_diff_batch(app_handle, diff_batch_channel.as_ref(), &event);
                 }
-                if allow_restarted_watch_bootstrap {
-                    restarted_watch_task_ids.push(task_id.clone());
+
+                if let Some(job_id) = mcp_job_id.as_deref() {
+                    state.mcp_jobs.try_update_progress(
+                        job_id,
+                        mcp_progress_from_dry_run(&batch_progress),
+                        unix_now_ms(),
+                    );
                 }
-                emit_runtime_watch_state(&app, &task_id, true, None);
             }
-            Err(err) => {
-                {
-                    let mut sources = state.runtime_watch_sources.write().await;
-                    sources.remove(&task_id);
-                }
-                emit_runtime_watch_state(&app, &task_id, false, Some(err));
+
+            if let Some(app_handle) = app.as_ref() {
+                let event = dry_run_progress_event(&task_id, &final_progress);
+                emit_dry_run_progress(app_handle, &event);
             }
-        }
-    }
 
-    if allow_restarted_watch_bootstrap && {
-        let enqueued_task_ids =
-            enqueue_runtime_watch_bootstrap_tasks(&restarted_watch_task_ids, &state).await;
-        if enqueued_task_ids.is_empty() {
-            false
-        } else {
-            emit_runtime_watch_bootstrap_queue_state(
-                &enqueued_task_ids,
-                "Initial sync after watch activation",
-                &app,
-            );
-            true
+            if let Some(job_id) = mcp_job_id.as_deref() {
+                state
+                    .mcp_jobs
+                    .try_update_progress(job_id, mcp_progress_from_dry_run(&final_progress), unix_now_ms());
+            }
         }
-    } {
-        schedule_runtime_sync_dispatcher(app.clone(), state.clone());
+
+        let result = {
+            let mut result = result;
+            result.target_preflight = Some(target_preflight.clone());
+            result
+        };
+
+        let mut artifact = build_dry_run_artifact(
+            &requested_source,
+            &requested_target,
+            &source,
+            &target,
+            checksum_mode,
+            &options.exclude_patterns,
+            &result,
+            &target_newer_conflicts,
+        )
+        .await?;
+        artifact.run_options = run_options;
+        store_dry_run_artifact(&task_id, artifact, state).await;
+
+        Ok(result)
     }
+    .await;
 
-    // Stop watchers no longer managed by runtime config.
-    for task_id in managed_sources.keys() {
-        if desired.contains_key(task_id) {
-            continue;
-        }
+    {
+        let mut running = state.dry_running_tasks.write().await;
+        running.remove(&task_id);
+    }
 
-        let stop_result = {
-            let mut manager = state.watcher_manager.write().await;
-            manager
-                .stop_watching(task_id)
-                .map_err(|e| format!("{}:{}", error_codes::ERR_WATCH_STOP_FAILED, e))
-        };
+    release_task_operation(&task_id, state).await;
 
-        match stop_result {
-            Ok(()) => {
-                state.log_manager.log_with_category(
-                    "info",
-                    "Watch stopped",
+    match result {
+        Ok(result) => {
+            let unit_system = state.runtime_config.read().await.settings.data_unit_system;
+            let msg = format!(
+                "Dry run completed.\nTo copy: {} files\nTotal size: {}",
+                format_number(result.files_to_copy as u64),
+                format_bytes_with_unit(result.bytes_to_copy, unit_system)
+            );
+            state
+                .log_manager
+                .log("success", &msg, Some(task_id.clone()));
+            if let Some(app_handle) = app.as_ref() {
+                emit_runtime_dry_run_state(
+                    app_handle,
+                    &task_id,
+                    false,
+                    Some("Dry run completed".to_string()),
+                );
+            }
+            Ok(result)
+        }
+        Err(e) => {
+            let err_text = format!("{:#}", e);
+            if err_text.contains("cancelled by user") {
+                state.log_manager.log(
+                    "warning",
+                    "Dry run cancelled by user",
                     Some(task_id.clone()),
-                    LogCategory::WatchStopped,
                 );
-                {
-                    let mut sources = state.runtime_watch_sources.write().await;
-                    sources.remove(task_id);
+                if let Some(app_handle) = app.as_ref() {
+                    emit_runtime_dry_run_state(
+                        app_handle,
+                        &task_id,
+                        false,
+                        Some("Dry run cancelled by user".to_string()),
+                    );
                 }
-                {
-                    remove_runtime_sync_task_state(task_id, &state).await;
+                Err("Dry run cancelled by user".to_string())
+            } else {
+                let msg = format!("Dry run failed: {err_text}");
+                state.log_manager.log("error", &msg, Some(task_id.clone()));
+                if let Some(app_handle) = app.as_ref() {
+                    emit_runtime_dry_run_state(app_handle, &task_id, false, Some(msg.clone()));
                 }
-                emit_runtime_sync_queue_state(
-                    &app,
-                    task_id,
-                    false,
-                    Some("Watch task removed".to_string()),
-                );
-                emit_runtime_watch_state(&app, task_id, false, None);
-            }
-            Err(err) => {
-                emit_runtime_watch_state(&app, task_id, true, Some(err));
+                Err(err_text)
             }
         }
     }
-
-    Ok(())
-}
-
-async fn enqueue_initial_runtime_watch_syncs(app: tauri::AppHandle, state: AppState) {
-    let runtime_config = {
-        let config = state.runtime_config.read().await;
-        config.clone()
-    };
-
-    let enqueued_task_ids = enqueue_runtime_watch_bootstrap_tasks(
-        &runtime_watch_bootstrap_task_ids(&runtime_config.tasks),
-        &state,
-    )
-    .await;
-
-    if !enqueued_task_ids.is_empty() {
-        emit_runtime_watch_bootstrap_queue_state(
-            &enqueued_task_ids,
-            "Initial sync after runtime initialization",
-            &app,
-        );
-        schedule_runtime_sync_dispatcher(app, state);
-    }
-}
-
-#[tauri::command]
-async fn get_app_config_dir(state: tauri::State<'_, AppState>) -> Result<String, String> {
-    let config_dir = state.config_store.config_dir().to_path_buf();
-    tokio::fs::create_dir_all(&config_dir)
-        .await
-        .map_err(|e| e.to_string())?;
-    Ok(config_dir.to_string_lossy().to_string())
-}
-
-#[tauri::command]
-async fn join_paths(path1: String, path2: String) -> Result<String, String> {
-    use std::path::Path;
-    let p1 = Path::new(&path1);
-    let p2 = Path::new(&path2);
-    p1.join(p2)
-        .to_str()
-        .ok_or_else(|| "Invalid path".to_string())
-        .map(|s| s.to_string())
-}
-
-#[tauri::command]
-fn greet(name: &str) -> String {
-    format!("Hello, {name}! You've been greeted from Rust!")
-}
-
-#[tauri::command]
-fn get_app_version() -> String {
-    env!("CARGO_PKG_VERSION").to_string()
-}
-
-#[tauri::command]
-#[allow(dead_code)]
-async fn get_app_data_dir(app: tauri::AppHandle) -> Result<String, String> {
-    config_store::app_support_dir_for_app(&app)
-        .map(|p| p.to_string_lossy().to_string())
-        .map_err(config_store_error_to_string)
-}
-
-#[tauri::command]
-async fn get_settings(
-    app: tauri::AppHandle,
-    state: tauri::State<'_, AppState>,
-) -> Result<SettingsEnvelope, String> {
-    let settings = current_settings_snapshot(&app, state.inner()).await?;
-    Ok(SettingsEnvelope { settings })
-}
-
-#[tauri::command]
-async fn get_mcp_stdio_config_example(
-    state: tauri::State<'_, AppState>,
-) -> Result<McpStdioConfigExample, String> {
-    let command = std::env::current_exe()
-        .map_err(|error| format!("Failed to resolve current executable path: {error}"))?;
-    let auth_token = current_mcp_auth_token(state.inner())?;
-    Ok(build_mcp_stdio_config_example(command, auth_token))
-}
-
-#[tauri::command]
-async fn regenerate_mcp_auth_token(
-    state: tauri::State<'_, AppState>,
-) -> Result<McpStdioConfigExample, String> {
-    let command = std::env::current_exe()
-        .map_err(|error| format!("Failed to resolve current executable path: {error}"))?;
-    let settings = state
-        .config_store
-        .regenerate_mcp_auth_token()
-        .map_err(config_store_error_to_string)?;
-    let auth_token = settings.mcp_auth_token.ok_or_else(|| {
-        "Failed to regenerate MCP auth token. Try again from SyncWatcher Settings.".to_string()
-    })?;
-    Ok(build_mcp_stdio_config_example(command, auth_token))
 }
 
-#[tauri::command]
-async fn get_distribution_info(app: tauri::AppHandle) -> Result<DistributionInfo, String> {
-    Ok(distribution::distribution_info(
-        &app,
-        security_scoped::legacy_import_available(&app),
-    ))
-}
+/// `run_options`의 압축 설정이 있어야 소스 옆의 `name.gz`를 압축 사본으로 보고 목록에서 뺍니다.
+async fn find_orphan_files_internal(
+    task_id: String,
+    source: PathBuf,
+    target: PathBuf,
+    exclude_patterns: Vec<String>,
+    run_options: SyncRunOptions,
+    state: &AppState,
+    external_cancel_token: Option<CancellationToken>,
+) -> Result<Vec<OrphanFile>, String> {
+    try_acquire_task_operation(&task_id, TaskOperationKind::OrphanScan, state).await?;
 
-#[tauri::command]
-async fn get_supporter_status(app: tauri::AppHandle) -> Result<supporter::SupporterStatus, String> {
-    supporter::get_supporter_status_for_app(app).await
-}
+    let result: Result<Vec<OrphanFile>, String> = async {
+        let task_records = state
+            .config_store
+            .load_tasks()
+            .map_err(config_store_error_to_string)?;
+        let runtime_tasks: Vec<RuntimeSyncTask> =
+            task_records.iter().map(to_runtime_task_record).collect();
+        if let Some(issue) = find_runtime_orphan_target_conflict_issue(&task_id, &runtime_tasks)? {
+            let selected_name = issue
+                .task_name
+                .as_deref()
+                .unwrap_or(issue.task_id.as_deref().unwrap_or("unknown"));
+            let conflicting_name = issue
+                .conflicting_task_names
+                .first()
+                .map(String::as_str)
+                .or_else(|| issue.conflicting_task_ids.first().map(String::as_str))
+                .unwrap_or("unknown");
+            return Err(match issue.code {
+                RuntimeTaskValidationCode::DuplicateTarget => format!(
+                    "Orphan scan blocked: '{selected_name}' shares its target with '{conflicting_name}'."
+                ),
+                RuntimeTaskValidationCode::TargetSubdirConflict => format!(
+                    "Orphan scan blocked: '{selected_name}' uses a target nested with '{conflicting_name}'."
+                ),
+                _ => "Orphan scan blocked by task validation.".to_string(),
+            });
+        }
 
-#[tauri::command]
-async fn refresh_supporter_status(
-    app: tauri::AppHandle,
-) -> Result<supporter::SupporterStatus, String> {
-    supporter::refresh_supporter_status_for_app(app).await
-}
+        let source =
+            resolve_path_with_uuid(source.to_str().unwrap_or("")).map_err(|e| e.to_string())?;
+        let target =
+            resolve_path_with_uuid(target.to_str().unwrap_or("")).map_err(|e| e.to_string())?;
+        ensure_non_overlapping_paths(&source, &target)?;
 
-#[tauri::command]
-async fn get_owner_license_debug_snapshot(
-    app: tauri::AppHandle,
-) -> Result<OwnerLicenseDebugSnapshot, String> {
-    let access = owner_license_debug_access_for_app(&app)?;
-    let app_support_dir =
-        config_store::app_support_dir_for_app(&app).map_err(config_store_error_to_string)?;
-    let distribution =
-        distribution::distribution_info(&app, security_scoped::legacy_import_available(&app));
-    let cached_supporter_status = supporter::get_supporter_status_for_app(app.clone()).await?;
-    let cached_license_status = license_validation::get_license_status(app.clone()).await?;
-    let license_state_path = license_validation::debug_license_state_path(&app)?;
-    let cached_license_state = license_validation::debug_load_license_state(&app);
-    let refresh_supporter_status =
-        match supporter::refresh_supporter_status_for_app(app.clone()).await {
-            Ok(status) => OwnerLicenseRefreshSnapshot {
-                ok: true,
-                status: Some(status),
-                error: None,
-            },
-            Err(error) => OwnerLicenseRefreshSnapshot {
-                ok: false,
-                status: None,
-                error: Some(error),
-            },
-        };
+        input_validation::validate_task_id(&task_id).map_err(|e| e.to_string())?;
+        input_validation::validate_path_argument(source.to_str().unwrap_or(""))
+            .map_err(|e| e.to_string())?;
+        input_validation::validate_path_argument(target.to_str().unwrap_or(""))
+            .map_err(|e| e.to_string())?;
+        input_validation::validate_exclude_patterns(&exclude_patterns)
+            .map_err(|e| e.to_string())?;
+        run_options.validate()?;
 
-    Ok(OwnerLicenseDebugSnapshot {
-        app_support_dir: app_support_dir.to_string_lossy().into_owned(),
-        marker_path: access.marker_path.to_string_lossy().into_owned(),
-        license_state_path: license_state_path.to_string_lossy().into_owned(),
-        distribution,
-        cached_supporter_status,
-        cached_license_status,
-        cached_license_state,
-        refresh_supporter_status,
-    })
-}
+        let options = build_sync_options(false, false, exclude_patterns, &run_options);
+        let min_source_fraction = state
+            .runtime_config
+            .read()
+            .await
+            .settings
+            .effective_orphan_min_source_fraction();
+        let engine = SyncEngine::new(source, target)
+            .with_allow_empty_source(run_options.allow_empty_source)
+            .with_min_source_fraction(min_source_fraction);
+        engine
+            .find_orphan_files_with_options(&options, external_cancel_token)
+            .await
+            .map_err(|e| format!("{:#}", e))
+    }
+    .await;
 
-#[tauri::command]
-async fn purchase_lifetime_supporter(
-    app: tauri::AppHandle,
-) -> Result<supporter::SupporterPurchaseResponse, String> {
-    supporter::purchase_supporter_for_app(app).await
-}
+    release_task_operation(&task_id, state).await;
 
-#[tauri::command]
-async fn restore_lifetime_supporter(
-    app: tauri::AppHandle,
-) -> Result<supporter::SupporterPurchaseResponse, String> {
-    supporter::restore_supporter_for_app(app).await
-}
+    let orphans = result?;
+    state.log_manager.log_with_category(
+        "info",
+        &format!("Orphan scan completed: {} candidates", orphans.len()),
+        Some(task_id),
+        LogCategory::Other,
+    );
 
-#[tauri::command]
-async fn capture_path_access(
-    app: tauri::AppHandle,
-    path: String,
-) -> Result<CapturedPathAccess, String> {
-    security_scoped::capture_path_access(&app, path)
+    Ok(orphans)
 }
 
-#[tauri::command]
-async fn capture_network_mount(path: String) -> Result<Option<NetworkMountCapturePayload>, String> {
-    network_mount::capture_from_path(&path)
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MirrorDeletionPreview {
+    deletions: Vec<OrphanFile>,
+    collapsed: Vec<OrphanFile>,
+    summary: OrphanSummary,
 }
 
-#[tauri::command]
-async fn import_legacy_channel_data(app: tauri::AppHandle) -> Result<LegacyImportStatus, String> {
-    let result = security_scoped::import_legacy_channel_data(&app)?;
-    if result.imported {
-        emit_config_store_changed(&app, &["settings", "syncTasks", "exclusionSets"]);
-        let state = app.state::<AppState>();
-        let _ = apply_canonical_config_to_runtime(app.clone(), state.inner().clone()).await?;
-    }
-    Ok(result)
-}
+/// 미러 동기화가 타겟에서 삭제할 경로를 실제 삭제 없이 계산 (exclude/레이아웃/압축 옵션 반영)
+async fn preview_mirror_deletions_internal(
+    task_id: String,
+    source: PathBuf,
+    target: PathBuf,
+    exclude_patterns: Vec<String>,
+    run_options: SyncRunOptions,
+    state: &AppState,
+) -> Result<MirrorDeletionPreview, String> {
+    try_acquire_task_operation(&task_id, TaskOperationKind::OrphanScan, state).await?;
 
-#[tauri::command]
-async fn check_app_store_update(
-    app: tauri::AppHandle,
-) -> Result<AppStoreUpdateCheckResult, String> {
-    Ok(distribution::check_app_store_update(&app).await)
-}
+    let result: Result<Vec<OrphanFile>, String> = async {
+        let source =
+            resolve_path_with_uuid(source.to_str().unwrap_or("")).map_err(|e| e.to_string())?;
+        let target =
+            resolve_path_with_uuid(target.to_str().unwrap_or("")).map_err(|e| e.to_string())?;
+        ensure_non_overlapping_paths(&source, &target)?;
 
-#[tauri::command]
-async fn set_launch_at_login(
-    enabled: bool,
-    app: tauri::AppHandle,
-    state: tauri::State<'_, AppState>,
-) -> Result<SettingsEnvelope, String> {
-    if enabled {
-        app.autolaunch()
-            .enable()
-            .map_err(|error| format!("Failed to enable launch at login: {error}"))?;
-    } else {
-        app.autolaunch()
-            .disable()
-            .map_err(|error| format!("Failed to disable launch at login: {error}"))?;
+        input_validation::validate_task_id(&task_id).map_err(|e| e.to_string())?;
+        input_validation::validate_path_argument(source.to_str().unwrap_or(""))
+            .map_err(|e| e.to_string())?;
+        input_validation::validate_path_argument(target.to_str().unwrap_or(""))
+            .map_err(|e| e.to_string())?;
+        input_validation::validate_exclude_patterns(&exclude_patterns)
+            .map_err(|e| e.to_string())?;
+
+        let options = build_sync_options(false, false, exclude_patterns, &run_options);
+        let min_source_fraction = state
+            .runtime_config
+            .read()
+            .await
+            .settings
+            .effective_orphan_min_source_fraction();
+        SyncEngine::new(source, target)
+            .with_allow_empty_source(run_options.allow_empty_source)
+            .with_min_source_fraction(min_source_fraction)
+            .preview_mirror_deletions(&options, None)
+            .await
+            .map_err(|e| format!("{:#}", e))
     }
+    .await;
 
-    let settings = current_settings_snapshot(&app, state.inner()).await?;
-    Ok(SettingsEnvelope { settings })
+    release_task_operation(&task_id, state).await;
+
+    let deletions = result?;
+    Ok(MirrorDeletionPreview {
+        summary: OrphanSummary::from_orphans(&deletions),
+        collapsed: SyncEngine::collapse_orphan_subtrees(deletions.clone()),
+        deletions,
+    })
 }
 
 #[tauri::command]
-async fn update_settings(
-    updates: UpdateSettingsPayload,
-    app: tauri::AppHandle,
+async fn preview_mirror_deletions(
+    task_id: String,
+    source: PathBuf,
+    target: PathBuf,
+    exclude_patterns: Vec<String>,
+    options: Option<SyncRunOptions>,
     state: tauri::State<'_, AppState>,
-) -> Result<SettingsEnvelope, String> {
-    let mut settings = state
-        .config_store
-        .load_settings()
-        .map_err(config_store_error_to_string)?;
-    updates.apply_to(&mut settings);
-    validate_settings_record(&settings)?;
-    state
-        .config_store
-        .save_settings(&settings)
-        .map_err(config_store_error_to_string)?;
-    emit_config_store_changed(&app, &["settings"]);
-    let _ = apply_canonical_config_to_runtime(app.clone(), state.inner().clone()).await?;
-    sync_control_plane_listener(app.clone(), state.inner().clone(), settings.mcp_enabled).await?;
-    let settings = current_settings_snapshot(&app, state.inner()).await?;
-    Ok(SettingsEnvelope { settings })
+) -> Result<MirrorDeletionPreview, String> {
+    activate_task_path_access(&task_id, state.inner()).await?;
+    preview_mirror_deletions_internal(
+        task_id,
+        source,
+        target,
+        exclude_patterns,
+        options.unwrap_or_default(),
+        state.inner(),
+    )
+    .await
 }
 
 #[tauri::command]
-async fn reset_settings(
-    app: tauri::AppHandle,
+async fn ensure_directory_exists(
+    path: String,
     state: tauri::State<'_, AppState>,
-) -> Result<SettingsEnvelope, String> {
-    app.autolaunch()
-        .disable()
-        .map_err(|error| format!("Failed to disable launch at login: {error}"))?;
-    state
-        .config_store
-        .reset_settings()
-        .map_err(config_store_error_to_string)?;
-    emit_config_store_changed(&app, &["settings"]);
-    let _ = apply_canonical_config_to_runtime(app.clone(), state.inner().clone()).await?;
-    sync_control_plane_listener(app.clone(), state.inner().clone(), false).await?;
-    let settings = current_settings_snapshot(&app, state.inner()).await?;
-    Ok(SettingsEnvelope { settings })
+) -> Result<(), String> {
+    let path = validate_legacy_config_store_dir_path(Path::new(&path), &state.config_store)?;
+    tokio::fs::create_dir_all(&path)
+        .await
+        .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
-async fn list_exclusion_sets(
-    state: tauri::State<'_, AppState>,
-) -> Result<ExclusionSetsEnvelope, String> {
-    let sets = state
-        .config_store
-        .load_exclusion_sets()
-        .map_err(config_store_error_to_string)?;
-    Ok(ExclusionSetsEnvelope { sets })
+async fn file_exists(path: String, state: tauri::State<'_, AppState>) -> Result<bool, String> {
+    let path_obj = Path::new(&path);
+    if let Ok(path) = validate_legacy_config_store_dir_path(path_obj, &state.config_store) {
+        return Ok(path.exists());
+    }
+    let (_, path) = validate_legacy_config_store_file_path(path_obj, &state.config_store)?;
+    Ok(path.exists())
 }
 
 #[tauri::command]
-async fn create_exclusion_set(
-    set: ExclusionSetRecord,
-    app: tauri::AppHandle,
-    state: tauri::State<'_, AppState>,
-) -> Result<ExclusionSetEnvelope, String> {
-    input_validation::validate_task_id(&set.id).map_err(|e| e.to_string())?;
-    input_validation::validate_exclude_patterns(&set.patterns).map_err(|e| e.to_string())?;
+async fn open_in_editor(path: String, state: tauri::State<'_, AppState>) -> Result<(), String> {
+    use std::path::Path;
 
-    let mut sets = state
-        .config_store
-        .load_exclusion_sets()
-        .map_err(config_store_error_to_string)?;
-    if sets.iter().any(|candidate| candidate.id == set.id) {
-        return Err(format!("Exclusion set already exists: {}", set.id));
+    // Path validation for security
+    let path_obj = Path::new(&path);
+
+    // Only allow absolute paths
+    if !path_obj.is_absolute() {
+        return Err("Only absolute paths are allowed".to_string());
     }
-    sets.push(set.clone());
-    validate_exclusion_sets(&sets).map_err(config_store_error_to_string)?;
-    state
-        .config_store
-        .save_exclusion_sets(&sets)
-        .map_err(config_store_error_to_string)?;
-    emit_config_store_changed(&app, &["exclusionSets"]);
-    let _ = apply_canonical_config_to_runtime(app.clone(), state.inner().clone()).await?;
-    Ok(ExclusionSetEnvelope { set })
-}
 
-#[derive(Debug, Clone, serde::Deserialize)]
-#[serde(rename_all = "camelCase")]
-struct ExclusionSetUpdatePayload {
-    name: Option<String>,
-    patterns: Option<Vec<String>>,
-}
+    // Canonicalize the path to resolve symlinks and .. components
+    let canonical = path_obj
+        .canonicalize()
+        .map_err(|e| format!("Invalid path: {e}"))?;
 
-#[tauri::command]
-async fn update_exclusion_set(
-    id: String,
-    updates: ExclusionSetUpdatePayload,
-    app: tauri::AppHandle,
-    state: tauri::State<'_, AppState>,
-) -> Result<ExclusionSetEnvelope, String> {
-    input_validation::validate_task_id(&id).map_err(|e| e.to_string())?;
-    if let Some(patterns) = &updates.patterns {
-        input_validation::validate_exclude_patterns(patterns).map_err(|e| e.to_string())?;
+    let (_, allowed_path) =
+        validate_legacy_config_store_file_path(Path::new(&path), &state.config_store)?;
+    let allowed_canonical = allowed_path
+        .canonicalize()
+        .map_err(|e| format!("Invalid config store path: {e}"))?;
+    if canonical != allowed_canonical {
+        return Err(format!(
+            "Access denied: path is not a managed config store file: {}",
+            canonical.display()
+        ));
     }
 
-    let mut sets = state
-        .config_store
-        .load_exclusion_sets()
-        .map_err(config_store_error_to_string)?;
-    let Some(existing_index) = sets.iter().position(|set| set.id == id) else {
-        return Err(format!("Exclusion set not found: {id}"));
-    };
-    let mut set = sets[existing_index].clone();
-    if let Some(name) = updates.name {
-        set.name = name;
-    }
-    if let Some(patterns) = updates.patterns {
-        set.patterns = patterns;
+    // Try to open with default system editor
+    #[cfg(target_os = "macos")]
+    {
+        std::process::Command::new("open")
+            .arg("-t")
+            .arg(&canonical)
+            .spawn()
+            .map_err(|e| format!("Failed to open file: {e}"))?;
     }
-    sets[existing_index] = set.clone();
-    validate_exclusion_sets(&sets).map_err(config_store_error_to_string)?;
-    state
-        .config_store
-        .save_exclusion_sets(&sets)
-        .map_err(config_store_error_to_string)?;
-    emit_config_store_changed(&app, &["exclusionSets"]);
-    let _ = apply_canonical_config_to_runtime(app.clone(), state.inner().clone()).await?;
-    Ok(ExclusionSetEnvelope { set })
-}
 
-#[tauri::command]
-async fn delete_exclusion_set(
-    id: String,
-    app: tauri::AppHandle,
-    state: tauri::State<'_, AppState>,
-) -> Result<DeleteResultEnvelope, String> {
-    input_validation::validate_task_id(&id).map_err(|e| e.to_string())?;
-    let mut sets = state
-        .config_store
-        .load_exclusion_sets()
-        .map_err(config_store_error_to_string)?;
-    let before_len = sets.len();
-    sets.retain(|set| set.id != id);
-    let deleted = sets.len() != before_len;
-    if deleted {
-        state
-            .config_store
-            .save_exclusion_sets(&sets)
-            .map_err(config_store_error_to_string)?;
-        emit_config_store_changed(&app, &["exclusionSets"]);
-        let _ = apply_canonical_config_to_runtime(app.clone(), state.inner().clone()).await?;
+    #[cfg(target_os = "linux")]
+    {
+        // Try xdg-open first, fall back to common editors
+        let editors = vec!["xdg-open", "gedit", "kate", "code", "vim"];
+        let mut opened = false;
+
+        for editor in editors {
+            if std::process::Command::new(editor)
+                .arg(&canonical)
+                .spawn()
+                .is_ok()
+            {
+                opened = true;
+                break;
+            }
+        }
+
+        if !opened {
+            return Err("No suitable editor found".to_string());
+        }
     }
-    Ok(DeleteResultEnvelope { deleted })
+
+    #[cfg(target_os = "windows")]
+    {
+        std::process::Command::new("notepad.exe")
+            .arg(&canonical)
+            .spawn()
+            .map_err(|e| format!("Failed to open file: {e}"))?;
+    }
+
+    Ok(())
 }
 
 #[tauri::command]
-async fn reset_exclusion_sets(
-    app: tauri::AppHandle,
+async fn sync_dry_run(
+    app: AppHandle,
+    webview: Webview,
+    task_id: String,
+    source: PathBuf,
+    target: PathBuf,
+    checksum_mode: bool,
+    exclude_patterns: Vec<String>,
+    options: Option<SyncRunOptions>,
+    diff_batch_channel: Option<JavaScriptChannelId>,
     state: tauri::State<'_, AppState>,
-) -> Result<ExclusionSetsEnvelope, String> {
-    let sets = default_exclusion_set_records();
-    validate_exclusion_sets(&sets).map_err(config_store_error_to_string)?;
-    state
-        .config_store
-        .save_exclusion_sets(&sets)
-        .map_err(config_store_error_to_string)?;
-    emit_config_store_changed(&app, &["exclusionSets"]);
-    let _ = apply_canonical_config_to_runtime(app.clone(), state.inner().clone()).await?;
-    Ok(ExclusionSetsEnvelope { sets })
+) -> Result<DryRunResult, String> {
+    activate_task_path_access(&task_id, state.inner()).await?;
+    sync_dry_run_internal(
+        Some(app),
+        diff_batch_channel.map(|channel_id| channel_id.channel_on(webview)),
+        task_id,
+        source,
+        target,
+        checksum_mode,
+        exclude_patterns,
+        options.unwrap_or_default(),
+        state.inner(),
+        None,
+        None,
+    )
+    .await
 }
 
-#[tauri::command]
-async fn read_yaml_file(path: String, state: tauri::State<'_, AppState>) -> Result<String, String> {
-    let (_, path) = validate_legacy_config_store_file_path(Path::new(&path), &state.config_store)?;
-    tokio::fs::read_to_string(&path)
-        .await
-        .map_err(|e| e.to_string())
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DryRunExportResult {
+    path: String,
+    diff_count: usize,
+    orphan_count: usize,
 }
 
-#[tauri::command]
-async fn write_yaml_file(
-    path: String,
-    content: String,
-    app: tauri::AppHandle,
-    state: tauri::State<'_, AppState>,
-) -> Result<(), String> {
-    let (_, path) = validate_legacy_config_store_file_path(Path::new(&path), &state.config_store)?;
-    tokio::fs::write(&path, content)
-        .await
+/// Dry Run 결과 전체를 IPC 대신 JSON 파일로 기록 (검토/승인용 보관)
+async fn export_dry_run_internal(
+    task_id: String,
+    source: PathBuf,
+    target: PathBuf,
+    checksum_mode: bool,
+    exclude_patterns: Vec<String>,
+    run_options: SyncRunOptions,
+    output_path: &Path,
+    state: &AppState,
+) -> Result<DryRunExportResult, String> {
+    input_validation::validate_path_argument(&output_path.to_string_lossy())
         .map_err(|e| e.to_string())?;
 
-    apply_config_write_side_effects(&path, app, state.inner().clone()).await
+    let result = sync_dry_run_internal(
+        None,
+        None,
+        task_id,
+        source,
+        target,
+        checksum_mode,
+        exclude_patterns,
+        run_options,
+        state,
+        None,
+        None,
+    )
+    .await?;
+
+    let content = serde_json::to_string_pretty(&result).map_err(|e| e.to_string())?;
+    tokio::fs::write(output_path, content)
+        .await
+        .map_err(|e| format!("Failed to write dry run export: {e}"))?;
+
+    Ok(DryRunExportResult {
+        path: output_path.to_string_lossy().to_string(),
+        diff_count: result.diffs.len(),
+        orphan_count: result.orphans.as_ref().map_or(0, Vec::len),
+    })
 }
 
 #[tauri::command]
-async fn read_config_store_file(
-    scope: String,
+async fn export_dry_run(
+    task_id: String,
+    source: PathBuf,
+    target: PathBuf,
+    checksum_mode: bool,
+    exclude_patterns: Vec<String>,
+    options: Option<SyncRunOptions>,
+    output_path: String,
     state: tauri::State<'_, AppState>,
-) -> Result<String, String> {
-    let scope = ConfigStoreFileScope::parse(&scope)?;
-    let path = scope.file_path(&state.config_store);
-    match tokio::fs::read_to_string(&path).await {
-        Ok(content) => Ok(content),
-        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(String::new()),
-        Err(error) => Err(error.to_string()),
-    }
+) -> Result<DryRunExportResult, String> {
+    activate_task_path_access(&task_id, state.inner()).await?;
+    export_dry_run_internal(
+        task_id,
+        source,
+        target,
+        checksum_mode,
+        exclude_patterns,
+        options.unwrap_or_default(),
+        Path::new(&output_path),
+        state.inner(),
+    )
+    .await
 }
 
 #[tauri::command]
-async fn repair_config_store_file(
-    scope: String,
-    content: String,
-    app: tauri::AppHandle,
+async fn find_orphan_files(
+    task_id: String,
+    source: PathBuf,
+    target: PathBuf,
+    exclude_patterns: Vec<String>,
+    collapse_orphan_subtrees: Option<bool>,
+    allow_empty_source: Option<bool>,
+    options: Option<SyncRunOptions>,
     state: tauri::State<'_, AppState>,
-) -> Result<(), String> {
-    let scope = ConfigStoreFileScope::parse(&scope)?;
-    validate_repaired_config_store_file(scope, &content)?;
-    let content = canonicalize_repaired_config_store_file(scope, &content)?;
+) -> Result<Vec<OrphanFile>, String> {
+    activate_task_path_access(&task_id, state.inner()).await?;
+    let mut run_options = options.unwrap_or_default();
+    run_options.allow_empty_source |= allow_empty_source.unwrap_or(false);
+    let orphans = find_orphan_files_internal(
+        task_id,
+        source,
+        target,
+        exclude_patterns,
+        run_options,
+        state.inner(),
+        None,
+    )
+    .await?;
+    // 하위 항목까지 모두 orphan인 디렉토리는 한 줄로 접어서 반환 (삭제 시 디렉토리가 하위를 포함)
+    Ok(if collapse_orphan_subtrees.unwrap_or(false) {
+        SyncEngine::collapse_orphan_subtrees(orphans)
+    } else {
+        orphans
+    })
+}
 
-    let path = scope.file_path(&state.config_store);
-    state
-        .config_store
-        .write_raw_file_at_path(&path, content.as_bytes())
-        .map_err(config_store_error_to_string)?;
+const DIRECTORY_FINGERPRINT_CACHE_FILE_NAME: &str = "directory_fingerprints.json";
 
-    emit_config_store_changed(&app, &[scope.event_scope()]);
-    let _ = apply_canonical_config_to_runtime(app.clone(), state.inner().clone()).await?;
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CachedDirectoryFingerprint {
+    fingerprint: String,
+    file_count: usize,
+    total_bytes: u64,
+    exclude_patterns: Vec<String>,
+    computed_at: i64,
+}
 
-    if scope == ConfigStoreFileScope::Settings {
-        let settings = state
-            .config_store
-            .load_settings()
-            .map_err(config_store_error_to_string)?;
-        sync_control_plane_listener(app.clone(), state.inner().clone(), settings.mcp_enabled)
-            .await?;
-    }
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DirectoryFingerprintResult {
+    path: String,
+    fingerprint: String,
+    file_count: usize,
+    total_bytes: u64,
+    deep: bool,
+    computed_at: i64,
+    /// 같은 경로·모드·제외 패턴으로 이전에 캐시된 값 (없으면 None)
+    previous_fingerprint: Option<String>,
+}
 
-    Ok(())
+fn directory_fingerprint_cache_key(path: &Path, deep: bool) -> String {
+    let mode = if deep { "deep" } else { "metadata" };
+    format!("{mode}:{}", path.display())
 }
 
-async fn sync_dry_run_internal(
-    app: Option<AppHandle>,
-    diff_batch_channel: Option<Channel<DryRunDiffBatchEvent>>,
-    task_id: String,
-    source: PathBuf,
-    target: PathBuf,
-    checksum_mode: bool,
+async fn load_directory_fingerprint_cache(
+    state: &AppState,
+) -> HashMap<String, CachedDirectoryFingerprint> {
+    let path = state
+        .config_store
+        .config_dir()
+        .join(DIRECTORY_FINGERPRINT_CACHE_FILE_NAME);
+    match tokio::fs::read(&path).await {
+        Ok(content) => serde_json::from_slice(&content).unwrap_or_default(),
+        Err(_) => HashMap::new(),
+    }
+}
+
+/// 디렉터리 전체 지문을 계산하고 설정 디렉터리에 경로별로 캐시합니다.
+async fn directory_fingerprint_internal(
+    path: &Path,
     exclude_patterns: Vec<String>,
+    deep: bool,
     state: &AppState,
-    external_cancel_token: Option<CancellationToken>,
-    mcp_job_id: Option<String>,
-) -> Result<DryRunResult, String> {
-    try_acquire_task_operation(&task_id, TaskOperationKind::DryRun, state).await?;
-    clear_dry_run_artifact(&task_id, state).await;
-    let requested_source = source.to_string_lossy().to_string();
-    let requested_target = target.to_string_lossy().to_string();
+    progress_callback: impl Fn(SyncProgress),
+) -> Result<DirectoryFingerprintResult, String> {
+    input_validation::validate_path_argument(&path.to_string_lossy()).map_err(|e| e.to_string())?;
+    input_validation::validate_exclude_patterns(&exclude_patterns).map_err(|e| e.to_string())?;
+    let canonical = tokio::fs::canonicalize(path)
+        .await
+        .map_err(|e| format!("Failed to resolve path '{}': {e}", path.display()))?;
 
-    let result: Result<DryRunResult, String> = async {
-        maybe_activate_task_path_access(&task_id, state).await?;
-        let (source, target) =
-            ensure_task_paths_available(&task_id, &source, &target, state).await?;
-        ensure_non_overlapping_paths(&source, &target)?;
+    let engine = SyncEngine::new(canonical.clone(), PathBuf::from("."));
+    let fingerprint = engine
+        .directory_fingerprint_with_progress(&exclude_patterns, deep, progress_callback)
+        .await
+        .map_err(|e| format!("{:#}", e))?;
 
-        input_validation::validate_task_id(&task_id).map_err(|e| e.to_string())?;
-        input_validation::validate_path_argument(source.to_str().unwrap_or(""))
-            .map_err(|e| e.to_string())?;
-        input_validation::validate_path_argument(target.to_str().unwrap_or(""))
-            .map_err(|e| e.to_string())?;
-        input_validation::validate_exclude_patterns(&exclude_patterns).map_err(|e| e.to_string())?;
-        let target_preflight = preflight_target_path(&target, false).await?;
+    let key = directory_fingerprint_cache_key(&canonical, deep);
+    let mut cache = load_directory_fingerprint_cache(state).await;
+    let previous_fingerprint = cache
+        .get(&key)
+        .filter(|entry| entry.exclude_patterns == exclude_patterns)
+        .map(|entry| entry.fingerprint.clone());
+    let computed_at = unix_now_ms();
+    cache.insert(
+        key,
+        CachedDirectoryFingerprint {
+            fingerprint: fingerprint.fingerprint.clone(),
+            file_count: fingerprint.file_count,
+            total_bytes: fingerprint.total_bytes,
+            exclude_patterns,
+            computed_at,
+        },
+    );
 
-        {
-            let mut running = state.dry_running_tasks.write().await;
-            running.insert(task_id.clone());
-        }
-        if let Some(app_handle) = app.as_ref() {
-            emit_runtime_dry_run_state(
-                app_handle,
-                &task_id,
-                true,
-                Some("Dry run started".to_string()),
-            );
-        }
+    let cache_path = state
+        .config_store
+        .config_dir()
+        .join(DIRECTORY_FINGERPRINT_CACHE_FILE_NAME);
+    let content = serde_json::to_vec_pretty(&cache).map_err(|e| e.to_string())?;
+    if let Err(error) = state
+        .config_store
+        .write_raw_file_at_path(&cache_path, content)
+    {
+        eprintln!(
+            "[Fingerprint] Failed to cache fingerprint: {}",
+            config_store_error_to_string(error)
+        );
+    }
 
-        state
-            .log_manager
-            .log("info", "Dry run started", Some(task_id.clone()));
-        if target_preflight.kind == TargetPreflightKind::WillCreateDirectory {
-            state.log_manager.log(
-                "warning",
-                &format!(
-                    "Target directory does not exist yet; dry run is previewing an empty target: {}",
-                    target_preflight.path
-                ),
-                Some(task_id.clone()),
-            );
-        }
+    Ok(DirectoryFingerprintResult {
+        path: canonical.to_string_lossy().to_string(),
+        fingerprint: fingerprint.fingerprint,
+        file_count: fingerprint.file_count,
+        total_bytes: fingerprint.total_bytes,
+        deep,
+        computed_at,
+        previous_fingerprint,
+    })
+}
+
+/// `task_id`를 넘기면 deep 모드의 해시 진행률을 해당 태스크의 sync-progress 이벤트(Verifying)로 보냅니다.
+#[tauri::command]
+async fn directory_fingerprint(
+    path: PathBuf,
+    exclude_patterns: Option<Vec<String>>,
+    deep: Option<bool>,
+    task_id: Option<String>,
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> Result<DirectoryFingerprintResult, String> {
+    let resolved_path =
+        resolve_path_with_uuid(path.to_str().unwrap_or("")).map_err(|e| e.to_string())?;
+    directory_fingerprint_internal(
+        &resolved_path,
+        exclude_patterns.unwrap_or_default(),
+        deep.unwrap_or(false),
+        state.inner(),
+        |progress| {
+            if let Some(task_id) = task_id.as_deref() {
+                let event =
+                    sync_progress_event(task_id, sync_event_origin(SyncOrigin::Manual), &progress);
+                emit_sync_progress(&app, &event);
+            }
+        },
+    )
+    .await
+}
 
-        let engine = SyncEngine::new(source.clone(), target.clone());
-        let options = SyncOptions {
-            checksum_mode,
-            preserve_permissions: true,
-            preserve_times: true,
-            verify_after_copy: false,
-            exclude_patterns,
-        };
+const DIRECTORY_LISTING_DEFAULT_BATCH_SIZE: usize = 1_000;
+const DIRECTORY_LISTING_MAX_BATCH_SIZE: usize = 10_000;
 
-        let cancel_token = CancellationToken::new();
-        if let Some(external_cancel_token) = external_cancel_token {
-            let forward_cancel = cancel_token.clone();
-            tauri::async_runtime::spawn(async move {
-                external_cancel_token.cancelled().await;
-                forward_cancel.cancel();
-            });
-        }
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DirectoryListingEntry {
+    path: String,
+    size: u64,
+    modified_unix_ms: Option<i64>,
+    is_file: bool,
+}
 
-        {
-            let mut tokens = state.dry_run_cancel_tokens.write().await;
-            tokens.insert(task_id.clone(), cancel_token.clone());
-        }
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DirectoryListingBatchEvent {
+    path: String,
+    batch_index: usize,
+    entries: Vec<DirectoryListingEntry>,
+}
 
-        let live_state = DryRunLiveState::new();
-        let progress_target_preflight = target_preflight.clone();
-        let diff_target_preflight = target_preflight.clone();
-        let diff_batch_channel_for_progress = diff_batch_channel.clone();
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DirectoryListingCompleteEvent {
+    path: String,
+    total_entries: usize,
+    total_files: usize,
+    total_bytes: u64,
+    batch_count: usize,
+}
 
-        let progress_state = live_state.clone();
-        let progress_app = app.clone();
-        let progress_jobs = state.mcp_jobs.clone();
-        let progress_job_id = mcp_job_id.clone();
-        let progress_task_id = task_id.clone();
-        let progress_emit = move |progress: DryRunProgress| {
-            let now = Instant::now();
-            let (maybe_progress, maybe_batch) = progress_state.record_progress(progress.clone(), now);
+/// 디렉터리 목록을 `batch_size`개씩 나눠 `on_batch`로 넘깁니다. 한 번의 IPC 응답으로 수십만 항목을
+/// 보내지 않도록 하기 위한 것이며, 항목은 경로 순서대로 정확히 한 번씩 전달됩니다.
+async fn stream_directory_listing_internal(
+    path: &Path,
+    exclude_patterns: Vec<String>,
+    batch_size: Option<usize>,
+    mut on_batch: impl FnMut(DirectoryListingBatchEvent),
+) -> Result<DirectoryListingCompleteEvent, String> {
+    input_validation::validate_path_argument(&path.to_string_lossy()).map_err(|e| e.to_string())?;
+    input_validation::validate_exclude_patterns(&exclude_patterns).map_err(|e| e.to_string())?;
+    let batch_size = batch_size
+        .unwrap_or(DIRECTORY_LISTING_DEFAULT_BATCH_SIZE)
+        .clamp(1, DIRECTORY_LISTING_MAX_BATCH_SIZE);
+
+    let engine = SyncEngine::new(path.to_path_buf(), PathBuf::from("."));
+    let entries = engine
+        .list_source_entries(&exclude_patterns)
+        .await
+        .map_err(|e| format!("{:#}", e))?;
 
-            if let Some((diffs, batch_progress)) = maybe_batch {
-                if let Some(app_handle) = progress_app.as_ref() {
-                    let event = DryRunDiffBatchEvent {
-                        task_id: progress_task_id.clone(),
-                        phase: batch_progress.phase,
-                        message: batch_progress.message.clone(),
-                        summary: batch_progress.summary.clone(),
-                        diffs,
-                        target_preflight: Some(progress_target_preflight.clone()),
-                    };
-                    emit_dry_run_diff_batch(
-                        app_handle,
-                        diff_batch_channel_for_progress.as_ref(),
-                        &event,
-                    );
+    let display = display_path(path);
+    let mut complete = DirectoryListingCompleteEvent {
+        path: display.clone(),
+        total_entries: entries.len(),
+        total_files: 0,
+        total_bytes: 0,
+        batch_count: 0,
+    };
+    for (batch_index, chunk) in entries.chunks(batch_size).enumerate() {
+        let entries = chunk
+            .iter()
+            .map(|entry| {
+                if entry.is_file {
+                    complete.total_files += 1;
+                    complete.total_bytes += entry.size;
                 }
-
-                if let Some(job_id) = progress_job_id.as_deref() {
-                    progress_jobs.try_update_progress(
-                        job_id,
-                        mcp_progress_from_dry_run(&batch_progress),
-                        unix_now_ms(),
-                    );
+                DirectoryListingEntry {
+                    path: display_path(&entry.path),
+                    size: entry.size,
+                    modified_unix_ms: system_time_to_unix_ms(entry.modified),
+                    is_file: entry.is_file,
                 }
-            }
+            })
+            .collect();
+        on_batch(DirectoryListingBatchEvent {
+            path: display.clone(),
+            batch_index,
+            entries,
+        });
+        complete.batch_count += 1;
+    }
+    Ok(complete)
+}
 
-            if let Some(emitted_progress) = maybe_progress {
-                if let Some(app_handle) = progress_app.as_ref() {
-                    let event = dry_run_progress_event(&progress_task_id, &emitted_progress);
-                    emit_dry_run_progress(app_handle, &event);
-                }
+/// 목록은 `directory-listing-batch`(채널이 있으면 채널)로 나눠 보내고, 끝나면
+/// `directory-listing-complete` 이벤트로 합계를 알립니다.
+#[tauri::command]
+async fn stream_directory_listing(
+    path: PathBuf,
+    exclude_patterns: Option<Vec<String>>,
+    batch_size: Option<usize>,
+    listing_batch_channel: Option<JavaScriptChannelId>,
+    app: AppHandle,
+    webview: Webview,
+) -> Result<DirectoryListingCompleteEvent, String> {
+    let resolved_path =
+        resolve_path_with_uuid(path.to_str().unwrap_or("")).map_err(|e| e.to_string())?;
+    let listing_batch_channel =
+        listing_batch_channel.map(|channel_id| channel_id.channel_on(webview));
+    let complete = stream_directory_listing_internal(
+        &resolved_path,
+        exclude_patterns.unwrap_or_default(),
+        batch_size,
+        |event| {
+            emit_batch_via_channel_or_event(
+                &app,
+                "directory-listing-batch",
+                listing_batch_channel.as_ref(),
+                &event,
+            )
+        },
+    )
+    .await?;
+    let _ = app.emit("directory-listing-complete", &complete);
+    Ok(complete)
+}
 
-                if let Some(job_id) = progress_job_id.as_deref() {
-                    progress_jobs.try_update_progress(
-                        job_id,
-                        mcp_progress_from_dry_run(&emitted_progress),
-                        unix_now_ms(),
-                    );
-                }
-            }
-        };
+async fn create_target_structure_internal(
+    source: &Path,
+    target: &Path,
+    exclude_patterns: Vec<String>,
+    preserve_permissions: bool,
+) -> Result<usize, String> {
+    input_validation::validate_path_argument(source.to_str().unwrap_or(""))
+        .map_err(|e| e.to_string())?;
+    input_validation::validate_path_argument(target.to_str().unwrap_or(""))
+        .map_err(|e| e.to_string())?;
+    input_validation::validate_exclude_patterns(&exclude_patterns).map_err(|e| e.to_string())?;
+    ensure_non_overlapping_paths(source, target)?;
 
-        let diff_state = live_state.clone();
-        let diff_app = app.clone();
-        let diff_batch_channel_for_diff = diff_batch_channel.clone();
-        let diff_jobs = state.mcp_jobs.clone();
-        let diff_job_id = mcp_job_id.clone();
-        let diff_task_id = task_id.clone();
-        let diff_emit = move |diff: FileDiff, progress: DryRunProgress| {
-            let now = Instant::now();
-            if let Some((diffs, batch_progress)) = diff_state.record_diff(diff, progress, now) {
-                if let Some(app_handle) = diff_app.as_ref() {
-                    let event = DryRunDiffBatchEvent {
-                        task_id: diff_task_id.clone(),
-                        phase: batch_progress.phase,
-                        message: batch_progress.message.clone(),
-                        summary: batch_progress.summary.clone(),
-                        diffs,
-                        target_preflight: Some(diff_target_preflight.clone()),
-                    };
-                    emit_dry_run_diff_batch(
-                        app_handle,
-                        diff_batch_channel_for_diff.as_ref(),
-                        &event,
-                    );
-                }
+    let engine = SyncEngine::new(source.to_path_buf(), target.to_path_buf());
+    engine
+        .create_target_structure(&exclude_patterns, preserve_permissions)
+        .await
+        .map_err(|e| format!("{:#}", e))
+}
 
-                if let Some(job_id) = diff_job_id.as_deref() {
-                    diff_jobs.try_update_progress(
-                        job_id,
-                        mcp_progress_from_dry_run(&batch_progress),
-                        unix_now_ms(),
-                    );
-                }
-            }
-        };
+/// 동기화 전에 source 디렉토리 구조만 target에 미리 생성합니다. 생성된 디렉토리 수를 반환합니다.
+#[tauri::command]
+async fn create_target_structure(
+    source: PathBuf,
+    target: PathBuf,
+    exclude_patterns: Option<Vec<String>>,
+    preserve_permissions: Option<bool>,
+) -> Result<usize, String> {
+    let source =
+        resolve_path_with_uuid(source.to_str().unwrap_or("")).map_err(|e| e.to_string())?;
+    let target =
+        resolve_path_with_uuid(target.to_str().unwrap_or("")).map_err(|e| e.to_string())?;
+    create_target_structure_internal(
+        &source,
+        &target,
+        exclude_patterns.unwrap_or_default(),
+        preserve_permissions.unwrap_or(false),
+    )
+    .await
+}
 
-        let result = engine
-            .dry_run_with_progress_and_conflicts(
-                &options,
-                cancel_token.clone(),
-                progress_emit,
-                diff_emit,
-            )
-            .await
-            .map_err(|e| format!("{:#}", e));
+async fn recopy_file_internal(
+    source_path: &Path,
+    target_path: &Path,
+    verify_after_copy: bool,
+    run_options: SyncRunOptions,
+) -> Result<RecopyFileResult, String> {
+    input_validation::validate_path_argument(source_path.to_str().unwrap_or(""))
+        .map_err(|e| e.to_string())?;
+    input_validation::validate_path_argument(target_path.to_str().unwrap_or(""))
+        .map_err(|e| e.to_string())?;
+    run_options.validate()?;
+    ensure_non_overlapping_paths(source_path, target_path)?;
+
+    let (Some(source_root), Some(target_root), Some(file_name)) = (
+        source_path.parent(),
+        target_path.parent(),
+        source_path.file_name(),
+    ) else {
+        return Err("Source and target must be file paths".to_string());
+    };
+    if target_path.file_name() != Some(file_name) {
+        return Err("Source and target file names must match".to_string());
+    }
 
-        {
-            let mut tokens = state.dry_run_cancel_tokens.write().await;
-            tokens.remove(&task_id);
-        }
+    let options = build_sync_options(false, verify_after_copy, Vec::new(), &run_options);
+    let engine = SyncEngine::new(source_root.to_path_buf(), target_root.to_path_buf());
+    engine
+        .recopy_file(Path::new(file_name), &options)
+        .await
+        .map_err(|e| format!("{:#}", e))
+}
 
-        let (result, target_newer_conflicts) = match result {
-            Ok(result) => result,
-            Err(err) => {
-                if let Some(final_progress) = live_state.latest_progress() {
-                    if let Some((diffs, batch_progress)) =
-                        live_state.flush_pending_diffs(final_progress.clone())
-                    {
-                        if let Some(app_handle) = app.as_ref() {
-                            let event = DryRunDiffBatchEvent {
-                                task_id: task_id.clone(),
-                                phase: batch_progress.phase,
-                                message: batch_progress.message.clone(),
-                                summary: batch_progress.summary.clone(),
-                                diffs,
-                                target_preflight: Some(target_preflight.clone()),
-                            };
-                            emit_dry_run_diff_batch(
-                                app_handle,
-                                diff_batch_channel.as_ref(),
-                                &event,
-                            );
-                        }
+/// 검증 실패 등으로 문제가 된 파일 하나만 다시 복사하고 검증합니다 (전체 동기화 없이 재시도).
+#[tauri::command]
+async fn recopy_file(
+    source_path: PathBuf,
+    target_path: PathBuf,
+    verify_after_copy: Option<bool>,
+    options: Option<SyncRunOptions>,
+) -> Result<RecopyFileResult, String> {
+    let source_path =
+        resolve_path_with_uuid(source_path.to_str().unwrap_or("")).map_err(|e| e.to_string())?;
+    let target_path =
+        resolve_path_with_uuid(target_path.to_str().unwrap_or("")).map_err(|e| e.to_string())?;
+    recopy_file_internal(
+        &source_path,
+        &target_path,
+        verify_after_copy.unwrap_or(true),
+        options.unwrap_or_default(),
+    )
+    .await
+}
 
-                        if let Some(job_id) = mcp_job_id.as_deref() {
-                            state.mcp_jobs.try_update_progress(
-                                job_id,
-                                mcp_progress_from_dry_run(&batch_progress),
-                                unix_now_ms(),
-                            );
-                        }
-                    }
+async fn audit_sync_internal(
+    source: &Path,
+    target: &Path,
+    checksum_mode: bool,
+    exclude_patterns: Vec<String>,
+    run_options: SyncRunOptions,
+) -> Result<DriftReport, String> {
+    input_validation::validate_path_argument(source.to_str().unwrap_or(""))
+        .map_err(|e| e.to_string())?;
+    input_validation::validate_path_argument(target.to_str().unwrap_or(""))
+        .map_err(|e| e.to_string())?;
+    input_validation::validate_exclude_patterns(&exclude_patterns).map_err(|e| e.to_string())?;
+    run_options.validate()?;
+    ensure_non_overlapping_paths(source, target)?;
+
+    let options = build_sync_options(checksum_mode, false, exclude_patterns, &run_options);
+    let engine = SyncEngine::new(source.to_path_buf(), target.to_path_buf());
+    engine
+        .audit_drift(&options)
+        .await
+        .map_err(|e| format!("{:#}", e))
+}
 
-                    if let Some(app_handle) = app.as_ref() {
-                        let event = dry_run_progress_event(&task_id, &final_progress);
-                        emit_dry_run_progress(app_handle, &event);
-                    }
+/// 유지 중인 미러의 상태 점검: 복사/삭제 없이 new/modified/orphan/identical 수와 바이트를 집계합니다.
+#[tauri::command]
+async fn audit_sync(
+    source: PathBuf,
+    target: PathBuf,
+    checksum_mode: Option<bool>,
+    exclude_patterns: Option<Vec<String>>,
+    options: Option<SyncRunOptions>,
+) -> Result<DriftReport, String> {
+    let source =
+        resolve_path_with_uuid(source.to_str().unwrap_or("")).map_err(|e| e.to_string())?;
+    let target =
+        resolve_path_with_uuid(target.to_str().unwrap_or("")).map_err(|e| e.to_string())?;
+    audit_sync_internal(
+        &source,
+        &target,
+        checksum_mode.unwrap_or(false),
+        exclude_patterns.unwrap_or_default(),
+        options.unwrap_or_default(),
+    )
+    .await
+}
+
+const ORPHAN_DELETION_TOKEN_TTL: Duration = Duration::from_secs(5 * 60);
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct OrphanDeletionPreview {
+    token: String,
+    expires_in_secs: u64,
+    summary: OrphanDeletionSummary,
+}
+
+fn generate_orphan_deletion_token() -> Result<String, String> {
+    use std::io::Read as _;
+    let mut bytes = [0u8; 16];
+    std::fs::File::open("/dev/urandom")
+        .and_then(|mut file| file.read_exact(&mut bytes))
+        .map_err(|error| format!("Failed to generate orphan deletion token: {error}"))?;
+    let mut token = String::from("orphdel_");
+    for byte in bytes {
+        use std::fmt::Write as _;
+        let _ = write!(&mut token, "{byte:02x}");
+    }
+    Ok(token)
+}
+
+fn normalized_orphan_selection(paths: &[String]) -> Vec<String> {
+    let mut normalized = paths.to_vec();
+    normalized.sort();
+    normalized.dedup();
+    normalized
+}
+
+/// 토큰은 성공·실패와 관계없이 한 번만 사용할 수 있습니다.
+async fn consume_orphan_deletion_token(
+    token: &str,
+    task_id: &str,
+    target: &Path,
+    paths: &[String],
+    state: &AppState,
+) -> Result<(), String> {
+    let pending = state
+        .orphan_deletion_tokens
+        .write()
+        .await
+        .remove(token)
+        .ok_or_else(|| {
+            "Orphan deletion token is missing or already used; prepare the deletion again"
+                .to_string()
+        })?;
+    if Instant::now() >= pending.expires_at {
+        return Err("Orphan deletion token expired; prepare the deletion again".to_string());
+    }
+    if pending.task_id != task_id
+        || pending.target != target
+        || pending.paths != normalized_orphan_selection(paths)
+    {
+        return Err(
+            "Orphan deletion token does not match this selection; prepare the deletion again"
+                .to_string(),
+        );
+    }
+    Ok(())
+}
 
-                    if let Some(job_id) = mcp_job_id.as_deref() {
-                        state.mcp_jobs.try_update_progress(
-                            job_id,
-                            mcp_progress_from_dry_run(&final_progress),
-                            unix_now_ms(),
-                        );
-                    }
-                }
+fn resolve_orphan_deletion_target(task_id: &str, target: &Path) -> Result<PathBuf, String> {
+    let target =
+        resolve_path_with_uuid(target.to_str().unwrap_or("")).map_err(|e| e.to_string())?;
+    input_validation::validate_task_id(task_id).map_err(|e| e.to_string())?;
+    input_validation::validate_path_argument(target.to_str().unwrap_or(""))
+        .map_err(|e| e.to_string())?;
+    Ok(std::fs::canonicalize(&target).unwrap_or(target))
+}
 
-                return Err(err);
-            }
-        };
+/// `scope`가 있으면 그 최상위 폴더 밖의 경로도 건너뜀으로 셉니다 (`source_subdirs` 실행의 orphan 목록 범위).
+fn orphan_relative_paths(paths: &[String], scope: Option<&[String]>) -> (Vec<PathBuf>, usize) {
+    let mut relative_paths: Vec<PathBuf> = Vec::new();
+    let mut invalid_count = 0usize;
+    for raw_path in paths {
+        let candidate = PathBuf::from(raw_path);
+        let in_scope = scope.is_none_or(|subdirs| {
+            matches!(
+                candidate.components().next(),
+                Some(Component::Normal(first)) if subdirs.iter().any(|subdir| first == subdir.as_str())
+            )
+        });
+        if !in_scope
+            || candidate.is_absolute()
+            || candidate
+                .components()
+                .any(|component| matches!(component, Component::ParentDir))
+        {
+            invalid_count += 1;
+            continue;
+        }
+        relative_paths.push(candidate);
+    }
+    (relative_paths, invalid_count)
+}
 
-        if let Some(final_progress) = live_state.latest_progress().or_else(|| {
-            Some(DryRunProgress {
-                phase: DryRunPhase::Comparing,
-                message: "Dry run completed".to_string(),
-                current: result.total_files as u64,
-                total: result.total_files as u64,
-                processed_bytes: result.bytes_to_copy,
-                total_bytes: result.bytes_to_copy,
-                summary: DryRunSummary {
-                    total_files: result.total_files,
-                    files_to_copy: result.files_to_copy,
-                    files_modified: result.files_modified,
-                    bytes_to_copy: result.bytes_to_copy,
-                },
-            })
-        }) {
-            if let Some((diffs, batch_progress)) = live_state.flush_pending_diffs(final_progress.clone()) {
-                if let Some(app_handle) = app.as_ref() {
-                    let event = DryRunDiffBatchEvent {
-                        task_id: task_id.clone(),
-                        phase: batch_progress.phase,
-                        message: batch_progress.message.clone(),
-                        summary: batch_progress.summary.clone(),
-                        diffs,
-                        target_preflight: Some(target_preflight.clone()),
-                    };
-                    emit_dry_run_diff_batch(app_handle, diff_batch_channel.as_ref(), &event);
-                }
+async fn prepare_orphan_deletion_internal(
+    task_id: &str,
+    target: &Path,
+    paths: Vec<String>,
+    run_options: &SyncRunOptions,
+    state: &AppState,
+) -> Result<OrphanDeletionPreview, String> {
+    run_options.validate()?;
+    let target = resolve_orphan_deletion_target(task_id, target)?;
+    let (relative_paths, invalid_count) = orphan_relative_paths(&paths, run_options.orphan_scope());
+
+    let engine = SyncEngine::new(PathBuf::from("."), target.clone());
+    let mut summary = engine
+        .summarize_orphan_paths(&relative_paths)
+        .await
+        .map_err(|e| format!("{:#}", e))?;
+    summary.skipped_count += invalid_count;
+
+    let token = generate_orphan_deletion_token()?;
+    let now = Instant::now();
+    let mut tokens = state.orphan_deletion_tokens.write().await;
+    tokens.retain(|_, pending| pending.expires_at > now);
+    tokens.insert(
+        token.clone(),
+        PendingOrphanDeletion {
+            task_id: task_id.to_string(),
+            target,
+            paths: normalized_orphan_selection(&paths),
+            expires_at: now + ORPHAN_DELETION_TOKEN_TTL,
+        },
+    );
 
-                if let Some(job_id) = mcp_job_id.as_deref() {
-                    state.mcp_jobs.try_update_progress(
-                        job_id,
-                        mcp_progress_from_dry_run(&batch_progress),
-                        unix_now_ms(),
-                    );
-                }
-            }
+    Ok(OrphanDeletionPreview {
+        token,
+        expires_in_secs: ORPHAN_DELETION_TOKEN_TTL.as_secs(),
+        summary,
+    })
+}
 
-            if let Some(app_handle) = app.as_ref() {
-                let event = dry_run_progress_event(&task_id, &final_progress);
-                emit_dry_run_progress(app_handle, &event);
-            }
+/// 삭제 전 확인 단계: 선택 경로가 실제로 지울 분량을 요약하고 `delete_orphan_files`에 넘길 토큰을 발급합니다.
+#[tauri::command]
+async fn prepare_orphan_deletion(
+    task_id: String,
+    target: PathBuf,
+    paths: Vec<String>,
+    options: Option<SyncRunOptions>,
+    state: tauri::State<'_, AppState>,
+) -> Result<OrphanDeletionPreview, String> {
+    activate_task_path_access(&task_id, state.inner()).await?;
+    prepare_orphan_deletion_internal(
+        &task_id,
+        &target,
+        paths,
+        &options.unwrap_or_default(),
+        state.inner(),
+    )
+    .await
+}
 
-            if let Some(job_id) = mcp_job_id.as_deref() {
-                state
-                    .mcp_jobs
-                    .try_update_progress(job_id, mcp_progress_from_dry_run(&final_progress), unix_now_ms());
-            }
-        }
+async fn delete_orphan_files_internal(
+    task_id: &str,
+    source: &Path,
+    target: &Path,
+    exclude_patterns: &[String],
+    paths: Vec<String>,
+    run_options: &SyncRunOptions,
+    use_trash: bool,
+    allow_system_target: bool,
+    confirmation_token: &str,
+    state: &AppState,
+    progress_callback: impl Fn(SyncProgress),
+) -> Result<DeleteOrphanResult, String> {
+    run_options.validate()?;
+    let target = resolve_orphan_deletion_target(task_id, target)?;
+    ensure_target_not_system_volume(&target, allow_system_target)?;
+    consume_orphan_deletion_token(confirmation_token, task_id, &target, &paths, state).await?;
+    let (relative_paths, invalid_count) = orphan_relative_paths(&paths, run_options.orphan_scope());
+
+    let source =
+        resolve_path_with_uuid(source.to_str().unwrap_or("")).map_err(|e| e.to_string())?;
+    input_validation::validate_path_argument(source.to_str().unwrap_or(""))
+        .map_err(|e| e.to_string())?;
+    input_validation::validate_exclude_patterns(exclude_patterns).map_err(|e| e.to_string())?;
+    ensure_non_overlapping_paths(&source, &target)?;
+    let min_source_fraction = state
+        .runtime_config
+        .read()
+        .await
+        .settings
+        .effective_orphan_min_source_fraction();
+    let engine = SyncEngine::new(source, target)
+        .with_allow_empty_source(run_options.allow_empty_source)
+        .with_min_source_fraction(min_source_fraction);
+    // 목록을 만든 뒤 소스가 분리되거나 반쯤 다시 마운트됐을 수 있으므로 삭제 직전에 다시 확인합니다.
+    let options = build_sync_options(false, false, exclude_patterns.to_vec(), run_options);
+    engine
+        .ensure_source_still_populated(&options)
+        .await
+        .map_err(|e| format!("{:#}", e))?;
 
-        let result = {
-            let mut result = result;
-            result.target_preflight = Some(target_preflight.clone());
-            result
-        };
+    let mut result = engine
+        .delete_orphan_paths_with_progress(&relative_paths, use_trash, progress_callback)
+        .await
+        .map_err(|e| format!("{:#}", e))?;
+    result.skipped_count += invalid_count;
 
-        let artifact = build_dry_run_artifact(
-            &requested_source,
-            &requested_target,
-            &source,
-            &target,
-            checksum_mode,
-            &options.exclude_patterns,
-            &result,
-            &target_newer_conflicts,
-        )
-        .await?;
-        store_dry_run_artifact(&task_id, artifact, state).await;
+    state.log_manager.log_with_category(
+        "info",
+        &format!(
+            "Orphan delete completed: files={}, dirs={}, total_deleted={}, trashed={}, skipped={}, failures={}",
+            result.deleted_files_count,
+            result.deleted_dirs_count,
+            result.deleted_count,
+            result.trashed_count,
+            result.skipped_count,
+            result.failures.len()
+        ),
+        Some(task_id.to_string()),
+        LogCategory::FileDeleted,
+    );
 
-        Ok(result)
+    for warning in &result.warnings {
+        state.log_manager.log_with_category(
+            "warning",
+            warning,
+            Some(task_id.to_string()),
+            LogCategory::Other,
+        );
     }
-    .await;
 
-    {
-        let mut running = state.dry_running_tasks.write().await;
-        running.remove(&task_id);
+    if !result.failures.is_empty() {
+        state.log_manager.log_with_category(
+            "warning",
+            &format!("Orphan delete failures: {}", result.failures.len()),
+            Some(task_id.to_string()),
+            LogCategory::Other,
+        );
+    } else {
+        state.log_manager.log_with_category(
+            "success",
+            "Orphan delete completed without failures",
+            Some(task_id.to_string()),
+            LogCategory::Other,
+        );
     }
 
-    release_task_operation(&task_id, state).await;
+    Ok(result)
+}
 
-    match result {
-        Ok(result) => {
-            let unit_system = state.runtime_config.read().await.settings.data_unit_system;
-            let msg = format!(
-                "Dry run completed.\nTo copy: {} files\nTotal size: {}",
-                format_number(result.files_to_copy as u64),
-                format_bytes_with_unit(result.bytes_to_copy, unit_system)
-            );
-            state
-                .log_manager
-                .log("success", &msg, Some(task_id.clone()));
-            if let Some(app_handle) = app.as_ref() {
-                emit_runtime_dry_run_state(
-                    app_handle,
-                    &task_id,
-                    false,
-                    Some("Dry run completed".to_string()),
-                );
-            }
-            Ok(result)
-        }
-        Err(e) => {
-            let err_text = format!("{:#}", e);
-            if err_text.contains("cancelled by user") {
-                state.log_manager.log(
-                    "warning",
-                    "Dry run cancelled by user",
-                    Some(task_id.clone()),
-                );
-                if let Some(app_handle) = app.as_ref() {
-                    emit_runtime_dry_run_state(
-                        app_handle,
-                        &task_id,
-                        false,
-                        Some("Dry run cancelled by user".to_string()),
-                    );
-                }
-                Err("Dry run cancelled by user".to_string())
-            } else {
-                let msg = format!("Dry run failed: {err_text}");
-                state.log_manager.log("error", &msg, Some(task_id.clone()));
-                if let Some(app_handle) = app.as_ref() {
-                    emit_runtime_dry_run_state(app_handle, &task_id, false, Some(msg.clone()));
-                }
-                Err(err_text)
+#[tauri::command]
+async fn delete_orphan_files(
+    task_id: String,
+    source: PathBuf,
+    target: PathBuf,
+    exclude_patterns: Vec<String>,
+    paths: Vec<String>,
+    use_trash: Option<bool>,
+    allow_system_target: Option<bool>,
+    confirmation_token: String,
+    options: Option<SyncRunOptions>,
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> Result<DeleteOrphanResult, String> {
+    activate_task_path_access(&task_id, state.inner()).await?;
+    // 삭제 단계도 sync-progress 이벤트로 알려 UI가 멈춘 것처럼 보이지 않게 합니다.
+    let last_progress_emit = StdMutex::new(None::<Instant>);
+    delete_orphan_files_internal(
+        &task_id,
+        &source,
+        &target,
+        &exclude_patterns,
+        paths,
+        &options.unwrap_or_default(),
+        use_trash.unwrap_or(false),
+        allow_system_target.unwrap_or(false),
+        &confirmation_token,
+        state.inner(),
+        |progress| {
+            let now = Instant::now();
+            let mut last = last_progress_emit.lock().unwrap_or_else(|e| e.into_inner());
+            let due =
+                last.is_none_or(|last| now.duration_since(last) >= Duration::from_millis(100));
+            if due || progress.processed_files == progress.total_files {
+                *last = Some(now);
+                let event =
+                    sync_progress_event(&task_id, sync_event_origin(SyncOrigin::Manual), &progress);
+                emit_sync_progress(&app, &event);
             }
+        },
+    )
+    .await
+}
+
+#[tauri::command]
+async fn list_conflict_review_sessions(
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<ConflictSessionSummary>, String> {
+    Ok(list_conflict_session_summaries_internal(state.inner()).await)
+}
+
+#[tauri::command]
+async fn get_conflict_review_session(
+    session_id: String,
+    state: tauri::State<'_, AppState>,
+) -> Result<ConflictSessionDetail, String> {
+    let sessions = state.conflict_review_sessions.read().await;
+    let session = sessions
+        .get(&session_id)
+        .ok_or_else(|| format!("Conflict session not found: {session_id}"))?;
+    Ok(to_conflict_detail(session))
+}
+
+/// 충돌 세션은 동기화 완료 직후에 만들어지므로, 같은 태스크에서 세션 생성 이전에 시작된
+/// 가장 최근 실행에 붙입니다. `runs`는 최신순이어야 합니다.
+fn attach_conflict_sessions_to_runs(runs: &mut [SyncRun], sessions: &[(String, String, i64)]) {
+    for (session_id, task_id, created_at_unix_ms) in sessions {
+        let owner = runs.iter_mut().find(|run| {
+            &run.task_id == task_id
+                && run
+                    .started_at_ms()
+                    .is_some_and(|started_at| started_at <= *created_at_unix_ms)
+        });
+        if let Some(run) = owner {
+            run.conflict_session_ids.push(session_id.clone());
         }
     }
 }
 
-async fn find_orphan_files_internal(
-    task_id: String,
-    source: PathBuf,
-    target: PathBuf,
-    exclude_patterns: Vec<String>,
-    state: &AppState,
-    external_cancel_token: Option<CancellationToken>,
-) -> Result<Vec<OrphanFile>, String> {
-    try_acquire_task_operation(&task_id, TaskOperationKind::OrphanScan, state).await?;
+/// 로그를 동기화 실행 단위로 묶은 타임라인 (최신순). `since`는 Unix ms 기준 시작 시각입니다.
+#[tauri::command]
+async fn get_activity_timeline(
+    since: Option<i64>,
+    limit: usize,
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<SyncRun>, String> {
+    let mut runs = state.log_manager.get_sync_runs(since, limit);
+    let sessions: Vec<(String, String, i64)> = {
+        let sessions = state.conflict_review_sessions.read().await;
+        sessions
+            .values()
+            .map(|session| {
+                (
+                    session.id.clone(),
+                    session.task_id.clone(),
+                    session.created_at_unix_ms,
+                )
+            })
+            .collect()
+    };
+    attach_conflict_sessions_to_runs(&mut runs, &sessions);
+    Ok(runs)
+}
 
-    let result: Result<Vec<OrphanFile>, String> = async {
-        let task_records = state
-            .config_store
-            .load_tasks()
-            .map_err(config_store_error_to_string)?;
-        let runtime_tasks: Vec<RuntimeSyncTask> =
-            task_records.iter().map(to_runtime_task_record).collect();
-        if let Some(issue) = find_runtime_orphan_target_conflict_issue(&task_id, &runtime_tasks)? {
-            let selected_name = issue
-                .task_name
-                .as_deref()
-                .unwrap_or(issue.task_id.as_deref().unwrap_or("unknown"));
-            let conflicting_name = issue
-                .conflicting_task_names
-                .first()
-                .map(String::as_str)
-                .or_else(|| issue.conflicting_task_ids.first().map(String::as_str))
-                .unwrap_or("unknown");
-            return Err(match issue.code {
-                RuntimeTaskValidationCode::DuplicateTarget => format!(
-                    "Orphan scan blocked: '{selected_name}' shares its target with '{conflicting_name}'."
-                ),
-                RuntimeTaskValidationCode::TargetSubdirConflict => format!(
-                    "Orphan scan blocked: '{selected_name}' uses a target nested with '{conflicting_name}'."
-                ),
-                _ => "Orphan scan blocked by task validation.".to_string(),
-            });
-        }
+const CONFLICT_GROUP_CONTENTS_MAX_LIMIT: usize = 500;
 
-        let source =
-            resolve_path_with_uuid(source.to_str().unwrap_or("")).map_err(|e| e.to_string())?;
-        let target =
-            resolve_path_with_uuid(target.to_str().unwrap_or("")).map_err(|e| e.to_string())?;
-        ensure_non_overlapping_paths(&source, &target)?;
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ConflictGroupEntry {
+    item_id: String,
+    /// 그룹 디렉터리 기준 상대 경로
+    relative_path: String,
+    status: ConflictItemStatus,
+    source: ConflictFileInfo,
+    target: ConflictFileInfo,
+}
 
-        input_validation::validate_task_id(&task_id).map_err(|e| e.to_string())?;
-        input_validation::validate_path_argument(source.to_str().unwrap_or(""))
-            .map_err(|e| e.to_string())?;
-        input_validation::validate_path_argument(target.to_str().unwrap_or(""))
-            .map_err(|e| e.to_string())?;
-        input_validation::validate_exclude_patterns(&exclude_patterns)
-            .map_err(|e| e.to_string())?;
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ConflictGroupContentsPage {
+    session_id: String,
+    group_id: String,
+    offset: usize,
+    total_count: usize,
+    entries: Vec<ConflictGroupEntry>,
+}
 
-        let engine = SyncEngine::new(source, target);
-        engine
-            .find_orphan_files_with_cancel(&exclude_patterns, external_cancel_token)
-            .await
-            .map_err(|e| format!("{:#}", e))
+/// 폴더 단위 충돌 그룹의 파일 목록을 페이지 단위로 반환.
+/// `group_id`는 세션 루트 기준 디렉터리 상대 경로이며, 그 아래의 기존 충돌 항목을 그대로 사용함.
+async fn list_conflict_group_contents_internal(
+    session_id: &str,
+    group_id: &str,
+    offset: usize,
+    limit: usize,
+    state: &AppState,
+) -> Result<ConflictGroupContentsPage, String> {
+    let group_dir = group_id.trim_matches('/');
+    if group_dir.is_empty() {
+        return Err("Conflict group id must be a directory path".to_string());
     }
-    .await;
+    let prefix = format!("{group_dir}/");
+    let limit = limit.min(CONFLICT_GROUP_CONTENTS_MAX_LIMIT);
 
-    release_task_operation(&task_id, state).await;
+    let sessions = state.conflict_review_sessions.read().await;
+    let session = sessions
+        .get(session_id)
+        .ok_or_else(|| format!("Conflict session not found: {session_id}"))?;
+    let mut members: Vec<&TargetNewerConflictItem> = session
+        .items
+        .iter()
+        .filter(|item| item.relative_path.starts_with(&prefix))
+        .collect();
+    if members.is_empty() {
+        return Err(format!("Conflict group not found: {group_id}"));
+    }
+    members.sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
 
-    let orphans = result?;
-    state.log_manager.log_with_category(
-        "info",
-        &format!("Orphan scan completed: {} candidates", orphans.len()),
-        Some(task_id),
-        LogCategory::Other,
-    );
+    let total_count = members.len();
+    let entries = members
+        .into_iter()
+        .skip(offset)
+        .take(limit)
+        .map(|item| ConflictGroupEntry {
+            item_id: item.id.clone(),
+            relative_path: item.relative_path[prefix.len()..].to_string(),
+            status: item.status.clone(),
+            source: item.source.clone(),
+            target: item.target.clone(),
+        })
+        .collect();
 
-    Ok(orphans)
+    Ok(ConflictGroupContentsPage {
+        session_id: session_id.to_string(),
+        group_id: group_dir.to_string(),
+        offset,
+        total_count,
+        entries,
+    })
 }
 
 #[tauri::command]
-async fn ensure_directory_exists(
-    path: String,
+async fn list_conflict_group_contents(
+    session_id: String,
+    group_id: String,
+    offset: usize,
+    limit: usize,
     state: tauri::State<'_, AppState>,
-) -> Result<(), String> {
-    let path = validate_legacy_config_store_dir_path(Path::new(&path), &state.config_store)?;
-    tokio::fs::create_dir_all(&path)
+) -> Result<ConflictGroupContentsPage, String> {
+    list_conflict_group_contents_internal(&session_id, &group_id, offset, limit, state.inner())
         .await
-        .map_err(|e| e.to_string())
 }
 
-#[tauri::command]
-async fn file_exists(path: String, state: tauri::State<'_, AppState>) -> Result<bool, String> {
-    let path_obj = Path::new(&path);
-    if let Ok(path) = validate_legacy_config_store_dir_path(path_obj, &state.config_store) {
-        return Ok(path.exists());
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ConflictSessionExportResult {
+    path: String,
+    session_count: usize,
+    item_count: usize,
+}
+
+const CONFLICT_EXPORT_CSV_HEADER: &str = "sessionId,taskId,taskName,relativePath,sourcePath,targetPath,sourceSize,sourceModifiedUnixMs,targetSize,targetModifiedUnixMs,status,note,resolvedAtUnixMs";
+
+fn conflict_item_status_label(status: &ConflictItemStatus) -> &'static str {
+    match status {
+        ConflictItemStatus::Pending => "pending",
+        ConflictItemStatus::ForceCopied => "forceCopied",
+        ConflictItemStatus::SafeCopied => "safeCopied",
+        ConflictItemStatus::Skipped => "skipped",
+        ConflictItemStatus::KeptTarget => "keptTarget",
     }
-    let (_, path) = validate_legacy_config_store_file_path(path_obj, &state.config_store)?;
-    Ok(path.exists())
 }
 
-#[tauri::command]
-async fn open_in_editor(path: String, state: tauri::State<'_, AppState>) -> Result<(), String> {
-    use std::path::Path;
+fn escape_csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// 세션별 항목을 한 줄씩 기록하는 CSV (헤더 포함, 마지막 줄도 개행으로 끝남)
+fn build_conflict_sessions_csv(sessions: &[ConflictSessionDetail]) -> String {
+    let optional = |value: Option<i64>| value.map(|v| v.to_string()).unwrap_or_default();
+    let mut csv = String::from(CONFLICT_EXPORT_CSV_HEADER);
+    csv.push('\n');
+    for session in sessions {
+        for item in &session.items {
+            let fields = [
+                session.id.clone(),
+                session.task_id.clone(),
+                session.task_name.clone(),
+                item.relative_path.clone(),
+                item.source_path.clone(),
+                item.target_path.clone(),
+                item.source.size.to_string(),
+                optional(item.source.modified_unix_ms),
+                item.target.size.to_string(),
+                optional(item.target.modified_unix_ms),
+                conflict_item_status_label(&item.status).to_string(),
+                item.note.clone().unwrap_or_default(),
+                optional(item.resolved_at_unix_ms),
+            ];
+            let row: Vec<String> = fields.iter().map(|field| escape_csv_field(field)).collect();
+            csv.push_str(&row.join(","));
+            csv.push('\n');
+        }
+    }
+    csv
+}
+
+/// 충돌 검토 세션을 감사/승인용 파일로 기록. 확장자가 `.csv`이면 CSV, 그 외에는 JSON.
+async fn export_conflict_sessions_internal(
+    output_path: &Path,
+    session_ids: Option<Vec<String>>,
+    state: &AppState,
+) -> Result<ConflictSessionExportResult, String> {
+    input_validation::validate_path_argument(&output_path.to_string_lossy())
+        .map_err(|e| e.to_string())?;
 
-    // Path validation for security
-    let path_obj = Path::new(&path);
+    let details: Vec<ConflictSessionDetail> = {
+        let sessions = state.conflict_review_sessions.read().await;
+        match session_ids {
+            Some(ids) => ids
+                .iter()
+                .map(|id| {
+                    sessions
+                        .get(id)
+                        .map(to_conflict_detail)
+                        .ok_or_else(|| format!("Conflict session not found: {id}"))
+                })
+                .collect::<Result<_, _>>()?,
+            None => {
+                let mut all: Vec<ConflictSessionDetail> =
+                    sessions.values().map(to_conflict_detail).collect();
+                all.sort_by_key(|detail| std::cmp::Reverse(detail.created_at_unix_ms));
+                all
+            }
+        }
+    };
 
-    // Only allow absolute paths
-    if !path_obj.is_absolute() {
-        return Err("Only absolute paths are allowed".to_string());
-    }
+    let is_csv = output_path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("csv"));
+    let content = if is_csv {
+        build_conflict_sessions_csv(&details)
+    } else {
+        serde_json::to_string_pretty(&details).map_err(|e| e.to_string())?
+    };
+    tokio::fs::write(output_path, content)
+        .await
+        .map_err(|e| format!("Failed to write conflict session export: {e}"))?;
 
-    // Canonicalize the path to resolve symlinks and .. components
-    let canonical = path_obj
-        .canonicalize()
-        .map_err(|e| format!("Invalid path: {e}"))?;
+    Ok(ConflictSessionExportResult {
+        path: output_path.to_string_lossy().to_string(),
+        session_count: details.len(),
+        item_count: details.iter().map(|detail| detail.items.len()).sum(),
+    })
+}
 
-    let (_, allowed_path) =
-        validate_legacy_config_store_file_path(Path::new(&path), &state.config_store)?;
-    let allowed_canonical = allowed_path
-        .canonicalize()
-        .map_err(|e| format!("Invalid config store path: {e}"))?;
-    if canonical != allowed_canonical {
-        return Err(format!(
-            "Access denied: path is not a managed config store file: {}",
-            canonical.display()
-        ));
-    }
+#[tauri::command]
+async fn export_conflict_sessions(
+    output_path: String,
+    session_ids: Option<Vec<String>>,
+    state: tauri::State<'_, AppState>,
+) -> Result<ConflictSessionExportResult, String> {
+    export_conflict_sessions_internal(Path::new(&output_path), session_ids, state.inner()).await
+}
 
-    // Try to open with default system editor
-    #[cfg(target_os = "macos")]
-    {
-        std::process::Command::new("open")
-            .arg("-t")
-            .arg(&canonical)
-            .spawn()
-            .map_err(|e| format!("Failed to open file: {e}"))?;
-    }
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ConflictResolutionRecord {
+    session_id: String,
+    task_id: String,
+    task_name: String,
+    item_id: String,
+    relative_path: String,
+    /// 적용된 처리 (항목 상태 그대로)
+    action: ConflictItemStatus,
+    note: Option<String>,
+    resolved_at_unix_ms: i64,
+}
 
-    #[cfg(target_os = "linux")]
-    {
-        // Try xdg-open first, fall back to common editors
-        let editors = vec!["xdg-open", "gedit", "kate", "code", "vim"];
-        let mut opened = false;
+/// 열려 있는 모든 세션에서 처리 완료된 항목을 최신순으로 모읍니다. `since`는 Unix ms 기준입니다.
+async fn get_recent_conflict_resolutions_internal(
+    limit: usize,
+    since: Option<i64>,
+    state: &AppState,
+) -> Vec<ConflictResolutionRecord> {
+    let sessions = state.conflict_review_sessions.read().await;
+    let mut records: Vec<ConflictResolutionRecord> = sessions
+        .values()
+        .flat_map(|session| {
+            session.items.iter().filter_map(move |item| {
+                let resolved_at_unix_ms = item.resolved_at_unix_ms?;
+                if item.status == ConflictItemStatus::Pending
+                    || since.is_some_and(|since| resolved_at_unix_ms < since)
+                {
+                    return None;
+                }
+                Some(ConflictResolutionRecord {
+                    session_id: session.id.clone(),
+                    task_id: session.task_id.clone(),
+                    task_name: session.task_name.clone(),
+                    item_id: item.id.clone(),
+                    relative_path: item.relative_path.clone(),
+                    action: item.status.clone(),
+                    note: item.note.clone(),
+                    resolved_at_unix_ms,
+                })
+            })
+        })
+        .collect();
+    records.sort_by(|a, b| {
+        b.resolved_at_unix_ms
+            .cmp(&a.resolved_at_unix_ms)
+            .then_with(|| a.relative_path.cmp(&b.relative_path))
+    });
+    records.truncate(limit);
+    records
+}
 
-        for editor in editors {
-            if std::process::Command::new(editor)
-                .arg(&canonical)
-                .spawn()
-                .is_ok()
-            {
-                opened = true;
-                break;
-            }
-        }
+#[tauri::command]
+async fn get_recent_conflict_resolutions(
+    limit: usize,
+    since: Option<i64>,
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<ConflictResolutionRecord>, String> {
+    Ok(get_recent_conflict_resolutions_internal(limit, since, state.inner()).await)
+}
 
-        if !opened {
-            return Err("No suitable editor found".to_string());
+#[tauri::command]
+async fn open_conflict_review_window(
+    session_id: String,
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> Result<(), String> {
+    {
+        let sessions = state.conflict_review_sessions.read().await;
+        if !sessions.contains_key(&session_id) {
+            return Err(format!("Conflict session not found: {session_id}"));
         }
     }
 
-    #[cfg(target_os = "windows")]
-    {
-        std::process::Command::new("notepad.exe")
-            .arg(&canonical)
-            .spawn()
-            .map_err(|e| format!("Failed to open file: {e}"))?;
-    }
+    if let Some(window) = app.get_webview_window("conflict-review") {
+        let _ = window.emit(
+            "conflict-review-open-session",
+            ConflictReviewOpenSessionEvent {
+                session_id: session_id.clone(),
+            },
+        );
+        let _ = window.show();
+        let _ = window.unminimize();
+        let _ = window.set_focus();
+        return Ok(());
+    }
+
+    let url =
+        WebviewUrl::App(format!("index.html?view=conflict-review&sessionId={session_id}").into());
+    let window = WebviewWindowBuilder::new(&app, "conflict-review", url)
+        .title("SyncWatcher - Conflict Review")
+        .inner_size(1320.0, 900.0)
+        .resizable(true)
+        .build()
+        .map_err(|e| format!("Failed to open conflict review window: {e}"))?;
+    let _ = window.show();
+    let _ = window.set_focus();
+    Ok(())
+}
+
+/// 감지 이후 파일이 바뀌었는지 확인하고, 저장된 스냅샷을 현재 메타데이터로 갱신합니다.
+async fn refresh_conflict_item_internal(
+    session_id: &str,
+    item_id: &str,
+    state: &AppState,
+) -> Result<ConflictItemRefreshResult, String> {
+    let (source_path, target_path) = {
+        let sessions = state.conflict_review_sessions.read().await;
+        let session = sessions
+            .get(session_id)
+            .ok_or_else(|| format!("Conflict session not found: {session_id}"))?;
+        let item = session
+            .items
+            .iter()
+            .find(|item| item.id == item_id)
+            .ok_or_else(|| format!("Conflict item not found: {item_id}"))?;
+        (
+            PathBuf::from(&item.source_path),
+            PathBuf::from(&item.target_path),
+        )
+    };
+
+    let current_source = read_current_conflict_file_info(&source_path).await?;
+    let current_target = read_current_conflict_file_info(&target_path).await?;
 
-    Ok(())
+    let mut sessions = state.conflict_review_sessions.write().await;
+    let item = sessions
+        .get_mut(session_id)
+        .and_then(|session| session.items.iter_mut().find(|item| item.id == item_id))
+        .ok_or_else(|| format!("Conflict item not found: {item_id}"))?;
+
+    let source_changed = conflict_file_info_changed(&item.source, &current_source);
+    let target_changed = conflict_file_info_changed(&item.target, &current_target);
+    item.source = current_source.clone();
+    item.target = current_target.clone();
+
+    Ok(ConflictItemRefreshResult {
+        item_id: item_id.to_string(),
+        changed: source_changed || target_changed,
+        source_changed,
+        target_changed,
+        source: current_source,
+        target: current_target,
+    })
 }
 
 #[tauri::command]
-async fn sync_dry_run(
-    app: AppHandle,
-    webview: Webview,
-    task_id: String,
-    source: PathBuf,
-    target: PathBuf,
-    checksum_mode: bool,
-    exclude_patterns: Vec<String>,
-    diff_batch_channel: Option<JavaScriptChannelId>,
+async fn refresh_conflict_item(
+    session_id: String,
+    item_id: String,
     state: tauri::State<'_, AppState>,
-) -> Result<DryRunResult, String> {
-    activate_task_path_access(&task_id, state.inner()).await?;
-    sync_dry_run_internal(
-        Some(app),
-        diff_batch_channel.map(|channel_id| channel_id.channel_on(webview)),
-        task_id,
-        source,
-        target,
-        checksum_mode,
-        exclude_patterns,
-        state.inner(),
-        None,
-        None,
-    )
-    .await
+) -> Result<ConflictItemRefreshResult, String> {
+    refresh_conflict_item_internal(&session_id, &item_id, state.inner()).await
 }
 
 #[tauri::command]
-async fn find_orphan_files(
-    task_id: String,
-    source: PathBuf,
-    target: PathBuf,
-    exclude_patterns: Vec<String>,
+async fn resolve_conflict_items(
+    session_id: String,
+    resolutions: Vec<ConflictResolutionRequest>,
+    idempotency_key: Option<String>,
+    app: tauri::AppHandle,
     state: tauri::State<'_, AppState>,
-) -> Result<Vec<OrphanFile>, String> {
-    activate_task_path_access(&task_id, state.inner()).await?;
-    find_orphan_files_internal(
-        task_id,
-        source,
-        target,
-        exclude_patterns,
+) -> Result<ConflictResolutionResult, String> {
+    resolve_conflict_items_internal(
+        session_id,
+        resolutions,
+        idempotency_key,
+        Some(&app),
         state.inner(),
-        None,
     )
     .await
 }
 
-#[tauri::command]
-async fn delete_orphan_files(
-    task_id: String,
-    target: PathBuf,
-    paths: Vec<String>,
-    state: tauri::State<'_, AppState>,
-) -> Result<DeleteOrphanResult, String> {
-    activate_task_path_access(&task_id, state.inner()).await?;
-    let target =
-        resolve_path_with_uuid(target.to_str().unwrap_or("")).map_err(|e| e.to_string())?;
-    input_validation::validate_task_id(&task_id).map_err(|e| e.to_string())?;
-    input_validation::validate_path_argument(target.to_str().unwrap_or(""))
-        .map_err(|e| e.to_string())?;
+/// `idempotency_key`가 같은 재요청은 다시 적용하지 않고 처음 결과를 그대로 반환함
+async fn resolve_conflict_items_internal(
+    session_id: String,
+    resolutions: Vec<ConflictResolutionRequest>,
+    idempotency_key: Option<String>,
+    app: Option<&tauri::AppHandle>,
+    state: &AppState,
+) -> Result<ConflictResolutionResult, String> {
+    let idempotency_key = idempotency_key
+        .map(|key| key.trim().to_string())
+        .filter(|key| !key.is_empty());
+    if let Some(key) = &idempotency_key {
+        let sessions = state.conflict_review_sessions.read().await;
+        let session = sessions
+            .get(&session_id)
+            .ok_or_else(|| format!("Conflict session not found: {session_id}"))?;
+        if let Some(previous) = session.applied_resolutions.get(key) {
+            return Ok(previous.clone());
+        }
+    }
+
+    if resolutions.is_empty() {
+        let pending_count = {
+            let sessions = state.conflict_review_sessions.read().await;
+            let session = sessions
+                .get(&session_id)
+                .ok_or_else(|| format!("Conflict session not found: {session_id}"))?;
+            pending_conflict_count(&session.items)
+        };
+        return Ok(ConflictResolutionResult {
+            session_id,
+            requested_count: 0,
+            processed_count: 0,
+            pending_count,
+            failures: Vec::new(),
+        });
+    }
+
+    {
+        let sessions = state.conflict_review_sessions.read().await;
+        if !sessions.contains_key(&session_id) {
+            return Err(format!("Conflict session not found: {session_id}"));
+        }
+    }
+
+    let mut processed_count = 0usize;
+    let mut failures = Vec::new();
+
+    for request in &resolutions {
+        let item_snapshot = {
+            let sessions = state.conflict_review_sessions.read().await;
+            let Some(session) = sessions.get(&session_id) else {
+                break;
+            };
+            session
+                .items
+                .iter()
+                .find(|item| item.id == request.item_id)
+                .cloned()
+                .map(|item| (item, session.task_id.clone(), session.target_root.clone()))
+        };
+
+        let Some((item_snapshot, session_task_id, session_target_root)) = item_snapshot else {
+            failures.push(ConflictResolutionFailure {
+                item_id: request.item_id.clone(),
+                message: "Conflict item not found".to_string(),
+            });
+            continue;
+        };
+
+        if item_snapshot.status != ConflictItemStatus::Pending {
+            continue;
+        }
+
+        let source_path = PathBuf::from(&item_snapshot.source_path);
+        let target_path = PathBuf::from(&item_snapshot.target_path);
+        let target_root = PathBuf::from(session_target_root);
+
+        match (
+            read_current_conflict_file_info(&source_path).await,
+            read_current_conflict_file_info(&target_path).await,
+        ) {
+            (Ok(current_source), Ok(current_target)) => {
+                let source_changed =
+                    conflict_file_info_changed(&item_snapshot.source, &current_source);
+                let target_changed =
+                    conflict_file_info_changed(&item_snapshot.target, &current_target);
+                if source_changed || target_changed {
+                    state.log_manager.log_with_category(
+                        "warning",
+                        &format!(
+                            "Conflict item changed since detection: {} (source_changed={}, target_changed={})",
+                            item_snapshot.relative_path, source_changed, target_changed
+                        ),
+                        Some(session_task_id.clone()),
+                        LogCategory::Other,
+                    );
+                }
+            }
+            (Err(source_err), Err(target_err)) => {
+                state.log_manager.log_with_category(
+                    "warning",
+                    &format!(
+                        "Conflict preflight metadata check failed for source and target ({}): source_error='{}', target_error='{}'",
+                        item_snapshot.relative_path, source_err, target_err
+                    ),
+                    Some(session_task_id.clone()),
+                    LogCategory::Other,
+                );
+            }
+            (Err(source_err), _) => {
+                state.log_manager.log_with_category(
+                    "warning",
+                    &format!(
+                        "Conflict preflight metadata check failed for source ({}): {}",
+                        item_snapshot.relative_path, source_err
+                    ),
+                    Some(session_task_id.clone()),
+                    LogCategory::Other,
+                );
+            }
+            (_, Err(target_err)) => {
+                state.log_manager.log_with_category(
+                    "warning",
+                    &format!(
+                        "Conflict preflight metadata check failed for target ({}): {}",
+                        item_snapshot.relative_path, target_err
+                    ),
+                    Some(session_task_id.clone()),
+                    LogCategory::Other,
+                );
+            }
+        }
+
+        let apply_result: Result<(ConflictItemStatus, Option<String>), String> = match request
+            .action
+        {
+            ConflictResolutionAction::Skip => Ok((
+                ConflictItemStatus::Skipped,
+                Some("User chose to skip this conflict item.".to_string()),
+            )),
+            ConflictResolutionAction::KeepTarget => Ok((
+                ConflictItemStatus::KeptTarget,
+                Some("User reviewed this conflict and kept the target file.".to_string()),
+            )),
+            ConflictResolutionAction::ForceCopy => {
+                let producer_id = runtime_conflict_producer_id(
+                    &session_id,
+                    &request.item_id,
+                    RuntimeProducerKind::ConflictForceCopy,
+                );
+                let target_key = resolved_path_key(target_path.to_string_lossy().as_ref())?;
+                register_runtime_producer(
+                    producer_id.clone(),
+                    RuntimeProducerKind::ConflictForceCopy,
+                    target_key.clone(),
+                    state,
+                )
+                .await;
+
+                let apply_result =
+                    copy_file_preserve_under_root(&source_path, &target_root, &target_path)
+                        .await
+                        .map(|_| {
+                            (
+                                ConflictItemStatus::ForceCopied,
+                                Some("Copied source file to target (force overwrite).".to_string()),
+                            )
+                        });
+
+                finish_runtime_producer(
+                    &producer_id,
+                    &target_key,
+                    apply_result.is_ok(),
+                    app,
+                    state,
+                )
+                .await;
+
+                apply_result
+            }
+            ConflictResolutionAction::RenameThenCopy => {
+                let producer_id = runtime_conflict_producer_id(
+                    &session_id,
+                    &request.item_id,
+                    RuntimeProducerKind::ConflictRenameThenCopy,
+                );
+                let target_key = resolved_path_key(target_path.to_string_lossy().as_ref())?;
+                register_runtime_producer(
+                    producer_id.clone(),
+                    RuntimeProducerKind::ConflictRenameThenCopy,
+                    target_key.clone(),
+                    state,
+                )
+                .await;
+
+                let apply_result = async {
+                    let source_path = source_path.clone();
+                    let target_path = target_path.clone();
+                    let target_root = target_root.clone();
+                    let parent = match target_path.parent() {
+                        Some(value) => value.to_path_buf(),
+                        None => Err("Target file parent directory is invalid".to_string())?,
+                    };
+                    reject_symlink_destination_under_root(&target_root, &target_path).await?;
+                    tokio::fs::create_dir_all(&parent)
+                        .await
+                        .map_err(|e| format!("Failed to ensure target parent directory: {e}"))?;
+                    reject_symlink_destination_under_root(&target_root, &target_path).await?;
+
+                    let previous_backup = match item_snapshot.renamed_backup_path.as_deref() {
+                        Some(path) if tokio::fs::symlink_metadata(path).await.is_ok() => {
+                            Some(PathBuf::from(path))
+                        }
+                        _ => None,
+                    };
+                    if previous_backup.is_none()
+                        && tokio::fs::symlink_metadata(&target_path).await.is_err()
+                    {
+                        Err("Target file does not exist for safe copy rename".to_string())?;
+                    }
 
-    let mut relative_paths: Vec<PathBuf> = Vec::new();
-    let mut invalid_count = 0usize;
-    for raw_path in paths {
-        let candidate = PathBuf::from(&raw_path);
-        if candidate.is_absolute()
-            || candidate
-                .components()
-                .any(|component| matches!(component, Component::ParentDir))
-        {
-            invalid_count += 1;
-            continue;
-        }
-        relative_paths.push(candidate);
-    }
+                    let file_name = source_path
+                        .file_name()
+                        .and_then(|name| name.to_str())
+                        .unwrap_or("file");
+                    let (stem, ext) = match file_name.rsplit_once('.') {
+                        Some((left, right)) if !left.is_empty() && !right.is_empty() => {
+                            (left.to_string(), Some(right.to_string()))
+                        }
+                        _ => (file_name.to_string(), None),
+                    };
+                    let timestamp =
+                        safe_copy_timestamp_label(item_snapshot.source.modified_unix_ms);
 
-    // `delete_orphan_paths` only operates on `target`; source is intentionally unused here.
-    let engine = SyncEngine::new(PathBuf::from("."), target);
-    let mut result = engine
-        .delete_orphan_paths(&relative_paths)
-        .await
-        .map_err(|e| format!("{:#}", e))?;
-    result.skipped_count += invalid_count;
+                    let mut renamed_to = previous_backup;
+                    if renamed_to.is_none() {
+                        for attempt in 0..20u64 {
+                            let seq = state.conflict_review_seq.fetch_add(1, Ordering::SeqCst) + 1;
+                            let seed = (unix_now_ms() as u64)
+                                .wrapping_add(seq)
+                                .wrapping_add(attempt);
+                            let suffix = random_suffix_token(seed);
+                            let backup_name = if let Some(ext) = ext.as_deref() {
+                                format!("{stem}_{timestamp}_{suffix}.{ext}")
+                            } else {
+                                format!("{stem}_{timestamp}_{suffix}")
+                            };
+                            let backup_path = parent.as_path().join(backup_name);
+                            reject_symlink_destination_under_root(&target_root, &backup_path)
+                                .await?;
+                            if tokio::fs::symlink_metadata(&backup_path).await.is_ok() {
+                                continue;
+                            }
 
-    state.log_manager.log_with_category(
-        "info",
-        &format!(
-            "Orphan delete completed: files={}, dirs={}, total_deleted={}, skipped={}, failures={}",
-            result.deleted_files_count,
-            result.deleted_dirs_count,
-            result.deleted_count,
-            result.skipped_count,
-            result.failures.len()
-        ),
-        Some(task_id.clone()),
-        LogCategory::FileDeleted,
-    );
+                            tokio::fs::rename(&target_path, &backup_path)
+                                .await
+                                .map_err(|e| format!("Failed to rename target file: {e}"))?;
+                            if let Some(item) = state
+                                .conflict_review_sessions
+                                .write()
+                                .await
+                                .get_mut(&session_id)
+                                .and_then(|session| {
+                                    session
+                                        .items
+                                        .iter_mut()
+                                        .find(|item| item.id == request.item_id)
+                                })
+                            {
+                                item.renamed_backup_path =
+                                    Some(backup_path.to_string_lossy().to_string());
+                            }
+                            renamed_to = Some(backup_path);
+                            break;
+                        }
+                    }
 
-    if !result.failures.is_empty() {
-        state.log_manager.log_with_category(
-            "warning",
-            &format!("Orphan delete failures: {}", result.failures.len()),
-            Some(task_id),
-            LogCategory::Other,
-        );
-    } else {
-        state.log_manager.log_with_category(
-            "success",
-            "Orphan delete completed without failures",
-            Some(task_id),
-            LogCategory::Other,
-        );
-    }
+                    let renamed_to = match renamed_to {
+                        Some(value) => value,
+                        None => {
+                            Err("Failed to generate non-conflicting backup file name".to_string())?
+                        }
+                    };
 
-    Ok(result)
-}
+                    copy_file_preserve_under_root(&source_path, &target_root, &target_path)
+                        .await
+                        .map(|_| {
+                            (
+                                ConflictItemStatus::SafeCopied,
+                                Some(format!(
+                                    "Renamed existing target to '{}' and copied source file.",
+                                    renamed_to.to_string_lossy()
+                                )),
+                            )
+                        })
+                        .map_err(|e| {
+                            format!(
+                                "Target was renamed to '{}' but source copy failed: {}",
+                                renamed_to.to_string_lossy(),
+                                e
+                            )
+                        })
+                }
+                .await;
 
-#[tauri::command]
-async fn list_conflict_review_sessions(
-    state: tauri::State<'_, AppState>,
-) -> Result<Vec<ConflictSessionSummary>, String> {
-    Ok(list_conflict_session_summaries_internal(state.inner()).await)
-}
+                finish_runtime_producer(
+                    &producer_id,
+                    &target_key,
+                    apply_result.is_ok(),
+                    app,
+                    state,
+                )
+                .await;
 
-#[tauri::command]
-async fn get_conflict_review_session(
-    session_id: String,
-    state: tauri::State<'_, AppState>,
-) -> Result<ConflictSessionDetail, String> {
-    let sessions = state.conflict_review_sessions.read().await;
-    let session = sessions
-        .get(&session_id)
-        .ok_or_else(|| format!("Conflict session not found: {session_id}"))?;
-    Ok(to_conflict_detail(session))
-}
+                apply_result
+            }
+        };
 
-#[tauri::command]
-async fn open_conflict_review_window(
-    session_id: String,
-    app: tauri::AppHandle,
-    state: tauri::State<'_, AppState>,
-) -> Result<(), String> {
-    {
-        let sessions = state.conflict_review_sessions.read().await;
-        if !sessions.contains_key(&session_id) {
-            return Err(format!("Conflict session not found: {session_id}"));
+        match apply_result {
+            Ok((next_status, note)) => {
+                processed_count += 1;
+                let note_for_log = note.clone();
+                let mut sessions = state.conflict_review_sessions.write().await;
+                if let Some(session) = sessions.get_mut(&session_id) {
+                    if let Some(item) = session
+                        .items
+                        .iter_mut()
+                        .find(|item| item.id == request.item_id)
+                    {
+                        item.status = next_status;
+                        item.note = note;
+                        item.resolved_at_unix_ms = Some(unix_now_ms());
+                    }
+                }
+                drop(sessions);
+                log_conflict_resolution_success(
+                    state,
+                    app,
+                    &session_task_id,
+                    &session_id,
+                    &item_snapshot,
+                    &request.action,
+                    note_for_log.as_deref(),
+                );
+            }
+            Err(error) => {
+                failures.push(ConflictResolutionFailure {
+                    item_id: request.item_id.clone(),
+                    message: error.clone(),
+                });
+                let mut sessions = state.conflict_review_sessions.write().await;
+                if let Some(session) = sessions.get_mut(&session_id) {
+                    if let Some(item) = session
+                        .items
+                        .iter_mut()
+                        .find(|item| item.id == request.item_id)
+                    {
+                        item.note = Some(error);
+                    }
+                }
+                drop(sessions);
+                log_conflict_resolution_failure(
+                    state,
+                    app,
+                    &session_task_id,
+                    &session_id,
+                    &item_snapshot,
+                    &request.action,
+                    failures
+                        .last()
+                        .map(|failure| failure.message.as_str())
+                        .unwrap_or("Conflict resolution failed"),
+                );
+            }
         }
     }
 
-    if let Some(window) = app.get_webview_window("conflict-review") {
-        let _ = window.emit(
-            "conflict-review-open-session",
-            ConflictReviewOpenSessionEvent {
+    let pending_count = {
+        let sessions = state.conflict_review_sessions.read().await;
+        let session = sessions
+            .get(&session_id)
+            .ok_or_else(|| format!("Conflict session not found: {session_id}"))?;
+        pending_conflict_count(&session.items)
+    };
+
+    if let Some(app) = app {
+        emit_conflict_review_queue_changed(app, state).await;
+        let _ = app.emit(
+            "conflict-review-session-updated",
+            ConflictReviewSessionUpdatedEvent {
                 session_id: session_id.clone(),
+                pending_count,
             },
         );
-        let _ = window.show();
-        let _ = window.unminimize();
-        let _ = window.set_focus();
-        return Ok(());
     }
 
-    let url =
-        WebviewUrl::App(format!("index.html?view=conflict-review&sessionId={session_id}").into());
-    let window = WebviewWindowBuilder::new(&app, "conflict-review", url)
-        .title("SyncWatcher - Conflict Review")
-        .inner_size(1320.0, 900.0)
-        .resizable(true)
-        .build()
-        .map_err(|e| format!("Failed to open conflict review window: {e}"))?;
-    let _ = window.show();
-    let _ = window.set_focus();
-    Ok(())
+    let result = ConflictResolutionResult {
+        session_id,
+        requested_count: resolutions.len(),
+        processed_count,
+        pending_count,
+        failures,
+    };
+    if let Some(key) = idempotency_key {
+        let mut sessions = state.conflict_review_sessions.write().await;
+        if let Some(session) = sessions.get_mut(&result.session_id) {
+            session.applied_resolutions.insert(key, result.clone());
+        }
+    }
+
+    Ok(result)
+}
+
+const MAX_CONFLICT_SESSION_LABEL_CHARS: usize = 120;
+
+/// 세션 이름을 바꿉니다. 공백뿐인 이름이나 `None`은 이름을 지웁니다.
+async fn rename_conflict_session_internal(
+    session_id: &str,
+    label: Option<String>,
+    state: &AppState,
+) -> Result<ConflictSessionSummary, String> {
+    let label = label
+        .map(|label| label.trim().to_string())
+        .filter(|label| !label.is_empty());
+    if let Some(label) = &label {
+        if label.chars().count() > MAX_CONFLICT_SESSION_LABEL_CHARS {
+            return Err(format!(
+                "Conflict session label must be at most {MAX_CONFLICT_SESSION_LABEL_CHARS} characters"
+            ));
+        }
+    }
+
+    let mut sessions = state.conflict_review_sessions.write().await;
+    let Some(session) = sessions.get_mut(session_id) else {
+        return Err(format!("Conflict session not found: {session_id}"));
+    };
+    session.label = label;
+    Ok(to_conflict_summary(session))
 }
 
 #[tauri::command]
-async fn resolve_conflict_items(
+async fn rename_conflict_session(
     session_id: String,
-    resolutions: Vec<ConflictResolutionRequest>,
+    label: Option<String>,
     app: tauri::AppHandle,
     state: tauri::State<'_, AppState>,
-) -> Result<ConflictResolutionResult, String> {
-    resolve_conflict_items_internal(session_id, resolutions, Some(&app), state.inner()).await
+) -> Result<ConflictSessionSummary, String> {
+    let summary = rename_conflict_session_internal(&session_id, label, state.inner()).await?;
+    emit_conflict_review_queue_changed(&app, state.inner()).await;
+    Ok(summary)
 }
 
-async fn resolve_conflict_items_internal(
+#[tauri::command]
+async fn close_conflict_review_session(
     session_id: String,
-    resolutions: Vec<ConflictResolutionRequest>,
+    force_skip_pending: bool,
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> Result<CloseConflictReviewSessionResult, String> {
+    close_conflict_review_session_internal(
+        session_id,
+        force_skip_pending,
+        Some(&app),
+        state.inner(),
+    )
+    .await
+}
+
+async fn close_conflict_review_session_internal(
+    session_id: String,
+    force_skip_pending: bool,
     app: Option<&tauri::AppHandle>,
     state: &AppState,
-) -> Result<ConflictResolutionResult, String> {
-    if resolutions.is_empty() {
-        let pending_count = {
-            let sessions = state.conflict_review_sessions.read().await;
-            let session = sessions
-                .get(&session_id)
-                .ok_or_else(|| format!("Conflict session not found: {session_id}"))?;
-            pending_conflict_count(&session.items)
-        };
-        return Ok(ConflictResolutionResult {
-            session_id,
-            requested_count: 0,
-            processed_count: 0,
-            pending_count,
-            failures: Vec::new(),
+) -> Result<CloseConflictReviewSessionResult, String> {
+    let mut sessions = state.conflict_review_sessions.write().await;
+    let Some(session) = sessions.get_mut(&session_id) else {
+        return Err(format!("Conflict session not found: {session_id}"));
+    };
+
+    let had_pending = pending_conflict_count(&session.items) > 0;
+    if had_pending && !force_skip_pending {
+        return Ok(CloseConflictReviewSessionResult {
+            closed: false,
+            had_pending: true,
+            skipped_count: 0,
         });
     }
 
-    {
-        let sessions = state.conflict_review_sessions.read().await;
-        if !sessions.contains_key(&session_id) {
-            return Err(format!("Conflict session not found: {session_id}"));
+    let session_task_id = session.task_id.clone();
+    let mut skipped_count = 0usize;
+    if had_pending && force_skip_pending {
+        for item in &mut session.items {
+            if item.status == ConflictItemStatus::Pending {
+                item.status = ConflictItemStatus::Skipped;
+                item.note = Some("Skipped when session closed with pending items.".to_string());
+                item.resolved_at_unix_ms = Some(unix_now_ms());
+                skipped_count += 1;
+                log_conflict_skip_on_close(state, app, &session_task_id, &session_id, item);
+            }
         }
     }
 
-    let mut processed_count = 0usize;
-    let mut failures = Vec::new();
+    sessions.remove(&session_id);
+    drop(sessions);
 
-    for request in &resolutions {
-        let item_snapshot = {
-            let sessions = state.conflict_review_sessions.read().await;
-            let Some(session) = sessions.get(&session_id) else {
-                break;
-            };
-            session
-                .items
-                .iter()
-                .find(|item| item.id == request.item_id)
-                .cloned()
-                .map(|item| (item, session.task_id.clone(), session.target_root.clone()))
-        };
+    if let Some(app) = app {
+        emit_conflict_review_queue_changed(app, state).await;
+        let _ = app.emit(
+            "conflict-review-session-updated",
+            ConflictReviewSessionUpdatedEvent {
+                session_id,
+                pending_count: 0,
+            },
+        );
+    }
 
-        let Some((item_snapshot, session_task_id, session_target_root)) = item_snapshot else {
-            failures.push(ConflictResolutionFailure {
-                item_id: request.item_id.clone(),
-                message: "Conflict item not found".to_string(),
-            });
-            continue;
-        };
+    Ok(CloseConflictReviewSessionResult {
+        closed: true,
+        had_pending,
+        skipped_count,
+    })
+}
 
-        if item_snapshot.status != ConflictItemStatus::Pending {
-            continue;
-        }
+#[tauri::command]
+async fn get_conflict_item_preview(
+    session_id: String,
+    item_id: String,
+    max_bytes: Option<usize>,
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> Result<ConflictPreviewPayload, String> {
+    let (source_path, target_path) = {
+        let sessions = state.conflict_review_sessions.read().await;
+        let session = sessions
+            .get(&session_id)
+            .ok_or_else(|| format!("Conflict session not found: {session_id}"))?;
+        let item = session
+            .items
+            .iter()
+            .find(|entry| entry.id == item_id)
+            .ok_or_else(|| format!("Conflict item not found: {item_id}"))?;
+        (item.source_path.clone(), item.target_path.clone())
+    };
 
-        let source_path = PathBuf::from(&item_snapshot.source_path);
-        let target_path = PathBuf::from(&item_snapshot.target_path);
-        let target_root = PathBuf::from(session_target_root);
+    let max_bytes = max_bytes
+        .unwrap_or(CONFLICT_PREVIEW_DEFAULT_BYTES)
+        .clamp(1024, CONFLICT_PREVIEW_MAX_BYTES);
+    let source_head = read_preview_head(&source_path).await;
+    let (resolved_kind, mime) = resolve_preview_kind(&source_path, &source_head);
+    let mut kind = resolved_kind.to_string();
 
-        match (
-            read_current_conflict_file_info(&source_path).await,
-            read_current_conflict_file_info(&target_path).await,
-        ) {
-            (Ok(current_source), Ok(current_target)) => {
-                let source_changed =
-                    conflict_file_info_changed(&item_snapshot.source, &current_source);
-                let target_changed =
-                    conflict_file_info_changed(&item_snapshot.target, &current_target);
-                if source_changed || target_changed {
-                    state.log_manager.log_with_category(
-                        "warning",
-                        &format!(
-                            "Conflict item changed since detection: {} (source_changed={}, target_changed={})",
-                            item_snapshot.relative_path, source_changed, target_changed
-                        ),
-                        Some(session_task_id.clone()),
-                        LogCategory::Other,
-                    );
-                }
-            }
-            (Err(source_err), Err(target_err)) => {
-                state.log_manager.log_with_category(
-                    "warning",
-                    &format!(
-                        "Conflict preflight metadata check failed for source and target ({}): source_error='{}', target_error='{}'",
-                        item_snapshot.relative_path, source_err, target_err
-                    ),
-                    Some(session_task_id.clone()),
-                    LogCategory::Other,
-                );
-            }
-            (Err(source_err), _) => {
-                state.log_manager.log_with_category(
-                    "warning",
-                    &format!(
-                        "Conflict preflight metadata check failed for source ({}): {}",
-                        item_snapshot.relative_path, source_err
-                    ),
-                    Some(session_task_id.clone()),
-                    LogCategory::Other,
-                );
-            }
-            (_, Err(target_err)) => {
-                state.log_manager.log_with_category(
-                    "warning",
-                    &format!(
-                        "Conflict preflight metadata check failed for target ({}): {}",
-                        item_snapshot.relative_path, target_err
-                    ),
-                    Some(session_task_id.clone()),
-                    LogCategory::Other,
-                );
-            }
-        }
+    let mut source_text = None;
+    let mut target_text = None;
+    let mut source_truncated = false;
+    let mut target_truncated = false;
 
-        let apply_result: Result<(ConflictItemStatus, Option<String>), String> = match request
-            .action
-        {
-            ConflictResolutionAction::Skip => Ok((
-                ConflictItemStatus::Skipped,
-                Some("User chose to skip this conflict item.".to_string()),
-            )),
-            ConflictResolutionAction::ForceCopy => {
-                let producer_id = runtime_conflict_producer_id(
-                    &session_id,
-                    &request.item_id,
-                    RuntimeProducerKind::ConflictForceCopy,
-                );
-                let target_key = resolved_path_key(target_path.to_string_lossy().as_ref())?;
-                register_runtime_producer(
-                    producer_id.clone(),
-                    RuntimeProducerKind::ConflictForceCopy,
-                    target_key.clone(),
-                    state,
-                )
-                .await;
+    if kind == "text" {
+        let (left, left_truncated) = read_text_preview(&source_path, max_bytes).await;
+        let (right, right_truncated) = read_text_preview(&target_path, max_bytes).await;
+        source_text = left;
+        target_text = right;
+        source_truncated = left_truncated;
+        target_truncated = right_truncated;
+        if source_text.is_none() || target_text.is_none() {
+            kind = "other".to_string();
+        }
+    } else if matches!(kind.as_str(), "image" | "video") {
+        allow_asset_preview_file(&app, &source_path)?;
+        allow_asset_preview_file(&app, &target_path)?;
+    }
 
-                let apply_result =
-                    copy_file_preserve_under_root(&source_path, &target_root, &target_path)
-                        .await
-                        .map(|_| {
-                            (
-                                ConflictItemStatus::ForceCopied,
-                                Some("Copied source file to target (force overwrite).".to_string()),
-                            )
-                        });
+    Ok(ConflictPreviewPayload {
+        kind,
+        source_text,
+        target_text,
+        source_truncated,
+        target_truncated,
+        mime: mime.map(str::to_string),
+    })
+}
 
-                finish_runtime_producer(
-                    &producer_id,
-                    &target_key,
-                    apply_result.is_ok(),
-                    app,
-                    state,
-                )
-                .await;
+/// 줄 단위 diff에서 변경 구간만 뽑습니다. 출력 줄 수가 `max_lines`를 넘으면 중단합니다.
+fn compute_conflict_text_diff_hunks(
+    source: &str,
+    target: &str,
+    max_lines: usize,
+) -> (Vec<ConflictTextDiffHunk>, bool) {
+    let diff = similar::TextDiff::from_lines(source, target);
+    let old_lines = diff.old_slices();
+    let new_lines = diff.new_slices();
+    let to_lines = |slices: &[&str], range: std::ops::Range<usize>| -> Vec<String> {
+        slices[range]
+            .iter()
+            .map(|line| line.trim_end_matches(['\r', '\n']).to_string())
+            .collect()
+    };
 
-                apply_result
-            }
-            ConflictResolutionAction::RenameThenCopy => {
-                let producer_id = runtime_conflict_producer_id(
-                    &session_id,
-                    &request.item_id,
-                    RuntimeProducerKind::ConflictRenameThenCopy,
-                );
-                let target_key = resolved_path_key(target_path.to_string_lossy().as_ref())?;
-                register_runtime_producer(
-                    producer_id.clone(),
-                    RuntimeProducerKind::ConflictRenameThenCopy,
-                    target_key.clone(),
-                    state,
-                )
-                .await;
+    let mut hunks = Vec::new();
+    let mut emitted_lines = 0usize;
+    for group in diff.grouped_ops(0) {
+        let (Some(first), Some(last)) = (group.first(), group.last()) else {
+            continue;
+        };
+        let source_range = first.old_range().start..last.old_range().end;
+        let target_range = first.new_range().start..last.new_range().end;
+        let hunk_lines = source_range.len() + target_range.len();
+        if emitted_lines + hunk_lines > max_lines && !hunks.is_empty() {
+            return (hunks, true);
+        }
+        emitted_lines += hunk_lines;
+        hunks.push(ConflictTextDiffHunk {
+            source_start: source_range.start + 1,
+            source_lines: to_lines(old_lines, source_range),
+            target_start: target_range.start + 1,
+            target_lines: to_lines(new_lines, target_range),
+        });
+    }
 
-                let apply_result = async {
-                    let source_path = source_path.clone();
-                    let target_path = target_path.clone();
-                    let target_root = target_root.clone();
-                    let parent = match target_path.parent() {
-                        Some(value) => value.to_path_buf(),
-                        None => Err("Target file parent directory is invalid".to_string())?,
-                    };
-                    reject_symlink_destination_under_root(&target_root, &target_path).await?;
-                    tokio::fs::create_dir_all(&parent)
-                        .await
-                        .map_err(|e| format!("Failed to ensure target parent directory: {e}"))?;
-                    reject_symlink_destination_under_root(&target_root, &target_path).await?;
+    (hunks, false)
+}
 
-                    if tokio::fs::symlink_metadata(&target_path).await.is_err() {
-                        Err("Target file does not exist for safe copy rename".to_string())?;
-                    }
+/// 텍스트 충돌 항목의 변경 구간만 계산합니다. 양쪽 전체 내용을 보내는 미리보기보다 가볍습니다.
+async fn get_conflict_text_diff_internal(
+    session_id: &str,
+    item_id: &str,
+    max_lines: Option<usize>,
+    state: &AppState,
+) -> Result<ConflictTextDiffPayload, String> {
+    let (source_path, target_path) = {
+        let sessions = state.conflict_review_sessions.read().await;
+        let session = sessions
+            .get(session_id)
+            .ok_or_else(|| format!("Conflict session not found: {session_id}"))?;
+        let item = session
+            .items
+            .iter()
+            .find(|entry| entry.id == item_id)
+            .ok_or_else(|| format!("Conflict item not found: {item_id}"))?;
+        (item.source_path.clone(), item.target_path.clone())
+    };
 
-                    let file_name = source_path
-                        .file_name()
-                        .and_then(|name| name.to_str())
-                        .unwrap_or("file");
-                    let (stem, ext) = match file_name.rsplit_once('.') {
-                        Some((left, right)) if !left.is_empty() && !right.is_empty() => {
-                            (left.to_string(), Some(right.to_string()))
-                        }
-                        _ => (file_name.to_string(), None),
-                    };
-                    let timestamp =
-                        safe_copy_timestamp_label(item_snapshot.source.modified_unix_ms);
+    let (source_text, source_truncated) =
+        read_text_preview(&source_path, CONFLICT_PREVIEW_DEFAULT_BYTES).await;
+    let (target_text, target_truncated) =
+        read_text_preview(&target_path, CONFLICT_PREVIEW_DEFAULT_BYTES).await;
+    let (Some(source_text), Some(target_text)) = (source_text, target_text) else {
+        return Err(format!("Conflict item is not a UTF-8 text file: {item_id}"));
+    };
 
-                    let mut renamed_to: Option<PathBuf> = None;
-                    for attempt in 0..20u64 {
-                        let seq = state.conflict_review_seq.fetch_add(1, Ordering::SeqCst) + 1;
-                        let seed = (unix_now_ms() as u64)
-                            .wrapping_add(seq)
-                            .wrapping_add(attempt);
-                        let suffix = random_suffix_token(seed);
-                        let backup_name = if let Some(ext) = ext.as_deref() {
-                            format!("{stem}_{timestamp}_{suffix}.{ext}")
-                        } else {
-                            format!("{stem}_{timestamp}_{suffix}")
-                        };
-                        let backup_path = parent.as_path().join(backup_name);
-                        reject_symlink_destination_under_root(&target_root, &backup_path).await?;
-                        if tokio::fs::symlink_metadata(&backup_path).await.is_ok() {
-                            continue;
-                        }
+    let max_lines = max_lines.unwrap_or(200).clamp(1, 5000);
+    let (hunks, hunks_truncated) =
+        compute_conflict_text_diff_hunks(&source_text, &target_text, max_lines);
 
-                        tokio::fs::rename(&target_path, &backup_path)
-                            .await
-                            .map_err(|e| format!("Failed to rename target file: {e}"))?;
-                        renamed_to = Some(backup_path);
-                        break;
-                    }
+    Ok(ConflictTextDiffPayload {
+        hunks,
+        hunks_truncated,
+        source_truncated,
+        target_truncated,
+    })
+}
 
-                    let renamed_to = match renamed_to {
-                        Some(value) => value,
-                        None => {
-                            Err("Failed to generate non-conflicting backup file name".to_string())?
-                        }
-                    };
+#[tauri::command]
+async fn get_conflict_text_diff(
+    session_id: String,
+    item_id: String,
+    max_lines: Option<usize>,
+    state: tauri::State<'_, AppState>,
+) -> Result<ConflictTextDiffPayload, String> {
+    get_conflict_text_diff_internal(&session_id, &item_id, max_lines, state.inner()).await
+}
 
-                    copy_file_preserve_under_root(&source_path, &target_root, &target_path)
-                        .await
-                        .map(|_| {
-                            (
-                                ConflictItemStatus::SafeCopied,
-                                Some(format!(
-                                    "Renamed existing target to '{}' and copied source file.",
-                                    renamed_to.to_string_lossy()
-                                )),
-                            )
-                        })
-                        .map_err(|e| {
-                            format!(
-                                "Target was renamed to '{}' but source copy failed: {}",
-                                renamed_to.to_string_lossy(),
-                                e
-                            )
-                        })
-                }
-                .await;
+#[tauri::command]
+fn list_volumes() -> Result<Vec<system_integration::VolumeInfo>, String> {
+    let monitor = DiskMonitor::new();
+    monitor.list_volumes().map_err(|e| e.to_string())
+}
 
-                finish_runtime_producer(
-                    &producer_id,
-                    &target_key,
-                    apply_result.is_ok(),
-                    app,
-                    state,
-                )
-                .await;
+/// 태스크 소스가 의존하는 볼륨 (카드 연결 체크리스트용)
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RequiredSourceVolume {
+    /// `DISK_UUID` / `VOLUME_UUID` / `UUID`(레거시) / `PATH`
+    token_type: String,
+    /// UUID 토큰이면 UUID, 일반 경로면 `/Volumes/<이름>` 마운트 루트
+    identifier: String,
+    task_ids: Vec<String>,
+    mounted: bool,
+    mount_point: Option<PathBuf>,
+    volume_name: Option<String>,
+}
+
+/// `/Volumes/<이름>/...` 형태의 일반 경로에서 볼륨 마운트 루트를 추출
+fn plain_source_volume_root(source: &str) -> Option<PathBuf> {
+    let mut components = Path::new(source).components();
+    if components.next() != Some(Component::RootDir) {
+        return None;
+    }
+    if components.next() != Some(Component::Normal(OsStr::new("Volumes"))) {
+        return None;
+    }
+    match components.next()? {
+        Component::Normal(name) => Some(Path::new("/Volumes").join(name)),
+        _ => None,
+    }
+}
 
-                apply_result
+/// 태스크 소스를 볼륨 단위로 묶어 중복을 제거합니다 (태스크 순서상 첫 등장 순).
+/// 형식이 잘못된 UUID 토큰과 `/Volumes` 밖의 일반 경로는 제외합니다.
+fn collect_required_source_volumes(
+    tasks: &[RuntimeSyncTask],
+    volumes: &[system_integration::VolumeInfo],
+) -> Vec<RequiredSourceVolume> {
+    let mut required: Vec<RequiredSourceVolume> = Vec::new();
+    for task in tasks {
+        let (token_type, identifier, volume) = if has_uuid_source_prefix(&task.source) {
+            let Some(parsed) = parse_uuid_source_path(&task.source) else {
+                continue;
+            };
+            let uuid = parsed.uuid.trim();
+            if uuid.is_empty() {
+                continue;
             }
+            (
+                uuid_token_label(parsed.token_type),
+                uuid.to_string(),
+                find_volume_for_uuid(volumes, parsed.token_type, uuid),
+            )
+        } else {
+            let Some(root) = plain_source_volume_root(&task.source) else {
+                continue;
+            };
+            let volume = volumes.iter().find(|volume| volume.mount_point == root);
+            ("PATH", root.to_string_lossy().to_string(), volume)
         };
 
-        match apply_result {
-            Ok((next_status, note)) => {
-                processed_count += 1;
-                let note_for_log = note.clone();
-                let mut sessions = state.conflict_review_sessions.write().await;
-                if let Some(session) = sessions.get_mut(&session_id) {
-                    if let Some(item) = session
-                        .items
-                        .iter_mut()
-                        .find(|item| item.id == request.item_id)
-                    {
-                        item.status = next_status;
-                        item.note = note;
-                        item.resolved_at_unix_ms = Some(unix_now_ms());
-                    }
-                }
-                drop(sessions);
-                log_conflict_resolution_success(
-                    state,
-                    app,
-                    &session_task_id,
-                    &session_id,
-                    &item_snapshot,
-                    &request.action,
-                    note_for_log.as_deref(),
-                );
-            }
-            Err(error) => {
-                failures.push(ConflictResolutionFailure {
-                    item_id: request.item_id.clone(),
-                    message: error.clone(),
-                });
-                let mut sessions = state.conflict_review_sessions.write().await;
-                if let Some(session) = sessions.get_mut(&session_id) {
-                    if let Some(item) = session
-                        .items
-                        .iter_mut()
-                        .find(|item| item.id == request.item_id)
-                    {
-                        item.note = Some(error);
-                    }
-                }
-                drop(sessions);
-                log_conflict_resolution_failure(
-                    state,
-                    app,
-                    &session_task_id,
-                    &session_id,
-                    &item_snapshot,
-                    &request.action,
-                    failures
-                        .last()
-                        .map(|failure| failure.message.as_str())
-                        .unwrap_or("Conflict resolution failed"),
-                );
+        if let Some(existing) = required
+            .iter_mut()
+            .find(|entry| entry.token_type == token_type && entry.identifier == identifier)
+        {
+            if !existing.task_ids.contains(&task.id) {
+                existing.task_ids.push(task.id.clone());
             }
+            continue;
         }
+        required.push(RequiredSourceVolume {
+            token_type: token_type.to_string(),
+            identifier,
+            task_ids: vec![task.id.clone()],
+            mounted: volume.is_some(),
+            mount_point: volume.map(|volume| volume.mount_point.clone()),
+            volume_name: volume.map(|volume| volume.name.clone()),
+        });
     }
+    required
+}
 
-    let pending_count = {
-        let sessions = state.conflict_review_sessions.read().await;
-        let session = sessions
-            .get(&session_id)
-            .ok_or_else(|| format!("Conflict session not found: {session_id}"))?;
-        pending_conflict_count(&session.items)
-    };
+#[tauri::command]
+async fn list_required_source_volumes(
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<RequiredSourceVolume>, String> {
+    let tasks = state.runtime_config.read().await.tasks.clone();
+    let volumes = tokio::task::spawn_blocking(|| DiskMonitor::new().list_volumes())
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())?;
+    Ok(collect_required_source_volumes(&tasks, &volumes))
+}
 
-    if let Some(app) = app {
-        emit_conflict_review_queue_changed(app, state).await;
-        let _ = app.emit(
-            "conflict-review-session-updated",
-            ConflictReviewSessionUpdatedEvent {
-                session_id: session_id.clone(),
-                pending_count,
-            },
-        );
+#[tauri::command]
+fn get_removable_volumes() -> Result<Vec<system_integration::VolumeInfo>, String> {
+    let monitor = DiskMonitor::new();
+    monitor.get_removable_volumes().map_err(|e| e.to_string())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PathRemovableInfo {
+    is_removable: bool,
+    is_network: bool,
+    volume_name: Option<String>,
+    mount_point: Option<String>,
+}
+
+/// 경로를 포함하는 볼륨 중 마운트 포인트가 가장 긴 것을 찾습니다.
+fn find_owning_volume<'a>(
+    path: &Path,
+    volumes: &'a [system_integration::VolumeInfo],
+) -> Option<&'a system_integration::VolumeInfo> {
+    let path_key = path_key_for_compare(path);
+    volumes
+        .iter()
+        .map(|volume| (path_key_for_compare(&volume.mount_point), volume))
+        .filter(|(mount_key, _)| mount_key == "/" || is_same_or_subpath(mount_key, &path_key))
+        .max_by_key(|(mount_key, _)| mount_key.len())
+        .map(|(_, volume)| volume)
+}
+
+fn path_removable_info(
+    path: &Path,
+    volumes: &[system_integration::VolumeInfo],
+) -> PathRemovableInfo {
+    match find_owning_volume(path, volumes) {
+        Some(volume) => PathRemovableInfo {
+            is_removable: volume.is_removable,
+            is_network: volume.is_network,
+            volume_name: Some(volume.name.clone()),
+            mount_point: Some(volume.mount_point.to_string_lossy().to_string()),
+        },
+        None => PathRemovableInfo {
+            is_removable: false,
+            is_network: false,
+            volume_name: None,
+            mount_point: None,
+        },
+    }
+}
+
+const BENCHMARK_WRITE_MIN_BYTES: u64 = 1024 * 1024;
+const BENCHMARK_WRITE_MAX_BYTES: u64 = 256 * 1024 * 1024;
+const BENCHMARK_WRITE_CHUNK_BYTES: usize = 1024 * 1024;
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TargetWriteBenchmark {
+    bytes_written: u64,
+    elapsed_ms: u64,
+    /// 10^6 bytes 기준 MB/s
+    megabytes_per_sec: f64,
+    volume: PathRemovableInfo,
+}
+
+/// 타겟에 임시 파일을 써서 쓰기 속도를 잽니다. fsync까지 포함해 캐시가 아닌 장치 속도를 보며,
+/// 성공·실패와 관계없이 임시 파일은 지웁니다. 크기는 BENCHMARK_WRITE_MIN/MAX_BYTES로 맞춥니다.
+async fn benchmark_target_write_internal(
+    target: &Path,
+    size_bytes: u64,
+    volumes: &[system_integration::VolumeInfo],
+) -> Result<TargetWriteBenchmark, String> {
+    use tokio::io::AsyncWriteExt;
+
+    let metadata = tokio::fs::metadata(target)
+        .await
+        .map_err(|e| format!("Target is not accessible: {e}"))?;
+    if !metadata.is_dir() {
+        return Err("Target must be a directory".to_string());
+    }
+
+    let size_bytes = size_bytes.clamp(BENCHMARK_WRITE_MIN_BYTES, BENCHMARK_WRITE_MAX_BYTES);
+    let probe_path = target.join(format!(
+        ".syncwatcher-write-benchmark-{}-{}.tmp",
+        std::process::id(),
+        unix_now_ms()
+    ));
+    let chunk: Vec<u8> = (0..BENCHMARK_WRITE_CHUNK_BYTES)
+        .map(|i| (i % 251) as u8)
+        .collect();
+
+    let started = Instant::now();
+    let write_result = async {
+        let mut file = tokio::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&probe_path)
+            .await?;
+        let mut remaining = size_bytes;
+        while remaining > 0 {
+            let n = remaining.min(chunk.len() as u64) as usize;
+            file.write_all(&chunk[..n]).await?;
+            remaining -= n as u64;
+        }
+        file.sync_all().await
     }
+    .await;
+    let elapsed = started.elapsed();
+    let _ = tokio::fs::remove_file(&probe_path).await;
+    write_result.map_err(|e| format!("Failed to write benchmark file: {e}"))?;
+
+    let seconds = elapsed.as_secs_f64().max(f64::EPSILON);
+    Ok(TargetWriteBenchmark {
+        bytes_written: size_bytes,
+        elapsed_ms: elapsed.as_millis() as u64,
+        megabytes_per_sec: size_bytes as f64 / 1_000_000.0 / seconds,
+        volume: path_removable_info(target, volumes),
+    })
+}
 
-    Ok(ConflictResolutionResult {
-        session_id,
-        requested_count: resolutions.len(),
-        processed_count,
-        pending_count,
-        failures,
+/// 새 드라이브를 백업 대상으로 정하기 전에 쓰기 속도를 확인합니다.
+#[tauri::command]
+async fn benchmark_target_write(
+    target: String,
+    size_bytes: u64,
+) -> Result<TargetWriteBenchmark, String> {
+    let resolved = resolve_path_with_uuid(&target).map_err(|e| e.to_string())?;
+    input_validation::validate_path_argument(resolved.to_str().unwrap_or(""))
+        .map_err(|e| e.to_string())?;
+    let resolved = std::fs::canonicalize(&resolved).unwrap_or(resolved);
+    let volumes = tokio::task::spawn_blocking(|| DiskMonitor::new().list_volumes())
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())?;
+    benchmark_target_write_internal(&resolved, size_bytes, &volumes).await
+}
+
+/// 양쪽에 모두 있는 큰 파일을 블록 단위 delta로 옮길 때 실제 전송량을 미리 계산합니다 (쓰기 없음)
+#[tauri::command]
+async fn plan_block_delta_sync(
+    source_path: String,
+    target_path: String,
+    block_size: Option<usize>,
+) -> Result<DeltaTransferStats, String> {
+    let source = resolve_path_with_uuid(&source_path).map_err(|e| e.to_string())?;
+    input_validation::validate_path_argument(source.to_str().unwrap_or(""))
+        .map_err(|e| e.to_string())?;
+    input_validation::validate_path_argument(&target_path).map_err(|e| e.to_string())?;
+    let target = PathBuf::from(target_path);
+    let block_size = block_size
+        .unwrap_or(sync_engine::delta::DEFAULT_DELTA_BLOCK_SIZE)
+        .clamp(1024, 16 * 1024 * 1024);
+
+    tokio::task::spawn_blocking(move || {
+        sync_engine::delta::plan_block_delta(&source, &target, block_size)
     })
+    .await
+    .map_err(|e| e.to_string())?
+    .map_err(|e| e.to_string())
 }
 
+const TARGET_LAYOUT_PREVIEW_DEFAULT_SAMPLES: usize = 50;
+const TARGET_LAYOUT_PREVIEW_MAX_SAMPLES: usize = 1_000;
+
+/// 타겟 레이아웃을 적용하기 전에 소스 파일이 어디로 옮겨질지와 충돌을 미리 보여줍니다 (쓰기 없음)
 #[tauri::command]
-async fn close_conflict_review_session(
-    session_id: String,
-    force_skip_pending: bool,
-    app: tauri::AppHandle,
+async fn preview_target_layout(
+    source: String,
+    layout: TargetLayout,
+    sample_limit: Option<usize>,
+    exclude_patterns: Option<Vec<String>>,
+) -> Result<TargetLayoutPreview, String> {
+    let source = resolve_path_with_uuid(&source).map_err(|e| e.to_string())?;
+    input_validation::validate_path_argument(source.to_str().unwrap_or(""))
+        .map_err(|e| e.to_string())?;
+    let exclude_patterns = exclude_patterns.unwrap_or_default();
+    input_validation::validate_exclude_patterns(&exclude_patterns).map_err(|e| e.to_string())?;
+    let sample_limit = sample_limit
+        .unwrap_or(TARGET_LAYOUT_PREVIEW_DEFAULT_SAMPLES)
+        .min(TARGET_LAYOUT_PREVIEW_MAX_SAMPLES);
+
+    SyncEngine::new(source, PathBuf::from("."))
+        .preview_target_layout(&layout, &exclude_patterns, sample_limit)
+        .await
+        .map_err(|e| format!("{:#}", e))
+}
+
+/// 큰 트리를 비교하기 전에 항목 수만 세어 비교 단계의 최대 메모리 사용량을 추정합니다 (쓰기 없음)
+#[tauri::command]
+async fn estimate_scan_memory(
+    path: String,
+    exclude_patterns: Option<Vec<String>>,
+    warn_threshold_mb: Option<u64>,
+) -> Result<ScanMemoryEstimate, String> {
+    let path = resolve_path_with_uuid(&path).map_err(|e| e.to_string())?;
+    input_validation::validate_path_argument(path.to_str().unwrap_or(""))
+        .map_err(|e| e.to_string())?;
+    let exclude_patterns = exclude_patterns.unwrap_or_default();
+    input_validation::validate_exclude_patterns(&exclude_patterns).map_err(|e| e.to_string())?;
+    let warn_threshold_bytes = warn_threshold_mb
+        .map(|mb| mb.saturating_mul(1024 * 1024))
+        .unwrap_or(DEFAULT_SCAN_MEMORY_WARN_BYTES);
+
+    SyncEngine::estimate_scan_memory(&path, &exclude_patterns, warn_threshold_bytes)
+        .await
+        .map_err(|e| format!("{:#}", e))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+enum TaskRedundancyReason {
+    /// 소스와 타겟이 같은 마운트 포인트(볼륨)에 있음
+    SameVolume,
+    /// 마운트 포인트는 다르지만 같은 disk_uuid를 가진 장치에 있음
+    SameDisk,
+}
+
+/// 소스와 타겟이 같은 물리 장치에 있어 백업 이중화가 되지 않는 태스크 (권고용, 실행은 막지 않음)
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TaskRedundancyWarning {
+    task_id: String,
+    task_name: String,
+    reason: TaskRedundancyReason,
+    source_volume: String,
+    target_volume: String,
+}
+
+/// 마운트되지 않았거나 소유 볼륨을 찾을 수 없는 경로의 태스크는 판단하지 않습니다.
+fn find_task_redundancy_warnings(
+    tasks: &[RuntimeSyncTask],
+    volumes: &[system_integration::VolumeInfo],
+) -> Vec<TaskRedundancyWarning> {
+    let owning_volume =
+        |path: &str| match resolve_path_with_uuid_outcome_with(path, || Ok(volumes.to_vec())) {
+            Ok(ResolvePathWithUuidOutcome::Resolved(resolved)) => {
+                find_owning_volume(&resolved, volumes)
+            }
+            _ => None,
+        };
+    tasks
+        .iter()
+        .filter_map(|task| {
+            let source = owning_volume(&task.source)?;
+            let target = owning_volume(&task.target)?;
+            let reason = if source.mount_point == target.mount_point {
+                TaskRedundancyReason::SameVolume
+            } else if source.disk_uuid.is_some() && source.disk_uuid == target.disk_uuid {
+                TaskRedundancyReason::SameDisk
+            } else {
+                return None;
+            };
+            Some(TaskRedundancyWarning {
+                task_id: task.id.clone(),
+                task_name: task.name.clone(),
+                reason,
+                source_volume: source.name.clone(),
+                target_volume: target.name.clone(),
+            })
+        })
+        .collect()
+}
+
+/// 소스와 타겟이 같은 장치에 있는 태스크 목록 (설정 오류 경고용)
+#[tauri::command]
+async fn check_task_redundancy(
     state: tauri::State<'_, AppState>,
-) -> Result<CloseConflictReviewSessionResult, String> {
-    close_conflict_review_session_internal(
-        session_id,
-        force_skip_pending,
-        Some(&app),
-        state.inner(),
-    )
-    .await
+) -> Result<Vec<TaskRedundancyWarning>, String> {
+    let tasks = state.runtime_config.read().await.tasks.clone();
+    let volumes = tokio::task::spawn_blocking(|| DiskMonitor::new().list_volumes())
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())?;
+    Ok(find_task_redundancy_warnings(&tasks, &volumes))
 }
 
-async fn close_conflict_review_session_internal(
-    session_id: String,
-    force_skip_pending: bool,
-    app: Option<&tauri::AppHandle>,
-    state: &AppState,
-) -> Result<CloseConflictReviewSessionResult, String> {
-    let mut sessions = state.conflict_review_sessions.write().await;
-    let Some(session) = sessions.get_mut(&session_id) else {
-        return Err(format!("Conflict session not found: {session_id}"));
+/// 경로 선택 시 UI 경고용: 경로가 이동식/네트워크 볼륨에 있는지 알려줍니다.
+#[tauri::command]
+fn is_path_removable(path: PathBuf) -> Result<PathRemovableInfo, String> {
+    let resolved =
+        resolve_path_with_uuid(path.to_str().unwrap_or("")).map_err(|e| e.to_string())?;
+    input_validation::validate_path_argument(resolved.to_str().unwrap_or(""))
+        .map_err(|e| e.to_string())?;
+    let resolved = std::fs::canonicalize(&resolved).unwrap_or(resolved);
+    let volumes = DiskMonitor::new()
+        .list_volumes()
+        .map_err(|e| e.to_string())?;
+    Ok(path_removable_info(&resolved, &volumes))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct NormalizedUserPath {
+    /// 화면 표시용 경로. 마운트되지 않은 UUID 토큰은 정규화된 토큰 그대로 둡니다.
+    path: String,
+    exists: bool,
+    is_dir: bool,
+    is_removable: bool,
+    is_network: bool,
+    uuid_not_mounted: bool,
+}
+
+/// 선행 `~` 또는 `~/`만 홈 디렉토리로 확장합니다 (`~user` 형식은 그대로 둡니다).
+fn expand_user_home(path: &str, home: Option<&Path>) -> Result<String, String> {
+    let rest = match path.strip_prefix('~') {
+        Some(rest) if rest.is_empty() || rest.starts_with('/') => rest,
+        _ => return Ok(path.to_string()),
     };
+    let home = home.ok_or_else(|| "Cannot expand '~': HOME is not set".to_string())?;
+    Ok(format!(
+        "{}{}",
+        home.to_string_lossy().trim_end_matches('/'),
+        rest
+    ))
+}
 
-    let had_pending = pending_conflict_count(&session.items) > 0;
-    if had_pending && !force_skip_pending {
-        return Ok(CloseConflictReviewSessionResult {
-            closed: false,
-            had_pending: true,
-            skipped_count: 0,
-        });
+fn normalize_user_path_with(
+    path: &str,
+    home: Option<&Path>,
+    volumes: &[system_integration::VolumeInfo],
+) -> Result<NormalizedUserPath, String> {
+    let trimmed = path.trim();
+    if trimmed.is_empty() {
+        return Err("Path is empty".to_string());
     }
+    let expanded = expand_user_home(trimmed, home)?;
+    input_validation::validate_path_argument(&expanded).map_err(|e| e.to_string())?;
 
-    let session_task_id = session.task_id.clone();
-    let mut skipped_count = 0usize;
-    if had_pending && force_skip_pending {
-        for item in &mut session.items {
-            if item.status == ConflictItemStatus::Pending {
-                item.status = ConflictItemStatus::Skipped;
-                item.note = Some("Skipped when session closed with pending items.".to_string());
-                item.resolved_at_unix_ms = Some(unix_now_ms());
-                skipped_count += 1;
-                log_conflict_skip_on_close(state, app, &session_task_id, &session_id, item);
-            }
+    let resolved = match resolve_path_with_uuid_outcome_with(&expanded, || Ok(volumes.to_vec()))? {
+        ResolvePathWithUuidOutcome::Resolved(resolved) => resolved,
+        ResolvePathWithUuidOutcome::UuidNotMounted {
+            normalized_source, ..
+        } => {
+            return Ok(NormalizedUserPath {
+                path: normalized_source,
+                exists: false,
+                is_dir: false,
+                is_removable: false,
+                is_network: false,
+                uuid_not_mounted: true,
+            });
         }
-    }
+    };
 
-    sessions.remove(&session_id);
-    drop(sessions);
+    let resolved = std::fs::canonicalize(&resolved).unwrap_or(resolved);
+    let metadata = std::fs::metadata(&resolved).ok();
+    let volume_info = path_removable_info(&resolved, volumes);
+    Ok(NormalizedUserPath {
+        path: resolved.to_string_lossy().to_string(),
+        exists: metadata.is_some(),
+        is_dir: metadata.is_some_and(|metadata| metadata.is_dir()),
+        is_removable: volume_info.is_removable,
+        is_network: volume_info.is_network,
+        uuid_not_mounted: false,
+    })
+}
 
-    if let Some(app) = app {
-        emit_conflict_review_queue_changed(app, state).await;
-        let _ = app.emit(
-            "conflict-review-session-updated",
-            ConflictReviewSessionUpdatedEvent {
-                session_id,
-                pending_count: 0,
-            },
-        );
-    }
+/// UI에서 입력/붙여넣은 경로를 사용 전에 검증하고 표시용으로 정규화합니다.
+#[tauri::command]
+fn normalize_user_path(path: String) -> Result<NormalizedUserPath, String> {
+    let home = std::env::var_os("HOME").map(PathBuf::from);
+    let volumes = DiskMonitor::new()
+        .list_volumes()
+        .map_err(|e| e.to_string())?;
+    normalize_user_path_with(&path, home.as_deref(), &volumes)
+}
 
-    Ok(CloseConflictReviewSessionResult {
-        closed: true,
-        had_pending,
-        skipped_count,
-    })
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ProjectedFreeSpace {
+    mount_point: Option<String>,
+    available_bytes: u64,
+    /// 대기/실행 중인 동기화가 쓸 것으로 예상되는 바이트 합계 (캐시된 Dry Run 기준)
+    pending_bytes: u64,
+    projected_free_bytes: i64,
+    will_run_out: bool,
+    contributing_task_ids: Vec<String>,
+    /// 캐시된 Dry Run이 없어 쓸 분량을 알 수 없는 태스크
+    unknown_task_ids: Vec<String>,
+}
+
+/// `pending_targets` 중 타겟이 `mount_point` 볼륨에 있는 태스크 ID만 남깁니다.
+fn pending_tasks_on_volume(
+    mount_point: &Path,
+    pending_targets: &[(String, PathBuf)],
+    volumes: &[system_integration::VolumeInfo],
+) -> Vec<String> {
+    let mount_key = path_key_for_compare(mount_point);
+    pending_targets
+        .iter()
+        .filter(|(_, target)| {
+            find_owning_volume(target, volumes)
+                .is_some_and(|volume| path_key_for_compare(&volume.mount_point) == mount_key)
+        })
+        .map(|(task_id, _)| task_id.clone())
+        .collect()
+}
+
+fn project_free_space(
+    mount_point: Option<String>,
+    available_bytes: u64,
+    pending: Vec<(String, Option<u64>)>,
+) -> ProjectedFreeSpace {
+    let mut pending_bytes = 0u64;
+    let mut contributing_task_ids = Vec::new();
+    let mut unknown_task_ids = Vec::new();
+    for (task_id, bytes) in pending {
+        match bytes {
+            Some(bytes) => {
+                pending_bytes = pending_bytes.saturating_add(bytes);
+                contributing_task_ids.push(task_id);
+            }
+            None => unknown_task_ids.push(task_id),
+        }
+    }
+    let projected = i128::from(available_bytes) - i128::from(pending_bytes);
+    ProjectedFreeSpace {
+        mount_point,
+        available_bytes,
+        pending_bytes,
+        projected_free_bytes: projected.clamp(i128::from(i64::MIN), i128::from(i64::MAX)) as i64,
+        will_run_out: projected < 0,
+        contributing_task_ids,
+        unknown_task_ids,
+    }
 }
 
+/// 같은 타겟 볼륨으로 대기/실행 중인 동기화가 쓸 분량을 뺀 예상 여유 공간을 계산합니다.
+/// 실행 중인 동기화가 이미 쓴 바이트는 `available_bytes`에 반영되어 있으므로 결과는 보수적입니다.
 #[tauri::command]
-async fn get_conflict_item_preview(
-    session_id: String,
-    item_id: String,
-    max_bytes: Option<usize>,
-    app: tauri::AppHandle,
+async fn projected_free_space(
+    target: PathBuf,
     state: tauri::State<'_, AppState>,
-) -> Result<ConflictPreviewPayload, String> {
-    let (source_path, target_path) = {
-        let sessions = state.conflict_review_sessions.read().await;
-        let session = sessions
-            .get(&session_id)
-            .ok_or_else(|| format!("Conflict session not found: {session_id}"))?;
-        let item = session
-            .items
+) -> Result<ProjectedFreeSpace, String> {
+    let resolved =
+        resolve_path_with_uuid(target.to_str().unwrap_or("")).map_err(|e| e.to_string())?;
+    input_validation::validate_path_argument(resolved.to_str().unwrap_or(""))
+        .map_err(|e| e.to_string())?;
+    let resolved = std::fs::canonicalize(&resolved).unwrap_or(resolved);
+    let volumes = DiskMonitor::new()
+        .list_volumes()
+        .map_err(|e| e.to_string())?;
+    let volume = find_owning_volume(&resolved, &volumes)
+        .ok_or_else(|| format!("No mounted volume contains {}", resolved.display()))?;
+    let available_bytes = volume
+        .available_bytes
+        .ok_or_else(|| format!("Free space is unknown for volume {}", volume.name))?;
+
+    let mut pending_ids: Vec<String> = state.syncing_tasks.read().await.iter().cloned().collect();
+    pending_ids.sort();
+    for task_id in state.runtime_sync_queue.read().await.iter() {
+        if !pending_ids.contains(task_id) {
+            pending_ids.push(task_id.clone());
+        }
+    }
+    let pending_targets: Vec<(String, PathBuf)> = {
+        let config = state.runtime_config.read().await;
+        pending_ids
             .iter()
-            .find(|entry| entry.id == item_id)
-            .ok_or_else(|| format!("Conflict item not found: {item_id}"))?;
-        (item.source_path.clone(), item.target_path.clone())
+            .filter_map(|task_id| {
+                let task = config.tasks.iter().find(|task| &task.id == task_id)?;
+                let target = resolve_path_with_uuid(&task.target).ok()?;
+                Some((
+                    task_id.clone(),
+                    std::fs::canonicalize(&target).unwrap_or(target),
+                ))
+            })
+            .collect()
     };
 
-    let max_bytes = max_bytes.unwrap_or(64 * 1024).clamp(1024, 512 * 1024);
-    let mut kind = preview_kind_for_path(&source_path).to_string();
+    let task_ids = pending_tasks_on_volume(&volume.mount_point, &pending_targets, &volumes);
+    let artifacts = state.dry_run_artifacts.read().await;
+    let pending = task_ids
+        .into_iter()
+        .map(|task_id| {
+            let bytes = artifacts
+                .get(&task_id)
+                .map(|artifact| artifact.result.bytes_to_copy);
+            (task_id, bytes)
+        })
+        .collect();
+    Ok(project_free_space(
+        Some(volume.mount_point.to_string_lossy().to_string()),
+        available_bytes,
+        pending,
+    ))
+}
+
+/// 타겟 경로들이 속한 볼륨마다 여유 공간 샘플을 하나씩 만듭니다.
+/// 용량을 알 수 없는 볼륨과 마운트되지 않은 타겟은 건너뜁니다.
+fn collect_capacity_samples(
+    targets: &[PathBuf],
+    volumes: &[system_integration::VolumeInfo],
+    timestamp: chrono::DateTime<Utc>,
+) -> Vec<CapacitySample> {
+    let mut seen_mounts = HashSet::new();
+    let mut samples = Vec::new();
+    for target in targets {
+        let Some(volume) = find_owning_volume(target, volumes) else {
+            continue;
+        };
+        let (Some(available_bytes), Some(total_bytes)) =
+            (volume.available_bytes, volume.total_bytes)
+        else {
+            continue;
+        };
+        if !seen_mounts.insert(volume.mount_point.clone()) {
+            continue;
+        }
+        samples.push(CapacitySample {
+            timestamp,
+            mount_point: volume.mount_point.clone(),
+            available_bytes,
+            total_bytes,
+        });
+    }
+    samples
+}
 
-    let mut source_text = None;
-    let mut target_text = None;
-    let mut source_truncated = false;
-    let mut target_truncated = false;
+/// 활성 태스크 타겟 볼륨의 여유 공간을 주기적으로 기록합니다.
+async fn capacity_sampler_loop(state: AppState) {
+    loop {
+        let (targets, interval) = {
+            let config = state.runtime_config.read().await;
+            let targets: Vec<PathBuf> = config
+                .tasks
+                .iter()
+                .filter(|task| task.enabled)
+                .filter_map(|task| resolve_path_with_uuid(&task.target).ok())
+                .collect();
+            (
+                targets,
+                config.settings.effective_capacity_sample_interval(),
+            )
+        };
 
-    if kind == "text" {
-        let (left, left_truncated) = read_text_preview(&source_path, max_bytes).await;
-        let (right, right_truncated) = read_text_preview(&target_path, max_bytes).await;
-        source_text = left;
-        target_text = right;
-        source_truncated = left_truncated;
-        target_truncated = right_truncated;
-        if source_text.is_none() || target_text.is_none() {
-            kind = "other".to_string();
+        if !targets.is_empty() {
+            let store = state.capacity_history_store.clone();
+            let result = tokio::task::spawn_blocking(move || {
+                let volumes = DiskMonitor::new()
+                    .list_volumes()
+                    .map_err(|e| e.to_string())?;
+                let samples = collect_capacity_samples(&targets, &volumes, Utc::now());
+                store.append_samples(&samples)
+            })
+            .await;
+            match result {
+                Ok(Ok(())) => {}
+                Ok(Err(error)) => eprintln!("[CapacityHistory] Failed to record samples: {error}"),
+                Err(error) => eprintln!("[CapacityHistory] Sampler task failed: {error}"),
+            }
         }
-    } else if matches!(kind.as_str(), "image" | "video") {
-        allow_asset_preview_file(&app, &source_path)?;
-        allow_asset_preview_file(&app, &target_path)?;
+
+        tokio::time::sleep(interval).await;
     }
+}
 
-    Ok(ConflictPreviewPayload {
-        kind,
-        source_text,
-        target_text,
-        source_truncated,
-        target_truncated,
-    })
+async fn get_capacity_history_internal(
+    mount_point: PathBuf,
+    since: Option<chrono::DateTime<Utc>>,
+    state: &AppState,
+) -> Result<Vec<CapacitySample>, String> {
+    let store = state.capacity_history_store.clone();
+    tokio::task::spawn_blocking(move || store.query(&mount_point, since))
+        .await
+        .map_err(|e| e.to_string())?
 }
 
+/// 볼륨의 여유 공간 샘플을 시간순으로 반환합니다 (용량 추이 차트용).
 #[tauri::command]
-fn list_volumes() -> Result<Vec<system_integration::VolumeInfo>, String> {
-    let monitor = DiskMonitor::new();
-    monitor.list_volumes().map_err(|e| e.to_string())
+async fn get_capacity_history(
+    mount_point: PathBuf,
+    since: Option<chrono::DateTime<Utc>>,
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<CapacitySample>, String> {
+    get_capacity_history_internal(mount_point, since, state.inner()).await
 }
 
-#[tauri::command]
-fn get_removable_volumes() -> Result<Vec<system_integration::VolumeInfo>, String> {
-    let monitor = DiskMonitor::new();
-    monitor.get_removable_volumes().map_err(|e| e.to_string())
+/// 볼륨 목록을 즉시 다시 읽고 `volumes-changed`를 한 번 발생시킵니다.
+/// `/Volumes` 감시자의 디바운스 상태는 건드리지 않습니다.
+fn refresh_volumes_internal<R: tauri::Runtime>(
+    app: &AppHandle<R>,
+) -> Result<Vec<system_integration::VolumeInfo>, String> {
+    let volumes = DiskMonitor::new()
+        .list_volumes()
+        .map_err(|e| e.to_string())?;
+    let _ = app.emit("volumes-changed", ());
+    Ok(volumes)
+}
+
+#[tauri::command]
+fn refresh_volumes(app: tauri::AppHandle) -> Result<Vec<system_integration::VolumeInfo>, String> {
+    refresh_volumes_internal(&app)
 }
 
 /// Disk UUID로 현재 마운트된 볼륨의 경로를 찾습니다.
@@ -7841,6 +12457,17 @@ fn resolve_path_by_uuid(disk_uuid: String) -> Result<std::path::PathBuf, String>
 }
 
 /// Removable 디스크를 언마운트합니다.
+/// Removable 볼륨의 SMART/상태 힌트를 조회합니다. 장시간 아카이브 동기화 전 점검용.
+#[tauri::command]
+async fn get_volume_health(path: PathBuf) -> Result<system_integration::VolumeHealth, String> {
+    let resolved_path =
+        resolve_path_with_uuid(path.to_str().unwrap_or("")).map_err(|e| e.to_string())?;
+    tauri::async_runtime::spawn_blocking(move || DiskMonitor::get_volume_health(&resolved_path))
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 async fn unmount_volume(path: PathBuf, state: tauri::State<'_, AppState>) -> Result<(), String> {
     let resolved_path =
@@ -7857,6 +12484,36 @@ async fn unmount_volume(path: PathBuf, state: tauri::State<'_, AppState>) -> Res
     Ok(())
 }
 
+/// 이름을 바꾸면 마운트 포인트도 바뀌므로 새 마운트 포인트를 반환합니다.
+#[tauri::command]
+async fn rename_volume(
+    path: PathBuf,
+    new_name: String,
+    state: tauri::State<'_, AppState>,
+) -> Result<PathBuf, String> {
+    let resolved_path =
+        resolve_path_with_uuid(path.to_str().unwrap_or("")).map_err(|e| e.to_string())?;
+    let renamed_mount_point = tokio::task::spawn_blocking({
+        let resolved_path = resolved_path.clone();
+        move || DiskMonitor::rename_volume(&resolved_path, &new_name)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+    .map_err(|e| e.to_string())?;
+
+    state.log_manager.log(
+        "success",
+        &format!(
+            "Volume renamed: {} -> {}",
+            resolved_path.display(),
+            renamed_mount_point.display()
+        ),
+        None,
+    );
+
+    Ok(renamed_mount_point)
+}
+
 #[tauri::command]
 async fn start_sync(
     task_id: String,
@@ -7866,12 +12523,18 @@ async fn start_sync(
     checksum_mode: bool,
     verify_after_copy: bool,
     exclude_patterns: Vec<String>,
+    options: Option<SyncRunOptions>,
     app: tauri::AppHandle,
     webview: Webview,
     file_batch_channel: Option<JavaScriptChannelId>,
     state: tauri::State<'_, AppState>,
 ) -> Result<SyncExecutionResult, String> {
     activate_task_path_access(&task_id, state.inner()).await?;
+    let mut run_options = options.unwrap_or_default();
+    // Dry Run 전용 옵션은 실제 동기화에서 의미가 없으므로 끕니다.
+    run_options.include_orphans = false;
+    run_options.collect_skip_reasons = false;
+    run_options.allow_empty_source = false;
     let result = execute_sync_internal(
         task_id,
         task_name.unwrap_or_else(|| "Manual Sync".to_string()),
@@ -7888,6 +12551,7 @@ async fn start_sync(
         None,
         file_batch_channel.map(|channel_id| channel_id.channel_on(webview)),
         None,
+        run_options,
     )
     .await
     .map_err(|error| error.error_detail)?;
@@ -7895,6 +12559,165 @@ async fn start_sync(
     Ok(result)
 }
 
+#[tauri::command]
+async fn get_task_lifetime_stats(
+    task_id: String,
+    state: tauri::State<'_, AppState>,
+) -> Result<TaskLifetimeStats, String> {
+    get_task_lifetime_stats_internal(&task_id, state.inner()).await
+}
+
+#[tauri::command]
+async fn reset_task_stats(
+    task_id: String,
+    state: tauri::State<'_, AppState>,
+) -> Result<TaskLifetimeStats, String> {
+    reset_task_stats_internal(&task_id, state.inner()).await
+}
+
+#[tauri::command]
+async fn retry_failed_files(
+    task_id: String,
+    state: tauri::State<'_, AppState>,
+) -> Result<RetryFailedFilesResult, String> {
+    activate_task_path_access(&task_id, state.inner()).await?;
+    retry_failed_files_internal(&task_id, state.inner()).await
+}
+
+#[tauri::command]
+async fn list_incomplete_syncs(
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<IncompleteSyncMarker>, String> {
+    Ok(list_incomplete_syncs_internal(state.inner()).await)
+}
+
+/// 비정상 종료로 남은 동기화를 같은 경로/옵션으로 다시 실행합니다.
+/// 최신 파일은 건너뛰므로 누락된 파일만 복사됩니다.
+#[tauri::command]
+async fn resume_incomplete_sync(
+    task_id: String,
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> Result<SyncExecutionResult, String> {
+    let marker = list_incomplete_syncs_internal(state.inner())
+        .await
+        .into_iter()
+        .find(|marker| marker.task_id == task_id)
+        .ok_or_else(|| format!("No incomplete sync recorded for task '{task_id}'"))?;
+
+    activate_task_path_access(&task_id, state.inner()).await?;
+    execute_sync_internal(
+        marker.task_id,
+        marker.task_name,
+        marker.source,
+        marker.target,
+        marker.checksum_mode,
+        marker.verify_after_copy,
+        marker.exclude_patterns,
+        app,
+        state.inner().clone(),
+        false,
+        SyncOrigin::Manual,
+        None,
+        None,
+        None,
+        None,
+        marker.options,
+    )
+    .await
+    .map_err(|error| error.error_detail)
+}
+
+/// 태스크 설정은 그대로 두고 타겟만 바꿔 한 번 실행할 때 쓰는 경로/옵션
+#[derive(Debug, Clone)]
+struct OverrideTargetSyncPlan {
+    task_id: String,
+    task_name: String,
+    source: PathBuf,
+    target: PathBuf,
+    checksum_mode: bool,
+    verify_after_copy: bool,
+    exclude_patterns: Vec<String>,
+}
+
+/// runtime_config의 태스크 source/제외 규칙/옵션에 `override_target`만 바꿔 끼운 실행 계획을 만듭니다.
+/// 타겟은 이미 존재하는 디렉터리여야 합니다. 없는 경로를 허용하면 네트워크 마운트 복구가
+/// 설정된 원래 타겟을 대신 돌려줄 수 있기 때문입니다.
+fn resolve_override_target_sync(
+    task_id: &str,
+    override_target: &Path,
+    runtime_config: &RuntimeConfigPayload,
+) -> Result<OverrideTargetSyncPlan, String> {
+    input_validation::validate_task_id(task_id).map_err(|e| e.to_string())?;
+    let task = runtime_config
+        .tasks
+        .iter()
+        .find(|task| task.id == task_id)
+        .ok_or_else(|| format!("Sync task not found: {task_id}"))?;
+
+    if !override_target.is_absolute() {
+        return Err(format!(
+            "Override target must be an absolute path: {}",
+            override_target.display()
+        ));
+    }
+    if !override_target.is_dir() {
+        return Err(format!(
+            "Override target is not an existing directory: {}",
+            override_target.display()
+        ));
+    }
+    let source = PathBuf::from(&task.source);
+    ensure_non_overlapping_paths(&source, override_target)?;
+
+    Ok(OverrideTargetSyncPlan {
+        task_id: task.id.clone(),
+        task_name: task.name.clone(),
+        source,
+        target: override_target.to_path_buf(),
+        checksum_mode: task.checksum_mode,
+        verify_after_copy: task.verify_after_copy,
+        exclude_patterns: resolve_runtime_exclude_patterns(task, &runtime_config.exclusion_sets),
+    })
+}
+
+/// 태스크 설정을 바꾸지 않고 다른 타겟으로 한 번만 동기화합니다 (예: 다른 드라이브로 임시 백업).
+#[tauri::command]
+async fn sync_task_to_override_target(
+    task_id: String,
+    override_target: PathBuf,
+    options: Option<SyncRunOptions>,
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> Result<SyncExecutionResult, String> {
+    let plan = {
+        let runtime_config = state.runtime_config.read().await;
+        resolve_override_target_sync(&task_id, &override_target, &runtime_config)?
+    };
+
+    activate_task_path_access(&task_id, state.inner()).await?;
+    execute_sync_internal(
+        plan.task_id,
+        plan.task_name,
+        plan.source,
+        plan.target,
+        plan.checksum_mode,
+        plan.verify_after_copy,
+        plan.exclude_patterns,
+        app,
+        state.inner().clone(),
+        false,
+        SyncOrigin::Manual,
+        None,
+        None,
+        None,
+        None,
+        options.unwrap_or_default(),
+    )
+    .await
+    .map_err(|error| error.error_detail)
+}
+
 #[tauri::command]
 async fn start_sync_from_dry_run(
     task_id: String,
@@ -7907,6 +12730,7 @@ async fn start_sync_from_dry_run(
     let artifact = load_dry_run_artifact(&task_id, state.inner())
         .await
         .ok_or_else(|| DRY_RUN_ARTIFACT_MISSING_ERROR.to_string())?;
+    let run_options = artifact.run_options.clone();
 
     let result = execute_sync_internal(
         task.id.clone(),
@@ -7924,6 +12748,7 @@ async fn start_sync_from_dry_run(
         None,
         file_batch_channel.map(|channel_id| channel_id.channel_on(webview)),
         Some(artifact),
+        run_options,
     )
     .await
     .map_err(|error| error.error_detail)?;
@@ -8040,6 +12865,10 @@ struct SyncTaskUpdatePayload {
     source_sub_path: Option<String>,
     source_identity: Option<config_store::SourceIdentitySnapshot>,
     recurring_schedules: Option<Vec<RecurringScheduleRecord>>,
+    notifications: Option<TaskNotificationPrefs>,
+    resource_group: Option<String>,
+    max_coalesce_secs: Option<u64>,
+    max_duration_secs: Option<u64>,
 }
 
 #[tauri::command]
@@ -8070,6 +12899,10 @@ async fn update_sync_task(
             source_sub_path: updates.source_sub_path,
             source_identity: updates.source_identity,
             recurring_schedules: updates.recurring_schedules,
+            notifications: updates.notifications,
+            resource_group: updates.resource_group,
+            max_coalesce_secs: updates.max_coalesce_secs,
+            max_duration_secs: updates.max_duration_secs,
         },
         updates.source_credential,
         updates.target_credential,
@@ -8197,34 +13030,113 @@ async fn stop_watch(
     task_id: String,
     app: tauri::AppHandle,
     state: tauri::State<'_, AppState>,
+) -> Result<(), String> {
+    stop_watch_internal(&task_id, &app, state.inner()).await
+}
+
+async fn stop_watch_internal(
+    task_id: &str,
+    app: &tauri::AppHandle,
+    state: &AppState,
 ) -> Result<(), String> {
     {
         let mut sources = state.runtime_watch_sources.write().await;
-        sources.remove(&task_id);
+        sources.remove(task_id);
     }
-    remove_runtime_sync_task_state(&task_id, state.inner()).await;
+    remove_runtime_sync_task_state(task_id, state).await;
 
     let mut manager = state.watcher_manager.write().await;
     manager
-        .stop_watching(&task_id)
+        .stop_watching(task_id)
         .map_err(|e| format!("{}:{}", error_codes::ERR_WATCH_STOP_FAILED, e))?;
 
     state.log_manager.log_with_category(
         "info",
         "Watch stopped",
-        Some(task_id.clone()),
+        Some(task_id.to_string()),
         LogCategory::WatchStopped,
     );
     emit_runtime_sync_queue_state(
-        &app,
-        &task_id,
+        app,
+        task_id,
         false,
         Some("Watch manually stopped".to_string()),
     );
-    emit_runtime_watch_state(&app, &task_id, false, None);
+    emit_runtime_watch_state(app, task_id, false, None);
     Ok(())
 }
 
+const GRACEFUL_STOP_DEFAULT_TIMEOUT_SECS: u64 = 30;
+const GRACEFUL_STOP_MAX_TIMEOUT_SECS: u64 = 600;
+const GRACEFUL_STOP_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+enum GracefulWatchStopOutcome {
+    /// 진행 중인 동기화가 없었거나 제한 시간 안에 끝남
+    Drained,
+    /// 동기화가 아직 진행 중인 채로 감시를 멈춤
+    TimedOut,
+}
+
+/// 태스크의 진행 중인 동기화가 끝날 때까지 기다립니다. 제한 시간 안에 끝나면 true
+async fn wait_for_task_sync_to_finish(task_id: &str, timeout: Duration, state: &AppState) -> bool {
+    let deadline = Instant::now() + timeout;
+    loop {
+        if !state.syncing_tasks.read().await.contains(task_id) {
+            return true;
+        }
+        let now = Instant::now();
+        if now >= deadline {
+            return false;
+        }
+        tokio::select! {
+            _ = state.runtime_dispatcher_wakeup.notified() => {},
+            _ = tokio::time::sleep(GRACEFUL_STOP_POLL_INTERVAL.min(deadline - now)) => {},
+        }
+    }
+}
+
+/// 새 enqueue를 막은 뒤 진행 중인 동기화가 끝나기를 기다렸다가 감시를 멈춥니다.
+/// 동기화 도중 감지된 변경으로 멈춘 태스크가 다시 큐에 들어가는 경합을 막습니다.
+#[tauri::command]
+async fn stop_watch_graceful(
+    task_id: String,
+    timeout_secs: Option<u64>,
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> Result<GracefulWatchStopOutcome, String> {
+    let state = state.inner();
+    let timeout = Duration::from_secs(
+        timeout_secs
+            .unwrap_or(GRACEFUL_STOP_DEFAULT_TIMEOUT_SECS)
+            .min(GRACEFUL_STOP_MAX_TIMEOUT_SECS),
+    );
+    state
+        .runtime_stopping_tasks
+        .write()
+        .await
+        .insert(task_id.clone());
+    remove_runtime_sync_task_state(&task_id, state).await;
+    emit_runtime_sync_queue_state(
+        &app,
+        &task_id,
+        false,
+        Some("Watch stopping after the current sync".to_string()),
+    );
+
+    let drained = wait_for_task_sync_to_finish(&task_id, timeout, state).await;
+    let result = stop_watch_internal(&task_id, &app, state).await;
+    state.runtime_stopping_tasks.write().await.remove(&task_id);
+    result?;
+
+    Ok(if drained {
+        GracefulWatchStopOutcome::Drained
+    } else {
+        GracefulWatchStopOutcome::TimedOut
+    })
+}
+
 /// 현재 감시 중인 Task 목록을 반환합니다.
 #[tauri::command]
 async fn get_watching_tasks(state: tauri::State<'_, AppState>) -> Result<Vec<String>, String> {
@@ -8232,11 +13144,79 @@ async fn get_watching_tasks(state: tauri::State<'_, AppState>) -> Result<Vec<Str
     Ok(manager.get_watching_tasks())
 }
 
+/// 감시 중인 Task별 watcher 이벤트/재시작 카운터를 반환합니다.
+#[tauri::command]
+async fn get_watcher_diagnostics(
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<WatcherDiagnostics>, String> {
+    Ok(get_watcher_diagnostics_internal(state.inner()).await)
+}
+
+/// 비활성 태스크와 조용한 시간대는 `paused`로 표시합니다.
+async fn get_watcher_diagnostics_internal(state: &AppState) -> Vec<WatcherDiagnostics> {
+    let mut diagnostics = state.watcher_manager.read().await.get_diagnostics();
+    let config = state.runtime_config.read().await;
+    let in_quiet_hours = config
+        .settings
+        .quiet_hours
+        .is_some_and(|window| is_within_quiet_hours(window, chrono::Local::now().time()));
+    for entry in &mut diagnostics {
+        let enabled = config
+            .tasks
+            .iter()
+            .find(|task| task.id == entry.task_id)
+            .is_none_or(|task| task.enabled);
+        entry.paused = if !enabled {
+            Some(WatchPauseReason::Disabled)
+        } else if in_quiet_hours {
+            Some(WatchPauseReason::QuietHours)
+        } else {
+            None
+        };
+    }
+    diagnostics
+}
+
+/// OS 감시 한도(Linux inotify max_user_watches 등)와 현재 watcher의 대략적인 사용량을 반환합니다.
+/// 읽을 수 없는 한도는 null로 반환합니다.
+#[tauri::command]
+async fn get_watch_system_limits(
+    state: tauri::State<'_, AppState>,
+) -> Result<WatchSystemLimits, String> {
+    let app_max_watchers = state
+        .runtime_config
+        .read()
+        .await
+        .settings
+        .effective_max_watchers();
+    let watched_roots: Vec<PathBuf> = {
+        let manager = state.watcher_manager.read().await;
+        manager
+            .get_watching_task_sources()
+            .into_values()
+            .map(PathBuf::from)
+            .collect()
+    };
+    tauri::async_runtime::spawn_blocking(move || {
+        watcher::watch_system_limits(&watched_roots, app_max_watchers)
+    })
+    .await
+    .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 async fn runtime_set_config(
     payload: RuntimeConfigPayload,
     app: tauri::AppHandle,
     state: tauri::State<'_, AppState>,
+) -> Result<RuntimeState, String> {
+    apply_runtime_config_payload(payload, app, state.inner().clone()).await
+}
+
+async fn apply_runtime_config_payload(
+    payload: RuntimeConfigPayload,
+    app: tauri::AppHandle,
+    state: AppState,
 ) -> Result<RuntimeState, String> {
     let _apply_guard = state.runtime_config_apply_lock.clone().lock_owned().await;
 
@@ -8250,21 +13230,67 @@ async fn runtime_set_config(
 
     {
         let mut config = state.runtime_config.write().await;
-        *config = payload;
+        replace_runtime_config(&mut config, payload);
+        state
+            .global_bandwidth_limiter
+            .set_bytes_per_sec(config.settings.global_max_bytes_per_sec);
     }
-    prune_auto_unmount_session_disabled_tasks(&valid_task_ids, state.inner()).await;
+    // 조용한 시간 설정이 바뀌었을 수 있으므로 보류 중인 디스패처를 깨움
+    state.runtime_dispatcher_wakeup.notify_waiters();
+    prune_auto_unmount_session_disabled_tasks(&valid_task_ids, &state).await;
 
-    reconcile_runtime_watchers(app.clone(), state.inner().clone()).await?;
+    reconcile_runtime_watchers(app.clone(), state.clone()).await?;
 
     if state
         .runtime_initial_watch_bootstrapped
         .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
         .is_ok()
     {
-        enqueue_initial_runtime_watch_syncs(app.clone(), state.inner().clone()).await;
+        enqueue_initial_runtime_watch_syncs(app.clone(), state.clone()).await;
     }
 
-    Ok(runtime_get_state_internal(state.inner()).await)
+    Ok(runtime_get_state_internal(&state).await)
+}
+
+/// 설정 파일에 저장한 뒤 런타임 설정에도 바로 반영합니다.
+async fn set_verbose_logging_internal(enabled: bool, state: &AppState) -> Result<bool, String> {
+    let mut settings = state
+        .config_store
+        .load_settings()
+        .map_err(config_store_error_to_string)?;
+    if settings.verbose_file_logging != enabled {
+        settings.verbose_file_logging = enabled;
+        state
+            .config_store
+            .save_settings(&settings)
+            .map_err(config_store_error_to_string)?;
+    }
+    let previous = {
+        let mut config = state.runtime_config.write().await;
+        std::mem::replace(&mut config.settings.verbose_file_logging, enabled)
+    };
+    if previous != enabled {
+        state.log_manager.log(
+            "info",
+            &format!(
+                "Verbose per-file logging {}",
+                if enabled { "enabled" } else { "disabled" }
+            ),
+            None,
+        );
+    }
+    Ok(enabled)
+}
+
+#[tauri::command]
+async fn set_verbose_logging(
+    enabled: bool,
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> Result<bool, String> {
+    let enabled = set_verbose_logging_internal(enabled, state.inner()).await?;
+    emit_config_store_changed(&app, &["settings"]);
+    Ok(enabled)
 }
 
 #[tauri::command]
@@ -8288,21 +13314,102 @@ async fn runtime_validate_tasks(
 }
 
 #[tauri::command]
-async fn runtime_validate_orphan_scan(
-    task_id: String,
-    tasks: Vec<RuntimeSyncTask>,
-) -> Result<RuntimeTaskValidationResult, String> {
-    let issue = find_runtime_orphan_target_conflict_issue(&task_id, &tasks)?;
+async fn runtime_validate_orphan_scan(
+    task_id: String,
+    tasks: Vec<RuntimeSyncTask>,
+) -> Result<RuntimeTaskValidationResult, String> {
+    let issue = find_runtime_orphan_target_conflict_issue(&task_id, &tasks)?;
+
+    Ok(RuntimeTaskValidationResult {
+        ok: issue.is_none(),
+        issue,
+    })
+}
+
+/// 편집 중인 제외 패턴 세트 하나를 패턴별로 검사합니다 (설정 화면 인라인 오류 표시용)
+#[tauri::command]
+async fn validate_exclusion_set(
+    set: RuntimeExclusionSet,
+) -> Result<Vec<ExclusionPatternValidity>, String> {
+    Ok(validate_exclusion_set_internal(&set))
+}
+
+/// 태스크를 설정에서 지우지 않고 켜거나 끔. 전체 설정을 다시 보내지 않고 watcher를 바로 맞춤
+#[tauri::command]
+async fn set_task_enabled(
+    task_id: String,
+    enabled: bool,
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> Result<RuntimeState, String> {
+    input_validation::validate_task_id(&task_id).map_err(|e| e.to_string())?;
+    let _apply_guard = state.runtime_config_apply_lock.clone().lock_owned().await;
+    if set_task_enabled_internal(&task_id, enabled, state.inner()).await? {
+        emit_config_store_changed(&app, &["syncTasks"]);
+        if !enabled {
+            remove_runtime_sync_task_state(&task_id, state.inner()).await;
+            emit_runtime_sync_queue_state(&app, &task_id, false, Some("Task disabled".to_string()));
+        }
+        reconcile_runtime_watchers(app.clone(), state.inner().clone()).await?;
+        state.recurring_scheduler_wakeup.notify_waiters();
+    }
+    Ok(runtime_get_state_internal(state.inner()).await)
+}
+
+#[tauri::command]
+async fn runtime_get_state(state: tauri::State<'_, AppState>) -> Result<RuntimeState, String> {
+    Ok(runtime_get_state_internal(state.inner()).await)
+}
+
+/// 앱 시작 시 큐잉된 초기 동기화 중 아직 실행되지 않은 것만 취소함. 취소된 태스크 ID 반환
+#[tauri::command]
+async fn cancel_bootstrap_syncs(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<String>, String> {
+    let cancelled = cancel_bootstrap_syncs_internal(state.inner()).await;
+    for task_id in &cancelled {
+        emit_runtime_sync_queue_state(
+            &app,
+            task_id,
+            false,
+            Some("Initial sync cancelled".to_string()),
+        );
+    }
+    Ok(cancelled)
+}
+
+#[tauri::command]
+async fn get_dispatcher_state(
+    state: tauri::State<'_, AppState>,
+) -> Result<DispatcherState, String> {
+    Ok(get_dispatcher_state_internal(state.inner()).await)
+}
 
-    Ok(RuntimeTaskValidationResult {
-        ok: issue.is_none(),
-        issue,
-    })
+#[tauri::command]
+async fn force_restart_dispatcher(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> Result<DispatcherState, String> {
+    if force_reset_runtime_dispatcher(state.inner()).await {
+        state.log_manager.log_with_category(
+            "warning",
+            "Runtime sync dispatcher was force-restarted with queued work",
+            None,
+            LogCategory::Other,
+        );
+        schedule_runtime_sync_dispatcher(app, state.inner().clone());
+    }
+    Ok(get_dispatcher_state_internal(state.inner()).await)
 }
 
 #[tauri::command]
-async fn runtime_get_state(state: tauri::State<'_, AppState>) -> Result<RuntimeState, String> {
-    Ok(runtime_get_state_internal(state.inner()).await)
+async fn get_task_queue_position(
+    task_id: String,
+    state: tauri::State<'_, AppState>,
+) -> Result<TaskQueuePosition, String> {
+    input_validation::validate_task_id(&task_id).map_err(|e| e.to_string())?;
+    Ok(get_task_queue_position_internal(&task_id, state.inner()).await)
 }
 
 #[tauri::command]
@@ -8356,6 +13463,432 @@ async fn load_task_context(
     Ok((task, exclude_patterns))
 }
 
+/// The options a saved task would run with, resolved through the same helpers the sync uses.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct EffectiveTaskConfig {
+    task_id: String,
+    source: String,
+    target: String,
+    resolved_source: Option<String>,
+    resolved_target: Option<String>,
+    /// 경로를 지금 해석할 수 없는 경우(볼륨 미마운트 등)의 사유
+    path_error: Option<String>,
+    exclude_patterns: Vec<String>,
+    checksum_mode: bool,
+    verify_after_copy: bool,
+    preserve_permissions: bool,
+    preserve_times: bool,
+}
+
+async fn get_effective_task_config_internal(
+    task_id: &str,
+    state: &AppState,
+) -> Result<EffectiveTaskConfig, String> {
+    input_validation::validate_task_id(task_id).map_err(|e| e.to_string())?;
+    let (task, exclude_patterns) = load_task_context(task_id, state).await?;
+    let options = build_sync_options(
+        task.checksum_mode,
+        task.verify_after_copy,
+        exclude_patterns,
+        &SyncRunOptions::default(),
+    );
+
+    // Introspection must not mount network shares, so only already-reachable paths resolve here.
+    let resolved = ensure_task_requested_path_available(
+        task_id,
+        Path::new(&task.source),
+        None,
+        NetworkMountRole::Source,
+        false,
+    )
+    .and_then(|source| {
+        let target = ensure_task_requested_path_available(
+            task_id,
+            Path::new(&task.target),
+            None,
+            NetworkMountRole::Target,
+            true,
+        )?;
+        Ok((source, target))
+    });
+    let (resolved_source, resolved_target, path_error) = match resolved {
+        Ok((source, target)) => (
+            Some(source.to_string_lossy().to_string()),
+            Some(target.to_string_lossy().to_string()),
+            None,
+        ),
+        Err(error) => (None, None, Some(error)),
+    };
+
+    Ok(EffectiveTaskConfig {
+        task_id: task.id,
+        source: task.source,
+        target: task.target,
+        resolved_source,
+        resolved_target,
+        path_error,
+        exclude_patterns: options.exclude_patterns,
+        checksum_mode: options.checksum_mode,
+        verify_after_copy: options.verify_after_copy,
+        preserve_permissions: options.preserve_permissions,
+        preserve_times: options.preserve_times,
+    })
+}
+
+/// 두 태스크가 왜 다르게 동작하는지 보도록 현재 런타임 설정 기준 제외 패턴을 3분할해 반환
+#[tauri::command]
+async fn compare_task_excludes(
+    task_id_a: String,
+    task_id_b: String,
+    state: tauri::State<'_, AppState>,
+) -> Result<TaskExcludeComparison, String> {
+    input_validation::validate_task_id(&task_id_a).map_err(|e| e.to_string())?;
+    input_validation::validate_task_id(&task_id_b).map_err(|e| e.to_string())?;
+    let config = state.runtime_config.read().await;
+    compare_task_excludes_internal(&task_id_a, &task_id_b, &config)
+}
+
+#[tauri::command]
+async fn get_effective_task_config(
+    task_id: String,
+    state: tauri::State<'_, AppState>,
+) -> Result<EffectiveTaskConfig, String> {
+    get_effective_task_config_internal(&task_id, state.inner()).await
+}
+
+fn record_sync_in_progress_file(
+    in_progress_files: &StdMutex<HashMap<String, InProgressSyncFile>>,
+    task_id: &str,
+    file: InProgressSyncFile,
+) {
+    let mut files = in_progress_files.lock().unwrap();
+    if files.get(task_id) != Some(&file) {
+        files.insert(task_id.to_string(), file);
+    }
+}
+
+fn clear_sync_in_progress_file(
+    in_progress_files: &StdMutex<HashMap<String, InProgressSyncFile>>,
+    task_id: &str,
+) {
+    in_progress_files.lock().unwrap().remove(task_id);
+}
+
+/// 복사가 시작된 파일을 기록하고, 파일이 어떤 상태로든 끝나면 지웁니다.
+/// 진행률 틱마다 공유 맵을 잠그지 않도록 현재 파일이 바뀔 때만 기록합니다.
+struct InProgressFileTracker {
+    files: Arc<StdMutex<HashMap<String, InProgressSyncFile>>>,
+    task_id: String,
+    source: PathBuf,
+    target: PathBuf,
+    options: SyncOptions,
+    /// target_layout으로 옮겨진 파일의 대상 상대 경로 → 소스 상대 경로
+    source_paths: StdMutex<HashMap<PathBuf, PathBuf>>,
+    /// 마지막으로 기록한 current_file의 해시 (0 = 없음)
+    current_file_key: AtomicU64,
+}
+
+impl InProgressFileTracker {
+    fn new(
+        files: Arc<StdMutex<HashMap<String, InProgressSyncFile>>>,
+        task_id: String,
+        source: PathBuf,
+        target: PathBuf,
+        options: SyncOptions,
+    ) -> Self {
+        Self {
+            files,
+            task_id,
+            source,
+            target,
+            options,
+            source_paths: StdMutex::new(HashMap::new()),
+            current_file_key: AtomicU64::new(0),
+        }
+    }
+
+    /// 진행률의 current_file은 대상 쪽 경로이므로, 계획에서 소스 경로를 되찾을 매핑을 받아 둡니다.
+    fn set_plan(&self, diffs: &[FileDiff]) {
+        *self.source_paths.lock().unwrap() = diffs
+            .iter()
+            .filter_map(|diff| {
+                diff.source_path
+                    .as_ref()
+                    .map(|source_path| (diff.path.clone(), source_path.clone()))
+            })
+            .collect();
+    }
+
+    fn on_progress(&self, progress: &sync_engine::types::SyncProgress) {
+        let Some(current_file) = progress.current_file.as_deref() else {
+            return;
+        };
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        current_file.hash(&mut hasher);
+        let key = hasher.finish();
+        // 같은 파일의 이후 틱과, 파일이 끝난 뒤 같은 이름으로 오는 마지막 틱은 건너뜀
+        if self.current_file_key.swap(key, Ordering::Relaxed) == key {
+            return;
+        }
+
+        let relative_path = Path::new(current_file);
+        let stored_path = SyncEngine::stored_relative_path(&self.options, relative_path);
+        let source_path = match self.source_paths.lock().unwrap().get(relative_path) {
+            Some(source_relative) => self.source.join(source_relative),
+            None => self.source.join(relative_path),
+        };
+        record_sync_in_progress_file(
+            &self.files,
+            &self.task_id,
+            InProgressSyncFile {
+                source_path,
+                target_path: self.target.join(&stored_path),
+                compressed: stored_path != relative_path,
+            },
+        );
+    }
+
+    fn on_file_finished(&self) {
+        clear_sync_in_progress_file(&self.files, &self.task_id);
+    }
+}
+
+/// 진행률 콜백마다 호출됩니다. 속도는 SYNC_RATE_WINDOW가 지날 때마다 그 구간의 평균으로 갱신됩니다.
+fn record_sync_rate_sample(
+    rates: &StdMutex<HashMap<String, SyncRateTracker>>,
+    task_id: &str,
+    processed_bytes: u64,
+    total_bytes: u64,
+    now: Instant,
+) {
+    let mut rates = rates.lock().unwrap();
+    let tracker = rates
+        .entry(task_id.to_string())
+        .or_insert_with(|| SyncRateTracker {
+            window_start: now,
+            window_start_bytes: processed_bytes,
+            bytes_per_sec: 0,
+            processed_bytes,
+            total_bytes,
+        });
+    tracker.processed_bytes = processed_bytes;
+    tracker.total_bytes = total_bytes;
+
+    let elapsed = now.saturating_duration_since(tracker.window_start);
+    if elapsed >= SYNC_RATE_WINDOW {
+        let transferred = processed_bytes.saturating_sub(tracker.window_start_bytes);
+        tracker.bytes_per_sec = (transferred as f64 / elapsed.as_secs_f64()) as u64;
+        tracker.window_start = now;
+        tracker.window_start_bytes = processed_bytes;
+    }
+}
+
+fn clear_sync_rate(rates: &StdMutex<HashMap<String, SyncRateTracker>>, task_id: &str) {
+    rates.lock().unwrap().remove(task_id);
+}
+
+fn active_sync_rates_snapshot(
+    rates: &StdMutex<HashMap<String, SyncRateTracker>>,
+) -> Vec<ActiveSyncRate> {
+    let mut snapshot: Vec<ActiveSyncRate> = rates
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|(task_id, tracker)| ActiveSyncRate {
+            task_id: task_id.clone(),
+            bytes_per_sec: tracker.bytes_per_sec,
+            processed_bytes: tracker.processed_bytes,
+            total_bytes: tracker.total_bytes,
+        })
+        .collect();
+    snapshot.sort_by(|a, b| a.task_id.cmp(&b.task_id));
+    snapshot
+}
+
+/// 복사 중인 태스크의 현재 전송 속도 스냅샷. 진행률 이벤트를 구독하지 않는 상태 표시줄용
+#[tauri::command]
+fn get_active_sync_rates(state: tauri::State<'_, AppState>) -> Vec<ActiveSyncRate> {
+    active_sync_rates_snapshot(&state.sync_transfer_rates)
+}
+
+fn record_sync_run_copied_file(
+    runs: &StdMutex<HashMap<String, SyncRunCopiedFiles>>,
+    task_id: &str,
+    kind: &FileDiffKind,
+    target_path: PathBuf,
+) {
+    let mut runs = runs.lock().unwrap();
+    let run = runs.entry(task_id.to_string()).or_default();
+    match kind {
+        FileDiffKind::New => run.new_files.push(target_path),
+        _ => run.overwritten_files.push(target_path),
+    }
+}
+
+/// 실행 종료 시 취소 토큰을 내립니다. 취소되지 않고 끝난 실행은 되돌릴 대상도, 정리할 미완성 파일도
+/// 없으므로 복사 기록과 복사 중 파일 기록도 버립니다.
+async fn finish_sync_run_tracking(
+    task_id: &str,
+    cancel_token: &CancellationToken,
+    state: &AppState,
+) {
+    if !cancel_token.is_cancelled() {
+        state.sync_run_copied_files.lock().unwrap().remove(task_id);
+        clear_sync_in_progress_file(&state.sync_in_progress_files, task_id);
+    }
+    state.cancel_tokens.write().await.remove(task_id);
+}
+
+/// 마지막 실행에서 새로 만든 파일만 지웁니다. 덮어쓴 파일은 이전 버전이 없으므로 경고와 함께 남깁니다.
+/// 기록은 취소된 실행에만 남고, 한 번 되돌리면 비워집니다.
+async fn rollback_sync_run_internal(
+    task_id: &str,
+    state: &AppState,
+) -> Result<SyncRollbackResult, String> {
+    input_validation::validate_task_id(task_id).map_err(|e| e.to_string())?;
+    if state.cancel_tokens.read().await.contains_key(task_id) {
+        return Err("Task is already syncing".to_string());
+    }
+
+    let run = state
+        .sync_run_copied_files
+        .lock()
+        .unwrap()
+        .remove(task_id)
+        .unwrap_or_default();
+    let mut result = SyncRollbackResult::default();
+
+    for path in run.new_files {
+        let display = path.to_string_lossy().to_string();
+        // 이미 사라졌거나 파일이 아니게 된 경로는 건드리지 않음
+        let is_file = tokio::fs::symlink_metadata(&path)
+            .await
+            .is_ok_and(|meta| meta.is_file());
+        if !is_file {
+            continue;
+        }
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => result.removed_files.push(display),
+            Err(error) => result.failures.push(format!("{display}: {error}")),
+        }
+    }
+
+    result.kept_overwritten_files = run
+        .overwritten_files
+        .iter()
+        .map(|path| path.to_string_lossy().to_string())
+        .collect();
+    if !result.kept_overwritten_files.is_empty() {
+        result.warnings.push(format!(
+            "{} overwritten file(s) were kept because their previous version is gone",
+            result.kept_overwritten_files.len()
+        ));
+    }
+
+    if !result.removed_files.is_empty() {
+        state.log_manager.log_with_category(
+            "warning",
+            &format!(
+                "Rolled back sync: removed {} new file(s)",
+                result.removed_files.len()
+            ),
+            Some(task_id.to_string()),
+            LogCategory::FileDeleted,
+        );
+    }
+    Ok(result)
+}
+
+const CANCEL_ROLLBACK_WAIT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// 동기화를 취소하고, 멈출 때까지 기다린 뒤 이번 실행에서 새로 만든 파일을 되돌립니다.
+#[tauri::command]
+async fn cancel_and_rollback(
+    task_id: String,
+    state: tauri::State<'_, AppState>,
+) -> Result<SyncRollbackResult, String> {
+    cancel_and_rollback_internal(&task_id, state.inner()).await
+}
+
+async fn cancel_and_rollback_internal(
+    task_id: &str,
+    state: &AppState,
+) -> Result<SyncRollbackResult, String> {
+    let cancelled = cancel_operation_internal(task_id, CancelOperationType::Sync, state).await;
+    if !cancelled {
+        return Err("No running sync to cancel and roll back".to_string());
+    }
+
+    let deadline = Instant::now() + CANCEL_ROLLBACK_WAIT_TIMEOUT;
+    while state.cancel_tokens.read().await.contains_key(task_id) {
+        if Instant::now() >= deadline {
+            return Err("Timed out waiting for the sync to stop".to_string());
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    let incomplete_file_removed = cleanup_incomplete_sync_internal(task_id, state).await?;
+    let mut result = rollback_sync_run_internal(task_id, state).await?;
+    result.cancelled = cancelled;
+    result.incomplete_file_removed = incomplete_file_removed;
+    Ok(result)
+}
+
+/// 취소/중단된 동기화가 남긴 마지막 미완성 타깃 파일을 제거합니다.
+/// 일반 복사본은 원본과 크기가 다를 때만 잘린 것으로 보고 삭제하며,
+/// 압축 복사본은 완료 시 기록이 지워지므로 기록이 남아 있으면 미완성으로 간주합니다.
+async fn cleanup_incomplete_sync_internal(
+    task_id: &str,
+    state: &AppState,
+) -> Result<Option<String>, String> {
+    input_validation::validate_task_id(task_id).map_err(|e| e.to_string())?;
+    if state.syncing_tasks.read().await.contains(task_id) {
+        return Err("Task is already syncing".to_string());
+    }
+
+    let Some(file) = state.sync_in_progress_files.lock().unwrap().remove(task_id) else {
+        return Ok(None);
+    };
+
+    let target_meta = match tokio::fs::symlink_metadata(&file.target_path).await {
+        Ok(meta) if meta.is_file() => meta,
+        _ => return Ok(None),
+    };
+    let truncated = if file.compressed {
+        true
+    } else {
+        match tokio::fs::metadata(&file.source_path).await {
+            Ok(source_meta) => source_meta.len() != target_meta.len(),
+            // 원본을 확인할 수 없으면 완성 여부를 판단할 수 없으므로 남겨 둔다.
+            Err(_) => false,
+        }
+    };
+    if !truncated {
+        return Ok(None);
+    }
+
+    tokio::fs::remove_file(&file.target_path)
+        .await
+        .map_err(|e| format!("Failed to remove incomplete file: {e}"))?;
+    let target_path = file.target_path.to_string_lossy().to_string();
+    state.log_manager.log_with_category(
+        "info",
+        &format!("Removed incomplete file: {target_path}"),
+        Some(task_id.to_string()),
+        LogCategory::FileDeleted,
+    );
+    Ok(Some(target_path))
+}
+
+#[tauri::command]
+async fn cleanup_incomplete_sync(
+    task_id: String,
+    state: tauri::State<'_, AppState>,
+) -> Result<Option<String>, String> {
+    cleanup_incomplete_sync_internal(&task_id, state.inner()).await
+}
+
 async fn spawn_mcp_sync_job(
     task_id: String,
     app: tauri::AppHandle,
@@ -8398,6 +13931,7 @@ async fn spawn_mcp_sync_job(
             Some(job_id_for_job.clone()),
             None,
             None,
+            SyncRunOptions::default(),
         )
         .await;
         state_for_job
@@ -8481,6 +14015,7 @@ async fn spawn_mcp_dry_run_job(
             PathBuf::from(task.target.clone()),
             task.checksum_mode,
             exclude_patterns,
+            SyncRunOptions::default(),
             &state_for_job,
             Some(cancel_token.clone()),
             Some(job_id_for_job.clone()),
@@ -8560,6 +14095,7 @@ async fn spawn_mcp_orphan_scan_job(task_id: String, state: AppState) -> Result<S
             PathBuf::from(task.source.clone()),
             PathBuf::from(task.target.clone()),
             exclude_patterns,
+            SyncRunOptions::default(),
             &state_for_job,
             Some(cancel_token.clone()),
         )
@@ -9310,6 +14846,99 @@ pub fn format_number(n: u64) -> String {
     result.chars().rev().collect()
 }
 
+#[derive(Debug, Clone, Copy, serde::Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+enum TestNotificationStatus {
+    Sent,
+    Blocked,
+    Failed,
+    Unsupported,
+}
+
+#[derive(Debug, Clone, serde::Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+struct TestNotificationResult {
+    status: TestNotificationStatus,
+    enabled: bool,
+    message: Option<String>,
+}
+
+impl TestNotificationResult {
+    fn unsupported(message: &str) -> Self {
+        Self {
+            status: TestNotificationStatus::Unsupported,
+            enabled: false,
+            message: Some(message.to_string()),
+        }
+    }
+}
+
+fn send_test_notification_internal<R: tauri::Runtime>(
+    app: &AppHandle<R>,
+) -> TestNotificationResult {
+    #[cfg(target_os = "macos")]
+    {
+        use tauri_plugin_notification::{NotificationExt, PermissionState};
+
+        if app
+            .try_state::<tauri_plugin_notification::Notification<R>>()
+            .is_none()
+        {
+            return TestNotificationResult::unsupported("Notification plugin is not available");
+        }
+
+        match app.notification().permission_state() {
+            Ok(PermissionState::Denied) => {
+                return TestNotificationResult {
+                    status: TestNotificationStatus::Blocked,
+                    enabled: false,
+                    message: Some("Notifications are blocked in System Settings".to_string()),
+                };
+            }
+            Ok(_) => {}
+            Err(error) => {
+                return TestNotificationResult {
+                    status: TestNotificationStatus::Failed,
+                    enabled: false,
+                    message: Some(format!("Failed to read notification permission: {error}")),
+                };
+            }
+        }
+
+        match app
+            .notification()
+            .builder()
+            .title("SyncWatcher")
+            .body("Notifications are working.")
+            .show()
+        {
+            Ok(()) => TestNotificationResult {
+                status: TestNotificationStatus::Sent,
+                enabled: true,
+                message: None,
+            },
+            Err(error) => TestNotificationResult {
+                status: TestNotificationStatus::Failed,
+                enabled: false,
+                message: Some(format!("Failed to send test notification: {error}")),
+            },
+        }
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        let _ = app;
+        TestNotificationResult::unsupported("Notifications are not supported on this platform")
+    }
+}
+
+/// 설정 화면에서 알림 허용 여부를 확인할 수 있도록 테스트 알림을 보냅니다.
+#[tauri::command]
+async fn send_test_notification(app: tauri::AppHandle) -> Result<TestNotificationResult, String> {
+    Ok(send_test_notification_internal(&app))
+}
+
 /// Mac 알림 센터에 알림을 보냅니다.
 #[tauri::command]
 async fn send_notification(
@@ -9637,10 +15266,11 @@ pub fn run() {
         config_store::config_dir_for_identifier(&context_identifier)
             .expect("failed to resolve SyncWatcher config directory"),
     ));
-    let managed_recurring_history_store = Arc::new(RecurringScheduleHistoryStore::new(
-        config_store::app_support_dir_for_identifier(&context_identifier)
-            .expect("failed to resolve SyncWatcher app support directory"),
-    ));
+    let app_support_dir = config_store::app_support_dir_for_identifier(&context_identifier)
+        .expect("failed to resolve SyncWatcher app support directory");
+    let managed_recurring_history_store =
+        Arc::new(RecurringScheduleHistoryStore::new(app_support_dir.clone()));
+    let managed_capacity_history_store = Arc::new(CapacityHistoryStore::new(app_support_dir));
     let autostart_args = vec![AUTOSTART_ARG];
     let distribution_channel =
         distribution::distribution_channel_for_identifier(&context_identifier);
@@ -9954,6 +15584,11 @@ pub fn run() {
                     return;
                 }
 
+                let incomplete_syncs = list_incomplete_syncs_internal(state.inner()).await;
+                if !incomplete_syncs.is_empty() {
+                    let _ = runtime_init_app.emit("incomplete-syncs-detected", &incomplete_syncs);
+                }
+
                 match state.config_store.load_settings() {
                     Ok(settings) => {
                         if let Err(error) = sync_control_plane_listener(
@@ -9983,6 +15618,12 @@ pub fn run() {
                 let app_handle = recurring_scheduler_app.clone();
                 recurring_scheduler_loop(app_handle, state.inner().clone()).await;
             });
+
+            let capacity_sampler_app = app.handle().clone();
+            tauri::async_runtime::spawn(async move {
+                let state = capacity_sampler_app.state::<AppState>();
+                capacity_sampler_loop(state.inner().clone()).await;
+            });
             Ok(())
         })
         .manage(AppState {
@@ -9994,13 +15635,21 @@ pub fn run() {
             dry_run_cancel_tokens: Arc::new(RwLock::new(HashMap::new())),
             dry_running_tasks: Arc::new(RwLock::new(HashSet::new())),
             dry_run_artifacts: Arc::new(RwLock::new(HashMap::new())),
+            orphan_deletion_tokens: Arc::new(RwLock::new(HashMap::new())),
             watcher_manager: Arc::new(RwLock::new(WatcherManager::new())),
             runtime_config: Arc::new(RwLock::new(RuntimeConfigPayload::default())),
             syncing_tasks: Arc::new(RwLock::new(HashSet::new())),
+            syncing_resource_groups: Arc::new(RwLock::new(HashMap::new())),
             runtime_sync_queue: Arc::new(RwLock::new(VecDeque::new())),
+            runtime_bootstrap_queued_tasks: Arc::new(RwLock::new(HashSet::new())),
+            last_sync_durations: Arc::new(RwLock::new(HashMap::new())),
+            global_bandwidth_limiter: Arc::new(BandwidthLimiter::unlimited()),
             queued_sync_tasks: Arc::new(RwLock::new(HashSet::new())),
             runtime_pending_sync_tasks: Arc::new(RwLock::new(HashSet::new())),
+            runtime_stopping_tasks: Arc::new(RwLock::new(HashSet::new())),
             runtime_dispatcher_running: Arc::new(Mutex::new(false)),
+            runtime_dispatcher_generation: Arc::new(AtomicU64::new(0)),
+            runtime_dispatcher_last_dispatch_at: Arc::new(StdMutex::new(None)),
             runtime_dispatcher_wakeup: Arc::new(Notify::new()),
             runtime_sync_slot_released: Arc::new(Notify::new()),
             runtime_chain_settle_until: Arc::new(RwLock::new(HashMap::new())),
@@ -10014,10 +15663,17 @@ pub fn run() {
             active_task_operations: Arc::new(RwLock::new(HashMap::new())),
             recurring_schedule_history_store: managed_recurring_history_store,
             recurring_scheduler_wakeup: Arc::new(Notify::new()),
+            capacity_history_store: managed_capacity_history_store,
             control_plane_handle: Arc::new(Mutex::new(None)),
             mcp_jobs: Arc::new(McpJobRegistry::new()),
             mcp_job_seq: Arc::new(AtomicU64::new(0)),
             security_scoped_access_manager: Arc::new(SecurityScopedAccessManager::default()),
+            sync_in_progress_files: Arc::new(StdMutex::new(HashMap::new())),
+            watch_noise_trackers: Arc::new(StdMutex::new(HashMap::new())),
+            sync_run_copied_files: Arc::new(StdMutex::new(HashMap::new())),
+            sync_transfer_rates: Arc::new(StdMutex::new(HashMap::new())),
+            task_lifetime_stats_lock: Arc::new(Mutex::new(())),
+            task_failed_files_lock: Arc::new(Mutex::new(())),
         })
         .manage(AppExitControl::default())
         .invoke_handler(tauri::generate_handler![
@@ -10040,22 +15696,58 @@ pub fn run() {
             update_settings,
             reset_settings,
             sync_dry_run,
+            export_dry_run,
             subscribe_task_log_batches,
             unsubscribe_task_log_batches,
             find_orphan_files,
+            preview_mirror_deletions,
+            prepare_orphan_deletion,
             delete_orphan_files,
+            directory_fingerprint,
+            stream_directory_listing,
+            create_target_structure,
+            audit_sync,
+            recopy_file,
             list_conflict_review_sessions,
             get_conflict_review_session,
+            export_conflict_sessions,
+            get_recent_conflict_resolutions,
+            list_conflict_group_contents,
             open_conflict_review_window,
             resolve_conflict_items,
+            refresh_conflict_item,
             close_conflict_review_session,
+            rename_conflict_session,
             get_conflict_item_preview,
+            get_conflict_text_diff,
             list_volumes,
             get_removable_volumes,
+            list_required_source_volumes,
+            check_task_redundancy,
+            is_path_removable,
+            benchmark_target_write,
+            plan_block_delta_sync,
+            preview_target_layout,
+            estimate_scan_memory,
+            normalize_user_path,
+            projected_free_space,
+            get_capacity_history,
+            refresh_volumes,
+            cleanup_incomplete_sync,
+            get_active_sync_rates,
+            cancel_and_rollback,
             resolve_path_by_uuid,
             unmount_volume,
+            rename_volume,
+            get_volume_health,
             start_sync,
+            list_incomplete_syncs,
+            get_task_lifetime_stats,
+            reset_task_stats,
+            retry_failed_files,
+            resume_incomplete_sync,
             start_sync_from_dry_run,
+            sync_task_to_override_target,
             list_sync_tasks,
             find_sync_task_source_recommendations,
             get_sync_task,
@@ -10069,20 +15761,40 @@ pub fn run() {
             create_exclusion_set,
             update_exclusion_set,
             delete_exclusion_set,
+            tasks_using_exclusion_set,
+            delete_exclusion_set_guarded,
             reset_exclusion_sets,
+            check_persisted_state_integrity,
+            export_exclusion_sets,
+            import_exclusion_sets,
+            export_config,
+            import_config,
             cancel_operation,
             send_notification,
+            send_test_notification,
             hide_to_background,
             quit_app,
             start_watch,
             stop_watch,
+            stop_watch_graceful,
             get_watching_tasks,
+            get_watcher_diagnostics,
+            get_watch_system_limits,
             runtime_set_config,
+            set_verbose_logging,
             runtime_validate_tasks,
             runtime_validate_orphan_scan,
+            validate_exclusion_set,
+            set_task_enabled,
             runtime_get_state,
+            cancel_bootstrap_syncs,
+            get_task_queue_position,
+            get_dispatcher_state,
+            force_restart_dispatcher,
             set_auto_unmount_session_disabled,
             is_auto_unmount_session_disabled,
+            get_effective_task_config,
+            compare_task_excludes,
             get_app_config_dir,
             join_paths,
             read_yaml_file,
@@ -10095,6 +15807,8 @@ pub fn run() {
             add_log,
             get_system_logs,
             get_task_logs,
+            get_log_category_counts,
+            get_activity_timeline,
             generate_licenses_report,
             license_validation::activate_license_key,
             license_validation::deactivate_license_key,
@@ -1,13 +1,15 @@
 #[cfg(test)]
 mod integration_tests {
+    use crate::capacity_history::CapacityHistoryStore;
     use crate::config_store::{
-        apply_sync_task_update, launch_at_login_status_or_default, ConfigStore, NetworkMountRecord,
-        NetworkMountScheme, SourceIdentitySnapshot, SourceType, SourceUuidType, SyncTaskRecord,
-        UpdateSyncTaskRequest,
+        apply_sync_task_update, default_settings_record, launch_at_login_status_or_default,
+        ConfigStore, ExclusionSetRecord, NetworkMountRecord, NetworkMountScheme,
+        SourceIdentitySnapshot, SourceType, SourceUuidType, SyncTaskRecord, UpdateSyncTaskRequest,
+        DEFAULT_MAX_WATCHERS,
     };
     use crate::control_plane::ControlPlaneRequest;
     use crate::distribution::DistributionChannel;
-    use crate::logging::{LogEvent, LogManager};
+    use crate::logging::{LogCategory, LogEvent, LogManager};
     use crate::mcp_jobs::McpJobRegistry;
     use crate::mcp_stdio::mcp_auth_token_from_args;
     use crate::network_mount::{
@@ -20,50 +22,88 @@ mod integration_tests {
     };
     use crate::security_scoped::SecurityScopedAccessManager;
     use crate::sync_engine::types::{
-        ConflictFileSnapshot, DryRunPhase, DryRunProgress, DryRunSummary, FileDiff, FileDiffKind,
-        SyncFileEntry, SyncFileStatus, TargetNewerConflictCandidate, TargetPreflightKind,
+        ConflictFileSnapshot, DryRunPhase, DryRunProgress, DryRunResult, DryRunSummary, FileDiff,
+        FileDiffKind, OrphanSummary, SyncError, SyncErrorKind, SyncFileEntry, SyncFileStatus,
+        SyncProgressPhase, SyncResult, TargetNewerConflictCandidate, TargetPreflightKind,
     };
+    use crate::sync_engine::BandwidthLimiter;
     use crate::system_integration::VolumeInfo;
-    use crate::watcher::WatcherManager;
+    use crate::watcher::{WatchPauseReason, WatcherDiagnostics, WatcherManager};
     use crate::{
-        build_dry_run_artifact, build_runtime_watch_upstreams, build_validated_runtime_tasks,
-        can_enqueue_runtime_watch_bootstrap_task, cancel_operation_internal,
-        classify_missing_target_path, close_conflict_review_session_internal,
-        compute_volume_mount_diff, copy_file_preserve_under_root, create_conflict_review_session,
-        create_sync_task_internal, decide_autostart_launch, decide_runtime_auto_unmount,
-        delete_sync_task_internal_core, dequeue_runtime_sync_task, emit_dry_run_diff_batch,
-        emit_sync_file_batch, emit_task_log_batch_transport, emit_task_log_with_recurring_detail,
+        acquire_runtime_sync_slot, active_sync_rates_snapshot, benchmark_target_write_internal,
+        build_conflict_sessions_csv, build_dry_run_artifact, build_runtime_watch_upstreams,
+        build_validated_runtime_tasks, can_enqueue_runtime_watch_bootstrap_task,
+        cancel_and_rollback_internal, cancel_bootstrap_syncs_internal, cancel_operation_internal,
+        check_persisted_state_integrity_internal, classify_missing_target_path,
+        cleanup_incomplete_sync_internal, clear_incomplete_sync_marker, clear_sync_rate,
+        close_conflict_review_session_internal, collect_capacity_samples,
+        collect_required_source_volumes, compare_task_excludes_internal,
+        compute_conflict_text_diff_hunks, compute_task_queue_position, compute_volume_mount_diff,
+        copy_file_preserve_under_root, create_conflict_review_session, create_sync_task_internal,
+        decide_autostart_launch, decide_runtime_auto_unmount,
+        delete_exclusion_set_guarded_internal, delete_orphan_files_internal,
+        delete_sync_task_internal_core, dequeue_runtime_sync_task, directory_fingerprint_internal,
+        emit_dry_run_diff_batch, emit_sync_file_batch, emit_task_log_batch_transport,
+        emit_task_log_with_recurring_detail, enqueue_initial_runtime_watch_bootstrap_tasks,
         enqueue_runtime_sync_task_internal, enqueue_runtime_watch_bootstrap_tasks,
-        ensure_non_overlapping_paths, find_orphan_files_internal,
+        ensure_non_overlapping_paths, ensure_target_not_system_volume, error_codes,
+        export_config_internal, export_conflict_sessions_internal, export_dry_run_internal,
+        export_exclusion_sets_internal, find_orphan_files_internal,
         find_runtime_orphan_target_conflict_issue, find_runtime_task_validation_issue,
-        find_runtime_watch_cycle, find_task_source_recommendation, finish_runtime_producer,
-        format_bytes_with_unit, get_app_config_dir, get_app_version, handle_volume_watch_event,
-        handle_volume_watch_tick, has_autostart_arg, is_auto_unmount_session_disabled_internal,
-        is_runtime_watch_task_active, join_paths, log_conflict_resolution_failure,
+        find_runtime_watch_cycle, find_task_redundancy_warnings, find_task_source_recommendation,
+        finish_runtime_producer, finish_sync_run_tracking, force_reset_runtime_dispatcher,
+        format_bytes_with_unit, get_app_config_dir, get_app_version, get_capacity_history_internal,
+        get_dispatcher_state_internal, get_effective_task_config_internal,
+        get_recent_conflict_resolutions_internal, get_task_lifetime_stats_internal,
+        get_watcher_diagnostics_internal, handle_volume_watch_event, handle_volume_watch_tick,
+        has_autostart_arg, import_config_internal, import_exclusion_sets_internal,
+        is_auto_unmount_session_disabled_internal, is_runtime_watch_task_active,
+        is_within_quiet_hours, join_paths, list_conflict_group_contents_internal,
+        list_conflict_session_summaries_internal, list_incomplete_syncs_internal,
+        load_canonical_runtime_payload, log_conflict_resolution_failure,
         log_conflict_resolution_success, log_conflict_skip_on_close,
-        mark_downstream_watch_tasks_settle_for_target, normalize_uuid_sub_path,
-        owner_license_debug_token_from_args, parse_uuid_source_path, patch_sync_task_internal_core,
+        mark_downstream_watch_tasks_settle_for_target, normalize_user_path_with,
+        normalize_uuid_sub_path, owner_license_debug_token_from_args, parse_uuid_source_path,
+        patch_sync_task_internal_core, path_removable_info, pending_tasks_on_volume,
         persist_patched_sync_task_and_collect_history_warnings, preflight_target_path,
-        progress_phase_to_log_category, prune_auto_unmount_session_disabled_tasks,
-        read_current_conflict_file_info, record_runtime_validation_issue,
-        refresh_uuid_source_identity, remove_runtime_sync_task_state,
-        resolve_conflict_items_internal, resolve_runtime_exclude_patterns,
+        prepare_orphan_deletion_internal, progress_phase_to_log_category, project_free_space,
+        prune_auto_unmount_session_disabled_tasks, quiet_hours_remaining,
+        read_current_conflict_file_info, record_runtime_validation_issue, record_sync_rate_sample,
+        record_sync_run_copied_file, record_task_failed_files, record_task_lifetime_stats,
+        refresh_conflict_item_internal, refresh_uuid_source_identity, refresh_volumes_internal,
+        release_sync_slot, remove_runtime_sync_task_state, rename_conflict_session_internal,
+        replace_runtime_config, reset_task_stats_internal, resolve_conflict_items_internal,
+        resolve_override_target_sync, resolve_preview_kind, resolve_runtime_exclude_patterns,
+        resolve_runtime_resource_group, retry_failed_files_internal, rollback_sync_run_internal,
         runtime_desired_watch_sources, runtime_find_watch_task, runtime_get_state_internal,
-        runtime_validation_issue_log_message, runtime_watch_bootstrap_task_ids,
-        runtime_watch_restart_task_ids, runtime_watch_task_needs_restart,
-        select_runtime_dispatch_candidate, set_auto_unmount_session_disabled_internal,
-        should_include_check_for_updates_menu, should_reconcile_runtime_watchers_for_volume_change,
-        snapshot_recurring_schedule_detail_entries, sync_dry_run_internal,
-        take_runtime_pending_sync_task, unix_now_ms, validate_control_plane_auth,
-        validate_dry_run_artifact, validate_legacy_config_store_file_path, validate_runtime_tasks,
-        volume_watch_next_tick_delay, AppState, CancelOperationType, ConflictFileInfo,
-        ConflictItemStatus, ConflictResolutionAction, ConflictResolutionRequest,
-        ConflictReviewSession, ConflictSessionOrigin, DataUnitSystem, DryRunDiffBatchEvent,
-        DryRunLiveState, KeychainCredentialAction, RuntimeActiveProducer,
-        RuntimeAutoUnmountDecision, RuntimeExclusionSet, RuntimeProducerKind,
+        runtime_tasks_blocked_by_resource_group, runtime_validation_issue_log_message,
+        runtime_watch_bootstrap_task_ids, runtime_watch_restart_task_ids,
+        runtime_watch_task_needs_restart, runtime_watch_tasks_over_limit,
+        select_runtime_dispatch_candidate, select_sync_notifications,
+        send_test_notification_internal, set_auto_unmount_session_disabled_internal,
+        set_task_enabled_internal, set_verbose_logging_internal,
+        should_include_check_for_updates_menu, should_log_file_entry, should_log_sync_milestone,
+        should_reconcile_runtime_watchers_for_volume_change,
+        snapshot_recurring_schedule_detail_entries, stream_directory_listing_internal,
+        sync_dry_run_internal, take_runtime_pending_sync_task, tasks_using_exclusion_set_internal,
+        to_conflict_detail, to_conflict_summary, to_runtime_settings_record, unix_now_ms,
+        validate_control_plane_auth, validate_dry_run_artifact, validate_exclusion_set_internal,
+        validate_legacy_config_store_file_path, validate_runtime_tasks, validate_settings_record,
+        volume_watch_next_tick_delay, wait_for_task_sync_to_finish, with_task_max_duration,
+        write_incomplete_sync_marker, write_task_sync_snapshot, ActiveSyncRate, AppState,
+        CancelOperationType, ConfigExportFile, ConflictFileInfo, ConflictItemStatus,
+        ConflictResolutionAction, ConflictResolutionRequest, ConflictReviewSession,
+        ConflictSessionOrigin, ConflictTextDiffHunk, DataUnitSystem, DryRunDiffBatchEvent,
+        DryRunLiveState, ExclusionSetTaskReference, InProgressSyncFile, IncompleteSyncMarker,
+        KeychainCredentialAction, PathRemovableInfo, PersistedFileStatus, RequiredSourceVolume,
+        RuntimeActiveProducer, RuntimeAutoUnmountDecision, RuntimeConfigPayload,
+        RuntimeExclusionSet, RuntimeProducerKind, RuntimeSettings, RuntimeSyncAcquireResult,
         RuntimeSyncEnqueueResult, RuntimeSyncTask, RuntimeTaskValidationCode,
-        RuntimeTaskValidationIssue, SyncEventOrigin, SyncFileBatchEvent, SyncLiveState, SyncOrigin,
-        TargetNewerConflictItem, TaskLogBatchSubscription, VolumeEmitDebounceState,
+        RuntimeTaskValidationIssue, SyncEventOrigin, SyncExecutionFailure, SyncExecutionResult,
+        SyncFileBatchEvent, SyncLiveState, SyncNotificationKind, SyncOrigin, SyncProgressState,
+        SyncRunOptions, TargetNewerConflictItem, TaskLogBatchSubscription, TaskNotificationPrefs,
+        TaskQueueStatus, TaskRedundancyReason, TestNotificationStatus, VolumeEmitDebounceState,
+        CONFLICT_EXPORT_CSV_HEADER, MAX_CONFLICT_SESSION_LABEL_CHARS, RUNTIME_SYNC_MAX_CONCURRENCY,
     };
     use serde::de::DeserializeOwned;
     use std::collections::{HashMap, HashSet, VecDeque};
@@ -109,6 +149,11 @@ mod integration_tests {
             auto_unmount: false,
             verify_after_copy: true,
             exclusion_sets: Vec::new(),
+            resource_group: None,
+            max_coalesce_secs: None,
+            max_duration_secs: None,
+            notifications: TaskNotificationPrefs::default(),
+            enabled: true,
         }
     }
 
@@ -213,6 +258,28 @@ mod integration_tests {
         );
     }
 
+    #[tokio::test]
+    async fn conflict_copy_recompresses_into_compressed_target() {
+        use std::io::Read;
+
+        let source_root = tempdir().expect("source temp dir should create");
+        let target_root = tempdir().expect("target temp dir should create");
+        let source = source_root.path().join("app.log");
+        let target = target_root.path().join("app.log.gz");
+        std::fs::write(&source, "source log line\n").expect("source should write");
+        std::fs::write(&target, "stale").expect("target should write");
+
+        copy_file_preserve_under_root(&source, target_root.path(), &target)
+            .await
+            .expect("compressed copy should succeed");
+
+        let mut decoded = String::new();
+        flate2::read::GzDecoder::new(std::fs::File::open(&target).expect("target should open"))
+            .read_to_string(&mut decoded)
+            .expect("target should be gzip");
+        assert_eq!(decoded, "source log line\n");
+    }
+
     fn build_network_mount() -> NetworkMountRecord {
         build_network_mount_with_credentials("smb://nas.local/share", Some("backup-user"))
     }
@@ -247,6 +314,11 @@ mod integration_tests {
             auto_unmount: false,
             verify_after_copy: true,
             exclusion_sets: Vec::new(),
+            resource_group: None,
+            max_coalesce_secs: None,
+            max_duration_secs: None,
+            notifications: TaskNotificationPrefs::default(),
+            enabled: true,
         }
     }
 
@@ -276,6 +348,35 @@ mod integration_tests {
         }
     }
 
+    fn build_path_task(id: &str, source: &str, target: &str, watch_mode: bool) -> SyncTaskRecord {
+        SyncTaskRecord {
+            id: id.to_string(),
+            name: format!("task-{id}"),
+            source: source.to_string(),
+            source_bookmark: None,
+            source_network_mount: None,
+            target: target.to_string(),
+            target_bookmark: None,
+            target_network_mount: None,
+            checksum_mode: false,
+            verify_after_copy: true,
+            exclusion_sets: Vec::new(),
+            watch_mode,
+            auto_unmount: false,
+            source_type: Some(SourceType::Path),
+            source_uuid: None,
+            source_uuid_type: None,
+            source_sub_path: None,
+            source_identity: None,
+            recurring_schedules: Vec::new(),
+            enabled: true,
+            notifications: TaskNotificationPrefs::default(),
+            resource_group: None,
+            max_coalesce_secs: None,
+            max_duration_secs: None,
+        }
+    }
+
     fn build_uuid_task(
         id: &str,
         source_uuid_type: SourceUuidType,
@@ -310,6 +411,11 @@ mod integration_tests {
             source_sub_path: Some(normalize_uuid_sub_path(source_sub_path).unwrap()),
             source_identity,
             recurring_schedules: Vec::new(),
+            enabled: true,
+            notifications: TaskNotificationPrefs::default(),
+            resource_group: None,
+            max_coalesce_secs: None,
+            max_duration_secs: None,
         }
     }
 
@@ -347,13 +453,21 @@ mod integration_tests {
             dry_run_cancel_tokens: Arc::new(RwLock::new(HashMap::new())),
             dry_running_tasks: Arc::new(RwLock::new(HashSet::new())),
             dry_run_artifacts: Arc::new(RwLock::new(HashMap::new())),
+            orphan_deletion_tokens: Arc::new(RwLock::new(HashMap::new())),
             watcher_manager: Arc::new(RwLock::new(WatcherManager::new())),
             runtime_config: Arc::new(RwLock::new(Default::default())),
             syncing_tasks: Arc::new(RwLock::new(HashSet::new())),
+            syncing_resource_groups: Arc::new(RwLock::new(HashMap::new())),
             runtime_sync_queue: Arc::new(RwLock::new(VecDeque::new())),
+            runtime_bootstrap_queued_tasks: Arc::new(RwLock::new(HashSet::new())),
+            last_sync_durations: Arc::new(RwLock::new(HashMap::new())),
+            global_bandwidth_limiter: Arc::new(BandwidthLimiter::unlimited()),
             queued_sync_tasks: Arc::new(RwLock::new(HashSet::new())),
             runtime_pending_sync_tasks: Arc::new(RwLock::new(HashSet::new())),
+            runtime_stopping_tasks: Arc::new(RwLock::new(HashSet::new())),
             runtime_dispatcher_running: Arc::new(Mutex::new(false)),
+            runtime_dispatcher_generation: Arc::new(AtomicU64::new(0)),
+            runtime_dispatcher_last_dispatch_at: Arc::new(StdMutex::new(None)),
             runtime_dispatcher_wakeup: Arc::new(Notify::new()),
             runtime_sync_slot_released: Arc::new(Notify::new()),
             runtime_chain_settle_until: Arc::new(RwLock::new(HashMap::new())),
@@ -369,10 +483,17 @@ mod integration_tests {
                 temp_config_dir(),
             )),
             recurring_scheduler_wakeup: Arc::new(Notify::new()),
+            capacity_history_store: Arc::new(CapacityHistoryStore::new(temp_config_dir())),
             control_plane_handle: Arc::new(Mutex::new(None)),
             mcp_jobs: Arc::new(McpJobRegistry::new()),
             mcp_job_seq: Arc::new(AtomicU64::new(0)),
             security_scoped_access_manager: Arc::new(SecurityScopedAccessManager::default()),
+            sync_in_progress_files: Arc::new(StdMutex::new(HashMap::new())),
+            watch_noise_trackers: Arc::new(StdMutex::new(HashMap::new())),
+            sync_run_copied_files: Arc::new(StdMutex::new(HashMap::new())),
+            sync_transfer_rates: Arc::new(StdMutex::new(HashMap::new())),
+            task_lifetime_stats_lock: Arc::new(Mutex::new(())),
+            task_failed_files_lock: Arc::new(Mutex::new(())),
         }
     }
 
@@ -435,6 +556,7 @@ mod integration_tests {
             status: ConflictItemStatus::Pending,
             note: None,
             resolved_at_unix_ms: None,
+            renamed_backup_path: None,
         }
     }
 
@@ -479,6 +601,8 @@ mod integration_tests {
             origin: ConflictSessionOrigin::Manual,
             created_at_unix_ms: unix_now_ms(),
             items,
+            applied_resolutions: HashMap::new(),
+            label: None,
         }
     }
 
@@ -515,6 +639,7 @@ mod integration_tests {
             status: ConflictItemStatus::Pending,
             note: None,
             resolved_at_unix_ms: None,
+            renamed_backup_path: None,
         }
     }
 
@@ -1028,6 +1153,8 @@ mod integration_tests {
                 target_size: None,
                 checksum_source: None,
                 checksum_target: None,
+                renamed_from: None,
+                source_path: None,
             }],
             target_preflight: None,
         };
@@ -1065,6 +1192,8 @@ mod integration_tests {
                 target_size: None,
                 checksum_source: None,
                 checksum_target: None,
+                renamed_from: None,
+                source_path: None,
             }],
             target_preflight: None,
         };
@@ -1198,6 +1327,11 @@ mod integration_tests {
                     checksum_mode: false,
                     retention_count: 20,
                 }],
+                enabled: true,
+                notifications: TaskNotificationPrefs::default(),
+                resource_group: None,
+                max_coalesce_secs: None,
+                max_duration_secs: None,
             },
             None,
             None,
@@ -1248,6 +1382,11 @@ mod integration_tests {
                     checksum_mode: false,
                     retention_count: 20,
                 }],
+                enabled: true,
+                notifications: TaskNotificationPrefs::default(),
+                resource_group: None,
+                max_coalesce_secs: None,
+                max_duration_secs: None,
             },
             None,
             None,
@@ -1297,6 +1436,11 @@ mod integration_tests {
                 source_sub_path: None,
                 source_identity: None,
                 recurring_schedules: Vec::new(),
+                enabled: true,
+                notifications: TaskNotificationPrefs::default(),
+                resource_group: None,
+                max_coalesce_secs: None,
+                max_duration_secs: None,
             },
             None,
             None,
@@ -1401,6 +1545,11 @@ mod integration_tests {
             source_sub_path: None,
             source_identity: None,
             recurring_schedules: Vec::new(),
+            enabled: true,
+            notifications: TaskNotificationPrefs::default(),
+            resource_group: None,
+            max_coalesce_secs: None,
+            max_duration_secs: None,
         }])
         .expect("repair content should serialize");
 
@@ -1492,6 +1641,11 @@ mod integration_tests {
             source_sub_path: None,
             source_identity: None,
             recurring_schedules: Vec::new(),
+            enabled: true,
+            notifications: TaskNotificationPrefs::default(),
+            resource_group: None,
+            max_coalesce_secs: None,
+            max_duration_secs: None,
         };
         state
             .config_store
@@ -1586,6 +1740,11 @@ mod integration_tests {
             source_sub_path: None,
             source_identity: None,
             recurring_schedules: Vec::new(),
+            enabled: true,
+            notifications: TaskNotificationPrefs::default(),
+            resource_group: None,
+            max_coalesce_secs: None,
+            max_duration_secs: None,
         };
         state
             .config_store
@@ -1665,6 +1824,11 @@ mod integration_tests {
                     checksum_mode: false,
                     retention_count: 5,
                 }],
+                enabled: true,
+                notifications: TaskNotificationPrefs::default(),
+                resource_group: None,
+                max_coalesce_secs: None,
+                max_duration_secs: None,
             }])
             .expect("tasks should save");
 
@@ -1758,6 +1922,135 @@ mod integration_tests {
         assert_eq!(history[0].detail_entries.len(), 150);
     }
 
+    #[test]
+    fn test_resolve_preview_kind_detects_extensionless_png_as_image() {
+        let png_head = b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR";
+        assert_eq!(
+            resolve_preview_kind("/conflicts/photo", png_head),
+            ("image", Some("image/png"))
+        );
+        assert_eq!(
+            resolve_preview_kind("/conflicts/README", b"plain words\n"),
+            ("text", Some("text/plain"))
+        );
+    }
+
+    #[test]
+    fn test_resolve_preview_kind_does_not_treat_binary_txt_as_text() {
+        let (kind, mime) = resolve_preview_kind("/conflicts/notes.txt", b"\x00\x01\xfe\xff\x00");
+        assert_ne!(kind, "text");
+        assert_eq!(mime, None);
+
+        // 확장자가 이미지면 내용과 무관하게 확장자 추정을 유지
+        assert_eq!(
+            resolve_preview_kind("/conflicts/photo.jpg", b"\x00\x01").0,
+            "image"
+        );
+    }
+
+    #[test]
+    fn test_compute_conflict_text_diff_hunks_reports_changed_lines() {
+        let source = "alpha\nbeta\ngamma\ndelta\nepsilon\n";
+        let target = "alpha\nBETA\ngamma\ndelta\nepsilon\nzeta\n";
+
+        let (hunks, truncated) = compute_conflict_text_diff_hunks(source, target, 100);
+
+        assert!(!truncated);
+        assert_eq!(
+            hunks,
+            vec![
+                ConflictTextDiffHunk {
+                    source_start: 2,
+                    source_lines: vec!["beta".to_string()],
+                    target_start: 2,
+                    target_lines: vec!["BETA".to_string()],
+                },
+                ConflictTextDiffHunk {
+                    source_start: 6,
+                    source_lines: Vec::new(),
+                    target_start: 6,
+                    target_lines: vec!["zeta".to_string()],
+                },
+            ]
+        );
+
+        let (limited, truncated) = compute_conflict_text_diff_hunks(source, target, 2);
+        assert!(truncated);
+        assert_eq!(limited.len(), 1);
+
+        let (identical, truncated) = compute_conflict_text_diff_hunks(source, source, 100);
+        assert!(identical.is_empty());
+        assert!(!truncated);
+    }
+
+    #[tokio::test]
+    async fn test_refresh_conflict_item_reports_changes_since_detection() {
+        let state = build_app_state();
+        let temp = tempdir().expect("tempdir should be created");
+        let source_root = temp.path().join("source");
+        let target_root = temp.path().join("target");
+        std::fs::create_dir_all(&source_root).unwrap();
+        std::fs::create_dir_all(&target_root).unwrap();
+        for name in ["edited.txt", "stable.txt"] {
+            std::fs::write(source_root.join(name), "source").unwrap();
+            std::fs::write(target_root.join(name), "target").unwrap();
+        }
+
+        let edited = build_conflict_item_with_paths(
+            "item-edited",
+            "edited.txt",
+            &source_root.join("edited.txt"),
+            &target_root.join("edited.txt"),
+        )
+        .await;
+        let stable = build_conflict_item_with_paths(
+            "item-stable",
+            "stable.txt",
+            &source_root.join("stable.txt"),
+            &target_root.join("stable.txt"),
+        )
+        .await;
+        let session = build_conflict_session(
+            "session-refresh",
+            "task-refresh",
+            "Task Refresh",
+            &source_root,
+            &target_root,
+            vec![edited, stable],
+        );
+        state
+            .conflict_review_sessions
+            .write()
+            .await
+            .insert("session-refresh".to_string(), session);
+
+        std::fs::write(target_root.join("edited.txt"), "target edited later").unwrap();
+
+        let changed = refresh_conflict_item_internal("session-refresh", "item-edited", &state)
+            .await
+            .expect("refresh should succeed");
+        assert!(changed.changed);
+        assert!(changed.target_changed);
+        assert!(!changed.source_changed);
+        assert_eq!(changed.target.size, "target edited later".len() as u64);
+
+        let unchanged = refresh_conflict_item_internal("session-refresh", "item-stable", &state)
+            .await
+            .expect("refresh should succeed");
+        assert!(!unchanged.changed);
+
+        let refreshed_again =
+            refresh_conflict_item_internal("session-refresh", "item-edited", &state)
+                .await
+                .expect("refresh should succeed");
+        assert!(!refreshed_again.changed);
+
+        let error = refresh_conflict_item_internal("session-refresh", "missing", &state)
+            .await
+            .expect_err("unknown item should fail");
+        assert!(error.contains("Conflict item not found"));
+    }
+
     #[tokio::test]
     async fn test_resolve_conflict_items_internal_rename_then_copy_updates_files_session_and_logs()
     {
@@ -1798,6 +2091,7 @@ mod integration_tests {
                 action: ConflictResolutionAction::RenameThenCopy,
             }],
             None,
+            None,
             &state,
         )
         .await
@@ -1840,6 +2134,150 @@ mod integration_tests {
         }));
     }
 
+    #[tokio::test]
+    async fn test_resolve_conflict_items_internal_same_idempotency_key_returns_prior_result() {
+        let state = build_app_state();
+        let temp = tempdir().expect("tempdir should be created");
+        let source_root = temp.path().join("source");
+        let target_root = temp.path().join("target");
+        let source_path = source_root.join("a.jpg");
+        let target_path = target_root.join("a.jpg");
+        std::fs::create_dir_all(&source_root).unwrap();
+        std::fs::create_dir_all(&target_root).unwrap();
+        std::fs::write(&source_path, "new-photo").unwrap();
+        std::fs::write(&target_path, "old-photo").unwrap();
+
+        let item =
+            build_conflict_item_with_paths("item-1", "a.jpg", &source_path, &target_path).await;
+        let session = build_conflict_session(
+            "session-retry",
+            "task-retry",
+            "Task Retry",
+            &source_root,
+            &target_root,
+            vec![item],
+        );
+        state
+            .conflict_review_sessions
+            .write()
+            .await
+            .insert("session-retry".to_string(), session);
+        let request = vec![ConflictResolutionRequest {
+            item_id: "item-1".to_string(),
+            action: ConflictResolutionAction::RenameThenCopy,
+        }];
+
+        let first = resolve_conflict_items_internal(
+            "session-retry".to_string(),
+            request.clone(),
+            Some("resolve-1".to_string()),
+            None,
+            &state,
+        )
+        .await
+        .expect("first resolution should succeed");
+        assert_eq!(first.processed_count, 1);
+
+        // Even if the item looks pending again, a retry with the same key must not re-apply.
+        state
+            .conflict_review_sessions
+            .write()
+            .await
+            .get_mut("session-retry")
+            .unwrap()
+            .items[0]
+            .status = ConflictItemStatus::Pending;
+        let retried = resolve_conflict_items_internal(
+            "session-retry".to_string(),
+            request,
+            Some("resolve-1".to_string()),
+            None,
+            &state,
+        )
+        .await
+        .expect("retried resolution should succeed");
+
+        assert_eq!(retried.processed_count, first.processed_count);
+        assert_eq!(retried.pending_count, first.pending_count);
+        assert_eq!(std::fs::read_dir(&target_root).unwrap().count(), 2);
+        assert_eq!(std::fs::read_to_string(&target_path).unwrap(), "new-photo");
+    }
+
+    #[tokio::test]
+    async fn test_resolve_conflict_items_internal_rename_then_copy_retry_reuses_partial_backup() {
+        let state = build_app_state();
+        let temp = tempdir().expect("tempdir should be created");
+        let source_root = temp.path().join("source");
+        let target_root = temp.path().join("target");
+        let source_path = source_root.join("a.jpg");
+        let target_path = target_root.join("a.jpg");
+        std::fs::create_dir_all(&source_root).unwrap();
+        std::fs::create_dir_all(&target_root).unwrap();
+        std::fs::write(&source_path, "new-photo").unwrap();
+        std::fs::write(&target_path, "old-photo").unwrap();
+
+        let item =
+            build_conflict_item_with_paths("item-1", "a.jpg", &source_path, &target_path).await;
+        let session = build_conflict_session(
+            "session-partial",
+            "task-partial",
+            "Task Partial",
+            &source_root,
+            &target_root,
+            vec![item],
+        );
+        state
+            .conflict_review_sessions
+            .write()
+            .await
+            .insert("session-partial".to_string(), session);
+        let request = vec![ConflictResolutionRequest {
+            item_id: "item-1".to_string(),
+            action: ConflictResolutionAction::RenameThenCopy,
+        }];
+
+        // The rename succeeds but the copy fails, leaving the item pending.
+        std::fs::remove_file(&source_path).unwrap();
+        let first = resolve_conflict_items_internal(
+            "session-partial".to_string(),
+            request.clone(),
+            None,
+            None,
+            &state,
+        )
+        .await
+        .expect("failure should be reported in the result");
+        assert_eq!(first.failures.len(), 1);
+        assert!(!target_path.exists());
+        let backup_path = {
+            let sessions = state.conflict_review_sessions.read().await;
+            let item = &sessions.get("session-partial").unwrap().items[0];
+            assert_eq!(item.status, ConflictItemStatus::Pending);
+            PathBuf::from(
+                item.renamed_backup_path
+                    .clone()
+                    .expect("backup path should be recorded"),
+            )
+        };
+
+        std::fs::write(&source_path, "new-photo").unwrap();
+        let retried = resolve_conflict_items_internal(
+            "session-partial".to_string(),
+            request,
+            None,
+            None,
+            &state,
+        )
+        .await
+        .expect("retried resolution should succeed");
+
+        assert_eq!(retried.processed_count, 1);
+        assert!(retried.failures.is_empty());
+        assert_eq!(std::fs::read_to_string(&target_path).unwrap(), "new-photo");
+        assert_eq!(std::fs::read_to_string(&backup_path).unwrap(), "old-photo");
+        assert_eq!(std::fs::read_dir(&target_root).unwrap().count(), 2);
+    }
+
     #[cfg(unix)]
     #[tokio::test]
     async fn test_resolve_conflict_items_internal_rename_then_copy_rejects_parent_symlink() {
@@ -1886,6 +2324,7 @@ mod integration_tests {
                 action: ConflictResolutionAction::RenameThenCopy,
             }],
             None,
+            None,
             &state,
         )
         .await
@@ -1909,6 +2348,98 @@ mod integration_tests {
         assert!(item.resolved_at_unix_ms.is_none());
     }
 
+    #[tokio::test]
+    async fn test_resolve_conflict_items_internal_keep_target_marks_reviewed_without_copy() {
+        let state = build_app_state();
+        let temp = tempdir().expect("tempdir should be created");
+        let source_root = temp.path().join("source");
+        let target_root = temp.path().join("target");
+        let source_path = source_root.join("a.txt");
+        let target_path = target_root.join("a.txt");
+        let other_source_path = source_root.join("b.txt");
+        let other_target_path = target_root.join("b.txt");
+        std::fs::create_dir_all(&source_root).unwrap();
+        std::fs::create_dir_all(&target_root).unwrap();
+        std::fs::write(&source_path, "source").unwrap();
+        std::fs::write(&target_path, "target").unwrap();
+        std::fs::write(&other_source_path, "source-b").unwrap();
+        std::fs::write(&other_target_path, "target-b").unwrap();
+
+        let keep_item =
+            build_conflict_item_with_paths("item-keep", "a.txt", &source_path, &target_path).await;
+        let skip_item = build_conflict_item_with_paths(
+            "item-skip",
+            "b.txt",
+            &other_source_path,
+            &other_target_path,
+        )
+        .await;
+        state.conflict_review_sessions.write().await.insert(
+            "session-keep".to_string(),
+            build_conflict_session(
+                "session-keep",
+                "task-keep",
+                "Task Keep",
+                &source_root,
+                &target_root,
+                vec![keep_item, skip_item],
+            ),
+        );
+
+        let result = resolve_conflict_items_internal(
+            "session-keep".to_string(),
+            vec![ConflictResolutionRequest {
+                item_id: "item-keep".to_string(),
+                action: ConflictResolutionAction::KeepTarget,
+            }],
+            None,
+            None,
+            &state,
+        )
+        .await
+        .expect("keep target should succeed");
+
+        assert_eq!(result.processed_count, 1);
+        assert_eq!(result.pending_count, 1);
+        assert_eq!(std::fs::read_to_string(&target_path).unwrap(), "target");
+        {
+            let sessions = state.conflict_review_sessions.read().await;
+            let session = sessions.get("session-keep").unwrap();
+            assert_eq!(session.items[0].status, ConflictItemStatus::KeptTarget);
+            assert!(session.items[0].resolved_at_unix_ms.is_some());
+            let summary = to_conflict_summary(session);
+            assert_eq!(summary.pending_count, 1);
+            assert_eq!(summary.resolved_count, 1);
+            assert_eq!(summary.kept_target_count, 1);
+        }
+
+        resolve_conflict_items_internal(
+            "session-keep".to_string(),
+            vec![ConflictResolutionRequest {
+                item_id: "item-skip".to_string(),
+                action: ConflictResolutionAction::Skip,
+            }],
+            None,
+            None,
+            &state,
+        )
+        .await
+        .expect("skip should succeed");
+
+        let sessions = state.conflict_review_sessions.read().await;
+        let session = sessions.get("session-keep").unwrap();
+        assert_eq!(session.items[1].status, ConflictItemStatus::Skipped);
+        let summary = to_conflict_summary(session);
+        assert_eq!(summary.pending_count, 0);
+        assert_eq!(summary.kept_target_count, 1);
+        drop(sessions);
+
+        let logs = state.log_manager.get_logs(Some("task-keep".to_string()));
+        assert!(logs.iter().any(|entry| entry
+            .message
+            .contains("Conflict review [session-keep] kept target after review: a.txt")));
+    }
+
     #[tokio::test]
     async fn test_resolve_conflict_items_internal_records_failure_log_and_note_on_copy_error() {
         let state = build_app_state();
@@ -1954,6 +2485,7 @@ mod integration_tests {
                 action: ConflictResolutionAction::ForceCopy,
             }],
             None,
+            None,
             &state,
         )
         .await
@@ -1987,6 +2519,67 @@ mod integration_tests {
         }));
     }
 
+    #[tokio::test]
+    async fn test_rename_conflict_session_sets_label_in_summary_and_detail() {
+        let state = build_app_state();
+        let session = build_conflict_session(
+            "session-label",
+            "task-label",
+            "Task Label",
+            Path::new("/tmp/source-root"),
+            Path::new("/tmp/target-root"),
+            vec![build_conflict_item("photos/a.jpg")],
+        );
+        state
+            .conflict_review_sessions
+            .write()
+            .await
+            .insert("session-label".to_string(), session);
+
+        let summary = rename_conflict_session_internal(
+            "session-label",
+            Some("  Camera A morning shoot ".to_string()),
+            &state,
+        )
+        .await
+        .expect("rename should succeed");
+        assert_eq!(summary.label.as_deref(), Some("Camera A morning shoot"));
+
+        let summaries = list_conflict_session_summaries_internal(&state).await;
+        assert_eq!(
+            summaries[0].label.as_deref(),
+            Some("Camera A morning shoot")
+        );
+        let session = state.conflict_review_sessions.read().await["session-label"].clone();
+        assert_eq!(
+            to_conflict_detail(&session).label.as_deref(),
+            Some("Camera A morning shoot")
+        );
+
+        let reloaded: ConflictReviewSession = serde_json::from_str(
+            &serde_json::to_string(&session).expect("session should serialize"),
+        )
+        .expect("session should deserialize");
+        assert_eq!(reloaded.label.as_deref(), Some("Camera A morning shoot"));
+
+        let cleared =
+            rename_conflict_session_internal("session-label", Some("   ".to_string()), &state)
+                .await
+                .expect("clearing should succeed");
+        assert_eq!(cleared.label, None);
+
+        assert!(rename_conflict_session_internal("missing", None, &state)
+            .await
+            .is_err());
+        assert!(rename_conflict_session_internal(
+            "session-label",
+            Some("x".repeat(MAX_CONFLICT_SESSION_LABEL_CHARS + 1)),
+            &state,
+        )
+        .await
+        .is_err());
+    }
+
     #[tokio::test]
     async fn test_close_conflict_review_session_internal_skips_pending_items_and_logs_each_one() {
         let state = build_app_state();
@@ -2079,839 +2672,3499 @@ mod integration_tests {
     }
 
     #[test]
-    fn test_has_autostart_arg_detects_flag() {
-        assert!(has_autostart_arg(["syncwatcher", "--autostart"]));
-        assert!(!has_autostart_arg(["syncwatcher", "--verbose"]));
+    fn test_active_sync_rates_are_computed_from_progress_samples() {
+        let state = build_app_state();
+        let rates = &state.sync_transfer_rates;
+        let start = Instant::now();
+
+        record_sync_rate_sample(rates, "task-b", 0, 1_000, start);
+        record_sync_rate_sample(rates, "task-a", 0, 10_000_000, start);
+        record_sync_rate_sample(
+            rates,
+            "task-a",
+            1_000_000,
+            10_000_000,
+            start + Duration::from_millis(500),
+        );
+        let snapshot = active_sync_rates_snapshot(rates);
+        assert_eq!(snapshot.len(), 2);
+        assert_eq!(snapshot[0].task_id, "task-a");
+        assert_eq!(snapshot[0].bytes_per_sec, 0);
+        assert_eq!(snapshot[0].processed_bytes, 1_000_000);
+
+        record_sync_rate_sample(
+            rates,
+            "task-a",
+            4_000_000,
+            10_000_000,
+            start + Duration::from_secs(2),
+        );
+        let snapshot = active_sync_rates_snapshot(rates);
+        assert_eq!(
+            snapshot[0],
+            ActiveSyncRate {
+                task_id: "task-a".to_string(),
+                bytes_per_sec: 2_000_000,
+                processed_bytes: 4_000_000,
+                total_bytes: 10_000_000,
+            }
+        );
+
+        clear_sync_rate(rates, "task-a");
+        let snapshot = active_sync_rates_snapshot(rates);
+        assert_eq!(snapshot.len(), 1);
+        assert_eq!(snapshot[0].task_id, "task-b");
     }
 
-    #[test]
-    fn test_owner_license_debug_token_from_args_extracts_secret() {
-        let token = owner_license_debug_token_from_args([
-            "syncwatcher",
-            "--owner-license-debug=my-secret-token",
-        ]);
+    #[tokio::test]
+    async fn test_benchmark_target_write_cleans_up_and_reports_rate() {
+        let target_dir = tempdir().expect("target dir");
+        let volumes = vec![build_volume("Backup", target_dir.path().to_str().unwrap())];
 
-        assert_eq!(token.as_deref(), Some("my-secret-token"));
-    }
+        let result = benchmark_target_write_internal(target_dir.path(), 2 * 1024 * 1024, &volumes)
+            .await
+            .expect("benchmark should succeed");
 
-    #[test]
-    fn test_owner_license_debug_token_from_args_rejects_empty_secret() {
-        let token = owner_license_debug_token_from_args(["syncwatcher", "--owner-license-debug="]);
+        assert_eq!(result.bytes_written, 2 * 1024 * 1024);
+        assert!(result.megabytes_per_sec > 0.0);
+        assert_eq!(result.volume.volume_name.as_deref(), Some("Backup"));
+        let leftovers = std::fs::read_dir(target_dir.path())
+            .expect("read target")
+            .count();
+        assert_eq!(leftovers, 0);
 
-        assert_eq!(token, None);
+        let clamped = benchmark_target_write_internal(target_dir.path(), 1, &[])
+            .await
+            .expect("tiny benchmark should be clamped up");
+        assert_eq!(clamped.bytes_written, 1024 * 1024);
     }
 
-    #[test]
-    fn test_mcp_auth_token_from_args_extracts_secret() {
-        let token =
-            mcp_auth_token_from_args(["syncwatcher", "--mcp-stdio", "--mcp-token", "secret-token"])
-                .expect("mcp token should parse");
+    #[tokio::test]
+    async fn test_rollback_sync_run_removes_new_files_and_keeps_overwritten() {
+        let state = build_app_state();
+        let target_dir = tempdir().expect("target dir");
+        let new_file = target_dir.path().join("fresh/new.txt");
+        let overwritten = target_dir.path().join("existing.txt");
+        std::fs::create_dir_all(new_file.parent().unwrap()).expect("create dir");
+        std::fs::write(&new_file, b"copied this run").expect("write new");
+        std::fs::write(&overwritten, b"copied over old content").expect("write existing");
+
+        let runs = &state.sync_run_copied_files;
+        runs.lock()
+            .unwrap()
+            .insert("task-rollback".to_string(), Default::default());
+        record_sync_run_copied_file(runs, "task-rollback", &FileDiffKind::New, new_file.clone());
+        record_sync_run_copied_file(
+            runs,
+            "task-rollback",
+            &FileDiffKind::Modified,
+            overwritten.clone(),
+        );
 
-        assert_eq!(token, "secret-token");
+        let result = rollback_sync_run_internal("task-rollback", &state)
+            .await
+            .expect("rollback should succeed");
+
+        assert_eq!(
+            result.removed_files,
+            vec![new_file.to_string_lossy().to_string()]
+        );
+        assert_eq!(
+            result.kept_overwritten_files,
+            vec![overwritten.to_string_lossy().to_string()]
+        );
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.failures.is_empty());
+        assert!(!new_file.exists());
+        assert_eq!(
+            std::fs::read(&overwritten).expect("read existing"),
+            b"copied over old content"
+        );
+
+        let again = rollback_sync_run_internal("task-rollback", &state)
+            .await
+            .expect("second rollback should succeed");
+        assert!(again.removed_files.is_empty());
     }
 
-    #[test]
-    fn test_mcp_auth_token_from_args_requires_non_empty_secret() {
-        let error = mcp_auth_token_from_args(["syncwatcher", "--mcp-stdio", "--mcp-token="])
-            .expect_err("empty token should be rejected");
+    async fn run_tracked_engine_sync(
+        state: Arc<AppState>,
+        task_id: &str,
+        source: PathBuf,
+        target: PathBuf,
+        cancel_token: CancellationToken,
+        wait_for_cancel: bool,
+    ) {
+        state
+            .cancel_tokens
+            .write()
+            .await
+            .insert(task_id.to_string(), cancel_token.clone());
+        state
+            .sync_run_copied_files
+            .lock()
+            .unwrap()
+            .insert(task_id.to_string(), Default::default());
 
-        assert!(error.contains("--mcp-token"));
+        let engine = crate::sync_engine::SyncEngine::new(source, target.clone());
+        let options = crate::sync_engine::types::SyncOptions::default();
+        engine
+            .sync_files(
+                &options,
+                |_| {},
+                |entry: SyncFileEntry| {
+                    if entry.status == SyncFileStatus::Copied {
+                        record_sync_run_copied_file(
+                            &state.sync_run_copied_files,
+                            task_id,
+                            &entry.kind,
+                            target.join(&entry.path),
+                        );
+                    }
+                },
+            )
+            .await
+            .expect("engine sync should succeed");
+        if wait_for_cancel {
+            cancel_token.cancelled().await;
+        }
+        finish_sync_run_tracking(task_id, &cancel_token, &state).await;
     }
 
     #[tokio::test]
-    async fn test_validate_control_plane_auth_rejects_missing_auth_token() {
-        let state = build_app_state();
-        state
-            .config_store
-            .load_settings()
-            .expect("settings should initialize token");
-        let error = validate_control_plane_auth(
-            &ControlPlaneRequest {
-                request_id: "req-missing-token".to_string(),
-                method: "syncwatcher_get_settings".to_string(),
-                auth_token: None,
-                params: serde_json::json!({}),
-            },
-            &state,
-        )
-        .expect_err("missing token should be rejected");
+    async fn test_cancel_and_rollback_cancels_running_sync_and_removes_new_files() {
+        let state = Arc::new(build_app_state());
+        let source_dir = tempdir().expect("source dir");
+        let target_dir = tempdir().expect("target dir");
+        std::fs::write(source_dir.path().join("a.txt"), b"alpha").expect("write a");
+        std::fs::write(source_dir.path().join("b.txt"), b"beta").expect("write b");
+
+        let run = tokio::spawn(run_tracked_engine_sync(
+            state.clone(),
+            "task-cancel-rollback",
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+            CancellationToken::new(),
+            true,
+        ));
+        // 실행이 파일 복사를 끝내고 취소를 기다릴 때까지 대기
+        let deadline = Instant::now() + Duration::from_secs(10);
+        loop {
+            let copied = state
+                .sync_run_copied_files
+                .lock()
+                .unwrap()
+                .get("task-cancel-rollback")
+                .map_or(0, |run| run.new_files.len());
+            if copied == 2 {
+                break;
+            }
+            assert!(Instant::now() < deadline, "sync did not copy files in time");
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert!(target_dir.path().join("a.txt").exists());
 
-        assert!(error.contains("missing"));
+        let result = cancel_and_rollback_internal("task-cancel-rollback", &state)
+            .await
+            .expect("cancel and rollback should succeed");
+        run.await.expect("sync task should finish");
+
+        assert!(result.cancelled);
+        assert_eq!(result.removed_files.len(), 2);
+        assert!(!target_dir.path().join("a.txt").exists());
+        assert!(!target_dir.path().join("b.txt").exists());
+        assert!(state.cancel_tokens.read().await.is_empty());
     }
 
     #[tokio::test]
-    async fn test_validate_control_plane_auth_rejects_stale_auth_token_after_rotation() {
-        let state = build_app_state();
-        let initial_token = state
-            .config_store
-            .load_settings()
-            .expect("settings should initialize token")
-            .mcp_auth_token
-            .expect("initial token should exist");
-        state
-            .config_store
-            .regenerate_mcp_auth_token()
-            .expect("token should rotate");
-        let error = validate_control_plane_auth(
-            &ControlPlaneRequest {
-                request_id: "req-stale-token".to_string(),
-                method: "syncwatcher_get_settings".to_string(),
-                auth_token: Some(initial_token),
-                params: serde_json::json!({}),
-            },
-            &state,
+    async fn test_cancel_and_rollback_without_running_sync_removes_nothing() {
+        let state = Arc::new(build_app_state());
+        let source_dir = tempdir().expect("source dir");
+        let target_dir = tempdir().expect("target dir");
+        std::fs::write(source_dir.path().join("kept.txt"), b"kept").expect("write source");
+
+        // 취소 없이 끝난 실행의 기록은 비워져야 함
+        run_tracked_engine_sync(
+            state.clone(),
+            "task-idle-rollback",
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+            CancellationToken::new(),
+            false,
         )
-        .expect_err("stale token should be rejected");
+        .await;
+        assert!(state
+            .sync_run_copied_files
+            .lock()
+            .unwrap()
+            .get("task-idle-rollback")
+            .is_none());
 
-        assert!(error.contains("mismatch"));
+        let error = cancel_and_rollback_internal("task-idle-rollback", &state)
+            .await
+            .expect_err("idle task should not be rolled back");
+        assert!(error.contains("No running sync"));
+        assert!(target_dir.path().join("kept.txt").exists());
+
+        let result = rollback_sync_run_internal("task-idle-rollback", &state)
+            .await
+            .expect("rollback should succeed");
+        assert!(result.removed_files.is_empty());
+        assert!(target_dir.path().join("kept.txt").exists());
     }
 
     #[test]
-    fn test_decide_autostart_launch_without_arg_keeps_normal_launch() {
-        let decision = decide_autostart_launch(false, Ok(true));
+    fn test_in_progress_file_tracker_records_copy_start_and_clears_on_any_finish() {
+        let files = Arc::new(StdMutex::new(HashMap::new()));
+        let options = crate::sync_engine::types::SyncOptions {
+            compress: true,
+            compress_extensions: vec!["log".to_string()],
+            ..crate::sync_engine::types::SyncOptions::default()
+        };
+        let tracker = InProgressFileTracker::new(
+            files.clone(),
+            "task-tracker".to_string(),
+            PathBuf::from("/src"),
+            PathBuf::from("/dst"),
+            options,
+        );
+        let tick = |current_file: &str, bytes: u64| crate::sync_engine::types::SyncProgress {
+            phase: SyncProgressPhase::Copying,
+            current_file: Some(current_file.to_string()),
+            total_files: 2,
+            processed_files: 0,
+            total_bytes: 100,
+            processed_bytes: bytes,
+            bytes_copied_current_file: bytes,
+            current_file_total_bytes: 100,
+        };
 
-        assert!(!decision.argv_present);
-        assert_eq!(decision.autolaunch_enabled, None);
-        assert!(!decision.hidden_start_accepted);
-        assert_eq!(decision.reject_reason, None);
-        assert_eq!(decision.status_error, None);
+        tracker.on_progress(&tick("clip.mov", 0));
+        assert_eq!(
+            files.lock().unwrap().get("task-tracker"),
+            Some(&InProgressSyncFile {
+                source_path: PathBuf::from("/src/clip.mov"),
+                target_path: PathBuf::from("/dst/clip.mov"),
+                compressed: false,
+            })
+        );
+
+        // 실패로 끝나도 기록이 지워지고, 같은 이름의 마지막 틱이 다시 기록하지 않아야 함
+        tracker.on_progress(&tick("clip.mov", 50));
+        tracker.on_file_finished();
+        tracker.on_progress(&tick("clip.mov", 50));
+        assert!(files.lock().unwrap().get("task-tracker").is_none());
+
+        tracker.on_progress(&tick("app.log", 0));
+        assert_eq!(
+            files.lock().unwrap().get("task-tracker"),
+            Some(&InProgressSyncFile {
+                source_path: PathBuf::from("/src/app.log"),
+                target_path: PathBuf::from("/dst/app.log.gz"),
+                compressed: true,
+            })
+        );
+
+        // target_layout으로 옮겨진 파일은 current_file이 대상 경로라 계획에서 소스 경로를 찾아야 함
+        tracker.set_plan(&[FileDiff {
+            path: PathBuf::from("2024/06/clip.mov"),
+            kind: FileDiffKind::New,
+            source_size: Some(100),
+            target_size: None,
+            checksum_source: None,
+            checksum_target: None,
+            renamed_from: None,
+            source_path: Some(PathBuf::from("DCIM/clip.mov")),
+        }]);
+        tracker.on_progress(&tick("2024/06/clip.mov", 0));
+        assert_eq!(
+            files.lock().unwrap().get("task-tracker"),
+            Some(&InProgressSyncFile {
+                source_path: PathBuf::from("/src/DCIM/clip.mov"),
+                target_path: PathBuf::from("/dst/2024/06/clip.mov"),
+                compressed: false,
+            })
+        );
     }
 
-    #[test]
-    fn test_decide_autostart_launch_accepts_only_when_enabled() {
-        let decision = decide_autostart_launch(true, Ok(true));
+    #[tokio::test]
+    async fn test_cleanup_incomplete_sync_removes_truncated_target_file() {
+        let state = build_app_state();
+        let source_dir = tempdir().expect("source dir");
+        let target_dir = tempdir().expect("target dir");
+        let source_path = source_dir.path().join("clip.mov");
+        let target_path = target_dir.path().join("clip.mov");
+        std::fs::write(&source_path, vec![7u8; 4096]).expect("write source");
+        std::fs::write(&target_path, vec![7u8; 1024]).expect("write partial target");
+        state.sync_in_progress_files.lock().unwrap().insert(
+            "task-cleanup".to_string(),
+            InProgressSyncFile {
+                source_path: source_path.clone(),
+                target_path: target_path.clone(),
+                compressed: false,
+            },
+        );
 
-        assert!(decision.argv_present);
-        assert_eq!(decision.autolaunch_enabled, Some(true));
-        assert!(decision.hidden_start_accepted);
-        assert_eq!(decision.reject_reason, None);
-        assert_eq!(decision.status_error, None);
+        let cleaned = cleanup_incomplete_sync_internal("task-cleanup", &state)
+            .await
+            .expect("cleanup should succeed");
+
+        assert_eq!(
+            cleaned.as_deref(),
+            Some(target_path.to_string_lossy().as_ref())
+        );
+        assert!(!target_path.exists());
+        assert!(source_path.exists());
+        assert!(state
+            .sync_in_progress_files
+            .lock()
+            .unwrap()
+            .get("task-cleanup")
+            .is_none());
     }
 
-    #[test]
-    fn test_decide_autostart_launch_rejects_disabled_login_item() {
-        let decision = decide_autostart_launch(true, Ok(false));
+    #[tokio::test]
+    async fn test_cleanup_incomplete_sync_keeps_complete_target_file() {
+        let state = build_app_state();
+        let source_dir = tempdir().expect("source dir");
+        let target_dir = tempdir().expect("target dir");
+        let source_path = source_dir.path().join("clip.mov");
+        let target_path = target_dir.path().join("clip.mov");
+        std::fs::write(&source_path, vec![7u8; 4096]).expect("write source");
+        std::fs::write(&target_path, vec![7u8; 4096]).expect("write target");
+        state.sync_in_progress_files.lock().unwrap().insert(
+            "task-cleanup".to_string(),
+            InProgressSyncFile {
+                source_path,
+                target_path: target_path.clone(),
+                compressed: false,
+            },
+        );
 
-        assert!(decision.argv_present);
-        assert_eq!(decision.autolaunch_enabled, Some(false));
-        assert!(!decision.hidden_start_accepted);
-        assert_eq!(decision.reject_reason, Some("launch_at_login_disabled"));
-        assert_eq!(decision.status_error, None);
+        let cleaned = cleanup_incomplete_sync_internal("task-cleanup", &state)
+            .await
+            .expect("cleanup should succeed");
+
+        assert!(cleaned.is_none());
+        assert!(target_path.exists());
+        assert!(cleanup_incomplete_sync_internal("task-cleanup", &state)
+            .await
+            .expect("second cleanup should succeed")
+            .is_none());
     }
 
-    #[test]
-    fn test_decide_autostart_launch_rejects_status_errors() {
-        let decision = decide_autostart_launch(true, Err("failed".to_string()));
+    fn build_exclusion_set(id: &str, patterns: &[&str]) -> ExclusionSetRecord {
+        ExclusionSetRecord {
+            id: id.to_string(),
+            name: format!("set-{id}"),
+            patterns: patterns.iter().map(|pattern| pattern.to_string()).collect(),
+        }
+    }
 
-        assert!(decision.argv_present);
-        assert_eq!(decision.autolaunch_enabled, None);
-        assert!(!decision.hidden_start_accepted);
+    #[test]
+    fn test_check_persisted_state_integrity_reports_malformed_license_json() {
+        let config_dir = tempdir().expect("config dir");
+        let store = ConfigStore::from_config_dir(config_dir.path().to_path_buf());
+        store.save_tasks(&[]).expect("tasks should save");
+        let license_path = config_dir.path().join("license_state.json");
+        std::fs::write(&license_path, "{\"licenseKey\": \"abc\", \"instanceId\"")
+            .expect("partial license write");
+
+        let report = check_persisted_state_integrity_internal(&store, &license_path, false);
+
+        let license = report
+            .files
+            .iter()
+            .find(|file| file.name == "license_state.json")
+            .expect("license file should be reported");
+        assert_eq!(license.status, PersistedFileStatus::Corrupt);
+        assert!(license.error.is_some());
+        assert!(!license.restored_from_backup);
+        assert_eq!(license.corrupt_backup_path, None);
         assert_eq!(
-            decision.reject_reason,
-            Some("launch_at_login_status_unavailable")
+            std::fs::read_to_string(&license_path).expect("corrupt file should stay in place"),
+            "{\"licenseKey\": \"abc\", \"instanceId\""
         );
-        assert_eq!(decision.status_error.as_deref(), Some("failed"));
+        let tasks = report
+            .files
+            .iter()
+            .find(|file| file.name == "tasks.yaml")
+            .expect("tasks should be reported");
+        assert_eq!(tasks.status, PersistedFileStatus::Valid);
     }
 
-    #[test]
-    fn test_launch_at_login_status_or_default_falls_back_to_false() {
-        assert!(launch_at_login_status_or_default(Ok(true)));
-        assert!(!launch_at_login_status_or_default(
-            Err("failed".to_string())
-        ));
+    #[tokio::test]
+    async fn test_tasks_using_exclusion_set_lists_referencing_tasks() {
+        let state = build_app_state();
+        let mut studio_task = build_runtime_task("studio-task", "/tmp/a", false);
+        studio_task.exclusion_sets = vec!["studio".to_string(), "audio".to_string()];
+        let mut audio_task = build_runtime_task("audio-task", "/tmp/b", false);
+        audio_task.exclusion_sets = vec!["audio".to_string()];
+        state.runtime_config.write().await.tasks = vec![
+            studio_task,
+            audio_task,
+            build_runtime_task("plain-task", "/tmp/c", false),
+        ];
+
+        let audio_refs = tasks_using_exclusion_set_internal("audio", &state).await;
+        assert_eq!(
+            audio_refs,
+            vec![
+                ExclusionSetTaskReference {
+                    task_id: "studio-task".to_string(),
+                    task_name: "task-studio-task".to_string(),
+                },
+                ExclusionSetTaskReference {
+                    task_id: "audio-task".to_string(),
+                    task_name: "task-audio-task".to_string(),
+                },
+            ]
+        );
+        assert_eq!(
+            tasks_using_exclusion_set_internal("studio", &state)
+                .await
+                .len(),
+            1
+        );
+        assert!(tasks_using_exclusion_set_internal("unused", &state)
+            .await
+            .is_empty());
     }
 
-    #[test]
-    fn test_runtime_desired_watch_sources_uses_watch_mode_only() {
-        let tasks = vec![
-            build_runtime_task("a", "/src/a", true),
-            build_runtime_task("b", "/src/b", false),
-            build_runtime_task("c", "/src/c", true),
-        ];
+    #[tokio::test]
+    async fn test_delete_exclusion_set_guarded_refuses_in_use_sets_unless_forced() {
+        let state = build_app_state();
+        state
+            .config_store
+            .save_exclusion_sets(&[
+                build_exclusion_set("studio", &["*.tmp"]),
+                build_exclusion_set("unused", &["*.bak"]),
+            ])
+            .expect("sets should save");
+        let mut task = build_runtime_task("studio-task", "/tmp/a", false);
+        task.exclusion_sets = vec!["studio".to_string()];
+        state.runtime_config.write().await.tasks = vec![task];
 
-        let desired = runtime_desired_watch_sources(&tasks);
-        assert_eq!(desired.len(), 2);
-        assert_eq!(desired.get("a"), Some(&"/src/a".to_string()));
-        assert_eq!(desired.get("c"), Some(&"/src/c".to_string()));
-        assert!(!desired.contains_key("b"));
+        let refused = delete_exclusion_set_guarded_internal("studio", false, &state)
+            .await
+            .expect("guarded delete should report references");
+        assert!(!refused.deleted);
+        assert_eq!(refused.referenced_by.len(), 1);
+        assert_eq!(refused.referenced_by[0].task_id, "studio-task");
+        let remaining_ids = |state: &AppState| -> Vec<String> {
+            state
+                .config_store
+                .load_exclusion_sets()
+                .expect("sets should load")
+                .into_iter()
+                .map(|set| set.id)
+                .collect()
+        };
+        assert!(remaining_ids(&state).contains(&"studio".to_string()));
+
+        let unused = delete_exclusion_set_guarded_internal("unused", false, &state)
+            .await
+            .expect("unused set should delete");
+        assert!(unused.deleted);
+        assert!(unused.referenced_by.is_empty());
+
+        let forced = delete_exclusion_set_guarded_internal("studio", true, &state)
+            .await
+            .expect("forced delete should succeed");
+        assert!(forced.deleted);
+        assert_eq!(forced.referenced_by.len(), 1);
+        assert!(!remaining_ids(&state).contains(&"studio".to_string()));
     }
 
-    #[test]
-    fn test_runtime_watch_bootstrap_task_ids_use_watch_mode_only() {
-        let tasks = vec![
-            build_runtime_task("a", "/src/a", true),
-            build_runtime_task("b", "/src/b", false),
-            build_runtime_task(
-                "uuid-watch",
-                "[VOLUME_UUID:07497716-6027-3FE9-B418-6BEA262C7D2F]/",
-                true,
-            ),
+    #[tokio::test]
+    async fn test_exclusion_sets_export_import_round_trip() {
+        let source_state = build_app_state();
+        let sets = vec![
+            build_exclusion_set("studio", &["*.tmp", "render-cache/"]),
+            build_exclusion_set("audio", &["*.pkf"]),
         ];
+        source_state
+            .config_store
+            .save_exclusion_sets(&sets)
+            .expect("sets should save");
+        let export_dir = tempdir().expect("export dir");
+        let export_path = export_dir.path().join("exclusion-sets.json");
+
+        let source_ids: Vec<String> = source_state
+            .config_store
+            .load_exclusion_sets()
+            .expect("sets should load")
+            .into_iter()
+            .map(|set| set.id)
+            .collect();
+
+        let exported = export_exclusion_sets_internal(&export_path, &source_state)
+            .await
+            .expect("export should succeed");
+        assert_eq!(exported, source_ids.len());
+        assert!(source_ids.iter().any(|id| id == "studio"));
+        assert!(source_ids.iter().any(|id| id == "audio"));
+
+        let target_state = build_app_state();
+        let imported = import_exclusion_sets_internal(&export_path, false, &target_state)
+            .await
+            .expect("import should succeed");
+        let reloaded = target_state
+            .config_store
+            .load_exclusion_sets()
+            .expect("sets should load");
 
         assert_eq!(
-            runtime_watch_bootstrap_task_ids(&tasks),
-            vec!["a".to_string(), "uuid-watch".to_string(),]
+            imported
+                .iter()
+                .map(|set| set.id.clone())
+                .collect::<Vec<_>>(),
+            source_ids
+        );
+        assert_eq!(
+            reloaded
+                .iter()
+                .find(|set| set.id == "studio")
+                .map(|set| set.patterns.clone()),
+            Some(vec!["*.tmp".to_string(), "render-cache/".to_string()])
         );
     }
 
-    #[test]
-    fn test_runtime_watch_task_needs_restart_when_new_stopped_or_source_changed() {
-        let managed_sources =
-            HashMap::from([("task-1".to_string(), "/Volumes/old/source".to_string())]);
-        let watching_now = HashSet::from(["task-1".to_string()]);
-        let watching_source_paths = HashMap::new();
+    #[tokio::test]
+    async fn test_import_exclusion_sets_merges_by_id() {
+        let state = build_app_state();
+        state
+            .config_store
+            .save_exclusion_sets(&[
+                build_exclusion_set("studio", &["*.tmp"]),
+                build_exclusion_set("local", &["*.bak"]),
+            ])
+            .expect("sets should save");
+        let import_dir = tempdir().expect("import dir");
+        let import_path = import_dir.path().join("shared.json");
+        std::fs::write(
+            &import_path,
+            serde_json::to_string(&vec![
+                build_exclusion_set("studio", &["*.tmp", "*.swp"]),
+                build_exclusion_set("shared", &["proxies/"]),
+            ])
+            .unwrap(),
+        )
+        .expect("write import file");
 
-        assert!(!runtime_watch_task_needs_restart(
-            "task-1",
-            "/Volumes/old/source",
-            &managed_sources,
-            &watching_now,
-            &watching_source_paths,
-        ));
-        assert!(runtime_watch_task_needs_restart(
-            "task-2",
-            "/Volumes/new/source",
-            &managed_sources,
-            &watching_now,
-            &watching_source_paths,
-        ));
-        assert!(runtime_watch_task_needs_restart(
-            "task-1",
-            "/Volumes/new/source",
-            &managed_sources,
-            &watching_now,
-            &watching_source_paths,
-        ));
-        assert!(runtime_watch_task_needs_restart(
-            "task-1",
-            "/Volumes/old/source",
-            &managed_sources,
-            &HashSet::new(),
-            &watching_source_paths,
-        ));
+        let merged = import_exclusion_sets_internal(&import_path, true, &state)
+            .await
+            .expect("merge import should succeed");
+
+        let by_id: HashMap<&str, &ExclusionSetRecord> =
+            merged.iter().map(|set| (set.id.as_str(), set)).collect();
+        assert_eq!(by_id["studio"].patterns, vec!["*.tmp", "*.swp"]);
+        assert_eq!(by_id["local"].patterns, vec!["*.bak"]);
+        assert_eq!(by_id["shared"].patterns, vec!["proxies/"]);
+        assert_eq!(merged.iter().filter(|set| set.id == "studio").count(), 1);
     }
 
-    #[test]
-    fn test_runtime_watch_restart_plan_restarts_uuid_task_when_registered_watcher_root_is_stale() {
-        let stale_mount = tempdir().unwrap();
-        let stale_watch_root = stale_mount.path().join("DCIM");
-        std::fs::create_dir_all(&stale_watch_root).unwrap();
-        let stale_watch_root = stale_watch_root.to_string_lossy().to_string();
+    #[tokio::test]
+    async fn test_import_exclusion_sets_replace_rejects_dropping_referenced_sets() {
+        let state = build_app_state();
+        state
+            .config_store
+            .save_exclusion_sets(&[
+                build_exclusion_set("studio", &["*.tmp"]),
+                build_exclusion_set("unused", &["*.bak"]),
+            ])
+            .expect("sets should save");
+        let mut task = build_runtime_task("studio-task", "/tmp/a", false);
+        task.exclusion_sets = vec!["studio".to_string()];
+        state.runtime_config.write().await.tasks = vec![task];
+        let import_dir = tempdir().expect("import dir");
+        let import_path = import_dir.path().join("replacement.json");
+        std::fs::write(
+            &import_path,
+            serde_json::to_string(&vec![build_exclusion_set("shared", &["proxies/"])]).unwrap(),
+        )
+        .expect("write import file");
 
-        let desired_sources = HashMap::from([(
-            "uuid-watch".to_string(),
-            "[DISK_UUID:disk-a]/DCIM".to_string(),
-        )]);
-        let managed_sources = desired_sources.clone();
-        let watching_now = HashSet::from(["uuid-watch".to_string()]);
-        let watching_source_paths =
-            HashMap::from([("uuid-watch".to_string(), stale_watch_root.clone())]);
+        let error = import_exclusion_sets_internal(&import_path, false, &state)
+            .await
+            .expect_err("replacing a referenced set should be rejected");
+        assert!(error.contains("studio (task-studio-task)"));
+        assert!(!error.contains("unused"));
+        let unchanged = state
+            .config_store
+            .load_exclusion_sets()
+            .expect("sets should load");
+        assert!(unchanged.iter().any(|set| set.id == "studio"));
 
-        assert!(runtime_watch_restart_task_ids(
-            &desired_sources,
-            &managed_sources,
-            &watching_now,
-            &watching_source_paths,
+        // 병합은 기존 세트를 지우지 않으므로 허용
+        let merged = import_exclusion_sets_internal(&import_path, true, &state)
+            .await
+            .expect("merge import should succeed");
+        assert_eq!(merged.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_import_exclusion_sets_rejects_invalid_patterns() {
+        let state = build_app_state();
+        let import_dir = tempdir().expect("import dir");
+        let import_path = import_dir.path().join("bad.json");
+        std::fs::write(
+            &import_path,
+            serde_json::to_string(&vec![build_exclusion_set("bad", &["[unclosed"])]).unwrap(),
         )
-        .is_empty());
+        .expect("write import file");
 
-        drop(stale_mount);
+        let error = import_exclusion_sets_internal(&import_path, false, &state)
+            .await
+            .expect_err("invalid pattern should be rejected");
 
-        assert_eq!(
-            runtime_watch_restart_task_ids(
-                &desired_sources,
-                &managed_sources,
-                &watching_now,
-                &watching_source_paths,
-            ),
-            vec!["uuid-watch".to_string()]
-        );
+        assert!(error.contains("Invalid exclusion set"));
     }
 
-    #[test]
-    fn test_runtime_watch_restart_plan_does_not_restart_when_uuid_task_is_already_tracking_current_root(
-    ) {
-        let current_mount = tempdir().unwrap();
-        let current_watch_root = current_mount.path().join("DCIM");
-        std::fs::create_dir_all(&current_watch_root).unwrap();
-        let current_watch_root = current_watch_root.to_string_lossy().to_string();
+    #[tokio::test]
+    async fn test_export_import_config_round_trips_stored_config() {
+        let state = build_app_state();
+        let base = tempdir().expect("tempdir should be created");
+        let mut card = build_path_task(
+            "card",
+            &base.path().join("card").to_string_lossy(),
+            &base.path().join("card-backup").to_string_lossy(),
+            true,
+        );
+        card.exclusion_sets = vec!["studio".to_string()];
+        let docs = build_path_task(
+            "docs",
+            &base.path().join("docs").to_string_lossy(),
+            &base.path().join("docs-backup").to_string_lossy(),
+            false,
+        );
+        let mut settings = state.config_store.load_settings().unwrap();
+        settings.max_watchers = 4;
+        settings.global_max_bytes_per_sec = 1024;
+        state.config_store.save_settings(&settings).unwrap();
+        state.config_store.save_tasks(&[card, docs]).unwrap();
+        state
+            .config_store
+            .save_exclusion_sets(&[build_exclusion_set("studio", &["*.tmp"])])
+            .unwrap();
 
-        let desired_sources = HashMap::from([(
-            "uuid-watch".to_string(),
-            "[DISK_UUID:disk-a]/DCIM".to_string(),
-        )]);
-        let managed_sources = desired_sources.clone();
-        let watching_now = HashSet::from(["uuid-watch".to_string()]);
-        let watching_source_paths = HashMap::from([("uuid-watch".to_string(), current_watch_root)]);
+        let export_path = base.path().join("config.yaml");
+        let exported = export_config_internal(&export_path, &state)
+            .await
+            .expect("export should succeed");
+        assert_eq!(exported, 2);
+        let yaml = std::fs::read_to_string(&export_path).expect("export file should exist");
+        let file: ConfigExportFile = serde_yaml::from_str(&yaml).expect("export should parse");
+        assert_eq!(file.settings.mcp_auth_token, None);
+
+        state.config_store.save_tasks(&[]).unwrap();
+        state.config_store.save_exclusion_sets(&[]).unwrap();
+        state
+            .config_store
+            .save_settings(&default_settings_record())
+            .unwrap();
+        let local_token_after_reset = state.config_store.load_settings().unwrap().mcp_auth_token;
+        import_config_internal(&export_path, false, &state)
+            .await
+            .expect("import should succeed");
 
-        assert!(runtime_watch_restart_task_ids(
-            &desired_sources,
-            &managed_sources,
-            &watching_now,
-            &watching_source_paths,
-        )
-        .is_empty());
+        // The import lands in the config store, so a canonical reload sees it.
+        let runtime = load_canonical_runtime_payload(&state)
+            .await
+            .expect("imported config should reload");
+        let task_ids: Vec<&str> = runtime.tasks.iter().map(|task| task.id.as_str()).collect();
+        assert_eq!(task_ids, vec!["card", "docs"]);
+        assert_eq!(runtime.tasks[0].exclusion_sets, vec!["studio".to_string()]);
+        assert_eq!(runtime.exclusion_sets.len(), 1);
+        assert_eq!(
+            runtime.exclusion_sets[0].patterns,
+            vec!["*.tmp".to_string()]
+        );
+        assert_eq!(runtime.settings.max_watchers, Some(4));
+        assert_eq!(runtime.settings.global_max_bytes_per_sec, 1024);
+        let stored = state.config_store.load_settings().unwrap();
+        assert_eq!(stored.mcp_auth_token, local_token_after_reset);
+
+        // Merging keeps tasks that are not in the file and replaces matching ids.
+        let mut local = build_path_task(
+            "local",
+            &base.path().join("local").to_string_lossy(),
+            &base.path().join("local-backup").to_string_lossy(),
+            false,
+        );
+        local.name = "Local".to_string();
+        let mut stale_docs = build_path_task(
+            "docs",
+            &base.path().join("docs").to_string_lossy(),
+            &base.path().join("docs-backup").to_string_lossy(),
+            false,
+        );
+        stale_docs.name = "Old docs".to_string();
+        state.config_store.save_tasks(&[local, stale_docs]).unwrap();
+        let mut settings = state.config_store.load_settings().unwrap();
+        settings.max_watchers = 9;
+        state.config_store.save_settings(&settings).unwrap();
+        import_config_internal(&export_path, true, &state)
+            .await
+            .expect("merge import should succeed");
+        let runtime = load_canonical_runtime_payload(&state)
+            .await
+            .expect("merged config should reload");
+        let names: Vec<(&str, &str)> = runtime
+            .tasks
+            .iter()
+            .map(|task| (task.id.as_str(), task.name.as_str()))
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                ("local", "Local"),
+                ("docs", "task-docs"),
+                ("card", "task-card")
+            ]
+        );
+        assert_eq!(runtime.settings.max_watchers, Some(9));
     }
 
-    #[test]
-    fn test_should_reconcile_runtime_watchers_for_volume_change_requires_mount_diff() {
-        assert!(!should_reconcile_runtime_watchers_for_volume_change(
-            &Vec::new(),
-            &Vec::new()
-        ));
-        assert!(should_reconcile_runtime_watchers_for_volume_change(
-            &vec!["/Volumes/CARD".to_string()],
-            &Vec::new()
-        ));
-        assert!(should_reconcile_runtime_watchers_for_volume_change(
-            &Vec::new(),
-            &vec!["/Volumes/CARD".to_string()]
-        ));
+    #[tokio::test]
+    async fn test_export_config_preserves_uuid_token_sources() {
+        let state = build_app_state();
+        let base = tempdir().expect("tempdir should be created");
+        let mut card = build_uuid_task("card", SourceUuidType::Disk, "disk-a", "/DCIM", None);
+        card.target = base
+            .path()
+            .join("card-backup")
+            .to_string_lossy()
+            .to_string();
+        state.config_store.save_tasks(&[card]).unwrap();
+
+        let export_path = base.path().join("config.yaml");
+        export_config_internal(&export_path, &state)
+            .await
+            .expect("export should succeed");
+
+        let yaml = std::fs::read_to_string(&export_path).expect("export file should exist");
+        let parsed: ConfigExportFile = serde_yaml::from_str(&yaml).expect("export should parse");
+        assert_eq!(parsed.tasks[0].source, "[DISK_UUID:disk-a]/DCIM");
     }
 
-    #[test]
-    fn test_can_enqueue_runtime_watch_bootstrap_task_blocks_syncing_queued_and_pending() {
-        let syncing = HashSet::from(["syncing".to_string()]);
-        let queued = HashSet::from(["queued".to_string()]);
-        let pending = HashSet::from(["pending".to_string()]);
+    #[tokio::test]
+    async fn test_import_config_rejects_invalid_config_with_clear_error() {
+        let state = build_app_state();
+        let base = tempdir().expect("tempdir should be created");
+        let source = base.path().join("source");
+        let overlapping = ConfigExportFile {
+            settings: default_settings_record(),
+            tasks: vec![build_path_task(
+                "nested",
+                &source.to_string_lossy(),
+                &source.join("backup").to_string_lossy(),
+                false,
+            )],
+            exclusion_sets: Vec::new(),
+        };
+        let import_path = base.path().join("overlap.yaml");
+        std::fs::write(&import_path, serde_yaml::to_string(&overlapping).unwrap())
+            .expect("write import file");
 
-        assert!(!can_enqueue_runtime_watch_bootstrap_task(
-            "syncing", &syncing, &queued, &pending
-        ));
-        assert!(!can_enqueue_runtime_watch_bootstrap_task(
-            "queued", &syncing, &queued, &pending
-        ));
-        assert!(!can_enqueue_runtime_watch_bootstrap_task(
-            "pending", &syncing, &queued, &pending
-        ));
-        assert!(can_enqueue_runtime_watch_bootstrap_task(
-            "eligible", &syncing, &queued, &pending
-        ));
+        let error = import_config_internal(&import_path, false, &state)
+            .await
+            .expect_err("overlapping task should be rejected");
+        assert!(error.starts_with("Invalid config file:"), "{error}");
+        assert!(error.contains("overlapping source/target"), "{error}");
+
+        let mut dangling = overlapping.clone();
+        dangling.tasks = vec![build_path_task(
+            "card",
+            &source.to_string_lossy(),
+            &base.path().join("backup").to_string_lossy(),
+            false,
+        )];
+        dangling.tasks[0].exclusion_sets = vec!["gone".to_string()];
+        std::fs::write(&import_path, serde_yaml::to_string(&dangling).unwrap())
+            .expect("write import file");
+        let error = import_config_internal(&import_path, false, &state)
+            .await
+            .expect_err("missing exclusion set should be rejected");
+        assert!(error.contains("gone (task-card)"), "{error}");
+
+        std::fs::write(&import_path, "tasks: [not, a, task]\n").expect("write import file");
+        let error = import_config_internal(&import_path, false, &state)
+            .await
+            .expect_err("malformed YAML should be rejected");
+        assert!(error.starts_with("Invalid config file:"), "{error}");
+        assert!(state.config_store.load_tasks().unwrap().is_empty());
     }
 
     #[tokio::test]
-    async fn test_enqueue_runtime_watch_bootstrap_tasks_enqueues_only_eligible_tasks() {
+    async fn test_get_effective_task_config_merges_set_and_default_patterns() {
         let state = build_app_state();
+        let source_dir = tempdir().expect("source dir");
+        let target_dir = tempdir().expect("target dir");
+        let mut exclusion_sets = state
+            .config_store
+            .load_exclusion_sets()
+            .expect("default sets should load");
+        let default_patterns = exclusion_sets
+            .iter()
+            .find(|set| set.id == "system-defaults")
+            .expect("system defaults set should exist")
+            .patterns
+            .clone();
+        assert!(!default_patterns.is_empty());
+        exclusion_sets.push(ExclusionSetRecord {
+            id: "custom-set".to_string(),
+            name: "Custom".to_string(),
+            patterns: vec!["*.tmp".to_string(), "build/".to_string()],
+        });
+        state
+            .config_store
+            .save_exclusion_sets(&exclusion_sets)
+            .expect("sets should save");
+
+        let mut task = build_uuid_task("task-effective", SourceUuidType::Volume, "UUID", "/", None);
+        task.source = source_dir.path().to_string_lossy().to_string();
+        task.target = target_dir.path().to_string_lossy().to_string();
+        task.source_type = Some(SourceType::Path);
+        task.source_uuid = None;
+        task.source_uuid_type = None;
+        task.source_sub_path = None;
+        task.checksum_mode = true;
+        task.exclusion_sets = vec!["system-defaults".to_string(), "custom-set".to_string()];
+        state
+            .config_store
+            .save_tasks(&[task.clone()])
+            .expect("task should save");
+
+        let config = get_effective_task_config_internal("task-effective", &state)
+            .await
+            .expect("effective config should resolve");
+
+        for pattern in default_patterns
+            .iter()
+            .chain(["*.tmp".to_string(), "build/".to_string()].iter())
         {
-            let mut syncing = state.syncing_tasks.write().await;
-            syncing.insert("syncing".to_string());
-        }
-        {
-            let mut queued = state.queued_sync_tasks.write().await;
-            queued.insert("queued".to_string());
-        }
-        {
-            let mut queue = state.runtime_sync_queue.write().await;
-            queue.push_back("queued".to_string());
-        }
-        {
-            let mut pending = state.runtime_pending_sync_tasks.write().await;
-            pending.insert("pending".to_string());
+            assert!(
+                config.exclude_patterns.contains(pattern),
+                "missing pattern {pattern}"
+            );
         }
+        assert!(config.checksum_mode);
+        assert!(config.verify_after_copy);
+        assert!(config.preserve_permissions);
+        assert!(config.preserve_times);
+        assert_eq!(
+            config.resolved_source.as_deref(),
+            Some(task.source.as_str())
+        );
+        assert_eq!(
+            config.resolved_target.as_deref(),
+            Some(task.target.as_str())
+        );
+        assert!(config.path_error.is_none());
+    }
 
-        let task_ids = vec![
-            "eligible".to_string(),
-            "syncing".to_string(),
-            "queued".to_string(),
-            "pending".to_string(),
-        ];
+    #[tokio::test]
+    async fn test_get_effective_task_config_rejects_unknown_task() {
+        let state = build_app_state();
 
-        let enqueued = enqueue_runtime_watch_bootstrap_tasks(&task_ids, &state).await;
-        assert_eq!(enqueued, vec!["eligible".to_string()]);
+        let error = get_effective_task_config_internal("missing-task", &state)
+            .await
+            .expect_err("unknown task should fail");
+
+        assert!(error.contains("Sync task not found"));
+    }
+
+    #[test]
+    fn test_refresh_volumes_returns_current_list_and_emits_change() {
+        let app = build_managed_mock_app();
+        let rx = listen_for_named_event::<(), _>(app.handle(), "volumes-changed");
+
+        let volumes = refresh_volumes_internal(app.handle()).expect("refresh should succeed");
+        let expected = crate::system_integration::DiskMonitor::new()
+            .list_volumes()
+            .expect("list volumes should succeed");
 
-        let queue = state.runtime_sync_queue.read().await;
         assert_eq!(
-            queue.iter().cloned().collect::<Vec<_>>(),
-            vec!["queued".to_string(), "eligible".to_string()]
+            volumes
+                .iter()
+                .map(|volume| volume.mount_point.clone())
+                .collect::<Vec<_>>(),
+            expected
+                .iter()
+                .map(|volume| volume.mount_point.clone())
+                .collect::<Vec<_>>()
         );
-        drop(queue);
+        rx.recv_timeout(Duration::from_secs(1))
+            .expect("volumes-changed should be emitted");
+    }
 
-        let queued = state.queued_sync_tasks.read().await;
-        assert!(queued.contains("eligible"));
-        assert!(queued.contains("queued"));
-        assert!(!queued.contains("syncing"));
-        assert!(!queued.contains("pending"));
+    #[test]
+    fn test_send_test_notification_reports_unsupported_without_notification_plugin() {
+        let app = build_managed_mock_app();
+
+        let result = send_test_notification_internal(app.handle());
+
+        assert_eq!(result.status, TestNotificationStatus::Unsupported);
+        assert!(!result.enabled);
+        assert!(result.message.is_some());
     }
 
-    #[tokio::test]
-    async fn test_runtime_watch_bootstrap_tasks_skip_syncing_queued_pending_even_after_remount_restart(
-    ) {
-        let state = build_app_state();
-        let stale_mount = tempdir().unwrap();
-        let eligible_root = stale_mount.path().join("eligible");
-        let syncing_root = stale_mount.path().join("syncing");
-        let queued_root = stale_mount.path().join("queued");
-        let pending_root = stale_mount.path().join("pending");
-        std::fs::create_dir_all(&eligible_root).unwrap();
-        std::fs::create_dir_all(&syncing_root).unwrap();
-        std::fs::create_dir_all(&queued_root).unwrap();
-        std::fs::create_dir_all(&pending_root).unwrap();
+    #[test]
+    fn test_should_log_sync_milestone_thresholds() {
+        let short = Duration::from_secs(5);
+        let long = Duration::from_secs(120);
 
-        let desired_sources = HashMap::from([
-            (
-                "eligible".to_string(),
-                "[DISK_UUID:eligible-disk]/DCIM".to_string(),
-            ),
-            (
-                "syncing".to_string(),
-                "[DISK_UUID:syncing-disk]/DCIM".to_string(),
-            ),
-            (
-                "queued".to_string(),
-                "[DISK_UUID:queued-disk]/DCIM".to_string(),
-            ),
-            (
-                "pending".to_string(),
-                "[DISK_UUID:pending-disk]/DCIM".to_string(),
-            ),
-        ]);
-        let managed_sources = desired_sources.clone();
-        let watching_now = HashSet::from([
-            "eligible".to_string(),
-            "syncing".to_string(),
-            "queued".to_string(),
-            "pending".to_string(),
-        ]);
-        let watching_source_paths = HashMap::from([
-            (
-                "eligible".to_string(),
-                eligible_root.to_string_lossy().to_string(),
-            ),
-            (
-                "syncing".to_string(),
-                syncing_root.to_string_lossy().to_string(),
-            ),
-            (
-                "queued".to_string(),
-                queued_root.to_string_lossy().to_string(),
-            ),
-            (
-                "pending".to_string(),
-                pending_root.to_string_lossy().to_string(),
-            ),
-        ]);
+        assert!(!should_log_sync_milestone(999, 0, short));
+        assert!(should_log_sync_milestone(1_000, 0, short));
+        assert!(!should_log_sync_milestone(1_999, 1_000, short));
+        assert!(should_log_sync_milestone(2_000, 1_000, short));
+        assert!(should_log_sync_milestone(1_001, 1_000, long));
+        assert!(!should_log_sync_milestone(1_000, 1_000, long));
+        assert!(!should_log_sync_milestone(0, 0, long));
+    }
 
-        drop(stale_mount);
+    #[test]
+    fn test_should_log_file_entry_respects_verbose_flag() {
+        assert!(!should_log_file_entry(false, &LogCategory::FileCopied));
+        assert!(should_log_file_entry(true, &LogCategory::FileCopied));
+        assert!(should_log_file_entry(false, &LogCategory::FileDeleted));
+    }
 
+    #[tokio::test]
+    async fn test_set_verbose_logging_persists_and_survives_runtime_config_replace() {
+        let state = build_app_state();
+        assert!(
+            !state
+                .runtime_config
+                .read()
+                .await
+                .settings
+                .verbose_file_logging
+        );
+
+        assert!(set_verbose_logging_internal(true, &state).await.unwrap());
+        assert!(
+            state
+                .config_store
+                .load_settings()
+                .unwrap()
+                .verbose_file_logging
+        );
         {
-            let mut syncing = state.syncing_tasks.write().await;
-            syncing.insert("syncing".to_string());
-        }
-        {
-            let mut queued = state.queued_sync_tasks.write().await;
-            queued.insert("queued".to_string());
-        }
-        {
-            let mut queue = state.runtime_sync_queue.write().await;
-            queue.push_back("queued".to_string());
-        }
-        {
-            let mut pending = state.runtime_pending_sync_tasks.write().await;
-            pending.insert("pending".to_string());
+            let mut config = state.runtime_config.write().await;
+            replace_runtime_config(&mut config, Default::default());
         }
-
-        let restart_task_ids = runtime_watch_restart_task_ids(
-            &desired_sources,
-            &managed_sources,
-            &watching_now,
-            &watching_source_paths,
+        assert!(
+            state
+                .runtime_config
+                .read()
+                .await
+                .settings
+                .verbose_file_logging
         );
+        let payload = load_canonical_runtime_payload(&state).await.unwrap();
+        assert!(payload.settings.verbose_file_logging);
 
-        assert_eq!(
-            restart_task_ids,
-            vec![
-                "eligible".to_string(),
-                "pending".to_string(),
-                "queued".to_string(),
-                "syncing".to_string(),
-            ]
+        assert!(!set_verbose_logging_internal(false, &state).await.unwrap());
+        assert!(
+            !state
+                .config_store
+                .load_settings()
+                .unwrap()
+                .verbose_file_logging
+        );
+        assert!(
+            !state
+                .runtime_config
+                .read()
+                .await
+                .settings
+                .verbose_file_logging
         );
-
-        let enqueued = enqueue_runtime_watch_bootstrap_tasks(&restart_task_ids, &state).await;
-        assert_eq!(enqueued, vec!["eligible".to_string()]);
     }
 
     #[test]
-    fn test_resolve_runtime_exclude_patterns_deduplicates_preserving_order() {
-        let task = RuntimeSyncTask {
-            id: "task-1".to_string(),
-            name: "task-1".to_string(),
-            source: "/tmp/source".to_string(),
-            target: "/tmp/target".to_string(),
-            checksum_mode: false,
-            watch_mode: true,
-            auto_unmount: false,
-            verify_after_copy: true,
-            exclusion_sets: vec!["set-a".to_string(), "set-b".to_string()],
-        };
-
-        let sets = vec![
-            RuntimeExclusionSet {
-                id: "set-a".to_string(),
-                name: "Set A".to_string(),
-                patterns: vec![
-                    "dist".to_string(),
-                    "build".to_string(),
-                    "coverage".to_string(),
-                ],
-            },
-            RuntimeExclusionSet {
-                id: "set-b".to_string(),
-                name: "Set B".to_string(),
-                patterns: vec![
-                    "build".to_string(),
-                    "coverage".to_string(),
-                    ".cache".to_string(),
-                ],
-            },
-        ];
-
-        let resolved = resolve_runtime_exclude_patterns(&task, &sets);
-        assert_eq!(
-            resolved,
-            vec![
-                "dist".to_string(),
-                "build".to_string(),
-                "coverage".to_string(),
-                ".cache".to_string()
-            ]
-        );
+    fn test_has_autostart_arg_detects_flag() {
+        assert!(has_autostart_arg(["syncwatcher", "--autostart"]));
+        assert!(!has_autostart_arg(["syncwatcher", "--verbose"]));
     }
 
     #[test]
-    fn test_runtime_find_watch_task_filters_non_watch_tasks() {
-        let tasks = vec![
-            build_runtime_task("watch-off", "/src/off", false),
-            build_runtime_task("watch-on", "/src/on", true),
-        ];
+    fn test_owner_license_debug_token_from_args_extracts_secret() {
+        let token = owner_license_debug_token_from_args([
+            "syncwatcher",
+            "--owner-license-debug=my-secret-token",
+        ]);
 
-        assert!(runtime_find_watch_task(&tasks, "watch-off").is_none());
-        assert!(runtime_find_watch_task(&tasks, "watch-on").is_some());
-        assert!(runtime_find_watch_task(&tasks, "missing").is_none());
+        assert_eq!(token.as_deref(), Some("my-secret-token"));
     }
 
     #[test]
-    fn test_runtime_get_state_internal_returns_syncing_tasks() {
-        let rt = tokio::runtime::Runtime::new().unwrap();
-        let state = build_app_state();
-
-        rt.block_on(async {
-            {
-                let mut syncing = state.syncing_tasks.write().await;
-                syncing.insert("task-1".to_string());
-                syncing.insert("task-2".to_string());
-            }
-
-            let runtime_state = runtime_get_state_internal(&state).await;
-            let syncing: HashSet<String> = runtime_state.syncing_tasks.into_iter().collect();
+    fn test_owner_license_debug_token_from_args_rejects_empty_secret() {
+        let token = owner_license_debug_token_from_args(["syncwatcher", "--owner-license-debug="]);
 
-            assert!(runtime_state.watching_tasks.is_empty());
-            assert!(syncing.contains("task-1"));
-            assert!(syncing.contains("task-2"));
-            assert_eq!(syncing.len(), 2);
-        });
+        assert_eq!(token, None);
     }
 
     #[test]
-    fn test_enqueue_runtime_sync_task_internal_defers_while_syncing() {
-        let rt = tokio::runtime::Runtime::new().unwrap();
-        let state = build_app_state();
-
-        rt.block_on(async {
-            {
-                let mut syncing = state.syncing_tasks.write().await;
-                syncing.insert("task-1".to_string());
-            }
-
-            let result = enqueue_runtime_sync_task_internal("task-1", &state).await;
-            assert_eq!(result, RuntimeSyncEnqueueResult::DeferredWhileSyncing);
+    fn test_mcp_auth_token_from_args_extracts_secret() {
+        let token =
+            mcp_auth_token_from_args(["syncwatcher", "--mcp-stdio", "--mcp-token", "secret-token"])
+                .expect("mcp token should parse");
 
-            let pending = state.runtime_pending_sync_tasks.read().await;
-            assert!(pending.contains("task-1"));
-            drop(pending);
+        assert_eq!(token, "secret-token");
+    }
 
-            let queued = state.queued_sync_tasks.read().await;
-            assert!(!queued.contains("task-1"));
-            drop(queued);
+    #[test]
+    fn test_mcp_auth_token_from_args_requires_non_empty_secret() {
+        let error = mcp_auth_token_from_args(["syncwatcher", "--mcp-stdio", "--mcp-token="])
+            .expect_err("empty token should be rejected");
 
-            let queue = state.runtime_sync_queue.read().await;
-            assert!(queue.is_empty());
-        });
+        assert!(error.contains("--mcp-token"));
     }
 
-    #[test]
-    fn test_take_runtime_pending_sync_task_returns_true_once() {
-        let rt = tokio::runtime::Runtime::new().unwrap();
+    #[tokio::test]
+    async fn test_validate_control_plane_auth_rejects_missing_auth_token() {
         let state = build_app_state();
+        state
+            .config_store
+            .load_settings()
+            .expect("settings should initialize token");
+        let error = validate_control_plane_auth(
+            &ControlPlaneRequest {
+                request_id: "req-missing-token".to_string(),
+                method: "syncwatcher_get_settings".to_string(),
+                auth_token: None,
+                params: serde_json::json!({}),
+            },
+            &state,
+        )
+        .expect_err("missing token should be rejected");
 
-        rt.block_on(async {
-            {
-                let mut pending = state.runtime_pending_sync_tasks.write().await;
-                pending.insert("task-1".to_string());
-            }
-
-            assert!(take_runtime_pending_sync_task("task-1", &state).await);
-            assert!(!take_runtime_pending_sync_task("task-1", &state).await);
-        });
+        assert!(error.contains("missing"));
     }
 
-    #[test]
-    fn test_dequeue_runtime_sync_task_keeps_queue_set_consistent() {
-        let rt = tokio::runtime::Runtime::new().unwrap();
+    #[tokio::test]
+    async fn test_validate_control_plane_auth_rejects_stale_auth_token_after_rotation() {
         let state = build_app_state();
+        let initial_token = state
+            .config_store
+            .load_settings()
+            .expect("settings should initialize token")
+            .mcp_auth_token
+            .expect("initial token should exist");
+        state
+            .config_store
+            .regenerate_mcp_auth_token()
+            .expect("token should rotate");
+        let error = validate_control_plane_auth(
+            &ControlPlaneRequest {
+                request_id: "req-stale-token".to_string(),
+                method: "syncwatcher_get_settings".to_string(),
+                auth_token: Some(initial_token),
+                params: serde_json::json!({}),
+            },
+            &state,
+        )
+        .expect_err("stale token should be rejected");
 
-        rt.block_on(async {
-            {
-                let mut queued = state.queued_sync_tasks.write().await;
-                queued.insert("task-1".to_string());
-            }
-            {
-                let mut queue = state.runtime_sync_queue.write().await;
-                queue.push_back("task-1".to_string());
-            }
-
-            let next = dequeue_runtime_sync_task(&state).await;
-            assert_eq!(next.as_deref(), Some("task-1"));
+        assert!(error.contains("mismatch"));
+    }
 
-            let queued = state.queued_sync_tasks.read().await;
-            assert!(!queued.contains("task-1"));
-            drop(queued);
+    #[test]
+    fn test_decide_autostart_launch_without_arg_keeps_normal_launch() {
+        let decision = decide_autostart_launch(false, Ok(true));
 
-            let queue = state.runtime_sync_queue.read().await;
-            assert!(queue.is_empty());
-        });
+        assert!(!decision.argv_present);
+        assert_eq!(decision.autolaunch_enabled, None);
+        assert!(!decision.hidden_start_accepted);
+        assert_eq!(decision.reject_reason, None);
+        assert_eq!(decision.status_error, None);
     }
 
     #[test]
-    fn test_remove_runtime_sync_task_state_clears_pending_queue_and_set() {
-        let rt = tokio::runtime::Runtime::new().unwrap();
-        let state = build_app_state();
+    fn test_decide_autostart_launch_accepts_only_when_enabled() {
+        let decision = decide_autostart_launch(true, Ok(true));
 
-        rt.block_on(async {
-            {
-                let mut pending = state.runtime_pending_sync_tasks.write().await;
-                pending.insert("task-1".to_string());
-            }
-            {
-                let mut queued = state.queued_sync_tasks.write().await;
-                queued.insert("task-1".to_string());
-            }
-            {
-                let mut queue = state.runtime_sync_queue.write().await;
-                queue.push_back("task-1".to_string());
-                queue.push_back("task-2".to_string());
-            }
+        assert!(decision.argv_present);
+        assert_eq!(decision.autolaunch_enabled, Some(true));
+        assert!(decision.hidden_start_accepted);
+        assert_eq!(decision.reject_reason, None);
+        assert_eq!(decision.status_error, None);
+    }
 
-            remove_runtime_sync_task_state("task-1", &state).await;
+    #[test]
+    fn test_decide_autostart_launch_rejects_disabled_login_item() {
+        let decision = decide_autostart_launch(true, Ok(false));
 
-            let pending = state.runtime_pending_sync_tasks.read().await;
-            assert!(!pending.contains("task-1"));
-            drop(pending);
+        assert!(decision.argv_present);
+        assert_eq!(decision.autolaunch_enabled, Some(false));
+        assert!(!decision.hidden_start_accepted);
+        assert_eq!(decision.reject_reason, Some("launch_at_login_disabled"));
+        assert_eq!(decision.status_error, None);
+    }
 
-            let queued = state.queued_sync_tasks.read().await;
-            assert!(!queued.contains("task-1"));
-            drop(queued);
+    #[test]
+    fn test_decide_autostart_launch_rejects_status_errors() {
+        let decision = decide_autostart_launch(true, Err("failed".to_string()));
 
-            let queue = state.runtime_sync_queue.read().await;
-            assert_eq!(
-                queue.iter().cloned().collect::<Vec<_>>(),
-                vec!["task-2".to_string()]
-            );
-        });
+        assert!(decision.argv_present);
+        assert_eq!(decision.autolaunch_enabled, None);
+        assert!(!decision.hidden_start_accepted);
+        assert_eq!(
+            decision.reject_reason,
+            Some("launch_at_login_status_unavailable")
+        );
+        assert_eq!(decision.status_error.as_deref(), Some("failed"));
+    }
+
+    #[test]
+    fn test_launch_at_login_status_or_default_falls_back_to_false() {
+        assert!(launch_at_login_status_or_default(Ok(true)));
+        assert!(!launch_at_login_status_or_default(
+            Err("failed".to_string())
+        ));
+    }
+
+    #[test]
+    fn test_runtime_desired_watch_sources_uses_watch_mode_only() {
+        let tasks = vec![
+            build_runtime_task("a", "/src/a", true),
+            build_runtime_task("b", "/src/b", false),
+            build_runtime_task("c", "/src/c", true),
+        ];
+
+        let desired = runtime_desired_watch_sources(&tasks);
+        assert_eq!(desired.len(), 2);
+        assert_eq!(desired.get("a"), Some(&"/src/a".to_string()));
+        assert_eq!(desired.get("c"), Some(&"/src/c".to_string()));
+        assert!(!desired.contains_key("b"));
     }
 
     #[tokio::test]
-    async fn test_cancel_operation_internal_cancels_dry_run_token() {
+    async fn test_disabled_task_is_not_watched_and_reenabling_restarts_watcher() {
         let state = build_app_state();
-        let token = CancellationToken::new();
+        state.runtime_config.write().await.tasks = vec![
+            build_runtime_task("a", "/src/a", true),
+            build_runtime_task("b", "/src/b", true),
+        ];
 
-        {
-            let mut tokens = state.dry_run_cancel_tokens.write().await;
-            tokens.insert("task-1".to_string(), token.clone());
-        }
+        assert!(set_task_enabled_internal("a", false, &state).await.unwrap());
+        assert!(!set_task_enabled_internal("a", false, &state).await.unwrap());
+        let tasks = state.runtime_config.read().await.tasks.clone();
+        let desired = runtime_desired_watch_sources(&tasks);
+        assert!(!desired.contains_key("a"));
+        assert!(desired.contains_key("b"));
+        assert!(runtime_find_watch_task(&tasks, "a").is_none());
+        assert_eq!(
+            runtime_watch_bootstrap_task_ids(&tasks),
+            vec!["b".to_string()]
+        );
 
-        let cancelled =
-            cancel_operation_internal("task-1", CancelOperationType::DryRun, &state).await;
-        assert!(cancelled);
-        assert!(token.is_cancelled());
+        // b만 watch 중인 상태에서 a를 다시 켜면 a의 watcher를 시작해야 함
+        let managed: HashMap<String, String> = desired.clone();
+        let watching: HashSet<String> = desired.keys().cloned().collect();
+        assert!(set_task_enabled_internal("a", true, &state).await.unwrap());
+        let tasks = state.runtime_config.read().await.tasks.clone();
+        let restart = runtime_watch_restart_task_ids(
+            &runtime_desired_watch_sources(&tasks),
+            &managed,
+            &watching,
+            &HashMap::new(),
+        );
+        assert_eq!(restart, vec!["a".to_string()]);
+
+        assert!(set_task_enabled_internal("missing", true, &state)
+            .await
+            .is_err());
     }
 
     #[tokio::test]
-    async fn test_cancel_operation_internal_cancels_sync_token() {
+    async fn test_disabled_task_stays_disabled_after_canonical_config_reload() {
         let state = build_app_state();
-        let token = CancellationToken::new();
+        state
+            .config_store
+            .save_tasks(&[
+                build_path_task("a", "/tmp/enabled-src-a", "/tmp/enabled-dst-a", true),
+                build_path_task("b", "/tmp/enabled-src-b", "/tmp/enabled-dst-b", true),
+            ])
+            .unwrap();
+        *state.runtime_config.write().await = load_canonical_runtime_payload(&state).await.unwrap();
 
-        {
-            let mut tokens = state.cancel_tokens.write().await;
-            tokens.insert("task-1".to_string(), token.clone());
-        }
+        assert!(set_task_enabled_internal("a", false, &state).await.unwrap());
+        let stored = state.config_store.load_tasks().unwrap();
+        assert!(!stored.iter().find(|task| task.id == "a").unwrap().enabled);
 
-        let cancelled =
-            cancel_operation_internal("task-1", CancelOperationType::Sync, &state).await;
-        assert!(cancelled);
-        assert!(token.is_cancelled());
+        let reloaded = load_canonical_runtime_payload(&state).await.unwrap();
+        let enabled: Vec<(String, bool)> = reloaded
+            .tasks
+            .iter()
+            .map(|task| (task.id.clone(), task.enabled))
+            .collect();
+        assert_eq!(
+            enabled,
+            vec![("a".to_string(), false), ("b".to_string(), true)]
+        );
+        assert!(!runtime_desired_watch_sources(&reloaded.tasks).contains_key("a"));
     }
 
-    #[test]
-    fn test_validate_runtime_tasks_allows_distinct_sources_and_targets() {
-        let tasks = vec![
-            build_runtime_task_with_paths("a", "/src/a", "/dst/a", false),
-            build_runtime_task_with_paths("b", "/src/b", "/dst/b", false),
-        ];
-
-        assert!(validate_runtime_tasks(&tasks).is_ok());
+    #[tokio::test]
+    async fn test_max_coalesce_secs_persists_into_runtime_config() {
+        let state = build_app_state();
+        let mut task = build_path_task("a", "/tmp/coalesce-src", "/tmp/coalesce-dst", true);
+        task.max_coalesce_secs = Some(120);
+        state.config_store.save_tasks(&[task.clone()]).unwrap();
+
+        let runtime = load_canonical_runtime_payload(&state).await.unwrap();
+        assert_eq!(runtime.tasks[0].max_coalesce_secs, Some(120));
+
+        let reset = apply_sync_task_update(
+            task,
+            &UpdateSyncTaskRequest {
+                task_id: "a".to_string(),
+                max_coalesce_secs: Some(0),
+                ..UpdateSyncTaskRequest::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(reset.max_coalesce_secs, None);
     }
 
-    #[test]
-    fn test_validate_runtime_tasks_rejects_duplicate_targets() {
-        let tasks = vec![
-            build_runtime_task_with_paths("a", "/src/a", "/dst/shared", false),
-            build_runtime_task_with_paths("b", "/src/b", "/dst/shared", false),
-        ];
+    #[tokio::test]
+    async fn test_max_duration_secs_persists_and_applies_to_runs_without_a_limit() {
+        let state = build_app_state();
+        let mut task = build_path_task("a", "/tmp/limit-src", "/tmp/limit-dst", true);
+        task.max_duration_secs = Some(600);
+        state.config_store.save_tasks(&[task.clone()]).unwrap();
 
-        let result = validate_runtime_tasks(&tasks);
-        assert!(result.is_err());
-        assert!(result
-            .err()
-            .unwrap_or_default()
-            .contains("Target path conflict"));
-    }
+        let runtime = load_canonical_runtime_payload(&state).await.unwrap();
+        assert_eq!(runtime.tasks[0].max_duration_secs, Some(600));
+        state.runtime_config.write().await.tasks = runtime.tasks;
 
-    #[test]
-    fn test_validate_runtime_tasks_rejects_target_subdirectory_conflict() {
-        let tasks = vec![
-            build_runtime_task_with_paths("a", "/src/a", "/dst/root", false),
-            build_runtime_task_with_paths("b", "/src/b", "/dst/root/child", false),
-        ];
+        // Watch and re-queued runs pass default options and pick up the stored limit.
+        let applied = with_task_max_duration(SyncRunOptions::default(), "a", &state).await;
+        assert_eq!(applied.max_duration_secs, Some(600));
 
-        let result = validate_runtime_tasks(&tasks);
-        assert!(result.is_err());
-        assert!(result
-            .err()
-            .unwrap_or_default()
-            .contains("Target path conflict"));
+        let explicit = SyncRunOptions {
+            max_duration_secs: Some(30),
+            ..SyncRunOptions::default()
+        };
+        let applied = with_task_max_duration(explicit, "a", &state).await;
+        assert_eq!(applied.max_duration_secs, Some(30));
+
+        let reset = apply_sync_task_update(
+            task,
+            &UpdateSyncTaskRequest {
+                task_id: "a".to_string(),
+                max_duration_secs: Some(0),
+                ..UpdateSyncTaskRequest::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(reset.max_duration_secs, None);
+    }
+
+    #[tokio::test]
+    async fn test_task_notification_prefs_persist_into_runtime_config() {
+        let state = build_app_state();
+        let prefs = TaskNotificationPrefs {
+            on_conflict: false,
+            on_complete: true,
+            on_error: true,
+        };
+        let mut task = build_path_task("a", "/tmp/notify-src", "/tmp/notify-dst", false);
+        task.notifications = prefs;
+        state.config_store.save_tasks(&[task.clone()]).unwrap();
+
+        let runtime = load_canonical_runtime_payload(&state).await.unwrap();
+        assert_eq!(runtime.tasks[0].notifications, prefs);
+
+        let renamed = apply_sync_task_update(
+            task,
+            &UpdateSyncTaskRequest {
+                task_id: "a".to_string(),
+                name: Some("renamed".to_string()),
+                ..UpdateSyncTaskRequest::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(renamed.notifications, prefs);
+        let reset = apply_sync_task_update(
+            renamed,
+            &UpdateSyncTaskRequest {
+                task_id: "a".to_string(),
+                notifications: Some(TaskNotificationPrefs::default()),
+                ..UpdateSyncTaskRequest::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(reset.notifications, TaskNotificationPrefs::default());
     }
 
     #[test]
-    fn test_orphan_target_conflict_helper_returns_duplicate_target_for_selected_task() {
+    fn test_runtime_watch_tasks_over_limit_skips_tasks_beyond_cap() {
         let tasks = vec![
-            build_runtime_task_with_paths("a", "/src/a", "/dst/shared", false),
-            build_runtime_task_with_paths("b", "/src/b", "/dst/shared", false),
+            build_runtime_task("a", "/src/a", true),
+            build_runtime_task("b", "/src/b", false),
+            build_runtime_task("c", "/src/c", true),
+            build_runtime_task("d", "/src/d", true),
+            build_runtime_task("e", "/src/e", true),
         ];
 
-        let issue = find_runtime_orphan_target_conflict_issue("b", &tasks)
-            .expect("helper should not error")
-            .expect("selected task should be blocked");
+        let over_limit = runtime_watch_tasks_over_limit(&tasks, 2);
+        assert_eq!(over_limit, vec!["d".to_string(), "e".to_string()]);
+        assert!(runtime_watch_tasks_over_limit(&tasks, 4).is_empty());
 
-        assert_eq!(issue.code, RuntimeTaskValidationCode::DuplicateTarget);
-        assert_eq!(issue.task_id.as_deref(), Some("b"));
-        assert_eq!(issue.conflicting_task_ids, vec!["a".to_string()]);
+        let settings = RuntimeSettings::default();
+        assert_eq!(
+            settings.effective_max_watchers(),
+            DEFAULT_MAX_WATCHERS as usize
+        );
+        let mut app_settings = default_settings_record();
+        app_settings.max_watchers = 0;
+        assert!(validate_settings_record(&app_settings)
+            .unwrap_err()
+            .contains("maxWatchers"));
+        app_settings.max_watchers = 2;
+        assert_eq!(
+            to_runtime_settings_record(&app_settings).effective_max_watchers(),
+            2
+        );
     }
 
     #[test]
-    fn test_orphan_target_conflict_helper_returns_nested_target_for_selected_task() {
+    fn test_runtime_watch_bootstrap_task_ids_use_watch_mode_only() {
         let tasks = vec![
-            build_runtime_task_with_paths("a", "/src/a", "/dst/root", false),
-            build_runtime_task_with_paths("b", "/src/b", "/dst/root/child", false),
+            build_runtime_task("a", "/src/a", true),
+            build_runtime_task("b", "/src/b", false),
+            build_runtime_task(
+                "uuid-watch",
+                "[VOLUME_UUID:07497716-6027-3FE9-B418-6BEA262C7D2F]/",
+                true,
+            ),
         ];
 
-        let issue = find_runtime_orphan_target_conflict_issue("b", &tasks)
-            .expect("helper should not error")
-            .expect("selected task should be blocked");
-
-        assert_eq!(issue.code, RuntimeTaskValidationCode::TargetSubdirConflict);
-        assert_eq!(issue.task_id.as_deref(), Some("b"));
-        assert_eq!(issue.conflicting_task_ids, vec!["a".to_string()]);
+        assert_eq!(
+            runtime_watch_bootstrap_task_ids(&tasks),
+            vec!["a".to_string(), "uuid-watch".to_string(),]
+        );
     }
 
     #[test]
-    fn test_orphan_target_conflict_helper_ignores_unrelated_task_conflicts() {
-        let tasks = vec![
-            build_runtime_task_with_paths("a", "/src/a", "/dst/root", false),
-            build_runtime_task_with_paths("b", "/src/b", "/dst/root/child", false),
-            build_runtime_task_with_paths("c", "/src/c", "/dst/isolated", false),
-        ];
+    fn test_runtime_watch_task_needs_restart_when_new_stopped_or_source_changed() {
+        let managed_sources =
+            HashMap::from([("task-1".to_string(), "/Volumes/old/source".to_string())]);
+        let watching_now = HashSet::from(["task-1".to_string()]);
+        let watching_source_paths = HashMap::new();
+
+        assert!(!runtime_watch_task_needs_restart(
+            "task-1",
+            "/Volumes/old/source",
+            &managed_sources,
+            &watching_now,
+            &watching_source_paths,
+        ));
+        assert!(runtime_watch_task_needs_restart(
+            "task-2",
+            "/Volumes/new/source",
+            &managed_sources,
+            &watching_now,
+            &watching_source_paths,
+        ));
+        assert!(runtime_watch_task_needs_restart(
+            "task-1",
+            "/Volumes/new/source",
+            &managed_sources,
+            &watching_now,
+            &watching_source_paths,
+        ));
+        assert!(runtime_watch_task_needs_restart(
+            "task-1",
+            "/Volumes/old/source",
+            &managed_sources,
+            &HashSet::new(),
+            &watching_source_paths,
+        ));
+    }
+
+    #[test]
+    fn test_runtime_watch_restart_plan_restarts_uuid_task_when_registered_watcher_root_is_stale() {
+        let stale_mount = tempdir().unwrap();
+        let stale_watch_root = stale_mount.path().join("DCIM");
+        std::fs::create_dir_all(&stale_watch_root).unwrap();
+        let stale_watch_root = stale_watch_root.to_string_lossy().to_string();
+
+        let desired_sources = HashMap::from([(
+            "uuid-watch".to_string(),
+            "[DISK_UUID:disk-a]/DCIM".to_string(),
+        )]);
+        let managed_sources = desired_sources.clone();
+        let watching_now = HashSet::from(["uuid-watch".to_string()]);
+        let watching_source_paths =
+            HashMap::from([("uuid-watch".to_string(), stale_watch_root.clone())]);
+
+        assert!(runtime_watch_restart_task_ids(
+            &desired_sources,
+            &managed_sources,
+            &watching_now,
+            &watching_source_paths,
+        )
+        .is_empty());
+
+        drop(stale_mount);
+
+        assert_eq!(
+            runtime_watch_restart_task_ids(
+                &desired_sources,
+                &managed_sources,
+                &watching_now,
+                &watching_source_paths,
+            ),
+            vec!["uuid-watch".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_runtime_watch_restart_plan_does_not_restart_when_uuid_task_is_already_tracking_current_root(
+    ) {
+        let current_mount = tempdir().unwrap();
+        let current_watch_root = current_mount.path().join("DCIM");
+        std::fs::create_dir_all(&current_watch_root).unwrap();
+        let current_watch_root = current_watch_root.to_string_lossy().to_string();
+
+        let desired_sources = HashMap::from([(
+            "uuid-watch".to_string(),
+            "[DISK_UUID:disk-a]/DCIM".to_string(),
+        )]);
+        let managed_sources = desired_sources.clone();
+        let watching_now = HashSet::from(["uuid-watch".to_string()]);
+        let watching_source_paths = HashMap::from([("uuid-watch".to_string(), current_watch_root)]);
+
+        assert!(runtime_watch_restart_task_ids(
+            &desired_sources,
+            &managed_sources,
+            &watching_now,
+            &watching_source_paths,
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn test_should_reconcile_runtime_watchers_for_volume_change_requires_mount_diff() {
+        assert!(!should_reconcile_runtime_watchers_for_volume_change(
+            &Vec::new(),
+            &Vec::new()
+        ));
+        assert!(should_reconcile_runtime_watchers_for_volume_change(
+            &vec!["/Volumes/CARD".to_string()],
+            &Vec::new()
+        ));
+        assert!(should_reconcile_runtime_watchers_for_volume_change(
+            &Vec::new(),
+            &vec!["/Volumes/CARD".to_string()]
+        ));
+    }
+
+    #[test]
+    fn test_can_enqueue_runtime_watch_bootstrap_task_blocks_syncing_queued_and_pending() {
+        let syncing = HashSet::from(["syncing".to_string()]);
+        let queued = HashSet::from(["queued".to_string()]);
+        let pending = HashSet::from(["pending".to_string()]);
+
+        assert!(!can_enqueue_runtime_watch_bootstrap_task(
+            "syncing", &syncing, &queued, &pending
+        ));
+        assert!(!can_enqueue_runtime_watch_bootstrap_task(
+            "queued", &syncing, &queued, &pending
+        ));
+        assert!(!can_enqueue_runtime_watch_bootstrap_task(
+            "pending", &syncing, &queued, &pending
+        ));
+        assert!(can_enqueue_runtime_watch_bootstrap_task(
+            "eligible", &syncing, &queued, &pending
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_runtime_watch_bootstrap_tasks_enqueues_only_eligible_tasks() {
+        let state = build_app_state();
+        {
+            let mut syncing = state.syncing_tasks.write().await;
+            syncing.insert("syncing".to_string());
+        }
+        {
+            let mut queued = state.queued_sync_tasks.write().await;
+            queued.insert("queued".to_string());
+        }
+        {
+            let mut queue = state.runtime_sync_queue.write().await;
+            queue.push_back("queued".to_string());
+        }
+        {
+            let mut pending = state.runtime_pending_sync_tasks.write().await;
+            pending.insert("pending".to_string());
+        }
+
+        let task_ids = vec![
+            "eligible".to_string(),
+            "syncing".to_string(),
+            "queued".to_string(),
+            "pending".to_string(),
+        ];
+
+        let enqueued = enqueue_runtime_watch_bootstrap_tasks(&task_ids, &state).await;
+        assert_eq!(enqueued, vec!["eligible".to_string()]);
+
+        let queue = state.runtime_sync_queue.read().await;
+        assert_eq!(
+            queue.iter().cloned().collect::<Vec<_>>(),
+            vec!["queued".to_string(), "eligible".to_string()]
+        );
+        drop(queue);
+
+        let queued = state.queued_sync_tasks.read().await;
+        assert!(queued.contains("eligible"));
+        assert!(queued.contains("queued"));
+        assert!(!queued.contains("syncing"));
+        assert!(!queued.contains("pending"));
+    }
+
+    #[tokio::test]
+    async fn test_force_reset_runtime_dispatcher_reschedules_only_with_queued_work() {
+        let state = build_app_state();
+        *state.runtime_dispatcher_running.lock().await = true;
+
+        assert!(!force_reset_runtime_dispatcher(&state).await);
+        let snapshot = get_dispatcher_state_internal(&state).await;
+        assert!(!snapshot.running);
+        assert_eq!(snapshot.queue_length, 0);
+        assert_eq!(snapshot.last_dispatch_at, None);
+
+        *state.runtime_dispatcher_running.lock().await = true;
+        state
+            .runtime_sync_queue
+            .write()
+            .await
+            .push_back("wedged".to_string());
+        let generation_before = state.runtime_dispatcher_generation.load(Ordering::SeqCst);
+
+        assert!(force_reset_runtime_dispatcher(&state).await);
+        assert_eq!(
+            state.runtime_dispatcher_generation.load(Ordering::SeqCst),
+            generation_before + 1
+        );
+        let snapshot = get_dispatcher_state_internal(&state).await;
+        assert!(!snapshot.running);
+        assert_eq!(snapshot.queue_length, 1);
+    }
+
+    #[tokio::test]
+    async fn test_initial_bootstrap_enqueue_respects_bootstrap_initial_sync_setting() {
+        let state = build_app_state();
+        let mut runtime_config = RuntimeConfigPayload {
+            tasks: vec![
+                build_runtime_task("a", "/src/a", true),
+                build_runtime_task("b", "/src/b", false),
+            ],
+            ..RuntimeConfigPayload::default()
+        };
+        runtime_config.settings.bootstrap_initial_sync = false;
+
+        let enqueued = enqueue_initial_runtime_watch_bootstrap_tasks(&runtime_config, &state).await;
+        assert!(enqueued.is_empty());
+        assert!(state.runtime_sync_queue.read().await.is_empty());
+        assert!(state.runtime_bootstrap_queued_tasks.read().await.is_empty());
+
+        runtime_config.settings.bootstrap_initial_sync = true;
+        let enqueued = enqueue_initial_runtime_watch_bootstrap_tasks(&runtime_config, &state).await;
+        assert_eq!(enqueued, vec!["a".to_string()]);
+        assert!(state
+            .runtime_bootstrap_queued_tasks
+            .read()
+            .await
+            .contains("a"));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_bootstrap_syncs_removes_only_bootstrap_queue_entries() {
+        let state = build_app_state();
+        let runtime_config = RuntimeConfigPayload {
+            tasks: vec![
+                build_runtime_task("boot-1", "/src/boot-1", true),
+                build_runtime_task("boot-2", "/src/boot-2", true),
+                build_runtime_task("rewatched", "/src/rewatched", true),
+            ],
+            ..RuntimeConfigPayload::default()
+        };
+        enqueue_initial_runtime_watch_bootstrap_tasks(&runtime_config, &state).await;
+        // A watch event for a bootstrap-queued task makes it a regular queue entry.
+        assert_eq!(
+            enqueue_runtime_sync_task_internal("rewatched", &state).await,
+            RuntimeSyncEnqueueResult::AlreadyQueued
+        );
+        assert_eq!(
+            enqueue_runtime_sync_task_internal("watched", &state).await,
+            RuntimeSyncEnqueueResult::Enqueued
+        );
+
+        let mut cancelled = cancel_bootstrap_syncs_internal(&state).await;
+        cancelled.sort();
+        assert_eq!(cancelled, vec!["boot-1".to_string(), "boot-2".to_string()]);
+
+        assert_eq!(
+            state
+                .runtime_sync_queue
+                .read()
+                .await
+                .iter()
+                .cloned()
+                .collect::<Vec<_>>(),
+            vec!["rewatched".to_string(), "watched".to_string()]
+        );
+        let queued = state.queued_sync_tasks.read().await;
+        assert!(!queued.contains("boot-1"));
+        assert!(queued.contains("rewatched"));
+        assert!(queued.contains("watched"));
+        drop(queued);
+        assert!(state.runtime_bootstrap_queued_tasks.read().await.is_empty());
+        assert!(cancel_bootstrap_syncs_internal(&state).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_runtime_watch_bootstrap_tasks_skip_syncing_queued_pending_even_after_remount_restart(
+    ) {
+        let state = build_app_state();
+        let stale_mount = tempdir().unwrap();
+        let eligible_root = stale_mount.path().join("eligible");
+        let syncing_root = stale_mount.path().join("syncing");
+        let queued_root = stale_mount.path().join("queued");
+        let pending_root = stale_mount.path().join("pending");
+        std::fs::create_dir_all(&eligible_root).unwrap();
+        std::fs::create_dir_all(&syncing_root).unwrap();
+        std::fs::create_dir_all(&queued_root).unwrap();
+        std::fs::create_dir_all(&pending_root).unwrap();
+
+        let desired_sources = HashMap::from([
+            (
+                "eligible".to_string(),
+                "[DISK_UUID:eligible-disk]/DCIM".to_string(),
+            ),
+            (
+                "syncing".to_string(),
+                "[DISK_UUID:syncing-disk]/DCIM".to_string(),
+            ),
+            (
+                "queued".to_string(),
+                "[DISK_UUID:queued-disk]/DCIM".to_string(),
+            ),
+            (
+                "pending".to_string(),
+                "[DISK_UUID:pending-disk]/DCIM".to_string(),
+            ),
+        ]);
+        let managed_sources = desired_sources.clone();
+        let watching_now = HashSet::from([
+            "eligible".to_string(),
+            "syncing".to_string(),
+            "queued".to_string(),
+            "pending".to_string(),
+        ]);
+        let watching_source_paths = HashMap::from([
+            (
+                "eligible".to_string(),
+                eligible_root.to_string_lossy().to_string(),
+            ),
+            (
+                "syncing".to_string(),
+                syncing_root.to_string_lossy().to_string(),
+            ),
+            (
+                "queued".to_string(),
+                queued_root.to_string_lossy().to_string(),
+            ),
+            (
+                "pending".to_string(),
+                pending_root.to_string_lossy().to_string(),
+            ),
+        ]);
+
+        drop(stale_mount);
+
+        {
+            let mut syncing = state.syncing_tasks.write().await;
+            syncing.insert("syncing".to_string());
+        }
+        {
+            let mut queued = state.queued_sync_tasks.write().await;
+            queued.insert("queued".to_string());
+        }
+        {
+            let mut queue = state.runtime_sync_queue.write().await;
+            queue.push_back("queued".to_string());
+        }
+        {
+            let mut pending = state.runtime_pending_sync_tasks.write().await;
+            pending.insert("pending".to_string());
+        }
+
+        let restart_task_ids = runtime_watch_restart_task_ids(
+            &desired_sources,
+            &managed_sources,
+            &watching_now,
+            &watching_source_paths,
+        );
+
+        assert_eq!(
+            restart_task_ids,
+            vec![
+                "eligible".to_string(),
+                "pending".to_string(),
+                "queued".to_string(),
+                "syncing".to_string(),
+            ]
+        );
+
+        let enqueued = enqueue_runtime_watch_bootstrap_tasks(&restart_task_ids, &state).await;
+        assert_eq!(enqueued, vec!["eligible".to_string()]);
+    }
+
+    #[test]
+    fn test_resolve_runtime_exclude_patterns_deduplicates_preserving_order() {
+        let task = RuntimeSyncTask {
+            id: "task-1".to_string(),
+            name: "task-1".to_string(),
+            source: "/tmp/source".to_string(),
+            target: "/tmp/target".to_string(),
+            checksum_mode: false,
+            watch_mode: true,
+            auto_unmount: false,
+            verify_after_copy: true,
+            exclusion_sets: vec!["set-a".to_string(), "set-b".to_string()],
+            resource_group: None,
+            max_coalesce_secs: None,
+            max_duration_secs: None,
+            notifications: TaskNotificationPrefs::default(),
+            enabled: true,
+        };
+
+        let sets = vec![
+            RuntimeExclusionSet {
+                id: "set-a".to_string(),
+                name: "Set A".to_string(),
+                patterns: vec![
+                    "dist".to_string(),
+                    "build".to_string(),
+                    "coverage".to_string(),
+                ],
+            },
+            RuntimeExclusionSet {
+                id: "set-b".to_string(),
+                name: "Set B".to_string(),
+                patterns: vec![
+                    "build".to_string(),
+                    "coverage".to_string(),
+                    ".cache".to_string(),
+                ],
+            },
+        ];
+
+        let resolved = resolve_runtime_exclude_patterns(&task, &sets);
+        assert_eq!(
+            resolved,
+            vec![
+                "dist".to_string(),
+                "build".to_string(),
+                "coverage".to_string(),
+                ".cache".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_exclusion_set_reports_each_pattern_individually() {
+        let set = RuntimeExclusionSet {
+            id: "set-a".to_string(),
+            name: "Set A".to_string(),
+            patterns: vec!["*.{jpg,png".to_string(), "node_modules".to_string()],
+        };
+
+        let results = validate_exclusion_set_internal(&set);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].pattern, "*.{jpg,png");
+        assert!(!results[0].valid);
+        assert!(results[0]
+            .error
+            .as_deref()
+            .is_some_and(|error| error.contains("Unclosed '{'")));
+        assert_eq!(results[1].pattern, "node_modules");
+        assert!(results[1].valid);
+        assert_eq!(results[1].error, None);
+    }
+
+    #[test]
+    fn test_compare_task_excludes_splits_unique_and_common_patterns() {
+        let mut task_a = build_runtime_task("a", "/src/a", false);
+        task_a.exclusion_sets = vec!["media".to_string(), "build".to_string()];
+        let mut task_b = build_runtime_task("b", "/src/b", false);
+        task_b.exclusion_sets = vec!["build".to_string(), "temp".to_string()];
+        let config = RuntimeConfigPayload {
+            tasks: vec![task_a, task_b],
+            exclusion_sets: vec![
+                RuntimeExclusionSet {
+                    id: "media".to_string(),
+                    name: "Media".to_string(),
+                    patterns: vec!["*.thm".to_string(), "*.tmp".to_string()],
+                },
+                RuntimeExclusionSet {
+                    id: "build".to_string(),
+                    name: "Build".to_string(),
+                    patterns: vec!["dist".to_string(), "node_modules".to_string()],
+                },
+                RuntimeExclusionSet {
+                    id: "temp".to_string(),
+                    name: "Temp".to_string(),
+                    patterns: vec!["*.tmp".to_string(), "*.swp".to_string()],
+                },
+            ],
+            ..RuntimeConfigPayload::default()
+        };
+
+        let comparison = compare_task_excludes_internal("a", "b", &config).unwrap();
+        assert_eq!(comparison.only_a, vec!["*.thm".to_string()]);
+        assert_eq!(comparison.only_b, vec!["*.swp".to_string()]);
+        assert_eq!(
+            comparison.common,
+            vec![
+                "*.tmp".to_string(),
+                "dist".to_string(),
+                "node_modules".to_string()
+            ]
+        );
+
+        assert!(compare_task_excludes_internal("a", "missing", &config).is_err());
+    }
+
+    #[test]
+    fn test_runtime_find_watch_task_filters_non_watch_tasks() {
+        let tasks = vec![
+            build_runtime_task("watch-off", "/src/off", false),
+            build_runtime_task("watch-on", "/src/on", true),
+        ];
+
+        assert!(runtime_find_watch_task(&tasks, "watch-off").is_none());
+        assert!(runtime_find_watch_task(&tasks, "watch-on").is_some());
+        assert!(runtime_find_watch_task(&tasks, "missing").is_none());
+    }
+
+    #[test]
+    fn test_runtime_get_state_internal_returns_syncing_tasks() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let state = build_app_state();
+
+        rt.block_on(async {
+            {
+                let mut syncing = state.syncing_tasks.write().await;
+                syncing.insert("task-1".to_string());
+                syncing.insert("task-2".to_string());
+            }
+
+            let runtime_state = runtime_get_state_internal(&state).await;
+            let syncing: HashSet<String> = runtime_state.syncing_tasks.into_iter().collect();
+
+            assert!(runtime_state.watching_tasks.is_empty());
+            assert!(syncing.contains("task-1"));
+            assert!(syncing.contains("task-2"));
+            assert_eq!(syncing.len(), 2);
+        });
+    }
+
+    #[test]
+    fn test_enqueue_runtime_sync_task_internal_defers_while_syncing() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let state = build_app_state();
+
+        rt.block_on(async {
+            {
+                let mut syncing = state.syncing_tasks.write().await;
+                syncing.insert("task-1".to_string());
+            }
+
+            let result = enqueue_runtime_sync_task_internal("task-1", &state).await;
+            assert_eq!(result, RuntimeSyncEnqueueResult::DeferredWhileSyncing);
+
+            let pending = state.runtime_pending_sync_tasks.read().await;
+            assert!(pending.contains("task-1"));
+            drop(pending);
+
+            let queued = state.queued_sync_tasks.read().await;
+            assert!(!queued.contains("task-1"));
+            drop(queued);
+
+            let queue = state.runtime_sync_queue.read().await;
+            assert!(queue.is_empty());
+        });
+    }
+
+    #[tokio::test]
+    async fn test_stopping_task_rejects_enqueue_while_sync_finishes() {
+        let state = build_app_state();
+        state
+            .syncing_tasks
+            .write()
+            .await
+            .insert("task-1".to_string());
+        state
+            .runtime_stopping_tasks
+            .write()
+            .await
+            .insert("task-1".to_string());
+
+        assert_eq!(
+            enqueue_runtime_sync_task_internal("task-1", &state).await,
+            RuntimeSyncEnqueueResult::Stopping
+        );
+        assert!(!state
+            .runtime_pending_sync_tasks
+            .read()
+            .await
+            .contains("task-1"));
+        assert!(state.runtime_sync_queue.read().await.is_empty());
+        assert!(!is_runtime_watch_task_active("task-1", &state).await);
+
+        assert!(
+            !wait_for_task_sync_to_finish("task-1", Duration::from_millis(50), &state).await,
+            "sync still running should time out"
+        );
+
+        let state_for_sync = state.clone();
+        let finish_sync = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            release_sync_slot("task-1", &state_for_sync).await;
+        });
+        assert!(wait_for_task_sync_to_finish("task-1", Duration::from_secs(5), &state).await);
+        finish_sync.await.expect("sync task should finish");
+        assert_eq!(
+            enqueue_runtime_sync_task_internal("task-1", &state).await,
+            RuntimeSyncEnqueueResult::Stopping
+        );
+
+        state.runtime_stopping_tasks.write().await.remove("task-1");
+        assert_eq!(
+            enqueue_runtime_sync_task_internal("task-1", &state).await,
+            RuntimeSyncEnqueueResult::Enqueued
+        );
+    }
+
+    #[test]
+    fn test_take_runtime_pending_sync_task_returns_true_once() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let state = build_app_state();
+
+        rt.block_on(async {
+            {
+                let mut pending = state.runtime_pending_sync_tasks.write().await;
+                pending.insert("task-1".to_string());
+            }
+
+            assert!(take_runtime_pending_sync_task("task-1", &state).await);
+            assert!(!take_runtime_pending_sync_task("task-1", &state).await);
+        });
+    }
+
+    #[test]
+    fn test_dequeue_runtime_sync_task_keeps_queue_set_consistent() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let state = build_app_state();
+
+        rt.block_on(async {
+            {
+                let mut queued = state.queued_sync_tasks.write().await;
+                queued.insert("task-1".to_string());
+            }
+            {
+                let mut queue = state.runtime_sync_queue.write().await;
+                queue.push_back("task-1".to_string());
+            }
+
+            let next = dequeue_runtime_sync_task(&state).await;
+            assert_eq!(next.as_deref(), Some("task-1"));
+
+            let queued = state.queued_sync_tasks.read().await;
+            assert!(!queued.contains("task-1"));
+            drop(queued);
+
+            let queue = state.runtime_sync_queue.read().await;
+            assert!(queue.is_empty());
+        });
+    }
+
+    #[test]
+    fn test_remove_runtime_sync_task_state_clears_pending_queue_and_set() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let state = build_app_state();
+
+        rt.block_on(async {
+            {
+                let mut pending = state.runtime_pending_sync_tasks.write().await;
+                pending.insert("task-1".to_string());
+            }
+            {
+                let mut queued = state.queued_sync_tasks.write().await;
+                queued.insert("task-1".to_string());
+            }
+            {
+                let mut queue = state.runtime_sync_queue.write().await;
+                queue.push_back("task-1".to_string());
+                queue.push_back("task-2".to_string());
+            }
+
+            remove_runtime_sync_task_state("task-1", &state).await;
+
+            let pending = state.runtime_pending_sync_tasks.read().await;
+            assert!(!pending.contains("task-1"));
+            drop(pending);
+
+            let queued = state.queued_sync_tasks.read().await;
+            assert!(!queued.contains("task-1"));
+            drop(queued);
+
+            let queue = state.runtime_sync_queue.read().await;
+            assert_eq!(
+                queue.iter().cloned().collect::<Vec<_>>(),
+                vec!["task-2".to_string()]
+            );
+        });
+    }
+
+    #[tokio::test]
+    async fn test_cancel_operation_internal_cancels_dry_run_token() {
+        let state = build_app_state();
+        let token = CancellationToken::new();
+
+        {
+            let mut tokens = state.dry_run_cancel_tokens.write().await;
+            tokens.insert("task-1".to_string(), token.clone());
+        }
+
+        let cancelled =
+            cancel_operation_internal("task-1", CancelOperationType::DryRun, &state).await;
+        assert!(cancelled);
+        assert!(token.is_cancelled());
+    }
+
+    #[tokio::test]
+    async fn test_cancel_operation_internal_cancels_sync_token() {
+        let state = build_app_state();
+        let token = CancellationToken::new();
+
+        {
+            let mut tokens = state.cancel_tokens.write().await;
+            tokens.insert("task-1".to_string(), token.clone());
+        }
+
+        let cancelled =
+            cancel_operation_internal("task-1", CancelOperationType::Sync, &state).await;
+        assert!(cancelled);
+        assert!(token.is_cancelled());
+    }
+
+    #[test]
+    fn test_validate_runtime_tasks_allows_distinct_sources_and_targets() {
+        let tasks = vec![
+            build_runtime_task_with_paths("a", "/src/a", "/dst/a", false),
+            build_runtime_task_with_paths("b", "/src/b", "/dst/b", false),
+        ];
+
+        assert!(validate_runtime_tasks(&tasks).is_ok());
+    }
+
+    #[test]
+    fn test_validate_runtime_tasks_rejects_duplicate_targets() {
+        let tasks = vec![
+            build_runtime_task_with_paths("a", "/src/a", "/dst/shared", false),
+            build_runtime_task_with_paths("b", "/src/b", "/dst/shared", false),
+        ];
+
+        let result = validate_runtime_tasks(&tasks);
+        assert!(result.is_err());
+        assert!(result
+            .err()
+            .unwrap_or_default()
+            .contains("Target path conflict"));
+    }
+
+    #[test]
+    fn test_validate_runtime_tasks_rejects_target_subdirectory_conflict() {
+        let tasks = vec![
+            build_runtime_task_with_paths("a", "/src/a", "/dst/root", false),
+            build_runtime_task_with_paths("b", "/src/b", "/dst/root/child", false),
+        ];
+
+        let result = validate_runtime_tasks(&tasks);
+        assert!(result.is_err());
+        assert!(result
+            .err()
+            .unwrap_or_default()
+            .contains("Target path conflict"));
+    }
+
+    #[test]
+    fn test_orphan_target_conflict_helper_returns_duplicate_target_for_selected_task() {
+        let tasks = vec![
+            build_runtime_task_with_paths("a", "/src/a", "/dst/shared", false),
+            build_runtime_task_with_paths("b", "/src/b", "/dst/shared", false),
+        ];
+
+        let issue = find_runtime_orphan_target_conflict_issue("b", &tasks)
+            .expect("helper should not error")
+            .expect("selected task should be blocked");
+
+        assert_eq!(issue.code, RuntimeTaskValidationCode::DuplicateTarget);
+        assert_eq!(issue.task_id.as_deref(), Some("b"));
+        assert_eq!(issue.conflicting_task_ids, vec!["a".to_string()]);
+    }
+
+    #[test]
+    fn test_orphan_target_conflict_helper_returns_nested_target_for_selected_task() {
+        let tasks = vec![
+            build_runtime_task_with_paths("a", "/src/a", "/dst/root", false),
+            build_runtime_task_with_paths("b", "/src/b", "/dst/root/child", false),
+        ];
+
+        let issue = find_runtime_orphan_target_conflict_issue("b", &tasks)
+            .expect("helper should not error")
+            .expect("selected task should be blocked");
+
+        assert_eq!(issue.code, RuntimeTaskValidationCode::TargetSubdirConflict);
+        assert_eq!(issue.task_id.as_deref(), Some("b"));
+        assert_eq!(issue.conflicting_task_ids, vec!["a".to_string()]);
+    }
+
+    #[test]
+    fn test_orphan_target_conflict_helper_ignores_unrelated_task_conflicts() {
+        let tasks = vec![
+            build_runtime_task_with_paths("a", "/src/a", "/dst/root", false),
+            build_runtime_task_with_paths("b", "/src/b", "/dst/root/child", false),
+            build_runtime_task_with_paths("c", "/src/c", "/dst/isolated", false),
+        ];
+
+        let issue = find_runtime_orphan_target_conflict_issue("c", &tasks)
+            .expect("helper should not error");
+
+        assert!(issue.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_find_orphan_files_internal_rejects_selected_task_target_conflict() {
+        let state = build_app_state();
+        state
+            .config_store
+            .save_tasks(&[
+                SyncTaskRecord {
+                    id: "task-a".to_string(),
+                    name: "Task A".to_string(),
+                    source: "/src/a".to_string(),
+                    target: "/dst/shared".to_string(),
+                    source_bookmark: None,
+                    source_network_mount: None,
+                    target_bookmark: None,
+                    target_network_mount: None,
+                    checksum_mode: false,
+                    verify_after_copy: true,
+                    exclusion_sets: Vec::new(),
+                    watch_mode: false,
+                    auto_unmount: false,
+                    source_type: Some(SourceType::Path),
+                    source_uuid: None,
+                    source_uuid_type: None,
+                    source_sub_path: None,
+                    source_identity: None,
+                    recurring_schedules: Vec::new(),
+                    enabled: true,
+                    notifications: TaskNotificationPrefs::default(),
+                    resource_group: None,
+                    max_coalesce_secs: None,
+                    max_duration_secs: None,
+                },
+                SyncTaskRecord {
+                    id: "task-b".to_string(),
+                    name: "Task B".to_string(),
+                    source: "/src/b".to_string(),
+                    target: "/dst/shared".to_string(),
+                    source_bookmark: None,
+                    source_network_mount: None,
+                    target_bookmark: None,
+                    target_network_mount: None,
+                    checksum_mode: false,
+                    verify_after_copy: true,
+                    exclusion_sets: Vec::new(),
+                    watch_mode: false,
+                    auto_unmount: false,
+                    source_type: Some(SourceType::Path),
+                    source_uuid: None,
+                    source_uuid_type: None,
+                    source_sub_path: None,
+                    source_identity: None,
+                    recurring_schedules: Vec::new(),
+                    enabled: true,
+                    notifications: TaskNotificationPrefs::default(),
+                    resource_group: None,
+                    max_coalesce_secs: None,
+                    max_duration_secs: None,
+                },
+            ])
+            .expect("tasks should save");
+
+        let result = find_orphan_files_internal(
+            "task-b".to_string(),
+            PathBuf::from("/src/b"),
+            PathBuf::from("/dst/shared"),
+            Vec::new(),
+            Default::default(),
+            &state,
+            None,
+        )
+        .await;
+
+        assert!(result.is_err());
+        assert!(result
+            .err()
+            .unwrap_or_default()
+            .contains("Orphan scan blocked"));
+    }
+
+    #[test]
+    fn test_validate_runtime_tasks_rejects_same_task_source_target_overlap() {
+        let tasks = vec![build_runtime_task_with_paths(
+            "a",
+            "/data/source",
+            "/data/source/sub",
+            false,
+        )];
+
+        let result = validate_runtime_tasks(&tasks);
+        assert!(result.is_err());
+        assert!(result
+            .err()
+            .unwrap_or_default()
+            .contains("overlapping source/target"));
+    }
+
+    #[test]
+    fn test_validate_runtime_tasks_allows_one_way_watch_chain() {
+        let tasks = vec![
+            build_runtime_task_with_paths("a", "/sdcard", "/camera/a7cr", true),
+            build_runtime_task_with_paths("b", "/camera", "/nas/camera", true),
+        ];
+
+        assert!(validate_runtime_tasks(&tasks).is_ok());
+    }
+
+    #[test]
+    fn test_validate_runtime_tasks_rejects_watch_cycle() {
+        let tasks = vec![
+            build_runtime_task_with_paths("a", "/watch/a", "/watch/b/import", true),
+            build_runtime_task_with_paths("b", "/watch/b", "/watch/a/export", true),
+        ];
+
+        let result = validate_runtime_tasks(&tasks);
+        assert!(result.is_err());
+        assert!(result
+            .err()
+            .unwrap_or_default()
+            .contains("Watch cycle detected"));
+    }
+
+    #[test]
+    fn test_validate_runtime_tasks_allows_manual_target_inside_watched_source() {
+        let tasks = vec![
+            build_runtime_task_with_paths("watch-a", "/media/incoming", "/backup/watch-a", true),
+            build_runtime_task_with_paths(
+                "manual-b",
+                "/src/manual",
+                "/media/incoming/export",
+                false,
+            ),
+        ];
+
+        assert!(validate_runtime_tasks(&tasks).is_ok());
+    }
+
+    #[test]
+    fn test_validate_runtime_tasks_allows_new_uuid_tokens() {
+        let tasks = vec![
+            build_runtime_task_with_paths("disk", "[DISK_UUID:disk-a]/DCIM", "/dst/disk", false),
+            build_runtime_task_with_paths(
+                "volume",
+                "[VOLUME_UUID:volume-a]/DCIM",
+                "/dst/volume",
+                false,
+            ),
+            build_runtime_task_with_paths("legacy", "[UUID:legacy-a]/DCIM", "/dst/legacy", false),
+        ];
+
+        assert!(validate_runtime_tasks(&tasks).is_ok());
+    }
+
+    #[test]
+    fn test_parse_uuid_source_path_handles_token_variants() {
+        let disk = parse_uuid_source_path("[DISK_UUID:disk-a]/DCIM/100");
+        assert!(disk.is_some());
+        let disk = disk.unwrap();
+        assert_eq!(disk.uuid, "disk-a");
+        assert_eq!(disk.sub_path, "/DCIM/100");
+
+        let volume = parse_uuid_source_path("[VOLUME_UUID:volume-a]/MOV");
+        assert!(volume.is_some());
+        let volume = volume.unwrap();
+        assert_eq!(volume.uuid, "volume-a");
+        assert_eq!(volume.sub_path, "/MOV");
+
+        let legacy = parse_uuid_source_path("[UUID:legacy-a]/RAW");
+        assert!(legacy.is_some());
+        let legacy = legacy.unwrap();
+        assert_eq!(legacy.uuid, "legacy-a");
+        assert_eq!(legacy.sub_path, "/RAW");
+    }
+
+    #[test]
+    fn test_collect_required_source_volumes_dedups_tokens_and_plain_paths() {
+        let tasks = vec![
+            build_runtime_task("disk-1", "[DISK_UUID:disk-a]/DCIM/100", false),
+            build_runtime_task("disk-2", "[DISK_UUID:disk-a]/DCIM/101", false),
+            build_runtime_task("volume", "[VOLUME_UUID:volume-b]/MOV", false),
+            build_runtime_task("legacy", "[UUID:volume-b]/RAW", false),
+            build_runtime_task("plain-1", "/Volumes/CARD/DCIM", false),
+            build_runtime_task("plain-2", "/Volumes/CARD", false),
+            build_runtime_task("unplugged", "/Volumes/OLD/Photos", false),
+            build_runtime_task("internal", "/Users/me/Pictures", false),
+            build_runtime_task("invalid", "[DISK_UUID:]/DCIM", false),
+        ];
+        let mut disk_volume = build_volume("SD", "/Volumes/SD");
+        disk_volume.disk_uuid = Some("disk-a".to_string());
+        let mut legacy_volume = build_volume("CAM", "/Volumes/CAM");
+        legacy_volume.volume_uuid = Some("volume-b".to_string());
+        let volumes = vec![
+            disk_volume,
+            legacy_volume,
+            build_volume("CARD", "/Volumes/CARD"),
+        ];
+
+        let required = collect_required_source_volumes(&tasks, &volumes);
+
+        let mounted = |name: &str, mount_point: &str| (Some(name), Some(mount_point));
+        let summary: Vec<(&str, &str, Vec<&str>, (Option<&str>, Option<&str>))> = required
+            .iter()
+            .map(|volume: &RequiredSourceVolume| {
+                assert_eq!(volume.mounted, volume.mount_point.is_some());
+                (
+                    volume.token_type.as_str(),
+                    volume.identifier.as_str(),
+                    volume.task_ids.iter().map(String::as_str).collect(),
+                    (
+                        volume.volume_name.as_deref(),
+                        volume.mount_point.as_deref().and_then(Path::to_str),
+                    ),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    "DISK_UUID",
+                    "disk-a",
+                    vec!["disk-1", "disk-2"],
+                    mounted("SD", "/Volumes/SD"),
+                ),
+                (
+                    "VOLUME_UUID",
+                    "volume-b",
+                    vec!["volume"],
+                    mounted("CAM", "/Volumes/CAM"),
+                ),
+                (
+                    "UUID",
+                    "volume-b",
+                    vec!["legacy"],
+                    mounted("CAM", "/Volumes/CAM"),
+                ),
+                (
+                    "PATH",
+                    "/Volumes/CARD",
+                    vec!["plain-1", "plain-2"],
+                    mounted("CARD", "/Volumes/CARD"),
+                ),
+                ("PATH", "/Volumes/OLD", vec!["unplugged"], (None, None)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_task_redundancy_warnings_flags_tasks_on_one_device() {
+        let mut card_a = build_volume("CARD_A", "/Volumes/CARD_A");
+        card_a.disk_uuid = Some("disk-card".to_string());
+        card_a.volume_uuid = Some("volume-card-a".to_string());
+        let mut card_b = build_volume("CARD_B", "/Volumes/CARD_B");
+        card_b.disk_uuid = Some("disk-card".to_string());
+        let mut backup = build_volume("Backup", "/Volumes/Backup");
+        backup.disk_uuid = Some("disk-backup".to_string());
+        let volumes = vec![card_a, card_b, backup];
+
+        let tasks = vec![
+            build_runtime_task_with_paths(
+                "same-volume",
+                "/Volumes/CARD_A/DCIM",
+                "/Volumes/CARD_A/Copy",
+                false,
+            ),
+            build_runtime_task_with_paths(
+                "same-disk",
+                "[VOLUME_UUID:volume-card-a]/DCIM",
+                "/Volumes/CARD_B/Copy",
+                false,
+            ),
+            build_runtime_task_with_paths(
+                "clean",
+                "/Volumes/CARD_A/DCIM",
+                "/Volumes/Backup/Photos",
+                false,
+            ),
+            build_runtime_task_with_paths(
+                "unmounted",
+                "[DISK_UUID:missing]/DCIM",
+                "/Volumes/Backup/Photos",
+                false,
+            ),
+        ];
+
+        let warnings = find_task_redundancy_warnings(&tasks, &volumes);
+        let summary: Vec<(&str, TaskRedundancyReason, &str, &str)> = warnings
+            .iter()
+            .map(|warning| {
+                (
+                    warning.task_id.as_str(),
+                    warning.reason,
+                    warning.source_volume.as_str(),
+                    warning.target_volume.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    "same-volume",
+                    TaskRedundancyReason::SameVolume,
+                    "CARD_A",
+                    "CARD_A"
+                ),
+                (
+                    "same-disk",
+                    TaskRedundancyReason::SameDisk,
+                    "CARD_A",
+                    "CARD_B"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_uuid_source_path_edge_cases() {
+        let empty_uuid = parse_uuid_source_path("[DISK_UUID:]/DCIM");
+        assert!(empty_uuid.is_some());
+        let empty_uuid = empty_uuid.unwrap();
+        assert_eq!(empty_uuid.uuid, "");
+        assert_eq!(empty_uuid.sub_path, "/DCIM");
+
+        assert!(parse_uuid_source_path("[DISK_UUID:abc/without-bracket").is_none());
+        assert!(parse_uuid_source_path("[CUSTOM_UUID:abc]/DCIM").is_none());
+    }
+
+    #[test]
+    fn test_find_runtime_task_validation_issue_returns_source_target_overlap_metadata() {
+        let tasks = vec![build_runtime_task_with_paths(
+            "a",
+            "/data/source",
+            "/data/source/sub",
+            false,
+        )];
+
+        let issue = find_runtime_task_validation_issue(&tasks).expect("expected issue");
+        assert_eq!(issue.code, RuntimeTaskValidationCode::SourceTargetOverlap);
+        assert_eq!(issue.task_id.as_deref(), Some("a"));
+        assert_eq!(issue.task_name.as_deref(), Some("task-a"));
+        assert_eq!(issue.source.as_deref(), Some("/data/source"));
+        assert_eq!(issue.target.as_deref(), Some("/data/source/sub"));
+        assert!(issue.conflicting_task_ids.is_empty());
+    }
+
+    #[test]
+    fn test_find_runtime_task_validation_issue_distinguishes_duplicate_target_and_subdir_conflict()
+    {
+        let duplicate_target_tasks = vec![
+            build_runtime_task_with_paths("a", "/src/a", "/dst/shared", false),
+            build_runtime_task_with_paths("b", "/src/b", "/dst/shared", false),
+        ];
+        let duplicate_issue =
+            find_runtime_task_validation_issue(&duplicate_target_tasks).expect("duplicate issue");
+        assert_eq!(
+            duplicate_issue.code,
+            RuntimeTaskValidationCode::DuplicateTarget
+        );
+        assert_eq!(duplicate_issue.task_id.as_deref(), Some("a"));
+        assert_eq!(duplicate_issue.conflicting_task_ids, vec!["b".to_string()]);
+
+        let nested_target_tasks = vec![
+            build_runtime_task_with_paths("a", "/src/a", "/dst/root", false),
+            build_runtime_task_with_paths("b", "/src/b", "/dst/root/child", false),
+        ];
+        let nested_issue =
+            find_runtime_task_validation_issue(&nested_target_tasks).expect("nested issue");
+        assert_eq!(
+            nested_issue.code,
+            RuntimeTaskValidationCode::TargetSubdirConflict
+        );
+        assert_eq!(nested_issue.task_id.as_deref(), Some("a"));
+        assert_eq!(nested_issue.conflicting_task_ids, vec!["b".to_string()]);
+    }
+
+    #[test]
+    fn test_find_runtime_watch_cycle_detects_longer_cycle() {
+        let tasks = vec![
+            build_runtime_task_with_paths("a", "/watch/a", "/watch/b/in", true),
+            build_runtime_task_with_paths("b", "/watch/b", "/watch/c/in", true),
+            build_runtime_task_with_paths("c", "/watch/c", "/watch/a/in", true),
+        ];
+
+        let validated = build_validated_runtime_tasks(&tasks).unwrap();
+        let cycle = find_runtime_watch_cycle(&validated);
+        assert!(cycle.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_mark_downstream_watch_tasks_settle_for_manual_target_overlap() {
+        let state = build_app_state();
+        {
+            let mut runtime_config = state.runtime_config.write().await;
+            runtime_config.tasks = vec![
+                build_runtime_task_with_paths(
+                    "watch-a",
+                    "/media/incoming",
+                    "/backup/watch-a",
+                    true,
+                ),
+                build_runtime_task_with_paths(
+                    "manual-b",
+                    "/src/manual",
+                    "/media/incoming/export",
+                    false,
+                ),
+            ];
+        }
+
+        mark_downstream_watch_tasks_settle_for_target("/media/incoming/export", &state).await;
+
+        let settle = state.runtime_chain_settle_until.read().await;
+        assert!(settle.get("watch-a").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_finish_runtime_producer_failure_blocks_downstream_before_removal() {
+        let state = build_app_state();
+
+        {
+            let mut runtime_config = state.runtime_config.write().await;
+            runtime_config.tasks = vec![
+                build_runtime_task_with_paths(
+                    "watch-a",
+                    "/media/incoming",
+                    "/backup/watch-a",
+                    true,
+                ),
+                build_runtime_task_with_paths(
+                    "manual-b",
+                    "/src/manual",
+                    "/media/incoming/export",
+                    false,
+                ),
+            ];
+        }
+
+        {
+            let mut queued = state.queued_sync_tasks.write().await;
+            queued.insert("watch-a".to_string());
+        }
+        {
+            let mut queue = state.runtime_sync_queue.write().await;
+            queue.push_back("watch-a".to_string());
+        }
+        {
+            let mut pending = state.runtime_pending_sync_tasks.write().await;
+            pending.insert("watch-a".to_string());
+        }
+        {
+            let mut producers = state.runtime_active_producers.write().await;
+            producers.insert(
+                "sync:manual:task-b".to_string(),
+                RuntimeActiveProducer {
+                    producer_id: "sync:manual:task-b".to_string(),
+                    kind: RuntimeProducerKind::ManualSync,
+                    target_key: "/media/incoming/export".to_string(),
+                },
+            );
+        }
+
+        finish_runtime_producer(
+            "sync:manual:task-b",
+            "/media/incoming/export",
+            false,
+            None,
+            &state,
+        )
+        .await;
+
+        let queued = state.queued_sync_tasks.read().await;
+        assert!(!queued.contains("watch-a"));
+        drop(queued);
+
+        let queue = state.runtime_sync_queue.read().await;
+        assert!(!queue.iter().any(|task_id| task_id == "watch-a"));
+        drop(queue);
+
+        let pending = state.runtime_pending_sync_tasks.read().await;
+        assert!(!pending.contains("watch-a"));
+        drop(pending);
+
+        let settle = state.runtime_chain_settle_until.read().await;
+        assert!(settle.get("watch-a").is_none());
+        drop(settle);
+
+        let producers = state.runtime_active_producers.read().await;
+        assert!(!producers.contains_key("sync:manual:task-b"));
+    }
+
+    #[tokio::test]
+    async fn test_finish_runtime_producer_success_marks_settle_before_removal() {
+        let state = build_app_state();
+
+        {
+            let mut runtime_config = state.runtime_config.write().await;
+            runtime_config.tasks = vec![
+                build_runtime_task_with_paths(
+                    "watch-a",
+                    "/media/incoming",
+                    "/backup/watch-a",
+                    true,
+                ),
+                build_runtime_task_with_paths(
+                    "manual-b",
+                    "/src/manual",
+                    "/media/incoming/export",
+                    false,
+                ),
+            ];
+        }
+
+        {
+            let mut queued = state.queued_sync_tasks.write().await;
+            queued.insert("watch-a".to_string());
+        }
+        {
+            let mut queue = state.runtime_sync_queue.write().await;
+            queue.push_back("watch-a".to_string());
+        }
+        {
+            let mut pending = state.runtime_pending_sync_tasks.write().await;
+            pending.insert("watch-a".to_string());
+        }
+        {
+            let mut producers = state.runtime_active_producers.write().await;
+            producers.insert(
+                "sync:manual:task-b".to_string(),
+                RuntimeActiveProducer {
+                    producer_id: "sync:manual:task-b".to_string(),
+                    kind: RuntimeProducerKind::ManualSync,
+                    target_key: "/media/incoming/export".to_string(),
+                },
+            );
+        }
+
+        finish_runtime_producer(
+            "sync:manual:task-b",
+            "/media/incoming/export",
+            true,
+            None,
+            &state,
+        )
+        .await;
+
+        let queued = state.queued_sync_tasks.read().await;
+        assert!(queued.contains("watch-a"));
+        drop(queued);
+
+        let queue = state.runtime_sync_queue.read().await;
+        assert!(queue.iter().any(|task_id| task_id == "watch-a"));
+        drop(queue);
+
+        let pending = state.runtime_pending_sync_tasks.read().await;
+        assert!(pending.contains("watch-a"));
+        drop(pending);
+
+        let settle = state.runtime_chain_settle_until.read().await;
+        assert!(settle.get("watch-a").is_some());
+        drop(settle);
+
+        let producers = state.runtime_active_producers.read().await;
+        assert!(!producers.contains_key("sync:manual:task-b"));
+    }
+
+    #[test]
+    fn test_validate_runtime_tasks_allows_manual_target_inside_watch_source() {
+        let tasks = vec![
+            build_runtime_task_with_paths("watch-a", "/media/incoming", "/backup/watch-a", true),
+            build_runtime_task_with_paths(
+                "manual-b",
+                "/src/manual",
+                "/media/incoming/export",
+                false,
+            ),
+        ];
+
+        assert!(validate_runtime_tasks(&tasks).is_ok());
+    }
+
+    #[test]
+    fn test_validate_runtime_tasks_rejects_two_node_watch_cycle() {
+        let tasks = vec![
+            build_runtime_task_with_paths("a", "/src/a", "/loop/target", true),
+            build_runtime_task_with_paths("b", "/loop/target/inbound", "/src/a/return", true),
+        ];
+
+        let result = validate_runtime_tasks(&tasks);
+        assert!(result.is_err());
+        assert!(result
+            .err()
+            .unwrap_or_default()
+            .contains("Watch cycle detected"));
+    }
+
+    #[test]
+    fn test_validate_runtime_tasks_rejects_longer_watch_cycle() {
+        let tasks = vec![
+            build_runtime_task_with_paths("a", "/src/a", "/chain/b", true),
+            build_runtime_task_with_paths("b", "/chain/b/in", "/chain/c", true),
+            build_runtime_task_with_paths("c", "/chain/c/in", "/src/a/back", true),
+        ];
+
+        let result = validate_runtime_tasks(&tasks);
+        assert!(result.is_err());
+        assert!(result
+            .err()
+            .unwrap_or_default()
+            .contains("Watch cycle detected"));
+    }
+
+    #[test]
+    fn test_find_runtime_task_validation_issue_returns_watch_cycle_metadata() {
+        let tasks = vec![
+            build_runtime_task_with_paths("a", "/src/a", "/chain/b", true),
+            build_runtime_task_with_paths("b", "/chain/b/in", "/chain/c", true),
+            build_runtime_task_with_paths("c", "/chain/c/in", "/src/a/back", true),
+        ];
+
+        let issue = find_runtime_task_validation_issue(&tasks).expect("cycle issue");
+        assert_eq!(issue.code, RuntimeTaskValidationCode::WatchCycle);
+        let mut involved_ids = issue.conflicting_task_ids.clone();
+        if let Some(task_id) = issue.task_id.clone() {
+            involved_ids.push(task_id);
+        }
+        involved_ids.sort();
+        assert_eq!(
+            involved_ids,
+            vec!["a".to_string(), "b".to_string(), "c".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_validate_runtime_tasks_allows_case_insensitive_parent_child_watch_chain() {
+        let tasks = vec![
+            build_runtime_task_with_paths(
+                "photo-save",
+                "[VOLUME_UUID:07497716-6027-3FE9-B418-6BEA262C702F1]/",
+                "/VoLumes/EV0990/Camera/a7cr",
+                true,
+            ),
+            build_runtime_task_with_paths(
+                "camera-nas",
+                "/Volumes/ev0990/camera",
+                "/Volumes/kimjeongjin/camera",
+                true,
+            ),
+        ];
+
+        assert!(validate_runtime_tasks(&tasks).is_ok());
+    }
+
+    #[test]
+    fn test_compute_task_queue_position_counts_tasks_ahead_and_eta() {
+        let queue: VecDeque<String> = ["task-a", "task-b", "task-c", "task-d"]
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        let syncing: HashSet<String> = HashSet::from(["task-running".to_string()]);
+        let durations: HashMap<String, Duration> = HashMap::from([
+            ("task-a".to_string(), Duration::from_secs(60)),
+            ("task-b".to_string(), Duration::from_secs(20)),
+            ("task-c".to_string(), Duration::from_secs(40)),
+        ]);
+
+        let third = compute_task_queue_position("task-c", &queue, &syncing, &durations);
+        assert_eq!(third.status, TaskQueueStatus::Queued);
+        assert_eq!(third.position, Some(2));
+        assert_eq!(third.tasks_ahead, Some(2));
+        assert_eq!(
+            third.eta_to_start_ms,
+            Some(80_000 / RUNTIME_SYNC_MAX_CONCURRENCY as u64)
+        );
+
+        let first = compute_task_queue_position("task-a", &queue, &syncing, &durations);
+        assert_eq!(first.position, Some(0));
+        assert_eq!(first.tasks_ahead, Some(0));
+        assert_eq!(first.eta_to_start_ms, Some(0));
+
+        let mut partial = durations.clone();
+        partial.remove("task-b");
+        let unknown = compute_task_queue_position("task-d", &queue, &syncing, &partial);
+        assert_eq!(unknown.position, Some(3));
+        assert_eq!(unknown.eta_to_start_ms, None);
+
+        let running = compute_task_queue_position("task-running", &queue, &syncing, &durations);
+        assert_eq!(running.status, TaskQueueStatus::Running);
+        assert_eq!(running.position, None);
+        assert_eq!(running.tasks_ahead, None);
+
+        let idle = compute_task_queue_position("task-idle", &queue, &syncing, &durations);
+        assert_eq!(idle.status, TaskQueueStatus::NotQueued);
+        assert_eq!(idle.eta_to_start_ms, None);
+    }
+
+    #[test]
+    fn test_select_runtime_dispatch_candidate_blocks_active_manual_producer() {
+        let queue = VecDeque::from([String::from("watch-a")]);
+        let queued_set = HashSet::from([String::from("watch-a")]);
+        let syncing = HashSet::new();
+        let watch_upstreams = HashMap::new();
+        let source_keys =
+            HashMap::from([(String::from("watch-a"), String::from("/media/incoming"))]);
+        let active_producers = HashMap::from([(
+            String::from("sync:manual:task-b"),
+            RuntimeActiveProducer {
+                producer_id: String::from("sync:manual:task-b"),
+                kind: RuntimeProducerKind::ManualSync,
+                target_key: String::from("/media/incoming/export"),
+            },
+        )]);
+        let settle_until = HashMap::new();
+
+        let selection = select_runtime_dispatch_candidate(
+            &queue,
+            &queued_set,
+            &syncing,
+            &watch_upstreams,
+            &source_keys,
+            &active_producers,
+            &settle_until,
+            &HashSet::new(),
+            Instant::now(),
+        );
+
+        assert!(selection.candidate_task_id.is_none());
+        assert!(selection.next_deadline.is_none());
+    }
+
+    #[test]
+    fn test_select_runtime_dispatch_candidate_blocks_active_conflict_producer() {
+        let queue = VecDeque::from([String::from("watch-a")]);
+        let queued_set = HashSet::from([String::from("watch-a")]);
+        let syncing = HashSet::new();
+        let watch_upstreams = HashMap::new();
+        let source_keys =
+            HashMap::from([(String::from("watch-a"), String::from("/media/incoming"))]);
+        let active_producers = HashMap::from([(
+            String::from("conflict:force-copy:session-1:item-1"),
+            RuntimeActiveProducer {
+                producer_id: String::from("conflict:force-copy:session-1:item-1"),
+                kind: RuntimeProducerKind::ConflictForceCopy,
+                target_key: String::from("/media/incoming/export/file.jpg"),
+            },
+        )]);
+
+        let selection = select_runtime_dispatch_candidate(
+            &queue,
+            &queued_set,
+            &syncing,
+            &watch_upstreams,
+            &source_keys,
+            &active_producers,
+            &HashMap::new(),
+            &HashSet::new(),
+            Instant::now(),
+        );
+
+        assert!(selection.candidate_task_id.is_none());
+    }
+
+    #[test]
+    fn test_select_runtime_dispatch_candidate_waits_for_settle_then_runs() {
+        let queue = VecDeque::from([String::from("watch-a"), String::from("watch-b")]);
+        let queued_set = HashSet::from([String::from("watch-a"), String::from("watch-b")]);
+        let syncing = HashSet::new();
+        let watch_upstreams = HashMap::new();
+        let source_keys = HashMap::from([
+            (String::from("watch-a"), String::from("/media/incoming")),
+            (String::from("watch-b"), String::from("/other/source")),
+        ]);
+        let active_producers = HashMap::new();
+        let future_deadline = Instant::now() + Duration::from_millis(200);
+        let settle_until = HashMap::from([(String::from("watch-a"), future_deadline)]);
+
+        let selection = select_runtime_dispatch_candidate(
+            &queue,
+            &queued_set,
+            &syncing,
+            &watch_upstreams,
+            &source_keys,
+            &active_producers,
+            &settle_until,
+            &HashSet::new(),
+            Instant::now(),
+        );
+
+        assert_eq!(selection.candidate_task_id.as_deref(), Some("watch-b"));
+        assert_eq!(selection.next_deadline, Some(future_deadline));
+
+        let selection_after = select_runtime_dispatch_candidate(
+            &VecDeque::from([String::from("watch-a")]),
+            &HashSet::from([String::from("watch-a")]),
+            &HashSet::new(),
+            &HashMap::new(),
+            &HashMap::from([(String::from("watch-a"), String::from("/media/incoming"))]),
+            &HashMap::new(),
+            &HashMap::from([(
+                String::from("watch-a"),
+                Instant::now() - Duration::from_millis(1),
+            )]),
+            &HashSet::new(),
+            Instant::now(),
+        );
+
+        assert_eq!(
+            selection_after.candidate_task_id.as_deref(),
+            Some("watch-a")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_acquire_runtime_sync_slot_serializes_same_resource_group() {
+        let state = build_app_state();
+
+        assert_eq!(
+            acquire_runtime_sync_slot("task-a", "/volumes/backup", &state).await,
+            RuntimeSyncAcquireResult::Acquired
+        );
+        assert_eq!(
+            acquire_runtime_sync_slot("task-b", "/volumes/backup", &state).await,
+            RuntimeSyncAcquireResult::ResourceGroupBusy
+        );
+        assert_eq!(
+            acquire_runtime_sync_slot("task-c", "/volumes/archive", &state).await,
+            RuntimeSyncAcquireResult::Acquired
+        );
+
+        release_sync_slot("task-a", &state).await;
+
+        assert_eq!(
+            acquire_runtime_sync_slot("task-b", "/volumes/backup", &state).await,
+            RuntimeSyncAcquireResult::Acquired
+        );
+        let syncing = state.syncing_tasks.read().await;
+        assert!(syncing.contains("task-b"));
+        assert!(syncing.contains("task-c"));
+    }
+
+    #[tokio::test]
+    async fn test_resource_group_defaults_to_target_device_and_persists() {
+        let root = tempdir().expect("temp dir should create");
+        let existing = root.path().join("existing");
+        std::fs::create_dir_all(&existing).unwrap();
+        let missing = root.path().join("not-yet/created");
+        let device_group = resolve_runtime_resource_group(None, &existing.to_string_lossy());
+        assert!(device_group.starts_with("dev:"));
+        assert_eq!(
+            resolve_runtime_resource_group(None, &missing.to_string_lossy()),
+            device_group
+        );
+        assert_eq!(
+            resolve_runtime_resource_group(None, "/Volumes/Backup/photos"),
+            "/volumes/backup"
+        );
+        assert_eq!(
+            resolve_runtime_resource_group(Some(" nas "), &existing.to_string_lossy()),
+            "nas"
+        );
+
+        let state = build_app_state();
+        let mut task = build_path_task("a", "/tmp/group-src", "/tmp/group-dst", false);
+        task.resource_group = Some("nas".to_string());
+        state.config_store.save_tasks(&[task.clone()]).unwrap();
+        let runtime = load_canonical_runtime_payload(&state).await.unwrap();
+        assert_eq!(runtime.tasks[0].resource_group.as_deref(), Some("nas"));
+
+        let cleared = apply_sync_task_update(
+            task,
+            &UpdateSyncTaskRequest {
+                task_id: "a".to_string(),
+                resource_group: Some("  ".to_string()),
+                ..UpdateSyncTaskRequest::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(cleared.resource_group, None);
+    }
+
+    #[test]
+    fn test_select_runtime_dispatch_candidate_skips_busy_resource_group() {
+        let mut explicit_group =
+            build_runtime_task_with_paths("watch-d", "/Volumes/CardD", "/Users/me/d", true);
+        explicit_group.resource_group = Some(" slow-nas ".to_string());
+        let tasks = vec![
+            build_runtime_task_with_paths("watch-a", "/Volumes/CardA", "/Volumes/Backup/a", true),
+            build_runtime_task_with_paths("watch-b", "/Volumes/CardB", "/Volumes/Backup/b", true),
+            build_runtime_task_with_paths("watch-c", "/Volumes/CardC", "/Volumes/Archive/c", true),
+            explicit_group,
+        ];
+        let validated = build_validated_runtime_tasks(&tasks).unwrap();
+        let groups: HashMap<&str, &str> = validated
+            .iter()
+            .map(|task| (task.id.as_str(), task.resource_group.as_str()))
+            .collect();
+        assert_eq!(groups["watch-a"], "/volumes/backup");
+        assert_eq!(groups["watch-b"], "/volumes/backup");
+        assert_eq!(groups["watch-c"], "/volumes/archive");
+        assert_eq!(groups["watch-d"], "slow-nas");
+
+        let busy_groups =
+            HashMap::from([(String::from("/volumes/backup"), String::from("watch-a"))]);
+        let blocked = runtime_tasks_blocked_by_resource_group(&validated, &busy_groups);
+        assert_eq!(blocked, HashSet::from([String::from("watch-b")]));
+
+        let source_keys: HashMap<String, String> = validated
+            .iter()
+            .map(|task| (task.id.clone(), task.source_key.clone()))
+            .collect();
+        let queue = VecDeque::from([String::from("watch-b"), String::from("watch-c")]);
+        let queued_set = HashSet::from([String::from("watch-b"), String::from("watch-c")]);
+        let syncing = HashSet::from([String::from("watch-a")]);
+
+        let selection = select_runtime_dispatch_candidate(
+            &queue,
+            &queued_set,
+            &syncing,
+            &HashMap::new(),
+            &source_keys,
+            &HashMap::new(),
+            &HashMap::new(),
+            &blocked,
+            Instant::now(),
+        );
+
+        assert_eq!(selection.candidate_task_id.as_deref(), Some("watch-c"));
+    }
+
+    #[test]
+    fn test_select_runtime_dispatch_candidate_blocks_queued_watch_upstream() {
+        let tasks = vec![
+            build_runtime_task_with_paths("watch-a", "/sdcard", "/camera/a7cr", true),
+            build_runtime_task_with_paths("watch-b", "/camera", "/nas/camera", true),
+        ];
+        let validated = build_validated_runtime_tasks(&tasks).unwrap();
+        let upstreams = build_runtime_watch_upstreams(&validated);
+        let queue = VecDeque::from([String::from("watch-b"), String::from("watch-a")]);
+        let queued_set = HashSet::from([String::from("watch-b"), String::from("watch-a")]);
+        let syncing = HashSet::new();
+        let source_keys = HashMap::from([
+            (String::from("watch-a"), String::from("/sdcard")),
+            (String::from("watch-b"), String::from("/camera")),
+        ]);
+
+        let selection = select_runtime_dispatch_candidate(
+            &queue,
+            &queued_set,
+            &syncing,
+            &upstreams,
+            &source_keys,
+            &HashMap::new(),
+            &HashMap::new(),
+            &HashSet::new(),
+            Instant::now(),
+        );
+
+        assert_eq!(selection.candidate_task_id.as_deref(), Some("watch-a"));
+    }
+
+    #[test]
+    fn test_source_recommendation_matches_by_device_serial() {
+        let task = build_uuid_task(
+            "task-1",
+            SourceUuidType::Disk,
+            "missing-disk",
+            "/DCIM",
+            Some(SourceIdentitySnapshot {
+                device_serial: Some("SERIAL-1".to_string()),
+                ..SourceIdentitySnapshot::default()
+            }),
+        );
+        let mut candidate = build_volume("Card A", "/Volumes/CardA");
+        candidate.disk_uuid = Some("disk-new".to_string());
+        candidate.device_serial = Some("SERIAL-1".to_string());
+
+        let recommendation = find_task_source_recommendation(&task, &[candidate]).unwrap();
+        assert_eq!(recommendation.proposed_uuid, "disk-new");
+        assert_eq!(recommendation.proposed_uuid_type, "disk");
+        assert_eq!(recommendation.confidence_label, "high");
+        assert!(recommendation
+            .evidence
+            .iter()
+            .any(|item| item == "device serial matched"));
+    }
+
+    #[test]
+    fn test_source_recommendation_matches_by_device_guid() {
+        let task = build_uuid_task(
+            "task-1",
+            SourceUuidType::Disk,
+            "missing-disk",
+            "/",
+            Some(SourceIdentitySnapshot {
+                device_guid: Some("GUID-1".to_string()),
+                ..SourceIdentitySnapshot::default()
+            }),
+        );
+        let mut candidate = build_volume("Card A", "/Volumes/CardA");
+        candidate.disk_uuid = Some("disk-new".to_string());
+        candidate.device_guid = Some("GUID-1".to_string());
+
+        let recommendation = find_task_source_recommendation(&task, &[candidate]).unwrap();
+        assert!(recommendation
+            .evidence
+            .iter()
+            .any(|item| item == "device GUID matched"));
+    }
+
+    #[test]
+    fn test_source_recommendation_matches_by_media_uuid() {
+        let task = build_uuid_task(
+            "task-1",
+            SourceUuidType::Disk,
+            "missing-disk",
+            "/",
+            Some(SourceIdentitySnapshot {
+                media_uuid: Some("MEDIA-1".to_string()),
+                ..SourceIdentitySnapshot::default()
+            }),
+        );
+        let mut candidate = build_volume("Card A", "/Volumes/CardA");
+        candidate.disk_uuid = Some("disk-new".to_string());
+        candidate.media_uuid = Some("MEDIA-1".to_string());
 
-        let issue = find_runtime_orphan_target_conflict_issue("c", &tasks)
-            .expect("helper should not error");
+        let recommendation = find_task_source_recommendation(&task, &[candidate]).unwrap();
+        assert!(recommendation
+            .evidence
+            .iter()
+            .any(|item| item == "media UUID matched"));
+    }
+
+    #[test]
+    fn test_source_recommendation_resolves_stale_volume_uuid_via_device_serial() {
+        let task = build_uuid_task(
+            "task-1",
+            SourceUuidType::Volume,
+            "old-volume",
+            "/DCIM",
+            Some(SourceIdentitySnapshot {
+                device_serial: Some("SERIAL-1".to_string()),
+                ..SourceIdentitySnapshot::default()
+            }),
+        );
+        let mut candidate = build_volume("Card A", "/Volumes/CardA");
+        candidate.volume_uuid = Some("volume-new".to_string());
+        candidate.device_serial = Some("SERIAL-1".to_string());
+
+        let recommendation = find_task_source_recommendation(&task, &[candidate]).unwrap();
+        assert_eq!(recommendation.proposed_uuid, "volume-new");
+        assert_eq!(recommendation.proposed_uuid_type, "volume");
+    }
+
+    #[test]
+    fn test_source_recommendation_preserves_parsed_sub_path_when_field_missing() {
+        let mut task = build_uuid_task(
+            "task-1",
+            SourceUuidType::Disk,
+            "missing-disk",
+            "/DCIM",
+            Some(SourceIdentitySnapshot {
+                device_serial: Some("SERIAL-1".to_string()),
+                ..SourceIdentitySnapshot::default()
+            }),
+        );
+        task.source_sub_path = None;
+        let mut candidate = build_volume("Card A", "/Volumes/CardA");
+        candidate.disk_uuid = Some("disk-new".to_string());
+        candidate.device_serial = Some("SERIAL-1".to_string());
+
+        let recommendation = find_task_source_recommendation(&task, &[candidate]).unwrap();
+        assert_eq!(recommendation.suggested_source, "[DISK_UUID:disk-new]/DCIM");
+    }
+
+    #[test]
+    fn test_source_recommendation_does_not_match_transport_serial_alone() {
+        let task = build_uuid_task(
+            "task-1",
+            SourceUuidType::Disk,
+            "missing-disk",
+            "/",
+            Some(SourceIdentitySnapshot {
+                transport_serial: Some("USB-BRIDGE".to_string()),
+                ..SourceIdentitySnapshot::default()
+            }),
+        );
+        let mut candidate = build_volume("Card A", "/Volumes/CardA");
+        candidate.disk_uuid = Some("disk-new".to_string());
+        candidate.transport_serial = Some("USB-BRIDGE".to_string());
+
+        assert!(find_task_source_recommendation(&task, &[candidate]).is_none());
+    }
+
+    #[test]
+    fn test_source_recommendation_continues_past_ambiguous_device_serial() {
+        let task = build_uuid_task(
+            "task-1",
+            SourceUuidType::Disk,
+            "missing-disk",
+            "/",
+            Some(SourceIdentitySnapshot {
+                device_serial: Some("SERIAL-1".to_string()),
+                media_uuid: Some("MEDIA-UNIQUE".to_string()),
+                ..SourceIdentitySnapshot::default()
+            }),
+        );
+        let mut first = build_volume("Card A", "/Volumes/CardA");
+        first.disk_uuid = Some("disk-a".to_string());
+        first.device_serial = Some("SERIAL-1".to_string());
+        first.media_uuid = Some("MEDIA-UNIQUE".to_string());
+
+        let mut second = build_volume("Card B", "/Volumes/CardB");
+        second.disk_uuid = Some("disk-b".to_string());
+        second.device_serial = Some("SERIAL-1".to_string());
+        second.media_uuid = Some("MEDIA-OTHER".to_string());
+
+        let recommendation = find_task_source_recommendation(&task, &[first, second]).unwrap();
+        assert_eq!(recommendation.proposed_uuid, "disk-a");
+        assert!(recommendation
+            .evidence
+            .iter()
+            .any(|item| item == "media UUID matched"));
+    }
+
+    #[test]
+    fn test_source_recommendation_uses_unique_composite_fallback() {
+        let task = build_uuid_task(
+            "task-1",
+            SourceUuidType::Disk,
+            "missing-disk",
+            "/DCIM",
+            Some(SourceIdentitySnapshot {
+                total_bytes: Some(512),
+                filesystem_name: Some("ExFAT".to_string()),
+                volume_name: Some("Untitled".to_string()),
+                transport_serial: Some("USB-BRIDGE".to_string()),
+                ..SourceIdentitySnapshot::default()
+            }),
+        );
+        let mut candidate = build_volume("Untitled", "/Volumes/Untitled");
+        candidate.total_bytes = Some(512);
+        candidate.filesystem_name = Some("ExFAT".to_string());
+        candidate.transport_serial = Some("USB-BRIDGE".to_string());
+        candidate.disk_uuid = Some("disk-new".to_string());
+
+        let recommendation = find_task_source_recommendation(&task, &[candidate]).unwrap();
+        assert_eq!(recommendation.confidence_label, "medium");
+        assert!(recommendation
+            .evidence
+            .iter()
+            .any(|item| item == "capacity matched (512 bytes)"));
+        assert!(recommendation
+            .evidence
+            .iter()
+            .any(|item| item == "transport serial matched"));
+    }
+
+    #[test]
+    fn test_source_recommendation_skips_ambiguous_composite_matches() {
+        let task = build_uuid_task(
+            "task-1",
+            SourceUuidType::Disk,
+            "missing-disk",
+            "/",
+            Some(SourceIdentitySnapshot {
+                total_bytes: Some(512),
+                filesystem_name: Some("ExFAT".to_string()),
+                volume_name: Some("Untitled".to_string()),
+                ..SourceIdentitySnapshot::default()
+            }),
+        );
+        let mut first = build_volume("Untitled", "/Volumes/CardA");
+        first.total_bytes = Some(512);
+        first.filesystem_name = Some("ExFAT".to_string());
+        first.disk_uuid = Some("disk-a".to_string());
+
+        let mut second = build_volume("Untitled", "/Volumes/CardB");
+        second.total_bytes = Some(512);
+        second.filesystem_name = Some("ExFAT".to_string());
+        second.disk_uuid = Some("disk-b".to_string());
+
+        assert!(find_task_source_recommendation(&task, &[first, second]).is_none());
+    }
+
+    #[test]
+    fn test_accepting_recommendation_rewrites_uuid_fields_and_refreshes_identity() {
+        let original_task = build_uuid_task(
+            "task-1",
+            SourceUuidType::Disk,
+            "old-disk",
+            "/DCIM",
+            Some(SourceIdentitySnapshot {
+                device_serial: Some("SERIAL-OLD".to_string()),
+                ..SourceIdentitySnapshot::default()
+            }),
+        );
+        let update = UpdateSyncTaskRequest {
+            task_id: original_task.id.clone(),
+            source: Some("[VOLUME_UUID:new-volume]/DCIM".to_string()),
+            source_uuid: Some("new-volume".to_string()),
+            source_uuid_type: Some(SourceUuidType::Volume),
+            source_sub_path: Some("/DCIM".to_string()),
+            ..UpdateSyncTaskRequest::default()
+        };
+        let mut updated_task = apply_sync_task_update(original_task, &update).unwrap();
+
+        let mut volume = build_volume("Card A", "/Volumes/CardA");
+        volume.volume_uuid = Some("new-volume".to_string());
+        volume.disk_uuid = Some("disk-current".to_string());
+        volume.device_serial = Some("SERIAL-NEW".to_string());
+        volume.filesystem_name = Some("ExFAT".to_string());
+        volume.total_bytes = Some(1024);
+
+        refresh_uuid_source_identity(&mut updated_task, &[volume]);
+
+        assert_eq!(updated_task.source_uuid.as_deref(), Some("new-volume"));
+        assert_eq!(updated_task.source_uuid_type, Some(SourceUuidType::Volume));
+        let identity = updated_task.source_identity.unwrap();
+        assert_eq!(
+            identity.last_seen_volume_uuid.as_deref(),
+            Some("new-volume")
+        );
+        assert_eq!(
+            identity.last_seen_disk_uuid.as_deref(),
+            Some("disk-current")
+        );
+        assert_eq!(identity.device_serial.as_deref(), Some("SERIAL-NEW"));
+    }
+
+    #[test]
+    fn test_retargeting_uuid_source_clears_stale_identity_when_new_media_missing() {
+        let original_task = build_uuid_task(
+            "task-1",
+            SourceUuidType::Disk,
+            "old-disk",
+            "/DCIM",
+            Some(SourceIdentitySnapshot {
+                device_serial: Some("SERIAL-OLD".to_string()),
+                last_seen_disk_uuid: Some("old-disk".to_string()),
+                ..SourceIdentitySnapshot::default()
+            }),
+        );
+        let update = UpdateSyncTaskRequest {
+            task_id: original_task.id.clone(),
+            source: Some("[VOLUME_UUID:new-volume]/DCIM".to_string()),
+            source_uuid: Some("new-volume".to_string()),
+            source_uuid_type: Some(SourceUuidType::Volume),
+            source_sub_path: Some("/DCIM".to_string()),
+            ..UpdateSyncTaskRequest::default()
+        };
+        let mut updated_task = apply_sync_task_update(original_task, &update).unwrap();
+
+        assert!(updated_task.source_identity.is_none());
+
+        refresh_uuid_source_identity(&mut updated_task, &[]);
+        assert!(updated_task.source_identity.is_none());
+    }
+
+    #[test]
+    fn test_non_source_updates_preserve_existing_uuid_identity() {
+        let original_task = build_uuid_task(
+            "task-1",
+            SourceUuidType::Disk,
+            "old-disk",
+            "/DCIM",
+            Some(SourceIdentitySnapshot {
+                device_serial: Some("SERIAL-OLD".to_string()),
+                last_seen_disk_uuid: Some("old-disk".to_string()),
+                ..SourceIdentitySnapshot::default()
+            }),
+        );
+        let update = UpdateSyncTaskRequest {
+            task_id: original_task.id.clone(),
+            name: Some("Renamed task".to_string()),
+            ..UpdateSyncTaskRequest::default()
+        };
+        let mut updated_task = apply_sync_task_update(original_task, &update).unwrap();
 
-        assert!(issue.is_none());
-    }
+        refresh_uuid_source_identity(&mut updated_task, &[]);
 
-    #[tokio::test]
-    async fn test_find_orphan_files_internal_rejects_selected_task_target_conflict() {
-        let state = build_app_state();
-        state
-            .config_store
-            .save_tasks(&[
-                SyncTaskRecord {
-                    id: "task-a".to_string(),
-                    name: "Task A".to_string(),
-                    source: "/src/a".to_string(),
-                    target: "/dst/shared".to_string(),
-                    source_bookmark: None,
-                    source_network_mount: None,
-                    target_bookmark: None,
-                    target_network_mount: None,
-                    checksum_mode: false,
-                    verify_after_copy: true,
-                    exclusion_sets: Vec::new(),
-                    watch_mode: false,
-                    auto_unmount: false,
-                    source_type: Some(SourceType::Path),
-                    source_uuid: None,
-                    source_uuid_type: None,
-                    source_sub_path: None,
-                    source_identity: None,
-                    recurring_schedules: Vec::new(),
-                },
-                SyncTaskRecord {
-                    id: "task-b".to_string(),
-                    name: "Task B".to_string(),
-                    source: "/src/b".to_string(),
-                    target: "/dst/shared".to_string(),
-                    source_bookmark: None,
-                    source_network_mount: None,
-                    target_bookmark: None,
-                    target_network_mount: None,
-                    checksum_mode: false,
-                    verify_after_copy: true,
-                    exclusion_sets: Vec::new(),
-                    watch_mode: false,
-                    auto_unmount: false,
-                    source_type: Some(SourceType::Path),
-                    source_uuid: None,
-                    source_uuid_type: None,
-                    source_sub_path: None,
-                    source_identity: None,
-                    recurring_schedules: Vec::new(),
-                },
-            ])
-            .expect("tasks should save");
+        let identity = updated_task.source_identity.unwrap();
+        assert_eq!(identity.device_serial.as_deref(), Some("SERIAL-OLD"));
+        assert_eq!(identity.last_seen_disk_uuid.as_deref(), Some("old-disk"));
+    }
 
-        let result = find_orphan_files_internal(
-            "task-b".to_string(),
-            PathBuf::from("/src/b"),
-            PathBuf::from("/dst/shared"),
-            Vec::new(),
-            &state,
-            None,
-        )
-        .await;
+    #[test]
+    fn test_normalize_uuid_sub_path_normalizes_common_cases() {
+        assert_eq!(normalize_uuid_sub_path("").unwrap(), "/");
+        assert_eq!(normalize_uuid_sub_path("DCIM").unwrap(), "/DCIM");
+        assert_eq!(
+            normalize_uuid_sub_path("//DCIM//100MSDCF//").unwrap(),
+            "/DCIM/100MSDCF"
+        );
+    }
 
+    #[test]
+    fn test_normalize_uuid_sub_path_rejects_parent_traversal() {
+        let result = normalize_uuid_sub_path("/DCIM/../secret");
         assert!(result.is_err());
         assert!(result
             .err()
             .unwrap_or_default()
-            .contains("Orphan scan blocked"));
+            .contains("escapes the mounted volume root"));
     }
 
     #[test]
-    fn test_validate_runtime_tasks_rejects_same_task_source_target_overlap() {
+    fn test_validate_runtime_tasks_rejects_malformed_uuid_token() {
         let tasks = vec![build_runtime_task_with_paths(
-            "a",
-            "/data/source",
-            "/data/source/sub",
+            "invalid",
+            "[VOLUME_UUID:broken-token",
+            "/dst/invalid",
             false,
         )];
 
@@ -2920,1242 +6173,1908 @@ mod integration_tests {
         assert!(result
             .err()
             .unwrap_or_default()
-            .contains("overlapping source/target"));
+            .contains("Invalid UUID source token format"));
     }
 
     #[test]
-    fn test_validate_runtime_tasks_allows_one_way_watch_chain() {
-        let tasks = vec![
-            build_runtime_task_with_paths("a", "/sdcard", "/camera/a7cr", true),
-            build_runtime_task_with_paths("b", "/camera", "/nas/camera", true),
-        ];
+    fn test_validate_runtime_tasks_rejects_uuid_subpath_escape() {
+        let tasks = vec![build_runtime_task_with_paths(
+            "escape",
+            "[VOLUME_UUID:uuid-a]/../../outside",
+            "/dst/escape",
+            false,
+        )];
 
-        assert!(validate_runtime_tasks(&tasks).is_ok());
+        let result = validate_runtime_tasks(&tasks);
+        assert!(result.is_err());
+        assert!(result
+            .err()
+            .unwrap_or_default()
+            .contains("escapes the mounted volume root"));
     }
 
     #[test]
-    fn test_validate_runtime_tasks_rejects_watch_cycle() {
-        let tasks = vec![
-            build_runtime_task_with_paths("a", "/watch/a", "/watch/b/import", true),
-            build_runtime_task_with_paths("b", "/watch/b", "/watch/a/export", true),
-        ];
+    fn test_validate_runtime_tasks_normalizes_unmounted_uuid_tokens_before_overlap_check() {
+        let test_uuid = "NOT_A_REAL_UUID_FOR_TEST_ONLY";
+        let source = format!("[DISK_UUID:{test_uuid}]DCIM");
+        let target = format!("[DISK_UUID:{test_uuid}]/DCIM");
+        let tasks = vec![build_runtime_task_with_paths(
+            "normalize",
+            &source,
+            &target,
+            false,
+        )];
 
         let result = validate_runtime_tasks(&tasks);
         assert!(result.is_err());
         assert!(result
             .err()
             .unwrap_or_default()
-            .contains("Watch cycle detected"));
+            .contains("overlapping source/target paths"));
     }
 
     #[test]
-    fn test_validate_runtime_tasks_allows_manual_target_inside_watched_source() {
-        let tasks = vec![
-            build_runtime_task_with_paths("watch-a", "/media/incoming", "/backup/watch-a", true),
-            build_runtime_task_with_paths(
-                "manual-b",
-                "/src/manual",
-                "/media/incoming/export",
-                false,
-            ),
-        ];
+    fn test_ensure_non_overlapping_paths_rejects_nested_target() {
+        let result = ensure_non_overlapping_paths(
+            Path::new("/Volumes/CARD"),
+            Path::new("/Volumes/CARD/DCIM"),
+        );
 
-        assert!(validate_runtime_tasks(&tasks).is_ok());
+        assert!(result.is_err());
+        assert!(result.err().unwrap_or_default().contains("overlap"));
     }
 
     #[test]
-    fn test_validate_runtime_tasks_allows_new_uuid_tokens() {
-        let tasks = vec![
-            build_runtime_task_with_paths("disk", "[DISK_UUID:disk-a]/DCIM", "/dst/disk", false),
-            build_runtime_task_with_paths(
-                "volume",
-                "[VOLUME_UUID:volume-a]/DCIM",
-                "/dst/volume",
-                false,
-            ),
-            build_runtime_task_with_paths("legacy", "[UUID:legacy-a]/DCIM", "/dst/legacy", false),
-        ];
+    fn test_classify_missing_target_path_allows_missing_subdir_on_mounted_volume() {
+        let mounted = HashSet::from(["/volumes/evo990".to_string()]);
 
-        assert!(validate_runtime_tasks(&tasks).is_ok());
+        let result = classify_missing_target_path(Path::new("/Volumes/EVO990/repo"), &mounted)
+            .expect("mounted volume root should allow creating subdir");
+
+        assert_eq!(result, TargetPreflightKind::WillCreateDirectory);
     }
 
     #[test]
-    fn test_parse_uuid_source_path_handles_token_variants() {
-        let disk = parse_uuid_source_path("[DISK_UUID:disk-a]/DCIM/100");
-        assert!(disk.is_some());
-        let disk = disk.unwrap();
-        assert_eq!(disk.uuid, "disk-a");
-        assert_eq!(disk.sub_path, "/DCIM/100");
+    fn test_normalize_user_path_expands_tilde_to_home() {
+        let home = tempdir().expect("home temp dir should create");
+        std::fs::create_dir_all(home.path().join("Pictures")).unwrap();
+        let canonical_home = std::fs::canonicalize(home.path()).unwrap();
 
-        let volume = parse_uuid_source_path("[VOLUME_UUID:volume-a]/MOV");
-        assert!(volume.is_some());
-        let volume = volume.unwrap();
-        assert_eq!(volume.uuid, "volume-a");
-        assert_eq!(volume.sub_path, "/MOV");
+        let normalized =
+            normalize_user_path_with("  ~/Pictures/  ", Some(home.path()), &[]).unwrap();
+        assert_eq!(
+            normalized.path,
+            canonical_home.join("Pictures").to_string_lossy()
+        );
+        assert!(normalized.exists);
+        assert!(normalized.is_dir);
+        assert!(!normalized.uuid_not_mounted);
 
-        let legacy = parse_uuid_source_path("[UUID:legacy-a]/RAW");
-        assert!(legacy.is_some());
-        let legacy = legacy.unwrap();
-        assert_eq!(legacy.uuid, "legacy-a");
-        assert_eq!(legacy.sub_path, "/RAW");
-    }
+        let bare = normalize_user_path_with("~", Some(home.path()), &[]).unwrap();
+        assert_eq!(bare.path, canonical_home.to_string_lossy());
 
-    #[test]
-    fn test_parse_uuid_source_path_edge_cases() {
-        let empty_uuid = parse_uuid_source_path("[DISK_UUID:]/DCIM");
-        assert!(empty_uuid.is_some());
-        let empty_uuid = empty_uuid.unwrap();
-        assert_eq!(empty_uuid.uuid, "");
-        assert_eq!(empty_uuid.sub_path, "/DCIM");
+        let missing = normalize_user_path_with("~/missing.txt", Some(home.path()), &[]).unwrap();
+        assert!(!missing.exists);
+        assert!(!missing.is_dir);
 
-        assert!(parse_uuid_source_path("[DISK_UUID:abc/without-bracket").is_none());
-        assert!(parse_uuid_source_path("[CUSTOM_UUID:abc]/DCIM").is_none());
+        // `~user` 형식은 확장하지 않습니다.
+        let other_user = normalize_user_path_with("~other/x", Some(home.path()), &[]).unwrap();
+        assert_eq!(other_user.path, "~other/x");
+
+        assert!(normalize_user_path_with("~/x", None, &[]).is_err());
     }
 
     #[test]
-    fn test_find_runtime_task_validation_issue_returns_source_target_overlap_metadata() {
-        let tasks = vec![build_runtime_task_with_paths(
-            "a",
-            "/data/source",
-            "/data/source/sub",
-            false,
-        )];
-
-        let issue = find_runtime_task_validation_issue(&tasks).expect("expected issue");
-        assert_eq!(issue.code, RuntimeTaskValidationCode::SourceTargetOverlap);
-        assert_eq!(issue.task_id.as_deref(), Some("a"));
-        assert_eq!(issue.task_name.as_deref(), Some("task-a"));
-        assert_eq!(issue.source.as_deref(), Some("/data/source"));
-        assert_eq!(issue.target.as_deref(), Some("/data/source/sub"));
-        assert!(issue.conflicting_task_ids.is_empty());
+    fn test_normalize_user_path_resolves_uuid_token() {
+        let mount = tempdir().expect("mount temp dir should create");
+        std::fs::create_dir_all(mount.path().join("DCIM")).unwrap();
+        let mut card = build_volume("SD_CARD", mount.path().to_str().unwrap());
+        card.volume_uuid = Some("VOL-1234".to_string());
+        let volumes = vec![card];
+
+        let normalized =
+            normalize_user_path_with("[VOLUME_UUID:VOL-1234]/DCIM/", None, &volumes).unwrap();
+        let expected = std::fs::canonicalize(mount.path().join("DCIM")).unwrap();
+        assert_eq!(normalized.path, expected.to_string_lossy());
+        assert!(normalized.exists);
+        assert!(normalized.is_dir);
+        assert!(!normalized.uuid_not_mounted);
+
+        let unmounted =
+            normalize_user_path_with("[DISK_UUID:MISSING]//DCIM/", None, &volumes).unwrap();
+        assert_eq!(unmounted.path, "[DISK_UUID:MISSING]/DCIM");
+        assert!(unmounted.uuid_not_mounted);
+        assert!(!unmounted.exists);
     }
 
     #[test]
-    fn test_find_runtime_task_validation_issue_distinguishes_duplicate_target_and_subdir_conflict()
-    {
-        let duplicate_target_tasks = vec![
-            build_runtime_task_with_paths("a", "/src/a", "/dst/shared", false),
-            build_runtime_task_with_paths("b", "/src/b", "/dst/shared", false),
-        ];
-        let duplicate_issue =
-            find_runtime_task_validation_issue(&duplicate_target_tasks).expect("duplicate issue");
-        assert_eq!(
-            duplicate_issue.code,
-            RuntimeTaskValidationCode::DuplicateTarget
-        );
-        assert_eq!(duplicate_issue.task_id.as_deref(), Some("a"));
-        assert_eq!(duplicate_issue.conflicting_task_ids, vec!["b".to_string()]);
-
-        let nested_target_tasks = vec![
-            build_runtime_task_with_paths("a", "/src/a", "/dst/root", false),
-            build_runtime_task_with_paths("b", "/src/b", "/dst/root/child", false),
-        ];
-        let nested_issue =
-            find_runtime_task_validation_issue(&nested_target_tasks).expect("nested issue");
-        assert_eq!(
-            nested_issue.code,
-            RuntimeTaskValidationCode::TargetSubdirConflict
-        );
-        assert_eq!(nested_issue.task_id.as_deref(), Some("a"));
-        assert_eq!(nested_issue.conflicting_task_ids, vec!["b".to_string()]);
+    fn test_normalize_user_path_rejects_shell_metacharacters() {
+        let home = tempdir().expect("home temp dir should create");
+        let error =
+            normalize_user_path_with("~/photos; rm -rf /", Some(home.path()), &[]).unwrap_err();
+        assert!(error.contains("metacharacters"), "{error}");
+        assert!(normalize_user_path_with("/tmp/$HOME", None, &[]).is_err());
+        assert!(normalize_user_path_with("   ", None, &[]).is_err());
     }
 
-    #[test]
-    fn test_find_runtime_watch_cycle_detects_longer_cycle() {
-        let tasks = vec![
-            build_runtime_task_with_paths("a", "/watch/a", "/watch/b/in", true),
-            build_runtime_task_with_paths("b", "/watch/b", "/watch/c/in", true),
-            build_runtime_task_with_paths("c", "/watch/c", "/watch/a/in", true),
+    #[tokio::test]
+    async fn test_delete_orphan_files_requires_matching_confirmation_token() {
+        let state = build_app_state();
+        let source = tempdir().expect("source temp dir should create");
+        let target = tempdir().expect("target temp dir should create");
+        std::fs::write(source.path().join("keep.txt"), b"keep").unwrap();
+        std::fs::create_dir_all(target.path().join("stale/nested")).unwrap();
+        std::fs::write(target.path().join("stale/nested/old.txt"), b"old!").unwrap();
+        std::fs::write(target.path().join("orphan.txt"), b"orphan").unwrap();
+        std::fs::write(target.path().join("keep.txt"), b"keep").unwrap();
+        let paths = vec![
+            "stale".to_string(),
+            "orphan.txt".to_string(),
+            "stale/nested/old.txt".to_string(),
         ];
 
-        let validated = build_validated_runtime_tasks(&tasks).unwrap();
-        let cycle = find_runtime_watch_cycle(&validated);
-        assert!(cycle.is_some());
+        let refused = delete_orphan_files_internal(
+            "task-1",
+            source.path(),
+            target.path(),
+            &[],
+            paths.clone(),
+            &SyncRunOptions::default(),
+            false,
+            false,
+            "orphdel_bogus",
+            &state,
+            |_| {},
+        )
+        .await
+        .unwrap_err();
+        assert!(refused.contains("missing or already used"), "{refused}");
+        assert!(target.path().join("orphan.txt").exists());
+
+        let preview = prepare_orphan_deletion_internal(
+            "task-1",
+            target.path(),
+            paths.clone(),
+            &SyncRunOptions::default(),
+            &state,
+        )
+        .await
+        .unwrap();
+        assert_eq!(preview.summary.file_count, 2);
+        assert_eq!(preview.summary.dir_count, 2);
+        assert_eq!(preview.summary.total_bytes, 10);
+        assert_eq!(preview.summary.top_level_dirs, vec![PathBuf::from("stale")]);
+
+        // A token only covers the exact selection it was prepared for, and is spent either way.
+        let mismatched = delete_orphan_files_internal(
+            "task-1",
+            source.path(),
+            target.path(),
+            &[],
+            vec!["keep.txt".to_string()],
+            &SyncRunOptions::default(),
+            false,
+            false,
+            &preview.token,
+            &state,
+            |_| {},
+        )
+        .await
+        .unwrap_err();
+        assert!(mismatched.contains("does not match"), "{mismatched}");
+        assert!(target.path().join("keep.txt").exists());
+
+        let preview = prepare_orphan_deletion_internal(
+            "task-1",
+            target.path(),
+            paths.clone(),
+            &SyncRunOptions::default(),
+            &state,
+        )
+        .await
+        .unwrap();
+        let mut reordered = paths.clone();
+        reordered.reverse();
+        let result = delete_orphan_files_internal(
+            "task-1",
+            source.path(),
+            target.path(),
+            &[],
+            reordered,
+            &SyncRunOptions::default(),
+            false,
+            false,
+            &preview.token,
+            &state,
+            |_| {},
+        )
+        .await
+        .unwrap();
+        assert_eq!(result.deleted_files_count, 2);
+        assert_eq!(result.deleted_dirs_count, 2);
+        assert!(!target.path().join("stale").exists());
+        assert!(!target.path().join("orphan.txt").exists());
+        assert!(target.path().join("keep.txt").exists());
+
+        let reused = delete_orphan_files_internal(
+            "task-1",
+            source.path(),
+            target.path(),
+            &[],
+            paths,
+            &SyncRunOptions::default(),
+            false,
+            false,
+            &preview.token,
+            &state,
+            |_| {},
+        )
+        .await
+        .unwrap_err();
+        assert!(reused.contains("missing or already used"), "{reused}");
     }
 
     #[tokio::test]
-    async fn test_mark_downstream_watch_tasks_settle_for_manual_target_overlap() {
+    async fn test_delete_orphan_files_skips_paths_outside_source_subdirs() {
         let state = build_app_state();
-        {
-            let mut runtime_config = state.runtime_config.write().await;
-            runtime_config.tasks = vec![
-                build_runtime_task_with_paths(
-                    "watch-a",
-                    "/media/incoming",
-                    "/backup/watch-a",
-                    true,
-                ),
-                build_runtime_task_with_paths(
-                    "manual-b",
-                    "/src/manual",
-                    "/media/incoming/export",
-                    false,
-                ),
-            ];
-        }
+        let source = tempdir().expect("source temp dir should create");
+        let target = tempdir().expect("target temp dir should create");
+        std::fs::create_dir_all(source.path().join("DCIM")).unwrap();
+        std::fs::write(source.path().join("DCIM/current.jpg"), b"new").unwrap();
+        std::fs::create_dir_all(target.path().join("DCIM")).unwrap();
+        std::fs::create_dir_all(target.path().join("Archive")).unwrap();
+        std::fs::write(target.path().join("DCIM/old.jpg"), b"old").unwrap();
+        std::fs::write(target.path().join("Archive/keep.jpg"), b"keep").unwrap();
+        let paths = vec!["DCIM/old.jpg".to_string(), "Archive/keep.jpg".to_string()];
+        let run_options = SyncRunOptions {
+            source_subdirs: Some(vec!["DCIM".to_string()]),
+            ..SyncRunOptions::default()
+        };
 
-        mark_downstream_watch_tasks_settle_for_target("/media/incoming/export", &state).await;
+        let preview = prepare_orphan_deletion_internal(
+            "task-1",
+            target.path(),
+            paths.clone(),
+            &run_options,
+            &state,
+        )
+        .await
+        .unwrap();
+        assert_eq!(preview.summary.file_count, 1);
+        assert_eq!(preview.summary.skipped_count, 1);
 
-        let settle = state.runtime_chain_settle_until.read().await;
-        assert!(settle.get("watch-a").is_some());
+        let result = delete_orphan_files_internal(
+            "task-1",
+            source.path(),
+            target.path(),
+            &[],
+            paths,
+            &run_options,
+            false,
+            false,
+            &preview.token,
+            &state,
+            |_| {},
+        )
+        .await
+        .unwrap();
+        assert_eq!(result.deleted_files_count, 1);
+        assert_eq!(result.skipped_count, 1);
+        assert!(!target.path().join("DCIM/old.jpg").exists());
+        assert!(target.path().join("Archive/keep.jpg").exists());
     }
 
     #[tokio::test]
-    async fn test_finish_runtime_producer_failure_blocks_downstream_before_removal() {
+    async fn test_delete_orphan_files_refuses_when_source_became_empty() {
         let state = build_app_state();
+        let source = tempdir().expect("source temp dir should create");
+        let target = tempdir().expect("target temp dir should create");
+        std::fs::write(source.path().join("keep.txt"), b"keep").unwrap();
+        std::fs::write(target.path().join("keep.txt"), b"keep").unwrap();
+        std::fs::write(target.path().join("orphan.txt"), b"orphan").unwrap();
+        let paths = vec!["orphan.txt".to_string()];
+
+        let preview = prepare_orphan_deletion_internal(
+            "task-1",
+            target.path(),
+            paths.clone(),
+            &SyncRunOptions::default(),
+            &state,
+        )
+        .await
+        .unwrap();
 
-        {
-            let mut runtime_config = state.runtime_config.write().await;
-            runtime_config.tasks = vec![
-                build_runtime_task_with_paths(
-                    "watch-a",
-                    "/media/incoming",
-                    "/backup/watch-a",
-                    true,
-                ),
-                build_runtime_task_with_paths(
-                    "manual-b",
-                    "/src/manual",
-                    "/media/incoming/export",
-                    false,
-                ),
-            ];
-        }
-
-        {
-            let mut queued = state.queued_sync_tasks.write().await;
-            queued.insert("watch-a".to_string());
-        }
-        {
-            let mut queue = state.runtime_sync_queue.write().await;
-            queue.push_back("watch-a".to_string());
-        }
-        {
-            let mut pending = state.runtime_pending_sync_tasks.write().await;
-            pending.insert("watch-a".to_string());
-        }
-        {
-            let mut producers = state.runtime_active_producers.write().await;
-            producers.insert(
-                "sync:manual:task-b".to_string(),
-                RuntimeActiveProducer {
-                    producer_id: "sync:manual:task-b".to_string(),
-                    kind: RuntimeProducerKind::ManualSync,
-                    target_key: "/media/incoming/export".to_string(),
-                },
-            );
-        }
-
-        finish_runtime_producer(
-            "sync:manual:task-b",
-            "/media/incoming/export",
+        // The card is pulled between the orphan scan and the confirmed delete.
+        std::fs::remove_file(source.path().join("keep.txt")).unwrap();
+        let error = delete_orphan_files_internal(
+            "task-1",
+            source.path(),
+            target.path(),
+            &[],
+            paths.clone(),
+            &SyncRunOptions::default(),
             false,
-            None,
+            false,
+            &preview.token,
             &state,
+            |_| {},
         )
-        .await;
+        .await
+        .unwrap_err();
+        assert!(
+            error.starts_with(error_codes::ERR_SUSPICIOUS_EMPTY_SOURCE),
+            "{error}"
+        );
+        assert!(target.path().join("orphan.txt").exists());
 
-        let queued = state.queued_sync_tasks.read().await;
-        assert!(!queued.contains("watch-a"));
-        drop(queued);
+        let preview = prepare_orphan_deletion_internal(
+            "task-1",
+            target.path(),
+            paths.clone(),
+            &SyncRunOptions::default(),
+            &state,
+        )
+        .await
+        .unwrap();
+        let allowed = SyncRunOptions {
+            allow_empty_source: true,
+            ..SyncRunOptions::default()
+        };
+        let result = delete_orphan_files_internal(
+            "task-1",
+            source.path(),
+            target.path(),
+            &[],
+            paths,
+            &allowed,
+            false,
+            false,
+            &preview.token,
+            &state,
+            |_| {},
+        )
+        .await
+        .unwrap();
+        assert_eq!(result.deleted_files_count, 1);
+        assert!(!target.path().join("orphan.txt").exists());
+    }
 
-        let queue = state.runtime_sync_queue.read().await;
-        assert!(!queue.iter().any(|task_id| task_id == "watch-a"));
-        drop(queue);
+    #[test]
+    fn test_system_volume_targets_are_blocked_unless_allowed() {
+        for target in ["/", "/System/Volumes/Data", "/usr/bin"] {
+            let error = ensure_target_not_system_volume(Path::new(target), false)
+                .expect_err("system target should be rejected by default");
+            assert!(
+                error.starts_with(error_codes::ERR_SYSTEM_TARGET_BLOCKED),
+                "{error}"
+            );
+            assert!(ensure_target_not_system_volume(Path::new(target), true).is_ok());
+        }
 
-        let pending = state.runtime_pending_sync_tasks.read().await;
-        assert!(!pending.contains("watch-a"));
-        drop(pending);
+        let user_target = tempdir().expect("target temp dir should create");
+        assert!(ensure_target_not_system_volume(user_target.path(), false).is_ok());
+    }
 
-        let settle = state.runtime_chain_settle_until.read().await;
-        assert!(settle.get("watch-a").is_none());
-        drop(settle);
+    #[tokio::test]
+    async fn test_delete_orphan_files_refuses_root_target_by_default() {
+        let state = build_app_state();
+        let source = tempdir().expect("source temp dir should create");
 
-        let producers = state.runtime_active_producers.read().await;
-        assert!(!producers.contains_key("sync:manual:task-b"));
+        let error = delete_orphan_files_internal(
+            "task-1",
+            source.path(),
+            Path::new("/"),
+            &[],
+            vec!["etc".to_string()],
+            &SyncRunOptions::default(),
+            false,
+            false,
+            "orphdel_bogus",
+            &state,
+            |_| {},
+        )
+        .await
+        .unwrap_err();
+        assert!(
+            error.starts_with(error_codes::ERR_SYSTEM_TARGET_BLOCKED),
+            "{error}"
+        );
     }
 
     #[tokio::test]
-    async fn test_finish_runtime_producer_success_marks_settle_before_removal() {
+    async fn test_delete_orphan_files_rejects_expired_confirmation_token() {
         let state = build_app_state();
+        let source = tempdir().expect("source temp dir should create");
+        let target = tempdir().expect("target temp dir should create");
+        std::fs::write(target.path().join("orphan.txt"), b"orphan").unwrap();
+        let paths = vec!["orphan.txt".to_string()];
 
-        {
-            let mut runtime_config = state.runtime_config.write().await;
-            runtime_config.tasks = vec![
-                build_runtime_task_with_paths(
-                    "watch-a",
-                    "/media/incoming",
-                    "/backup/watch-a",
-                    true,
-                ),
-                build_runtime_task_with_paths(
-                    "manual-b",
-                    "/src/manual",
-                    "/media/incoming/export",
-                    false,
-                ),
-            ];
-        }
-
-        {
-            let mut queued = state.queued_sync_tasks.write().await;
-            queued.insert("watch-a".to_string());
-        }
-        {
-            let mut queue = state.runtime_sync_queue.write().await;
-            queue.push_back("watch-a".to_string());
-        }
-        {
-            let mut pending = state.runtime_pending_sync_tasks.write().await;
-            pending.insert("watch-a".to_string());
-        }
-        {
-            let mut producers = state.runtime_active_producers.write().await;
-            producers.insert(
-                "sync:manual:task-b".to_string(),
-                RuntimeActiveProducer {
-                    producer_id: "sync:manual:task-b".to_string(),
-                    kind: RuntimeProducerKind::ManualSync,
-                    target_key: "/media/incoming/export".to_string(),
-                },
-            );
-        }
+        let preview = prepare_orphan_deletion_internal(
+            "task-1",
+            target.path(),
+            paths.clone(),
+            &SyncRunOptions::default(),
+            &state,
+        )
+        .await
+        .unwrap();
+        state
+            .orphan_deletion_tokens
+            .write()
+            .await
+            .get_mut(&preview.token)
+            .unwrap()
+            .expires_at = Instant::now();
 
-        finish_runtime_producer(
-            "sync:manual:task-b",
-            "/media/incoming/export",
-            true,
-            None,
+        let error = delete_orphan_files_internal(
+            "task-1",
+            source.path(),
+            target.path(),
+            &[],
+            paths,
+            &SyncRunOptions::default(),
+            false,
+            false,
+            &preview.token,
             &state,
+            |_| {},
         )
-        .await;
+        .await
+        .unwrap_err();
+        assert!(error.contains("expired"), "{error}");
+        assert!(target.path().join("orphan.txt").exists());
+    }
 
-        let queued = state.queued_sync_tasks.read().await;
-        assert!(queued.contains("watch-a"));
-        drop(queued);
+    #[test]
+    fn test_project_free_space_subtracts_pending_sync_bytes() {
+        let pending = vec![
+            ("a".to_string(), Some(300)),
+            ("b".to_string(), None),
+            ("c".to_string(), Some(500)),
+        ];
+        let projection = project_free_space(Some("/Volumes/Backup".to_string()), 1_000, pending);
+        assert_eq!(projection.pending_bytes, 800);
+        assert_eq!(projection.projected_free_bytes, 200);
+        assert!(!projection.will_run_out);
+        assert_eq!(projection.contributing_task_ids, vec!["a", "c"]);
+        assert_eq!(projection.unknown_task_ids, vec!["b"]);
+
+        let over = project_free_space(
+            None,
+            1_000,
+            vec![("a".to_string(), Some(700)), ("b".to_string(), Some(700))],
+        );
+        assert_eq!(over.projected_free_bytes, -400);
+        assert!(over.will_run_out);
 
-        let queue = state.runtime_sync_queue.read().await;
-        assert!(queue.iter().any(|task_id| task_id == "watch-a"));
-        drop(queue);
+        let exact = project_free_space(None, 1_000, vec![("a".to_string(), Some(1_000))]);
+        assert_eq!(exact.projected_free_bytes, 0);
+        assert!(!exact.will_run_out);
 
-        let pending = state.runtime_pending_sync_tasks.read().await;
-        assert!(pending.contains("watch-a"));
-        drop(pending);
+        let idle = project_free_space(None, u64::MAX, Vec::new());
+        assert_eq!(idle.pending_bytes, 0);
+        assert_eq!(idle.projected_free_bytes, i64::MAX);
+        assert!(!idle.will_run_out);
+    }
 
-        let settle = state.runtime_chain_settle_until.read().await;
-        assert!(settle.get("watch-a").is_some());
-        drop(settle);
+    #[tokio::test]
+    async fn test_get_capacity_history_filters_by_mount_point_and_since() {
+        let mut root = build_volume("Macintosh HD", "/");
+        root.is_removable = false;
+        let mut unknown = build_volume("Unknown", "/Volumes/Unknown");
+        unknown.available_bytes = None;
+        let volumes = vec![root, build_volume("Archive", "/Volumes/Archive"), unknown];
+        let targets = vec![
+            PathBuf::from("/Volumes/Archive/Photos"),
+            PathBuf::from("/Volumes/Archive/Video"),
+            PathBuf::from("/Volumes/Unknown/Docs"),
+        ];
 
-        let producers = state.runtime_active_producers.read().await;
-        assert!(!producers.contains_key("sync:manual:task-b"));
-    }
+        let start = chrono::Utc::now() - chrono::Duration::hours(2);
+        let samples = collect_capacity_samples(&targets, &volumes, start);
+        assert_eq!(samples.len(), 1);
+        assert_eq!(samples[0].mount_point, PathBuf::from("/Volumes/Archive"));
+        assert_eq!(samples[0].available_bytes, 128);
+        assert_eq!(samples[0].total_bytes, 256);
 
-    #[test]
-    fn test_validate_runtime_tasks_allows_manual_target_inside_watch_source() {
-        let tasks = vec![
-            build_runtime_task_with_paths("watch-a", "/media/incoming", "/backup/watch-a", true),
-            build_runtime_task_with_paths(
-                "manual-b",
-                "/src/manual",
-                "/media/incoming/export",
-                false,
+        let state = build_app_state();
+        let later = start + chrono::Duration::hours(1);
+        let mut other = samples[0].clone();
+        other.mount_point = PathBuf::from("/Volumes/Other");
+        state
+            .capacity_history_store
+            .append_samples(&[samples[0].clone(), other])
+            .expect("append should succeed");
+        state
+            .capacity_history_store
+            .append_samples(&collect_capacity_samples(&targets, &volumes, later))
+            .expect("append should succeed");
+
+        let all = get_capacity_history_internal(PathBuf::from("/Volumes/Archive"), None, &state)
+            .await
+            .expect("history should load");
+        assert_eq!(all.len(), 2);
+        assert!(all
+            .iter()
+            .all(|sample| sample.mount_point == Path::new("/Volumes/Archive")));
+
+        let recent =
+            get_capacity_history_internal(PathBuf::from("/Volumes/Archive"), Some(later), &state)
+                .await
+                .expect("history should load");
+        assert_eq!(recent.len(), 1);
+        assert_eq!(recent[0].timestamp, later);
+    }
+
+    #[test]
+    fn test_pending_tasks_on_volume_only_counts_same_target_volume() {
+        let mut root = build_volume("Macintosh HD", "/");
+        root.is_removable = false;
+        let backup = build_volume("Backup", "/Volumes/Backup");
+        let other = build_volume("Backup 2", "/Volumes/Backup 2");
+        let volumes = vec![root, backup, other];
+        let pending_targets = vec![
+            (
+                "photos".to_string(),
+                PathBuf::from("/Volumes/Backup/Photos"),
             ),
+            ("docs".to_string(), PathBuf::from("/Volumes/Backup 2/Docs")),
+            ("music".to_string(), PathBuf::from("/Volumes/Backup")),
+            ("home".to_string(), PathBuf::from("/Users/me/Mirror")),
         ];
 
-        assert!(validate_runtime_tasks(&tasks).is_ok());
+        assert_eq!(
+            pending_tasks_on_volume(Path::new("/Volumes/Backup"), &pending_targets, &volumes),
+            vec!["photos", "music"]
+        );
+        assert_eq!(
+            pending_tasks_on_volume(Path::new("/"), &pending_targets, &volumes),
+            vec!["home"]
+        );
     }
 
     #[test]
-    fn test_validate_runtime_tasks_rejects_two_node_watch_cycle() {
-        let tasks = vec![
-            build_runtime_task_with_paths("a", "/src/a", "/loop/target", true),
-            build_runtime_task_with_paths("b", "/loop/target/inbound", "/src/a/return", true),
-        ];
+    fn test_path_removable_info_uses_longest_mount_point_prefix() {
+        let mut root = build_volume("Macintosh HD", "/");
+        root.is_removable = false;
+        let card = build_volume("SD_CARD", "/Volumes/SD_CARD");
+        let mut nas = build_volume("photos", "/Volumes/photos");
+        nas.is_removable = false;
+        nas.is_network = true;
+        let mut nested = build_volume("SD_CARD_2", "/Volumes/SD_CARD 2");
+        nested.is_removable = false;
+        let volumes = vec![root, card, nas, nested];
+
+        let removable = path_removable_info(Path::new("/Volumes/SD_CARD/DCIM/100"), &volumes);
+        assert_eq!(
+            removable,
+            PathRemovableInfo {
+                is_removable: true,
+                is_network: false,
+                volume_name: Some("SD_CARD".to_string()),
+                mount_point: Some("/Volumes/SD_CARD".to_string()),
+            }
+        );
 
-        let result = validate_runtime_tasks(&tasks);
-        assert!(result.is_err());
-        assert!(result
-            .err()
-            .unwrap_or_default()
-            .contains("Watch cycle detected"));
+        let network = path_removable_info(Path::new("/volumes/PHOTOS/2024"), &volumes);
+        assert!(network.is_network);
+        assert!(!network.is_removable);
+        assert_eq!(network.volume_name.as_deref(), Some("photos"));
+
+        let fixed = path_removable_info(Path::new("/Users/me/Pictures"), &volumes);
+        assert!(!fixed.is_removable);
+        assert!(!fixed.is_network);
+        assert_eq!(fixed.mount_point.as_deref(), Some("/"));
+
+        // "/Volumes/SD_CARD 2" must not be treated as inside "/Volumes/SD_CARD".
+        let sibling = path_removable_info(Path::new("/Volumes/SD_CARD 2/x"), &volumes);
+        assert_eq!(sibling.volume_name.as_deref(), Some("SD_CARD_2"));
+        assert!(!sibling.is_removable);
+
+        let unknown = path_removable_info(Path::new("relative/path"), &[]);
+        assert_eq!(unknown.volume_name, None);
     }
 
     #[test]
-    fn test_validate_runtime_tasks_rejects_longer_watch_cycle() {
-        let tasks = vec![
-            build_runtime_task_with_paths("a", "/src/a", "/chain/b", true),
-            build_runtime_task_with_paths("b", "/chain/b/in", "/chain/c", true),
-            build_runtime_task_with_paths("c", "/chain/c/in", "/src/a/back", true),
-        ];
+    fn test_classify_missing_target_path_rejects_unmounted_volume_target() {
+        let mounted = HashSet::from(["/volumes/evo990".to_string()]);
+
+        let result = classify_missing_target_path(Path::new("/Volumes/kimjeongjin/repo"), &mounted);
 
-        let result = validate_runtime_tasks(&tasks);
         assert!(result.is_err());
         assert!(result
             .err()
             .unwrap_or_default()
-            .contains("Watch cycle detected"));
+            .contains("Target volume is not mounted"));
     }
 
     #[test]
-    fn test_find_runtime_task_validation_issue_returns_watch_cycle_metadata() {
-        let tasks = vec![
-            build_runtime_task_with_paths("a", "/src/a", "/chain/b", true),
-            build_runtime_task_with_paths("b", "/chain/b/in", "/chain/c", true),
-            build_runtime_task_with_paths("c", "/chain/c/in", "/src/a/back", true),
-        ];
+    fn test_classify_missing_target_path_is_case_insensitive_for_volumes_prefix() {
+        let mounted = HashSet::from(["/volumes/evo990".to_string()]);
 
-        let issue = find_runtime_task_validation_issue(&tasks).expect("cycle issue");
-        assert_eq!(issue.code, RuntimeTaskValidationCode::WatchCycle);
-        let mut involved_ids = issue.conflicting_task_ids.clone();
-        if let Some(task_id) = issue.task_id.clone() {
-            involved_ids.push(task_id);
-        }
-        involved_ids.sort();
-        assert_eq!(
-            involved_ids,
-            vec!["a".to_string(), "b".to_string(), "c".to_string()]
-        );
+        let result = classify_missing_target_path(Path::new("/volumes/EVO990/repo"), &mounted)
+            .expect("lowercase /volumes prefix should still be recognized");
+
+        assert_eq!(result, TargetPreflightKind::WillCreateDirectory);
     }
 
     #[test]
-    fn test_validate_runtime_tasks_allows_case_insensitive_parent_child_watch_chain() {
-        let tasks = vec![
-            build_runtime_task_with_paths(
-                "photo-save",
-                "[VOLUME_UUID:07497716-6027-3FE9-B418-6BEA262C702F1]/",
-                "/VoLumes/EV0990/Camera/a7cr",
-                true,
-            ),
-            build_runtime_task_with_paths(
-                "camera-nas",
-                "/Volumes/ev0990/camera",
-                "/Volumes/kimjeongjin/camera",
-                true,
-            ),
-        ];
+    fn test_classify_missing_target_path_allows_missing_general_directory() {
+        let mounted = HashSet::from(["/volumes/evo990".to_string()]);
 
-        assert!(validate_runtime_tasks(&tasks).is_ok());
+        let result =
+            classify_missing_target_path(Path::new("/Users/test/new-backup-dir"), &mounted)
+                .expect("general missing directory should be creatable");
+
+        assert_eq!(result, TargetPreflightKind::WillCreateDirectory);
+    }
+
+    #[tokio::test]
+    async fn test_preflight_target_path_creates_missing_directory_for_sync() {
+        let base = tempdir().expect("tempdir should be created");
+        let target = base.path().join("nested/backup");
+
+        let result = preflight_target_path(&target, true)
+            .await
+            .expect("sync preflight should create missing directory");
+
+        assert_eq!(result.kind, TargetPreflightKind::CreatedDirectory);
+        assert!(target.is_dir());
+    }
+
+    #[tokio::test]
+    async fn test_sync_dry_run_internal_cleans_up_cancel_token_on_error() {
+        let state = build_app_state();
+        let base = tempdir().expect("tempdir should be created");
+        let target = base.path().join("target");
+        std::fs::create_dir_all(&target).expect("target directory should be created");
+
+        let result = sync_dry_run_internal(
+            None,
+            None,
+            "task-1".to_string(),
+            base.path().join("missing-source"),
+            target,
+            false,
+            Vec::new(),
+            SyncRunOptions::default(),
+            &state,
+            None,
+            None,
+        )
+        .await;
+
+        assert!(result.is_err());
+        assert!(state.dry_run_cancel_tokens.read().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_runtime_get_state_includes_dry_running_tasks() {
+        let state = build_app_state();
+
+        {
+            let mut running = state.dry_running_tasks.write().await;
+            running.insert("task-1".to_string());
+        }
+
+        let runtime = runtime_get_state_internal(&state).await;
+
+        assert_eq!(runtime.dry_running_tasks, vec!["task-1".to_string()]);
     }
 
     #[test]
-    fn test_select_runtime_dispatch_candidate_blocks_active_manual_producer() {
-        let queue = VecDeque::from([String::from("watch-a")]);
-        let queued_set = HashSet::from([String::from("watch-a")]);
-        let syncing = HashSet::new();
-        let watch_upstreams = HashMap::new();
-        let source_keys =
-            HashMap::from([(String::from("watch-a"), String::from("/media/incoming"))]);
-        let active_producers = HashMap::from([(
-            String::from("sync:manual:task-b"),
-            RuntimeActiveProducer {
-                producer_id: String::from("sync:manual:task-b"),
-                kind: RuntimeProducerKind::ManualSync,
-                target_key: String::from("/media/incoming/export"),
-            },
-        )]);
-        let settle_until = HashMap::new();
+    fn test_dry_run_live_state_throttles_progress_and_flushes_timed_batches() {
+        let live = DryRunLiveState::new();
+        let base = Instant::now();
+        let scan_progress =
+            build_dry_run_progress(DryRunPhase::ScanningSource, "scanning", 1, 0, 1, 0, 0, 0);
 
-        let selection = select_runtime_dispatch_candidate(
-            &queue,
-            &queued_set,
-            &syncing,
-            &watch_upstreams,
-            &source_keys,
-            &active_producers,
-            &settle_until,
-            Instant::now(),
+        let (first_progress, first_batch) = live.record_progress(scan_progress.clone(), base);
+        assert_eq!(
+            first_progress
+                .as_ref()
+                .map(|progress| progress.message.as_str()),
+            Some("scanning")
         );
+        assert!(first_batch.is_none());
 
-        assert!(selection.candidate_task_id.is_none());
-        assert!(selection.next_deadline.is_none());
+        let (second_progress, second_batch) =
+            live.record_progress(scan_progress.clone(), base + Duration::from_millis(50));
+        assert!(second_progress.is_none());
+        assert!(second_batch.is_none());
+
+        let compare_progress =
+            build_dry_run_progress(DryRunPhase::Comparing, "compare", 2, 3, 3, 2, 1, 4096);
+        let diff_a = FileDiff {
+            path: PathBuf::from("b.txt"),
+            kind: FileDiffKind::New,
+            source_size: Some(1024),
+            target_size: None,
+            checksum_source: None,
+            checksum_target: None,
+            renamed_from: None,
+            source_path: None,
+        };
+        let diff_b = FileDiff {
+            path: PathBuf::from("a.txt"),
+            kind: FileDiffKind::Modified,
+            source_size: Some(2048),
+            target_size: Some(1024),
+            checksum_source: None,
+            checksum_target: None,
+            renamed_from: None,
+            source_path: None,
+        };
+
+        assert!(live
+            .record_diff(
+                diff_a,
+                compare_progress.clone(),
+                base + Duration::from_millis(120)
+            )
+            .is_none());
+        assert!(live
+            .record_diff(
+                diff_b,
+                compare_progress.clone(),
+                base + Duration::from_millis(180)
+            )
+            .is_none());
+
+        let (third_progress, timed_batch) =
+            live.record_progress(compare_progress.clone(), base + Duration::from_millis(320));
+        assert!(third_progress.is_some());
+        let (diffs, batch_progress) = timed_batch.expect("timed batch should flush");
+        assert_eq!(diffs.len(), 2);
+        assert_eq!(batch_progress.phase, DryRunPhase::Comparing);
+        assert_eq!(batch_progress.message, "compare");
     }
 
     #[test]
-    fn test_select_runtime_dispatch_candidate_blocks_active_conflict_producer() {
-        let queue = VecDeque::from([String::from("watch-a")]);
-        let queued_set = HashSet::from([String::from("watch-a")]);
-        let syncing = HashSet::new();
-        let watch_upstreams = HashMap::new();
-        let source_keys =
-            HashMap::from([(String::from("watch-a"), String::from("/media/incoming"))]);
-        let active_producers = HashMap::from([(
-            String::from("conflict:force-copy:session-1:item-1"),
-            RuntimeActiveProducer {
-                producer_id: String::from("conflict:force-copy:session-1:item-1"),
-                kind: RuntimeProducerKind::ConflictForceCopy,
-                target_key: String::from("/media/incoming/export/file.jpg"),
-            },
-        )]);
+    fn test_dry_run_live_state_flushes_on_batch_size_limit() {
+        let live = DryRunLiveState::new();
+        let progress =
+            build_dry_run_progress(DryRunPhase::Comparing, "compare", 50, 50, 50, 50, 0, 1024);
 
-        let selection = select_runtime_dispatch_candidate(
-            &queue,
-            &queued_set,
-            &syncing,
-            &watch_upstreams,
-            &source_keys,
-            &active_producers,
-            &HashMap::new(),
-            Instant::now(),
-        );
+        let mut batch = None;
+        for index in 0..50 {
+            let diff = FileDiff {
+                path: PathBuf::from(format!("file-{index}.txt")),
+                kind: FileDiffKind::New,
+                source_size: Some(1),
+                target_size: None,
+                checksum_source: None,
+                checksum_target: None,
+                renamed_from: None,
+                source_path: None,
+            };
+            batch = live.record_diff(diff, progress.clone(), Instant::now());
+        }
 
-        assert!(selection.candidate_task_id.is_none());
+        let (diffs, batch_progress) = batch.expect("batch size flush should trigger");
+        assert_eq!(diffs.len(), 50);
+        assert_eq!(batch_progress.phase, DryRunPhase::Comparing);
     }
 
     #[test]
-    fn test_select_runtime_dispatch_candidate_waits_for_settle_then_runs() {
-        let queue = VecDeque::from([String::from("watch-a"), String::from("watch-b")]);
-        let queued_set = HashSet::from([String::from("watch-a"), String::from("watch-b")]);
-        let syncing = HashSet::new();
-        let watch_upstreams = HashMap::new();
-        let source_keys = HashMap::from([
-            (String::from("watch-a"), String::from("/media/incoming")),
-            (String::from("watch-b"), String::from("/other/source")),
-        ]);
-        let active_producers = HashMap::new();
-        let future_deadline = Instant::now() + Duration::from_millis(200);
-        let settle_until = HashMap::from([(String::from("watch-a"), future_deadline)]);
-
-        let selection = select_runtime_dispatch_candidate(
-            &queue,
-            &queued_set,
-            &syncing,
-            &watch_upstreams,
-            &source_keys,
-            &active_producers,
-            &settle_until,
-            Instant::now(),
-        );
+    fn test_sync_live_state_flushes_timed_batches() {
+        let live = SyncLiveState::new();
+        let base = Instant::now();
 
-        assert_eq!(selection.candidate_task_id.as_deref(), Some("watch-b"));
-        assert_eq!(selection.next_deadline, Some(future_deadline));
+        assert!(live
+            .record_entry(
+                SyncFileEntry {
+                    path: PathBuf::from("a.txt"),
+                    kind: FileDiffKind::New,
+                    status: SyncFileStatus::Copied,
+                    source_size: Some(1),
+                    target_size: Some(0),
+                    error: None,
+                },
+                base,
+            )
+            .is_none());
 
-        let selection_after = select_runtime_dispatch_candidate(
-            &VecDeque::from([String::from("watch-a")]),
-            &HashSet::from([String::from("watch-a")]),
-            &HashSet::new(),
-            &HashMap::new(),
-            &HashMap::from([(String::from("watch-a"), String::from("/media/incoming"))]),
-            &HashMap::new(),
-            &HashMap::from([(
-                String::from("watch-a"),
-                Instant::now() - Duration::from_millis(1),
-            )]),
-            Instant::now(),
+        let batch = live.record_entry(
+            SyncFileEntry {
+                path: PathBuf::from("b.txt"),
+                kind: FileDiffKind::Modified,
+                status: SyncFileStatus::Failed,
+                source_size: Some(2),
+                target_size: Some(1),
+                error: Some("copy failed".to_string()),
+            },
+            base + Duration::from_millis(250),
         );
 
-        assert_eq!(
-            selection_after.candidate_task_id.as_deref(),
-            Some("watch-a")
-        );
+        let entries = batch.expect("timed batch should flush");
+        assert_eq!(entries.len(), 2);
     }
 
     #[test]
-    fn test_select_runtime_dispatch_candidate_blocks_queued_watch_upstream() {
-        let tasks = vec![
-            build_runtime_task_with_paths("watch-a", "/sdcard", "/camera/a7cr", true),
-            build_runtime_task_with_paths("watch-b", "/camera", "/nas/camera", true),
-        ];
-        let validated = build_validated_runtime_tasks(&tasks).unwrap();
-        let upstreams = build_runtime_watch_upstreams(&validated);
-        let queue = VecDeque::from([String::from("watch-b"), String::from("watch-a")]);
-        let queued_set = HashSet::from([String::from("watch-b"), String::from("watch-a")]);
-        let syncing = HashSet::new();
-        let source_keys = HashMap::from([
-            (String::from("watch-a"), String::from("/sdcard")),
-            (String::from("watch-b"), String::from("/camera")),
-        ]);
+    fn test_sync_live_state_flushes_on_batch_size_limit() {
+        let live = SyncLiveState::new();
 
-        let selection = select_runtime_dispatch_candidate(
-            &queue,
-            &queued_set,
-            &syncing,
-            &upstreams,
-            &source_keys,
-            &HashMap::new(),
-            &HashMap::new(),
-            Instant::now(),
-        );
+        let mut batch = None;
+        for index in 0..50 {
+            batch = live.record_entry(
+                SyncFileEntry {
+                    path: PathBuf::from(format!("file-{index}.txt")),
+                    kind: FileDiffKind::New,
+                    status: SyncFileStatus::Copied,
+                    source_size: Some(1),
+                    target_size: Some(0),
+                    error: None,
+                },
+                Instant::now(),
+            );
+        }
 
-        assert_eq!(selection.candidate_task_id.as_deref(), Some("watch-a"));
+        let entries = batch.expect("batch size flush should trigger");
+        assert_eq!(entries.len(), 50);
     }
 
     #[test]
-    fn test_source_recommendation_matches_by_device_serial() {
-        let task = build_uuid_task(
-            "task-1",
-            SourceUuidType::Disk,
-            "missing-disk",
-            "/DCIM",
-            Some(SourceIdentitySnapshot {
-                device_serial: Some("SERIAL-1".to_string()),
-                ..SourceIdentitySnapshot::default()
-            }),
-        );
-        let mut candidate = build_volume("Card A", "/Volumes/CardA");
-        candidate.disk_uuid = Some("disk-new".to_string());
-        candidate.device_serial = Some("SERIAL-1".to_string());
+    fn test_sync_live_state_flushes_pending_entries_explicitly() {
+        let live = SyncLiveState::new();
+        assert!(live
+            .record_entry(
+                SyncFileEntry {
+                    path: PathBuf::from("tail.txt"),
+                    kind: FileDiffKind::New,
+                    status: SyncFileStatus::Copied,
+                    source_size: Some(1),
+                    target_size: Some(0),
+                    error: None,
+                },
+                Instant::now(),
+            )
+            .is_none());
 
-        let recommendation = find_task_source_recommendation(&task, &[candidate]).unwrap();
-        assert_eq!(recommendation.proposed_uuid, "disk-new");
-        assert_eq!(recommendation.proposed_uuid_type, "disk");
-        assert_eq!(recommendation.confidence_label, "high");
-        assert!(recommendation
-            .evidence
-            .iter()
-            .any(|item| item == "device serial matched"));
+        let entries = live
+            .flush_pending_entries()
+            .expect("explicit flush should return pending entries");
+        assert_eq!(entries.len(), 1);
     }
 
-    #[test]
-    fn test_source_recommendation_matches_by_device_guid() {
-        let task = build_uuid_task(
-            "task-1",
-            SourceUuidType::Disk,
-            "missing-disk",
-            "/",
-            Some(SourceIdentitySnapshot {
-                device_guid: Some("GUID-1".to_string()),
-                ..SourceIdentitySnapshot::default()
-            }),
-        );
-        let mut candidate = build_volume("Card A", "/Volumes/CardA");
-        candidate.disk_uuid = Some("disk-new".to_string());
-        candidate.device_guid = Some("GUID-1".to_string());
+    #[tokio::test]
+    async fn test_sync_dry_run_internal_returns_sorted_diff_order() {
+        let state = build_app_state();
+        let base = tempdir().expect("tempdir should be created");
+        let source = base.path().join("source");
+        let target = base.path().join("target");
+        std::fs::create_dir_all(&source).expect("source directory should be created");
+        std::fs::create_dir_all(&target).expect("target directory should be created");
 
-        let recommendation = find_task_source_recommendation(&task, &[candidate]).unwrap();
-        assert!(recommendation
-            .evidence
+        std::fs::write(source.join("b.txt"), b"bbb").expect("should write b.txt");
+        std::fs::write(source.join("a.txt"), b"aaa").expect("should write a.txt");
+
+        let result = sync_dry_run_internal(
+            None,
+            None,
+            "task-1".to_string(),
+            source.clone(),
+            target.clone(),
+            false,
+            Vec::new(),
+            SyncRunOptions::default(),
+            &state,
+            None,
+            None,
+        )
+        .await
+        .expect("dry run should succeed");
+
+        let paths: Vec<String> = result
+            .diffs
             .iter()
-            .any(|item| item == "device GUID matched"));
+            .map(|diff| diff.path.to_string_lossy().to_string())
+            .collect();
+
+        assert_eq!(paths, vec!["a.txt".to_string(), "b.txt".to_string()]);
     }
 
-    #[test]
-    fn test_source_recommendation_matches_by_media_uuid() {
-        let task = build_uuid_task(
-            "task-1",
-            SourceUuidType::Disk,
-            "missing-disk",
-            "/",
-            Some(SourceIdentitySnapshot {
-                media_uuid: Some("MEDIA-1".to_string()),
-                ..SourceIdentitySnapshot::default()
-            }),
-        );
-        let mut candidate = build_volume("Card A", "/Volumes/CardA");
-        candidate.disk_uuid = Some("disk-new".to_string());
-        candidate.media_uuid = Some("MEDIA-1".to_string());
+    #[tokio::test]
+    async fn test_sync_dry_run_internal_includes_orphans_when_requested() {
+        let state = build_app_state();
+        let base = tempdir().expect("tempdir should be created");
+        let source = base.path().join("source");
+        let target = base.path().join("target");
+        std::fs::create_dir_all(&source).expect("source directory should be created");
+        std::fs::create_dir_all(&target).expect("target directory should be created");
+
+        std::fs::write(source.join("a.txt"), b"aaa").expect("should write a.txt");
+        std::fs::write(target.join("stale.txt"), b"stale").expect("should write stale.txt");
+
+        let without_orphans = sync_dry_run_internal(
+            None,
+            None,
+            "task-1".to_string(),
+            source.clone(),
+            target.clone(),
+            false,
+            Vec::new(),
+            SyncRunOptions::default(),
+            &state,
+            None,
+            None,
+        )
+        .await
+        .expect("dry run should succeed");
+        assert!(without_orphans.orphans.is_none());
+        assert!(without_orphans.orphan_summary.is_none());
 
-        let recommendation = find_task_source_recommendation(&task, &[candidate]).unwrap();
-        assert!(recommendation
-            .evidence
-            .iter()
-            .any(|item| item == "media UUID matched"));
-    }
+        let result = sync_dry_run_internal(
+            None,
+            None,
+            "task-1".to_string(),
+            source.clone(),
+            target.clone(),
+            false,
+            Vec::new(),
+            SyncRunOptions {
+                include_orphans: true,
+                ..SyncRunOptions::default()
+            },
+            &state,
+            None,
+            None,
+        )
+        .await
+        .expect("dry run with orphans should succeed");
 
-    #[test]
-    fn test_source_recommendation_resolves_stale_volume_uuid_via_device_serial() {
-        let task = build_uuid_task(
-            "task-1",
-            SourceUuidType::Volume,
-            "old-volume",
-            "/DCIM",
-            Some(SourceIdentitySnapshot {
-                device_serial: Some("SERIAL-1".to_string()),
-                ..SourceIdentitySnapshot::default()
-            }),
+        assert_eq!(result.diffs.len(), 1);
+        assert_eq!(result.diffs[0].path, PathBuf::from("a.txt"));
+        let orphans = result.orphans.expect("orphans should be attached");
+        assert_eq!(orphans.len(), 1);
+        assert_eq!(orphans[0].path, PathBuf::from("stale.txt"));
+        assert_eq!(
+            result.orphan_summary,
+            Some(OrphanSummary {
+                file_count: 1,
+                dir_count: 0,
+                total_bytes: 5,
+            })
         );
-        let mut candidate = build_volume("Card A", "/Volumes/CardA");
-        candidate.volume_uuid = Some("volume-new".to_string());
-        candidate.device_serial = Some("SERIAL-1".to_string());
-
-        let recommendation = find_task_source_recommendation(&task, &[candidate]).unwrap();
-        assert_eq!(recommendation.proposed_uuid, "volume-new");
-        assert_eq!(recommendation.proposed_uuid_type, "volume");
     }
 
-    #[test]
-    fn test_source_recommendation_preserves_parsed_sub_path_when_field_missing() {
-        let mut task = build_uuid_task(
-            "task-1",
-            SourceUuidType::Disk,
-            "missing-disk",
-            "/DCIM",
-            Some(SourceIdentitySnapshot {
-                device_serial: Some("SERIAL-1".to_string()),
-                ..SourceIdentitySnapshot::default()
-            }),
-        );
-        task.source_sub_path = None;
-        let mut candidate = build_volume("Card A", "/Volumes/CardA");
-        candidate.disk_uuid = Some("disk-new".to_string());
-        candidate.device_serial = Some("SERIAL-1".to_string());
+    #[tokio::test]
+    async fn test_sync_dry_run_bypass_checksum_cache_rehashes_snapshot_files() {
+        let state = build_app_state();
+        let base = tempdir().expect("tempdir should be created");
+        let source = base.path().join("source");
+        let target = base.path().join("target");
+        std::fs::create_dir_all(&source).expect("source directory should be created");
+        std::fs::create_dir_all(&target).expect("target directory should be created");
+        // Same size and mtime, different content: only a fresh hash notices.
+        let same_time = filetime::FileTime::from_unix_time(1_700_000_000, 0);
+        for (root, content) in [(&source, b"good"), (&target, b"rot!")] {
+            let path = root.join("photo.raw");
+            std::fs::write(&path, content).expect("should write photo.raw");
+            filetime::set_file_mtime(&path, same_time).expect("should set mtime");
+        }
+        let snapshot = crate::sync_engine::SyncEngine::new(source.clone(), target.clone())
+            .capture_sync_snapshot(&[])
+            .await
+            .expect("snapshot should capture");
+        write_task_sync_snapshot("task-1", &snapshot, &state)
+            .await
+            .expect("snapshot should be written");
 
-        let recommendation = find_task_source_recommendation(&task, &[candidate]).unwrap();
-        assert_eq!(recommendation.suggested_source, "[DISK_UUID:disk-new]/DCIM");
-    }
+        let dry_run = |bypass_checksum_cache| {
+            sync_dry_run_internal(
+                None,
+                None,
+                "task-1".to_string(),
+                source.clone(),
+                target.clone(),
+                true,
+                Vec::new(),
+                SyncRunOptions {
+                    checksum_changed_only: true,
+                    bypass_checksum_cache,
+                    ..SyncRunOptions::default()
+                },
+                &state,
+                None,
+                None,
+            )
+        };
 
-    #[test]
-    fn test_source_recommendation_does_not_match_transport_serial_alone() {
-        let task = build_uuid_task(
-            "task-1",
-            SourceUuidType::Disk,
-            "missing-disk",
-            "/",
-            Some(SourceIdentitySnapshot {
-                transport_serial: Some("USB-BRIDGE".to_string()),
-                ..SourceIdentitySnapshot::default()
-            }),
-        );
-        let mut candidate = build_volume("Card A", "/Volumes/CardA");
-        candidate.disk_uuid = Some("disk-new".to_string());
-        candidate.transport_serial = Some("USB-BRIDGE".to_string());
+        let trusted = dry_run(false).await.expect("dry run should succeed");
+        assert!(trusted.diffs.is_empty());
 
-        assert!(find_task_source_recommendation(&task, &[candidate]).is_none());
+        let bypassed = dry_run(true).await.expect("dry run should succeed");
+        assert_eq!(bypassed.diffs.len(), 1);
+        assert_eq!(bypassed.diffs[0].path, PathBuf::from("photo.raw"));
     }
 
-    #[test]
-    fn test_source_recommendation_continues_past_ambiguous_device_serial() {
-        let task = build_uuid_task(
-            "task-1",
-            SourceUuidType::Disk,
-            "missing-disk",
-            "/",
-            Some(SourceIdentitySnapshot {
-                device_serial: Some("SERIAL-1".to_string()),
-                media_uuid: Some("MEDIA-UNIQUE".to_string()),
-                ..SourceIdentitySnapshot::default()
-            }),
-        );
-        let mut first = build_volume("Card A", "/Volumes/CardA");
-        first.disk_uuid = Some("disk-a".to_string());
-        first.device_serial = Some("SERIAL-1".to_string());
-        first.media_uuid = Some("MEDIA-UNIQUE".to_string());
-
-        let mut second = build_volume("Card B", "/Volumes/CardB");
-        second.disk_uuid = Some("disk-b".to_string());
-        second.device_serial = Some("SERIAL-1".to_string());
-        second.media_uuid = Some("MEDIA-OTHER".to_string());
+    #[tokio::test]
+    async fn test_incomplete_sync_markers_persist_until_cleared() {
+        let state = build_app_state();
+        let marker = IncompleteSyncMarker {
+            task_id: "task-1".to_string(),
+            task_name: "Archive".to_string(),
+            source: PathBuf::from("/tmp/source"),
+            target: PathBuf::from("/Volumes/Backup/target"),
+            checksum_mode: true,
+            verify_after_copy: false,
+            exclude_patterns: vec!["*.tmp".to_string()],
+            options: SyncRunOptions {
+                update_existing_only: true,
+                ..SyncRunOptions::default()
+            },
+            started_at: 1_700_000_000_000,
+        };
+        let earlier = IncompleteSyncMarker {
+            task_id: "task-0".to_string(),
+            started_at: 1_600_000_000_000,
+            ..marker.clone()
+        };
 
-        let recommendation = find_task_source_recommendation(&task, &[first, second]).unwrap();
-        assert_eq!(recommendation.proposed_uuid, "disk-a");
-        assert!(recommendation
-            .evidence
-            .iter()
-            .any(|item| item == "media UUID matched"));
+        assert!(list_incomplete_syncs_internal(&state).await.is_empty());
+        write_incomplete_sync_marker(&marker, &state)
+            .await
+            .expect("marker should be written");
+        write_incomplete_sync_marker(&earlier, &state)
+            .await
+            .expect("marker should be written");
+        std::fs::write(
+            state
+                .config_store
+                .config_dir()
+                .join("incomplete_syncs")
+                .join("broken.json"),
+            b"not json",
+        )
+        .expect("should write broken marker");
+
+        let markers = list_incomplete_syncs_internal(&state).await;
+        assert_eq!(markers, vec![earlier.clone(), marker.clone()]);
+
+        clear_incomplete_sync_marker("task-1", &state).await;
+        clear_incomplete_sync_marker("task-1", &state).await;
+        assert_eq!(list_incomplete_syncs_internal(&state).await, vec![earlier]);
+    }
+
+    fn build_sync_execution_result(
+        files_copied: u64,
+        bytes_copied: u64,
+        error_count: usize,
+    ) -> SyncExecutionResult {
+        SyncExecutionResult {
+            sync_result: SyncResult {
+                files_copied,
+                bytes_copied,
+                errors: (0..error_count)
+                    .map(|index| SyncError {
+                        path: PathBuf::from(format!("failed-{index}.txt")),
+                        message: "copy failed".to_string(),
+                        kind: SyncErrorKind::CopyFailed,
+                    })
+                    .collect(),
+                ..SyncResult::default()
+            },
+            conflict_session_id: None,
+            conflict_count: 0,
+            has_pending_conflicts: false,
+            target_preflight: None,
+            recurring_history_detail_entries: Vec::new(),
+        }
     }
 
-    #[test]
-    fn test_source_recommendation_uses_unique_composite_fallback() {
-        let task = build_uuid_task(
-            "task-1",
-            SourceUuidType::Disk,
-            "missing-disk",
-            "/DCIM",
-            Some(SourceIdentitySnapshot {
-                total_bytes: Some(512),
-                filesystem_name: Some("ExFAT".to_string()),
-                volume_name: Some("Untitled".to_string()),
-                transport_serial: Some("USB-BRIDGE".to_string()),
-                ..SourceIdentitySnapshot::default()
-            }),
-        );
-        let mut candidate = build_volume("Untitled", "/Volumes/Untitled");
-        candidate.total_bytes = Some(512);
-        candidate.filesystem_name = Some("ExFAT".to_string());
-        candidate.transport_serial = Some("USB-BRIDGE".to_string());
-        candidate.disk_uuid = Some("disk-new".to_string());
-
-        let recommendation = find_task_source_recommendation(&task, &[candidate]).unwrap();
-        assert_eq!(recommendation.confidence_label, "medium");
-        assert!(recommendation
-            .evidence
-            .iter()
-            .any(|item| item == "capacity matched (512 bytes)"));
-        assert!(recommendation
-            .evidence
-            .iter()
-            .any(|item| item == "transport serial matched"));
+    fn build_progress_log_entry(message: &str) -> crate::logging::LogEntry {
+        crate::logging::LogEntry {
+            id: message.to_string(),
+            timestamp: "2026-04-17T00:00:00Z".to_string(),
+            level: "info".to_string(),
+            message: message.to_string(),
+            task_id: Some("task-1".to_string()),
+            category: LogCategory::FileCopied,
+        }
     }
 
     #[test]
-    fn test_source_recommendation_skips_ambiguous_composite_matches() {
-        let task = build_uuid_task(
-            "task-1",
-            SourceUuidType::Disk,
-            "missing-disk",
-            "/",
-            Some(SourceIdentitySnapshot {
-                total_bytes: Some(512),
-                filesystem_name: Some("ExFAT".to_string()),
-                volume_name: Some("Untitled".to_string()),
-                ..SourceIdentitySnapshot::default()
-            }),
+    fn test_sync_progress_state_honors_configured_intervals() {
+        let settings = RuntimeSettings {
+            progress_emit_ms: Some(30),
+            log_flush_ms: Some(60),
+            ..RuntimeSettings::default()
+        };
+        let state = SyncProgressState::new(
+            false,
+            settings.effective_progress_emit_interval(),
+            settings.effective_log_flush_interval(),
+        );
+        let default_settings = RuntimeSettings::default();
+        let default_state = SyncProgressState::new(
+            false,
+            default_settings.effective_progress_emit_interval(),
+            default_settings.effective_log_flush_interval(),
         );
-        let mut first = build_volume("Untitled", "/Volumes/CardA");
-        first.total_bytes = Some(512);
-        first.filesystem_name = Some("ExFAT".to_string());
-        first.disk_uuid = Some("disk-a".to_string());
-
-        let mut second = build_volume("Untitled", "/Volumes/CardB");
-        second.total_bytes = Some(512);
-        second.filesystem_name = Some("ExFAT".to_string());
-        second.disk_uuid = Some("disk-b".to_string());
 
-        assert!(find_task_source_recommendation(&task, &[first, second]).is_none());
+        assert!(!default_state.should_emit_progress());
+        assert!(default_state
+            .add_log(build_progress_log_entry("default"))
+            .is_none());
+        assert!(!state.should_emit_progress());
+        assert!(state.add_log(build_progress_log_entry("first")).is_none());
+        std::thread::sleep(Duration::from_millis(70));
+        assert!(state.should_emit_progress());
+        assert!(!state.should_emit_progress());
+        let batch = state
+            .add_log(build_progress_log_entry("second"))
+            .expect("flush interval elapsed");
+        assert_eq!(batch.len(), 2);
     }
 
     #[test]
-    fn test_accepting_recommendation_rewrites_uuid_fields_and_refreshes_identity() {
-        let original_task = build_uuid_task(
-            "task-1",
-            SourceUuidType::Disk,
-            "old-disk",
-            "/DCIM",
-            Some(SourceIdentitySnapshot {
-                device_serial: Some("SERIAL-OLD".to_string()),
-                ..SourceIdentitySnapshot::default()
-            }),
+    fn test_runtime_settings_clamp_progress_intervals() {
+        let defaults = RuntimeSettings::default();
+        assert_eq!(
+            defaults.effective_progress_emit_interval(),
+            Duration::from_millis(100)
+        );
+        assert_eq!(
+            defaults.effective_log_flush_interval(),
+            Duration::from_millis(200)
         );
-        let update = UpdateSyncTaskRequest {
-            task_id: original_task.id.clone(),
-            source: Some("[VOLUME_UUID:new-volume]/DCIM".to_string()),
-            source_uuid: Some("new-volume".to_string()),
-            source_uuid_type: Some(SourceUuidType::Volume),
-            source_sub_path: Some("/DCIM".to_string()),
-            ..UpdateSyncTaskRequest::default()
-        };
-        let mut updated_task = apply_sync_task_update(original_task, &update).unwrap();
-
-        let mut volume = build_volume("Card A", "/Volumes/CardA");
-        volume.volume_uuid = Some("new-volume".to_string());
-        volume.disk_uuid = Some("disk-current".to_string());
-        volume.device_serial = Some("SERIAL-NEW".to_string());
-        volume.filesystem_name = Some("ExFAT".to_string());
-        volume.total_bytes = Some(1024);
 
-        refresh_uuid_source_identity(&mut updated_task, &[volume]);
+        let too_fast = RuntimeSettings {
+            progress_emit_ms: Some(0),
+            log_flush_ms: Some(1),
+            ..RuntimeSettings::default()
+        };
+        assert_eq!(
+            too_fast.effective_progress_emit_interval(),
+            Duration::from_millis(16)
+        );
+        assert_eq!(
+            too_fast.effective_log_flush_interval(),
+            Duration::from_millis(50)
+        );
 
-        assert_eq!(updated_task.source_uuid.as_deref(), Some("new-volume"));
-        assert_eq!(updated_task.source_uuid_type, Some(SourceUuidType::Volume));
-        let identity = updated_task.source_identity.unwrap();
+        let too_slow = RuntimeSettings {
+            progress_emit_ms: Some(60_000),
+            log_flush_ms: Some(60_000),
+            ..RuntimeSettings::default()
+        };
         assert_eq!(
-            identity.last_seen_volume_uuid.as_deref(),
-            Some("new-volume")
+            too_slow.effective_progress_emit_interval(),
+            Duration::from_secs(2)
         );
         assert_eq!(
-            identity.last_seen_disk_uuid.as_deref(),
-            Some("disk-current")
+            too_slow.effective_log_flush_interval(),
+            Duration::from_secs(5)
         );
-        assert_eq!(identity.device_serial.as_deref(), Some("SERIAL-NEW"));
     }
 
-    #[test]
-    fn test_retargeting_uuid_source_clears_stale_identity_when_new_media_missing() {
-        let original_task = build_uuid_task(
-            "task-1",
-            SourceUuidType::Disk,
-            "old-disk",
-            "/DCIM",
-            Some(SourceIdentitySnapshot {
-                device_serial: Some("SERIAL-OLD".to_string()),
-                last_seen_disk_uuid: Some("old-disk".to_string()),
-                ..SourceIdentitySnapshot::default()
-            }),
+    #[tokio::test]
+    async fn test_watch_noise_names_round_trip_through_canonical_config() {
+        let state = build_app_state();
+        let defaults = load_canonical_runtime_payload(&state)
+            .await
+            .unwrap()
+            .settings;
+        assert_eq!(
+            defaults.effective_watch_noise_names(),
+            RuntimeSettings::default().effective_watch_noise_names()
         );
-        let update = UpdateSyncTaskRequest {
-            task_id: original_task.id.clone(),
-            source: Some("[VOLUME_UUID:new-volume]/DCIM".to_string()),
-            source_uuid: Some("new-volume".to_string()),
-            source_uuid_type: Some(SourceUuidType::Volume),
-            source_sub_path: Some("/DCIM".to_string()),
-            ..UpdateSyncTaskRequest::default()
-        };
-        let mut updated_task = apply_sync_task_update(original_task, &update).unwrap();
 
-        assert!(updated_task.source_identity.is_none());
+        let mut settings = state.config_store.load_settings().unwrap();
+        settings.watch_noise_names = Some(vec![".DS_Store".to_string(), "Thumbs.db".to_string()]);
+        state.config_store.save_settings(&settings).unwrap();
 
-        refresh_uuid_source_identity(&mut updated_task, &[]);
-        assert!(updated_task.source_identity.is_none());
+        let runtime = load_canonical_runtime_payload(&state)
+            .await
+            .unwrap()
+            .settings;
+        assert_eq!(
+            runtime.effective_watch_noise_names(),
+            vec![".DS_Store".to_string(), "Thumbs.db".to_string()]
+        );
     }
 
-    #[test]
-    fn test_non_source_updates_preserve_existing_uuid_identity() {
-        let original_task = build_uuid_task(
-            "task-1",
-            SourceUuidType::Disk,
-            "old-disk",
-            "/DCIM",
-            Some(SourceIdentitySnapshot {
-                device_serial: Some("SERIAL-OLD".to_string()),
-                last_seen_disk_uuid: Some("old-disk".to_string()),
-                ..SourceIdentitySnapshot::default()
-            }),
-        );
-        let update = UpdateSyncTaskRequest {
-            task_id: original_task.id.clone(),
-            name: Some("Renamed task".to_string()),
-            ..UpdateSyncTaskRequest::default()
-        };
-        let mut updated_task = apply_sync_task_update(original_task, &update).unwrap();
+    #[tokio::test]
+    async fn test_orphan_min_source_fraction_round_trips_and_validates() {
+        let state = build_app_state();
+        let defaults = load_canonical_runtime_payload(&state)
+            .await
+            .unwrap()
+            .settings;
+        assert_eq!(defaults.effective_orphan_min_source_fraction(), 0.0);
 
-        refresh_uuid_source_identity(&mut updated_task, &[]);
+        let mut settings = state.config_store.load_settings().unwrap();
+        settings.orphan_min_source_fraction = 0.5;
+        state.config_store.save_settings(&settings).unwrap();
+        let runtime = load_canonical_runtime_payload(&state)
+            .await
+            .unwrap()
+            .settings;
+        assert_eq!(runtime.effective_orphan_min_source_fraction(), 0.5);
 
-        let identity = updated_task.source_identity.unwrap();
-        assert_eq!(identity.device_serial.as_deref(), Some("SERIAL-OLD"));
-        assert_eq!(identity.last_seen_disk_uuid.as_deref(), Some("old-disk"));
+        settings.orphan_min_source_fraction = 1.5;
+        state.config_store.save_settings(&settings).unwrap();
+        assert!(load_canonical_runtime_payload(&state)
+            .await
+            .unwrap_err()
+            .contains("orphanMinSourceFraction"));
     }
 
-    #[test]
-    fn test_normalize_uuid_sub_path_normalizes_common_cases() {
-        assert_eq!(normalize_uuid_sub_path("").unwrap(), "/");
-        assert_eq!(normalize_uuid_sub_path("DCIM").unwrap(), "/DCIM");
+    #[tokio::test]
+    async fn test_capacity_sample_interval_round_trips_and_validates() {
+        let state = build_app_state();
+        let defaults = load_canonical_runtime_payload(&state)
+            .await
+            .unwrap()
+            .settings;
         assert_eq!(
-            normalize_uuid_sub_path("//DCIM//100MSDCF//").unwrap(),
-            "/DCIM/100MSDCF"
+            defaults.effective_capacity_sample_interval(),
+            RuntimeSettings::default().effective_capacity_sample_interval()
         );
-    }
 
-    #[test]
-    fn test_normalize_uuid_sub_path_rejects_parent_traversal() {
-        let result = normalize_uuid_sub_path("/DCIM/../secret");
-        assert!(result.is_err());
-        assert!(result
-            .err()
-            .unwrap_or_default()
-            .contains("escapes the mounted volume root"));
+        let mut settings = state.config_store.load_settings().unwrap();
+        settings.capacity_sample_interval_secs = 120;
+        state.config_store.save_settings(&settings).unwrap();
+        let runtime = load_canonical_runtime_payload(&state)
+            .await
+            .unwrap()
+            .settings;
+        assert_eq!(
+            runtime.effective_capacity_sample_interval(),
+            Duration::from_secs(120)
+        );
+
+        settings.capacity_sample_interval_secs = 10;
+        state.config_store.save_settings(&settings).unwrap();
+        assert!(load_canonical_runtime_payload(&state)
+            .await
+            .unwrap_err()
+            .contains("capacitySampleIntervalSecs"));
     }
 
     #[test]
-    fn test_validate_runtime_tasks_rejects_malformed_uuid_token() {
-        let tasks = vec![build_runtime_task_with_paths(
-            "invalid",
-            "[VOLUME_UUID:broken-token",
-            "/dst/invalid",
-            false,
-        )];
-
-        let result = validate_runtime_tasks(&tasks);
-        assert!(result.is_err());
-        assert!(result
-            .err()
-            .unwrap_or_default()
-            .contains("Invalid UUID source token format"));
+    fn test_settings_record_maps_and_validates_license_http_settings() {
+        let mut app_settings = default_settings_record();
+        let config = to_runtime_settings_record(&app_settings).license_http_config();
+        assert_eq!(config.timeout_secs, Some(15));
+        assert_eq!(config.proxy_url, None);
+
+        let patch: crate::config_store::SettingsPatch = serde_json::from_str(
+            r#"{"licenseHttpTimeoutSecs":30,"licenseHttpProxy":" http://proxy.local:3128 "}"#,
+        )
+        .unwrap();
+        patch.apply_to(&mut app_settings);
+        assert!(validate_settings_record(&app_settings).is_ok());
+        let config = to_runtime_settings_record(&app_settings).license_http_config();
+        assert_eq!(config.timeout_secs, Some(30));
+        assert_eq!(config.proxy_url.as_deref(), Some("http://proxy.local:3128"));
+
+        app_settings.license_http_proxy = Some("not a url".to_string());
+        assert!(validate_settings_record(&app_settings)
+            .unwrap_err()
+            .contains("licenseHttpProxy"));
+        app_settings.license_http_proxy = None;
+        app_settings.license_http_timeout_secs = 0;
+        assert!(validate_settings_record(&app_settings)
+            .unwrap_err()
+            .contains("licenseHttpTimeoutSecs"));
+
+        let clear: crate::config_store::SettingsPatch =
+            serde_json::from_str(r#"{"licenseHttpProxy":""}"#).unwrap();
+        app_settings.license_http_proxy = Some("http://proxy.local:3128".to_string());
+        clear.apply_to(&mut app_settings);
+        assert_eq!(app_settings.license_http_proxy, None);
     }
 
     #[test]
-    fn test_validate_runtime_tasks_rejects_uuid_subpath_escape() {
-        let tasks = vec![build_runtime_task_with_paths(
-            "escape",
-            "[VOLUME_UUID:uuid-a]/../../outside",
-            "/dst/escape",
-            false,
-        )];
+    fn test_settings_record_maps_and_validates_progress_intervals() {
+        let mut app_settings = default_settings_record();
+        let runtime = to_runtime_settings_record(&app_settings);
+        assert_eq!(
+            runtime.effective_progress_emit_interval(),
+            Duration::from_millis(100)
+        );
+        assert_eq!(
+            runtime.effective_log_flush_interval(),
+            Duration::from_millis(200)
+        );
 
-        let result = validate_runtime_tasks(&tasks);
-        assert!(result.is_err());
-        assert!(result
-            .err()
-            .unwrap_or_default()
-            .contains("escapes the mounted volume root"));
+        app_settings.progress_emit_ms = 250;
+        app_settings.log_flush_ms = 1_000;
+        assert!(validate_settings_record(&app_settings).is_ok());
+        let runtime = to_runtime_settings_record(&app_settings);
+        assert_eq!(runtime.progress_emit_ms, Some(250));
+        assert_eq!(runtime.log_flush_ms, Some(1_000));
+
+        app_settings.progress_emit_ms = 1;
+        assert!(validate_settings_record(&app_settings)
+            .unwrap_err()
+            .contains("progressEmitMs"));
+        app_settings.progress_emit_ms = 250;
+        app_settings.log_flush_ms = 60_000;
+        assert!(validate_settings_record(&app_settings)
+            .unwrap_err()
+            .contains("logFlushMs"));
     }
 
     #[test]
-    fn test_validate_runtime_tasks_normalizes_unmounted_uuid_tokens_before_overlap_check() {
-        let test_uuid = "NOT_A_REAL_UUID_FOR_TEST_ONLY";
-        let source = format!("[DISK_UUID:{test_uuid}]DCIM");
-        let target = format!("[DISK_UUID:{test_uuid}]/DCIM");
-        let tasks = vec![build_runtime_task_with_paths(
-            "normalize",
-            &source,
-            &target,
-            false,
-        )];
+    fn test_select_sync_notifications_follows_task_prefs_and_outcome() {
+        let defaults = TaskNotificationPrefs::default();
+        assert_eq!(
+            select_sync_notifications(true, &defaults, false, 3),
+            vec![SyncNotificationKind::Conflict]
+        );
+        assert!(select_sync_notifications(true, &defaults, false, 0).is_empty());
+        assert!(select_sync_notifications(true, &defaults, true, 0).is_empty());
 
-        let result = validate_runtime_tasks(&tasks);
-        assert!(result.is_err());
-        assert!(result
-            .err()
-            .unwrap_or_default()
-            .contains("overlapping source/target paths"));
+        let all = TaskNotificationPrefs {
+            on_conflict: true,
+            on_complete: true,
+            on_error: true,
+        };
+        assert_eq!(
+            select_sync_notifications(true, &all, false, 2),
+            vec![
+                SyncNotificationKind::Completed,
+                SyncNotificationKind::Conflict
+            ]
+        );
+        assert_eq!(
+            select_sync_notifications(true, &all, true, 0),
+            vec![SyncNotificationKind::Failed]
+        );
+        assert!(select_sync_notifications(false, &all, true, 2).is_empty());
+
+        let errors_only = TaskNotificationPrefs {
+            on_conflict: false,
+            on_complete: false,
+            on_error: true,
+        };
+        assert!(select_sync_notifications(true, &errors_only, false, 5).is_empty());
+        assert_eq!(
+            select_sync_notifications(true, &errors_only, true, 0),
+            vec![SyncNotificationKind::Failed]
+        );
     }
 
     #[test]
-    fn test_ensure_non_overlapping_paths_rejects_nested_target() {
-        let result = ensure_non_overlapping_paths(
-            Path::new("/Volumes/CARD"),
-            Path::new("/Volumes/CARD/DCIM"),
+    fn test_task_notification_prefs_default_when_missing_from_payload() {
+        let task: RuntimeSyncTask = serde_json::from_value(serde_json::json!({
+            "id": "task-1",
+            "name": "Task",
+            "source": "/tmp/source",
+            "target": "/tmp/target",
+            "notifications": { "onComplete": true },
+        }))
+        .expect("task should deserialize");
+        assert_eq!(
+            task.notifications,
+            TaskNotificationPrefs {
+                on_conflict: true,
+                on_complete: true,
+                on_error: false,
+            }
         );
+    }
 
-        assert!(result.is_err());
-        assert!(result.err().unwrap_or_default().contains("overlap"));
+    fn hm(hour: u32, minute: u32) -> chrono::NaiveTime {
+        chrono::NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
     }
 
     #[test]
-    fn test_classify_missing_target_path_allows_missing_subdir_on_mounted_volume() {
-        let mounted = HashSet::from(["/volumes/evo990".to_string()]);
+    fn test_quiet_hours_within_same_day_window() {
+        let window = (hm(13, 0), hm(15, 30));
 
-        let result = classify_missing_target_path(Path::new("/Volumes/EVO990/repo"), &mounted)
-            .expect("mounted volume root should allow creating subdir");
-
-        assert_eq!(result, TargetPreflightKind::WillCreateDirectory);
+        assert!(is_within_quiet_hours(window, hm(13, 0)));
+        assert!(is_within_quiet_hours(window, hm(14, 45)));
+        assert_eq!(
+            quiet_hours_remaining(window, hm(14, 45)),
+            Some(Duration::from_secs(45 * 60))
+        );
     }
 
     #[test]
-    fn test_classify_missing_target_path_rejects_unmounted_volume_target() {
-        let mounted = HashSet::from(["/volumes/evo990".to_string()]);
+    fn test_quiet_hours_outside_window() {
+        let window = (hm(13, 0), hm(15, 30));
 
-        let result = classify_missing_target_path(Path::new("/Volumes/kimjeongjin/repo"), &mounted);
+        assert!(!is_within_quiet_hours(window, hm(12, 59)));
+        assert!(!is_within_quiet_hours(window, hm(15, 30)));
+        assert_eq!(quiet_hours_remaining(window, hm(18, 0)), None);
 
-        assert!(result.is_err());
-        assert!(result
-            .err()
-            .unwrap_or_default()
-            .contains("Target volume is not mounted"));
+        let empty = (hm(9, 0), hm(9, 0));
+        assert!(!is_within_quiet_hours(empty, hm(9, 0)));
     }
 
-    #[test]
-    fn test_classify_missing_target_path_is_case_insensitive_for_volumes_prefix() {
-        let mounted = HashSet::from(["/volumes/evo990".to_string()]);
+    #[tokio::test]
+    async fn test_watcher_diagnostics_mark_disabled_tasks_and_quiet_hours_as_paused() {
+        let state = build_app_state();
+        let source = tempdir().expect("source temp dir should create");
+        let source_path = source.path().to_string_lossy().to_string();
+        let mut disabled = build_runtime_task("disabled", &source_path, true);
+        disabled.enabled = false;
+        state.runtime_config.write().await.tasks =
+            vec![build_runtime_task("active", &source_path, true), disabled];
+        {
+            let mut manager = state.watcher_manager.write().await;
+            for task_id in ["active", "disabled"] {
+                manager
+                    .start_watching(task_id.to_string(), source.path().to_path_buf(), |_| {})
+                    .unwrap();
+            }
+        }
 
-        let result = classify_missing_target_path(Path::new("/volumes/EVO990/repo"), &mounted)
-            .expect("lowercase /volumes prefix should still be recognized");
+        let paused = |diagnostics: Vec<WatcherDiagnostics>| -> Vec<Option<WatchPauseReason>> {
+            diagnostics.into_iter().map(|entry| entry.paused).collect()
+        };
+        assert_eq!(
+            paused(get_watcher_diagnostics_internal(&state).await),
+            vec![None, Some(WatchPauseReason::Disabled)]
+        );
 
-        assert_eq!(result, TargetPreflightKind::WillCreateDirectory);
+        let now = chrono::Local::now().time();
+        state.runtime_config.write().await.settings.quiet_hours = Some((
+            now - chrono::Duration::minutes(1),
+            now + chrono::Duration::hours(1),
+        ));
+        assert_eq!(
+            paused(get_watcher_diagnostics_internal(&state).await),
+            vec![
+                Some(WatchPauseReason::QuietHours),
+                Some(WatchPauseReason::Disabled)
+            ]
+        );
     }
 
     #[test]
-    fn test_classify_missing_target_path_allows_missing_general_directory() {
-        let mounted = HashSet::from(["/volumes/evo990".to_string()]);
+    fn test_quiet_hours_window_wrapping_midnight() {
+        let window = (hm(22, 0), hm(7, 0));
+
+        assert!(is_within_quiet_hours(window, hm(23, 30)));
+        assert!(is_within_quiet_hours(window, hm(0, 0)));
+        assert!(is_within_quiet_hours(window, hm(6, 59)));
+        assert!(!is_within_quiet_hours(window, hm(7, 0)));
+        assert!(!is_within_quiet_hours(window, hm(12, 0)));
+        assert_eq!(
+            quiet_hours_remaining(window, hm(23, 0)),
+            Some(Duration::from_secs(8 * 3600))
+        );
+        assert_eq!(
+            quiet_hours_remaining(window, hm(5, 0)),
+            Some(Duration::from_secs(2 * 3600))
+        );
+    }
 
-        let result =
-            classify_missing_target_path(Path::new("/Users/test/new-backup-dir"), &mounted)
-                .expect("general missing directory should be creatable");
+    #[tokio::test]
+    async fn test_retry_failed_files_recopies_recovered_file_and_clears_it() {
+        let state = build_app_state();
+        let source_dir = tempdir().expect("source dir");
+        let target_dir = tempdir().expect("target dir");
+        std::fs::create_dir_all(source_dir.path().join("DCIM")).expect("source subdir");
+        std::fs::write(source_dir.path().join("DCIM/flaky.jpg"), b"now readable")
+            .expect("source file");
+
+        let mut task = build_uuid_task("task-retry", SourceUuidType::Volume, "UUID", "/", None);
+        task.source = source_dir.path().to_string_lossy().to_string();
+        task.target = target_dir.path().to_string_lossy().to_string();
+        task.source_type = Some(SourceType::Path);
+        task.source_uuid = None;
+        task.source_uuid_type = None;
+        task.source_sub_path = None;
+        state
+            .config_store
+            .save_tasks(&[task])
+            .expect("task should save");
 
-        assert_eq!(result, TargetPreflightKind::WillCreateDirectory);
+        let mut execution = build_sync_execution_result(0, 0, 0);
+        execution.sync_result.errors = vec![
+            SyncError {
+                path: PathBuf::from("DCIM/flaky.jpg"),
+                message: "Input/output error".to_string(),
+                kind: SyncErrorKind::CopyFailed,
+            },
+            SyncError {
+                path: PathBuf::from("DCIM/very-long-name.jpg"),
+                message: "path too long".to_string(),
+                kind: SyncErrorKind::PathTooLong,
+            },
+        ];
+        record_task_failed_files("task-retry", &Ok(execution), &state)
+            .await
+            .expect("failed files should be recorded");
+
+        let result = retry_failed_files_internal("task-retry", &state)
+            .await
+            .expect("retry should run");
+        assert_eq!(result.retried.len(), 1);
+        assert!(result.retried[0].success);
+        assert_eq!(result.retried[0].path, PathBuf::from("DCIM/flaky.jpg"));
+        assert_eq!(
+            std::fs::read(target_dir.path().join("DCIM/flaky.jpg")).expect("target file"),
+            b"now readable"
+        );
+        let remaining: Vec<PathBuf> = result
+            .remaining_errors
+            .iter()
+            .map(|error| error.path.clone())
+            .collect();
+        assert_eq!(remaining, vec![PathBuf::from("DCIM/very-long-name.jpg")]);
+
+        let again = retry_failed_files_internal("task-retry", &state)
+            .await
+            .expect("second retry should run");
+        assert!(again.retried.is_empty());
+        assert_eq!(again.remaining_errors.len(), 1);
     }
 
     #[tokio::test]
-    async fn test_preflight_target_path_creates_missing_directory_for_sync() {
-        let base = tempdir().expect("tempdir should be created");
-        let target = base.path().join("nested/backup");
+    async fn test_task_lifetime_stats_accumulate_across_syncs_and_reset() {
+        let state = build_app_state();
 
-        let result = preflight_target_path(&target, true)
+        let empty = get_task_lifetime_stats_internal("task-1", &state)
             .await
-            .expect("sync preflight should create missing directory");
+            .expect("stats should load");
+        assert_eq!(empty.total_runs, 0);
+        assert_eq!(empty.first_seen_at, None);
 
-        assert_eq!(result.kind, TargetPreflightKind::CreatedDirectory);
-        assert!(target.is_dir());
+        let first = record_task_lifetime_stats(
+            "task-1",
+            &Ok(build_sync_execution_result(3, 300, 0)),
+            &state,
+        )
+        .await
+        .expect("first run should be recorded");
+        let second = record_task_lifetime_stats(
+            "task-1",
+            &Ok(build_sync_execution_result(2, 50, 1)),
+            &state,
+        )
+        .await
+        .expect("second run should be recorded");
+        record_task_lifetime_stats(
+            "task-2",
+            &Err(SyncExecutionFailure::new("Source missing".to_string())),
+            &state,
+        )
+        .await
+        .expect("other task should be recorded");
+
+        let stats = get_task_lifetime_stats_internal("task-1", &state)
+            .await
+            .expect("stats should load");
+        assert_eq!(stats, second);
+        assert_eq!(stats.total_runs, 2);
+        assert_eq!(stats.total_files_copied, 5);
+        assert_eq!(stats.total_bytes_copied, 350);
+        assert_eq!(stats.total_errors, 1);
+        assert_eq!(stats.first_seen_at, first.first_seen_at);
+        assert!(stats.last_run_at >= first.last_run_at);
+
+        let other = get_task_lifetime_stats_internal("task-2", &state)
+            .await
+            .expect("stats should load");
+        assert_eq!((other.total_runs, other.total_errors), (1, 1));
+
+        let reset = reset_task_stats_internal("task-1", &state)
+            .await
+            .expect("reset should succeed");
+        assert_eq!(reset.total_runs, 0);
+        let after_reset = get_task_lifetime_stats_internal("task-1", &state)
+            .await
+            .expect("stats should load");
+        assert_eq!(after_reset, reset);
+        assert!(get_task_lifetime_stats_internal("../escape", &state)
+            .await
+            .is_err());
     }
 
     #[tokio::test]
-    async fn test_sync_dry_run_internal_cleans_up_cancel_token_on_error() {
+    async fn test_directory_fingerprint_caches_previous_value_per_path() {
         let state = build_app_state();
         let base = tempdir().expect("tempdir should be created");
-        let target = base.path().join("target");
-        std::fs::create_dir_all(&target).expect("target directory should be created");
+        let tree = base.path().join("card");
+        std::fs::create_dir_all(&tree).expect("tree directory should be created");
+        std::fs::write(tree.join("a.txt"), b"alpha").expect("should write a.txt");
 
-        let result = sync_dry_run_internal(
-            None,
-            None,
-            "task-1".to_string(),
-            base.path().join("missing-source"),
-            target,
-            false,
-            Vec::new(),
-            &state,
-            None,
-            None,
-        )
-        .await;
+        let first = directory_fingerprint_internal(&tree, Vec::new(), false, &state, |_| {})
+            .await
+            .expect("fingerprint should succeed");
+        assert_eq!(first.file_count, 1);
+        assert_eq!(first.previous_fingerprint, None);
 
-        assert!(result.is_err());
-        assert!(state.dry_run_cancel_tokens.read().await.is_empty());
+        let second = directory_fingerprint_internal(&tree, Vec::new(), false, &state, |_| {})
+            .await
+            .expect("fingerprint should succeed");
+        assert_eq!(second.fingerprint, first.fingerprint);
+        assert_eq!(second.previous_fingerprint, Some(first.fingerprint.clone()));
+
+        // Deep mode and different exclusions are cached separately.
+        let deep = directory_fingerprint_internal(&tree, Vec::new(), true, &state, |_| {})
+            .await
+            .expect("deep fingerprint should succeed");
+        assert_eq!(deep.previous_fingerprint, None);
+        let excluded =
+            directory_fingerprint_internal(&tree, vec!["*.txt".to_string()], false, &state, |_| {})
+                .await
+                .expect("fingerprint should succeed");
+        assert_eq!(excluded.file_count, 0);
+        assert_eq!(excluded.previous_fingerprint, None);
     }
 
     #[tokio::test]
-    async fn test_runtime_get_state_includes_dry_running_tasks() {
-        let state = build_app_state();
-
-        {
-            let mut running = state.dry_running_tasks.write().await;
-            running.insert("task-1".to_string());
+    async fn test_stream_directory_listing_emits_each_entry_exactly_once_in_batches() {
+        let base = tempdir().expect("tempdir should be created");
+        let tree = base.path().join("card");
+        std::fs::create_dir_all(tree.join("DCIM/100CANON")).expect("tree should be created");
+        for index in 0..6 {
+            std::fs::write(tree.join(format!("DCIM/100CANON/IMG_{index}.JPG")), b"jpeg")
+                .expect("should write image");
         }
+        std::fs::write(tree.join("notes.tmp"), b"skip").expect("should write excluded file");
 
-        let runtime = runtime_get_state_internal(&state).await;
-
-        assert_eq!(runtime.dry_running_tasks, vec!["task-1".to_string()]);
-    }
-
-    #[test]
-    fn test_dry_run_live_state_throttles_progress_and_flushes_timed_batches() {
-        let live = DryRunLiveState::new();
-        let base = Instant::now();
-        let scan_progress =
-            build_dry_run_progress(DryRunPhase::ScanningSource, "scanning", 1, 0, 1, 0, 0, 0);
+        let mut batches = Vec::new();
+        let complete =
+            stream_directory_listing_internal(&tree, vec!["*.tmp".to_string()], Some(3), |event| {
+                batches.push(event)
+            })
+            .await
+            .expect("listing should succeed");
 
-        let (first_progress, first_batch) = live.record_progress(scan_progress.clone(), base);
+        // 2 directories + 6 files, split 3/3/2.
+        assert_eq!(complete.total_entries, 8);
+        assert_eq!(complete.total_files, 6);
+        assert_eq!(complete.total_bytes, 24);
+        assert_eq!(complete.batch_count, 3);
         assert_eq!(
-            first_progress
-                .as_ref()
-                .map(|progress| progress.message.as_str()),
-            Some("scanning")
+            batches
+                .iter()
+                .map(|batch| (batch.batch_index, batch.entries.len()))
+                .collect::<Vec<_>>(),
+            vec![(0, 3), (1, 3), (2, 2)]
         );
-        assert!(first_batch.is_none());
-
-        let (second_progress, second_batch) =
-            live.record_progress(scan_progress.clone(), base + Duration::from_millis(50));
-        assert!(second_progress.is_none());
-        assert!(second_batch.is_none());
 
-        let compare_progress =
-            build_dry_run_progress(DryRunPhase::Comparing, "compare", 2, 3, 3, 2, 1, 4096);
-        let diff_a = FileDiff {
-            path: PathBuf::from("b.txt"),
-            kind: FileDiffKind::New,
-            source_size: Some(1024),
-            target_size: None,
-            checksum_source: None,
-            checksum_target: None,
-        };
-        let diff_b = FileDiff {
-            path: PathBuf::from("a.txt"),
-            kind: FileDiffKind::Modified,
-            source_size: Some(2048),
-            target_size: Some(1024),
-            checksum_source: None,
-            checksum_target: None,
-        };
+        let listed: Vec<String> = batches
+            .iter()
+            .flat_map(|batch| batch.entries.iter().map(|entry| entry.path.clone()))
+            .collect();
+        let unique: HashSet<&String> = listed.iter().collect();
+        assert_eq!(unique.len(), listed.len());
+        assert!(listed.contains(&"DCIM".to_string()));
+        assert!(listed.contains(&"DCIM/100CANON/IMG_5.JPG".to_string()));
+        assert!(!listed.iter().any(|path| path.ends_with(".tmp")));
+    }
 
-        assert!(live
-            .record_diff(
-                diff_a,
-                compare_progress.clone(),
-                base + Duration::from_millis(120)
-            )
-            .is_none());
-        assert!(live
-            .record_diff(
-                diff_b,
-                compare_progress.clone(),
-                base + Duration::from_millis(180)
-            )
-            .is_none());
+    #[tokio::test]
+    async fn test_list_conflict_group_contents_paginates_folder_items() {
+        let state = build_app_state();
+        let mut items: Vec<TargetNewerConflictItem> = (0..5)
+            .map(|index| {
+                let mut item = build_conflict_item(&format!("DCIM/100CANON/IMG_{index:03}.JPG"));
+                item.id = format!("item-{index}");
+                item
+            })
+            .collect();
+        let mut outside = build_conflict_item("DCIM/100CANON_OLD/IMG_999.JPG");
+        outside.id = "item-outside".to_string();
+        items.push(outside);
+        items.reverse();
+        state.conflict_review_sessions.write().await.insert(
+            "session-1".to_string(),
+            build_conflict_session(
+                "session-1",
+                "task-1",
+                "Task One",
+                Path::new("/tmp/source"),
+                Path::new("/tmp/target"),
+                items,
+            ),
+        );
 
-        let (third_progress, timed_batch) =
-            live.record_progress(compare_progress.clone(), base + Duration::from_millis(320));
-        assert!(third_progress.is_some());
-        let (diffs, batch_progress) = timed_batch.expect("timed batch should flush");
-        assert_eq!(diffs.len(), 2);
-        assert_eq!(batch_progress.phase, DryRunPhase::Comparing);
-        assert_eq!(batch_progress.message, "compare");
-    }
+        let first =
+            list_conflict_group_contents_internal("session-1", "DCIM/100CANON/", 0, 2, &state)
+                .await
+                .expect("first page should load");
+        assert_eq!(first.group_id, "DCIM/100CANON");
+        assert_eq!(first.total_count, 5);
+        assert_eq!(
+            first
+                .entries
+                .iter()
+                .map(|entry| entry.relative_path.as_str())
+                .collect::<Vec<_>>(),
+            vec!["IMG_000.JPG", "IMG_001.JPG"]
+        );
+        assert_eq!(first.entries[0].item_id, "item-0");
+        assert_eq!(first.entries[0].source.size, 16);
+        assert_eq!(first.entries[0].target.modified_unix_ms, Some(110));
 
-    #[test]
-    fn test_dry_run_live_state_flushes_on_batch_size_limit() {
-        let live = DryRunLiveState::new();
-        let progress =
-            build_dry_run_progress(DryRunPhase::Comparing, "compare", 50, 50, 50, 50, 0, 1024);
+        let last =
+            list_conflict_group_contents_internal("session-1", "DCIM/100CANON", 4, 2, &state)
+                .await
+                .expect("last page should load");
+        assert_eq!(last.total_count, 5);
+        assert_eq!(last.entries.len(), 1);
+        assert_eq!(last.entries[0].relative_path, "IMG_004.JPG");
 
-        let mut batch = None;
-        for index in 0..50 {
-            let diff = FileDiff {
-                path: PathBuf::from(format!("file-{index}.txt")),
-                kind: FileDiffKind::New,
-                source_size: Some(1),
-                target_size: None,
-                checksum_source: None,
-                checksum_target: None,
-            };
-            batch = live.record_diff(diff, progress.clone(), Instant::now());
-        }
+        let past_end =
+            list_conflict_group_contents_internal("session-1", "DCIM/100CANON", 10, 2, &state)
+                .await
+                .expect("page past the end should be empty");
+        assert!(past_end.entries.is_empty());
+        assert_eq!(past_end.total_count, 5);
 
-        let (diffs, batch_progress) = batch.expect("batch size flush should trigger");
-        assert_eq!(diffs.len(), 50);
-        assert_eq!(batch_progress.phase, DryRunPhase::Comparing);
+        assert!(
+            list_conflict_group_contents_internal("session-1", "DCIM/200", 0, 2, &state)
+                .await
+                .is_err()
+        );
+        assert!(
+            list_conflict_group_contents_internal("missing", "DCIM/100CANON", 0, 2, &state)
+                .await
+                .is_err()
+        );
     }
 
     #[test]
-    fn test_sync_live_state_flushes_timed_batches() {
-        let live = SyncLiveState::new();
-        let base = Instant::now();
+    fn test_build_conflict_sessions_csv_row_shape() {
+        let mut item = build_conflict_item("docs/report, final.txt");
+        item.note = Some("checked by \"lead\"".to_string());
+        let session = build_conflict_session(
+            "session-1",
+            "task-1",
+            "Task One",
+            Path::new("/tmp/source"),
+            Path::new("/tmp/target"),
+            vec![item],
+        );
 
-        assert!(live
-            .record_entry(
-                SyncFileEntry {
-                    path: PathBuf::from("a.txt"),
-                    kind: FileDiffKind::New,
-                    status: SyncFileStatus::Copied,
-                    source_size: Some(1),
-                    target_size: Some(0),
-                    error: None,
-                },
-                base,
-            )
-            .is_none());
+        let csv = build_conflict_sessions_csv(&[to_conflict_detail(&session)]);
+        let lines: Vec<&str> = csv.lines().collect();
 
-        let batch = live.record_entry(
-            SyncFileEntry {
-                path: PathBuf::from("b.txt"),
-                kind: FileDiffKind::Modified,
-                status: SyncFileStatus::Failed,
-                source_size: Some(2),
-                target_size: Some(1),
-                error: Some("copy failed".to_string()),
-            },
-            base + Duration::from_millis(250),
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0], CONFLICT_EXPORT_CSV_HEADER);
+        assert_eq!(
+            lines[1],
+            "session-1,task-1,Task One,\"docs/report, final.txt\",\"/tmp/source/docs/report, final.txt\",\"/tmp/target/docs/report, final.txt\",16,100,8,110,pending,\"checked by \"\"lead\"\"\","
         );
-
-        let entries = batch.expect("timed batch should flush");
-        assert_eq!(entries.len(), 2);
     }
 
-    #[test]
-    fn test_sync_live_state_flushes_on_batch_size_limit() {
-        let live = SyncLiveState::new();
-
-        let mut batch = None;
-        for index in 0..50 {
-            batch = live.record_entry(
-                SyncFileEntry {
-                    path: PathBuf::from(format!("file-{index}.txt")),
-                    kind: FileDiffKind::New,
-                    status: SyncFileStatus::Copied,
-                    source_size: Some(1),
-                    target_size: Some(0),
-                    error: None,
-                },
-                Instant::now(),
+    #[tokio::test]
+    async fn test_recent_conflict_resolutions_are_sorted_and_skip_unresolved() {
+        let state = build_app_state();
+        let resolved_item = |path: &str, status: ConflictItemStatus, resolved_at: i64| {
+            let mut item = build_conflict_item(path);
+            item.id = format!("item-{path}");
+            item.status = status;
+            item.resolved_at_unix_ms = Some(resolved_at);
+            item
+        };
+        {
+            let mut sessions = state.conflict_review_sessions.write().await;
+            sessions.insert(
+                "session-1".to_string(),
+                build_conflict_session(
+                    "session-1",
+                    "task-1",
+                    "Task One",
+                    Path::new("/tmp/source"),
+                    Path::new("/tmp/target"),
+                    vec![
+                        resolved_item("old.txt", ConflictItemStatus::Skipped, 1_000),
+                        build_conflict_item("pending.txt"),
+                        resolved_item("newest.txt", ConflictItemStatus::ForceCopied, 3_000),
+                    ],
+                ),
+            );
+            let mut kept = resolved_item("kept.txt", ConflictItemStatus::KeptTarget, 2_000);
+            kept.note = Some("approved edit".to_string());
+            sessions.insert(
+                "session-2".to_string(),
+                build_conflict_session(
+                    "session-2",
+                    "task-2",
+                    "Task Two",
+                    Path::new("/tmp/source"),
+                    Path::new("/tmp/target"),
+                    vec![kept],
+                ),
             );
         }
 
-        let entries = batch.expect("batch size flush should trigger");
-        assert_eq!(entries.len(), 50);
+        let all = get_recent_conflict_resolutions_internal(10, None, &state).await;
+        let paths: Vec<&str> = all.iter().map(|r| r.relative_path.as_str()).collect();
+        assert_eq!(paths, vec!["newest.txt", "kept.txt", "old.txt"]);
+        assert_eq!(all[0].action, ConflictItemStatus::ForceCopied);
+        assert_eq!(all[1].session_id, "session-2");
+        assert_eq!(all[1].task_name, "Task Two");
+        assert_eq!(all[1].note.as_deref(), Some("approved edit"));
+
+        let recent = get_recent_conflict_resolutions_internal(1, Some(1_500), &state).await;
+        assert_eq!(recent.len(), 1);
+        assert_eq!(recent[0].relative_path, "newest.txt");
+        let since_only = get_recent_conflict_resolutions_internal(10, Some(1_500), &state).await;
+        assert_eq!(since_only.len(), 2);
     }
 
-    #[test]
-    fn test_sync_live_state_flushes_pending_entries_explicitly() {
-        let live = SyncLiveState::new();
-        assert!(live
-            .record_entry(
-                SyncFileEntry {
-                    path: PathBuf::from("tail.txt"),
-                    kind: FileDiffKind::New,
-                    status: SyncFileStatus::Copied,
-                    source_size: Some(1),
-                    target_size: Some(0),
-                    error: None,
-                },
-                Instant::now(),
-            )
-            .is_none());
+    #[tokio::test]
+    async fn test_export_conflict_sessions_includes_resolution_details() {
+        let state = build_app_state();
+        let base = tempdir().expect("tempdir should be created");
+        let mut resolved = build_conflict_item("a.txt");
+        resolved.id = "item-resolved".to_string();
+        resolved.status = ConflictItemStatus::KeptTarget;
+        resolved.note = Some("target is the approved edit".to_string());
+        resolved.resolved_at_unix_ms = Some(1_700_000_000_000);
+        let pending = build_conflict_item("b.txt");
+        {
+            let mut sessions = state.conflict_review_sessions.write().await;
+            sessions.insert(
+                "session-1".to_string(),
+                build_conflict_session(
+                    "session-1",
+                    "task-1",
+                    "Task One",
+                    Path::new("/tmp/source"),
+                    Path::new("/tmp/target"),
+                    vec![resolved, pending],
+                ),
+            );
+            sessions.insert(
+                "session-2".to_string(),
+                build_conflict_session(
+                    "session-2",
+                    "task-2",
+                    "Task Two",
+                    Path::new("/tmp/source"),
+                    Path::new("/tmp/target"),
+                    vec![build_conflict_item("c.txt")],
+                ),
+            );
+        }
 
-        let entries = live
-            .flush_pending_entries()
-            .expect("explicit flush should return pending entries");
-        assert_eq!(entries.len(), 1);
+        let json_path = base.path().join("conflicts.json");
+        let exported = export_conflict_sessions_internal(
+            &json_path,
+            Some(vec!["session-1".to_string()]),
+            &state,
+        )
+        .await
+        .expect("json export should succeed");
+        assert_eq!(exported.session_count, 1);
+        assert_eq!(exported.item_count, 2);
+        let content = std::fs::read_to_string(&json_path).expect("export should be readable");
+        let json: serde_json::Value =
+            serde_json::from_str(&content).expect("export should be valid JSON");
+        let item = &json[0]["items"][0];
+        assert_eq!(item["status"], "keptTarget");
+        assert_eq!(item["note"], "target is the approved edit");
+        assert_eq!(item["resolvedAtUnixMs"], 1_700_000_000_000_i64);
+
+        let csv_path = base.path().join("conflicts.csv");
+        let exported = export_conflict_sessions_internal(&csv_path, None, &state)
+            .await
+            .expect("csv export should succeed");
+        assert_eq!(exported.session_count, 2);
+        assert_eq!(exported.item_count, 3);
+        let csv = std::fs::read_to_string(&csv_path).expect("csv export should be readable");
+        assert!(csv
+            .lines()
+            .any(|line| line.ends_with("keptTarget,target is the approved edit,1700000000000")));
+
+        let missing = export_conflict_sessions_internal(
+            &json_path,
+            Some(vec!["missing".to_string()]),
+            &state,
+        )
+        .await;
+        assert!(missing.is_err());
+        let invalid =
+            export_conflict_sessions_internal(Path::new("/tmp/out;rm.csv"), None, &state).await;
+        assert!(invalid.is_err());
     }
 
     #[tokio::test]
-    async fn test_sync_dry_run_internal_returns_sorted_diff_order() {
+    async fn test_export_dry_run_writes_result_that_round_trips() {
         let state = build_app_state();
         let base = tempdir().expect("tempdir should be created");
         let source = base.path().join("source");
@@ -4163,10 +8082,32 @@ mod integration_tests {
         std::fs::create_dir_all(&source).expect("source directory should be created");
         std::fs::create_dir_all(&target).expect("target directory should be created");
 
-        std::fs::write(source.join("b.txt"), b"bbb").expect("should write b.txt");
         std::fs::write(source.join("a.txt"), b"aaa").expect("should write a.txt");
+        std::fs::write(source.join("b.txt"), b"bbbb").expect("should write b.txt");
+        std::fs::write(target.join("stale.txt"), b"stale").expect("should write stale.txt");
+        let output_path = base.path().join("dry-run.json");
+        let run_options = SyncRunOptions {
+            include_orphans: true,
+            ..SyncRunOptions::default()
+        };
 
-        let result = sync_dry_run_internal(
+        let exported = export_dry_run_internal(
+            "task-1".to_string(),
+            source.clone(),
+            target.clone(),
+            false,
+            Vec::new(),
+            run_options.clone(),
+            &output_path,
+            &state,
+        )
+        .await
+        .expect("export should succeed");
+        assert_eq!(exported.path, output_path.to_string_lossy());
+        assert_eq!(exported.diff_count, 2);
+        assert_eq!(exported.orphan_count, 1);
+
+        let in_memory = sync_dry_run_internal(
             None,
             None,
             "task-1".to_string(),
@@ -4174,20 +8115,21 @@ mod integration_tests {
             target.clone(),
             false,
             Vec::new(),
+            run_options,
             &state,
             None,
             None,
         )
         .await
         .expect("dry run should succeed");
+        let content = std::fs::read_to_string(&output_path).expect("export should be readable");
+        let round_tripped: DryRunResult =
+            serde_json::from_str(&content).expect("export should parse as DryRunResult");
 
-        let paths: Vec<String> = result
-            .diffs
-            .iter()
-            .map(|diff| diff.path.to_string_lossy().to_string())
-            .collect();
-
-        assert_eq!(paths, vec!["a.txt".to_string(), "b.txt".to_string()]);
+        assert_eq!(
+            serde_json::to_value(&round_tripped).unwrap(),
+            serde_json::to_value(&in_memory).unwrap()
+        );
     }
 
     #[tokio::test]
@@ -4208,6 +8150,7 @@ mod integration_tests {
             target.clone(),
             false,
             Vec::new(),
+            SyncRunOptions::default(),
             &state,
             None,
             None,
@@ -4240,6 +8183,7 @@ mod integration_tests {
             target.clone(),
             false,
             Vec::new(),
+            SyncRunOptions::default(),
             &state,
             None,
             None,
@@ -4262,6 +8206,88 @@ mod integration_tests {
         assert_eq!(second_paths, vec!["b.txt".to_string()]);
     }
 
+    #[tokio::test]
+    async fn test_override_target_sync_copies_to_override_and_leaves_configured_target() {
+        let base = tempdir().expect("tempdir should be created");
+        let source = base.path().join("source");
+        let configured_target = base.path().join("configured");
+        let override_target = base.path().join("override");
+        for dir in [&source, &configured_target, &override_target] {
+            std::fs::create_dir_all(dir).expect("directory should be created");
+        }
+        std::fs::write(source.join("keep.txt"), b"keep").expect("should write keep.txt");
+        std::fs::write(source.join("skip.tmp"), b"skip").expect("should write skip.tmp");
+
+        let mut task =
+            build_runtime_task_with_paths("task-1", &source.to_string_lossy(), "", false);
+        task.target = configured_target.to_string_lossy().to_string();
+        task.exclusion_sets = vec!["tmp".to_string()];
+        let runtime_config = RuntimeConfigPayload {
+            tasks: vec![task],
+            exclusion_sets: vec![RuntimeExclusionSet {
+                id: "tmp".to_string(),
+                name: "Temp".to_string(),
+                patterns: vec!["*.tmp".to_string()],
+            }],
+            ..RuntimeConfigPayload::default()
+        };
+
+        let plan = resolve_override_target_sync("task-1", &override_target, &runtime_config)
+            .expect("override plan should resolve");
+        assert_eq!(plan.source, source);
+        assert_eq!(plan.target, override_target);
+        assert_eq!(plan.exclude_patterns, vec!["*.tmp".to_string()]);
+
+        let engine = crate::sync_engine::SyncEngine::new(plan.source, plan.target);
+        let options = crate::sync_engine::types::SyncOptions {
+            checksum_mode: plan.checksum_mode,
+            verify_after_copy: plan.verify_after_copy,
+            exclude_patterns: plan.exclude_patterns,
+            ..crate::sync_engine::types::SyncOptions::default()
+        };
+        engine
+            .sync_files(&options, |_| {}, |_| {})
+            .await
+            .expect("override sync should succeed");
+
+        assert_eq!(
+            std::fs::read(override_target.join("keep.txt")).unwrap(),
+            b"keep"
+        );
+        assert!(!override_target.join("skip.tmp").exists());
+        assert_eq!(std::fs::read_dir(&configured_target).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_override_target_sync_rejects_overlap_missing_target_and_unknown_task() {
+        let base = tempdir().expect("tempdir should be created");
+        let source = base.path().join("source");
+        std::fs::create_dir_all(source.join("nested")).expect("source should be created");
+        let runtime_config = RuntimeConfigPayload {
+            tasks: vec![build_runtime_task(
+                "task-1",
+                &source.to_string_lossy(),
+                false,
+            )],
+            ..RuntimeConfigPayload::default()
+        };
+
+        let overlap =
+            resolve_override_target_sync("task-1", &source.join("nested"), &runtime_config)
+                .expect_err("target inside source should be rejected");
+        assert!(overlap.contains("overlap"));
+        let missing =
+            resolve_override_target_sync("task-1", &base.path().join("missing"), &runtime_config)
+                .expect_err("missing target should be rejected");
+        assert!(missing.contains("not an existing directory"));
+        let relative = resolve_override_target_sync("task-1", Path::new("backup"), &runtime_config)
+            .expect_err("relative target should be rejected");
+        assert!(relative.contains("absolute"));
+        let unknown = resolve_override_target_sync("task-2", base.path(), &runtime_config)
+            .expect_err("unknown task should be rejected");
+        assert!(unknown.contains("Sync task not found"));
+    }
+
     #[tokio::test]
     async fn test_validate_dry_run_artifact_accepts_unchanged_snapshot_and_rejects_changes() {
         let base = tempdir().expect("tempdir should be created");
@@ -4478,6 +8504,10 @@ mod integration_tests {
             progress_phase_to_log_category(&SyncProgressPhase::Copying),
             Some(LogCategory::FileCopied)
         );
+        assert_eq!(
+            progress_phase_to_log_category(&SyncProgressPhase::Deleting),
+            Some(LogCategory::FileDeleted)
+        );
         assert_eq!(
             progress_phase_to_log_category(&SyncProgressPhase::ScanningSource),
             None
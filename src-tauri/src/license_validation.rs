@@ -16,6 +16,37 @@ const LEMON_SQUEEZY_STORE_ID_ENV: &str = "SYNCWATCHER_LEMON_SQUEEZY_STORE_ID";
 const LEMON_SQUEEZY_PRODUCT_ID_ENV: &str = "SYNCWATCHER_LEMON_SQUEEZY_PRODUCT_ID";
 const LEMON_SQUEEZY_VARIANT_ID_ENV: &str = "SYNCWATCHER_LEMON_SQUEEZY_VARIANT_ID";
 
+/// 라이선스 API 요청 기본 제한 시간 (연결 + 응답 전체)
+pub(crate) const DEFAULT_LICENSE_HTTP_TIMEOUT_SECS: u64 = 15;
+/// 0초 제한은 모든 요청을 즉시 실패시키므로 최소 1초로 올림
+pub(crate) const MIN_LICENSE_HTTP_TIMEOUT_SECS: u64 = 1;
+
+/// 라이선스 API HTTP 클라이언트 설정 (RuntimeSettings에서 전달)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct LicenseHttpConfig {
+    /// 요청 제한 시간(초). 비어 있으면 DEFAULT_LICENSE_HTTP_TIMEOUT_SECS
+    pub timeout_secs: Option<u64>,
+    /// 명시적 프록시 URL. 비어 있으면 HTTPS_PROXY/NO_PROXY 환경 변수를 따름
+    pub proxy_url: Option<String>,
+}
+
+impl LicenseHttpConfig {
+    fn effective_timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(
+            self.timeout_secs
+                .unwrap_or(DEFAULT_LICENSE_HTTP_TIMEOUT_SECS)
+                .max(MIN_LICENSE_HTTP_TIMEOUT_SECS),
+        )
+    }
+
+    fn effective_proxy_url(&self) -> Option<&str> {
+        self.proxy_url
+            .as_deref()
+            .map(str::trim)
+            .filter(|url| !url.is_empty())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct LemonSqueezyConfig {
     store_id: u64,
@@ -108,7 +139,7 @@ struct LsDeactivateResponse {
 ///
 /// # Returns
 /// 라이선스 상태 파일의 절대 경로
-fn license_state_path(app: &tauri::AppHandle) -> Result<std::path::PathBuf, String> {
+pub(crate) fn license_state_path(app: &tauri::AppHandle) -> Result<std::path::PathBuf, String> {
     let app_data = app.path().app_data_dir().map_err(|e| e.to_string())?;
     Ok(app_data.join(LICENSE_STATE_FILE))
 }
@@ -245,6 +276,51 @@ impl LemonSqueezyConfig {
     }
 }
 
+/// 제한 시간과 프록시 설정을 적용한 라이선스 API 클라이언트를 만듭니다.
+///
+/// 명시적 프록시가 없으면 reqwest 기본 동작대로 HTTPS_PROXY/NO_PROXY 환경 변수를 따르고,
+/// 명시적 프록시를 쓸 때도 NO_PROXY 예외는 유지합니다.
+fn build_license_http_client(config: &LicenseHttpConfig) -> Result<reqwest::Client, String> {
+    let timeout = config.effective_timeout();
+    let mut builder = reqwest::Client::builder()
+        .connect_timeout(timeout)
+        .timeout(timeout);
+    if let Some(url) = config.effective_proxy_url() {
+        let proxy = reqwest::Proxy::all(url)
+            .map_err(|error| format!("Invalid license proxy URL: {error}"))?
+            .no_proxy(reqwest::NoProxy::from_env());
+        builder = builder.proxy(proxy);
+    }
+    builder
+        .build()
+        .map_err(|error| format!("Failed to build license HTTP client: {error}"))
+}
+
+/// 앱 런타임 설정에서 라이선스 HTTP 설정을 읽습니다. 상태가 없으면 기본값.
+async fn load_license_http_config(app: &tauri::AppHandle) -> LicenseHttpConfig {
+    match app.try_state::<crate::AppState>() {
+        Some(state) => state
+            .runtime_config
+            .read()
+            .await
+            .settings
+            .license_http_config(),
+        None => LicenseHttpConfig::default(),
+    }
+}
+
+/// 요청 실패를 사용자용 메시지로 바꿉니다. 제한 시간 초과는 별도 문구로 구분합니다.
+fn describe_license_request_error(error: &reqwest::Error, config: &LicenseHttpConfig) -> String {
+    if error.is_timeout() {
+        format!(
+            "License server timed out after {}s",
+            config.effective_timeout().as_secs()
+        )
+    } else {
+        format!("Network error: {error}")
+    }
+}
+
 /// Lemon Squeezy에서 라이선스 키를 활성화합니다.
 ///
 /// # Arguments
@@ -259,7 +335,8 @@ pub async fn activate_license_key(
     license_key: String,
 ) -> Result<serde_json::Value, String> {
     let config = LemonSqueezyConfig::load()?;
-    let client = reqwest::Client::new();
+    let http_config = load_license_http_config(&app).await;
+    let client = build_license_http_client(&http_config)?;
     let instance_name = get_instance_name();
 
     let response = client
@@ -271,7 +348,7 @@ pub async fn activate_license_key(
         ])
         .send()
         .await
-        .map_err(|e| format!("Network error: {}", e))?;
+        .map_err(|e| describe_license_request_error(&e, &http_config))?;
 
     let body: LsActivateResponse = response
         .json()
@@ -345,7 +422,14 @@ pub async fn validate_license_key(app: tauri::AppHandle) -> Result<serde_json::V
     };
     let config = LemonSqueezyConfig::load()?;
 
-    let client = reqwest::Client::new();
+    let http_config = load_license_http_config(&app).await;
+    let client = match build_license_http_client(&http_config) {
+        Ok(client) => client,
+        Err(error) => {
+            eprintln!("[LicenseValidation] {}", error);
+            return check_grace_period(&state);
+        }
+    };
 
     let response = client
         .post("https://api.lemonsqueezy.com/v1/licenses/validate")
@@ -411,7 +495,10 @@ pub async fn validate_license_key(app: tauri::AppHandle) -> Result<serde_json::V
             }
         }
         Err(e) => {
-            eprintln!("[LicenseValidation] Network error: {}", e);
+            eprintln!(
+                "[LicenseValidation] {}",
+                describe_license_request_error(&e, &http_config)
+            );
             check_grace_period(&state)
         }
     }
@@ -431,7 +518,8 @@ pub async fn deactivate_license_key(app: tauri::AppHandle) -> Result<serde_json:
     };
     let config = LemonSqueezyConfig::load()?;
 
-    let client = reqwest::Client::new();
+    let http_config = load_license_http_config(&app).await;
+    let client = build_license_http_client(&http_config)?;
     let response = client
         .post("https://api.lemonsqueezy.com/v1/licenses/deactivate")
         .header("Accept", "application/json")
@@ -441,7 +529,7 @@ pub async fn deactivate_license_key(app: tauri::AppHandle) -> Result<serde_json:
         ])
         .send()
         .await
-        .map_err(|error| format!("Network error: {error}"))?;
+        .map_err(|error| describe_license_request_error(&error, &http_config))?;
 
     let body: LsDeactivateResponse = response
         .json()
@@ -557,7 +645,11 @@ fn check_grace_period(state: &LicenseState) -> Result<serde_json::Value, String>
 
 #[cfg(test)]
 mod tests {
-    use super::{mask_license_key, LemonSqueezyConfig};
+    use super::{
+        build_license_http_client, mask_license_key, LemonSqueezyConfig, LicenseHttpConfig,
+        DEFAULT_LICENSE_HTTP_TIMEOUT_SECS,
+    };
+    use std::time::{Duration, Instant};
 
     #[test]
     fn parses_required_and_optional_lemon_config() {
@@ -598,4 +690,59 @@ mod tests {
         assert_eq!(mask_license_key("abcd1234wxyz9876"), "abcd…9876");
         assert_eq!(mask_license_key("short"), "****");
     }
+
+    #[test]
+    fn license_http_timeout_defaults_and_clamps() {
+        assert_eq!(
+            LicenseHttpConfig::default().effective_timeout(),
+            Duration::from_secs(DEFAULT_LICENSE_HTTP_TIMEOUT_SECS)
+        );
+        let zero = LicenseHttpConfig {
+            timeout_secs: Some(0),
+            proxy_url: None,
+        };
+        assert_eq!(zero.effective_timeout(), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn rejects_invalid_license_proxy_url() {
+        let config = LicenseHttpConfig {
+            timeout_secs: None,
+            proxy_url: Some("not a url".to_string()),
+        };
+        let error = build_license_http_client(&config).expect_err("invalid proxy should fail");
+        assert!(error.contains("Invalid license proxy URL"));
+
+        let blank = LicenseHttpConfig {
+            timeout_secs: None,
+            proxy_url: Some("  ".to_string()),
+        };
+        assert!(build_license_http_client(&blank).is_ok());
+    }
+
+    #[tokio::test]
+    async fn license_http_client_honors_configured_timeout() {
+        // 연결은 받지만 응답하지 않는 서버
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("listener should bind");
+        let address = listener.local_addr().expect("listener should have address");
+        let _server = std::thread::spawn(move || {
+            let _connections: Vec<_> = listener.incoming().take(1).collect();
+            std::thread::sleep(Duration::from_secs(10));
+        });
+
+        let config = LicenseHttpConfig {
+            timeout_secs: Some(1),
+            proxy_url: None,
+        };
+        let client = build_license_http_client(&config).expect("client should build");
+        let started = Instant::now();
+        let error = client
+            .get(format!("http://{address}/"))
+            .send()
+            .await
+            .expect_err("silent server should time out");
+
+        assert!(error.is_timeout());
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
 }
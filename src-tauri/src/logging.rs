@@ -1,6 +1,6 @@
 use crate::AppState;
 use serde::{Deserialize, Serialize};
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::sync::{Arc, Mutex};
 use tauri::Emitter;
 
@@ -18,7 +18,7 @@ pub struct LogEntry {
     pub category: LogCategory,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, Default)]
 pub enum LogCategory {
     SyncStarted,
     SyncCompleted,
@@ -64,6 +64,104 @@ impl LogCategory {
     }
 }
 
+fn timestamp_ms(timestamp: &str) -> Option<i64> {
+    chrono::DateTime::parse_from_rfc3339(timestamp)
+        .ok()
+        .map(|timestamp| timestamp.timestamp_millis())
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum SyncRunOutcome {
+    Running,
+    Completed,
+    Failed,
+}
+
+/// One sync run reconstructed from the `SyncStarted` / `SyncCompleted` / `SyncError`
+/// entries of a task.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncRun {
+    pub task_id: String,
+    pub started_at: String,
+    pub finished_at: Option<String>,
+    /// Only known when both the start and the end of the run are still buffered.
+    pub duration_ms: Option<i64>,
+    pub outcome: SyncRunOutcome,
+    pub summary: Option<String>,
+    pub errors: Vec<String>,
+    pub conflict_session_ids: Vec<String>,
+}
+
+impl SyncRun {
+    fn open(entry: &LogEntry, task_id: &str) -> Self {
+        Self {
+            task_id: task_id.to_string(),
+            started_at: entry.timestamp.clone(),
+            finished_at: None,
+            duration_ms: None,
+            outcome: SyncRunOutcome::Running,
+            summary: None,
+            errors: Vec::new(),
+            conflict_session_ids: Vec::new(),
+        }
+    }
+
+    fn close(&mut self, entry: &LogEntry, outcome: SyncRunOutcome, has_start: bool) {
+        self.finished_at = Some(entry.timestamp.clone());
+        self.outcome = outcome;
+        if has_start {
+            self.duration_ms = timestamp_ms(&entry.timestamp)
+                .zip(self.started_at_ms())
+                .map(|(finished, started)| (finished - started).max(0));
+        }
+    }
+
+    pub fn started_at_ms(&self) -> Option<i64> {
+        timestamp_ms(&self.started_at)
+    }
+}
+
+/// Correlate sync lifecycle entries (oldest first) into runs, oldest first. A `SyncError`
+/// ends the open run as failed; end entries without a buffered start become runs of their own.
+pub fn build_sync_runs(entries: &[LogEntry]) -> Vec<SyncRun> {
+    let mut runs: Vec<SyncRun> = Vec::new();
+    let mut open_runs: HashMap<String, usize> = HashMap::new();
+
+    for entry in entries {
+        let Some(task_id) = entry.task_id.as_deref() else {
+            continue;
+        };
+        match entry.category {
+            LogCategory::SyncStarted => {
+                open_runs.insert(task_id.to_string(), runs.len());
+                runs.push(SyncRun::open(entry, task_id));
+            }
+            LogCategory::SyncCompleted | LogCategory::SyncError => {
+                let (index, has_start) = match open_runs.remove(task_id) {
+                    Some(index) => (index, true),
+                    None => {
+                        runs.push(SyncRun::open(entry, task_id));
+                        (runs.len() - 1, false)
+                    }
+                };
+                let run = &mut runs[index];
+                if entry.category == LogCategory::SyncCompleted {
+                    run.summary = Some(entry.message.clone());
+                    run.close(entry, SyncRunOutcome::Completed, has_start);
+                } else {
+                    run.errors.push(entry.message.clone());
+                    run.close(entry, SyncRunOutcome::Failed, has_start);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    runs
+}
+
 /// Event emitted when a new log entry is added
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LogEvent {
@@ -243,6 +341,49 @@ impl LogManager {
             .collect()
     }
 
+    /// Count buffered entries per category, optionally limited to one task and to entries
+    /// logged at or after `since_unix_ms`. Entries whose timestamp cannot be parsed are
+    /// left out when a time window is requested.
+    pub fn get_category_counts(
+        &self,
+        task_id: Option<&str>,
+        since_unix_ms: Option<i64>,
+    ) -> HashMap<LogCategory, usize> {
+        let logs = self.system_logs.lock().unwrap();
+        let mut counts = HashMap::new();
+        for entry in logs.iter() {
+            if task_id.is_some_and(|id| entry.task_id.as_deref() != Some(id)) {
+                continue;
+            }
+            if let Some(since) = since_unix_ms {
+                if !timestamp_ms(&entry.timestamp).is_some_and(|logged_at| logged_at >= since) {
+                    continue;
+                }
+            }
+            *counts.entry(entry.category.clone()).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Sync runs newest first, limited to runs started at or after `since_unix_ms`.
+    pub fn get_sync_runs(&self, since_unix_ms: Option<i64>, limit: usize) -> Vec<SyncRun> {
+        let entries: Vec<LogEntry> = {
+            let logs = self.system_logs.lock().unwrap();
+            logs.iter().cloned().collect()
+        };
+        build_sync_runs(&entries)
+            .into_iter()
+            .rev()
+            .filter(|run| {
+                since_unix_ms.is_none_or(|since| {
+                    run.started_at_ms()
+                        .is_some_and(|started_at| started_at >= since)
+                })
+            })
+            .take(limit)
+            .collect()
+    }
+
     /// Get logs with pagination for better performance with large log sets
     pub fn get_logs_paginated(
         &self,
@@ -452,6 +593,139 @@ mod tests {
             .all(|entry| entry.category.is_task_visible()));
     }
 
+    #[test]
+    fn test_category_counts_honor_task_and_time_filters() {
+        let manager = LogManager::new(20);
+        let entry = |timestamp: &str, task_id: Option<&str>, category: LogCategory| LogEntry {
+            id: timestamp.to_string(),
+            timestamp: timestamp.to_string(),
+            level: "info".to_string(),
+            message: "entry".to_string(),
+            task_id: task_id.map(str::to_string),
+            category,
+        };
+        manager.append_entries(&[
+            entry(
+                "2024-05-01T08:00:00+00:00",
+                Some("task1"),
+                LogCategory::SyncError,
+            ),
+            entry(
+                "2024-05-02T08:00:00+00:00",
+                Some("task1"),
+                LogCategory::SyncError,
+            ),
+            entry(
+                "2024-05-02T09:00:00+00:00",
+                Some("task1"),
+                LogCategory::SyncCompleted,
+            ),
+            entry(
+                "2024-05-02T10:00:00+00:00",
+                Some("task2"),
+                LogCategory::SyncCompleted,
+            ),
+            entry(
+                "2024-05-02T11:00:00+00:00",
+                None,
+                LogCategory::VolumeMounted,
+            ),
+            entry("not-a-timestamp", Some("task1"), LogCategory::Other),
+        ]);
+
+        let all = manager.get_category_counts(None, None);
+        assert_eq!(all.get(&LogCategory::SyncError), Some(&2));
+        assert_eq!(all.get(&LogCategory::SyncCompleted), Some(&2));
+        assert_eq!(all.get(&LogCategory::VolumeMounted), Some(&1));
+        assert_eq!(all.get(&LogCategory::Other), Some(&1));
+
+        let task1 = manager.get_category_counts(Some("task1"), None);
+        assert_eq!(task1.get(&LogCategory::SyncCompleted), Some(&1));
+        assert_eq!(task1.get(&LogCategory::VolumeMounted), None);
+
+        let since = chrono::DateTime::parse_from_rfc3339("2024-05-02T00:00:00+00:00")
+            .unwrap()
+            .timestamp_millis();
+        let task1_today = manager.get_category_counts(Some("task1"), Some(since));
+        assert_eq!(task1_today.get(&LogCategory::SyncError), Some(&1));
+        assert_eq!(task1_today.get(&LogCategory::SyncCompleted), Some(&1));
+        assert_eq!(task1_today.get(&LogCategory::Other), None);
+        assert_eq!(task1_today.values().sum::<usize>(), 2);
+    }
+
+    fn lifecycle_entry(timestamp: &str, task_id: &str, category: LogCategory) -> LogEntry {
+        LogEntry {
+            id: timestamp.to_string(),
+            timestamp: timestamp.to_string(),
+            level: "info".to_string(),
+            message: format!("{category:?}"),
+            task_id: Some(task_id.to_string()),
+            category,
+        }
+    }
+
+    #[test]
+    fn test_sync_runs_collapse_start_and_completion_into_one_run() {
+        let runs = build_sync_runs(&[
+            lifecycle_entry(
+                "2024-05-01T08:00:00+00:00",
+                "task1",
+                LogCategory::SyncStarted,
+            ),
+            lifecycle_entry(
+                "2024-05-01T08:00:01+00:00",
+                "task1",
+                LogCategory::FileCopied,
+            ),
+            lifecycle_entry(
+                "2024-05-01T08:00:42.500+00:00",
+                "task1",
+                LogCategory::SyncCompleted,
+            ),
+        ]);
+
+        assert_eq!(runs.len(), 1);
+        let run = &runs[0];
+        assert_eq!(run.task_id, "task1");
+        assert_eq!(run.outcome, SyncRunOutcome::Completed);
+        assert_eq!(run.duration_ms, Some(42_500));
+        assert_eq!(run.summary.as_deref(), Some("SyncCompleted"));
+        assert!(run.errors.is_empty());
+    }
+
+    #[test]
+    fn test_sync_runs_track_failures_and_interleaved_tasks() {
+        let runs = build_sync_runs(&[
+            lifecycle_entry(
+                "2024-05-01T08:00:00+00:00",
+                "task1",
+                LogCategory::SyncStarted,
+            ),
+            lifecycle_entry(
+                "2024-05-01T08:00:05+00:00",
+                "task2",
+                LogCategory::SyncStarted,
+            ),
+            lifecycle_entry("2024-05-01T08:00:10+00:00", "task1", LogCategory::SyncError),
+            lifecycle_entry(
+                "2024-05-01T08:00:20+00:00",
+                "task3",
+                LogCategory::SyncCompleted,
+            ),
+        ]);
+
+        assert_eq!(runs.len(), 3);
+        assert_eq!(runs[0].outcome, SyncRunOutcome::Failed);
+        assert_eq!(runs[0].duration_ms, Some(10_000));
+        assert_eq!(runs[0].errors, vec!["SyncError".to_string()]);
+        assert_eq!(runs[1].task_id, "task2");
+        assert_eq!(runs[1].outcome, SyncRunOutcome::Running);
+        assert_eq!(runs[1].finished_at, None);
+        assert_eq!(runs[2].task_id, "task3");
+        assert_eq!(runs[2].outcome, SyncRunOutcome::Completed);
+        assert_eq!(runs[2].duration_ms, None);
+    }
+
     #[test]
     fn test_log_category_visibility_whitelist_is_exact() {
         let categories = [
@@ -529,3 +803,15 @@ pub fn get_system_logs(state: tauri::State<'_, AppState>) -> Vec<LogEntry> {
 pub fn get_task_logs(task_id: String, state: tauri::State<'_, AppState>) -> Vec<LogEntry> {
     state.log_manager.get_logs(Some(task_id))
 }
+
+/// 카테고리별 로그 개수 (상태 배지용). `since`는 Unix ms 기준 시작 시각입니다.
+#[tauri::command]
+pub fn get_log_category_counts(
+    task_id: Option<String>,
+    since: Option<i64>,
+    state: tauri::State<'_, AppState>,
+) -> HashMap<LogCategory, usize> {
+    state
+        .log_manager
+        .get_category_counts(task_id.as_deref(), since)
+}
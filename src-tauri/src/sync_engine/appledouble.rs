@@ -0,0 +1,242 @@
+use anyhow::{bail, Result};
+use std::path::{Path, PathBuf};
+
+const APPLE_DOUBLE_MAGIC: u32 = 0x0005_1607;
+const HEADER_LEN: usize = 26;
+const ENTRY_DESCRIPTOR_LEN: usize = 12;
+const ENTRY_RESOURCE_FORK: u32 = 2;
+const ENTRY_FINDER_INFO: u32 = 9;
+const FINDER_INFO_LEN: usize = 32;
+/// Inside the Finder Info entry, the extended attribute header follows the 32-byte
+/// FinderInfo and two bytes of padding.
+const ATTR_HEADER_OFFSET: usize = FINDER_INFO_LEN + 2;
+const ATTR_HEADER_LEN: usize = 36;
+const ATTR_ENTRY_FIXED_LEN: usize = 11;
+
+pub const RESOURCE_FORK_XATTR: &str = "com.apple.ResourceFork";
+pub const FINDER_INFO_XATTR: &str = "com.apple.FinderInfo";
+
+/// Metadata recovered from a `._name` AppleDouble sidecar, expressed as the extended
+/// attributes it stands in for on a native macOS volume.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct AppleDouble {
+    pub xattrs: Vec<(String, Vec<u8>)>,
+}
+
+/// `dir/._name` -> `dir/name`; `None` for paths that are not AppleDouble sidecars.
+pub fn paired_data_path(sidecar: &Path) -> Option<PathBuf> {
+    let name = sidecar.file_name()?.to_str()?;
+    let data_name = name.strip_prefix("._").filter(|rest| !rest.is_empty())?;
+    Some(sidecar.with_file_name(data_name))
+}
+
+/// Parses an AppleDouble (version 2) file. The resource fork becomes
+/// `com.apple.ResourceFork`, a non-empty FinderInfo becomes `com.apple.FinderInfo`, and
+/// any attributes in the Finder Info entry's `ATTR` block are returned as-is.
+pub fn parse(bytes: &[u8]) -> Result<AppleDouble> {
+    if read_u32(bytes, 0)? != APPLE_DOUBLE_MAGIC {
+        bail!("Not an AppleDouble file");
+    }
+
+    let entry_count = read_u16(bytes, 24)? as usize;
+    let mut xattrs = Vec::new();
+    for index in 0..entry_count {
+        let descriptor = HEADER_LEN + index * ENTRY_DESCRIPTOR_LEN;
+        let id = read_u32(bytes, descriptor)?;
+        let offset = read_u32(bytes, descriptor + 4)? as usize;
+        let length = read_u32(bytes, descriptor + 8)? as usize;
+        let entry = slice(bytes, offset, length)?;
+
+        match id {
+            ENTRY_RESOURCE_FORK if !entry.is_empty() => {
+                xattrs.push((RESOURCE_FORK_XATTR.to_string(), entry.to_vec()));
+            }
+            ENTRY_FINDER_INFO if entry.len() >= FINDER_INFO_LEN => {
+                let finder_info = &entry[..FINDER_INFO_LEN];
+                if finder_info.iter().any(|byte| *byte != 0) {
+                    xattrs.push((FINDER_INFO_XATTR.to_string(), finder_info.to_vec()));
+                }
+                if entry.len() >= ATTR_HEADER_OFFSET + ATTR_HEADER_LEN {
+                    parse_attr_block(bytes, offset + ATTR_HEADER_OFFSET, &mut xattrs)?;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(AppleDouble { xattrs })
+}
+
+/// Attribute offsets in the `ATTR` block are relative to the start of the file.
+fn parse_attr_block(
+    bytes: &[u8],
+    header: usize,
+    xattrs: &mut Vec<(String, Vec<u8>)>,
+) -> Result<()> {
+    if slice(bytes, header, 4)? != b"ATTR" {
+        return Ok(());
+    }
+
+    let attr_count = read_u16(bytes, header + 34)? as usize;
+    let mut cursor = header + ATTR_HEADER_LEN;
+    for _ in 0..attr_count {
+        let offset = read_u32(bytes, cursor)? as usize;
+        let length = read_u32(bytes, cursor + 4)? as usize;
+        let name_len = *slice(bytes, cursor + 10, 1)?.first().unwrap_or(&0) as usize;
+        let raw_name = slice(bytes, cursor + ATTR_ENTRY_FIXED_LEN, name_len)?;
+        let name = raw_name.strip_suffix(&[0]).unwrap_or(raw_name);
+        let name = match std::str::from_utf8(name) {
+            Ok(name) if !name.is_empty() => name.to_string(),
+            _ => bail!("Invalid attribute name in AppleDouble file"),
+        };
+        xattrs.push((name, slice(bytes, offset, length)?.to_vec()));
+        cursor = (cursor + ATTR_ENTRY_FIXED_LEN + name_len + 3) & !3;
+    }
+    Ok(())
+}
+
+/// Writes the sidecar's attributes onto `path`. Only macOS can store them natively; other
+/// platforms return an error so callers fall back to copying the sidecar.
+pub fn apply(path: &Path, double: &AppleDouble) -> Result<()> {
+    for (name, value) in &double.xattrs {
+        set_xattr(path, name, value)
+            .map_err(|e| anyhow::anyhow!("Failed to set {} on {}: {}", name, path.display(), e))?;
+    }
+    Ok(())
+}
+
+#[cfg(target_os = "macos")]
+fn set_xattr(path: &Path, name: &str, value: &[u8]) -> std::io::Result<()> {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+
+    let path = CString::new(path.as_os_str().as_bytes())?;
+    let name = CString::new(name)?;
+    // SAFETY: both strings are NUL-terminated and `value` outlives the call.
+    let rc = unsafe {
+        nix::libc::setxattr(
+            path.as_ptr(),
+            name.as_ptr(),
+            value.as_ptr().cast(),
+            value.len(),
+            0,
+            nix::libc::XATTR_NOFOLLOW,
+        )
+    };
+    if rc == 0 {
+        Ok(())
+    } else {
+        Err(std::io::Error::last_os_error())
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+fn set_xattr(_path: &Path, _name: &str, _value: &[u8]) -> std::io::Result<()> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "AppleDouble merge is only supported on macOS",
+    ))
+}
+
+fn slice(bytes: &[u8], offset: usize, length: usize) -> Result<&[u8]> {
+    match offset.checked_add(length) {
+        Some(end) if end <= bytes.len() => Ok(&bytes[offset..end]),
+        _ => bail!("Truncated AppleDouble file"),
+    }
+}
+
+fn read_u16(bytes: &[u8], offset: usize) -> Result<u16> {
+    let raw = slice(bytes, offset, 2)?;
+    Ok(u16::from_be_bytes([raw[0], raw[1]]))
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> Result<u32> {
+    let raw = slice(bytes, offset, 4)?;
+    Ok(u32::from_be_bytes([raw[0], raw[1], raw[2], raw[3]]))
+}
+
+/// Builds a minimal AppleDouble file with a resource fork and one extended attribute.
+#[cfg(test)]
+pub(crate) fn build_test_sidecar(resource_fork: &[u8], xattr: (&str, &[u8])) -> Vec<u8> {
+    let (xattr_name, xattr_value) = xattr;
+    let name_len = xattr_name.len() + 1;
+    let attr_entries_len = (ATTR_ENTRY_FIXED_LEN + name_len + 3) & !3;
+    let finder_info_offset = HEADER_LEN + 2 * ENTRY_DESCRIPTOR_LEN;
+    let attr_header = finder_info_offset + ATTR_HEADER_OFFSET;
+    let attr_data = attr_header + ATTR_HEADER_LEN + attr_entries_len;
+    let finder_info_len = attr_data + xattr_value.len() - finder_info_offset;
+    let resource_offset = finder_info_offset + finder_info_len;
+
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(&APPLE_DOUBLE_MAGIC.to_be_bytes());
+    bytes.extend_from_slice(&0x0002_0000u32.to_be_bytes());
+    bytes.extend_from_slice(&[0; 16]);
+    bytes.extend_from_slice(&2u16.to_be_bytes());
+    for (id, offset, length) in [
+        (ENTRY_FINDER_INFO, finder_info_offset, finder_info_len),
+        (ENTRY_RESOURCE_FORK, resource_offset, resource_fork.len()),
+    ] {
+        bytes.extend_from_slice(&id.to_be_bytes());
+        bytes.extend_from_slice(&(offset as u32).to_be_bytes());
+        bytes.extend_from_slice(&(length as u32).to_be_bytes());
+    }
+
+    bytes.extend_from_slice(&[0; ATTR_HEADER_OFFSET]);
+    bytes.extend_from_slice(b"ATTR");
+    bytes.extend_from_slice(&[0; 4]);
+    bytes.extend_from_slice(&(resource_offset as u32).to_be_bytes());
+    bytes.extend_from_slice(&(attr_data as u32).to_be_bytes());
+    bytes.extend_from_slice(&(xattr_value.len() as u32).to_be_bytes());
+    bytes.extend_from_slice(&[0; 12]);
+    bytes.extend_from_slice(&0u16.to_be_bytes());
+    bytes.extend_from_slice(&1u16.to_be_bytes());
+
+    let entry_start = bytes.len();
+    bytes.extend_from_slice(&(attr_data as u32).to_be_bytes());
+    bytes.extend_from_slice(&(xattr_value.len() as u32).to_be_bytes());
+    bytes.extend_from_slice(&0u16.to_be_bytes());
+    bytes.push(name_len as u8);
+    bytes.extend_from_slice(xattr_name.as_bytes());
+    bytes.push(0);
+    bytes.resize(entry_start + attr_entries_len, 0);
+
+    bytes.extend_from_slice(xattr_value);
+    bytes.extend_from_slice(resource_fork);
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paired_data_path_only_matches_sidecars() {
+        assert_eq!(
+            paired_data_path(Path::new("dir/._photo.jpg")),
+            Some(PathBuf::from("dir/photo.jpg"))
+        );
+        assert_eq!(paired_data_path(Path::new("dir/photo.jpg")), None);
+        assert_eq!(paired_data_path(Path::new("._")), None);
+    }
+
+    #[test]
+    fn test_parse_reads_resource_fork_and_attr_block() {
+        let bytes = build_test_sidecar(b"fork-data", ("com.example.tag", b"blue"));
+        let double = parse(&bytes).unwrap();
+
+        assert_eq!(
+            double.xattrs,
+            vec![
+                ("com.example.tag".to_string(), b"blue".to_vec()),
+                (RESOURCE_FORK_XATTR.to_string(), b"fork-data".to_vec()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_non_appledouble_and_truncated_input() {
+        assert!(parse(b"plain text, not a sidecar").is_err());
+        let bytes = build_test_sidecar(b"fork-data", ("com.example.tag", b"blue"));
+        assert!(parse(&bytes[..bytes.len() - 4]).is_err());
+    }
+}
@@ -0,0 +1,140 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+/// Share of a second the bucket may bank, so idle time does not turn into a long burst.
+const BURST_WINDOW_MS: u64 = 100;
+
+/// Token bucket shared by every sync that should draw from the same byte budget.
+///
+/// A rate of `0` means unlimited. Waiters queue on a FIFO mutex and each sleeps off its own
+/// deficit while holding it, so concurrent consumers are served chunk by chunk in arrival
+/// order and none can starve another.
+#[derive(Debug)]
+pub struct BandwidthLimiter {
+    bytes_per_sec: AtomicU64,
+    bucket: Mutex<Bucket>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    available: f64,
+    last_refill: Instant,
+}
+
+impl BandwidthLimiter {
+    pub fn new(bytes_per_sec: u64) -> Self {
+        Self {
+            bytes_per_sec: AtomicU64::new(bytes_per_sec),
+            bucket: Mutex::new(Bucket {
+                available: Self::burst_capacity(bytes_per_sec),
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    pub fn unlimited() -> Self {
+        Self::new(0)
+    }
+
+    pub fn bytes_per_sec(&self) -> u64 {
+        self.bytes_per_sec.load(Ordering::Relaxed)
+    }
+
+    /// Changes the budget for subsequent `acquire` calls; `0` removes the limit.
+    pub fn set_bytes_per_sec(&self, bytes_per_sec: u64) {
+        self.bytes_per_sec.store(bytes_per_sec, Ordering::Relaxed);
+    }
+
+    fn burst_capacity(bytes_per_sec: u64) -> f64 {
+        (bytes_per_sec.saturating_mul(BURST_WINDOW_MS) / 1000) as f64
+    }
+
+    /// Waits until `bytes` may be transferred under the current budget.
+    pub async fn acquire(&self, bytes: u64) {
+        let rate = self.bytes_per_sec();
+        if rate == 0 || bytes == 0 {
+            return;
+        }
+
+        let mut bucket = self.bucket.lock().await;
+        let now = Instant::now();
+        let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+        bucket.available =
+            (bucket.available + elapsed * rate as f64).min(Self::burst_capacity(rate));
+        bucket.last_refill = now;
+        bucket.available -= bytes as f64;
+
+        if bucket.available < 0.0 {
+            let wait = Duration::from_secs_f64(-bucket.available / rate as f64);
+            tokio::time::sleep(wait).await;
+            bucket.available = 0.0;
+            bucket.last_refill = Instant::now();
+        }
+    }
+}
+
+impl Default for BandwidthLimiter {
+    fn default() -> Self {
+        Self::unlimited()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    async fn consume(limiter: Arc<BandwidthLimiter>, total: u64, chunk: u64) -> Instant {
+        let mut sent = 0;
+        while sent < total {
+            limiter.acquire(chunk).await;
+            sent += chunk;
+        }
+        Instant::now()
+    }
+
+    #[tokio::test]
+    async fn test_unlimited_limiter_does_not_wait() {
+        let limiter = BandwidthLimiter::unlimited();
+        let started = Instant::now();
+        for _ in 0..1000 {
+            limiter.acquire(1024 * 1024).await;
+        }
+        assert!(started.elapsed() < Duration::from_millis(100));
+    }
+
+    #[tokio::test]
+    async fn test_two_consumers_share_global_cap_fairly() {
+        const RATE: u64 = 400_000;
+        let limiter = Arc::new(BandwidthLimiter::new(RATE));
+        let started = Instant::now();
+
+        let first = tokio::spawn(consume(limiter.clone(), 100_000, 10_000));
+        let second = tokio::spawn(consume(limiter.clone(), 100_000, 10_000));
+        let first_done = first.await.unwrap();
+        let second_done = second.await.unwrap();
+        let elapsed = started.elapsed();
+
+        // 200 KB total minus the 40 KB initial burst at 400 KB/s needs at least 400 ms.
+        assert!(elapsed >= Duration::from_millis(380), "{elapsed:?}");
+        assert!(elapsed < Duration::from_secs(2), "{elapsed:?}");
+        // Interleaved service: both finish within a couple of chunks of each other.
+        let gap = if first_done > second_done {
+            first_done - second_done
+        } else {
+            second_done - first_done
+        };
+        assert!(gap < Duration::from_millis(150), "{gap:?}");
+    }
+
+    #[tokio::test]
+    async fn test_rate_change_applies_to_next_acquire() {
+        let limiter = BandwidthLimiter::new(1_000);
+        limiter.set_bytes_per_sec(0);
+        let started = Instant::now();
+        limiter.acquire(1_000_000).await;
+        assert!(started.elapsed() < Duration::from_millis(50));
+        assert_eq!(limiter.bytes_per_sec(), 0);
+    }
+}
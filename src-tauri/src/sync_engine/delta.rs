@@ -0,0 +1,416 @@
+//! rsync-style block delta: signatures of the target's fixed-size blocks are matched against a
+//! rolling window over the source, so only bytes the target does not already hold are written.
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs::File;
+use std::hash::Hasher;
+use std::io::{BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+use twox_hash::XxHash64;
+
+pub const DEFAULT_DELTA_BLOCK_SIZE: usize = 64 * 1024;
+
+/// Files smaller than this are cheaper to copy whole than to sign and match.
+pub const DEFAULT_DELTA_MIN_FILE_SIZE: u64 = 64 * 1024 * 1024;
+
+/// Largest literal run buffered before it is handed to the caller.
+const MAX_LITERAL_CHUNK: usize = 1024 * 1024;
+
+/// rsync's weak checksum; cheap to slide one byte at a time.
+#[derive(Debug, Clone, Copy)]
+struct RollingChecksum {
+    a: u32,
+    b: u32,
+    len: u32,
+}
+
+impl RollingChecksum {
+    fn new(window: &[u8]) -> Self {
+        let len = window.len() as u32;
+        let mut a = 0u32;
+        let mut b = 0u32;
+        for (index, &byte) in window.iter().enumerate() {
+            a = a.wrapping_add(byte as u32);
+            b = b.wrapping_add((len - index as u32).wrapping_mul(byte as u32));
+        }
+        Self { a, b, len }
+    }
+
+    fn digest(&self) -> u32 {
+        (self.a & 0xffff) | ((self.b & 0xffff) << 16)
+    }
+
+    /// Slides the window forward by one byte.
+    fn roll(&mut self, out: u8, incoming: u8) {
+        self.a = self
+            .a
+            .wrapping_sub(out as u32)
+            .wrapping_add(incoming as u32);
+        self.b = self
+            .b
+            .wrapping_sub(self.len.wrapping_mul(out as u32))
+            .wrapping_add(self.a);
+    }
+
+    /// Drops the first byte without taking a new one; used once the source tail is shorter
+    /// than a block.
+    fn roll_out(&mut self, out: u8) {
+        self.a = self.a.wrapping_sub(out as u32);
+        self.b = self.b.wrapping_sub(self.len.wrapping_mul(out as u32));
+        self.len -= 1;
+    }
+}
+
+fn strong_hash(data: &[u8]) -> u64 {
+    let mut hasher = XxHash64::with_seed(0);
+    hasher.write(data);
+    hasher.finish()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockSignature {
+    pub index: u64,
+    pub len: usize,
+    pub weak: u32,
+    pub strong: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeltaOp {
+    /// Reuse block `index` of the existing target.
+    Copy { index: u64 },
+    /// Bytes the target does not have.
+    Literal(Vec<u8>),
+}
+
+/// What a delta transfer would cost compared with copying the whole file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeltaTransferStats {
+    pub file_size: u64,
+    pub block_size: usize,
+    pub matched_blocks: u64,
+    pub matched_bytes: u64,
+    /// Bytes that had to come from the source; the rest were reused from the target.
+    pub bytes_transferred: u64,
+}
+
+fn read_full(reader: &mut impl Read, buffer: &mut [u8]) -> std::io::Result<usize> {
+    let mut filled = 0;
+    while filled < buffer.len() {
+        match reader.read(&mut buffer[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(error) if error.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(error) => return Err(error),
+        }
+    }
+    Ok(filled)
+}
+
+/// Signs every `block_size` block of `reader`; the final block may be shorter.
+pub fn compute_block_signatures(
+    mut reader: impl Read,
+    block_size: usize,
+) -> std::io::Result<Vec<BlockSignature>> {
+    let block_size = block_size.max(1);
+    let mut buffer = vec![0u8; block_size];
+    let mut signatures = Vec::new();
+    loop {
+        let n = read_full(&mut reader, &mut buffer)?;
+        if n == 0 {
+            break;
+        }
+        let block = &buffer[..n];
+        signatures.push(BlockSignature {
+            index: signatures.len() as u64,
+            len: n,
+            weak: RollingChecksum::new(block).digest(),
+            strong: strong_hash(block),
+        });
+        if n < block_size {
+            break;
+        }
+    }
+    Ok(signatures)
+}
+
+/// Streams `source` against the target `signatures`, emitting copy/literal ops in order.
+pub fn compute_delta(
+    signatures: &[BlockSignature],
+    block_size: usize,
+    mut source: impl Read,
+    mut on_op: impl FnMut(DeltaOp) -> std::io::Result<()>,
+) -> std::io::Result<()> {
+    let block_size = block_size.max(1);
+    let mut by_weak: HashMap<u32, Vec<&BlockSignature>> = HashMap::new();
+    for signature in signatures {
+        by_weak.entry(signature.weak).or_default().push(signature);
+    }
+
+    let refill_size = block_size.saturating_mul(4).max(8192);
+    let mut buffer: Vec<u8> = Vec::with_capacity(refill_size + block_size);
+    let mut pos = 0usize;
+    let mut eof = false;
+    let mut literal: Vec<u8> = Vec::new();
+    let mut rolling: Option<RollingChecksum> = None;
+
+    loop {
+        // Keep one byte past the window so the checksum can roll forward.
+        if !eof && buffer.len() - pos <= block_size {
+            buffer.drain(..pos);
+            pos = 0;
+            let start = buffer.len();
+            buffer.resize(start + refill_size, 0);
+            let n = read_full(&mut source, &mut buffer[start..])?;
+            buffer.truncate(start + n);
+            eof = n == 0 || n < refill_size;
+        }
+
+        let available = buffer.len() - pos;
+        if available == 0 {
+            break;
+        }
+        let window_len = available.min(block_size);
+        let window = &buffer[pos..pos + window_len];
+        let checksum = *rolling.get_or_insert_with(|| RollingChecksum::new(window));
+
+        let matched = by_weak.get(&checksum.digest()).and_then(|candidates| {
+            let strong = strong_hash(window);
+            candidates
+                .iter()
+                .find(|signature| signature.len == window_len && signature.strong == strong)
+                .map(|signature| signature.index)
+        });
+
+        if let Some(index) = matched {
+            if !literal.is_empty() {
+                on_op(DeltaOp::Literal(std::mem::take(&mut literal)))?;
+            }
+            on_op(DeltaOp::Copy { index })?;
+            pos += window_len;
+            rolling = None;
+            continue;
+        }
+
+        let out = buffer[pos];
+        literal.push(out);
+        if literal.len() >= MAX_LITERAL_CHUNK {
+            on_op(DeltaOp::Literal(std::mem::take(&mut literal)))?;
+        }
+        if let Some(checksum) = rolling.as_mut() {
+            match buffer.get(pos + window_len) {
+                Some(&incoming) if window_len == block_size => checksum.roll(out, incoming),
+                _ => checksum.roll_out(out),
+            }
+        }
+        pos += 1;
+    }
+
+    if !literal.is_empty() {
+        on_op(DeltaOp::Literal(literal))?;
+    }
+    Ok(())
+}
+
+/// Computes what a delta transfer of `source` onto `target` would cost without writing anything.
+pub fn plan_block_delta(
+    source: &Path,
+    target: &Path,
+    block_size: usize,
+) -> Result<DeltaTransferStats> {
+    let block_size = block_size.max(1);
+    let target_file =
+        File::open(target).with_context(|| format!("Failed to open delta target: {:?}", target))?;
+    let signatures = compute_block_signatures(std::io::BufReader::new(target_file), block_size)?;
+    let source_file =
+        File::open(source).with_context(|| format!("Failed to open delta source: {:?}", source))?;
+
+    let mut stats = DeltaTransferStats {
+        block_size,
+        ..DeltaTransferStats::default()
+    };
+    compute_delta(
+        &signatures,
+        block_size,
+        std::io::BufReader::new(source_file),
+        |op| {
+            match op {
+                DeltaOp::Copy { index } => {
+                    let len = signatures[index as usize].len as u64;
+                    stats.matched_blocks += 1;
+                    stats.matched_bytes += len;
+                    stats.file_size += len;
+                }
+                DeltaOp::Literal(bytes) => {
+                    stats.bytes_transferred += bytes.len() as u64;
+                    stats.file_size += bytes.len() as u64;
+                }
+            }
+            Ok(())
+        },
+    )?;
+    Ok(stats)
+}
+
+/// Rebuilds `target` from its own matching blocks plus the differing source bytes. The result is
+/// written to a temp file beside the target and renamed over it, so a failure leaves the old
+/// target intact.
+pub fn apply_block_delta(
+    source: &Path,
+    target: &Path,
+    block_size: usize,
+) -> Result<DeltaTransferStats> {
+    let block_size = block_size.max(1);
+    let parent = target
+        .parent()
+        .with_context(|| format!("Delta target has no parent directory: {:?}", target))?;
+    let mut existing =
+        File::open(target).with_context(|| format!("Failed to open delta target: {:?}", target))?;
+    let signatures = compute_block_signatures(std::io::BufReader::new(&existing), block_size)?;
+    let source_file =
+        File::open(source).with_context(|| format!("Failed to open delta source: {:?}", source))?;
+
+    let temp = tempfile::NamedTempFile::new_in(parent)
+        .with_context(|| format!("Failed to create delta temp file in {:?}", parent))?;
+    let mut writer = BufWriter::new(temp.as_file());
+    let mut block = vec![0u8; block_size];
+    let mut stats = DeltaTransferStats {
+        block_size,
+        ..DeltaTransferStats::default()
+    };
+
+    compute_delta(
+        &signatures,
+        block_size,
+        std::io::BufReader::new(source_file),
+        |op| {
+            match op {
+                DeltaOp::Copy { index } => {
+                    let len = signatures[index as usize].len;
+                    existing.seek(SeekFrom::Start(index * block_size as u64))?;
+                    existing.read_exact(&mut block[..len])?;
+                    writer.write_all(&block[..len])?;
+                    stats.matched_blocks += 1;
+                    stats.matched_bytes += len as u64;
+                    stats.file_size += len as u64;
+                }
+                DeltaOp::Literal(bytes) => {
+                    writer.write_all(&bytes)?;
+                    stats.bytes_transferred += bytes.len() as u64;
+                    stats.file_size += bytes.len() as u64;
+                }
+            }
+            Ok(())
+        },
+    )?;
+    writer.flush()?;
+    drop(writer);
+    temp.as_file().sync_all()?;
+
+    if let Ok(metadata) = std::fs::metadata(source) {
+        let _ = std::fs::set_permissions(temp.path(), metadata.permissions());
+        if let Ok(modified) = metadata.modified() {
+            let _ = filetime::set_file_mtime(
+                temp.path(),
+                filetime::FileTime::from_system_time(modified),
+            );
+        }
+    }
+
+    temp.persist(target)
+        .with_context(|| format!("Failed to replace delta target: {:?}", target))?;
+    Ok(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn patterned_bytes(len: usize) -> Vec<u8> {
+        let mut state = 0x2545_f491_u32;
+        (0..len)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                state as u8
+            })
+            .collect()
+    }
+
+    #[test]
+    fn rolling_checksum_matches_fresh_checksum_after_roll() {
+        let data = patterned_bytes(64);
+        let mut rolling = RollingChecksum::new(&data[0..16]);
+        for start in 1..=48 {
+            rolling.roll(data[start - 1], data[start + 15]);
+            assert_eq!(
+                rolling.digest(),
+                RollingChecksum::new(&data[start..start + 16]).digest()
+            );
+        }
+
+        let mut shrinking = RollingChecksum::new(&data[48..64]);
+        shrinking.roll_out(data[48]);
+        assert_eq!(
+            shrinking.digest(),
+            RollingChecksum::new(&data[49..64]).digest()
+        );
+    }
+
+    #[test]
+    fn apply_block_delta_transfers_only_changed_region_of_large_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("source.img");
+        let target = dir.path().join("target.img");
+        let block_size = 4096;
+
+        let original = patterned_bytes(4 * 1024 * 1024);
+        // An edit that shifts everything after it plus an append, like a growing image.
+        let mut modified = original[..1_000_000].to_vec();
+        modified.extend_from_slice(b"inserted bytes here");
+        modified.extend_from_slice(&original[1_000_010..]);
+        modified.extend_from_slice(b"appended tail");
+        std::fs::write(&target, &original).unwrap();
+        std::fs::write(&source, &modified).unwrap();
+
+        let plan = plan_block_delta(&source, &target, block_size).unwrap();
+        let stats = apply_block_delta(&source, &target, block_size).unwrap();
+
+        assert_eq!(plan, stats);
+        assert_eq!(stats.file_size, modified.len() as u64);
+        assert_eq!(std::fs::read(&target).unwrap(), modified);
+        assert!(
+            stats.bytes_transferred < 3 * block_size as u64,
+            "transferred {} of {} bytes",
+            stats.bytes_transferred,
+            stats.file_size
+        );
+        assert_eq!(
+            stats.matched_bytes + stats.bytes_transferred,
+            stats.file_size
+        );
+    }
+
+    #[test]
+    fn apply_block_delta_handles_unrelated_and_empty_sources() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("source.bin");
+        let target = dir.path().join("target.bin");
+
+        std::fs::write(&target, patterned_bytes(10_000)).unwrap();
+        std::fs::write(&source, b"completely different").unwrap();
+        let stats = apply_block_delta(&source, &target, 1024).unwrap();
+        assert_eq!(stats.matched_blocks, 0);
+        assert_eq!(stats.bytes_transferred, 20);
+        assert_eq!(std::fs::read(&target).unwrap(), b"completely different");
+
+        std::fs::write(&source, b"").unwrap();
+        let stats = apply_block_delta(&source, &target, 1024).unwrap();
+        assert_eq!(stats.file_size, 0);
+        assert!(std::fs::read(&target).unwrap().is_empty());
+    }
+}
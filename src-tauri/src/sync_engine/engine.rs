@@ -1,12 +1,18 @@
+use crate::sync_engine::appledouble;
+use crate::sync_engine::bandwidth::BandwidthLimiter;
 use crate::sync_engine::types::{
-    ConflictFileSnapshot, DeleteOrphanFailure, DeleteOrphanResult, DryRunPhase, DryRunProgress,
-    DryRunResult, DryRunSummary, FileDiff, FileDiffKind, FileMetadata, OrphanFile, SyncFileEntry,
-    SyncFileStatus, SyncOptions, SyncProgress, SyncProgressPhase, SyncResult,
-    TargetNewerConflictCandidate,
+    validate_source_subdirs, ConflictFileSnapshot, DeleteOrphanFailure, DeleteOrphanResult,
+    DirectoryFingerprint, DriftReport, DryRunPhase, DryRunProgress, DryRunResult, DryRunSummary,
+    FileDiff, FileDiffKind, FileMetadata, MatchedFile, OrphanDeletionSummary, OrphanFile,
+    PathTooLongDiagnostic, RecopyFileResult, ScanMemoryEstimate, SkipReason, SkippedFile,
+    SyncAndVerifyResult, SyncError, SyncErrorKind, SyncFileEntry, SyncFileStatus, SyncOptions,
+    SyncProgress, SyncProgressPhase, SyncResult, SyncSnapshot, SyncSnapshotEntry, TargetLayout,
+    TargetLayoutCollision, TargetLayoutMapping, TargetLayoutPreview, TargetNewerConflictCandidate,
+    MAX_SKIP_REASONS, VERIFY_ALWAYS_ABOVE_BYTES,
 };
 use anyhow::Context;
 use anyhow::Result;
-use globset::{Glob, GlobSetBuilder};
+use globset::{Glob, GlobSet, GlobSetBuilder};
 use std::cmp::Ordering;
 use std::collections::{HashMap, HashSet};
 use std::ffi::OsStr;
@@ -22,6 +28,10 @@ use walkdir::WalkDir; // Import Context trait
 pub struct SyncEngine {
     source: PathBuf,
     target: PathBuf,
+    bandwidth_limiter: Option<Arc<BandwidthLimiter>>,
+    trusted_snapshot: Option<Arc<SyncSnapshot>>,
+    allow_empty_source: bool,
+    min_source_fraction: f64,
 }
 
 const HARD_IGNORED_ROOT_METADATA_DIRS: [&str; 4] = [
@@ -54,6 +64,111 @@ fn is_hard_ignored_root_metadata_dir(relative_path: &Path, is_dir: bool) -> bool
     }
 }
 
+/// Exclusion matcher shared by every walk: each pattern (brace-expanded) matches at the root
+/// and, unless anchored, at any depth.
+fn build_exclude_globset(patterns: &[String]) -> Result<GlobSet> {
+    // Pattern validation constants
+    const MAX_PATTERN_LENGTH: usize = 255;
+    const MAX_PATTERN_COUNT: usize = 300;
+
+    // Validate pattern count
+    if patterns.len() > MAX_PATTERN_COUNT {
+        anyhow::bail!(
+            "Too many exclusion patterns: {} (max: {})",
+            patterns.len(),
+            MAX_PATTERN_COUNT
+        );
+    }
+
+    // Build GlobSet with validation
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        // Skip empty patterns
+        let trimmed = pattern.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        // Validate pattern length
+        if trimmed.len() > MAX_PATTERN_LENGTH {
+            anyhow::bail!(
+                "Exclusion pattern too long: '{}...' ({} chars, max: {})",
+                &trimmed[..50.min(trimmed.len())],
+                trimmed.len(),
+                MAX_PATTERN_LENGTH
+            );
+        }
+
+        // Helper to add glob with error handling
+        let mut add_glob = |p: &str| -> anyhow::Result<()> {
+            match Glob::new(p) {
+                Ok(glob) => {
+                    builder.add(glob);
+                    Ok(())
+                }
+                Err(e) => anyhow::bail!("Invalid exclusion pattern '{}': {}", p, e),
+            }
+        };
+
+        // Expand braces first so every alternative gets its own anchored and
+        // "**/" variant, e.g. "*.{jpg,png}" -> "*.jpg", "**/*.jpg", "*.png", "**/*.png"
+        for expanded in crate::input_validation::expand_glob_braces(trimmed)? {
+            // Add original pattern
+            add_glob(&expanded)?;
+
+            // If pattern doesn't start with explicitly anchored path or wildcard, allow matching in subdirectories
+            // e.g. ".venv" -> "**/.venv"
+            // e.g. "*.log" -> "**/*.log"
+            // e.g. "dist" -> "**/dist"
+            if !expanded.starts_with('/') && !expanded.starts_with("**/") {
+                add_glob(&format!("**/{}", expanded))?;
+            }
+        }
+
+        // Also handle directory contents if the pattern matches a directory name?
+        // filter_entry takes care of directories, but if a pattern is "node_modules", we skip the dir.
+        // If we are already inside? No, filter_entry prevents entering.
+        // So "**/pattern" is sufficient to catch the directory at any depth.
+    }
+    Ok(builder.build()?)
+}
+
+/// Bounded builder for `DryRunResult::skipped`; records nothing unless `collect_skip_reasons`.
+struct SkipCollector {
+    enabled: bool,
+    entries: Vec<SkippedFile>,
+    truncated: bool,
+}
+
+impl SkipCollector {
+    fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            entries: Vec::new(),
+            truncated: false,
+        }
+    }
+
+    fn record(&mut self, path: &Path, reason: SkipReason) {
+        if !self.enabled {
+            return;
+        }
+        if self.entries.len() >= MAX_SKIP_REASONS {
+            self.truncated = true;
+            return;
+        }
+        self.entries.push(SkippedFile {
+            path: path.to_path_buf(),
+            reason,
+        });
+    }
+
+    fn finish(mut self) -> (Vec<SkippedFile>, bool) {
+        self.entries.sort_by(|a, b| a.path.cmp(&b.path));
+        (self.entries, self.truncated)
+    }
+}
+
 async fn reject_symlink_destination_under_root(root: &Path, target: &Path) -> Result<()> {
     let relative = target.strip_prefix(root).with_context(|| {
         format!(
@@ -95,9 +210,160 @@ async fn reject_symlink_destination_under_root(root: &Path, target: &Path) -> Re
     Ok(())
 }
 
+/// Files at least this large copy through a resumable partial file when
+/// `SyncOptions::resume_partial_copies` is set; smaller ones just restart.
+pub const RESUMABLE_COPY_MIN_BYTES: u64 = 64 * 1024 * 1024;
+
+/// Deterministic in-progress name for `target`, so a later run can find it.
+pub fn partial_copy_path(target: &Path) -> PathBuf {
+    let file_name = target
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    target.with_file_name(format!(".{file_name}.syncwatcher-partial"))
+}
+
+/// Records the source metadata a partial file was written from.
+pub fn partial_copy_sidecar_path(target: &Path) -> PathBuf {
+    let file_name = target
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    target.with_file_name(format!(".{file_name}.syncwatcher-partial.json"))
+}
+
+/// Whether `path` is a partial copy or its sidecar. These belong to an unfinished copy, so the
+/// compare leaves them out; orphan scans only hide the ones a later run can still resume.
+pub fn is_partial_copy_artifact(path: &Path) -> bool {
+    path.file_name()
+        .and_then(OsStr::to_str)
+        .is_some_and(|name| {
+            name.ends_with(".syncwatcher-partial") || name.ends_with(".syncwatcher-partial.json")
+        })
+}
+
+/// Target file a partial copy or its sidecar belongs to, or `None` for any other path.
+pub fn partial_copy_owner(path: &Path) -> Option<PathBuf> {
+    let name = path.file_name()?.to_str()?;
+    let owner = name
+        .strip_suffix(".syncwatcher-partial.json")
+        .or_else(|| name.strip_suffix(".syncwatcher-partial"))?
+        .strip_prefix('.')
+        .filter(|owner| !owner.is_empty())?;
+    Some(path.with_file_name(owner))
+}
+
+/// Source identity a partial copy may only be resumed against.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PartialCopyHeader {
+    pub size: u64,
+    pub modified_secs: Option<u64>,
+    pub modified_nanos: u32,
+}
+
+impl PartialCopyHeader {
+    pub fn from_metadata(metadata: &std::fs::Metadata) -> Self {
+        let modified = metadata
+            .modified()
+            .ok()
+            .and_then(|time| time.duration_since(SystemTime::UNIX_EPOCH).ok());
+        Self {
+            size: metadata.len(),
+            modified_secs: modified.map(|elapsed| elapsed.as_secs()),
+            modified_nanos: modified.map_or(0, |elapsed| elapsed.subsec_nanos()),
+        }
+    }
+}
+
 impl SyncEngine {
     pub fn new(source: PathBuf, target: PathBuf) -> Self {
-        Self { source, target }
+        Self {
+            source,
+            target,
+            bandwidth_limiter: None,
+            trusted_snapshot: None,
+            allow_empty_source: false,
+            min_source_fraction: 0.0,
+        }
+    }
+
+    /// Throttles copies through `limiter`, which may be shared with other engines.
+    pub fn with_bandwidth_limiter(mut self, limiter: Arc<BandwidthLimiter>) -> Self {
+        self.bandwidth_limiter = Some(limiter);
+        self
+    }
+
+    /// In checksum mode, skips hashing pairs whose size and mtime on both sides still match
+    /// `snapshot`; only files changed since it was captured are hashed.
+    pub fn with_trusted_snapshot(mut self, snapshot: Arc<SyncSnapshot>) -> Self {
+        self.trusted_snapshot = Some(snapshot);
+        self
+    }
+
+    fn snapshot_proves_unchanged(
+        &self,
+        path: &Path,
+        source_meta: &FileMetadata,
+        target_meta: &FileMetadata,
+    ) -> bool {
+        let Some(entry) = self
+            .trusted_snapshot
+            .as_ref()
+            .and_then(|snapshot| snapshot.entries.get(path))
+        else {
+            return false;
+        };
+        *entry == SyncSnapshotEntry::from_metadata(source_meta)
+            && *entry == SyncSnapshotEntry::from_metadata(target_meta)
+    }
+
+    /// Lets orphan and mirror-deletion scans run even when the source looks empty. Without it a
+    /// scan fails with `ERR_SUSPICIOUS_EMPTY_SOURCE` instead of listing the whole target.
+    pub fn with_allow_empty_source(mut self, allow: bool) -> Self {
+        self.allow_empty_source = allow;
+        self
+    }
+
+    /// Also treats a source holding fewer files than `fraction` (0.0–1.0) of the target's as
+    /// suspiciously empty. `0.0` only catches a source with no files at all.
+    pub fn with_min_source_fraction(mut self, fraction: f64) -> Self {
+        self.min_source_fraction = fraction.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Refuses target-side deletion scans when the source file count suggests a half-mounted or
+    /// unreadable source, where every target file would otherwise look orphaned.
+    fn ensure_source_not_suspiciously_empty(
+        &self,
+        source_files: &[FileMetadata],
+        target_files: &[FileMetadata],
+    ) -> Result<()> {
+        if self.allow_empty_source {
+            return Ok(());
+        }
+        let target_count = target_files.iter().filter(|meta| meta.is_file).count();
+        if target_count == 0 {
+            return Ok(());
+        }
+        let source_count = source_files.iter().filter(|meta| meta.is_file).count();
+        if source_count == 0
+            || (source_count as f64) < self.min_source_fraction * target_count as f64
+        {
+            anyhow::bail!(
+                "{}:Source {:?} has {} file(s) against {} on the target; refusing to list orphans. Pass allowEmptySource to override.",
+                crate::error_codes::ERR_SUSPICIOUS_EMPTY_SOURCE,
+                self.source,
+                source_count,
+                target_count
+            );
+        }
+        Ok(())
+    }
+
+    async fn throttle(&self, bytes: u64) {
+        if let Some(limiter) = &self.bandwidth_limiter {
+            limiter.acquire(bytes).await;
+        }
     }
 
     fn system_time_to_unix_ms(value: Option<SystemTime>) -> Option<i64> {
@@ -132,6 +398,25 @@ impl SyncEngine {
         }
     }
 
+    /// Returns whether `modified` falls inside the optional `modified_after`/`modified_before`
+    /// window. Files outside the window are left untouched by the sync.
+    fn is_within_modified_window(options: &SyncOptions, modified: SystemTime) -> bool {
+        if options.modified_after.is_none() && options.modified_before.is_none() {
+            return true;
+        }
+
+        let Some(modified_ms) = Self::system_time_to_unix_ms(Some(modified)) else {
+            return false;
+        };
+
+        options
+            .modified_after
+            .is_none_or(|after| modified_ms >= after)
+            && options
+                .modified_before
+                .is_none_or(|before| modified_ms <= before)
+    }
+
     fn build_dry_run_progress(
         phase: DryRunPhase,
         message: String,
@@ -152,7 +437,107 @@ impl SyncEngine {
         }
     }
 
+    fn should_compress(options: &SyncOptions, relative_path: &Path) -> bool {
+        if !options.compress {
+            return false;
+        }
+        let Some(extension) = relative_path.extension().and_then(OsStr::to_str) else {
+            return false;
+        };
+        options.compress_extensions.iter().any(|allowed| {
+            allowed
+                .trim_start_matches('.')
+                .eq_ignore_ascii_case(extension)
+        })
+    }
+
+    /// Relative path a source file is written to on the target, accounting for compression.
+    pub fn stored_relative_path(options: &SyncOptions, relative_path: &Path) -> PathBuf {
+        if Self::should_compress(options, relative_path) {
+            Self::compressed_relative_path(relative_path)
+        } else {
+            relative_path.to_path_buf()
+        }
+    }
+
+    fn compressed_relative_path(relative_path: &Path) -> PathBuf {
+        let mut stored = relative_path.as_os_str().to_os_string();
+        stored.push(".gz");
+        PathBuf::from(stored)
+    }
+
+    /// Re-keys source files by their `layout` target path. Directories are dropped (the layout
+    /// decides the target tree), and when two files land on the same target path the first in
+    /// source path order wins; the rest are returned as collisions.
+    fn apply_target_layout(
+        layout: &TargetLayout,
+        mut source_files: Vec<FileMetadata>,
+    ) -> (Vec<FileMetadata>, HashMap<PathBuf, PathBuf>, Vec<PathBuf>) {
+        source_files.sort_by(|a, b| a.path.cmp(&b.path));
+        let mut placed = Vec::new();
+        let mut sources: HashMap<PathBuf, PathBuf> = HashMap::new();
+        let mut collisions = Vec::new();
+        for mut file in source_files.into_iter().filter(|file| file.is_file) {
+            let target_relative = layout.relative_path_for(&file.path, file.modified);
+            if sources.contains_key(&target_relative) {
+                collisions.push(file.path);
+                continue;
+            }
+            sources.insert(
+                target_relative.clone(),
+                std::mem::replace(&mut file.path, target_relative),
+            );
+            placed.push(file);
+        }
+        (placed, sources, collisions)
+    }
+
+    /// Drops source files stored at the same path as another file's compressed copy: with `log`
+    /// compressed, `x.log` is stored as `x.log.gz`, which a literal `x.log.gz` would overwrite.
+    /// The compressed file wins, as the earlier path does in `apply_target_layout`; the dropped
+    /// paths are returned as collisions.
+    fn drop_compressed_name_collisions(
+        options: &SyncOptions,
+        source_files: Vec<FileMetadata>,
+    ) -> (Vec<FileMetadata>, Vec<PathBuf>) {
+        if !options.compress {
+            return (source_files, Vec::new());
+        }
+        let compressed_paths: HashSet<PathBuf> = source_files
+            .iter()
+            .filter(|file| file.is_file && Self::should_compress(options, &file.path))
+            .map(|file| Self::compressed_relative_path(&file.path))
+            .collect();
+        let (kept, dropped): (Vec<FileMetadata>, Vec<FileMetadata>) =
+            source_files.into_iter().partition(|file| {
+                !file.is_file
+                    || Self::should_compress(options, &file.path)
+                    || !compressed_paths.contains(&file.path)
+            });
+        (kept, dropped.into_iter().map(|file| file.path).collect())
+    }
+
+    /// A copy failure is attributed to a disconnected source only when the source root itself
+    /// can no longer be stat'ed as a directory; ordinary per-file errors keep the sync going.
+    async fn is_source_root_accessible(source_root: &Path) -> bool {
+        fs::metadata(source_root)
+            .await
+            .map(|meta| meta.is_dir())
+            .unwrap_or(false)
+    }
+
+    /// Always hashes the file's current content; no digest is cached. Only a trusted sync
+    /// snapshot (`with_trusted_snapshot`) lets the compare skip this for unchanged files.
     async fn calculate_checksum(&self, path: &Path) -> Result<String> {
+        self.calculate_checksum_with_progress(path, |_| {}).await
+    }
+
+    /// `calculate_checksum` that reports the size of every chunk it hashes.
+    async fn calculate_checksum_with_progress(
+        &self,
+        path: &Path,
+        mut on_chunk: impl FnMut(u64),
+    ) -> Result<String> {
         use twox_hash::XxHash64;
 
         let mut file = fs::File::open(path)
@@ -167,24 +552,86 @@ impl SyncEngine {
                 break;
             }
             hasher.write(&buffer[..n]);
+            on_chunk(n as u64);
         }
 
         Ok(format!("{:x}", hasher.finish()))
     }
 
+    /// Same digest as `calculate_checksum`, computed over the decompressed content of a `.gz` copy.
+    async fn calculate_gzip_checksum(&self, path: &Path) -> Result<String> {
+        use std::io::Read;
+        use twox_hash::XxHash64;
+
+        let path = path.to_path_buf();
+        tokio::task::spawn_blocking(move || {
+            let file = std::fs::File::open(&path)
+                .with_context(|| format!("Failed to open file for checksum: {:?}", path))?;
+            let mut decoder = flate2::read::GzDecoder::new(std::io::BufReader::new(file));
+            let mut hasher = XxHash64::with_seed(0);
+            let mut buffer = [0u8; 8192];
+
+            loop {
+                let n = decoder
+                    .read(&mut buffer)
+                    .with_context(|| format!("Failed to decompress file: {:?}", path))?;
+                if n == 0 {
+                    break;
+                }
+                hasher.write(&buffer[..n]);
+            }
+
+            Ok(format!("{:x}", hasher.finish()))
+        })
+        .await?
+    }
+
     async fn read_directory<P>(
         &self,
         dir: &Path,
         phase: DryRunPhase,
         exclude_patterns: &[String],
+        parallel_scan: bool,
+        cancel_token: Option<CancellationToken>,
+        progress_callback: Arc<StdMutex<P>>,
+    ) -> Result<Vec<FileMetadata>>
+    where
+        P: FnMut(DryRunProgress) + Send + 'static,
+    {
+        self.read_directory_scoped(
+            dir,
+            phase,
+            exclude_patterns,
+            None,
+            parallel_scan,
+            cancel_token,
+            progress_callback,
+            None,
+        )
+        .await
+    }
+
+    /// `read_directory` limited to the top-level directories named in `top_level_dirs` (when
+    /// set), that also pushes the relative path of every entry pruned by an exclusion pattern
+    /// into `excluded`. Pruned directories are recorded once.
+    #[allow(clippy::too_many_arguments)]
+    async fn read_directory_scoped<P>(
+        &self,
+        dir: &Path,
+        phase: DryRunPhase,
+        exclude_patterns: &[String],
+        top_level_dirs: Option<&[String]>,
+        parallel_scan: bool,
         cancel_token: Option<CancellationToken>,
         progress_callback: Arc<StdMutex<P>>,
+        excluded: Option<Arc<StdMutex<Vec<PathBuf>>>>,
     ) -> Result<Vec<FileMetadata>>
     where
         P: FnMut(DryRunProgress) + Send + 'static,
     {
         let dir_buf = dir.to_path_buf();
         let patterns = exclude_patterns.to_vec();
+        let top_level_dirs = top_level_dirs.map(<[String]>::to_vec);
 
         tokio::task::spawn_blocking(move || {
             let mut files = Vec::new();
@@ -195,115 +642,113 @@ impl SyncEngine {
                 std::time::Instant::now() - std::time::Duration::from_millis(100);
             let emit_interval = std::time::Duration::from_millis(100);
 
-            // Pattern validation constants
-            const MAX_PATTERN_LENGTH: usize = 255;
-            const MAX_PATTERN_COUNT: usize = 300;
-
-            // Validate pattern count
-            if patterns.len() > MAX_PATTERN_COUNT {
-                anyhow::bail!(
-                    "Too many exclusion patterns: {} (max: {})",
-                    patterns.len(),
-                    MAX_PATTERN_COUNT
-                );
-            }
+            let globs = build_exclude_globset(&patterns)?;
 
-            // Build GlobSet with validation
-            let mut builder = GlobSetBuilder::new();
-            for pattern in &patterns {
-                // Skip empty patterns
-                let trimmed = pattern.trim();
-                if trimmed.is_empty() {
-                    continue;
-                }
+            let is_excluded = {
+                let root = dir_buf.clone();
+                move |path: &Path, is_dir: bool| -> bool {
+                    // Calculate relative path from root
+                    // For root directory itself, relative path is empty or "."
+                    let relative_path = match path.strip_prefix(&root) {
+                        Ok(p) => p,
+                        Err(_) => return false, // Should not happen for children
+                    };
 
-                // Validate pattern length
-                if trimmed.len() > MAX_PATTERN_LENGTH {
-                    anyhow::bail!(
-                        "Exclusion pattern too long: '{}...' ({} chars, max: {})",
-                        &trimmed[..50.min(trimmed.len())],
-                        trimmed.len(),
-                        MAX_PATTERN_LENGTH
-                    );
-                }
+                    if is_hard_ignored_root_metadata_dir(relative_path, is_dir) {
+                        return true;
+                    }
 
-                // Helper to add glob with error handling
-                let mut add_glob = |p: &str| -> anyhow::Result<()> {
-                    match Glob::new(p) {
-                        Ok(glob) => {
-                            builder.add(glob);
-                            Ok(())
+                    // Outside the selected top-level directories: prune root files and every
+                    // other root directory before descending.
+                    if let Some(allowed) = top_level_dirs.as_ref() {
+                        let mut components = relative_path.components();
+                        if let (Some(first), None) = (components.next(), components.next()) {
+                            if !is_dir
+                                || !allowed
+                                    .iter()
+                                    .any(|name| first.as_os_str() == OsStr::new(name))
+                            {
+                                return true;
+                            }
                         }
-                        Err(e) => anyhow::bail!("Invalid exclusion pattern '{}': {}", p, e),
                     }
-                };
-
-                // Add original pattern
-                add_glob(trimmed)?;
-
-                // If pattern doesn't start with explicitly anchored path or wildcard, allow matching in subdirectories
-                // e.g. ".venv" -> "**/.venv"
-                // e.g. "*.log" -> "**/*.log"
-                // e.g. "dist" -> "**/dist"
-                if !trimmed.starts_with('/') && !trimmed.starts_with("**/") {
-                    add_glob(&format!("**/{}", trimmed))?;
-                }
-
-                // Also handle directory contents if the pattern matches a directory name?
-                // filter_entry takes care of directories, but if a pattern is "node_modules", we skip the dir.
-                // If we are already inside? No, filter_entry prevents entering.
-                // So "**/pattern" is sufficient to catch the directory at any depth.
-            }
-            let globs = builder.build()?;
 
-            let walker = WalkDir::new(&dir_buf).into_iter().filter_entry(|e| {
-                // Skip if error accessing entry
-                let path = e.path();
-
-                // Calculate relative path from root
-                // For root directory itself, relative path is empty or "."
-                let relative_path = match path.strip_prefix(&dir_buf) {
-                    Ok(p) => p,
-                    Err(_) => return true, // Should not happen for children
-                };
-
-                if is_hard_ignored_root_metadata_dir(relative_path, e.file_type().is_dir()) {
-                    return false;
+                    // Check exclusion patterns
+                    // If it matches, skip entering directory or processing file
+                    let matched = globs.is_match(relative_path);
+                    if matched {
+                        if let Some(Ok(mut excluded)) = excluded.as_ref().map(|sink| sink.lock()) {
+                            excluded.push(relative_path.to_path_buf());
+                        }
+                    }
+                    matched
                 }
+            };
 
-                // Check exclusion patterns
-                // If it matches, return FALSE to skip entering directory or processing file
-                !globs.is_match(relative_path)
-            });
+            // Both walkers prune excluded entries before descending; jwalk does it per directory
+            // batch in `process_read_dir`, which mirrors walkdir's `filter_entry`.
+            let walker: Box<dyn Iterator<Item = Result<PathBuf>>> = if parallel_scan {
+                let is_excluded = Arc::new(is_excluded);
+                Box::new(
+                    jwalk::WalkDir::new(&dir_buf)
+                        .skip_hidden(false)
+                        .process_read_dir(move |_, _, _, children| {
+                            children.retain(|child| match child {
+                                Ok(entry) => {
+                                    !is_excluded(&entry.path(), entry.file_type().is_dir())
+                                }
+                                Err(_) => true,
+                            });
+                        })
+                        .into_iter()
+                        .map(|entry_result| match entry_result {
+                            Ok(entry) => Ok(entry.path()),
+                            Err(err) => {
+                                let failed_path = err
+                                    .path()
+                                    .map(|path| path.to_path_buf())
+                                    .unwrap_or_else(|| dir_buf.clone());
+                                Err(anyhow::Error::new(err)).with_context(|| {
+                                    format!("Failed to traverse directory entry: {:?}", failed_path)
+                                })
+                            }
+                        }),
+                )
+            } else {
+                Box::new(
+                    WalkDir::new(&dir_buf)
+                        .into_iter()
+                        .filter_entry(|e| !is_excluded(e.path(), e.file_type().is_dir()))
+                        .map(|entry_result| match entry_result {
+                            Ok(entry) => Ok(entry.into_path()),
+                            Err(err) => {
+                                let failed_path = err
+                                    .path()
+                                    .map(|path| path.to_path_buf())
+                                    .unwrap_or_else(|| dir_buf.clone());
+                                Err(anyhow::Error::new(err)).with_context(|| {
+                                    format!("Failed to traverse directory entry: {:?}", failed_path)
+                                })
+                            }
+                        }),
+                )
+            };
 
             for entry_result in walker {
-                let entry = match entry_result {
-                    Ok(entry) => entry,
-                    Err(err) => {
-                        let failed_path = err
-                            .path()
-                            .map(|path| path.to_path_buf())
-                            .unwrap_or_else(|| dir_buf.clone());
-                        return Err(anyhow::Error::new(err)).with_context(|| {
-                            format!("Failed to traverse directory entry: {:?}", failed_path)
-                        });
-                    }
-                };
+                let path = entry_result?;
                 if let Some(token) = cancel_token.as_ref() {
                     if token.is_cancelled() {
                         anyhow::bail!("Dry run cancelled by user");
                     }
                 }
 
-                let path = entry.path();
-
                 // Root directory itself is yielded, skip it
                 if path == dir_buf {
                     continue;
                 }
 
                 // Use std::fs instead of tokio::fs inside blocking task
-                let metadata = match std::fs::symlink_metadata(path) {
+                let metadata = match std::fs::symlink_metadata(&path) {
                     Ok(m) => m,
                     Err(_) => continue, // Skip files we can't read metadata for
                 };
@@ -371,6 +816,11 @@ impl SyncEngine {
                 callback(final_progress);
             }
 
+            if parallel_scan {
+                // jwalk yields directories in completion order; sort so repeated scans stay deterministic.
+                files.sort_by(|a, b| a.path.cmp(&b.path));
+            }
+
             Ok(files)
         })
         .await?
@@ -444,32 +894,107 @@ impl SyncEngine {
             None
         };
 
-        // 5. Use canonicalized paths for all operations
+        // 5. A target that resolves to the source (same path or symlink) would compare every
+        // file against itself; refuse before walking anything.
+        if target_canonical.as_deref() == Some(source_canonical.as_path()) {
+            anyhow::bail!(
+                "SourceEqualsTarget: source and target resolve to the same directory: {:?}",
+                source_canonical
+            );
+        }
+
+        if let Some(subdirs) = &options.source_subdirs {
+            validate_source_subdirs(subdirs).map_err(anyhow::Error::msg)?;
+        }
+
+        // 6. Use canonicalized paths for all operations
+        let mut skips = SkipCollector::new(options.collect_skip_reasons);
+        let excluded_sources = options
+            .collect_skip_reasons
+            .then(|| Arc::new(StdMutex::new(Vec::new())));
         let source_files = self
-            .read_directory(
+            .read_directory_scoped(
                 &source_canonical,
                 DryRunPhase::ScanningSource,
                 &options.exclude_patterns,
+                options.source_subdirs.as_deref(),
+                options.parallel_scan,
                 cancel_token.clone(),
                 progress_callback.clone(),
+                excluded_sources.clone(),
             )
             .await
             .context("Failed to read source directory")?;
+        if let Some(Ok(excluded)) = excluded_sources.as_ref().map(|sink| sink.lock()) {
+            for path in excluded.iter() {
+                skips.record(path, SkipReason::Excluded);
+            }
+        }
 
         let target_files = if let Some(ref target) = target_canonical {
-            self.read_directory(
+            self.read_directory_scoped(
                 target,
                 DryRunPhase::ScanningTarget,
                 &options.exclude_patterns,
+                Self::target_subdirs(
+                    options.source_subdirs.as_deref(),
+                    options.target_layout.as_ref(),
+                ),
+                options.parallel_scan,
                 cancel_token.clone(),
                 progress_callback.clone(),
+                None,
             )
             .await
             .context("Failed to read target directory")?
+            .into_iter()
+            .filter(|file| !is_partial_copy_artifact(&file.path))
+            .collect()
         } else {
             Vec::new()
         };
 
+        // Files outside the modified-time window are dropped before comparison, so they are
+        // neither copied nor counted. Orphan detection scans separately and still sees them.
+        let (source_files, outside_window): (Vec<FileMetadata>, Vec<FileMetadata>) =
+            source_files.into_iter().partition(|file| {
+                !file.is_file || Self::is_within_modified_window(options, file.modified)
+            });
+        for file in &outside_window {
+            skips.record(&file.path, SkipReason::OutsideModifiedWindow);
+        }
+
+        // With a target layout, source entries are re-keyed by their target-relative path and
+        // `layout_sources` maps back to where each file lives in the source.
+        let mut layout_sources: HashMap<PathBuf, PathBuf> = HashMap::new();
+        let mut layout_collisions = Vec::new();
+        let source_files = match &options.target_layout {
+            Some(layout) => {
+                layout.validate().map_err(anyhow::Error::msg)?;
+                let (files, sources, collisions) = Self::apply_target_layout(layout, source_files);
+                layout_sources = sources;
+                for path in &collisions {
+                    skips.record(path, SkipReason::LayoutCollision);
+                }
+                layout_collisions = collisions;
+                files
+            }
+            None => source_files,
+        };
+        let source_relative = |path: &Path| -> PathBuf {
+            layout_sources
+                .get(path)
+                .cloned()
+                .unwrap_or_else(|| path.to_path_buf())
+        };
+        let (source_files, compressed_collisions) =
+            Self::drop_compressed_name_collisions(options, source_files);
+        for path in compressed_collisions {
+            let path = source_relative(&path);
+            skips.record(&path, SkipReason::LayoutCollision);
+            layout_collisions.push(path);
+        }
+
         let total_files = source_files.iter().filter(|f| f.is_file).count();
         let total_bytes = source_files
             .iter()
@@ -499,9 +1024,26 @@ impl SyncEngine {
             target_map.insert(file.path.clone(), file);
         }
 
+        // Target files no source path claims exactly, keyed by case-folded path, so a
+        // source file can be matched against a differently-cased copy on the target.
+        let mut case_drift_candidates: HashMap<String, Vec<PathBuf>> = HashMap::new();
+        if options.reconcile_case_drift {
+            for file in &target_files {
+                if file.is_file && !source_map.contains_key(&file.path) {
+                    case_drift_candidates
+                        .entry(Self::case_fold_key(&file.path))
+                        .or_default()
+                        .push(file.path.clone());
+                }
+            }
+        }
+
         let mut diffs = Vec::new();
         let mut bytes_to_copy = 0u64;
+        let mut empty_files_skipped = 0usize;
         let mut target_newer_conflicts = Vec::new();
+        let mut matched_files = Vec::new();
+        let mut merged_sidecars = Vec::new();
         let mut compare_paths: Vec<PathBuf> = source_map.keys().cloned().collect();
         compare_paths.sort();
 
@@ -520,11 +1062,148 @@ impl SyncEngine {
                 continue;
             };
 
-            if let Some(target_meta) = target_map.get(&path) {
+            if options.skip_empty_files && source_meta.is_file && source_meta.size == 0 {
+                compare_processed_files += 1;
+                empty_files_skipped += 1;
+                skips.record(&path, SkipReason::EmptyFile);
+                continue;
+            }
+
+            // A merged sidecar leaves no `._` file behind, so it is only applied again when its
+            // data file is copied again (decided after the loop).
+            if options.merge_appledouble
+                && source_meta.is_file
+                && !target_map.contains_key(&path)
+                && appledouble::paired_data_path(&path).is_some_and(|data| {
+                    target_map.contains_key(&Self::stored_relative_path(options, &data))
+                })
+            {
+                compare_processed_files += 1;
+                compare_processed_bytes += source_meta.size;
+                merged_sidecars.push(path);
+                continue;
+            }
+
+            let case_drift_match = if options.reconcile_case_drift
+                && source_meta.is_file
+                && !Self::should_compress(options, &path)
+                && !target_map.contains_key(&path)
+            {
+                let target_root = target_canonical.as_ref().unwrap_or(&self.target);
+                self.take_case_drift_match(
+                    &path,
+                    &source_canonical.join(source_relative(&path)),
+                    source_meta,
+                    target_root,
+                    &target_map,
+                    &mut case_drift_candidates,
+                )
+                .await?
+            } else {
+                None
+            };
+
+            if source_meta.is_file && Self::should_compress(options, &path) {
+                // Compressed copies never match by size, so only mtime (and the decompressed
+                // checksum) decide whether they are current. A newer target, or equal mtimes
+                // with different content under `flag_equal_mtime_content_diff`, goes to conflict
+                // review like an uncompressed copy would.
+                compare_processed_files += 1;
+                compare_processed_bytes += source_meta.size;
+                let stored_path = Self::compressed_relative_path(&path);
+                let mut conflict = None;
+                let pending_kind = match target_map.get(&stored_path) {
+                    None if options.update_existing_only => None,
+                    None => Some((FileDiffKind::New, None)),
+                    Some(target_meta) => {
+                        let source_path = source_canonical.join(source_relative(&path));
+                        let target_path = target_canonical
+                            .as_ref()
+                            .map(|target| target.join(&stored_path))
+                            .unwrap_or_else(|| self.target.join(&stored_path));
+                        let mtime_order = Self::compare_modified_time_at_second_precision(
+                            target_meta.modified,
+                            source_meta.modified,
+                        );
+                        let content_differs = if mtime_order == Ordering::Greater
+                            || (mtime_order == Ordering::Equal && options.checksum_mode)
+                        {
+                            let source_hash = self.calculate_checksum(&source_path).await?;
+                            let target_hash = self.calculate_gzip_checksum(&target_path).await?;
+                            source_hash != target_hash
+                        } else {
+                            mtime_order == Ordering::Less
+                        };
+                        let conflict_reason = match mtime_order {
+                            Ordering::Greater => Some(SkipReason::TargetNewer),
+                            Ordering::Equal if options.flag_equal_mtime_content_diff => {
+                                Some(SkipReason::ContentConflict)
+                            }
+                            _ => None,
+                        };
+                        match conflict_reason {
+                            Some(reason) if content_differs => {
+                                conflict = Some((
+                                    reason,
+                                    TargetNewerConflictCandidate {
+                                        path: path.clone(),
+                                        source_path,
+                                        target_path,
+                                        source: Self::snapshot_from_metadata(source_meta),
+                                        target: Self::snapshot_from_metadata(target_meta),
+                                    },
+                                ));
+                                None
+                            }
+                            _ => content_differs
+                                .then_some((FileDiffKind::Modified, Some(target_meta.size))),
+                        }
+                    }
+                };
+
+                if let Some((reason, candidate)) = conflict {
+                    target_newer_conflicts.push(candidate);
+                    skips.record(&path, reason);
+                } else if let Some((kind, target_size)) = pending_kind {
+                    bytes_to_copy += source_meta.size;
+                    compare_summary.files_to_copy += 1;
+                    if kind == FileDiffKind::Modified {
+                        compare_summary.files_modified += 1;
+                    }
+                    compare_summary.bytes_to_copy = bytes_to_copy;
+                    let diff = FileDiff {
+                        path: path.clone(),
+                        kind,
+                        source_size: Some(source_meta.size),
+                        target_size,
+                        checksum_source: None,
+                        checksum_target: None,
+                        renamed_from: None,
+                        source_path: layout_sources.get(&path).cloned(),
+                    };
+                    on_diff(
+                        diff.clone(),
+                        SyncEngine::build_dry_run_progress(
+                            DryRunPhase::Comparing,
+                            path.to_string_lossy().to_string(),
+                            compare_processed_files,
+                            total_files as u64,
+                            compare_processed_bytes,
+                            total_bytes,
+                            compare_summary.clone(),
+                        ),
+                    );
+                    diffs.push(diff);
+                } else if target_map.contains_key(&stored_path) {
+                    skips.record(&path, SkipReason::UpToDate);
+                } else {
+                    skips.record(&path, SkipReason::NotOnTarget);
+                }
+            } else if let Some(target_meta) = target_map.get(&path) {
                 if source_meta.is_file {
                     compare_processed_files += 1;
                     compare_processed_bytes += source_meta.size;
-                    let source_path = source_canonical.join(&path);
+                    let source_path = source_canonical.join(source_relative(&path));
                     let target_path = target_canonical
                         .as_ref()
                         .map(|target| target.join(&path))
@@ -545,6 +1224,7 @@ impl SyncEngine {
                                 source: Self::snapshot_from_metadata(source_meta),
                                 target: Self::snapshot_from_metadata(target_meta),
                             });
+                            skips.record(&path, SkipReason::TargetNewer);
                             let now = std::time::Instant::now();
                             if now.duration_since(last_compare_emit_at) >= compare_emit_interval
                                 || compare_processed_files == total_files as u64
@@ -573,6 +1253,7 @@ impl SyncEngine {
                                 source: Self::snapshot_from_metadata(source_meta),
                                 target: Self::snapshot_from_metadata(target_meta),
                             });
+                            skips.record(&path, SkipReason::TargetNewer);
                             let now = std::time::Instant::now();
                             if now.duration_since(last_compare_emit_at) >= compare_emit_interval
                                 || compare_processed_files == total_files as u64
@@ -610,8 +1291,15 @@ impl SyncEngine {
                         needs_copy = source_hash != target_hash;
                     }
 
-                    // 3. If metadata matches but checksum mode is on, check content (slower but accurate)
-                    if !needs_copy && options.checksum_mode && !already_checked_equal_hash {
+                    // 3. If metadata matches but checksum mode is on, check content (slower but accurate).
+                    // Two empty files are trivially equal, and so are pairs a trusted snapshot
+                    // shows untouched since the last checksum-mode sync.
+                    if !needs_copy
+                        && options.checksum_mode
+                        && !already_checked_equal_hash
+                        && source_meta.size > 0
+                        && !self.snapshot_proves_unchanged(&path, source_meta, target_meta)
+                    {
                         let source_hash = self.calculate_checksum(&source_path).await?;
                         let target_hash = self.calculate_checksum(&target_path).await?;
 
@@ -620,7 +1308,51 @@ impl SyncEngine {
                         }
                     }
 
+                    // 4. Same mtime but different content: neither side is provably newer, so
+                    // optionally hand the pair to conflict review instead of overwriting.
+                    if needs_copy
+                        && options.checksum_mode
+                        && options.flag_equal_mtime_content_diff
+                        && Self::compare_modified_time_at_second_precision(
+                            source_meta.modified,
+                            target_meta.modified,
+                        ) == Ordering::Equal
+                    {
+                        target_newer_conflicts.push(TargetNewerConflictCandidate {
+                            path: path.clone(),
+                            source_path: source_path.clone(),
+                            target_path: target_path.clone(),
+                            source: Self::snapshot_from_metadata(source_meta),
+                            target: Self::snapshot_from_metadata(target_meta),
+                        });
+                        skips.record(&path, SkipReason::ContentConflict);
+                        let now = std::time::Instant::now();
+                        if now.duration_since(last_compare_emit_at) >= compare_emit_interval
+                            || compare_processed_files == total_files as u64
+                        {
+                            last_compare_emit_at = now;
+                            emit_progress(SyncEngine::build_dry_run_progress(
+                                DryRunPhase::Comparing,
+                                path.to_string_lossy().to_string(),
+                                compare_processed_files,
+                                total_files as u64,
+                                compare_processed_bytes,
+                                total_bytes,
+                                compare_summary.clone(),
+                            ));
+                        }
+                        continue;
+                    }
+
                     if needs_copy {
+                        // Sync skips locked targets under `respect_readonly_target`; only probe
+                        // them here when the caller asked for reasons.
+                        if skips.enabled
+                            && options.respect_readonly_target
+                            && Self::is_target_locked(&target_path).await
+                        {
+                            skips.record(&path, SkipReason::TargetReadOnly);
+                        }
                         bytes_to_copy += source_meta.size;
                         compare_summary.files_to_copy += 1;
                         compare_summary.files_modified += 1;
@@ -632,6 +1364,8 @@ impl SyncEngine {
                             target_size: Some(target_meta.size),
                             checksum_source: None,
                             checksum_target: None,
+                            renamed_from: None,
+                            source_path: layout_sources.get(&path).cloned(),
                         };
                         on_diff(
                             diff.clone(),
@@ -646,12 +1380,52 @@ impl SyncEngine {
                             ),
                         );
                         diffs.push(diff);
+                    } else {
+                        skips.record(&path, SkipReason::UpToDate);
+                        if options.verify_existing_after_sync {
+                            matched_files.push(MatchedFile {
+                                path: path.clone(),
+                                source_path,
+                                target_path,
+                                size: source_meta.size,
+                            });
+                        }
                     }
                 }
-            } else if source_meta.is_file {
+            } else if let Some((renamed_from, target_size)) = case_drift_match {
                 compare_processed_files += 1;
                 compare_processed_bytes += source_meta.size;
-                bytes_to_copy += source_meta.size;
+                let diff = FileDiff {
+                    path: path.clone(),
+                    kind: FileDiffKind::CaseRename,
+                    source_size: Some(source_meta.size),
+                    target_size: Some(target_size),
+                    checksum_source: None,
+                    checksum_target: None,
+                    renamed_from: Some(renamed_from),
+                    source_path: layout_sources.get(&path).cloned(),
+                };
+                on_diff(
+                    diff.clone(),
+                    SyncEngine::build_dry_run_progress(
+                        DryRunPhase::Comparing,
+                        path.to_string_lossy().to_string(),
+                        compare_processed_files,
+                        total_files as u64,
+                        compare_processed_bytes,
+                        total_bytes,
+                        compare_summary.clone(),
+                    ),
+                );
+                diffs.push(diff);
+            } else if source_meta.is_file && options.update_existing_only {
+                compare_processed_files += 1;
+                compare_processed_bytes += source_meta.size;
+                skips.record(&path, SkipReason::NotOnTarget);
+            } else if source_meta.is_file {
+                compare_processed_files += 1;
+                compare_processed_bytes += source_meta.size;
+                bytes_to_copy += source_meta.size;
                 compare_summary.files_to_copy += 1;
                 compare_summary.bytes_to_copy = bytes_to_copy;
                 let diff = FileDiff {
@@ -661,6 +1435,8 @@ impl SyncEngine {
                     target_size: None,
                     checksum_source: None,
                     checksum_target: None,
+                    renamed_from: None,
+                    source_path: layout_sources.get(&path).cloned(),
                 };
                 on_diff(
                     diff.clone(),
@@ -694,9 +1470,48 @@ impl SyncEngine {
             }
         }
 
+        for path in merged_sidecars {
+            let data_recopied = appledouble::paired_data_path(&path)
+                .is_some_and(|data| diffs.iter().any(|diff: &FileDiff| diff.path == data));
+            if !data_recopied {
+                skips.record(&path, SkipReason::UpToDate);
+                continue;
+            }
+            let source_size = source_map.get(&path).map_or(0, |meta| meta.size);
+            bytes_to_copy += source_size;
+            compare_summary.files_to_copy += 1;
+            compare_summary.bytes_to_copy = bytes_to_copy;
+            let diff = FileDiff {
+                path: path.clone(),
+                kind: FileDiffKind::New,
+                source_size: Some(source_size),
+                target_size: None,
+                checksum_source: None,
+                checksum_target: None,
+                renamed_from: None,
+                source_path: layout_sources.get(&path).cloned(),
+            };
+            on_diff(
+                diff.clone(),
+                SyncEngine::build_dry_run_progress(
+                    DryRunPhase::Comparing,
+                    path.to_string_lossy().to_string(),
+                    compare_processed_files,
+                    total_files as u64,
+                    compare_processed_bytes,
+                    total_bytes,
+                    compare_summary.clone(),
+                ),
+            );
+            diffs.push(diff);
+        }
+
         compare_summary.total_files = total_files;
         compare_summary.bytes_to_copy = bytes_to_copy;
-        compare_summary.files_to_copy = diffs.len();
+        compare_summary.files_to_copy = diffs
+            .iter()
+            .filter(|d| d.kind != FileDiffKind::CaseRename)
+            .count();
         compare_summary.files_modified = diffs
             .iter()
             .filter(|d| d.kind == FileDiffKind::Modified)
@@ -711,6 +1526,12 @@ impl SyncEngine {
             compare_summary.clone(),
         ));
 
+        let path_too_long = self.collect_path_too_long(options, &diffs);
+        for diagnostic in &path_too_long {
+            skips.record(&diagnostic.path, SkipReason::PathTooLong);
+        }
+        let (skipped, skipped_truncated) = skips.finish();
+
         Ok((
             DryRunResult {
                 diffs,
@@ -719,11 +1540,263 @@ impl SyncEngine {
                 files_modified: compare_summary.files_modified,
                 bytes_to_copy,
                 target_preflight: None,
+                orphans: None,
+                orphan_summary: None,
+                path_too_long,
+                empty_files_skipped,
+                layout_collisions,
+                matched_files,
+                skipped,
+                skipped_truncated,
             },
             target_newer_conflicts,
         ))
     }
 
+    /// Deterministic sampling for `verify_after_copy`: the path hash (seeded by
+    /// `verify_sample_seed`) picks the same files on every run for a given rate.
+    fn should_verify_copy(options: &SyncOptions, relative_path: &Path, size: u64) -> bool {
+        use twox_hash::XxHash64;
+
+        let rate = options.verify_sample_rate;
+        if rate.is_nan() || rate >= 1.0 || size >= VERIFY_ALWAYS_ABOVE_BYTES {
+            return true;
+        }
+        if rate <= 0.0 {
+            return false;
+        }
+
+        let mut hasher = XxHash64::with_seed(options.verify_sample_seed);
+        hasher.write(relative_path.to_string_lossy().as_bytes());
+        let unit = (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64;
+        unit < rate
+    }
+
+    /// Whether the copy phase drops permission/time preservation: `skip_metadata_on_network` is
+    /// set, the target is on a network mount, and there is metadata to preserve.
+    pub fn should_skip_metadata_ops(options: &SyncOptions, target_is_network: bool) -> bool {
+        options.skip_metadata_on_network
+            && target_is_network
+            && (options.preserve_permissions || options.preserve_times)
+    }
+
+    /// Volume lookup failures count as local so the default behavior is kept.
+    fn is_network_target(target: &Path) -> bool {
+        crate::system_integration::DiskMonitor::new()
+            .is_network_path(target)
+            .unwrap_or(false)
+    }
+
+    /// Full target path length in bytes, including the `.gz` suffix when compressed.
+    fn target_path_len(&self, options: &SyncOptions, relative_path: &Path) -> usize {
+        self.target
+            .join(Self::stored_relative_path(options, relative_path))
+            .as_os_str()
+            .len()
+    }
+
+    /// Target-side top-level scope for `source_subdirs`. A target layout places files by its
+    /// own rule, so the target is then scanned whole.
+    pub fn target_subdirs<'a>(
+        source_subdirs: Option<&'a [String]>,
+        target_layout: Option<&TargetLayout>,
+    ) -> Option<&'a [String]> {
+        source_subdirs.filter(|_| target_layout.is_none())
+    }
+
+    fn collect_path_too_long(
+        &self,
+        options: &SyncOptions,
+        diffs: &[FileDiff],
+    ) -> Vec<PathTooLongDiagnostic> {
+        let Some(limit) = options.max_target_path_len else {
+            return Vec::new();
+        };
+        diffs
+            .iter()
+            .filter_map(|diff| {
+                let target_path_len = self.target_path_len(options, &diff.path);
+                (target_path_len > limit).then(|| PathTooLongDiagnostic {
+                    path: diff.path.clone(),
+                    target_path_len,
+                    limit,
+                })
+            })
+            .collect()
+    }
+
+    /// Empty source and target copies are trivially identical, so verification needs no hashing.
+    async fn both_empty(source: &Path, target: &Path) -> Result<bool> {
+        Ok(fs::metadata(source).await?.len() == 0 && fs::metadata(target).await?.len() == 0)
+    }
+
+    /// True when an existing target file must not be overwritten: read-only permissions, or
+    /// the user/system immutable flag on macOS.
+    async fn is_target_locked(target: &Path) -> bool {
+        let Ok(metadata) = fs::symlink_metadata(target).await else {
+            return false;
+        };
+        if !metadata.is_file() {
+            return false;
+        }
+        if metadata.permissions().readonly() {
+            return true;
+        }
+        #[cfg(target_os = "macos")]
+        {
+            use std::os::macos::fs::MetadataExt;
+            const UF_IMMUTABLE: u32 = 0x0000_0002;
+            const SF_IMMUTABLE: u32 = 0x0002_0000;
+            if metadata.st_flags() & (UF_IMMUTABLE | SF_IMMUTABLE) != 0 {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Flags that block writes to the file; they are cleared before an overwrite and applied
+    /// only after everything else (data, permissions, times, other flags) is in place.
+    #[cfg(target_os = "macos")]
+    fn write_blocking_flags() -> nix::sys::stat::FileFlag {
+        use nix::sys::stat::FileFlag;
+        FileFlag::UF_IMMUTABLE | FileFlag::UF_APPEND | FileFlag::SF_IMMUTABLE | FileFlag::SF_APPEND
+    }
+
+    /// Best-effort removal of write-blocking flags from an existing target so a
+    /// `preserve_flags` copy can replace a file that an earlier run locked.
+    #[cfg(target_os = "macos")]
+    fn clear_write_blocking_flags(target: &Path) {
+        use nix::sys::stat::FileFlag;
+        use std::os::macos::fs::MetadataExt;
+
+        let Ok(metadata) = std::fs::symlink_metadata(target) else {
+            return;
+        };
+        if !metadata.is_file() {
+            return;
+        }
+        let flags = FileFlag::from_bits_truncate(metadata.st_flags());
+        if flags.intersects(Self::write_blocking_flags()) {
+            let _ = nix::unistd::chflags(target, flags - Self::write_blocking_flags());
+        }
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    fn clear_write_blocking_flags(_target: &Path) {}
+
+    /// Copies the source's BSD flags onto the target. Returns a description of the flags that
+    /// could not be set (e.g. `SF_*` flags without root), or `None` when all were applied.
+    #[cfg(target_os = "macos")]
+    fn apply_file_flags(source: &Path, target: &Path) -> Result<Option<String>> {
+        use nix::sys::stat::FileFlag;
+        use std::os::macos::fs::MetadataExt;
+
+        // UF_COMPRESSED and UF_TRACKED describe how the source is stored, not the copied data.
+        let storage_flags = FileFlag::UF_COMPRESSED | FileFlag::UF_TRACKED;
+        let flags =
+            FileFlag::from_bits_truncate(std::fs::metadata(source)?.st_flags()) - storage_flags;
+        let mut applied =
+            FileFlag::from_bits_truncate(std::fs::symlink_metadata(target)?.st_flags())
+                & storage_flags;
+        // Reset first so flags an earlier run left on the target do not linger.
+        nix::unistd::chflags(target, applied)
+            .with_context(|| format!("Failed to set file flags on {target:?}"))?;
+
+        let deferred = flags & Self::write_blocking_flags();
+        let mut failed = FileFlag::empty();
+        for group in [
+            (flags - deferred) & FileFlag::UF_SETTABLE,
+            (flags - deferred) - FileFlag::UF_SETTABLE,
+            deferred & FileFlag::UF_SETTABLE,
+            deferred - FileFlag::UF_SETTABLE,
+        ] {
+            if group.is_empty() {
+                continue;
+            }
+            match nix::unistd::chflags(target, applied | group) {
+                Ok(()) => applied |= group,
+                Err(_) => failed |= group,
+            }
+        }
+
+        if failed.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(format!("Could not set file flags {failed:?}")))
+        }
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    fn apply_file_flags(_source: &Path, _target: &Path) -> Result<Option<String>> {
+        Ok(None)
+    }
+
+    fn case_fold_key(path: &Path) -> String {
+        path.to_string_lossy().to_lowercase()
+    }
+
+    /// Claims the single target file whose path differs from `path` only in case, provided it
+    /// has the same size and content. Ambiguous or differing matches fall back to a fresh copy.
+    async fn take_case_drift_match(
+        &self,
+        path: &Path,
+        source_path: &Path,
+        source_meta: &FileMetadata,
+        target_root: &Path,
+        target_map: &HashMap<PathBuf, &FileMetadata>,
+        candidates: &mut HashMap<String, Vec<PathBuf>>,
+    ) -> Result<Option<(PathBuf, u64)>> {
+        let key = Self::case_fold_key(path);
+        let Some(candidate) = candidates
+            .get(&key)
+            .and_then(|paths| match paths.as_slice() {
+                [only] => Some(only.clone()),
+                _ => None,
+            })
+        else {
+            return Ok(None);
+        };
+        let Some(target_meta) = target_map.get(&candidate) else {
+            return Ok(None);
+        };
+        if target_meta.size != source_meta.size {
+            return Ok(None);
+        }
+
+        let source_hash = self.calculate_checksum(source_path).await?;
+        let target_hash = self
+            .calculate_checksum(&target_root.join(&candidate))
+            .await?;
+        if source_hash != target_hash {
+            return Ok(None);
+        }
+
+        candidates.remove(&key);
+        Ok(Some((candidate, target_meta.size)))
+    }
+
+    /// Renames a target file to a name differing only in case. Goes through a temporary name
+    /// because a direct rename is a no-op on some case-insensitive filesystems.
+    async fn rename_case_only(from: &Path, to: &Path) -> Result<()> {
+        if let Some(parent) = to.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .with_context(|| format!("Failed to create directory: {parent:?}"))?;
+        }
+        let file_name = to
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let staging = to.with_file_name(format!(".{file_name}.syncwatcher-case-rename"));
+        tokio::fs::rename(from, &staging)
+            .await
+            .with_context(|| format!("Failed to rename {from:?} to {staging:?}"))?;
+        tokio::fs::rename(&staging, to)
+            .await
+            .with_context(|| format!("Failed to rename {staging:?} to {to:?}"))?;
+        Ok(())
+    }
+
     fn dry_run_progress_to_sync_progress(progress: DryRunProgress) -> SyncProgress {
         let phase = match progress.phase {
             DryRunPhase::ScanningSource => SyncProgressPhase::ScanningSource,
@@ -846,6 +1919,76 @@ impl SyncEngine {
         .await
     }
 
+    /// Applies the `._name` sidecar at `sidecar_source` to the already-synced `name` on the
+    /// target. `Ok(false)` means the path is not a paired sidecar; errors mean the caller
+    /// should copy the sidecar instead.
+    async fn merge_appledouble_sidecar(
+        &self,
+        sidecar_source: &Path,
+        sidecar_relative: &Path,
+        options: &SyncOptions,
+    ) -> Result<bool> {
+        let (Some(source_data), Some(data_relative)) = (
+            appledouble::paired_data_path(sidecar_source),
+            appledouble::paired_data_path(sidecar_relative),
+        ) else {
+            return Ok(false);
+        };
+        if !fs::symlink_metadata(&source_data)
+            .await
+            .map(|metadata| metadata.is_file())
+            .unwrap_or(false)
+        {
+            return Ok(false);
+        }
+
+        let double = appledouble::parse(&fs::read(sidecar_source).await?)?;
+        let target_data = self
+            .target
+            .join(Self::stored_relative_path(options, &data_relative));
+        appledouble::apply(&target_data, &double)?;
+        Ok(true)
+    }
+
+    /// Replaces `target` with a hard link to `existing`, an identical file already written in
+    /// this run. Errors leave the caller to copy the file normally.
+    async fn link_duplicate(&self, existing: &Path, target: &Path) -> Result<()> {
+        reject_symlink_destination_under_root(&self.target, target).await?;
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+        reject_symlink_destination_under_root(&self.target, target).await?;
+
+        match fs::remove_file(target).await {
+            Ok(()) => {}
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {}
+            Err(error) => return Err(error.into()),
+        }
+        fs::hard_link(existing, target)
+            .await
+            .with_context(|| format!("Failed to link {target:?} to {existing:?}"))?;
+        Ok(())
+    }
+
+    /// Removes `target` when it shares its inode with other paths, so an in-place rewrite does
+    /// not change the other names too. `link_duplicate` leaves such links behind.
+    async fn unlink_shared_target(target: &Path) -> Result<()> {
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+
+            match fs::symlink_metadata(target).await {
+                Ok(meta) if meta.is_file() && meta.nlink() > 1 => fs::remove_file(target).await?,
+                Ok(_) => {}
+                Err(error) if error.kind() == std::io::ErrorKind::NotFound => {}
+                Err(error) => return Err(error.into()),
+            }
+        }
+        #[cfg(not(unix))]
+        let _ = target;
+        Ok(())
+    }
+
     pub async fn sync_files_from_dry_run(
         &self,
         dry_run: &DryRunResult,
@@ -857,6 +2000,50 @@ impl SyncEngine {
             files_copied: 0,
             bytes_copied: 0,
             errors: Vec::new(),
+            compressed_files: 0,
+            compressed_original_bytes: 0,
+            compressed_stored_bytes: 0,
+            files_verified: 0,
+            files_verify_sampled_out: 0,
+            empty_files_skipped: dry_run.empty_files_skipped as u64,
+            stopped_on_error: None,
+            time_limit_reached: false,
+            remaining_files: 0,
+            remaining_bytes: 0,
+            appledouble_merged: 0,
+            dedup_linked: 0,
+            dedup_bytes_saved: 0,
+            warnings: Vec::new(),
+        };
+
+        // Collisions never reach `diffs`, so report each skipped source file here.
+        for path in &dry_run.layout_collisions {
+            result.errors.push(crate::sync_engine::types::SyncError {
+                path: path.clone(),
+                message: "Skipped: another source file is stored at the same target path"
+                    .to_string(),
+                kind: crate::sync_engine::types::SyncErrorKind::LayoutCollision,
+            });
+        }
+
+        // Decided once per run so a network target yields a single warning instead of a
+        // permission/time failure per file.
+        let network_options;
+        let options = if options.skip_metadata_on_network
+            && Self::should_skip_metadata_ops(options, Self::is_network_target(&self.target))
+        {
+            result.warnings.push(format!(
+                "Target {:?} is on a network mount; permissions and modification times were not preserved",
+                self.target
+            ));
+            network_options = SyncOptions {
+                preserve_permissions: false,
+                preserve_times: false,
+                ..options.clone()
+            };
+            &network_options
+        } else {
+            options
         };
 
         let mut total_bytes = 0u64;
@@ -870,6 +2057,7 @@ impl SyncEngine {
                     }
                     total_files_to_copy += 1;
                 }
+                FileDiffKind::CaseRename => total_files_to_copy += 1,
             }
         }
 
@@ -886,9 +2074,106 @@ impl SyncEngine {
 
         progress_callback(current_progress.clone());
 
-        for diff in &dry_run.diffs {
-            let source_path = self.source.join(&diff.path);
-            let target_path = self.target.join(&diff.path);
+        let too_long: HashMap<&Path, &PathTooLongDiagnostic> = dry_run
+            .path_too_long
+            .iter()
+            .map(|diagnostic| (diagnostic.path.as_path(), diagnostic))
+            .collect();
+
+        let mut ordered_diffs: Vec<&FileDiff> = dry_run.diffs.iter().collect();
+        if options.merge_appledouble {
+            // Sidecars go last so their data files are already on the target when merged.
+            ordered_diffs.sort_by_key(|diff| appledouble::paired_data_path(&diff.path).is_some());
+        }
+
+        // Only files sharing a size with another copy can be duplicates, so only those are hashed.
+        let dedup = options.dedup_identical && options.checksum_mode;
+        let mut dedup_size_counts: HashMap<u64, usize> = HashMap::new();
+        if dedup {
+            for diff in &dry_run.diffs {
+                if diff.kind != FileDiffKind::CaseRename {
+                    if let Some(size) = diff.source_size.filter(|size| *size > 0) {
+                        *dedup_size_counts.entry(size).or_default() += 1;
+                    }
+                }
+            }
+        }
+        let mut dedup_targets: HashMap<(u64, String), PathBuf> = HashMap::new();
+
+        let copy_started_at = std::time::Instant::now();
+        for (index, diff) in ordered_diffs.iter().copied().enumerate() {
+            if let Some(max_duration) = options.max_duration {
+                if index > 0 && copy_started_at.elapsed() >= max_duration {
+                    let remaining = &ordered_diffs[index..];
+                    result.time_limit_reached = true;
+                    result.remaining_files = remaining.len() as u64;
+                    result.remaining_bytes = remaining
+                        .iter()
+                        .filter(|diff| diff.kind != FileDiffKind::CaseRename)
+                        .filter_map(|diff| diff.source_size)
+                        .sum();
+                    break;
+                }
+            }
+
+            let source_path = self
+                .source
+                .join(diff.source_path.as_ref().unwrap_or(&diff.path));
+            let compress = Self::should_compress(options, &diff.path);
+            let target_path = self
+                .target
+                .join(Self::stored_relative_path(options, &diff.path));
+
+            if let Some(diagnostic) = too_long.get(diff.path.as_path()) {
+                let message = format!(
+                    "Target path too long ({} > {} bytes): {}",
+                    diagnostic.target_path_len,
+                    diagnostic.limit,
+                    target_path.display()
+                );
+                result.errors.push(crate::sync_engine::types::SyncError {
+                    path: diff.path.clone(),
+                    message: message.clone(),
+                    kind: crate::sync_engine::types::SyncErrorKind::PathTooLong,
+                });
+                file_callback(SyncFileEntry {
+                    path: diff.path.clone(),
+                    kind: diff.kind.clone(),
+                    status: SyncFileStatus::Failed,
+                    source_size: diff.source_size,
+                    target_size: diff.target_size,
+                    error: Some(message),
+                });
+                current_progress.processed_files += 1;
+                progress_callback(current_progress.clone());
+                if options.stop_on_first_error {
+                    result.stopped_on_error = Some(diff.path.clone());
+                    break;
+                }
+                continue;
+            }
+
+            if options.merge_appledouble
+                && diff.kind != FileDiffKind::CaseRename
+                && self
+                    .merge_appledouble_sidecar(&source_path, &diff.path, options)
+                    .await
+                    .unwrap_or(false)
+            {
+                result.appledouble_merged += 1;
+                file_callback(SyncFileEntry {
+                    path: diff.path.clone(),
+                    kind: diff.kind.clone(),
+                    status: SyncFileStatus::Copied,
+                    source_size: diff.source_size,
+                    target_size: diff.target_size,
+                    error: None,
+                });
+                current_progress.processed_files += 1;
+                current_progress.processed_bytes += diff.source_size.unwrap_or(0);
+                progress_callback(current_progress.clone());
+                continue;
+            }
 
             match diff.kind {
                 FileDiffKind::New | FileDiffKind::Modified => {
@@ -899,61 +2184,251 @@ impl SyncEngine {
                     progress_callback(current_progress.clone());
                     let mut last_emitted_current_file_bytes = 0u64;
 
-                    if let Err(e) = self
-                        .copy_file_chunked(&source_path, &target_path, options, |written_chunk| {
-                            current_progress.processed_bytes += written_chunk;
-                            current_progress.bytes_copied_current_file += written_chunk;
-                            const PROGRESS_EMIT_CHUNK_BYTES: u64 = 1024 * 1024;
-                            let should_emit = current_progress
-                                .bytes_copied_current_file
-                                .saturating_sub(last_emitted_current_file_bytes)
-                                >= PROGRESS_EMIT_CHUNK_BYTES;
-                            if should_emit {
-                                last_emitted_current_file_bytes =
-                                    current_progress.bytes_copied_current_file;
-                                progress_callback(current_progress.clone());
-                            }
-                        })
-                        .await
-                    {
-                        let kind = if e.to_string().contains("Verification failed") {
-                            crate::sync_engine::types::SyncErrorKind::VerificationFailed
-                        } else {
-                            crate::sync_engine::types::SyncErrorKind::CopyFailed
-                        };
+                    let on_chunk = |written_chunk: u64| {
+                        current_progress.processed_bytes += written_chunk;
+                        current_progress.bytes_copied_current_file += written_chunk;
+                        const PROGRESS_EMIT_CHUNK_BYTES: u64 = 1024 * 1024;
+                        let should_emit = current_progress
+                            .bytes_copied_current_file
+                            .saturating_sub(last_emitted_current_file_bytes)
+                            >= PROGRESS_EMIT_CHUNK_BYTES;
+                        if should_emit {
+                            last_emitted_current_file_bytes =
+                                current_progress.bytes_copied_current_file;
+                            progress_callback(current_progress.clone());
+                        }
+                    };
+                    let target_locked = diff.kind == FileDiffKind::Modified
+                        && Self::is_target_locked(&target_path).await;
+                    if target_locked && options.respect_readonly_target {
+                        let message =
+                            format!("Skipped read-only target: {}", target_path.display());
                         result.errors.push(crate::sync_engine::types::SyncError {
                             path: diff.path.clone(),
-                            message: e.to_string(),
-                            kind,
+                            message: message.clone(),
+                            kind: crate::sync_engine::types::SyncErrorKind::TargetReadOnly,
                         });
-                        let entry = SyncFileEntry {
+                        file_callback(SyncFileEntry {
                             path: diff.path.clone(),
                             kind: diff.kind.clone(),
                             status: SyncFileStatus::Failed,
                             source_size: diff.source_size,
                             target_size: diff.target_size,
-                            error: Some(e.to_string()),
-                        };
-                        file_callback(entry);
+                            error: Some(message),
+                        });
+                        current_progress.processed_files += 1;
+                        progress_callback(current_progress.clone());
+                        if options.stop_on_first_error {
+                            result.stopped_on_error = Some(diff.path.clone());
+                            break;
+                        }
+                        continue;
+                    }
+
+                    let dedup_key = if dedup
+                        && !compress
+                        && dedup_size_counts.get(&file_size).copied().unwrap_or(0) > 1
+                    {
+                        self.calculate_checksum(&source_path)
+                            .await
+                            .ok()
+                            .map(|hash| (file_size, hash))
+                    } else {
+                        None
+                    };
+                    if let Some(existing) =
+                        dedup_key.as_ref().and_then(|key| dedup_targets.get(key))
+                    {
+                        if self.link_duplicate(existing, &target_path).await.is_ok() {
+                            result.dedup_linked += 1;
+                            result.dedup_bytes_saved += file_size;
+                            file_callback(SyncFileEntry {
+                                path: diff.path.clone(),
+                                kind: diff.kind.clone(),
+                                status: SyncFileStatus::Copied,
+                                source_size: diff.source_size,
+                                target_size: diff.target_size.or(Some(0)),
+                                error: None,
+                            });
+                            current_progress.processed_files += 1;
+                            current_progress.processed_bytes += file_size;
+                            progress_callback(current_progress.clone());
+                            continue;
+                        }
+                    }
+
+                    if options.preserve_flags {
+                        Self::clear_write_blocking_flags(&target_path);
+                    }
+                    let verify = options.verify_after_copy
+                        && Self::should_verify_copy(options, &diff.path, file_size);
+                    let copy_result = if compress {
+                        self.copy_file_compressed(
+                            &source_path,
+                            &target_path,
+                            options,
+                            verify,
+                            on_chunk,
+                        )
+                        .await
+                        .map(Some)
                     } else {
-                        result.files_copied += 1;
-                        result.bytes_copied += file_size;
-                        current_progress.bytes_copied_current_file = file_size;
-                        let entry = SyncFileEntry {
+                        self.copy_file_chunked(
+                            &source_path,
+                            &target_path,
+                            options,
+                            verify,
+                            on_chunk,
+                        )
+                        .await
+                        .map(|_| None)
+                    };
+
+                    match copy_result {
+                        Err(e) => {
+                            if !Self::is_source_root_accessible(&self.source).await {
+                                let message = format!(
+                                    "Source disconnected: '{}' is no longer accessible",
+                                    self.source.display()
+                                );
+                                result.errors.push(crate::sync_engine::types::SyncError {
+                                path: diff.path.clone(),
+                                message: message.clone(),
+                                kind: crate::sync_engine::types::SyncErrorKind::SourceDisconnected,
+                            });
+                                file_callback(SyncFileEntry {
+                                    path: diff.path.clone(),
+                                    kind: diff.kind.clone(),
+                                    status: SyncFileStatus::Failed,
+                                    source_size: diff.source_size,
+                                    target_size: diff.target_size,
+                                    error: Some(message),
+                                });
+                                current_progress.processed_files += 1;
+                                progress_callback(current_progress.clone());
+                                if options.stop_on_first_error {
+                                    result.stopped_on_error = Some(diff.path.clone());
+                                }
+                                break;
+                            }
+
+                            let kind = if e.to_string().contains("Verification failed") {
+                                crate::sync_engine::types::SyncErrorKind::VerificationFailed
+                            } else {
+                                crate::sync_engine::types::SyncErrorKind::CopyFailed
+                            };
+                            let message = if target_locked {
+                                format!("{e} (target is read-only)")
+                            } else {
+                                e.to_string()
+                            };
+                            result.errors.push(crate::sync_engine::types::SyncError {
+                                path: diff.path.clone(),
+                                message: message.clone(),
+                                kind,
+                            });
+                            let entry = SyncFileEntry {
+                                path: diff.path.clone(),
+                                kind: diff.kind.clone(),
+                                status: SyncFileStatus::Failed,
+                                source_size: diff.source_size,
+                                target_size: diff.target_size,
+                                error: Some(message),
+                            };
+                            file_callback(entry);
+                            if options.stop_on_first_error {
+                                result.stopped_on_error = Some(diff.path.clone());
+                            }
+                        }
+                        Ok(stored_bytes) => {
+                            if options.preserve_flags {
+                                let flag_error =
+                                    match Self::apply_file_flags(&source_path, &target_path) {
+                                        Ok(failed) => failed,
+                                        Err(e) => Some(e.to_string()),
+                                    };
+                                if let Some(message) = flag_error {
+                                    result.errors.push(crate::sync_engine::types::SyncError {
+                                        path: diff.path.clone(),
+                                        message,
+                                        kind: crate::sync_engine::types::SyncErrorKind::FlagsNotPreserved,
+                                    });
+                                }
+                            }
+                            if let Some(stored_bytes) = stored_bytes {
+                                result.compressed_files += 1;
+                                result.compressed_original_bytes += file_size;
+                                result.compressed_stored_bytes += stored_bytes;
+                            }
+                            if verify {
+                                result.files_verified += 1;
+                            } else if options.verify_after_copy {
+                                result.files_verify_sampled_out += 1;
+                            }
+                            result.files_copied += 1;
+                            result.bytes_copied += file_size;
+                            if let Some(key) = dedup_key {
+                                dedup_targets.entry(key).or_insert(target_path.clone());
+                            }
+                            current_progress.bytes_copied_current_file = file_size;
+                            let entry = SyncFileEntry {
+                                path: diff.path.clone(),
+                                kind: diff.kind.clone(),
+                                status: SyncFileStatus::Copied,
+                                source_size: diff.source_size,
+                                target_size: diff.target_size.or(Some(0)),
+                                error: None,
+                            };
+                            file_callback(entry);
+                        }
+                    }
+
+                    current_progress.processed_files += 1;
+                    progress_callback(current_progress.clone());
+                }
+                FileDiffKind::CaseRename => {
+                    let Some(renamed_from) = diff.renamed_from.as_ref() else {
+                        continue;
+                    };
+                    current_progress.current_file = Some(diff.path.to_string_lossy().to_string());
+                    current_progress.bytes_copied_current_file = 0;
+                    current_progress.current_file_total_bytes = 0;
+                    progress_callback(current_progress.clone());
+
+                    let rename_result =
+                        Self::rename_case_only(&self.target.join(renamed_from), &target_path).await;
+                    let error = rename_result.err().map(|e| e.to_string());
+                    if let Some(message) = error.as_ref() {
+                        result.errors.push(crate::sync_engine::types::SyncError {
                             path: diff.path.clone(),
-                            kind: diff.kind.clone(),
-                            status: SyncFileStatus::Copied,
-                            source_size: diff.source_size,
-                            target_size: diff.target_size.or(Some(0)),
-                            error: None,
-                        };
-                        file_callback(entry);
+                            message: message.clone(),
+                            kind: crate::sync_engine::types::SyncErrorKind::CopyFailed,
+                        });
+                        if options.stop_on_first_error {
+                            result.stopped_on_error = Some(diff.path.clone());
+                        }
                     }
+                    file_callback(SyncFileEntry {
+                        path: diff.path.clone(),
+                        kind: diff.kind.clone(),
+                        status: if error.is_some() {
+                            SyncFileStatus::Failed
+                        } else {
+                            SyncFileStatus::Copied
+                        },
+                        source_size: diff.source_size,
+                        target_size: diff.target_size,
+                        error,
+                    });
 
                     current_progress.processed_files += 1;
                     progress_callback(current_progress.clone());
                 }
             }
+
+            if result.stopped_on_error.is_some() {
+                break;
+            }
         }
 
         Ok(result)
@@ -972,17 +2447,571 @@ impl SyncEngine {
             .await
     }
 
-    pub async fn find_orphan_files(&self, exclude_patterns: &[String]) -> Result<Vec<OrphanFile>> {
-        self.find_orphan_files_with_cancel(exclude_patterns, None)
-            .await
+    /// `sync_files` followed by a sweep over the files the compare found already in sync. With
+    /// `verify_after_copy` and `verify_existing_after_sync`, the files the sampling picks
+    /// (`verify_sample_rate`/`verify_sample_seed`) are re-hashed against the source, reusing the
+    /// compare's directory walk. The sweep is skipped when the copy phase stopped early.
+    pub async fn sync_and_verify(
+        &self,
+        options: &SyncOptions,
+        progress_callback: impl Fn(SyncProgress),
+        file_callback: impl Fn(SyncFileEntry),
+    ) -> Result<SyncAndVerifyResult> {
+        let (dry_run, _) = self
+            .compare_dirs_internal(options, None, |_| {}, |_, _| {})
+            .await?;
+        let sync = self
+            .sync_files_from_dry_run(&dry_run, options, progress_callback, file_callback)
+            .await?;
+        let mut result = SyncAndVerifyResult {
+            sync,
+            ..SyncAndVerifyResult::default()
+        };
+        if !options.verify_after_copy
+            || result.sync.stopped_on_error.is_some()
+            || result.sync.time_limit_reached
+            || result.sync.source_disconnected()
+        {
+            return Ok(result);
+        }
+
+        for matched in &dry_run.matched_files {
+            if !Self::should_verify_copy(options, &matched.path, matched.size) {
+                result.existing_sampled_out += 1;
+                continue;
+            }
+            let source_hash = self.calculate_checksum(&matched.source_path).await;
+            let target_hash = self.calculate_checksum(&matched.target_path).await;
+            let error = match (source_hash, target_hash) {
+                (Ok(source_hash), Ok(target_hash)) => {
+                    result.existing_verified += 1;
+                    (source_hash != target_hash).then(|| {
+                        (
+                            "Verification failed: target content differs from source".to_string(),
+                            SyncErrorKind::VerificationFailed,
+                        )
+                    })
+                }
+                (Err(error), _) | (_, Err(error)) => {
+                    Some((format!("{error:#}"), SyncErrorKind::Other))
+                }
+            };
+            if let Some((message, kind)) = error {
+                result.existing_errors.push(SyncError {
+                    path: matched.path.clone(),
+                    message,
+                    kind,
+                });
+            }
+        }
+        Ok(result)
     }
 
-    pub async fn find_orphan_files_with_cancel(
+    /// Fingerprints the source tree. Equal metadata fingerprints strongly imply identical trees;
+    /// equal deep fingerprints prove identical contents.
+    pub async fn directory_fingerprint(
         &self,
         exclude_patterns: &[String],
-        cancel_token: Option<CancellationToken>,
-    ) -> Result<Vec<OrphanFile>> {
-        let source_canonical = tokio::fs::canonicalize(&self.source)
+        deep: bool,
+    ) -> Result<DirectoryFingerprint> {
+        self.directory_fingerprint_with_progress(exclude_patterns, deep, |_| {})
+            .await
+    }
+
+    /// Every file and directory under the source, excluded entries pruned, in path order.
+    pub async fn list_source_entries(
+        &self,
+        exclude_patterns: &[String],
+    ) -> Result<Vec<FileMetadata>> {
+        let source_canonical = tokio::fs::canonicalize(&self.source)
+            .await
+            .with_context(|| format!("Failed to canonicalize path: {:?}", self.source))?;
+        if !tokio::fs::metadata(&source_canonical).await?.is_dir() {
+            anyhow::bail!("Path is not a directory: {:?}", source_canonical);
+        }
+
+        let mut entries = self
+            .read_directory(
+                &source_canonical,
+                DryRunPhase::ScanningSource,
+                exclude_patterns,
+                false,
+                None,
+                Arc::new(StdMutex::new(|_: DryRunProgress| {})),
+            )
+            .await
+            .context("Failed to read directory")?;
+        entries.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(entries)
+    }
+
+    /// Peak bytes `compare_dirs_internal` holds for `entry_count` entries per side: both
+    /// `FileMetadata` lists, the two path-keyed maps (cloned keys plus hashbrown's 1/8 slack and
+    /// control byte), and the sorted source path list. Diffs are left out; they are bounded by
+    /// the source side and usually far smaller.
+    pub fn estimate_compare_peak_bytes(entry_count: u64, average_path_bytes: u64) -> u64 {
+        let path_buf = std::mem::size_of::<PathBuf>() as u64;
+        let metadata = std::mem::size_of::<FileMetadata>() as u64 + average_path_bytes;
+        let map_slot = path_buf + average_path_bytes + std::mem::size_of::<usize>() as u64;
+        let map_entry = map_slot * 8 / 7 + 1;
+        let compare_path = path_buf + average_path_bytes;
+        let per_source_entry = metadata + map_entry + compare_path;
+        let per_target_entry = metadata + map_entry;
+        entry_count.saturating_mul(per_source_entry + per_target_entry)
+    }
+
+    /// Counts entries under `root` (exclusions applied, nothing kept per entry) and sizes a
+    /// compare of that tree with `estimate_compare_peak_bytes`.
+    pub async fn estimate_scan_memory(
+        root: &Path,
+        exclude_patterns: &[String],
+        warn_threshold_bytes: u64,
+    ) -> Result<ScanMemoryEstimate> {
+        let root = tokio::fs::canonicalize(root)
+            .await
+            .with_context(|| format!("Failed to canonicalize path: {:?}", root))?;
+        if !tokio::fs::metadata(&root).await?.is_dir() {
+            anyhow::bail!("Path is not a directory: {:?}", root);
+        }
+        let globs = build_exclude_globset(exclude_patterns)?;
+
+        let (entry_count, total_path_bytes) = tokio::task::spawn_blocking(move || {
+            let mut entry_count = 0u64;
+            let mut total_path_bytes = 0u64;
+            let walker = WalkDir::new(&root)
+                .min_depth(1)
+                .into_iter()
+                .filter_entry(|entry| {
+                    let Ok(relative_path) = entry.path().strip_prefix(&root) else {
+                        return true;
+                    };
+                    let is_dir = entry.file_type().is_dir();
+                    !is_hard_ignored_root_metadata_dir(relative_path, is_dir)
+                        && !globs.is_match(relative_path)
+                });
+            for entry in walker {
+                let entry = entry.context("Failed to traverse directory entry")?;
+                entry_count += 1;
+                if let Ok(relative_path) = entry.path().strip_prefix(&root) {
+                    total_path_bytes += relative_path.as_os_str().len() as u64;
+                }
+            }
+            Ok::<_, anyhow::Error>((entry_count, total_path_bytes))
+        })
+        .await??;
+
+        let average_path_bytes = total_path_bytes.checked_div(entry_count).unwrap_or(0);
+        let estimated_peak_bytes =
+            Self::estimate_compare_peak_bytes(entry_count, average_path_bytes);
+        let exceeds_threshold = estimated_peak_bytes > warn_threshold_bytes;
+        let warning = exceeds_threshold.then(|| {
+            const MIB: u64 = 1024 * 1024;
+            format!(
+                "Comparing {entry_count} entries may need about {} MiB of memory (warning above {} MiB). \
+                 The comparison holds every entry in memory instead of streaming it; split the task \
+                 with source subdirectories or exclusions so each run walks fewer entries.",
+                estimated_peak_bytes / MIB,
+                warn_threshold_bytes / MIB
+            )
+        });
+        Ok(ScanMemoryEstimate {
+            entry_count,
+            average_path_bytes,
+            estimated_peak_bytes,
+            warn_threshold_bytes,
+            exceeds_threshold,
+            warning,
+        })
+    }
+
+    /// Where `layout` would place each source file, without touching the target. Collisions are
+    /// resolved the same way a sync resolves them: the first source path in order wins.
+    pub async fn preview_target_layout(
+        &self,
+        layout: &TargetLayout,
+        exclude_patterns: &[String],
+        sample_limit: usize,
+    ) -> Result<TargetLayoutPreview> {
+        layout.validate().map_err(anyhow::Error::msg)?;
+        let files = self.list_source_entries(exclude_patterns).await?;
+
+        let mut total_files = 0;
+        let mut mappings = Vec::new();
+        let mut claimed: HashMap<PathBuf, Vec<PathBuf>> = HashMap::new();
+        for file in files.into_iter().filter(|file| file.is_file) {
+            total_files += 1;
+            let target_path = layout.relative_path_for(&file.path, file.modified);
+            if mappings.len() < sample_limit {
+                mappings.push(TargetLayoutMapping {
+                    source_path: file.path.clone(),
+                    target_path: target_path.clone(),
+                    collides: false,
+                });
+            }
+            claimed.entry(target_path).or_default().push(file.path);
+        }
+
+        for mapping in &mut mappings {
+            mapping.collides = claimed
+                .get(&mapping.target_path)
+                .is_some_and(|sources| sources.len() > 1);
+        }
+        let mut collisions: Vec<TargetLayoutCollision> = claimed
+            .into_iter()
+            .filter(|(_, source_paths)| source_paths.len() > 1)
+            .map(|(target_path, source_paths)| TargetLayoutCollision {
+                target_path,
+                source_paths,
+            })
+            .collect();
+        collisions.sort_by(|a, b| a.target_path.cmp(&b.target_path));
+
+        Ok(TargetLayoutPreview {
+            total_files,
+            mappings,
+            collisions,
+        })
+    }
+
+    /// `directory_fingerprint` that reports `Verifying` progress while deep mode hashes file
+    /// contents. Updates are throttled to one per 100ms, plus one when hashing finishes.
+    pub async fn directory_fingerprint_with_progress(
+        &self,
+        exclude_patterns: &[String],
+        deep: bool,
+        progress_callback: impl Fn(SyncProgress),
+    ) -> Result<DirectoryFingerprint> {
+        use twox_hash::XxHash64;
+
+        let source_canonical = tokio::fs::canonicalize(&self.source)
+            .await
+            .with_context(|| format!("Failed to canonicalize path: {:?}", self.source))?;
+        if !tokio::fs::metadata(&source_canonical).await?.is_dir() {
+            anyhow::bail!("Path is not a directory: {:?}", source_canonical);
+        }
+
+        let mut files: Vec<FileMetadata> = self
+            .read_directory(
+                &source_canonical,
+                DryRunPhase::ScanningSource,
+                exclude_patterns,
+                false,
+                None,
+                Arc::new(StdMutex::new(|_: DryRunProgress| {})),
+            )
+            .await
+            .context("Failed to read directory")?
+            .into_iter()
+            .filter(|file| file.is_file)
+            .collect();
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+
+        let mut progress = SyncProgress {
+            phase: SyncProgressPhase::Verifying,
+            current_file: None,
+            total_files: files.len() as u64,
+            processed_files: 0,
+            total_bytes: files.iter().map(|file| file.size).sum(),
+            processed_bytes: 0,
+            bytes_copied_current_file: 0,
+            current_file_total_bytes: 0,
+        };
+        let emit_interval = std::time::Duration::from_millis(100);
+        let mut last_emit_at: Option<std::time::Instant> = None;
+        let mut emit_throttled = |progress: &SyncProgress| {
+            let now = std::time::Instant::now();
+            if last_emit_at.is_none_or(|last| now.duration_since(last) >= emit_interval) {
+                last_emit_at = Some(now);
+                progress_callback(progress.clone());
+            }
+        };
+
+        let mut hasher = XxHash64::with_seed(0);
+        let mut total_bytes = 0u64;
+        for file in &files {
+            hasher.write(file.path.to_string_lossy().as_bytes());
+            hasher.write_u8(0);
+            hasher.write_u64(file.size);
+            if deep {
+                progress.current_file = Some(file.path.to_string_lossy().to_string());
+                progress.bytes_copied_current_file = 0;
+                progress.current_file_total_bytes = file.size;
+                emit_throttled(&progress);
+                let checksum = self
+                    .calculate_checksum_with_progress(
+                        &source_canonical.join(&file.path),
+                        |hashed_chunk| {
+                            progress.processed_bytes += hashed_chunk;
+                            progress.bytes_copied_current_file += hashed_chunk;
+                            emit_throttled(&progress);
+                        },
+                    )
+                    .await?;
+                hasher.write(checksum.as_bytes());
+                progress.processed_files += 1;
+            } else {
+                let modified_ms = Self::system_time_to_unix_ms(Some(file.modified)).unwrap_or(0);
+                hasher.write_i64(modified_ms / 1000);
+            }
+            hasher.write_u8(0);
+            total_bytes += file.size;
+        }
+        if deep {
+            progress.current_file = None;
+            progress_callback(progress);
+        }
+
+        Ok(DirectoryFingerprint {
+            fingerprint: format!("{:016x}", hasher.finish()),
+            file_count: files.len(),
+            total_bytes,
+            deep,
+        })
+    }
+
+    /// Mirrors the source directory tree (directories only) into the target ahead of a sync.
+    /// Returns the number of target directories that did not exist before the call.
+    pub async fn create_target_structure(
+        &self,
+        exclude_patterns: &[String],
+        preserve_permissions: bool,
+    ) -> Result<usize> {
+        let source_canonical = tokio::fs::canonicalize(&self.source)
+            .await
+            .with_context(|| format!("Failed to canonicalize source: {:?}", self.source))?;
+        if !tokio::fs::metadata(&source_canonical).await?.is_dir() {
+            anyhow::bail!("Source path is not a directory: {:?}", source_canonical);
+        }
+        if let Ok(target_canonical) = tokio::fs::canonicalize(&self.target).await {
+            if target_canonical == source_canonical {
+                anyhow::bail!(
+                    "SourceEqualsTarget: source and target resolve to the same directory: {:?}",
+                    source_canonical
+                );
+            }
+        }
+
+        let mut dirs: Vec<PathBuf> = self
+            .read_directory(
+                &source_canonical,
+                DryRunPhase::ScanningSource,
+                exclude_patterns,
+                false,
+                None,
+                Arc::new(StdMutex::new(|_: DryRunProgress| {})),
+            )
+            .await
+            .context("Failed to read source directory")?
+            .into_iter()
+            .filter(|entry| !entry.is_file)
+            .map(|entry| entry.path)
+            .collect();
+        dirs.sort();
+
+        fs::create_dir_all(&self.target)
+            .await
+            .with_context(|| format!("Failed to create target: {:?}", self.target))?;
+
+        let mut created = 0usize;
+        for rel in dirs {
+            let source_dir = source_canonical.join(&rel);
+            let source_meta = match fs::symlink_metadata(&source_dir).await {
+                Ok(meta) if meta.is_dir() => meta,
+                _ => continue,
+            };
+            let target_dir = self.target.join(&rel);
+            if fs::symlink_metadata(&target_dir).await.is_ok() {
+                continue;
+            }
+            fs::create_dir_all(&target_dir)
+                .await
+                .with_context(|| format!("Failed to create directory: {:?}", target_dir))?;
+            if preserve_permissions {
+                fs::set_permissions(&target_dir, source_meta.permissions()).await?;
+            }
+            created += 1;
+        }
+
+        Ok(created)
+    }
+
+    /// Copies a single source-relative file again, e.g. to retry one that failed verification,
+    /// without re-running the whole sync.
+    pub async fn recopy_file(
+        &self,
+        relative_path: &Path,
+        options: &SyncOptions,
+    ) -> Result<RecopyFileResult> {
+        let source_path = self.source.join(relative_path);
+        let target_path = self.target.join(relative_path);
+        let source_meta = fs::symlink_metadata(&source_path)
+            .await
+            .with_context(|| format!("Failed to access source: {:?}", source_path))?;
+        if !source_meta.is_file() {
+            anyhow::bail!("Source path is not a regular file: {:?}", source_path);
+        }
+
+        let verify = options.verify_after_copy;
+        match self
+            .copy_file_chunked(&source_path, &target_path, options, verify, |_| {})
+            .await
+        {
+            Ok(()) => Ok(RecopyFileResult {
+                path: relative_path.to_path_buf(),
+                success: true,
+                bytes_copied: source_meta.len(),
+                verified: verify,
+                error: None,
+            }),
+            Err(error) if error.to_string().contains("Verification failed") => {
+                Ok(RecopyFileResult {
+                    path: relative_path.to_path_buf(),
+                    success: false,
+                    bytes_copied: 0,
+                    verified: true,
+                    error: Some(error.to_string()),
+                })
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Read-only drift check: a dry run supplies new/modified files, an orphan scan supplies
+    /// extra target files, and every other compared source file counts as identical.
+    pub async fn audit_drift(&self, options: &SyncOptions) -> Result<DriftReport> {
+        let (dry_run, conflicts) = self
+            .compare_dirs_internal(options, None, |_| {}, |_, _| {})
+            .await?;
+        let orphans = self.find_orphan_files_with_options(options, None).await?;
+
+        let mut report = DriftReport::default();
+        let mut drifted: HashSet<&Path> = HashSet::new();
+        for diff in &dry_run.diffs {
+            let bytes = diff.source_size.unwrap_or(0);
+            match diff.kind {
+                FileDiffKind::New => report.new.add(bytes),
+                FileDiffKind::Modified | FileDiffKind::CaseRename => report.modified.add(bytes),
+            }
+            drifted.insert(diff.path.as_path());
+        }
+        for conflict in &conflicts {
+            report.modified.add(conflict.source.size);
+            drifted.insert(conflict.path.as_path());
+        }
+        for orphan in orphans.iter().filter(|orphan| !orphan.is_dir) {
+            report.orphan.add(orphan.size);
+        }
+
+        let source_canonical = tokio::fs::canonicalize(&self.source)
+            .await
+            .with_context(|| format!("Failed to canonicalize source: {:?}", self.source))?;
+        let source_files = self
+            .read_directory_scoped(
+                &source_canonical,
+                DryRunPhase::ScanningSource,
+                &options.exclude_patterns,
+                options.source_subdirs.as_deref(),
+                options.parallel_scan,
+                None,
+                Arc::new(StdMutex::new(|_: DryRunProgress| {})),
+                None,
+            )
+            .await
+            .context("Failed to read source directory")?;
+        for file in source_files.iter().filter(|file| file.is_file) {
+            let compared = Self::is_within_modified_window(options, file.modified)
+                && !(options.skip_empty_files && file.size == 0);
+            if compared && !drifted.contains(file.path.as_path()) {
+                report.identical.add(file.size);
+            }
+        }
+
+        Ok(report)
+    }
+
+    pub async fn find_orphan_files(&self, exclude_patterns: &[String]) -> Result<Vec<OrphanFile>> {
+        self.find_orphan_files_with_cancel(exclude_patterns, None)
+            .await
+    }
+
+    pub async fn find_orphan_files_with_cancel(
+        &self,
+        exclude_patterns: &[String],
+        cancel_token: Option<CancellationToken>,
+    ) -> Result<Vec<OrphanFile>> {
+        self.find_orphan_files_with_layout(exclude_patterns, None, None, cancel_token)
+            .await
+    }
+
+    /// Reads both trees for a target-side scan, with `exclude_patterns` applied on each side,
+    /// both limited to `source_subdirs` (the target only without a layout) and the source
+    /// re-keyed by `target_layout`. `None` when the target does not exist yet.
+    async fn scan_source_and_target(
+        &self,
+        exclude_patterns: &[String],
+        source_subdirs: Option<&[String]>,
+        target_layout: Option<&TargetLayout>,
+        cancel_token: Option<CancellationToken>,
+    ) -> Result<Option<(Vec<FileMetadata>, Vec<FileMetadata>)>> {
+        if let Some(layout) = target_layout {
+            layout.validate().map_err(anyhow::Error::msg)?;
+        }
+        if let Some(subdirs) = source_subdirs {
+            validate_source_subdirs(subdirs).map_err(anyhow::Error::msg)?;
+        }
+        let Some((source_files, target_files)) = self
+            .read_source_and_target_trees(
+                exclude_patterns,
+                source_subdirs,
+                Self::target_subdirs(source_subdirs, target_layout),
+                cancel_token,
+            )
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        self.ensure_source_not_suspiciously_empty(&source_files, &target_files)?;
+
+        let source_files = match target_layout {
+            Some(layout) => Self::apply_target_layout(layout, source_files).0,
+            None => source_files,
+        };
+        let target_files = Self::drop_resumable_partial_artifacts(&source_files, target_files);
+        Ok(Some((source_files, target_files)))
+    }
+
+    /// Drops partial copies and sidecars whose target file is still a source file, so a later
+    /// run can resume them. A partial whose source file is gone is stale and stays in the list,
+    /// so orphan and mirror-deletion scans report it instead of leaking target space.
+    fn drop_resumable_partial_artifacts(
+        source_files: &[FileMetadata],
+        target_files: Vec<FileMetadata>,
+    ) -> Vec<FileMetadata> {
+        let source_file_paths: HashSet<&Path> = source_files
+            .iter()
+            .filter(|file| file.is_file)
+            .map(|file| file.path.as_path())
+            .collect();
+        target_files
+            .into_iter()
+            .filter(|file| {
+                partial_copy_owner(&file.path)
+                    .is_none_or(|owner| !source_file_paths.contains(owner.as_path()))
+            })
+            .collect()
+    }
+
+    /// Both trees as stored on disk, with `exclude_patterns` applied on each side and each walk
+    /// limited to its top-level directory list. `None` when the target does not exist yet.
+    async fn read_source_and_target_trees(
+        &self,
+        exclude_patterns: &[String],
+        source_subdirs: Option<&[String]>,
+        target_subdirs: Option<&[String]>,
+        cancel_token: Option<CancellationToken>,
+    ) -> Result<Option<(Vec<FileMetadata>, Vec<FileMetadata>)>> {
+        let source_canonical = tokio::fs::canonicalize(&self.source)
             .await
             .with_context(|| format!("Failed to canonicalize source: {:?}", self.source))?;
 
@@ -1005,38 +3034,158 @@ impl SyncEngine {
                     .await
                     .with_context(|| format!("Failed to canonicalize target: {:?}", self.target))?
             }
-            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
             Err(err) => {
                 return Err(err)
                     .with_context(|| format!("Failed to access target: {:?}", self.target))
             }
         };
+        if target_canonical == source_canonical {
+            anyhow::bail!(
+                "SourceEqualsTarget: source and target resolve to the same directory: {:?}",
+                source_canonical
+            );
+        }
 
         let source_files = self
-            .read_directory(
+            .read_directory_scoped(
                 &source_canonical,
                 DryRunPhase::ScanningSource,
                 exclude_patterns,
+                source_subdirs,
+                false,
                 cancel_token.clone(),
                 Arc::new(StdMutex::new(|_: DryRunProgress| {})),
+                None,
             )
             .await
             .context("Failed to read source directory")?;
         let target_files = self
-            .read_directory(
+            .read_directory_scoped(
                 &target_canonical,
                 DryRunPhase::ScanningTarget,
                 exclude_patterns,
+                target_subdirs,
+                false,
                 cancel_token,
                 Arc::new(StdMutex::new(|_: DryRunProgress| {})),
+                None,
             )
             .await
             .context("Failed to read target directory")?;
+        Ok(Some((source_files, target_files)))
+    }
+
+    /// Records every file whose source and target copies agree on size and whole-second mtime.
+    /// Meant to run right after a successful checksum-mode sync, when those pairs were either
+    /// just copied or hashed equal, so a later run can trust them via `with_trusted_snapshot`.
+    pub async fn capture_sync_snapshot(&self, exclude_patterns: &[String]) -> Result<SyncSnapshot> {
+        let Some((source_files, target_files)) = self
+            .read_source_and_target_trees(exclude_patterns, None, None, None)
+            .await?
+        else {
+            return Ok(SyncSnapshot::default());
+        };
+        let target_map: HashMap<&Path, &FileMetadata> = target_files
+            .iter()
+            .filter(|meta| meta.is_file)
+            .map(|meta| (meta.path.as_path(), meta))
+            .collect();
+        let entries = source_files
+            .iter()
+            .filter(|meta| meta.is_file)
+            .filter_map(|source_meta| {
+                let target_meta = target_map.get(source_meta.path.as_path())?;
+                let entry = SyncSnapshotEntry::from_metadata(source_meta);
+                (entry == SyncSnapshotEntry::from_metadata(target_meta))
+                    .then(|| (source_meta.path.clone(), entry))
+            })
+            .collect();
+        Ok(SyncSnapshot { entries })
+    }
+
+    /// Orphan scan against the target tree `target_layout` produces: a target entry is kept when
+    /// it is a placed file or one of the directories leading to one.
+    pub async fn find_orphan_files_with_layout(
+        &self,
+        exclude_patterns: &[String],
+        source_subdirs: Option<&[String]>,
+        target_layout: Option<&TargetLayout>,
+        cancel_token: Option<CancellationToken>,
+    ) -> Result<Vec<OrphanFile>> {
+        let options = SyncOptions {
+            exclude_patterns: exclude_patterns.to_vec(),
+            source_subdirs: source_subdirs.map(<[String]>::to_vec),
+            target_layout: target_layout.cloned(),
+            ..SyncOptions::default()
+        };
+        self.find_orphan_files_with_options(&options, cancel_token)
+            .await
+    }
+
+    /// Repeats the orphan scans' suspiciously-empty check right before a deletion, since the
+    /// source may have been unplugged or only half remounted after the orphan list was built.
+    pub async fn ensure_source_still_populated(&self, options: &SyncOptions) -> Result<()> {
+        if self.allow_empty_source {
+            return Ok(());
+        }
+        self.scan_source_and_target(
+            &options.exclude_patterns,
+            options.source_subdirs.as_deref(),
+            options.target_layout.as_ref(),
+            None,
+        )
+        .await?;
+        Ok(())
+    }
 
-        let source_paths: HashSet<&PathBuf> = source_files.iter().map(|f| &f.path).collect();
+    /// Orphan scan with the exclusions, `source_subdirs` and `target_layout` of `options`. A
+    /// `name.ext.gz` next to a source `name.ext` only counts as its compressed copy when
+    /// `options` would compress that file.
+    pub async fn find_orphan_files_with_options(
+        &self,
+        options: &SyncOptions,
+        cancel_token: Option<CancellationToken>,
+    ) -> Result<Vec<OrphanFile>> {
+        let target_layout = options.target_layout.as_ref();
+        let Some((source_files, target_files)) = self
+            .scan_source_and_target(
+                &options.exclude_patterns,
+                options.source_subdirs.as_deref(),
+                target_layout,
+                cancel_token,
+            )
+            .await?
+        else {
+            return Ok(Vec::new());
+        };
+        let mut source_paths: HashSet<&Path> =
+            source_files.iter().map(|f| f.path.as_path()).collect();
+        if target_layout.is_some() {
+            for file in &source_files {
+                source_paths.extend(
+                    file.path
+                        .ancestors()
+                        .skip(1)
+                        .filter(|ancestor| !ancestor.as_os_str().is_empty()),
+                );
+            }
+        }
+        let source_file_paths: HashSet<&PathBuf> = source_files
+            .iter()
+            .filter(|f| f.is_file)
+            .map(|f| &f.path)
+            .collect();
+        let is_compressed_copy = |meta: &FileMetadata| {
+            if !meta.is_file || meta.path.extension() != Some(OsStr::new("gz")) {
+                return false;
+            }
+            let source_path = meta.path.with_extension("");
+            source_file_paths.contains(&source_path) && Self::should_compress(options, &source_path)
+        };
         let mut orphans: Vec<OrphanFile> = target_files
             .iter()
-            .filter(|meta| !source_paths.contains(&meta.path))
+            .filter(|meta| !source_paths.contains(meta.path.as_path()) && !is_compressed_copy(meta))
             .map(|meta| OrphanFile {
                 path: meta.path.clone(),
                 size: if meta.is_file { meta.size } else { 0 },
@@ -1048,25 +3197,94 @@ impl SyncEngine {
         Ok(orphans)
     }
 
-    /// Counts the number of descendant files and directories inside `path`.
-    ///
-    /// **Note**: The counts are a snapshot taken *before* the actual deletion. Between the
-    /// time this function returns and `remove_dir_all` completes, external processes may
-    /// add or remove entries, making the reported counts approximate. This is an inherent
-    /// limitation — the alternative (counting after deletion) is impossible.
-    async fn count_dir_contents(path: PathBuf) -> Result<(usize, usize)> {
-        tokio::task::spawn_blocking(move || {
-            let mut files_count = 0usize;
-            let mut dirs_count = 0usize;
-
-            for entry in WalkDir::new(&path)
-                .into_iter()
-                .filter_map(|entry| entry.ok())
-            {
-                if entry.path() == path.as_path() {
-                    continue;
-                }
-
+    /// Target paths a mirror sync with `options` would remove: every target entry that is not a
+    /// stored path of a source file (after exclusions, `target_layout` and compression naming)
+    /// or a directory leading to one. Copy filters such as the mtime window or
+    /// `skip_empty_files` only skip copying, so files they hold back still keep their target
+    /// copies. Unlike the orphan scan, an uncompressed copy left behind after enabling
+    /// compression is listed. Path-sorted and not collapsed.
+    pub async fn preview_mirror_deletions(
+        &self,
+        options: &SyncOptions,
+        cancel_token: Option<CancellationToken>,
+    ) -> Result<Vec<OrphanFile>> {
+        let Some((source_files, target_files)) = self
+            .scan_source_and_target(
+                &options.exclude_patterns,
+                options.source_subdirs.as_deref(),
+                options.target_layout.as_ref(),
+                cancel_token,
+            )
+            .await?
+        else {
+            return Ok(Vec::new());
+        };
+        let mut kept: HashSet<PathBuf> = HashSet::new();
+        for file in &source_files {
+            let stored = if file.is_file {
+                Self::stored_relative_path(options, &file.path)
+            } else {
+                file.path.clone()
+            };
+            kept.extend(
+                stored
+                    .ancestors()
+                    .filter(|ancestor| !ancestor.as_os_str().is_empty())
+                    .map(Path::to_path_buf),
+            );
+        }
+        let mut deletions: Vec<OrphanFile> = target_files
+            .into_iter()
+            .filter(|meta| !kept.contains(&meta.path))
+            .map(|meta| OrphanFile {
+                size: if meta.is_file { meta.size } else { 0 },
+                is_dir: !meta.is_file,
+                path: meta.path,
+            })
+            .collect();
+
+        deletions.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(deletions)
+    }
+
+    /// Reduces a path-sorted orphan list to top-level orphan directories and standalone files,
+    /// the same ancestor coverage `delete_orphan_paths` applies. Each kept directory's `size`
+    /// becomes the total size of the orphan files beneath it.
+    pub fn collapse_orphan_subtrees(orphans: Vec<OrphanFile>) -> Vec<OrphanFile> {
+        let mut collapsed: Vec<OrphanFile> = Vec::new();
+        for orphan in orphans {
+            if let Some(top) = collapsed.last_mut() {
+                if top.is_dir && orphan.path.starts_with(&top.path) && orphan.path != top.path {
+                    if !orphan.is_dir {
+                        top.size += orphan.size;
+                    }
+                    continue;
+                }
+            }
+            collapsed.push(orphan);
+        }
+        collapsed
+    }
+
+    /// Counts the number of descendant files and directories inside `path`.
+    ///
+    /// **Note**: The counts are a snapshot taken *before* the actual deletion. Between the
+    /// time this function returns and `remove_dir_all` completes, external processes may
+    /// add or remove entries, making the reported counts approximate. This is an inherent
+    /// limitation — the alternative (counting after deletion) is impossible.
+    async fn count_dir_contents(path: PathBuf) -> Result<(usize, usize)> {
+        tokio::task::spawn_blocking(move || {
+            let mut files_count = 0usize;
+            let mut dirs_count = 0usize;
+
+            for entry in WalkDir::new(&path)
+                .into_iter()
+                .filter_map(|entry| entry.ok())
+            {
+                if entry.path() == path.as_path() {
+                    continue;
+                }
+
                 if entry.file_type().is_dir() {
                     dirs_count += 1;
                 } else {
@@ -1082,6 +3300,116 @@ impl SyncEngine {
     pub async fn delete_orphan_paths(
         &self,
         relative_paths: &[PathBuf],
+    ) -> Result<DeleteOrphanResult> {
+        self.delete_orphan_paths_with_trash(relative_paths, false)
+            .await
+    }
+
+    /// Counts what `delete_orphan_paths` would remove for `relative_paths` without deleting.
+    pub async fn summarize_orphan_paths(
+        &self,
+        relative_paths: &[PathBuf],
+    ) -> Result<OrphanDeletionSummary> {
+        let target_canonical = tokio::fs::canonicalize(&self.target)
+            .await
+            .with_context(|| format!("Failed to canonicalize target: {:?}", self.target))?;
+
+        let mut summary = OrphanDeletionSummary::default();
+        let mut selected: Vec<PathBuf> = Vec::new();
+        for relative in relative_paths {
+            if relative.is_absolute()
+                || relative
+                    .components()
+                    .any(|c| matches!(c, std::path::Component::ParentDir))
+            {
+                summary.skipped_count += 1;
+                continue;
+            }
+            let in_target = match tokio::fs::canonicalize(target_canonical.join(relative)).await {
+                Ok(canonical) => canonical.starts_with(&target_canonical),
+                Err(_) => false,
+            };
+            if !in_target {
+                summary.skipped_count += 1;
+                continue;
+            }
+            selected.push(relative.clone());
+        }
+
+        // Path ordering is component-wise, so descendants directly follow their ancestor.
+        selected.sort();
+        selected.dedup();
+        let mut top_level: Vec<PathBuf> = Vec::new();
+        for relative in selected {
+            if top_level
+                .last()
+                .is_some_and(|kept| relative.starts_with(kept))
+            {
+                continue;
+            }
+            top_level.push(relative);
+        }
+
+        for relative in top_level {
+            let full_path = target_canonical.join(&relative);
+            let metadata = tokio::fs::symlink_metadata(&full_path).await?;
+            if !metadata.is_dir() {
+                summary.file_count += 1;
+                summary.total_bytes += metadata.len();
+                continue;
+            }
+            let (files, dirs, bytes) = tokio::task::spawn_blocking(move || {
+                let mut counts = (0usize, 0usize, 0u64);
+                for entry in WalkDir::new(&full_path)
+                    .into_iter()
+                    .filter_map(|entry| entry.ok())
+                {
+                    if entry.file_type().is_dir() {
+                        counts.1 += 1;
+                    } else {
+                        counts.0 += 1;
+                        counts.2 += entry.metadata().map(|meta| meta.len()).unwrap_or(0);
+                    }
+                }
+                counts
+            })
+            .await?;
+            summary.file_count += files;
+            summary.dir_count += dirs;
+            summary.total_bytes += bytes;
+            summary.top_level_dirs.push(relative);
+        }
+
+        Ok(summary)
+    }
+
+    /// Moves a file or directory to the user Trash. Errors when trashing is unsupported
+    /// (non-macOS or volumes without a Trash) so callers can fall back to deletion.
+    async fn move_to_trash(path: &Path) -> std::result::Result<(), String> {
+        let path = path.to_string_lossy().to_string();
+        tokio::task::spawn_blocking(move || crate::apple_bridge::trash_item(&path).map(|_| ()))
+            .await
+            .map_err(|error| error.to_string())?
+    }
+
+    /// `use_trash` moves entries to the Trash so deletions stay recoverable; if trashing
+    /// fails the entry is removed permanently and a warning is recorded.
+    pub async fn delete_orphan_paths_with_trash(
+        &self,
+        relative_paths: &[PathBuf],
+        use_trash: bool,
+    ) -> Result<DeleteOrphanResult> {
+        self.delete_orphan_paths_with_progress(relative_paths, use_trash, |_| {})
+            .await
+    }
+
+    /// Same as `delete_orphan_paths_with_trash`, reporting a `Deleting` progress update before
+    /// each top-level entry is removed and once more when the loop finishes.
+    pub async fn delete_orphan_paths_with_progress(
+        &self,
+        relative_paths: &[PathBuf],
+        use_trash: bool,
+        progress_callback: impl Fn(SyncProgress),
     ) -> Result<DeleteOrphanResult> {
         let target_canonical = tokio::fs::canonicalize(&self.target)
             .await
@@ -1141,9 +3469,26 @@ impl SyncEngine {
 
         let mut deleted_files_count = 0usize;
         let mut deleted_dirs_count = 0usize;
+        let mut trashed_count = 0usize;
         let mut failures = Vec::new();
+        let mut warnings = Vec::new();
+
+        let mut current_progress = SyncProgress {
+            phase: SyncProgressPhase::Deleting,
+            current_file: None,
+            total_files: reduced_targets.len() as u64,
+            processed_files: 0,
+            total_bytes: 0,
+            processed_bytes: 0,
+            bytes_copied_current_file: 0,
+            current_file_total_bytes: 0,
+        };
 
         for (relative, canonical) in reduced_targets {
+            current_progress.current_file = Some(relative.to_string_lossy().to_string());
+            progress_callback(current_progress.clone());
+            current_progress.processed_files += 1;
+
             let metadata = match tokio::fs::symlink_metadata(&canonical).await {
                 Ok(meta) => meta,
                 Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
@@ -1160,7 +3505,7 @@ impl SyncEngine {
             };
 
             let mut dir_contents = None;
-            let delete_result = if metadata.is_dir() {
+            if metadata.is_dir() {
                 match Self::count_dir_contents(canonical.clone()).await {
                     Ok(counts) => {
                         dir_contents = Some(counts);
@@ -1173,6 +3518,26 @@ impl SyncEngine {
                         continue;
                     }
                 }
+            }
+
+            let trashed = if use_trash {
+                match Self::move_to_trash(&canonical).await {
+                    Ok(()) => true,
+                    Err(error) => {
+                        warnings.push(format!(
+                            "Could not move {} to Trash, deleted permanently: {error}",
+                            relative.display()
+                        ));
+                        false
+                    }
+                }
+            } else {
+                false
+            };
+            let delete_result = if trashed {
+                trashed_count += 1;
+                Ok(())
+            } else if metadata.is_dir() {
                 tokio::fs::remove_dir_all(&canonical).await
             } else {
                 tokio::fs::remove_file(&canonical).await
@@ -1198,6 +3563,9 @@ impl SyncEngine {
             }
         }
 
+        current_progress.current_file = None;
+        progress_callback(current_progress);
+
         let deleted_count = deleted_files_count + deleted_dirs_count;
         Ok(DeleteOrphanResult {
             deleted_count,
@@ -1205,6 +3573,8 @@ impl SyncEngine {
             deleted_dirs_count,
             skipped_count,
             failures,
+            trashed_count,
+            warnings,
         })
     }
 
@@ -1213,10 +3583,9 @@ impl SyncEngine {
         source: &Path,
         target: &Path,
         options: &SyncOptions,
+        verify: bool,
         mut on_progress: impl FnMut(u64),
     ) -> Result<()> {
-        use tokio::io::AsyncWriteExt; // Import for write_all
-
         reject_symlink_destination_under_root(&self.target, target).await?;
 
         if let Some(parent) = target.parent() {
@@ -1225,23 +3594,70 @@ impl SyncEngine {
 
         reject_symlink_destination_under_root(&self.target, target).await?;
 
+        if options.resume_partial_copies
+            && fs::metadata(source).await?.len() >= RESUMABLE_COPY_MIN_BYTES
+        {
+            self.copy_file_resumable(source, target, options, verify, &mut on_progress)
+                .await?;
+            // Already verified before the rename.
+            return self
+                .finish_copied_file(source, target, options, false)
+                .await;
+        }
+
+        // A clone writes no data, so there is nothing to throttle or read back.
+        #[cfg(target_os = "macos")]
+        if !options.read_after_write
+            && !self
+                .bandwidth_limiter
+                .as_ref()
+                .is_some_and(|limiter| limiter.bytes_per_sec() > 0)
+            && Self::clone_file_same_volume(source, target).await?
+        {
+            if !options.preserve_flags {
+                Self::clear_write_blocking_flags(target);
+            }
+            on_progress(fs::metadata(target).await?.len());
+            return self
+                .finish_copied_file(source, target, options, verify)
+                .await;
+        }
+
+        Self::unlink_shared_target(target).await?;
         let mut source_file = fs::File::open(source).await?;
         let mut target_options = fs::OpenOptions::new();
         target_options.write(true).create(true).truncate(true);
         #[cfg(unix)]
         target_options.custom_flags(nix::fcntl::OFlag::O_NOFOLLOW.bits());
         let mut target_file = target_options.open(target).await?;
-        let mut buffer = [0u8; 64 * 1024]; // 64KB chunks
+        let mut readback = if options.read_after_write {
+            Some(Self::open_uncached_readback(target).await?)
+        } else {
+            None
+        };
 
-        loop {
-            let n = source_file.read(&mut buffer).await?;
-            if n == 0 {
-                break;
-            }
-            target_file.write_all(&buffer[..n]).await?;
-            on_progress(n as u64);
-        }
+        self.write_chunks(
+            &mut source_file,
+            &mut target_file,
+            readback.as_mut(),
+            &mut on_progress,
+            0,
+        )
+        .await?;
+
+        self.finish_copied_file(source, target, options, verify)
+            .await
+    }
 
+    /// Applies preserved metadata to a freshly written `target` and, with `verify`, compares
+    /// checksums, removing the target on mismatch.
+    async fn finish_copied_file(
+        &self,
+        source: &Path,
+        target: &Path,
+        options: &SyncOptions,
+        verify: bool,
+    ) -> Result<()> {
         if options.preserve_permissions {
             let meta = fs::metadata(source).await?;
             let perms = meta.permissions();
@@ -1254,7 +3670,7 @@ impl SyncEngine {
             filetime::set_file_mtime(target, filetime::FileTime::from_system_time(modified))?;
         }
 
-        if options.verify_after_copy {
+        if verify && !Self::both_empty(source, target).await? {
             let source_hash = self.calculate_checksum(source).await?;
             let target_hash = self.calculate_checksum(target).await?;
 
@@ -1266,431 +3682,2747 @@ impl SyncEngine {
 
         Ok(())
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::os::unix::fs::PermissionsExt;
-    use std::sync::{Arc, Mutex as StdMutex};
-    use tempfile::TempDir;
+    /// Copies through a deterministic partial file beside `target` that survives a failed or
+    /// cancelled run. A later run appends to it instead of starting over when the sidecar shows
+    /// the source still has the same size and mtime. With `verify`, the assembled partial is
+    /// checked against the source before it is renamed over `target`; a mismatch discards it
+    /// so the next run starts over. Only newly written bytes are reported to `on_progress`.
+    async fn copy_file_resumable(
+        &self,
+        source: &Path,
+        target: &Path,
+        options: &SyncOptions,
+        verify: bool,
+        on_progress: &mut impl FnMut(u64),
+    ) -> Result<()> {
+        use tokio::io::{AsyncSeekExt, AsyncWriteExt};
 
-    #[tokio::test]
-    async fn test_basic_sync() -> Result<()> {
-        let source_dir = TempDir::new()?;
-        let target_dir = TempDir::new()?;
+        let partial = partial_copy_path(target);
+        let sidecar = partial_copy_sidecar_path(target);
+        reject_symlink_destination_under_root(&self.target, &partial).await?;
+        reject_symlink_destination_under_root(&self.target, &sidecar).await?;
 
-        let source = source_dir.path().join("test.txt");
-        fs::write(&source, b"hello world").await?;
+        let header = PartialCopyHeader::from_metadata(&fs::metadata(source).await?);
+        let recorded = fs::read(&sidecar)
+            .await
+            .ok()
+            .and_then(|bytes| serde_json::from_slice::<PartialCopyHeader>(&bytes).ok());
+        let resume_from = match fs::symlink_metadata(&partial).await {
+            Ok(meta) if meta.is_file() && recorded.as_ref() == Some(&header) => {
+                meta.len().min(header.size)
+            }
+            _ => 0,
+        };
+        if resume_from == 0 {
+            fs::write(&sidecar, serde_json::to_vec(&header)?).await?;
+        }
 
-        let engine = SyncEngine::new(
-            source_dir.path().to_path_buf(),
-            target_dir.path().to_path_buf(),
-        );
-        let options = SyncOptions::default();
+        let mut source_file = fs::File::open(source).await?;
+        let mut partial_options = fs::OpenOptions::new();
+        partial_options
+            .write(true)
+            .create(true)
+            .truncate(resume_from == 0);
+        #[cfg(unix)]
+        partial_options.custom_flags(nix::fcntl::OFlag::O_NOFOLLOW.bits());
+        let mut partial_file = partial_options.open(&partial).await?;
+        partial_file.set_len(resume_from).await?;
+        source_file
+            .seek(std::io::SeekFrom::Start(resume_from))
+            .await?;
+        partial_file
+            .seek(std::io::SeekFrom::Start(resume_from))
+            .await?;
+        let mut readback = if options.read_after_write {
+            Some(Self::open_uncached_readback(&partial).await?)
+        } else {
+            None
+        };
 
-        let dry_run = engine.dry_run(&options).await?;
-        assert_eq!(dry_run.files_to_copy, 1);
+        // On error the partial file and sidecar stay behind for the next run.
+        self.write_chunks(
+            &mut source_file,
+            &mut partial_file,
+            readback.as_mut(),
+            on_progress,
+            resume_from,
+        )
+        .await?;
+        partial_file.flush().await?;
+        partial_file.sync_all().await?;
+        drop(partial_file);
+
+        if verify
+            && self.calculate_checksum(source).await? != self.calculate_checksum(&partial).await?
+        {
+            let _ = fs::remove_file(&partial).await;
+            let _ = fs::remove_file(&sidecar).await;
+            anyhow::bail!("Verification failed: Checksum mismatch for {target:?}");
+        }
 
-        let result = engine.sync_files(&options, |_| {}, |_| {}).await?;
-        assert_eq!(result.files_copied, 1);
+        fs::rename(&partial, target).await?;
+        let _ = fs::remove_file(&sidecar).await;
+        Ok(())
+    }
+
+    /// Streams `source` into `target` in 64KB chunks, starting at `start_offset` of both. With
+    /// `readback` (a second handle on the target), each chunk is flushed, read back at its
+    /// offset, and compared before the next chunk is read, so a bad write fails at the chunk
+    /// that went wrong.
+    async fn write_chunks<S, W, R>(
+        &self,
+        source: &mut S,
+        target: &mut W,
+        mut readback: Option<&mut R>,
+        on_progress: &mut impl FnMut(u64),
+        start_offset: u64,
+    ) -> Result<()>
+    where
+        S: tokio::io::AsyncRead + Unpin,
+        W: tokio::io::AsyncWrite + Unpin,
+        R: tokio::io::AsyncRead + tokio::io::AsyncSeek + Unpin,
+    {
+        let mut buffer = [0u8; 64 * 1024]; // 64KB chunks
+        let mut offset = start_offset;
+
+        loop {
+            let n = source.read(&mut buffer).await?;
+            if n == 0 {
+                break;
+            }
+            offset = self
+                .write_chunk(target, readback.as_deref_mut(), &buffer[..n], offset)
+                .await?;
+            on_progress(n as u64);
+        }
 
         Ok(())
     }
 
-    #[cfg(unix)]
-    #[tokio::test]
-    async fn copy_file_chunked_rejects_target_symlink() -> Result<()> {
-        let source_dir = TempDir::new()?;
-        let target_dir = TempDir::new()?;
-        let outside_dir = TempDir::new()?;
+    /// Throttles and writes one chunk that starts at `offset` of `target`. With `readback`, the
+    /// chunk is flushed, read back and compared before returning. Returns the offset after it.
+    async fn write_chunk<W, R>(
+        &self,
+        target: &mut W,
+        readback: Option<&mut R>,
+        chunk: &[u8],
+        offset: u64,
+    ) -> Result<u64>
+    where
+        W: tokio::io::AsyncWrite + Unpin,
+        R: tokio::io::AsyncRead + tokio::io::AsyncSeek + Unpin,
+    {
+        use tokio::io::{AsyncSeekExt, AsyncWriteExt};
 
-        let source = source_dir.path().join("test.txt");
-        let target = target_dir.path().join("test.txt");
-        let outside = outside_dir.path().join("outside.txt");
-        fs::write(&source, b"safe content").await?;
-        fs::write(&outside, b"original outside").await?;
-        std::os::unix::fs::symlink(&outside, &target)?;
+        if chunk.is_empty() {
+            return Ok(offset);
+        }
+        self.throttle(chunk.len() as u64).await;
+        target.write_all(chunk).await?;
+
+        if let Some(reader) = readback {
+            target.flush().await?;
+            reader.seek(std::io::SeekFrom::Start(offset)).await?;
+            let mut read_back = vec![0u8; chunk.len()];
+            let matches = match reader.read_exact(&mut read_back).await {
+                Ok(_) => read_back == chunk,
+                Err(error) if error.kind() == std::io::ErrorKind::UnexpectedEof => false,
+                Err(error) => return Err(error.into()),
+            };
+            if !matches {
+                anyhow::bail!(
+                    "Verification failed: Read-after-write mismatch in chunk at offset {offset} ({} bytes)",
+                    chunk.len()
+                );
+            }
+        }
 
-        let engine = SyncEngine::new(
-            source_dir.path().to_path_buf(),
-            target_dir.path().to_path_buf(),
-        );
-        let result = engine
-            .copy_file_chunked(&source, &target, &SyncOptions::default(), |_| {})
-            .await;
+        Ok(offset + chunk.len() as u64)
+    }
 
-        let err = result.expect_err("target symlink should be rejected");
-        assert!(format!("{:#}", err).contains("Refusing to write through symlink"));
-        assert_eq!(fs::read(&outside).await?, b"original outside");
+    /// Clones `source` to `target` with `clonefile(2)` when both are on the same volume. APFS
+    /// shares the data blocks until either copy is written, and the clone is a single metadata
+    /// operation, so there are no chunks to report or cancel between. An existing `target` file
+    /// is removed first since `clonefile` will not replace it. Returns `Ok(false)` when the
+    /// volumes differ or the filesystem cannot clone, so the caller falls back to `write_chunks`.
+    #[cfg(target_os = "macos")]
+    async fn clone_file_same_volume(source: &Path, target: &Path) -> Result<bool> {
+        use std::os::unix::ffi::OsStrExt;
+        use std::os::unix::fs::MetadataExt;
+
+        // `<sys/clonefile.h>`: clone a symlink itself rather than what it points to.
+        const CLONE_NOFOLLOW: u32 = 0x0001;
+
+        let Some(parent) = target.parent() else {
+            return Ok(false);
+        };
+        if fs::metadata(source).await?.dev() != fs::metadata(parent).await?.dev() {
+            return Ok(false);
+        }
+        match fs::symlink_metadata(target).await {
+            Ok(meta) if meta.is_file() => fs::remove_file(target).await?,
+            Ok(_) => return Ok(false),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {}
+            Err(error) => return Err(error.into()),
+        }
+
+        let source_path = std::ffi::CString::new(source.as_os_str().as_bytes())?;
+        let target_path = std::ffi::CString::new(target.as_os_str().as_bytes())?;
+        let result = tokio::task::spawn_blocking(move || {
+            // SAFETY: both pointers come from `CString`s owned by this closure.
+            let rc = unsafe {
+                nix::libc::clonefile(source_path.as_ptr(), target_path.as_ptr(), CLONE_NOFOLLOW)
+            };
+            if rc == 0 {
+                Ok(())
+            } else {
+                Err(std::io::Error::last_os_error())
+            }
+        })
+        .await?;
+
+        match result {
+            Ok(()) => Ok(true),
+            Err(error)
+                if matches!(
+                    error.raw_os_error(),
+                    Some(nix::libc::ENOTSUP | nix::libc::EXDEV)
+                ) =>
+            {
+                Ok(false)
+            }
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    /// Read handle for `read_after_write`. On macOS the page cache is bypassed (`F_NOCACHE`) so
+    /// reads come from the device where possible rather than from the data just written.
+    async fn open_uncached_readback(target: &Path) -> Result<fs::File> {
+        let file = fs::File::open(target).await?;
+        #[cfg(target_os = "macos")]
+        {
+            use std::os::fd::AsRawFd;
+            // SAFETY: fcntl on a file descriptor owned by `file`, which outlives the call.
+            unsafe {
+                nix::libc::fcntl(file.as_raw_fd(), nix::libc::F_NOCACHE, 1);
+            }
+        }
+        Ok(file)
+    }
+
+    /// Writes `source` gzip-compressed to `target` and returns the stored (compressed) size.
+    /// Each 64KB source chunk is compressed in memory and written through `write_chunk`, so
+    /// throttling and `read_after_write` apply to the stored bytes chunk by chunk, and progress
+    /// is reported in source bytes. Verification compares the source against the decompressed
+    /// copy.
+    async fn copy_file_compressed(
+        &self,
+        source: &Path,
+        target: &Path,
+        options: &SyncOptions,
+        verify: bool,
+        mut on_progress: impl FnMut(u64),
+    ) -> Result<u64> {
+        use std::io::Write;
+        use tokio::io::AsyncWriteExt;
+
+        reject_symlink_destination_under_root(&self.target, target).await?;
+
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+
+        reject_symlink_destination_under_root(&self.target, target).await?;
+        Self::unlink_shared_target(target).await?;
+
+        let mut source_file = fs::File::open(source).await?;
+        let mut target_options = fs::OpenOptions::new();
+        target_options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        target_options.custom_flags(nix::fcntl::OFlag::O_NOFOLLOW.bits());
+        let mut target_file = target_options.open(target).await?;
+        let mut readback = if options.read_after_write {
+            Some(Self::open_uncached_readback(target).await?)
+        } else {
+            None
+        };
+
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        let mut buffer = [0u8; 64 * 1024];
+        let mut offset = 0u64;
+        loop {
+            let n = source_file.read(&mut buffer).await?;
+            if n == 0 {
+                break;
+            }
+            encoder.write_all(&buffer[..n])?;
+            let compressed = std::mem::take(encoder.get_mut());
+            offset = self
+                .write_chunk(&mut target_file, readback.as_mut(), &compressed, offset)
+                .await?;
+            on_progress(n as u64);
+        }
+        let trailer = encoder.finish()?;
+        self.write_chunk(&mut target_file, readback.as_mut(), &trailer, offset)
+            .await?;
+        target_file.flush().await?;
+        drop(target_file);
+
+        if options.preserve_permissions {
+            let meta = fs::metadata(source).await?;
+            let perms = meta.permissions();
+            fs::set_permissions(target, perms).await?;
+        }
+
+        if options.preserve_times {
+            let meta = fs::metadata(source).await?;
+            let modified = meta.modified()?;
+            filetime::set_file_mtime(target, filetime::FileTime::from_system_time(modified))?;
+        }
+
+        if verify {
+            let source_hash = self.calculate_checksum(source).await?;
+            let target_hash = self.calculate_gzip_checksum(target).await?;
+
+            if source_hash != target_hash {
+                let _ = fs::remove_file(target).await;
+                anyhow::bail!("Verification failed: Checksum mismatch for {target:?}");
+            }
+        }
+
+        Ok(fs::metadata(target).await?.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sync_engine::types::{DriftCategory, DEFAULT_SCAN_MEMORY_WARN_BYTES};
+    use std::os::unix::fs::PermissionsExt;
+    use std::sync::{Arc, Mutex as StdMutex};
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_basic_sync() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+
+        let source = source_dir.path().join("test.txt");
+        fs::write(&source, b"hello world").await?;
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let options = SyncOptions::default();
+
+        let dry_run = engine.dry_run(&options).await?;
+        assert_eq!(dry_run.files_to_copy, 1);
+
+        let result = engine.sync_files(&options, |_| {}, |_| {}).await?;
+        assert_eq!(result.files_copied, 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_sync_aborts_once_when_source_root_disappears() -> Result<()> {
+        let base_dir = TempDir::new()?;
+        let source_root = base_dir.path().join("card");
+        let target_dir = TempDir::new()?;
+        fs::create_dir_all(&source_root).await?;
+        for name in ["a.txt", "b.txt", "c.txt", "d.txt"] {
+            fs::write(source_root.join(name), b"payload").await?;
+        }
+
+        let engine = SyncEngine::new(source_root.clone(), target_dir.path().to_path_buf());
+        let options = SyncOptions::default();
+        let dry_run = engine.dry_run(&options).await?;
+        assert_eq!(dry_run.files_to_copy, 4);
+
+        let source_for_callback = source_root.clone();
+        let failed_entries = Arc::new(StdMutex::new(0usize));
+        let failed_entries_for_callback = failed_entries.clone();
+        let result = engine
+            .sync_files_from_dry_run(
+                &dry_run,
+                &options,
+                |_| {},
+                move |entry| match entry.status {
+                    SyncFileStatus::Copied => {
+                        let _ = std::fs::remove_dir_all(&source_for_callback);
+                    }
+                    SyncFileStatus::Failed => {
+                        *failed_entries_for_callback.lock().unwrap() += 1;
+                    }
+                },
+            )
+            .await?;
+
+        assert_eq!(result.files_copied, 1);
+        assert_eq!(result.errors.len(), 1);
+        assert!(matches!(
+            result.errors[0].kind,
+            crate::sync_engine::types::SyncErrorKind::SourceDisconnected
+        ));
+        assert!(result.source_disconnected());
+        assert_eq!(*failed_entries.lock().unwrap(), 1);
+        assert!(target_dir.path().join("a.txt").exists());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_compress_stores_allowlisted_files_as_gzip() -> Result<()> {
+        use std::io::Read;
+
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+        let text = "line of log output\n".repeat(512);
+        fs::write(source_dir.path().join("app.log"), &text).await?;
+        fs::write(source_dir.path().join("photo.jpg"), b"jpeg-bytes").await?;
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let options = SyncOptions {
+            verify_after_copy: true,
+            compress: true,
+            compress_extensions: vec![".LOG".to_string()],
+            ..SyncOptions::default()
+        };
+        let result = engine.sync_files(&options, |_| {}, |_| {}).await?;
+
+        assert!(result.errors.is_empty());
+        assert_eq!(result.files_copied, 2);
+        assert_eq!(result.compressed_files, 1);
+        assert_eq!(result.compressed_original_bytes, text.len() as u64);
+        assert!(result.compressed_stored_bytes < result.compressed_original_bytes);
+        assert!(!target_dir.path().join("app.log").exists());
+        assert!(target_dir.path().join("photo.jpg").exists());
+
+        let stored = std::fs::File::open(target_dir.path().join("app.log.gz"))?;
+        let mut decoded = String::new();
+        flate2::read::GzDecoder::new(stored).read_to_string(&mut decoded)?;
+        assert_eq!(decoded, text);
+
+        let rerun = engine.dry_run(&options).await?;
+        assert_eq!(rerun.files_to_copy, 0);
+        assert!(engine
+            .find_orphan_files_with_options(&options, None)
+            .await?
+            .is_empty());
+
+        // Without compression (or outside the allowlist) a `.gz` beside its source is an orphan.
+        let orphans = engine.find_orphan_files(&[]).await?;
+        assert_eq!(orphans.len(), 1);
+        assert_eq!(orphans[0].path, PathBuf::from("app.log.gz"));
+        let other_allowlist = SyncOptions {
+            compress_extensions: vec!["txt".to_string()],
+            ..options.clone()
+        };
+        let orphans = engine
+            .find_orphan_files_with_options(&other_allowlist, None)
+            .await?;
+        assert_eq!(orphans.len(), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_compress_reports_source_gz_sharing_a_compressed_name() -> Result<()> {
+        use std::io::Read;
+
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+        fs::write(source_dir.path().join("app.log"), "plain log\n".repeat(64)).await?;
+        fs::write(source_dir.path().join("app.log.gz"), b"already gzipped").await?;
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let options = SyncOptions {
+            compress: true,
+            compress_extensions: vec!["log".to_string()],
+            ..SyncOptions::default()
+        };
+        let dry_run = engine.dry_run(&options).await?;
+        assert_eq!(dry_run.layout_collisions, vec![PathBuf::from("app.log.gz")]);
+        assert_eq!(dry_run.files_to_copy, 1);
+
+        let result = engine.sync_files(&options, |_| {}, |_| {}).await?;
+        assert_eq!(result.files_copied, 1);
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].path, PathBuf::from("app.log.gz"));
+        assert!(matches!(
+            result.errors[0].kind,
+            crate::sync_engine::types::SyncErrorKind::LayoutCollision
+        ));
+
+        let stored = std::fs::File::open(target_dir.path().join("app.log.gz"))?;
+        let mut decoded = String::new();
+        flate2::read::GzDecoder::new(stored).read_to_string(&mut decoded)?;
+        assert_eq!(decoded, "plain log\n".repeat(64));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_compressed_copy_reports_progress_per_chunk_with_read_after_write() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+        let source = source_dir.path().join("big.log");
+        let text = "repeated log line with some entropy 0123456789\n".repeat(8 * 1024);
+        fs::write(&source, &text).await?;
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let options = SyncOptions {
+            compress: true,
+            compress_extensions: vec!["log".to_string()],
+            read_after_write: true,
+            ..SyncOptions::default()
+        };
+        let target = target_dir.path().join("big.log.gz");
+        let mut chunks = Vec::new();
+        let stored = engine
+            .copy_file_compressed(&source, &target, &options, true, |n| chunks.push(n))
+            .await?;
+
+        assert!(chunks.len() > 1);
+        assert_eq!(chunks.iter().sum::<u64>(), text.len() as u64);
+        assert_eq!(stored, fs::metadata(&target).await?.len());
+        assert_eq!(
+            engine.calculate_gzip_checksum(&target).await?,
+            engine.calculate_checksum(&source).await?
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_compress_routes_newer_or_conflicting_targets_to_conflict_review() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+        let source_log = source_dir.path().join("app.log");
+        fs::write(&source_log, "source line\n".repeat(64)).await?;
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let options = SyncOptions {
+            compress: true,
+            compress_extensions: vec!["log".to_string()],
+            flag_equal_mtime_content_diff: true,
+            ..SyncOptions::default()
+        };
+        engine.sync_files(&options, |_| {}, |_| {}).await?;
+
+        // Rewrite the stored copy with other content and a newer mtime.
+        let stored = target_dir.path().join("app.log.gz");
+        let write_stored = |content: &str, modified: SystemTime| -> Result<()> {
+            use std::io::Write;
+            let mut encoder = flate2::write::GzEncoder::new(
+                std::fs::File::create(&stored)?,
+                flate2::Compression::default(),
+            );
+            encoder.write_all(content.as_bytes())?;
+            encoder.finish()?;
+            filetime::set_file_mtime(&stored, filetime::FileTime::from_system_time(modified))?;
+            Ok(())
+        };
+        let source_modified = std::fs::metadata(&source_log)?.modified()?;
+        write_stored(
+            "edited on target\n",
+            source_modified + std::time::Duration::from_secs(120),
+        )?;
+
+        let (dry_run, conflicts) = engine
+            .compare_dirs_internal(&options, None, |_| {}, |_, _| {})
+            .await?;
+        assert!(dry_run.diffs.is_empty());
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(
+            conflicts[0].target_path.file_name(),
+            Some(OsStr::new("app.log.gz"))
+        );
+
+        // Same mtime, different content: flagged rather than overwritten.
+        write_stored("edited on target\n", source_modified)?;
+        let (dry_run, conflicts) = engine
+            .compare_dirs_internal(&options, None, |_| {}, |_, _| {})
+            .await?;
+        assert!(dry_run.diffs.is_empty());
+        assert_eq!(conflicts.len(), 1);
+
+        // A newer target with the same content is already in sync.
+        write_stored(
+            &"source line\n".repeat(64),
+            source_modified + std::time::Duration::from_secs(120),
+        )?;
+        let (dry_run, conflicts) = engine
+            .compare_dirs_internal(&options, None, |_| {}, |_, _| {})
+            .await?;
+        assert!(dry_run.diffs.is_empty());
+        assert!(conflicts.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_source_equals_target_errors_before_scan() -> Result<()> {
+        let dir = TempDir::new()?;
+        fs::write(dir.path().join("keep.txt"), b"keep").await?;
+        let link = TempDir::new()?;
+        let link_path = link.path().join("alias");
+        std::os::unix::fs::symlink(dir.path(), &link_path)?;
+
+        for target in [dir.path().to_path_buf(), link_path] {
+            let engine = SyncEngine::new(dir.path().to_path_buf(), target);
+            let error = engine
+                .dry_run(&SyncOptions::default())
+                .await
+                .expect_err("identical source and target must fail");
+            assert!(error.to_string().contains("SourceEqualsTarget"));
+            let error = engine
+                .find_orphan_files(&[])
+                .await
+                .expect_err("orphan scan must fail too");
+            assert!(error.to_string().contains("SourceEqualsTarget"));
+        }
+        assert!(dir.path().join("keep.txt").exists());
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_read_after_write_fails_at_the_mismatched_chunk() -> Result<()> {
+        const CHUNK: usize = 64 * 1024;
+        let engine = SyncEngine::new(PathBuf::from("/source"), PathBuf::from("/target"));
+        let payload: Vec<u8> = (0..CHUNK * 3).map(|index| (index % 251) as u8).collect();
+
+        // The read-back view of the target has one flipped byte in the second chunk.
+        let mut corrupted = payload.clone();
+        corrupted[CHUNK + 10] ^= 0xFF;
+        let mut source = std::io::Cursor::new(payload.clone());
+        let mut written: Vec<u8> = Vec::new();
+        let mut readback = std::io::Cursor::new(corrupted);
+        let mut progressed = 0u64;
+
+        let error = engine
+            .write_chunks(
+                &mut source,
+                &mut written,
+                Some(&mut readback),
+                &mut |bytes| progressed += bytes,
+                0,
+            )
+            .await
+            .expect_err("mismatched chunk should fail the copy");
+        assert!(error.to_string().contains("Verification failed"), "{error}");
+        assert!(
+            error.to_string().contains(&format!("offset {CHUNK}")),
+            "{error}"
+        );
+        assert_eq!(written.len(), CHUNK * 2, "third chunk must not be written");
+        assert_eq!(progressed, CHUNK as u64);
+
+        // A healthy target passes with the option enabled.
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+        fs::write(source_dir.path().join("clip.bin"), &payload).await?;
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let options = SyncOptions {
+            read_after_write: true,
+            ..SyncOptions::default()
+        };
+        let result = engine.sync_files(&options, |_| {}, |_| {}).await?;
+        assert!(result.errors.is_empty());
+        assert_eq!(fs::read(target_dir.path().join("clip.bin")).await?, payload);
+        Ok(())
+    }
+
+    #[cfg(target_os = "macos")]
+    #[tokio::test]
+    async fn copy_file_chunked_clones_files_on_the_same_volume() -> Result<()> {
+        let root = TempDir::new()?;
+        let source_dir = root.path().join("source");
+        let target_dir = root.path().join("target");
+        fs::create_dir_all(&source_dir).await?;
+        fs::create_dir_all(&target_dir).await?;
+
+        let source = source_dir.join("clip.bin");
+        let payload: Vec<u8> = (0..(3 * 1024 * 1024 + 17))
+            .map(|i| (i % 251) as u8)
+            .collect();
+        fs::write(&source, &payload).await?;
+
+        let existing = target_dir.join("existing.bin");
+        fs::write(&existing, b"old").await?;
+        assert!(SyncEngine::clone_file_same_volume(&source, &existing).await?);
+        assert_eq!(fs::read(&existing).await?, payload);
+
+        let engine = SyncEngine::new(source_dir.clone(), target_dir.clone());
+        let target = target_dir.join("nested/clip.bin");
+        let mut reported = 0u64;
+        engine
+            .copy_file_chunked(&source, &target, &SyncOptions::default(), true, |n| {
+                reported += n
+            })
+            .await?;
+        assert_eq!(fs::read(&target).await?, payload);
+        assert_eq!(reported, payload.len() as u64);
+
+        // Writing to the clone leaves the source untouched.
+        fs::write(&target, b"changed").await?;
+        assert_eq!(fs::read(&source).await?, payload);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn resumable_copy_continues_interrupted_partial_without_recopying_prefix() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+        let source = source_dir.path().join("disk.img");
+        let target = target_dir.path().join("disk.img");
+        let payload: Vec<u8> = (0..(4 * 1024 * 1024 + 17))
+            .map(|i| (i % 251) as u8)
+            .collect();
+        fs::write(&source, &payload).await?;
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let options = SyncOptions {
+            resume_partial_copies: true,
+            ..SyncOptions::default()
+        };
+
+        // Drop the first copy partway through, as a cancelled run would.
+        let interrupt = tokio::sync::Notify::new();
+        let mut first_run_bytes = 0u64;
+        let mut on_first_progress = |n| {
+            first_run_bytes += n;
+            if first_run_bytes >= 1024 * 1024 {
+                interrupt.notify_one();
+            }
+        };
+        let first_run =
+            engine.copy_file_resumable(&source, &target, &options, true, &mut on_first_progress);
+        tokio::select! {
+            result = first_run => panic!("copy should have been interrupted: {result:?}"),
+            _ = interrupt.notified() => {}
+        }
+
+        let partial = partial_copy_path(&target);
+        let prefix_len = fs::metadata(&partial).await?.len();
+        assert!(prefix_len > 0 && prefix_len < payload.len() as u64);
+        assert!(partial_copy_sidecar_path(&target).exists());
+        assert!(!target.exists());
+
+        let mut second_run_bytes = 0u64;
+        engine
+            .copy_file_resumable(&source, &target, &options, true, &mut |n| {
+                second_run_bytes += n
+            })
+            .await?;
+
+        assert_eq!(fs::read(&target).await?, payload);
+        assert_eq!(second_run_bytes, payload.len() as u64 - prefix_len);
+        assert!(!partial.exists());
+        assert!(!partial_copy_sidecar_path(&target).exists());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn copy_file_chunked_rejects_target_symlink() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+        let outside_dir = TempDir::new()?;
+
+        let source = source_dir.path().join("test.txt");
+        let target = target_dir.path().join("test.txt");
+        let outside = outside_dir.path().join("outside.txt");
+        fs::write(&source, b"safe content").await?;
+        fs::write(&outside, b"original outside").await?;
+        std::os::unix::fs::symlink(&outside, &target)?;
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let result = engine
+            .copy_file_chunked(&source, &target, &SyncOptions::default(), false, |_| {})
+            .await;
+
+        let err = result.expect_err("target symlink should be rejected");
+        assert!(format!("{:#}", err).contains("Refusing to write through symlink"));
+        assert_eq!(fs::read(&outside).await?, b"original outside");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_prepare_sync_plan_with_progress_reports_scan_phases() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+
+        fs::write(source_dir.path().join("a.txt"), b"hello").await?;
+        fs::write(target_dir.path().join("a.txt"), b"older").await?;
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let options = SyncOptions::default();
+        let phases = Arc::new(StdMutex::new(Vec::new()));
+        let phases_for_progress = phases.clone();
+
+        let _ = engine
+            .prepare_sync_plan_with_progress(
+                &options,
+                move |progress| {
+                    phases_for_progress
+                        .lock()
+                        .expect("phase collection lock should succeed")
+                        .push(progress.phase);
+                },
+                |_, _| {},
+            )
+            .await?;
+
+        let recorded = phases
+            .lock()
+            .expect("phase collection lock should succeed")
+            .clone();
+
+        assert!(recorded.contains(&SyncProgressPhase::ScanningSource));
+        assert!(recorded.contains(&SyncProgressPhase::ScanningTarget));
+        assert!(recorded.contains(&SyncProgressPhase::Comparing));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_prepare_sync_plan_with_progress_and_cancel_stops_mid_scan() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+
+        for index in 0..64 {
+            fs::write(
+                source_dir.path().join(format!("file-{index}.txt")),
+                b"payload",
+            )
+            .await?;
+        }
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let options = SyncOptions::default();
+        let cancel_token = CancellationToken::new();
+        let cancel_for_progress = cancel_token.clone();
+
+        let result = engine
+            .prepare_sync_plan_with_progress_and_cancel(
+                &options,
+                cancel_token,
+                move |progress| {
+                    if progress.phase == SyncProgressPhase::ScanningSource
+                        && progress.processed_files > 0
+                    {
+                        cancel_for_progress.cancel();
+                    }
+                },
+                |_, _| {},
+            )
+            .await;
+
+        let error = result.expect_err("planning should cancel while scanning");
+        assert!(format!("{:#}", error).contains("cancelled by user"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_fails_when_source_scan_hits_walkdir_error() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+        let restricted_dir = source_dir.path().join("restricted");
+        let visible_file = source_dir.path().join("visible.txt");
+
+        fs::create_dir(&restricted_dir).await?;
+        fs::write(&visible_file, b"visible").await?;
+        std::fs::set_permissions(&restricted_dir, std::fs::Permissions::from_mode(0o000))?;
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let result = engine.dry_run(&SyncOptions::default()).await;
+
+        std::fs::set_permissions(&restricted_dir, std::fs::Permissions::from_mode(0o755))?;
+
+        let err = result.expect_err("dry run should fail on traversal errors");
+        let message = format!("{:#}", err);
+        assert!(message.contains("Failed to read source directory"));
+        assert!(message.contains("Failed to traverse directory entry"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_target_newer_conflict_is_not_copied() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+
+        let relative = PathBuf::from("media/photo.jpg");
+        let source_file = source_dir.path().join(&relative);
+        let target_file = target_dir.path().join(&relative);
+        fs::create_dir_all(source_file.parent().unwrap()).await?;
+        fs::create_dir_all(target_file.parent().unwrap()).await?;
+
+        fs::write(&source_file, b"source-v1").await?;
+        fs::write(&target_file, b"target-v2").await?;
+
+        let source_time =
+            std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000);
+        let target_time = source_time + std::time::Duration::from_secs(60);
+        filetime::set_file_mtime(
+            &source_file,
+            filetime::FileTime::from_system_time(source_time),
+        )?;
+        filetime::set_file_mtime(
+            &target_file,
+            filetime::FileTime::from_system_time(target_time),
+        )?;
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let options = SyncOptions::default();
+
+        let dry_run = engine.compare_dirs(&options).await?;
+        assert_eq!(dry_run.files_to_copy, 0);
+
+        let conflicts = engine.target_newer_conflicts(&options).await?;
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].path, relative);
+
+        let sync_result = engine.sync_files(&options, |_| {}, |_| {}).await?;
+        assert_eq!(sync_result.files_copied, 0);
+
+        let target_content = fs::read(&target_file).await?;
+        assert_eq!(target_content, b"target-v2");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_collect_skip_reasons_explains_skipped_files() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+        let source_time =
+            std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000);
+        let target_newer_time = source_time + std::time::Duration::from_secs(60);
+
+        fs::create_dir_all(source_dir.path().join("cache")).await?;
+        fs::write(source_dir.path().join("cache/thumb.bin"), b"cache").await?;
+        fs::write(source_dir.path().join("fresh.txt"), b"fresh").await?;
+        for (name, source, target, target_time) in [
+            (
+                "same.txt",
+                b"same-bytes".as_slice(),
+                b"same-bytes".as_slice(),
+                source_time,
+            ),
+            (
+                "photo.jpg",
+                b"source-v1".as_slice(),
+                b"target-edit-v2".as_slice(),
+                target_newer_time,
+            ),
+        ] {
+            let source_file = source_dir.path().join(name);
+            let target_file = target_dir.path().join(name);
+            fs::write(&source_file, source).await?;
+            fs::write(&target_file, target).await?;
+            filetime::set_file_mtime(
+                &source_file,
+                filetime::FileTime::from_system_time(source_time),
+            )?;
+            filetime::set_file_mtime(
+                &target_file,
+                filetime::FileTime::from_system_time(target_time),
+            )?;
+        }
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let mut options = SyncOptions {
+            exclude_patterns: vec!["cache".to_string()],
+            collect_skip_reasons: true,
+            ..SyncOptions::default()
+        };
+
+        let dry_run = engine.dry_run(&options).await?;
+        assert_eq!(dry_run.diffs.len(), 1);
+        assert_eq!(dry_run.diffs[0].path, PathBuf::from("fresh.txt"));
+        assert_eq!(
+            dry_run.skipped,
+            vec![
+                SkippedFile {
+                    path: PathBuf::from("cache"),
+                    reason: SkipReason::Excluded,
+                },
+                SkippedFile {
+                    path: PathBuf::from("photo.jpg"),
+                    reason: SkipReason::TargetNewer,
+                },
+                SkippedFile {
+                    path: PathBuf::from("same.txt"),
+                    reason: SkipReason::UpToDate,
+                },
+            ]
+        );
+        assert!(!dry_run.skipped_truncated);
+
+        options.collect_skip_reasons = false;
+        assert!(engine.dry_run(&options).await?.skipped.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_source_subdirs_limit_compare_copy_and_orphans() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+        for relative in [
+            "DCIM/100CANON/IMG_0001.JPG",
+            "DCIM/.thumbs/IMG_0001.THM",
+            "CLIPS/C0001.MP4",
+            "MISC/notes.txt",
+            "root.txt",
+        ] {
+            let path = source_dir.path().join(relative);
+            fs::create_dir_all(path.parent().unwrap()).await?;
+            fs::write(&path, relative.as_bytes()).await?;
+        }
+        for relative in ["DCIM/stale.jpg", "MISC/old.txt"] {
+            let path = target_dir.path().join(relative);
+            fs::create_dir_all(path.parent().unwrap()).await?;
+            fs::write(&path, b"old").await?;
+        }
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let options = SyncOptions {
+            exclude_patterns: vec![".thumbs".to_string()],
+            source_subdirs: Some(vec!["DCIM".to_string(), "CLIPS".to_string()]),
+            ..SyncOptions::default()
+        };
+
+        let dry_run = engine.dry_run(&options).await?;
+        let diff_paths: Vec<PathBuf> = dry_run.diffs.iter().map(|d| d.path.clone()).collect();
+        assert_eq!(
+            diff_paths,
+            vec![
+                PathBuf::from("CLIPS/C0001.MP4"),
+                PathBuf::from("DCIM/100CANON/IMG_0001.JPG"),
+            ]
+        );
+
+        let orphans = engine
+            .find_orphan_files_with_layout(&[], options.source_subdirs.as_deref(), None, None)
+            .await?;
+        let orphan_paths: Vec<PathBuf> = orphans.iter().map(|o| o.path.clone()).collect();
+        assert_eq!(orphan_paths, vec![PathBuf::from("DCIM/stale.jpg")]);
+
+        let result = engine.sync_files(&options, |_| {}, |_| {}).await?;
+        assert!(result.errors.is_empty());
+        assert_eq!(result.files_copied, 2);
+        assert!(target_dir.path().join("CLIPS/C0001.MP4").exists());
+        assert!(!target_dir.path().join("DCIM/.thumbs").exists());
+        assert!(!target_dir.path().join("MISC/notes.txt").exists());
+        assert!(!target_dir.path().join("root.txt").exists());
+
+        let invalid = SyncOptions {
+            source_subdirs: Some(vec!["DCIM/100CANON".to_string()]),
+            ..SyncOptions::default()
+        };
+        assert!(engine.dry_run(&invalid).await.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_estimate_compare_peak_bytes_scales_with_entries_and_paths() {
+        assert_eq!(SyncEngine::estimate_compare_peak_bytes(0, 80), 0);
+        assert_eq!(
+            SyncEngine::estimate_compare_peak_bytes(2_000_000, 60),
+            2 * SyncEngine::estimate_compare_peak_bytes(1_000_000, 60)
+        );
+        assert!(
+            SyncEngine::estimate_compare_peak_bytes(1_000_000, 120)
+                > SyncEngine::estimate_compare_peak_bytes(1_000_000, 60)
+        );
+        // Every entry keeps at least its path twice per side (metadata list and map key).
+        assert!(SyncEngine::estimate_compare_peak_bytes(1_000_000, 60) >= 1_000_000 * 4 * 60);
+
+        let card = SyncEngine::estimate_compare_peak_bytes(100_000, 60);
+        let archive = SyncEngine::estimate_compare_peak_bytes(20_000_000, 60);
+        assert!(card < DEFAULT_SCAN_MEMORY_WARN_BYTES);
+        assert!(archive > DEFAULT_SCAN_MEMORY_WARN_BYTES);
+        assert_eq!(
+            SyncEngine::estimate_compare_peak_bytes(u64::MAX, 60),
+            u64::MAX
+        );
+    }
+
+    #[tokio::test]
+    async fn test_estimate_scan_memory_counts_entries_after_exclusions() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        fs::create_dir_all(source_dir.path().join("photos")).await?;
+        fs::create_dir_all(source_dir.path().join("node_modules/pkg")).await?;
+        fs::write(source_dir.path().join("photos/a.jpg"), b"a").await?;
+        fs::write(source_dir.path().join("photos/b.jpg"), b"b").await?;
+        fs::write(source_dir.path().join("node_modules/pkg/index.js"), b"js").await?;
+
+        let estimate = SyncEngine::estimate_scan_memory(
+            source_dir.path(),
+            &["node_modules".to_string()],
+            DEFAULT_SCAN_MEMORY_WARN_BYTES,
+        )
+        .await?;
+        // "photos", "photos/a.jpg", "photos/b.jpg"
+        assert_eq!(estimate.entry_count, 3);
+        assert_eq!(estimate.average_path_bytes, (6 + 12 + 12) / 3);
+        assert_eq!(
+            estimate.estimated_peak_bytes,
+            SyncEngine::estimate_compare_peak_bytes(3, 10)
+        );
+        assert!(!estimate.exceeds_threshold);
+        assert_eq!(estimate.warning, None);
+
+        let tight = SyncEngine::estimate_scan_memory(source_dir.path(), &[], 1).await?;
+        assert_eq!(tight.entry_count, 6);
+        assert!(tight.exceeds_threshold);
+        assert!(tight.warning.is_some());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_trusted_snapshot_skips_rehashing_unchanged_files() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+        let same_time =
+            std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000);
+        // Same size and mtime but different bytes: only a hash can tell them apart.
+        for (name, source, target) in [
+            ("kept.txt", b"source-a", b"target-a"),
+            ("edited.txt", b"source-b", b"target-b"),
+        ] {
+            let source_file = source_dir.path().join(name);
+            let target_file = target_dir.path().join(name);
+            fs::write(&source_file, source).await?;
+            fs::write(&target_file, target).await?;
+            for file in [&source_file, &target_file] {
+                filetime::set_file_mtime(file, filetime::FileTime::from_system_time(same_time))?;
+            }
+        }
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let options = SyncOptions {
+            checksum_mode: true,
+            ..SyncOptions::default()
+        };
+        assert_eq!(engine.compare_dirs(&options).await?.files_modified, 2);
+
+        let mut snapshot = engine.capture_sync_snapshot(&[]).await?;
+        assert_eq!(snapshot.entries.len(), 2);
+        // Pretend edited.txt had a different mtime when the snapshot was taken.
+        snapshot
+            .entries
+            .get_mut(Path::new("edited.txt"))
+            .expect("snapshot should cover edited.txt")
+            .modified_secs = Some(1);
+
+        let trusted = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        )
+        .with_trusted_snapshot(Arc::new(snapshot));
+        let result = trusted.compare_dirs(&options).await?;
+        assert_eq!(result.files_modified, 1);
+        assert_eq!(result.diffs.len(), 1);
+        assert_eq!(result.diffs[0].path, PathBuf::from("edited.txt"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_target_newer_same_content_is_not_conflict() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+
+        let relative = PathBuf::from("media/photo.jpg");
+        let source_file = source_dir.path().join(&relative);
+        let target_file = target_dir.path().join(&relative);
+        fs::create_dir_all(source_file.parent().unwrap()).await?;
+        fs::create_dir_all(target_file.parent().unwrap()).await?;
+
+        fs::write(&source_file, b"same-content").await?;
+        fs::write(&target_file, b"same-content").await?;
+
+        let source_time =
+            std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000);
+        let target_time = source_time + std::time::Duration::from_secs(60);
+        filetime::set_file_mtime(
+            &source_file,
+            filetime::FileTime::from_system_time(source_time),
+        )?;
+        filetime::set_file_mtime(
+            &target_file,
+            filetime::FileTime::from_system_time(target_time),
+        )?;
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let options = SyncOptions::default();
+
+        let dry_run = engine.compare_dirs(&options).await?;
+        assert_eq!(dry_run.files_to_copy, 0);
+
+        let conflicts = engine.target_newer_conflicts(&options).await?;
+        assert_eq!(conflicts.len(), 0);
+
+        let sync_result = engine.sync_files(&options, |_| {}, |_| {}).await?;
+        assert_eq!(sync_result.files_copied, 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_equal_mtime_content_diff_is_flagged_as_conflict_when_enabled() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+
+        let source_file = source_dir.path().join("edit.txt");
+        let target_file = target_dir.path().join("edit.txt");
+        fs::write(&source_file, b"restored-v1").await?;
+        fs::write(&target_file, b"edited-v002").await?;
+        let same_time =
+            std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000);
+        for file in [&source_file, &target_file] {
+            filetime::set_file_mtime(file, filetime::FileTime::from_system_time(same_time))?;
+        }
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+
+        let default_options = SyncOptions {
+            checksum_mode: true,
+            ..SyncOptions::default()
+        };
+        assert_eq!(
+            engine.compare_dirs(&default_options).await?.files_modified,
+            1
+        );
+        assert!(engine
+            .target_newer_conflicts(&default_options)
+            .await?
+            .is_empty());
+
+        let options = SyncOptions {
+            checksum_mode: true,
+            flag_equal_mtime_content_diff: true,
+            ..SyncOptions::default()
+        };
+        assert_eq!(engine.compare_dirs(&options).await?.files_to_copy, 0);
+        let conflicts = engine.target_newer_conflicts(&options).await?;
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].path, PathBuf::from("edit.txt"));
+
+        let sync_result = engine.sync_files(&options, |_| {}, |_| {}).await?;
+        assert_eq!(sync_result.files_copied, 0);
+        assert_eq!(fs::read(&target_file).await?, b"edited-v002");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_source_newer_same_content_is_not_modified_without_checksum_mode() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+
+        let relative = PathBuf::from("media/photo.jpg");
+        let source_file = source_dir.path().join(&relative);
+        let target_file = target_dir.path().join(&relative);
+        fs::create_dir_all(source_file.parent().unwrap()).await?;
+        fs::create_dir_all(target_file.parent().unwrap()).await?;
+
+        fs::write(&source_file, b"same-content").await?;
+        fs::write(&target_file, b"same-content").await?;
+
+        let target_time =
+            std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000);
+        let source_time = target_time + std::time::Duration::from_secs(60);
+        filetime::set_file_mtime(
+            &source_file,
+            filetime::FileTime::from_system_time(source_time),
+        )?;
+        filetime::set_file_mtime(
+            &target_file,
+            filetime::FileTime::from_system_time(target_time),
+        )?;
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let options = SyncOptions {
+            checksum_mode: false,
+            ..SyncOptions::default()
+        };
+
+        let dry_run = engine.compare_dirs(&options).await?;
+        assert_eq!(dry_run.files_to_copy, 0);
+        assert!(dry_run.diffs.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_source_newer_different_content_same_size_is_modified_without_checksum_mode(
+    ) -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+
+        let relative = PathBuf::from("media/photo.jpg");
+        let source_file = source_dir.path().join(&relative);
+        let target_file = target_dir.path().join(&relative);
+        fs::create_dir_all(source_file.parent().unwrap()).await?;
+        fs::create_dir_all(target_file.parent().unwrap()).await?;
+
+        fs::write(&source_file, b"source-v2").await?;
+        fs::write(&target_file, b"target-v1").await?;
+
+        let target_time =
+            std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000);
+        let source_time = target_time + std::time::Duration::from_secs(60);
+        filetime::set_file_mtime(
+            &source_file,
+            filetime::FileTime::from_system_time(source_time),
+        )?;
+        filetime::set_file_mtime(
+            &target_file,
+            filetime::FileTime::from_system_time(target_time),
+        )?;
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let options = SyncOptions {
+            checksum_mode: false,
+            ..SyncOptions::default()
+        };
+
+        let dry_run = engine.compare_dirs(&options).await?;
+        assert_eq!(dry_run.files_to_copy, 1);
+        assert_eq!(dry_run.files_modified, 1);
+        assert_eq!(dry_run.diffs[0].kind, FileDiffKind::Modified);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_subsecond_modified_time_drift_is_ignored_without_checksum_mode() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+
+        let relative = PathBuf::from("media/clip.mp4");
+        let source_file = source_dir.path().join(&relative);
+        let target_file = target_dir.path().join(&relative);
+        fs::create_dir_all(source_file.parent().unwrap()).await?;
+        fs::create_dir_all(target_file.parent().unwrap()).await?;
+
+        fs::write(&source_file, b"same-content").await?;
+        fs::write(&target_file, b"same-content").await?;
+
+        let base_secs = 1_700_000_000i64;
+        filetime::set_file_mtime(
+            &source_file,
+            filetime::FileTime::from_unix_time(base_secs, 900_000_000),
+        )?;
+        filetime::set_file_mtime(
+            &target_file,
+            filetime::FileTime::from_unix_time(base_secs, 100_000_000),
+        )?;
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let options = SyncOptions {
+            checksum_mode: false,
+            ..SyncOptions::default()
+        };
+
+        let dry_run = engine.compare_dirs(&options).await?;
+        assert_eq!(dry_run.files_to_copy, 0);
+        assert!(dry_run.diffs.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_target_newer_different_size_is_conflict() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+
+        let relative = PathBuf::from("media/photo.jpg");
+        let source_file = source_dir.path().join(&relative);
+        let target_file = target_dir.path().join(&relative);
+        fs::create_dir_all(source_file.parent().unwrap()).await?;
+        fs::create_dir_all(target_file.parent().unwrap()).await?;
+
+        fs::write(&source_file, b"source-v1").await?;
+        fs::write(&target_file, b"target-v2-with-larger-content").await?;
+
+        let source_time =
+            std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000);
+        let target_time = source_time + std::time::Duration::from_secs(60);
+        filetime::set_file_mtime(
+            &source_file,
+            filetime::FileTime::from_system_time(source_time),
+        )?;
+        filetime::set_file_mtime(
+            &target_file,
+            filetime::FileTime::from_system_time(target_time),
+        )?;
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let options = SyncOptions::default();
+
+        let dry_run = engine.compare_dirs(&options).await?;
+        assert_eq!(dry_run.files_to_copy, 0);
+
+        let conflicts = engine.target_newer_conflicts(&options).await?;
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].path, relative);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_modified_window_filters_by_each_bound() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+
+        let base_secs = 1_700_000_000i64;
+        let old_file = source_dir.path().join("old.txt");
+        let mid_file = source_dir.path().join("mid.txt");
+        let new_file = source_dir.path().join("new.txt");
+        fs::write(&old_file, b"old").await?;
+        fs::write(&mid_file, b"mid").await?;
+        fs::write(&new_file, b"new").await?;
+        filetime::set_file_mtime(&old_file, filetime::FileTime::from_unix_time(base_secs, 0))?;
+        filetime::set_file_mtime(
+            &mid_file,
+            filetime::FileTime::from_unix_time(base_secs + 3_600, 0),
+        )?;
+        filetime::set_file_mtime(
+            &new_file,
+            filetime::FileTime::from_unix_time(base_secs + 7_200, 0),
+        )?;
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let diff_paths = |dry_run: &DryRunResult| {
+            let mut paths: Vec<String> = dry_run
+                .diffs
+                .iter()
+                .map(|diff| diff.path.to_string_lossy().to_string())
+                .collect();
+            paths.sort();
+            paths
+        };
+
+        let after_only = SyncOptions {
+            modified_after: Some((base_secs + 3_600) * 1000),
+            ..SyncOptions::default()
+        };
+        let dry_run = engine.dry_run(&after_only).await?;
+        assert_eq!(diff_paths(&dry_run), vec!["mid.txt", "new.txt"]);
+        assert_eq!(dry_run.total_files, 2);
+
+        let before_only = SyncOptions {
+            modified_before: Some((base_secs + 3_600) * 1000),
+            ..SyncOptions::default()
+        };
+        let dry_run = engine.dry_run(&before_only).await?;
+        assert_eq!(diff_paths(&dry_run), vec!["mid.txt", "old.txt"]);
+
+        let window = SyncOptions {
+            modified_after: Some((base_secs + 1) * 1000),
+            modified_before: Some((base_secs + 7_199) * 1000),
+            ..SyncOptions::default()
+        };
+        let result = engine.sync_files(&window, |_| {}, |_| {}).await?;
+        assert_eq!(result.files_copied, 1);
+        assert!(target_dir.path().join("mid.txt").exists());
+        assert!(!target_dir.path().join("old.txt").exists());
+        assert!(!target_dir.path().join("new.txt").exists());
+
+        // Source files outside the window still exist, so their target copies are not orphans.
+        fs::write(target_dir.path().join("old.txt"), b"old").await?;
+        let orphans = engine.find_orphan_files(&[]).await?;
+        assert!(orphans.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_update_existing_only_skips_new_files() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+        fs::write(source_dir.path().join("shared.txt"), b"updated content").await?;
+        fs::write(source_dir.path().join("fresh.txt"), b"new file").await?;
+        fs::write(target_dir.path().join("shared.txt"), b"old").await?;
+        let target_time =
+            std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000);
+        filetime::set_file_mtime(
+            target_dir.path().join("shared.txt"),
+            filetime::FileTime::from_system_time(target_time),
+        )?;
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let options = SyncOptions {
+            update_existing_only: true,
+            ..SyncOptions::default()
+        };
+
+        let dry_run = engine.dry_run(&options).await?;
+        assert_eq!(dry_run.files_to_copy, 1);
+        assert_eq!(dry_run.diffs[0].path, PathBuf::from("shared.txt"));
+        assert_eq!(dry_run.diffs[0].kind, FileDiffKind::Modified);
+
+        let result = engine.sync_files(&options, |_| {}, |_| {}).await?;
+        assert_eq!(result.files_copied, 1);
+        assert_eq!(
+            fs::read(target_dir.path().join("shared.txt")).await?,
+            b"updated content"
+        );
+        assert!(!target_dir.path().join("fresh.txt").exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_sampling_is_deterministic_and_respects_rate() {
+        let options = SyncOptions {
+            verify_after_copy: true,
+            verify_sample_rate: 0.25,
+            verify_sample_seed: 42,
+            ..SyncOptions::default()
+        };
+        let paths: Vec<PathBuf> = (0..4000)
+            .map(|i| PathBuf::from(format!("dir{}/file{i}.bin", i % 7)))
+            .collect();
+
+        let first: Vec<bool> = paths
+            .iter()
+            .map(|path| SyncEngine::should_verify_copy(&options, path, 1024))
+            .collect();
+        let second: Vec<bool> = paths
+            .iter()
+            .map(|path| SyncEngine::should_verify_copy(&options, path, 1024))
+            .collect();
+        assert_eq!(first, second);
+
+        let selected = first.iter().filter(|verify| **verify).count();
+        assert!(
+            (800..=1200).contains(&selected),
+            "expected roughly 25% of 4000, got {selected}"
+        );
+
+        let reseeded = SyncOptions {
+            verify_sample_seed: 7,
+            ..options.clone()
+        };
+        let reseeded_selection: Vec<bool> = paths
+            .iter()
+            .map(|path| SyncEngine::should_verify_copy(&reseeded, path, 1024))
+            .collect();
+        assert_ne!(first, reseeded_selection);
+
+        let none = SyncOptions {
+            verify_sample_rate: 0.0,
+            ..options.clone()
+        };
+        let all = SyncOptions {
+            verify_sample_rate: 1.0,
+            ..options.clone()
+        };
+        assert!(paths
+            .iter()
+            .all(|path| !SyncEngine::should_verify_copy(&none, path, 1024)));
+        assert!(paths
+            .iter()
+            .all(|path| SyncEngine::should_verify_copy(&all, path, 1024)));
+        assert!(SyncEngine::should_verify_copy(
+            &none,
+            &paths[0],
+            VERIFY_ALWAYS_ABOVE_BYTES
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_verify_sampling_reports_verified_and_sampled_out_counts() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+        for i in 0..20 {
+            fs::write(source_dir.path().join(format!("file{i}.txt")), b"content").await?;
+        }
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let options = SyncOptions {
+            verify_after_copy: true,
+            verify_sample_rate: 0.5,
+            ..SyncOptions::default()
+        };
+        let expected_verified = (0..20)
+            .filter(|i| {
+                SyncEngine::should_verify_copy(&options, Path::new(&format!("file{i}.txt")), 7)
+            })
+            .count() as u64;
+
+        let result = engine.sync_files(&options, |_| {}, |_| {}).await?;
+        assert_eq!(result.files_copied, 20);
+        assert_eq!(result.files_verified, expected_verified);
+        assert_eq!(result.files_verify_sampled_out, 20 - expected_verified);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_sync_and_verify_detects_corrupted_existing_file() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+        let source_existing = source_dir.path().join("existing.txt");
+        let target_existing = target_dir.path().join("existing.txt");
+        fs::write(&source_existing, b"original").await?;
+        fs::write(&target_existing, b"bitr0t!!").await?;
+        let modified = filetime::FileTime::from_unix_time(1_700_000_000, 0);
+        filetime::set_file_mtime(&source_existing, modified)?;
+        filetime::set_file_mtime(&target_existing, modified)?;
+        fs::write(source_dir.path().join("new.txt"), b"new").await?;
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let options = SyncOptions {
+            checksum_mode: false,
+            verify_after_copy: true,
+            verify_existing_after_sync: true,
+            ..SyncOptions::default()
+        };
+        let result = engine.sync_and_verify(&options, |_| {}, |_| {}).await?;
+
+        assert_eq!(result.sync.files_copied, 1);
+        assert_eq!(result.sync.files_verified, 1);
+        assert!(result.sync.errors.is_empty());
+        assert_eq!(result.existing_verified, 1);
+        assert_eq!(result.existing_errors.len(), 1);
+        assert_eq!(
+            result.existing_errors[0].path,
+            PathBuf::from("existing.txt")
+        );
+        assert!(matches!(
+            result.existing_errors[0].kind,
+            SyncErrorKind::VerificationFailed
+        ));
+        assert_eq!(fs::read(&target_existing).await?, b"bitr0t!!");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_should_skip_metadata_ops_only_for_network_targets() {
+        let options = SyncOptions {
+            skip_metadata_on_network: true,
+            ..SyncOptions::default()
+        };
+        assert!(SyncEngine::should_skip_metadata_ops(&options, true));
+        assert!(!SyncEngine::should_skip_metadata_ops(&options, false));
+
+        let disabled = SyncOptions::default();
+        assert!(!SyncEngine::should_skip_metadata_ops(&disabled, true));
+
+        let nothing_to_preserve = SyncOptions {
+            preserve_permissions: false,
+            preserve_times: false,
+            ..options
+        };
+        assert!(!SyncEngine::should_skip_metadata_ops(
+            &nothing_to_preserve,
+            true
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_respect_readonly_target_skips_locked_file() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+        let locked = target_dir.path().join("locked.txt");
+        fs::write(source_dir.path().join("locked.txt"), b"new content").await?;
+        fs::write(source_dir.path().join("open.txt"), b"new content").await?;
+        fs::write(&locked, b"old").await?;
+        fs::write(target_dir.path().join("open.txt"), b"old").await?;
+        fs::set_permissions(&locked, std::fs::Permissions::from_mode(0o444)).await?;
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let options = SyncOptions {
+            respect_readonly_target: true,
+            ..SyncOptions::default()
+        };
+
+        let result = engine.sync_files(&options, |_| {}, |_| {}).await?;
+
+        assert_eq!(result.files_copied, 1);
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].path, PathBuf::from("locked.txt"));
+        assert!(matches!(
+            result.errors[0].kind,
+            crate::sync_engine::types::SyncErrorKind::TargetReadOnly
+        ));
+        assert_eq!(fs::read(&locked).await?, b"old");
+        assert_eq!(
+            fs::read(target_dir.path().join("open.txt")).await?,
+            b"new content"
+        );
+
+        let strict = SyncOptions {
+            stop_on_first_error: true,
+            ..options.clone()
+        };
+        let result = engine.sync_files(&strict, |_| {}, |_| {}).await?;
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.stopped_on_error, Some(PathBuf::from("locked.txt")));
+
+        fs::set_permissions(&locked, std::fs::Permissions::from_mode(0o644)).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_parallel_scan_matches_sequential_scan() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let root = source_dir.path();
+        for dir in ["a/b/c", "node_modules/pkg", ".hidden", ".Trashes", "logs"] {
+            fs::create_dir_all(root.join(dir)).await?;
+        }
+        for (file, content) in [
+            ("top.txt", "top"),
+            ("a/one.txt", "one"),
+            ("a/b/two.txt", "two!"),
+            ("a/b/c/three.txt", "three"),
+            ("node_modules/pkg/index.js", "skip"),
+            (".hidden/secret.txt", "hidden"),
+            (".Trashes/old.txt", "trash"),
+            ("logs/run.log", "log"),
+            ("logs/keep.txt", "keep"),
+        ] {
+            fs::write(root.join(file), content).await?;
+        }
+
+        let engine = SyncEngine::new(root.to_path_buf(), TempDir::new()?.path().to_path_buf());
+        let patterns = vec!["node_modules".to_string(), "*.log".to_string()];
+        let scan = |parallel: bool| {
+            let engine = &engine;
+            let patterns = &patterns;
+            async move {
+                engine
+                    .read_directory(
+                        root,
+                        DryRunPhase::ScanningSource,
+                        patterns,
+                        parallel,
+                        None,
+                        Arc::new(StdMutex::new(|_: DryRunProgress| {})),
+                    )
+                    .await
+            }
+        };
+        let summarize = |files: Vec<FileMetadata>| -> Vec<(PathBuf, u64, bool)> {
+            let mut summary: Vec<_> = files
+                .into_iter()
+                .map(|file| (file.path, file.size, file.is_file))
+                .collect();
+            summary.sort();
+            summary
+        };
+
+        let parallel = scan(true).await?;
+        let parallel_paths: Vec<PathBuf> = parallel.iter().map(|file| file.path.clone()).collect();
+        let mut sorted_paths = parallel_paths.clone();
+        sorted_paths.sort();
+        assert_eq!(parallel_paths, sorted_paths);
+
+        let sequential = summarize(scan(false).await?);
+        let parallel = summarize(parallel);
+        assert_eq!(parallel, sequential);
+        assert!(sequential
+            .iter()
+            .any(|(path, _, _)| path == Path::new(".hidden/secret.txt")));
+        assert!(sequential
+            .iter()
+            .any(|(path, _, _)| path == Path::new("a/b/c/three.txt")));
+        assert!(!sequential.iter().any(|(path, _, _)| {
+            path.starts_with("node_modules")
+                || path.starts_with(".Trashes")
+                || path == Path::new("logs/run.log")
+        }));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_create_target_structure_mirrors_source_dirs_only() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+        let source = source_dir.path();
+        let target = target_dir.path().join("mirror");
+        for dir in ["a/b/c", "empty", "node_modules/pkg", "docs"] {
+            fs::create_dir_all(source.join(dir)).await?;
+        }
+        fs::write(source.join("top.txt"), "top").await?;
+        fs::write(source.join("a/b/two.txt"), "two").await?;
+        fs::write(source.join("docs/readme.md"), "readme").await?;
+
+        let engine = SyncEngine::new(source.to_path_buf(), target.clone());
+        let patterns = vec!["node_modules".to_string()];
+        let created = engine.create_target_structure(&patterns, true).await?;
+        assert_eq!(created, 5);
+
+        let mut entries = Vec::new();
+        for entry in walkdir::WalkDir::new(&target).min_depth(1) {
+            let entry = entry?;
+            assert!(
+                entry.file_type().is_dir(),
+                "unexpected file {:?}",
+                entry.path()
+            );
+            entries.push(entry.path().strip_prefix(&target)?.to_path_buf());
+        }
+        entries.sort();
+        let expected: Vec<PathBuf> = ["a", "a/b", "a/b/c", "docs", "empty"]
+            .iter()
+            .map(PathBuf::from)
+            .collect();
+        assert_eq!(entries, expected);
+
+        assert_eq!(engine.create_target_structure(&patterns, true).await?, 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_max_duration_stops_at_file_boundary_and_reports_remainder() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+        fs::write(source_dir.path().join("a.txt"), b"aaaa").await?;
+        fs::write(source_dir.path().join("b.txt"), b"bbbbbb").await?;
+        fs::write(source_dir.path().join("c.txt"), b"cc").await?;
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let options = SyncOptions {
+            max_duration: Some(std::time::Duration::from_nanos(1)),
+            ..SyncOptions::default()
+        };
+        let result = engine.sync_files(&options, |_| {}, |_| {}).await?;
+
+        // The first file always runs so a re-queued sync makes progress.
+        assert!(result.time_limit_reached);
+        assert_eq!(result.files_copied, 1);
+        assert_eq!(result.bytes_copied, 4);
+        assert_eq!(result.remaining_files, 2);
+        assert_eq!(result.remaining_bytes, 8);
+        assert!(result.errors.is_empty());
+        assert!(target_dir.path().join("a.txt").exists());
+        assert!(!target_dir.path().join("b.txt").exists());
+
+        let result = engine
+            .sync_files(&SyncOptions::default(), |_| {}, |_| {})
+            .await?;
+        assert!(!result.time_limit_reached);
+        assert_eq!(result.files_copied, 2);
+        assert_eq!(result.remaining_files, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_date_tree_layout_renders_local_mtime_and_keeps_file_name() {
+        let layout = TargetLayout::DateTree {
+            pattern: "%Y/%m/%d".to_string(),
+        };
+        let modified = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000);
+        let local: chrono::DateTime<chrono::Local> = modified.into();
+
+        assert_eq!(
+            layout.relative_path_for(Path::new("DCIM/100CANON/IMG_0001.JPG"), modified),
+            PathBuf::from(local.format("%Y/%m/%d").to_string()).join("IMG_0001.JPG")
+        );
+    }
+
+    #[test]
+    fn test_date_tree_layout_rejects_unsafe_or_invalid_patterns() {
+        for pattern in ["", "../%Y", "/%Y/%m", "%Y/../%m", "%Y/%Q"] {
+            let layout = TargetLayout::DateTree {
+                pattern: pattern.to_string(),
+            };
+            assert!(layout.validate().is_err(), "{pattern:?} should be rejected");
+        }
+        assert!(TargetLayout::DateTree {
+            pattern: "%Y/%Y-%m".to_string()
+        }
+        .validate()
+        .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_date_tree_layout_places_files_by_mtime_and_reports_collisions() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+        let modified = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000);
+        for name in ["DCIM/100CANON/IMG_0001.JPG", "DCIM/101CANON/IMG_0001.JPG"] {
+            let path = source_dir.path().join(name);
+            fs::create_dir_all(path.parent().unwrap()).await?;
+            fs::write(&path, name).await?;
+            filetime::set_file_mtime(&path, filetime::FileTime::from_system_time(modified))?;
+        }
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let layout = TargetLayout::DateTree {
+            pattern: "%Y/%m/%d".to_string(),
+        };
+        let options = SyncOptions {
+            target_layout: Some(layout.clone()),
+            ..SyncOptions::default()
+        };
+        let expected = layout.relative_path_for(Path::new("IMG_0001.JPG"), modified);
+
+        let (dry_run, _) = engine
+            .compare_dirs_internal(&options, None, |_| {}, |_, _| {})
+            .await?;
+        assert_eq!(dry_run.diffs.len(), 1);
+        assert_eq!(dry_run.diffs[0].path, expected);
+        assert_eq!(
+            dry_run.diffs[0].source_path.as_deref(),
+            Some(Path::new("DCIM/100CANON/IMG_0001.JPG"))
+        );
+        assert_eq!(
+            dry_run.layout_collisions,
+            vec![PathBuf::from("DCIM/101CANON/IMG_0001.JPG")]
+        );
+
+        let result = engine.sync_files(&options, |_| {}, |_| {}).await?;
+        assert_eq!(result.files_copied, 1);
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(
+            result.errors[0].path,
+            PathBuf::from("DCIM/101CANON/IMG_0001.JPG")
+        );
+        assert!(matches!(
+            result.errors[0].kind,
+            crate::sync_engine::types::SyncErrorKind::LayoutCollision
+        ));
+        assert_eq!(
+            fs::read(target_dir.path().join(&expected)).await?,
+            b"DCIM/100CANON/IMG_0001.JPG"
+        );
+        assert!(!target_dir.path().join("DCIM").exists());
+
+        let orphans = engine
+            .find_orphan_files_with_layout(&[], None, Some(&layout), None)
+            .await?;
+        assert!(orphans.is_empty(), "unexpected orphans: {orphans:?}");
+
+        fs::write(target_dir.path().join("stale.txt"), b"stale").await?;
+        let orphans = engine
+            .find_orphan_files_with_layout(&[], None, Some(&layout), None)
+            .await?;
+        assert_eq!(orphans.len(), 1);
+        assert_eq!(orphans[0].path, PathBuf::from("stale.txt"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_preview_target_layout_maps_sampled_files_and_flags_collisions() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let june = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_717_243_200);
+        let july = june + std::time::Duration::from_secs(30 * 24 * 60 * 60);
+        for (name, modified) in [
+            ("DCIM/100CANON/IMG_0001.CR3", june),
+            ("DCIM/100CANON/IMG_0002.CR3", july),
+            ("DCIM/101CANON/IMG_0001.CR3", june),
+        ] {
+            let path = source_dir.path().join(name);
+            fs::create_dir_all(path.parent().unwrap()).await?;
+            fs::write(&path, name).await?;
+            filetime::set_file_mtime(&path, filetime::FileTime::from_system_time(modified))?;
+        }
+
+        let engine = SyncEngine::new(source_dir.path().to_path_buf(), PathBuf::from("."));
+        let layout = TargetLayout::DateTree {
+            pattern: "%Y/%m/%d".to_string(),
+        };
+        let june_target = layout.relative_path_for(Path::new("IMG_0001.CR3"), june);
+        let july_target = layout.relative_path_for(Path::new("IMG_0002.CR3"), july);
+
+        let preview = engine.preview_target_layout(&layout, &[], 2).await?;
+        assert_eq!(preview.total_files, 3);
+        assert_eq!(
+            preview.mappings,
+            vec![
+                TargetLayoutMapping {
+                    source_path: PathBuf::from("DCIM/100CANON/IMG_0001.CR3"),
+                    target_path: june_target.clone(),
+                    collides: true,
+                },
+                TargetLayoutMapping {
+                    source_path: PathBuf::from("DCIM/100CANON/IMG_0002.CR3"),
+                    target_path: july_target,
+                    collides: false,
+                },
+            ]
+        );
+        assert_eq!(
+            preview.collisions,
+            vec![TargetLayoutCollision {
+                target_path: june_target,
+                source_paths: vec![
+                    PathBuf::from("DCIM/100CANON/IMG_0001.CR3"),
+                    PathBuf::from("DCIM/101CANON/IMG_0001.CR3"),
+                ],
+            }]
+        );
+
+        let invalid = TargetLayout::DateTree {
+            pattern: "../%Y".to_string(),
+        };
+        assert!(engine
+            .preview_target_layout(&invalid, &[], 10)
+            .await
+            .is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_merge_appledouble_applies_sidecar_or_falls_back_to_copy() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+        fs::write(source_dir.path().join("photo.jpg"), b"jpeg").await?;
+        fs::write(
+            source_dir.path().join("._photo.jpg"),
+            appledouble::build_test_sidecar(b"fork-data", ("com.example.tag", b"blue")),
+        )
+        .await?;
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let options = SyncOptions {
+            merge_appledouble: true,
+            ..SyncOptions::default()
+        };
+        let result = engine.sync_files(&options, |_| {}, |_| {}).await?;
+
+        assert!(result.errors.is_empty());
+        assert_eq!(
+            fs::read(target_dir.path().join("photo.jpg")).await?,
+            b"jpeg"
+        );
+        if cfg!(target_os = "macos") {
+            assert_eq!(result.appledouble_merged, 1);
+            assert_eq!(result.files_copied, 1);
+            assert!(!target_dir.path().join("._photo.jpg").exists());
+        } else {
+            // No native xattr support for com.apple.* names: the sidecar is copied as-is.
+            assert_eq!(result.appledouble_merged, 0);
+            assert_eq!(result.files_copied, 2);
+            assert!(target_dir.path().join("._photo.jpg").exists());
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_merge_appledouble_resync_finds_no_diffs_until_data_file_changes() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+        fs::write(source_dir.path().join("photo.jpg"), b"jpeg").await?;
+        fs::write(
+            source_dir.path().join("._photo.jpg"),
+            appledouble::build_test_sidecar(b"fork-data", ("com.example.tag", b"blue")),
+        )
+        .await?;
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let options = SyncOptions {
+            merge_appledouble: true,
+            ..SyncOptions::default()
+        };
+        engine.sync_files(&options, |_| {}, |_| {}).await?;
+
+        let (dry_run, _) = engine
+            .compare_dirs_internal(&options, None, |_| {}, |_, _| {})
+            .await?;
+        assert!(
+            dry_run.diffs.is_empty(),
+            "unexpected diffs: {:?}",
+            dry_run.diffs
+        );
+
+        let edited = source_dir.path().join("photo.jpg");
+        fs::write(&edited, b"edited jpeg").await?;
+        filetime::set_file_mtime(
+            &edited,
+            filetime::FileTime::from_system_time(
+                SystemTime::now() + std::time::Duration::from_secs(60),
+            ),
+        )?;
+        let (dry_run, _) = engine
+            .compare_dirs_internal(&options, None, |_| {}, |_, _| {})
+            .await?;
+        let mut paths: Vec<PathBuf> = dry_run.diffs.iter().map(|diff| diff.path.clone()).collect();
+        paths.sort();
+        if cfg!(target_os = "macos") {
+            // The recopied data file loses its xattrs, so the sidecar is merged again.
+            assert_eq!(
+                paths,
+                vec![PathBuf::from("._photo.jpg"), PathBuf::from("photo.jpg")]
+            );
+        } else {
+            // The sidecar was copied as-is and is compared like any other file.
+            assert_eq!(paths, vec![PathBuf::from("photo.jpg")]);
+        }
+        Ok(())
+    }
+
+    #[cfg(target_os = "macos")]
+    #[tokio::test]
+    async fn test_merge_appledouble_writes_resource_fork_xattr_on_macos() -> Result<()> {
+        use std::ffi::CString;
+        use std::os::unix::ffi::OsStrExt;
+
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+        fs::write(source_dir.path().join("photo.jpg"), b"jpeg").await?;
+        fs::write(
+            source_dir.path().join("._photo.jpg"),
+            appledouble::build_test_sidecar(b"fork-data", ("com.example.tag", b"blue")),
+        )
+        .await?;
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let options = SyncOptions {
+            merge_appledouble: true,
+            ..SyncOptions::default()
+        };
+        engine.sync_files(&options, |_| {}, |_| {}).await?;
+
+        let path = CString::new(target_dir.path().join("photo.jpg").as_os_str().as_bytes())?;
+        let read_xattr = |name: &str| -> Vec<u8> {
+            let name = CString::new(name).unwrap();
+            let mut buffer = vec![0u8; 64];
+            // SAFETY: NUL-terminated strings and a buffer of the advertised length.
+            let len = unsafe {
+                nix::libc::getxattr(
+                    path.as_ptr(),
+                    name.as_ptr(),
+                    buffer.as_mut_ptr().cast(),
+                    buffer.len(),
+                    0,
+                    0,
+                )
+            };
+            assert!(len >= 0, "xattr {name:?} should exist");
+            buffer.truncate(len as usize);
+            buffer
+        };
+        assert_eq!(read_xattr(appledouble::RESOURCE_FORK_XATTR), b"fork-data");
+        assert_eq!(read_xattr("com.example.tag"), b"blue");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_dedup_identical_links_duplicate_content_and_reports_savings() -> Result<()> {
+        use std::os::unix::fs::MetadataExt;
+
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+        fs::write(source_dir.path().join("A001.MOV"), b"same clip bytes").await?;
+        fs::write(
+            source_dir.path().join("copy of A001.MOV"),
+            b"same clip bytes",
+        )
+        .await?;
+        fs::write(source_dir.path().join("A002.MOV"), b"other clip byte").await?;
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let options = SyncOptions {
+            checksum_mode: true,
+            dedup_identical: true,
+            ..SyncOptions::default()
+        };
+        let result = engine.sync_files(&options, |_| {}, |_| {}).await?;
 
+        assert!(result.errors.is_empty());
+        assert_eq!(result.files_copied, 2);
+        assert_eq!(result.dedup_linked, 1);
+        assert_eq!(result.dedup_bytes_saved, b"same clip bytes".len() as u64);
+
+        let first = fs::metadata(target_dir.path().join("A001.MOV")).await?;
+        let second = fs::metadata(target_dir.path().join("copy of A001.MOV")).await?;
+        let other = fs::metadata(target_dir.path().join("A002.MOV")).await?;
+        assert_eq!(first.ino(), second.ino());
+        assert_eq!(first.nlink(), 2);
+        assert_ne!(first.ino(), other.ino());
+        assert_eq!(
+            fs::read(target_dir.path().join("copy of A001.MOV")).await?,
+            b"same clip bytes"
+        );
         Ok(())
     }
 
     #[tokio::test]
-    async fn test_prepare_sync_plan_with_progress_reports_scan_phases() -> Result<()> {
+    async fn test_dedup_identical_resync_of_edited_duplicate_leaves_other_copy_intact() -> Result<()>
+    {
+        use std::os::unix::fs::MetadataExt;
+
         let source_dir = TempDir::new()?;
         let target_dir = TempDir::new()?;
-
-        fs::write(source_dir.path().join("a.txt"), b"hello").await?;
-        fs::write(target_dir.path().join("a.txt"), b"older").await?;
+        fs::write(source_dir.path().join("A001.MOV"), b"same clip bytes").await?;
+        fs::write(
+            source_dir.path().join("copy of A001.MOV"),
+            b"same clip bytes",
+        )
+        .await?;
 
         let engine = SyncEngine::new(
             source_dir.path().to_path_buf(),
             target_dir.path().to_path_buf(),
         );
-        let options = SyncOptions::default();
-        let phases = Arc::new(StdMutex::new(Vec::new()));
-        let phases_for_progress = phases.clone();
-
-        let _ = engine
-            .prepare_sync_plan_with_progress(
-                &options,
-                move |progress| {
-                    phases_for_progress
-                        .lock()
-                        .expect("phase collection lock should succeed")
-                        .push(progress.phase);
-                },
-                |_, _| {},
-            )
-            .await?;
-
-        let recorded = phases
-            .lock()
-            .expect("phase collection lock should succeed")
-            .clone();
+        let options = SyncOptions {
+            checksum_mode: true,
+            dedup_identical: true,
+            ..SyncOptions::default()
+        };
+        let first = engine.sync_files(&options, |_| {}, |_| {}).await?;
+        assert_eq!(first.dedup_linked, 1);
 
-        assert!(recorded.contains(&SyncProgressPhase::ScanningSource));
-        assert!(recorded.contains(&SyncProgressPhase::ScanningTarget));
-        assert!(recorded.contains(&SyncProgressPhase::Comparing));
+        fs::write(
+            source_dir.path().join("copy of A001.MOV"),
+            b"edited clip bytes, now longer",
+        )
+        .await?;
+        let second = engine.sync_files(&options, |_| {}, |_| {}).await?;
 
+        assert!(second.errors.is_empty());
+        assert_eq!(second.files_copied, 1);
+        assert_eq!(
+            fs::read(target_dir.path().join("A001.MOV")).await?,
+            b"same clip bytes"
+        );
+        assert_eq!(
+            fs::read(target_dir.path().join("copy of A001.MOV")).await?,
+            b"edited clip bytes, now longer"
+        );
+        let original = fs::metadata(target_dir.path().join("A001.MOV")).await?;
+        assert_eq!(original.nlink(), 1);
         Ok(())
     }
 
     #[tokio::test]
-    async fn test_prepare_sync_plan_with_progress_and_cancel_stops_mid_scan() -> Result<()> {
+    async fn test_stop_on_first_error_halts_after_first_failed_copy() -> Result<()> {
         let source_dir = TempDir::new()?;
         let target_dir = TempDir::new()?;
-
-        for index in 0..64 {
-            fs::write(
-                source_dir.path().join(format!("file-{index}.txt")),
-                b"payload",
-            )
-            .await?;
+        // A directory where the file should go makes the copy fail.
+        for name in ["a.txt", "b.txt"] {
+            let blocker = target_dir.path().join(name);
+            std::fs::create_dir(&blocker)?;
+            filetime::set_file_mtime(&blocker, filetime::FileTime::from_unix_time(1_000, 0))?;
+            fs::write(source_dir.path().join(name), name).await?;
         }
+        fs::write(source_dir.path().join("c.txt"), "c").await?;
 
         let engine = SyncEngine::new(
             source_dir.path().to_path_buf(),
             target_dir.path().to_path_buf(),
         );
-        let options = SyncOptions::default();
-        let cancel_token = CancellationToken::new();
-        let cancel_for_progress = cancel_token.clone();
-
-        let result = engine
-            .prepare_sync_plan_with_progress_and_cancel(
-                &options,
-                cancel_token,
-                move |progress| {
-                    if progress.phase == SyncProgressPhase::ScanningSource
-                        && progress.processed_files > 0
-                    {
-                        cancel_for_progress.cancel();
-                    }
-                },
-                |_, _| {},
-            )
-            .await;
 
-        let error = result.expect_err("planning should cancel while scanning");
-        assert!(format!("{:#}", error).contains("cancelled by user"));
+        let strict = SyncOptions {
+            stop_on_first_error: true,
+            ..SyncOptions::default()
+        };
+        let result = engine.sync_files(&strict, |_| {}, |_| {}).await?;
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].path, PathBuf::from("a.txt"));
+        assert_eq!(result.stopped_on_error, Some(PathBuf::from("a.txt")));
+        assert_eq!(result.files_copied, 0);
+        assert!(!target_dir.path().join("c.txt").exists());
 
+        let result = engine
+            .sync_files(&SyncOptions::default(), |_| {}, |_| {})
+            .await?;
+        assert_eq!(result.errors.len(), 2);
+        assert_eq!(result.stopped_on_error, None);
+        assert_eq!(result.files_copied, 1);
+        assert_eq!(
+            fs::read_to_string(target_dir.path().join("c.txt")).await?,
+            "c"
+        );
         Ok(())
     }
 
     #[tokio::test]
-    async fn test_dry_run_fails_when_source_scan_hits_walkdir_error() -> Result<()> {
+    async fn test_recopy_file_repairs_corrupted_target() -> Result<()> {
         let source_dir = TempDir::new()?;
         let target_dir = TempDir::new()?;
-        let restricted_dir = source_dir.path().join("restricted");
-        let visible_file = source_dir.path().join("visible.txt");
-
-        fs::create_dir(&restricted_dir).await?;
-        fs::write(&visible_file, b"visible").await?;
-        std::fs::set_permissions(&restricted_dir, std::fs::Permissions::from_mode(0o000))?;
+        fs::create_dir_all(source_dir.path().join("photos")).await?;
+        fs::create_dir_all(target_dir.path().join("photos")).await?;
+        fs::write(source_dir.path().join("photos/img.raw"), b"original pixels").await?;
+        fs::write(target_dir.path().join("photos/img.raw"), b"origXnal pixels").await?;
 
         let engine = SyncEngine::new(
             source_dir.path().to_path_buf(),
             target_dir.path().to_path_buf(),
         );
-        let result = engine.dry_run(&SyncOptions::default()).await;
-
-        std::fs::set_permissions(&restricted_dir, std::fs::Permissions::from_mode(0o755))?;
+        let options = SyncOptions {
+            verify_after_copy: true,
+            ..SyncOptions::default()
+        };
+        let result = engine
+            .recopy_file(Path::new("photos/img.raw"), &options)
+            .await?;
 
-        let err = result.expect_err("dry run should fail on traversal errors");
-        let message = format!("{:#}", err);
-        assert!(message.contains("Failed to read source directory"));
-        assert!(message.contains("Failed to traverse directory entry"));
+        assert!(result.success);
+        assert!(result.verified);
+        assert_eq!(result.bytes_copied, 15);
+        assert_eq!(result.error, None);
+        assert_eq!(
+            fs::read(target_dir.path().join("photos/img.raw")).await?,
+            b"original pixels"
+        );
 
+        let missing = engine
+            .recopy_file(Path::new("photos/missing.raw"), &options)
+            .await;
+        assert!(missing.is_err());
         Ok(())
     }
 
     #[tokio::test]
-    async fn test_target_newer_conflict_is_not_copied() -> Result<()> {
+    async fn test_audit_drift_counts_each_category() -> Result<()> {
         let source_dir = TempDir::new()?;
         let target_dir = TempDir::new()?;
-
-        let relative = PathBuf::from("media/photo.jpg");
-        let source_file = source_dir.path().join(&relative);
-        let target_file = target_dir.path().join(&relative);
-        fs::create_dir_all(source_file.parent().unwrap()).await?;
-        fs::create_dir_all(target_file.parent().unwrap()).await?;
-
-        fs::write(&source_file, b"source-v1").await?;
-        fs::write(&target_file, b"target-v2").await?;
-
-        let source_time =
-            std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000);
-        let target_time = source_time + std::time::Duration::from_secs(60);
-        filetime::set_file_mtime(
-            &source_file,
-            filetime::FileTime::from_system_time(source_time),
-        )?;
+        let source = source_dir.path();
+        let target = target_dir.path();
+        fs::write(source.join("same.txt"), "same").await?;
+        fs::write(target.join("same.txt"), "same").await?;
+        fs::write(source.join("changed.txt"), "changed content").await?;
+        fs::write(target.join("changed.txt"), "old").await?;
         filetime::set_file_mtime(
-            &target_file,
-            filetime::FileTime::from_system_time(target_time),
+            target.join("changed.txt"),
+            filetime::FileTime::from_unix_time(1_600_000_000, 0),
         )?;
-
-        let engine = SyncEngine::new(
-            source_dir.path().to_path_buf(),
-            target_dir.path().to_path_buf(),
+        fs::write(source.join("fresh.txt"), "fresh!").await?;
+        fs::write(target.join("extra.txt"), "leftover").await?;
+
+        let engine = SyncEngine::new(source.to_path_buf(), target.to_path_buf());
+        let report = engine.audit_drift(&SyncOptions::default()).await?;
+
+        assert_eq!(report.new, DriftCategory { count: 1, bytes: 6 });
+        assert_eq!(
+            report.modified,
+            DriftCategory {
+                count: 1,
+                bytes: 15
+            }
         );
-        let options = SyncOptions::default();
-
-        let dry_run = engine.compare_dirs(&options).await?;
-        assert_eq!(dry_run.files_to_copy, 0);
-
-        let conflicts = engine.target_newer_conflicts(&options).await?;
-        assert_eq!(conflicts.len(), 1);
-        assert_eq!(conflicts[0].path, relative);
-
-        let sync_result = engine.sync_files(&options, |_| {}, |_| {}).await?;
-        assert_eq!(sync_result.files_copied, 0);
+        assert_eq!(report.orphan, DriftCategory { count: 1, bytes: 8 });
+        assert_eq!(report.identical, DriftCategory { count: 1, bytes: 4 });
+        assert!(report.is_drifted());
 
-        let target_content = fs::read(&target_file).await?;
-        assert_eq!(target_content, b"target-v2");
+        assert_eq!(fs::read_to_string(target.join("changed.txt")).await?, "old");
+        assert!(!target.join("fresh.txt").exists());
+        assert!(target.join("extra.txt").exists());
         Ok(())
     }
 
     #[tokio::test]
-    async fn test_target_newer_same_content_is_not_conflict() -> Result<()> {
+    async fn test_skip_empty_files_leaves_zero_byte_sources_out() -> Result<()> {
         let source_dir = TempDir::new()?;
         let target_dir = TempDir::new()?;
-
-        let relative = PathBuf::from("media/photo.jpg");
-        let source_file = source_dir.path().join(&relative);
-        let target_file = target_dir.path().join(&relative);
-        fs::create_dir_all(source_file.parent().unwrap()).await?;
-        fs::create_dir_all(target_file.parent().unwrap()).await?;
-
-        fs::write(&source_file, b"same-content").await?;
-        fs::write(&target_file, b"same-content").await?;
-
-        let source_time =
-            std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000);
-        let target_time = source_time + std::time::Duration::from_secs(60);
-        filetime::set_file_mtime(
-            &source_file,
-            filetime::FileTime::from_system_time(source_time),
-        )?;
-        filetime::set_file_mtime(
-            &target_file,
-            filetime::FileTime::from_system_time(target_time),
-        )?;
+        fs::write(source_dir.path().join("placeholder.jpg"), b"").await?;
+        fs::write(target_dir.path().join("placeholder.jpg"), b"real pixels").await?;
+        fs::write(source_dir.path().join("new_empty.txt"), b"").await?;
+        fs::write(source_dir.path().join("photo.jpg"), b"pixels").await?;
 
         let engine = SyncEngine::new(
             source_dir.path().to_path_buf(),
             target_dir.path().to_path_buf(),
         );
-        let options = SyncOptions::default();
-
-        let dry_run = engine.compare_dirs(&options).await?;
-        assert_eq!(dry_run.files_to_copy, 0);
+        let options = SyncOptions {
+            skip_empty_files: true,
+            verify_after_copy: true,
+            ..SyncOptions::default()
+        };
 
-        let conflicts = engine.target_newer_conflicts(&options).await?;
-        assert_eq!(conflicts.len(), 0);
+        let dry_run = engine.dry_run(&options).await?;
+        assert_eq!(dry_run.diffs.len(), 1);
+        assert_eq!(dry_run.diffs[0].path, PathBuf::from("photo.jpg"));
+        assert_eq!(dry_run.empty_files_skipped, 2);
 
-        let sync_result = engine.sync_files(&options, |_| {}, |_| {}).await?;
-        assert_eq!(sync_result.files_copied, 0);
+        let result = engine.sync_files(&options, |_| {}, |_| {}).await?;
+        assert!(result.errors.is_empty());
+        assert_eq!(result.files_copied, 1);
+        assert_eq!(result.empty_files_skipped, 2);
+        assert_eq!(
+            fs::read(target_dir.path().join("placeholder.jpg")).await?,
+            b"real pixels"
+        );
+        assert!(!target_dir.path().join("new_empty.txt").exists());
         Ok(())
     }
 
+    #[cfg(target_os = "macos")]
     #[tokio::test]
-    async fn test_source_newer_same_content_is_not_modified_without_checksum_mode() -> Result<()> {
+    async fn test_preserve_flags_copies_hidden_flag() -> Result<()> {
+        use nix::sys::stat::FileFlag;
+        use std::os::macos::fs::MetadataExt;
+
         let source_dir = TempDir::new()?;
         let target_dir = TempDir::new()?;
-
-        let relative = PathBuf::from("media/photo.jpg");
-        let source_file = source_dir.path().join(&relative);
-        let target_file = target_dir.path().join(&relative);
-        fs::create_dir_all(source_file.parent().unwrap()).await?;
-        fs::create_dir_all(target_file.parent().unwrap()).await?;
-
-        fs::write(&source_file, b"same-content").await?;
-        fs::write(&target_file, b"same-content").await?;
-
-        let target_time =
-            std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000);
-        let source_time = target_time + std::time::Duration::from_secs(60);
-        filetime::set_file_mtime(
-            &source_file,
-            filetime::FileTime::from_system_time(source_time),
-        )?;
-        filetime::set_file_mtime(
-            &target_file,
-            filetime::FileTime::from_system_time(target_time),
-        )?;
+        let hidden = source_dir.path().join("hidden.jpg");
+        fs::write(&hidden, b"pixels").await?;
+        fs::write(source_dir.path().join("plain.jpg"), b"plain").await?;
+        nix::unistd::chflags(&hidden, FileFlag::UF_HIDDEN)?;
 
         let engine = SyncEngine::new(
             source_dir.path().to_path_buf(),
             target_dir.path().to_path_buf(),
         );
         let options = SyncOptions {
-            checksum_mode: false,
+            preserve_flags: true,
             ..SyncOptions::default()
         };
+        let result = engine.sync_files(&options, |_| {}, |_| {}).await?;
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        assert_eq!(result.files_copied, 2);
 
-        let dry_run = engine.compare_dirs(&options).await?;
-        assert_eq!(dry_run.files_to_copy, 0);
-        assert!(dry_run.diffs.is_empty());
-
+        let flags_of = |name: &str| -> Result<FileFlag> {
+            let metadata = std::fs::metadata(target_dir.path().join(name))?;
+            Ok(FileFlag::from_bits_truncate(metadata.st_flags()))
+        };
+        assert!(flags_of("hidden.jpg")?.contains(FileFlag::UF_HIDDEN));
+        assert!(!flags_of("plain.jpg")?.contains(FileFlag::UF_HIDDEN));
         Ok(())
     }
 
     #[tokio::test]
-    async fn test_source_newer_different_content_same_size_is_modified_without_checksum_mode(
-    ) -> Result<()> {
+    async fn test_empty_files_copied_and_verified_without_skip_flag() -> Result<()> {
         let source_dir = TempDir::new()?;
         let target_dir = TempDir::new()?;
-
-        let relative = PathBuf::from("media/photo.jpg");
-        let source_file = source_dir.path().join(&relative);
-        let target_file = target_dir.path().join(&relative);
-        fs::create_dir_all(source_file.parent().unwrap()).await?;
-        fs::create_dir_all(target_file.parent().unwrap()).await?;
-
-        fs::write(&source_file, b"source-v2").await?;
-        fs::write(&target_file, b"target-v1").await?;
-
-        let target_time =
-            std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000);
-        let source_time = target_time + std::time::Duration::from_secs(60);
-        filetime::set_file_mtime(
-            &source_file,
-            filetime::FileTime::from_system_time(source_time),
-        )?;
-        filetime::set_file_mtime(
-            &target_file,
-            filetime::FileTime::from_system_time(target_time),
-        )?;
+        fs::write(source_dir.path().join("placeholder.jpg"), b"").await?;
+        fs::write(target_dir.path().join("placeholder.jpg"), b"real pixels").await?;
+        fs::write(source_dir.path().join("new_empty.txt"), b"").await?;
 
         let engine = SyncEngine::new(
             source_dir.path().to_path_buf(),
             target_dir.path().to_path_buf(),
         );
         let options = SyncOptions {
-            checksum_mode: false,
+            verify_after_copy: true,
             ..SyncOptions::default()
         };
 
-        let dry_run = engine.compare_dirs(&options).await?;
-        assert_eq!(dry_run.files_to_copy, 1);
-        assert_eq!(dry_run.files_modified, 1);
-        assert_eq!(dry_run.diffs[0].kind, FileDiffKind::Modified);
+        let dry_run = engine.dry_run(&options).await?;
+        assert_eq!(dry_run.diffs.len(), 2);
+        assert_eq!(dry_run.empty_files_skipped, 0);
 
+        let result = engine.sync_files(&options, |_| {}, |_| {}).await?;
+        assert!(result.errors.is_empty());
+        assert_eq!(result.files_copied, 2);
+        assert_eq!(result.files_verified, 2);
+        assert_eq!(result.empty_files_skipped, 0);
+        assert!(fs::read(target_dir.path().join("placeholder.jpg"))
+            .await?
+            .is_empty());
+        assert!(target_dir.path().join("new_empty.txt").exists());
         Ok(())
     }
 
     #[tokio::test]
-    async fn test_subsecond_modified_time_drift_is_ignored_without_checksum_mode() -> Result<()> {
+    async fn test_max_target_path_len_flags_and_skips_long_paths() -> Result<()> {
         let source_dir = TempDir::new()?;
         let target_dir = TempDir::new()?;
+        let long_name = format!("{}.txt", "x".repeat(80));
+        let long_relative = PathBuf::from("nested").join(&long_name);
+        fs::create_dir_all(source_dir.path().join("nested")).await?;
+        fs::write(source_dir.path().join(&long_relative), b"long").await?;
+        fs::write(source_dir.path().join("short.txt"), b"short").await?;
 
-        let relative = PathBuf::from("media/clip.mp4");
-        let source_file = source_dir.path().join(&relative);
-        let target_file = target_dir.path().join(&relative);
-        fs::create_dir_all(source_file.parent().unwrap()).await?;
-        fs::create_dir_all(target_file.parent().unwrap()).await?;
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let limit = target_dir.path().as_os_str().len() + 40;
+        let options = SyncOptions {
+            max_target_path_len: Some(limit),
+            ..SyncOptions::default()
+        };
 
-        fs::write(&source_file, b"same-content").await?;
-        fs::write(&target_file, b"same-content").await?;
+        let dry_run = engine.dry_run(&options).await?;
+        assert_eq!(dry_run.diffs.len(), 2);
+        assert_eq!(dry_run.path_too_long.len(), 1);
+        let diagnostic = &dry_run.path_too_long[0];
+        assert_eq!(diagnostic.path, long_relative);
+        assert_eq!(diagnostic.limit, limit);
+        assert_eq!(
+            diagnostic.target_path_len,
+            target_dir.path().join(&long_relative).as_os_str().len()
+        );
+
+        let result = engine.sync_files(&options, |_| {}, |_| {}).await?;
+        assert_eq!(result.files_copied, 1);
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].path, long_relative);
+        assert!(matches!(
+            result.errors[0].kind,
+            crate::sync_engine::types::SyncErrorKind::PathTooLong
+        ));
+        assert!(!target_dir.path().join(&long_relative).exists());
+        assert!(target_dir.path().join("short.txt").exists());
+
+        let strict = SyncOptions {
+            stop_on_first_error: true,
+            ..options.clone()
+        };
+        let result = engine.sync_files(&strict, |_| {}, |_| {}).await?;
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.stopped_on_error, Some(long_relative.clone()));
 
-        let base_secs = 1_700_000_000i64;
-        filetime::set_file_mtime(
-            &source_file,
-            filetime::FileTime::from_unix_time(base_secs, 900_000_000),
-        )?;
-        filetime::set_file_mtime(
-            &target_file,
-            filetime::FileTime::from_unix_time(base_secs, 100_000_000),
-        )?;
+        let unlimited = engine.dry_run(&SyncOptions::default()).await?;
+        assert!(unlimited.path_too_long.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_case_drift_renames_instead_of_copying() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+        fs::create_dir_all(source_dir.path().join("album")).await?;
+        fs::create_dir_all(target_dir.path().join("album")).await?;
+        fs::write(source_dir.path().join("album/photo.jpg"), b"same pixels").await?;
+        fs::write(target_dir.path().join("album/Photo.JPG"), b"same pixels").await?;
 
         let engine = SyncEngine::new(
             source_dir.path().to_path_buf(),
             target_dir.path().to_path_buf(),
         );
         let options = SyncOptions {
-            checksum_mode: false,
+            reconcile_case_drift: true,
             ..SyncOptions::default()
         };
 
-        let dry_run = engine.compare_dirs(&options).await?;
+        let dry_run = engine.dry_run(&options).await?;
+        assert_eq!(dry_run.diffs.len(), 1);
+        assert_eq!(dry_run.diffs[0].kind, FileDiffKind::CaseRename);
+        assert_eq!(dry_run.diffs[0].path, PathBuf::from("album/photo.jpg"));
+        assert_eq!(
+            dry_run.diffs[0].renamed_from,
+            Some(PathBuf::from("album/Photo.JPG"))
+        );
         assert_eq!(dry_run.files_to_copy, 0);
-        assert!(dry_run.diffs.is_empty());
+        assert_eq!(dry_run.bytes_to_copy, 0);
+
+        let result = engine.sync_files(&options, |_| {}, |_| {}).await?;
+        assert!(result.errors.is_empty());
+        assert_eq!(result.files_copied, 0);
+
+        let mut names = Vec::new();
+        let mut entries = fs::read_dir(target_dir.path().join("album")).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            names.push(entry.file_name().to_string_lossy().to_string());
+        }
+        assert_eq!(names, vec!["photo.jpg".to_string()]);
+        assert_eq!(
+            fs::read(target_dir.path().join("album/photo.jpg")).await?,
+            b"same pixels"
+        );
 
         Ok(())
     }
 
     #[tokio::test]
-    async fn test_target_newer_different_size_is_conflict() -> Result<()> {
+    async fn test_reconcile_case_drift_disabled_copies_fresh() -> Result<()> {
         let source_dir = TempDir::new()?;
         let target_dir = TempDir::new()?;
-
-        let relative = PathBuf::from("media/photo.jpg");
-        let source_file = source_dir.path().join(&relative);
-        let target_file = target_dir.path().join(&relative);
-        fs::create_dir_all(source_file.parent().unwrap()).await?;
-        fs::create_dir_all(target_file.parent().unwrap()).await?;
-
-        fs::write(&source_file, b"source-v1").await?;
-        fs::write(&target_file, b"target-v2-with-larger-content").await?;
-
-        let source_time =
-            std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000);
-        let target_time = source_time + std::time::Duration::from_secs(60);
-        filetime::set_file_mtime(
-            &source_file,
-            filetime::FileTime::from_system_time(source_time),
-        )?;
-        filetime::set_file_mtime(
-            &target_file,
-            filetime::FileTime::from_system_time(target_time),
-        )?;
+        fs::write(source_dir.path().join("readme.md"), b"docs").await?;
+        fs::write(target_dir.path().join("README.md"), b"docs").await?;
 
         let engine = SyncEngine::new(
             source_dir.path().to_path_buf(),
             target_dir.path().to_path_buf(),
         );
-        let options = SyncOptions::default();
+        let dry_run = engine.dry_run(&SyncOptions::default()).await?;
+        assert!(dry_run
+            .diffs
+            .iter()
+            .all(|diff| diff.kind != FileDiffKind::CaseRename));
 
-        let dry_run = engine.compare_dirs(&options).await?;
-        assert_eq!(dry_run.files_to_copy, 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_directory_fingerprint_matches_identical_trees() -> Result<()> {
+        let left = TempDir::new()?;
+        let right = TempDir::new()?;
+        let mtime = filetime::FileTime::from_unix_time(1_700_000_000, 0);
+        for root in [left.path(), right.path()] {
+            fs::create_dir_all(root.join("nested")).await?;
+            fs::write(root.join("a.txt"), b"alpha").await?;
+            fs::write(root.join("nested/b.txt"), b"bravo").await?;
+            filetime::set_file_mtime(root.join("a.txt"), mtime)?;
+            filetime::set_file_mtime(root.join("nested/b.txt"), mtime)?;
+        }
+
+        let fingerprint = |root: &Path, deep: bool| {
+            let engine = SyncEngine::new(root.to_path_buf(), PathBuf::from("."));
+            async move { engine.directory_fingerprint(&[], deep).await }
+        };
+
+        let left_meta = fingerprint(left.path(), false).await?;
+        assert_eq!(left_meta, fingerprint(right.path(), false).await?);
+        assert_eq!(left_meta.file_count, 2);
+        assert_eq!(left_meta.total_bytes, 10);
+        assert_eq!(
+            fingerprint(left.path(), true).await?,
+            fingerprint(right.path(), true).await?
+        );
+
+        // Same size and mtime but different bytes: only deep mode notices.
+        fs::write(right.path().join("nested/b.txt"), b"BRAVO").await?;
+        filetime::set_file_mtime(right.path().join("nested/b.txt"), mtime)?;
+        assert_eq!(
+            left_meta.fingerprint,
+            fingerprint(right.path(), false).await?.fingerprint
+        );
+        assert_ne!(
+            fingerprint(left.path(), true).await?.fingerprint,
+            fingerprint(right.path(), true).await?.fingerprint
+        );
+
+        fs::write(right.path().join("a.txt"), b"alpha!").await?;
+        filetime::set_file_mtime(right.path().join("a.txt"), mtime)?;
+        assert_ne!(
+            left_meta.fingerprint,
+            fingerprint(right.path(), false).await?.fingerprint
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_deep_fingerprint_reports_verifying_progress() -> Result<()> {
+        let root = TempDir::new()?;
+        fs::create_dir_all(root.path().join("nested")).await?;
+        for index in 0..5 {
+            fs::write(
+                root.path().join(format!("nested/file{index}.bin")),
+                vec![index as u8; 4096],
+            )
+            .await?;
+        }
+        let engine = SyncEngine::new(root.path().to_path_buf(), PathBuf::from("."));
+
+        let updates = StdMutex::new(Vec::new());
+        engine
+            .directory_fingerprint_with_progress(&[], true, |progress| {
+                updates.lock().unwrap().push(progress)
+            })
+            .await?;
+        let updates = updates.into_inner().unwrap();
+
+        assert!(updates.len() >= 2, "expected start and finish updates");
+        assert!(updates
+            .iter()
+            .all(|update| update.phase == SyncProgressPhase::Verifying));
+        assert!(updates.windows(2).all(|pair| {
+            pair[0].processed_files <= pair[1].processed_files
+                && pair[0].processed_bytes <= pair[1].processed_bytes
+        }));
+        assert_eq!(updates[0].processed_files, 0);
+        let last = updates.last().unwrap();
+        assert_eq!((last.processed_files, last.total_files), (5, 5));
+        assert_eq!(last.processed_bytes, 5 * 4096);
+        assert_eq!(last.total_bytes, 5 * 4096);
+
+        // Metadata mode reads no file contents, so there is nothing to report.
+        let metadata_updates = StdMutex::new(0usize);
+        engine
+            .directory_fingerprint_with_progress(&[], false, |_| {
+                *metadata_updates.lock().unwrap() += 1
+            })
+            .await?;
+        assert_eq!(metadata_updates.into_inner().unwrap(), 0);
 
-        let conflicts = engine.target_newer_conflicts(&options).await?;
-        assert_eq!(conflicts.len(), 1);
-        assert_eq!(conflicts[0].path, relative);
         Ok(())
     }
 
@@ -1847,6 +6579,33 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_exclusion_brace_alternatives_match_at_any_depth() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+
+        let nested = source_dir.path().join("photos/2024");
+        fs::create_dir_all(&nested).await?;
+        fs::write(source_dir.path().join("top.a"), b"a").await?;
+        fs::write(nested.join("deep.b"), b"b").await?;
+        fs::write(nested.join("keep.c"), b"c").await?;
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let mut options = SyncOptions::default();
+        options.exclude_patterns = vec!["*.{a,b}".to_string()];
+
+        let dry_run = engine.dry_run(&options).await?;
+        assert_eq!(dry_run.files_to_copy, 1);
+
+        options.exclude_patterns = vec!["*.{a,b".to_string()];
+        let err = engine.dry_run(&options).await.unwrap_err();
+        assert!(format!("{:#}", err).contains("Unclosed '{'"));
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_exclusion_validation_limits() -> Result<()> {
         let source_dir = TempDir::new()?;
@@ -1968,6 +6727,130 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_find_orphan_files_refuses_empty_source_by_default() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+        fs::write(target_dir.path().join("a.txt"), b"a").await?;
+        fs::write(target_dir.path().join("b.txt"), b"b").await?;
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let err = engine
+            .find_orphan_files(&[])
+            .await
+            .expect_err("empty source must not list the whole target as orphans");
+        assert!(err
+            .to_string()
+            .starts_with(crate::error_codes::ERR_SUSPICIOUS_EMPTY_SOURCE));
+
+        let orphans = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        )
+        .with_allow_empty_source(true)
+        .find_orphan_files(&[])
+        .await?;
+        assert_eq!(orphans.len(), 2);
+
+        fs::write(source_dir.path().join("a.txt"), b"a").await?;
+        let below_fraction = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        )
+        .with_min_source_fraction(0.75)
+        .find_orphan_files(&[])
+        .await;
+        assert!(below_fraction.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_collapse_orphan_subtrees_reports_orphaned_dir_once() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+        fs::create_dir_all(source_dir.path().join("kept")).await?;
+        fs::write(source_dir.path().join("kept/a.txt"), b"a").await?;
+        fs::create_dir_all(target_dir.path().join("kept")).await?;
+        fs::write(target_dir.path().join("kept/a.txt"), b"a").await?;
+        fs::write(target_dir.path().join("kept/extra.txt"), b"extra").await?;
+        fs::create_dir_all(target_dir.path().join("stale/sub")).await?;
+        fs::write(target_dir.path().join("stale/a.txt"), b"1234").await?;
+        fs::write(target_dir.path().join("stale/sub/b.txt"), b"123456").await?;
+        fs::write(target_dir.path().join("stale/sub/c.txt"), b"12").await?;
+        fs::write(target_dir.path().join("stale-x.txt"), b"xyz").await?;
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let orphans = engine.find_orphan_files(&[]).await?;
+        assert_eq!(orphans.len(), 7);
+
+        let collapsed: Vec<(PathBuf, u64, bool)> = SyncEngine::collapse_orphan_subtrees(orphans)
+            .into_iter()
+            .map(|orphan| (orphan.path, orphan.size, orphan.is_dir))
+            .collect();
+        assert_eq!(
+            collapsed,
+            vec![
+                (PathBuf::from("kept/extra.txt"), 5, false),
+                (PathBuf::from("stale"), 12, true),
+                (PathBuf::from("stale-x.txt"), 3, false),
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_preview_mirror_deletions_skips_excluded_target_files() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+        fs::write(source_dir.path().join("kept.txt"), b"kept").await?;
+        fs::write(source_dir.path().join("empty.txt"), b"").await?;
+        fs::write(target_dir.path().join("kept.txt"), b"kept").await?;
+        fs::write(target_dir.path().join("empty.txt"), b"").await?;
+        fs::write(target_dir.path().join("scratch.tmp"), b"excluded").await?;
+        fs::create_dir_all(target_dir.path().join("cache")).await?;
+        fs::write(target_dir.path().join("cache/blob.bin"), b"excluded").await?;
+        fs::create_dir_all(target_dir.path().join("stale")).await?;
+        fs::write(target_dir.path().join("stale/old.txt"), b"1234").await?;
+        fs::write(target_dir.path().join("gone.txt"), b"12").await?;
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let options = SyncOptions {
+            exclude_patterns: vec!["*.tmp".to_string(), "cache".to_string()],
+            skip_empty_files: true,
+            ..SyncOptions::default()
+        };
+        let deletions = engine.preview_mirror_deletions(&options, None).await?;
+
+        let paths: Vec<PathBuf> = deletions.iter().map(|entry| entry.path.clone()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                PathBuf::from("gone.txt"),
+                PathBuf::from("stale"),
+                PathBuf::from("stale/old.txt"),
+            ]
+        );
+        let collapsed: Vec<(PathBuf, u64)> = SyncEngine::collapse_orphan_subtrees(deletions)
+            .into_iter()
+            .map(|entry| (entry.path, entry.size))
+            .collect();
+        assert_eq!(
+            collapsed,
+            vec![(PathBuf::from("gone.txt"), 2), (PathBuf::from("stale"), 4)]
+        );
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_find_orphan_files_ignores_root_metadata_dirs() -> Result<()> {
         let source_dir = TempDir::new()?;
@@ -2005,6 +6888,96 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_find_orphan_files_ignores_partial_copies() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+
+        fs::write(source_dir.path().join("disk.img"), b"image").await?;
+        fs::write(target_dir.path().join("orphan.txt"), b"orphan").await?;
+        let target = target_dir.path().join("disk.img");
+        fs::write(partial_copy_path(&target), b"ima").await?;
+        fs::write(partial_copy_sidecar_path(&target), b"{}").await?;
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let orphans = engine.find_orphan_files(&[]).await?;
+        let paths: Vec<&Path> = orphans.iter().map(|orphan| orphan.path.as_path()).collect();
+        assert_eq!(paths, vec![Path::new("orphan.txt")]);
+
+        let report = engine.audit_drift(&SyncOptions::default()).await?;
+        assert_eq!(report.orphan.count, 1);
+        assert_eq!(report.new.count, 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_find_orphan_files_reports_partial_copies_of_removed_sources() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+
+        fs::write(source_dir.path().join("kept.img"), b"image").await?;
+        for name in ["kept.img", "removed.img"] {
+            let target = target_dir.path().join(name);
+            fs::write(partial_copy_path(&target), b"ima").await?;
+            fs::write(partial_copy_sidecar_path(&target), b"{}").await?;
+        }
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let orphans = engine.find_orphan_files(&[]).await?;
+        let paths: Vec<&Path> = orphans.iter().map(|orphan| orphan.path.as_path()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                Path::new(".removed.img.syncwatcher-partial"),
+                Path::new(".removed.img.syncwatcher-partial.json"),
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn resumable_copy_discards_partial_that_fails_verification() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+        let source = source_dir.path().join("disk.img");
+        let target = target_dir.path().join("disk.img");
+        let payload = vec![7u8; 256 * 1024];
+        fs::write(&source, &payload).await?;
+
+        // A resumable prefix whose bytes no longer match the source (e.g. bad media).
+        let header = PartialCopyHeader::from_metadata(&std::fs::metadata(&source)?);
+        fs::write(partial_copy_path(&target), vec![0u8; 64 * 1024]).await?;
+        fs::write(
+            partial_copy_sidecar_path(&target),
+            serde_json::to_vec(&header)?,
+        )
+        .await?;
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let options = SyncOptions {
+            resume_partial_copies: true,
+            ..SyncOptions::default()
+        };
+        let error = engine
+            .copy_file_resumable(&source, &target, &options, true, &mut |_| {})
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("Verification failed"), "{error}");
+        assert!(!target.exists());
+        assert!(!partial_copy_path(&target).exists());
+        assert!(!partial_copy_sidecar_path(&target).exists());
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_delete_orphan_paths() -> Result<()> {
         let source_dir = TempDir::new()?;
@@ -2073,4 +7046,99 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_delete_orphan_paths_emits_deleting_progress() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+        fs::create_dir_all(target_dir.path().join("stale/nested")).await?;
+        fs::write(target_dir.path().join("stale/nested/old.txt"), b"old").await?;
+        fs::write(target_dir.path().join("a.txt"), b"a").await?;
+        fs::write(target_dir.path().join("b.txt"), b"b").await?;
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let updates = Arc::new(StdMutex::new(Vec::new()));
+        let updates_clone = updates.clone();
+        let result = engine
+            .delete_orphan_paths_with_progress(
+                &[
+                    PathBuf::from("a.txt"),
+                    PathBuf::from("b.txt"),
+                    PathBuf::from("stale"),
+                    PathBuf::from("stale/nested/old.txt"),
+                ],
+                false,
+                move |progress| updates_clone.lock().unwrap().push(progress),
+            )
+            .await?;
+        assert_eq!(result.deleted_count, 5);
+
+        let updates = updates.lock().unwrap();
+        assert!(updates
+            .iter()
+            .all(|progress| progress.phase == SyncProgressPhase::Deleting));
+        // One update per top-level entry (nested paths are covered by `stale`), plus a final one.
+        assert_eq!(updates.len(), 4);
+        assert!(updates.iter().all(|progress| progress.total_files == 3));
+        let processed: Vec<u64> = updates
+            .iter()
+            .map(|progress| progress.processed_files)
+            .collect();
+        assert_eq!(processed, vec![0, 1, 2, 3]);
+        assert!(updates[..3]
+            .iter()
+            .all(|progress| progress.current_file.is_some()));
+        assert_eq!(updates[3].current_file, None);
+        Ok(())
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    #[tokio::test]
+    async fn test_delete_orphan_paths_with_trash_falls_back_when_unsupported() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+        fs::write(target_dir.path().join("orphan.txt"), b"orphan").await?;
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let result = engine
+            .delete_orphan_paths_with_trash(&[PathBuf::from("orphan.txt")], true)
+            .await?;
+
+        assert_eq!(result.trashed_count, 0);
+        assert_eq!(result.deleted_files_count, 1);
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.warnings[0].contains("orphan.txt"));
+        assert!(!target_dir.path().join("orphan.txt").exists());
+
+        Ok(())
+    }
+
+    #[cfg(target_os = "macos")]
+    #[tokio::test]
+    async fn test_delete_orphan_paths_with_trash_moves_to_trash() -> Result<()> {
+        let source_dir = TempDir::new()?;
+        let target_dir = TempDir::new()?;
+        fs::write(target_dir.path().join("orphan.txt"), b"orphan").await?;
+
+        let engine = SyncEngine::new(
+            source_dir.path().to_path_buf(),
+            target_dir.path().to_path_buf(),
+        );
+        let result = engine
+            .delete_orphan_paths_with_trash(&[PathBuf::from("orphan.txt")], true)
+            .await?;
+
+        assert_eq!(result.trashed_count, 1);
+        assert_eq!(result.deleted_files_count, 1);
+        assert!(result.warnings.is_empty());
+        assert!(!target_dir.path().join("orphan.txt").exists());
+
+        Ok(())
+    }
 }
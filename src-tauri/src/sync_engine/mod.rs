@@ -1,9 +1,14 @@
+pub mod appledouble;
+pub mod bandwidth;
+pub mod delta;
 pub mod engine;
 pub mod types;
 
+pub use bandwidth::BandwidthLimiter;
 pub use engine::SyncEngine;
 pub use types::{
-    ConflictFileSnapshot, DeleteOrphanFailure, DeleteOrphanResult, DryRunResult, FileDiff,
-    FileDiffKind, FileMetadata, OrphanFile, SyncFileEntry, SyncFileStatus, SyncOptions, SyncResult,
+    ConflictFileSnapshot, DeleteOrphanFailure, DeleteOrphanResult, DirectoryFingerprint,
+    DryRunResult, FileDiff, FileDiffKind, FileMetadata, OrphanFile, SyncAndVerifyResult,
+    SyncFileEntry, SyncFileStatus, SyncOptions, SyncResult, SyncSnapshot, TargetLayout,
     TargetNewerConflictCandidate, TargetPreflightInfo, TargetPreflightKind,
 };
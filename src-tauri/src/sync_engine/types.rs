@@ -1,10 +1,13 @@
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
+use std::time::Duration;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum FileDiffKind {
     New,
     Modified,
+    /// Target already holds the same content under a name that differs only in letter case.
+    CaseRename,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -22,6 +25,13 @@ pub struct FileDiff {
     pub target_size: Option<u64>,
     pub checksum_source: Option<String>,
     pub checksum_target: Option<String>,
+    /// Existing target-relative path for `CaseRename` diffs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub renamed_from: Option<PathBuf>,
+    /// Source-relative path when `target_layout` placed the file somewhere else; `path` is
+    /// then the target-relative path.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_path: Option<PathBuf>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -41,6 +51,231 @@ pub struct SyncOptions {
     pub preserve_times: bool,
     pub verify_after_copy: bool,
     pub exclude_patterns: Vec<String>,
+    /// Only source files modified at or after this unix timestamp (ms) are considered.
+    #[serde(default)]
+    pub modified_after: Option<i64>,
+    /// Only source files modified at or before this unix timestamp (ms) are considered.
+    #[serde(default)]
+    pub modified_before: Option<i64>,
+    /// Store allowlisted files gzip-compressed on the target with a `.gz` suffix.
+    #[serde(default)]
+    pub compress: bool,
+    /// Extensions (without the dot, case-insensitive) eligible for `compress`.
+    #[serde(default)]
+    pub compress_extensions: Vec<String>,
+    /// Only refresh files the target already has; source files missing on the target are skipped.
+    #[serde(default)]
+    pub update_existing_only: bool,
+    /// Match source files to target files whose names differ only in case and rename them
+    /// instead of copying. Intended for case-insensitive target filesystems.
+    #[serde(default)]
+    pub reconcile_case_drift: bool,
+    /// Fraction (0.0–1.0) of copied files re-hashed when `verify_after_copy` is on. Selection is
+    /// deterministic per path and seed; files of `VERIFY_ALWAYS_ABOVE_BYTES` or more are always
+    /// verified. `1.0` verifies everything.
+    #[serde(default = "default_verify_sample_rate")]
+    pub verify_sample_rate: f64,
+    #[serde(default)]
+    pub verify_sample_seed: u64,
+    /// Skip existing target files that are read-only (or flagged immutable on macOS) instead of
+    /// overwriting them; skipped files are reported as `TargetReadOnly` errors.
+    #[serde(default)]
+    pub respect_readonly_target: bool,
+    /// Maximum full target path length in bytes. Longer paths are reported as `PathTooLong`
+    /// in dry runs and skipped during sync instead of failing the run.
+    #[serde(default)]
+    pub max_target_path_len: Option<usize>,
+    /// Leave zero-byte source files out of the diff so placeholders never overwrite the target.
+    #[serde(default)]
+    pub skip_empty_files: bool,
+    /// Walk directories with jwalk's parallel walker; results are sorted by path.
+    #[serde(default)]
+    pub parallel_scan: bool,
+    /// Copy BSD file flags (`UF_HIDDEN`, `SF_ARCHIVED`, ...) to the target after each copy.
+    /// Immutable/append-only flags are applied last. macOS only; a no-op elsewhere.
+    #[serde(default)]
+    pub preserve_flags: bool,
+    /// Stop the copy phase at the first per-file failure (copy, verification, rename, too-long
+    /// target path, read-only target, or a disconnected source) instead of collecting errors and
+    /// continuing.
+    #[serde(default)]
+    pub stop_on_first_error: bool,
+    /// Wall-clock budget for the copy phase. Checked between files, so the file in flight
+    /// always finishes; at least one file is processed per run so re-queued runs make progress.
+    #[serde(default)]
+    pub max_duration: Option<Duration>,
+    /// Rewrites where each source file lands on the target instead of mirroring the source tree.
+    #[serde(default)]
+    pub target_layout: Option<TargetLayout>,
+    /// Fold `._name` AppleDouble sidecars into `name` on the target as extended attributes
+    /// (resource fork, FinderInfo, xattrs) instead of copying them. macOS only; when merging
+    /// fails the sidecar is copied as a regular file.
+    #[serde(default)]
+    pub merge_appledouble: bool,
+    /// In checksum mode, hard-link source files whose content matches a file already copied in
+    /// the same run instead of copying them again. Changes the target's link topology, so
+    /// editing one linked copy changes all of them; compressed copies are never linked.
+    #[serde(default)]
+    pub dedup_identical: bool,
+    /// In checksum mode, report files whose mtimes match but whose content differs as
+    /// conflicts (alongside target-newer ones) instead of overwriting the target.
+    #[serde(default)]
+    pub flag_equal_mtime_content_diff: bool,
+    /// Read every chunk back from the target right after writing it and fail the file with
+    /// `VerificationFailed` on the first mismatch. Independent of `verify_after_copy`; applies to
+    /// uncompressed copies only.
+    #[serde(default)]
+    pub read_after_write: bool,
+    /// With `verify_after_copy`, keep the files the compare found already in sync so
+    /// `sync_and_verify` can re-hash a `verify_sample_rate` sample of them after copying.
+    #[serde(default)]
+    pub verify_existing_after_sync: bool,
+    /// Skip `preserve_permissions`/`preserve_times` when the target is on a network mount, where
+    /// the server manages them and the calls are slow or fail. Local targets are unaffected.
+    #[serde(default)]
+    pub skip_metadata_on_network: bool,
+    /// Copy files of `RESUMABLE_COPY_MIN_BYTES` or more through a partial file that a later run
+    /// continues from when the source size and mtime are unchanged, instead of restarting.
+    #[serde(default)]
+    pub resume_partial_copies: bool,
+    /// Record why each source file the compare leaves alone is skipped in
+    /// `DryRunResult::skipped`, up to `MAX_SKIP_REASONS` entries.
+    #[serde(default)]
+    pub collect_skip_reasons: bool,
+    /// Walk only these top-level source directories; exclusions still apply inside them. The
+    /// target scan, orphan detection and mirror previews are limited to the same top-level
+    /// names unless `target_layout` is set. Names must be single path components.
+    #[serde(default)]
+    pub source_subdirs: Option<Vec<String>>,
+}
+
+/// Target placement rule applied per source file. Only files are placed; source directories are
+/// not recreated.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum TargetLayout {
+    /// `<pattern>/<file name>`, where `pattern` is a strftime pattern (e.g. `%Y/%m/%d`) rendered
+    /// from the source file's modified time in local time.
+    DateTree { pattern: String },
+}
+
+/// Checks `SyncOptions::source_subdirs`: at least one name, each a single normal path component.
+pub fn validate_source_subdirs(subdirs: &[String]) -> Result<(), String> {
+    if subdirs.is_empty() {
+        return Err("Source subdirectories must list at least one directory".to_string());
+    }
+    for name in subdirs {
+        let mut components = std::path::Path::new(name).components();
+        let simple = matches!(components.next(), Some(std::path::Component::Normal(_)))
+            && components.next().is_none()
+            && !name.contains(['/', '\\']);
+        if !simple {
+            return Err(format!(
+                "Source subdirectory must be a single directory name: {name:?}"
+            ));
+        }
+    }
+    Ok(())
+}
+
+impl TargetLayout {
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            Self::DateTree { pattern } => {
+                use chrono::format::{Item, StrftimeItems};
+
+                if pattern.trim().is_empty() {
+                    return Err("Date layout pattern must not be empty".to_string());
+                }
+                if StrftimeItems::new(pattern).any(|item| matches!(item, Item::Error)) {
+                    return Err(format!("Invalid date layout pattern: {pattern}"));
+                }
+                let path = std::path::Path::new(pattern);
+                if !path
+                    .components()
+                    .all(|component| matches!(component, std::path::Component::Normal(_)))
+                {
+                    return Err(format!(
+                        "Date layout pattern must be a relative path without '.' or '..': {pattern}"
+                    ));
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Target-relative path for a source file. Call `validate` first; invalid patterns panic
+    /// when rendered.
+    pub fn relative_path_for(
+        &self,
+        source_relative: &std::path::Path,
+        modified: std::time::SystemTime,
+    ) -> PathBuf {
+        match self {
+            Self::DateTree { pattern } => {
+                let modified: chrono::DateTime<chrono::Local> = modified.into();
+                let directory = PathBuf::from(modified.format(pattern).to_string());
+                match source_relative.file_name() {
+                    Some(name) => directory.join(name),
+                    None => directory,
+                }
+            }
+        }
+    }
+}
+
+/// One source file and where `TargetLayout` would place it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TargetLayoutMapping {
+    pub source_path: PathBuf,
+    pub target_path: PathBuf,
+    /// Another source file maps to the same target path.
+    pub collides: bool,
+}
+
+/// Source files that a layout maps to one target path. Only the first, in path order, is copied.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TargetLayoutCollision {
+    pub target_path: PathBuf,
+    pub source_paths: Vec<PathBuf>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TargetLayoutPreview {
+    pub total_files: usize,
+    /// The first `sample_limit` source files in path order.
+    pub mappings: Vec<TargetLayoutMapping>,
+    /// Every collision across the whole source, not just the sampled files.
+    pub collisions: Vec<TargetLayoutCollision>,
+}
+
+/// Estimated peak comparison memory above which `estimate_scan_memory` warns.
+pub const DEFAULT_SCAN_MEMORY_WARN_BYTES: u64 = 2 * 1024 * 1024 * 1024;
+
+/// Result of a count-only walk sized against what a full compare would hold in memory.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ScanMemoryEstimate {
+    /// Files and directories left after exclusions.
+    pub entry_count: u64,
+    /// Mean relative path length in bytes.
+    pub average_path_bytes: u64,
+    /// Peak compare memory, assuming the target mirrors the source entry for entry.
+    pub estimated_peak_bytes: u64,
+    pub warn_threshold_bytes: u64,
+    pub exceeds_threshold: bool,
+    /// Set when `exceeds_threshold`; says how to shrink the walk.
+    pub warning: Option<String>,
+}
+
+/// Copies at least this large are always verified regardless of `verify_sample_rate`.
+pub const VERIFY_ALWAYS_ABOVE_BYTES: u64 = 1024 * 1024 * 1024;
+
+fn default_verify_sample_rate() -> f64 {
+    1.0
 }
 
 impl Default for SyncOptions {
@@ -51,6 +286,31 @@ impl Default for SyncOptions {
             preserve_times: true,
             verify_after_copy: false,
             exclude_patterns: Vec::new(),
+            modified_after: None,
+            modified_before: None,
+            compress: false,
+            compress_extensions: Vec::new(),
+            update_existing_only: false,
+            reconcile_case_drift: false,
+            verify_sample_rate: default_verify_sample_rate(),
+            verify_sample_seed: 0,
+            respect_readonly_target: false,
+            max_target_path_len: None,
+            skip_empty_files: false,
+            parallel_scan: false,
+            preserve_flags: false,
+            stop_on_first_error: false,
+            max_duration: None,
+            target_layout: None,
+            merge_appledouble: false,
+            dedup_identical: false,
+            flag_equal_mtime_content_diff: false,
+            read_after_write: false,
+            verify_existing_after_sync: false,
+            skip_metadata_on_network: false,
+            resume_partial_copies: false,
+            collect_skip_reasons: false,
+            source_subdirs: None,
         }
     }
 }
@@ -59,6 +319,17 @@ impl Default for SyncOptions {
 pub enum SyncErrorKind {
     CopyFailed,
     VerificationFailed,
+    /// The source root became inaccessible mid-sync (e.g. card removed); remaining copies were skipped.
+    SourceDisconnected,
+    /// The existing target file is read-only or immutable and `respect_readonly_target` skipped it.
+    TargetReadOnly,
+    /// The full target path exceeds `max_target_path_len`; the file was skipped.
+    PathTooLong,
+    /// The file was copied but some of its BSD flags could not be applied to the target.
+    FlagsNotPreserved,
+    /// `target_layout` or compression naming (`x.log` stored as `x.log.gz`) put another source
+    /// file at the same target path; this one was skipped.
+    LayoutCollision,
     Other,
 }
 
@@ -69,11 +340,72 @@ pub struct SyncError {
     pub kind: SyncErrorKind,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SyncResult {
     pub files_copied: u64,
     pub bytes_copied: u64,
     pub errors: Vec<SyncError>,
+    /// Files written as `.gz` copies; their source sizes are included in `bytes_copied`.
+    #[serde(default)]
+    pub compressed_files: u64,
+    #[serde(default)]
+    pub compressed_original_bytes: u64,
+    #[serde(default)]
+    pub compressed_stored_bytes: u64,
+    /// Copies re-hashed after writing.
+    #[serde(default)]
+    pub files_verified: u64,
+    /// Copies skipped by `verify_sample_rate` while verification was enabled.
+    #[serde(default)]
+    pub files_verify_sampled_out: u64,
+    /// Zero-byte source files skipped by `skip_empty_files`.
+    #[serde(default)]
+    pub empty_files_skipped: u64,
+    /// File whose failure ended the run early under `stop_on_first_error`; its error is the
+    /// last entry in `errors`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stopped_on_error: Option<PathBuf>,
+    /// The run hit `max_duration`; `remaining_files`/`remaining_bytes` were not attempted.
+    #[serde(default)]
+    pub time_limit_reached: bool,
+    #[serde(default)]
+    pub remaining_files: u64,
+    #[serde(default)]
+    pub remaining_bytes: u64,
+    /// `._name` sidecars applied to their data file under `merge_appledouble` instead of copied.
+    #[serde(default)]
+    pub appledouble_merged: u64,
+    /// Files hard-linked to an identical copy under `dedup_identical` instead of copied; they
+    /// are not counted in `files_copied`.
+    #[serde(default)]
+    pub dedup_linked: u64,
+    /// Source bytes that `dedup_linked` files did not have to write.
+    #[serde(default)]
+    pub dedup_bytes_saved: u64,
+    /// Run-level notices that did not fail any file (e.g. metadata skipped on a network target).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+/// `sync_files` result plus the post-sync sweep over target files the run did not copy.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SyncAndVerifyResult {
+    pub sync: SyncResult,
+    /// Already-matched files re-hashed against the source after the copy phase.
+    pub existing_verified: u64,
+    /// Already-matched files skipped by `verify_sample_rate`.
+    pub existing_sampled_out: u64,
+    /// Already-matched files whose target content no longer matches the source
+    /// (`VerificationFailed`) or could not be read (`Other`).
+    pub existing_errors: Vec<SyncError>,
+}
+
+impl SyncResult {
+    pub fn source_disconnected(&self) -> bool {
+        self.errors
+            .iter()
+            .any(|error| matches!(error.kind, SyncErrorKind::SourceDisconnected))
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -101,6 +433,89 @@ pub struct DryRunResult {
     pub bytes_to_copy: u64,
     #[serde(rename = "targetPreflight")]
     pub target_preflight: Option<TargetPreflightInfo>,
+    /// Target files with no source counterpart; only populated when the dry run asked for orphans.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub orphans: Option<Vec<OrphanFile>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub orphan_summary: Option<OrphanSummary>,
+    /// Diffs whose target path exceeds `max_target_path_len`; sync skips these.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub path_too_long: Vec<PathTooLongDiagnostic>,
+    /// Zero-byte source files left out of `diffs` by `skip_empty_files`.
+    #[serde(default)]
+    pub empty_files_skipped: usize,
+    /// Source files skipped because `target_layout` placed an earlier source file (in path
+    /// order) at the same target path, or because a compressed file is stored under their name.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub layout_collisions: Vec<PathBuf>,
+    /// Uncompressed source files whose target copies the compare left as they were; only
+    /// collected under `verify_existing_after_sync`.
+    #[serde(skip)]
+    pub matched_files: Vec<MatchedFile>,
+    /// Why files were left out of (or will be skipped from) the copy; only collected under
+    /// `collect_skip_reasons`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skipped: Vec<SkippedFile>,
+    /// True when `skipped` stopped at `MAX_SKIP_REASONS`.
+    #[serde(default)]
+    pub skipped_truncated: bool,
+}
+
+/// Upper bound on `DryRunResult::skipped`, so huge up-to-date trees stay cheap to return.
+pub const MAX_SKIP_REASONS: usize = 10_000;
+
+/// Why the engine does not copy a file.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum SkipReason {
+    /// Matched an exclusion pattern. An excluded directory is listed once, not per file.
+    Excluded,
+    /// Modified time falls outside `modified_after`/`modified_before`.
+    OutsideModifiedWindow,
+    /// Zero-byte file left alone by `skip_empty_files`.
+    EmptyFile,
+    /// Target already holds the same content.
+    UpToDate,
+    /// Target copy is newer and differs; handed to conflict review.
+    TargetNewer,
+    /// Same modified time but different content; handed to conflict review by
+    /// `flag_equal_mtime_content_diff`.
+    ContentConflict,
+    /// Missing on the target while `update_existing_only` is set.
+    NotOnTarget,
+    /// `target_layout` or compression naming put another source file at the same target path.
+    LayoutCollision,
+    /// Target path exceeds `max_target_path_len`.
+    PathTooLong,
+    /// Existing target file is read-only or immutable under `respect_readonly_target`.
+    TargetReadOnly,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SkippedFile {
+    /// Source-relative for `Excluded`, `OutsideModifiedWindow` and `LayoutCollision`;
+    /// target-relative otherwise, like `FileDiff::path`.
+    pub path: PathBuf,
+    pub reason: SkipReason,
+}
+
+/// A source file the compare found already in sync on the target.
+#[derive(Debug, Clone)]
+pub struct MatchedFile {
+    /// Target-relative path.
+    pub path: PathBuf,
+    pub source_path: PathBuf,
+    pub target_path: PathBuf,
+    pub size: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PathTooLongDiagnostic {
+    pub path: PathBuf,
+    pub target_path_len: usize,
+    pub limit: usize,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
@@ -155,6 +570,10 @@ pub enum SyncProgressPhase {
     Comparing,
     ValidatingDryRun,
     Copying,
+    /// Removing orphan target entries; `processed_files`/`total_files` count deletions.
+    Deleting,
+    /// Read-only hashing pass; `processed_files`/`processed_bytes` count what has been hashed.
+    Verifying,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -178,6 +597,46 @@ pub struct FileMetadata {
     pub is_file: bool,
 }
 
+/// Size and whole-second mtime of a file as recorded in a `SyncSnapshot`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncSnapshotEntry {
+    pub size: u64,
+    /// `None` for mtimes before the Unix epoch.
+    pub modified_secs: Option<u64>,
+}
+
+impl SyncSnapshotEntry {
+    pub fn from_metadata(meta: &FileMetadata) -> Self {
+        Self {
+            size: meta.size,
+            modified_secs: meta
+                .modified
+                .duration_since(std::time::SystemTime::UNIX_EPOCH)
+                .ok()
+                .map(|elapsed| elapsed.as_secs()),
+        }
+    }
+}
+
+/// Files a task's last successful checksum-mode sync left identical on both sides, keyed by
+/// relative path.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncSnapshot {
+    pub entries: std::collections::HashMap<PathBuf, SyncSnapshotEntry>,
+}
+
+/// Single hash over a directory tree. Metadata mode combines relative path, size, and mtime;
+/// deep mode replaces mtime with a content hash.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DirectoryFingerprint {
+    pub fingerprint: String,
+    pub file_count: usize,
+    pub total_bytes: u64,
+    pub deep: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrphanFile {
     pub path: PathBuf,
@@ -185,6 +644,71 @@ pub struct OrphanFile {
     pub is_dir: bool,
 }
 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct OrphanSummary {
+    pub file_count: usize,
+    pub dir_count: usize,
+    pub total_bytes: u64,
+}
+
+impl OrphanSummary {
+    pub fn from_orphans(orphans: &[OrphanFile]) -> Self {
+        orphans.iter().fold(Self::default(), |mut summary, orphan| {
+            if orphan.is_dir {
+                summary.dir_count += 1;
+            } else {
+                summary.file_count += 1;
+                summary.total_bytes += orphan.size;
+            }
+            summary
+        })
+    }
+}
+
+/// Outcome of re-copying one file pair outside a sync run. A failed verification is reported
+/// through `error` (the corrupt copy is removed); other failures surface as errors.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RecopyFileResult {
+    pub path: PathBuf,
+    pub success: bool,
+    pub bytes_copied: u64,
+    pub verified: bool,
+    pub error: Option<String>,
+}
+
+/// File count and total source (or, for orphans, target) bytes for one drift category.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DriftCategory {
+    pub count: usize,
+    pub bytes: u64,
+}
+
+impl DriftCategory {
+    pub fn add(&mut self, bytes: u64) {
+        self.count += 1;
+        self.bytes += bytes;
+    }
+}
+
+/// Read-only comparison of a maintained mirror against its source.
+/// `modified` includes target-newer conflicts and case-only renames; `orphan` counts files only.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DriftReport {
+    pub new: DriftCategory,
+    pub modified: DriftCategory,
+    pub orphan: DriftCategory,
+    pub identical: DriftCategory,
+}
+
+impl DriftReport {
+    pub fn is_drifted(&self) -> bool {
+        self.new.count > 0 || self.modified.count > 0 || self.orphan.count > 0
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DeleteOrphanFailure {
     pub path: PathBuf,
@@ -198,4 +722,23 @@ pub struct DeleteOrphanResult {
     pub deleted_dirs_count: usize,
     pub skipped_count: usize,
     pub failures: Vec<DeleteOrphanFailure>,
+    /// Entries moved to the Trash instead of being removed permanently.
+    #[serde(default)]
+    pub trashed_count: usize,
+    #[serde(default)]
+    pub warnings: Vec<String>,
+}
+
+/// What deleting a selection of orphan paths would remove, counted on disk with the same
+/// ancestor coverage `delete_orphan_paths` applies.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct OrphanDeletionSummary {
+    pub file_count: usize,
+    pub dir_count: usize,
+    pub total_bytes: u64,
+    /// Selected directories that are not inside another selected directory.
+    pub top_level_dirs: Vec<PathBuf>,
+    /// Selected paths that are invalid, missing, escape the target, or fall outside the run's
+    /// `source_subdirs`.
+    pub skipped_count: usize,
 }
@@ -57,6 +57,7 @@ struct VolumeMetadata {
     volume_name: Option<String>,
     device_identifier: Option<String>,
     parent_whole_disk: Option<String>,
+    mount_point: Option<String>,
     internal: Option<bool>,
     ejectable: Option<bool>,
     removable_media: Option<bool>,
@@ -127,6 +128,7 @@ impl DiskMonitor {
             volume_name: plist_string(dict, "VolumeName"),
             device_identifier: plist_string(dict, "DeviceIdentifier"),
             parent_whole_disk: plist_string(dict, "ParentWholeDisk"),
+            mount_point: plist_string(dict, "MountPoint"),
             internal: parse_optional_bool(dict, "Internal"),
             ejectable: parse_optional_bool(dict, "Ejectable"),
             removable_media: parse_optional_bool(dict, "RemovableMedia"),
@@ -212,46 +214,71 @@ impl DiskMonitor {
         Ok(all_volumes.into_iter().filter(|v| v.is_removable).collect())
     }
 
-    /// Removable 디스크를 언마운트합니다.
-    /// macOS의 diskutil 명령을 사용합니다.
-    pub fn unmount_volume(path: &Path) -> Result<()> {
-        use crate::path_validation::{validate_path, verify_path_exists};
-        use std::process::Command;
-        use std::thread;
-        use std::time::Duration;
-
-        // 1. Convert to string, reject if invalid UTF-8 or contains null
-        let path_str = path
-            .to_str()
-            .ok_or_else(|| anyhow::anyhow!("Invalid path: contains non-UTF-8 characters"))?;
+    /// 경로가 네트워크 마운트에 있는지 확인합니다 (경로를 포함하는 가장 깊은 마운트 기준).
+    /// 아직 없는 경로는 존재하는 가장 가까운 상위 경로로 판단합니다.
+    pub fn is_network_path(&self, path: &Path) -> Result<bool> {
+        let resolved = path
+            .ancestors()
+            .find_map(|ancestor| std::fs::canonicalize(ancestor).ok())
+            .unwrap_or_else(|| path.to_path_buf());
+        let entries = list_mount_entries()?;
+        Ok(entries
+            .iter()
+            .filter(|entry| resolved.starts_with(&entry.mount_point))
+            .max_by_key(|entry| entry.mount_point.components().count())
+            .is_some_and(|entry| is_network_mount(entry.flags)))
+    }
 
-        // 2. Use existing validation module
-        validate_path(path_str).map_err(|e| anyhow::anyhow!("Path validation failed: {}", e))?;
+    /// 경로가 속한 removable 볼륨의 마운트 루트를 찾습니다.
+    fn resolve_removable_mount_root(path: &Path) -> Result<Option<PathBuf>> {
+        let removable_volumes = Self::new().get_removable_volumes()?;
+        Ok(find_matching_removable_mount_root(path, &removable_volumes))
+    }
 
-        // 3. Verify path exists and is accessible
-        verify_path_exists(path).map_err(|e| anyhow::anyhow!("Path verification failed: {}", e))?;
+    /// Removable 볼륨의 SMART/상태 힌트를 조회합니다 (읽기 전용).
+    /// `diskutil info -plist`로 디바이스를 확인하고, `smartctl`이 설치되어 있으면 함께 사용합니다.
+    pub fn get_volume_health(path: &Path) -> Result<VolumeHealth> {
+        let mount_point = Self::resolve_removable_mount_root(path)?
+            .ok_or_else(|| anyhow::anyhow!("Not a mounted removable volume: {:?}", path))?;
 
-        // 4. Additional validation: must be under /Volumes
-        if !path_str.starts_with("/Volumes/") {
+        let output = Command::new("diskutil")
+            .arg("info")
+            .arg("-plist")
+            .arg(&mount_point)
+            .output()
+            .map_err(|e| anyhow::anyhow!("diskutil execution failed: {}", e))?;
+        if !output.status.success() {
             return Err(anyhow::anyhow!(
-                "Invalid volume path: must be under /Volumes, got: {}",
-                path_str
+                "diskutil info failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
             ));
         }
+        let disk_health = parse_disk_health_from_plist(&output.stdout).unwrap_or_default();
 
-        // 5. Validate no shell metacharacters
-        if path_str.contains('|')
-            || path_str.contains('&')
-            || path_str.contains(';')
-            || path_str.contains('$')
-            || path_str.contains('`')
-            || path_str.contains('\n')
-        {
-            return Err(anyhow::anyhow!("Path contains shell metacharacters"));
-        }
+        let smartctl_report = disk_health
+            .whole_disk
+            .as_deref()
+            .and_then(run_smartctl)
+            .map(|stdout| parse_smartctl_output(&stdout));
 
-        let removable_volumes = Self::new().get_removable_volumes()?;
-        let removable_mount_root = find_matching_removable_mount_root(path, &removable_volumes)
+        Ok(build_volume_health(
+            mount_point,
+            disk_health,
+            smartctl_report,
+        ))
+    }
+
+    /// Removable 디스크를 언마운트합니다.
+    /// macOS의 diskutil 명령을 사용합니다.
+    pub fn unmount_volume(path: &Path) -> Result<()> {
+        use std::process::Command;
+        use std::thread;
+        use std::time::Duration;
+
+        // 1-5. Path, /Volumes, and shell metacharacter checks
+        validate_volume_command_path(path)?;
+
+        let removable_mount_root = Self::resolve_removable_mount_root(path)?
             .ok_or_else(|| anyhow::anyhow!("Unmount denied: not a mounted removable volume"))?;
 
         let max_retries = 3;
@@ -289,6 +316,276 @@ impl DiskMonitor {
             last_error
         ))
     }
+
+    /// Removable 볼륨의 이름을 바꾸고 바뀐 마운트 포인트를 반환합니다.
+    /// `unmount_volume`과 같은 경로 검사를 거친 뒤 `diskutil rename <device> <name>`을 실행하며,
+    /// 새 이름은 셸을 거치지 않는 별도 인자로 전달됩니다.
+    pub fn rename_volume(path: &Path, new_name: &str) -> Result<PathBuf> {
+        validate_volume_command_path(path)?;
+        validate_volume_name(new_name)?;
+
+        let removable_mount_root = Self::resolve_removable_mount_root(path)?
+            .ok_or_else(|| anyhow::anyhow!("Rename denied: not a mounted removable volume"))?;
+        let device_identifier = Self::get_volume_metadata(&removable_mount_root)
+            .and_then(|metadata| metadata.device_identifier)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Could not resolve device for {}",
+                    removable_mount_root.display()
+                )
+            })?;
+        if !is_safe_device_identifier(&device_identifier) {
+            return Err(anyhow::anyhow!(
+                "Unexpected device identifier: {device_identifier}"
+            ));
+        }
+
+        let output = Command::new("diskutil")
+            .arg("rename")
+            .arg(&device_identifier)
+            .arg(new_name)
+            .output()
+            .map_err(|e| anyhow::anyhow!("diskutil execution failed: {}", e))?;
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "Rename failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        // macOS가 이름 충돌 시 " 1" 등을 붙일 수 있으므로 실제 마운트 포인트를 다시 조회합니다.
+        let renamed_mount_point =
+            Self::get_volume_metadata(Path::new(&format!("/dev/{device_identifier}")))
+                .and_then(|metadata| metadata.mount_point)
+                .filter(|mount_point| !mount_point.is_empty())
+                .map(PathBuf::from)
+                .unwrap_or_else(|| Path::new("/Volumes").join(new_name));
+        Ok(renamed_mount_point)
+    }
+}
+
+/// `diskutil`에 넘기는 볼륨 경로 검사: UTF-8, 경로 검증, 존재 여부, `/Volumes/` 하위, 셸 메타문자 없음.
+fn validate_volume_command_path(path: &Path) -> Result<()> {
+    use crate::path_validation::{validate_path, verify_path_exists};
+
+    // 1. Convert to string, reject if invalid UTF-8 or contains null
+    let path_str = path
+        .to_str()
+        .ok_or_else(|| anyhow::anyhow!("Invalid path: contains non-UTF-8 characters"))?;
+
+    // 2. Use existing validation module
+    validate_path(path_str).map_err(|e| anyhow::anyhow!("Path validation failed: {}", e))?;
+
+    // 3. Verify path exists and is accessible
+    verify_path_exists(path).map_err(|e| anyhow::anyhow!("Path verification failed: {}", e))?;
+
+    // 4. Additional validation: must be under /Volumes
+    if !path_str.starts_with("/Volumes/") {
+        return Err(anyhow::anyhow!(
+            "Invalid volume path: must be under /Volumes, got: {}",
+            path_str
+        ));
+    }
+
+    // 5. Validate no shell metacharacters
+    if path_str.contains('|')
+        || path_str.contains('&')
+        || path_str.contains(';')
+        || path_str.contains('$')
+        || path_str.contains('`')
+        || path_str.contains('\n')
+    {
+        return Err(anyhow::anyhow!("Path contains shell metacharacters"));
+    }
+
+    Ok(())
+}
+
+const MAX_VOLUME_NAME_LEN: usize = 63;
+
+/// 새 볼륨 이름 검사. 영문자·숫자·공백·`-`·`_`·`.`만 허용하고, 옵션으로 읽히거나
+/// 숨김/상대 경로가 되는 선행 `-`·`.`와 앞뒤 공백은 거부합니다.
+pub fn validate_volume_name(name: &str) -> Result<()> {
+    if name.is_empty() {
+        return Err(anyhow::anyhow!("Volume name cannot be empty"));
+    }
+    if name.chars().count() > MAX_VOLUME_NAME_LEN {
+        return Err(anyhow::anyhow!(
+            "Volume name too long (max: {MAX_VOLUME_NAME_LEN} characters)"
+        ));
+    }
+    if name.trim() != name {
+        return Err(anyhow::anyhow!(
+            "Volume name cannot start or end with whitespace"
+        ));
+    }
+    if name.starts_with('-') || name.starts_with('.') {
+        return Err(anyhow::anyhow!("Volume name cannot start with '-' or '.'"));
+    }
+    if let Some(invalid) = name
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || matches!(c, ' ' | '-' | '_' | '.')))
+    {
+        return Err(anyhow::anyhow!(
+            "Volume name contains an unsupported character: {invalid:?}"
+        ));
+    }
+    Ok(())
+}
+
+fn is_safe_device_identifier(identifier: &str) -> bool {
+    identifier.starts_with("disk") && identifier.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum VolumeHealthStatus {
+    Verified,
+    Failing,
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct VolumeHealth {
+    pub mount_point: PathBuf,
+    pub device_identifier: Option<String>,
+    pub device_model: Option<String>,
+    pub status: VolumeHealthStatus,
+    /// `smartctl`을 찾아 실행했는지 여부. false면 `status`는 diskutil 값에만 의존합니다.
+    pub smartctl_available: bool,
+    /// SMART가 보고한 오류/경고 요약 (예: "Media and Data Integrity Errors: 3")
+    pub errors: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct DiskHealthMetadata {
+    whole_disk: Option<String>,
+    device_model: Option<String>,
+    smart_status: Option<VolumeHealthStatus>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct SmartctlReport {
+    device_model: Option<String>,
+    status: Option<VolumeHealthStatus>,
+    errors: Vec<String>,
+}
+
+/// `diskutil info -plist`의 `SMARTStatus`는 "Verified" / "Failing" / "Not Supported" 중 하나입니다.
+fn parse_disk_health_from_plist(data: &[u8]) -> Option<DiskHealthMetadata> {
+    let value = plist::from_bytes::<plist::Value>(data).ok()?;
+    let dict = value.as_dictionary()?;
+    let smart_status = plist_string(dict, "SMARTStatus").and_then(|status| {
+        match status.to_ascii_lowercase().as_str() {
+            "verified" => Some(VolumeHealthStatus::Verified),
+            "failing" => Some(VolumeHealthStatus::Failing),
+            _ => None,
+        }
+    });
+
+    Some(DiskHealthMetadata {
+        whole_disk: first_non_empty([
+            plist_string(dict, "ParentWholeDisk"),
+            plist_string(dict, "DeviceIdentifier"),
+        ]),
+        device_model: first_non_empty([
+            plist_string(dict, "MediaName"),
+            plist_string(dict, "IORegistryEntryName"),
+        ]),
+        smart_status,
+    })
+}
+
+/// GUI 앱은 셸 PATH를 상속하지 않으므로 Homebrew 기본 경로도 확인합니다.
+const SMARTCTL_CANDIDATES: &[&str] = &[
+    "smartctl",
+    "/opt/homebrew/bin/smartctl",
+    "/usr/local/bin/smartctl",
+];
+
+/// `smartctl -H -i /dev/<disk>` 출력을 반환합니다. smartctl이 없으면 None.
+/// smartctl 종료 코드는 비트마스크라 실패 코드여도 stdout을 그대로 사용합니다.
+fn run_smartctl(whole_disk: &str) -> Option<String> {
+    let is_whole_disk = whole_disk
+        .strip_prefix("disk")
+        .is_some_and(|index| !index.is_empty() && index.chars().all(|c| c.is_ascii_digit()));
+    if !is_whole_disk {
+        return None;
+    }
+    let device = format!("/dev/{whole_disk}");
+    SMARTCTL_CANDIDATES.iter().find_map(|program| {
+        let output = Command::new(program)
+            .args(["-H", "-i", &device])
+            .output()
+            .ok()?;
+        let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+        (!stdout.trim().is_empty()).then_some(stdout)
+    })
+}
+
+const SMARTCTL_ERROR_COUNTERS: &[&str] = &[
+    "Media and Data Integrity Errors",
+    "Error Information Log Entries",
+    "ATA Error Count",
+    "Critical Warning",
+];
+
+fn parse_smartctl_output(output: &str) -> SmartctlReport {
+    let mut report = SmartctlReport::default();
+
+    for line in output.lines() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let key = key.trim();
+        let value = value.trim();
+
+        match key {
+            "Device Model" | "Model Number" | "Product" if report.device_model.is_none() => {
+                report.device_model = normalize_optional_string(Some(value.to_string()));
+            }
+            "SMART overall-health self-assessment test result" | "SMART Health Status" => {
+                let verdict = value.to_ascii_uppercase();
+                report.status = if verdict.starts_with("PASSED") || verdict.starts_with("OK") {
+                    Some(VolumeHealthStatus::Verified)
+                } else {
+                    Some(VolumeHealthStatus::Failing)
+                };
+            }
+            _ if SMARTCTL_ERROR_COUNTERS.contains(&key) => {
+                let count = value.split_whitespace().next().unwrap_or_default();
+                let is_zero = count.trim_start_matches("0x").chars().all(|c| c == '0');
+                if !is_zero {
+                    report.errors.push(format!("{key}: {count}"));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    report
+}
+
+fn build_volume_health(
+    mount_point: PathBuf,
+    disk_health: DiskHealthMetadata,
+    smartctl_report: Option<SmartctlReport>,
+) -> VolumeHealth {
+    let smartctl_available = smartctl_report.is_some();
+    let report = smartctl_report.unwrap_or_default();
+    let status = report
+        .status
+        .or(disk_health.smart_status)
+        .unwrap_or(VolumeHealthStatus::Unknown);
+
+    VolumeHealth {
+        mount_point,
+        device_identifier: disk_health.whole_disk,
+        device_model: report.device_model.or(disk_health.device_model),
+        status,
+        smartctl_available,
+        errors: report.errors,
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -618,6 +915,91 @@ fn is_user_visible_mount(path: &Path, flags: u32) -> bool {
     true
 }
 
+/// 동기화/orphan 삭제 타겟으로 쓰면 시스템 파일을 덮어쓰거나 지울 수 있는 위치의 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SystemTargetKind {
+    /// 부트 볼륨 루트 (`/`)
+    RootMount,
+    /// `/System/Volumes/*` 등 시스템 볼륨 또는 OS가 관리하는 디렉터리
+    SystemVolume,
+    /// Recovery/Preboot/스냅샷처럼 사용자에게 노출되지 않는 마운트
+    HiddenVolume,
+}
+
+/// 시스템 경로로 취급하는 최상위 디렉터리 (`/usr/local`은 사용자 영역이라 제외)
+/// `/etc`, `/var`, `/tmp`는 `/private` 아래로 정규화되므로 `/private`로 함께 막습니다.
+const SYSTEM_PATH_PREFIXES: [&str; 7] = [
+    "/system",
+    "/library",
+    "/applications",
+    "/private",
+    "/usr",
+    "/bin",
+    "/sbin",
+];
+/// `$TMPDIR`이 놓이는 사용자별 임시 폴더(`/private/var/folders`)와 `/usr/local`은 허용합니다.
+const USER_PATH_EXCEPTIONS: [&str; 2] = ["/usr/local", "/private/var/folders"];
+
+/// 타겟이 시스템/부트 볼륨을 가리키는지 판별합니다.
+///
+/// 접두사 비교 전에 경로를 먼저 정규화합니다. 심볼릭 링크(예: `/Volumes/Macintosh HD` -> `/`,
+/// `/etc` -> `/private/etc`)를 따라간 경로를 기준으로 하며,
+/// 아직 없는 경로는 존재하는 가장 가까운 상위 디렉터리로 판단합니다.
+pub fn classify_system_target(path: &Path) -> Option<SystemTargetKind> {
+    let resolved = resolve_existing_prefix(path);
+    let mount_entries = list_mount_entries().unwrap_or_default();
+    classify_system_target_with_mounts(&resolved, &mount_entries)
+}
+
+fn classify_system_target_with_mounts(
+    path: &Path,
+    mount_entries: &[MountEntry],
+) -> Option<SystemTargetKind> {
+    let key = path.to_string_lossy().to_lowercase();
+    let key = key.trim_end_matches('/');
+    if key.is_empty() {
+        return Some(SystemTargetKind::RootMount);
+    }
+
+    let is_under = |prefix: &str| key == prefix || key.starts_with(&format!("{prefix}/"));
+    if SYSTEM_PATH_PREFIXES.iter().any(|prefix| is_under(prefix))
+        && !USER_PATH_EXCEPTIONS.iter().any(|prefix| is_under(prefix))
+    {
+        return Some(SystemTargetKind::SystemVolume);
+    }
+
+    // `/`는 사용자 폴더도 포함하므로 루트 자체가 아닌 한 마운트 기준으로는 막지 않습니다.
+    let containing_mount = mount_entries
+        .iter()
+        .filter(|entry| entry.mount_point != Path::new(ROOT_MOUNT))
+        .filter(|entry| path.starts_with(&entry.mount_point))
+        .max_by_key(|entry| entry.mount_point.components().count())?;
+    if !is_user_visible_mount(&containing_mount.mount_point, containing_mount.flags) {
+        return Some(SystemTargetKind::HiddenVolume);
+    }
+    None
+}
+
+fn resolve_existing_prefix(path: &Path) -> PathBuf {
+    let mut missing = Vec::new();
+    let mut current = path;
+    loop {
+        if let Ok(canonical) = current.canonicalize() {
+            return missing
+                .iter()
+                .rev()
+                .fold(canonical, |resolved, name| resolved.join(name));
+        }
+        match (current.parent(), current.file_name()) {
+            (Some(parent), Some(name)) => {
+                missing.push(name.to_os_string());
+                current = parent;
+            }
+            _ => return path.to_path_buf(),
+        }
+    }
+}
+
 fn is_network_mount(flags: u32) -> bool {
     flags & nix::libc::MNT_LOCAL as u32 == 0
 }
@@ -828,6 +1210,98 @@ mod tests {
         assert_eq!(metadata.removable_media, Some(true));
     }
 
+    #[test]
+    fn test_parse_smartctl_output_ata_passed() {
+        let output = "\
+smartctl 7.4 2023-08-01 r5530 [Darwin 23.4.0 arm64] (local build)
+=== START OF INFORMATION SECTION ===
+Model Family:     Samsung based SSDs
+Device Model:     Samsung SSD 870 EVO 1TB
+Serial Number:    S5Y1NX0T123456
+=== START OF READ SMART DATA SECTION ===
+SMART overall-health self-assessment test result: PASSED
+";
+
+        let report = parse_smartctl_output(output);
+        assert_eq!(
+            report.device_model,
+            Some("Samsung SSD 870 EVO 1TB".to_string())
+        );
+        assert_eq!(report.status, Some(VolumeHealthStatus::Verified));
+        assert!(report.errors.is_empty());
+    }
+
+    #[test]
+    fn test_parse_smartctl_output_nvme_reports_errors() {
+        let output = "\
+=== START OF INFORMATION SECTION ===
+Model Number:                       WD_BLACK SN850X 2000GB
+=== START OF SMART DATA SECTION ===
+SMART overall-health self-assessment test result: FAILED!
+Critical Warning:                   0x04
+Media and Data Integrity Errors:    3
+Error Information Log Entries:      0
+";
+
+        let report = parse_smartctl_output(output);
+        assert_eq!(
+            report.device_model,
+            Some("WD_BLACK SN850X 2000GB".to_string())
+        );
+        assert_eq!(report.status, Some(VolumeHealthStatus::Failing));
+        assert_eq!(
+            report.errors,
+            vec![
+                "Critical Warning: 0x04".to_string(),
+                "Media and Data Integrity Errors: 3".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build_volume_health_without_smartctl_is_unknown() {
+        let health = build_volume_health(
+            PathBuf::from("/Volumes/Archive"),
+            DiskHealthMetadata {
+                whole_disk: Some("disk4".to_string()),
+                device_model: Some("Portable SSD".to_string()),
+                smart_status: None,
+            },
+            None,
+        );
+
+        assert_eq!(health.status, VolumeHealthStatus::Unknown);
+        assert!(!health.smartctl_available);
+        assert_eq!(health.device_identifier, Some("disk4".to_string()));
+        assert_eq!(health.device_model, Some("Portable SSD".to_string()));
+        assert!(health.errors.is_empty());
+    }
+
+    #[test]
+    fn test_parse_disk_health_from_plist() {
+        let xml = r#"
+        <?xml version="1.0" encoding="UTF-8"?>
+        <!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+        <plist version="1.0">
+        <dict>
+            <key>DeviceIdentifier</key>
+            <string>disk4s2</string>
+            <key>ParentWholeDisk</key>
+            <string>disk4</string>
+            <key>MediaName</key>
+            <string>Extreme SSD</string>
+            <key>SMARTStatus</key>
+            <string>Verified</string>
+        </dict>
+        </plist>
+        "#;
+
+        let health = parse_disk_health_from_plist(xml.as_bytes()).expect("plist should parse");
+        assert_eq!(health.whole_disk, Some("disk4".to_string()));
+        assert_eq!(health.device_model, Some("Extreme SSD".to_string()));
+        assert_eq!(health.smart_status, Some(VolumeHealthStatus::Verified));
+    }
+
     #[test]
     fn test_parse_volume_metadata_missing_fields() {
         let xml = r#"
@@ -881,6 +1355,91 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_classify_system_target_blocks_root_and_system_paths() {
+        let hidden_mount = |mount_point: &str, flags: u32| MountEntry {
+            mount_point: PathBuf::from(mount_point),
+            mount_from: "/dev/disk3s5".to_string(),
+            flags,
+            block_size: 4096,
+            blocks: 0,
+            blocks_available: 0,
+        };
+        let mounts = vec![
+            hidden_mount("/", 0),
+            hidden_mount("/Volumes/EVO990", 0),
+            hidden_mount("/Volumes/Recovery", nix::libc::MNT_DONTBROWSE as u32),
+        ];
+        let classify = |path: &str| classify_system_target_with_mounts(Path::new(path), &mounts);
+
+        assert_eq!(classify("/"), Some(SystemTargetKind::RootMount));
+        assert_eq!(
+            classify("/System/Volumes/Data"),
+            Some(SystemTargetKind::SystemVolume)
+        );
+        for system_path in [
+            "/usr/bin",
+            "/bin",
+            "/sbin",
+            "/Library/Application Support",
+            "/Applications/Backup.app",
+            "/private/etc",
+            "/private/var/db",
+            "/private/tmp/backup",
+        ] {
+            assert_eq!(
+                classify(system_path),
+                Some(SystemTargetKind::SystemVolume),
+                "{system_path}"
+            );
+        }
+        assert_eq!(
+            classify("/Volumes/Recovery/Backup"),
+            Some(SystemTargetKind::HiddenVolume)
+        );
+        assert_eq!(classify("/usr/local/backup"), None);
+        assert_eq!(classify("/private/var/folders/xy/T/backup"), None);
+        assert_eq!(classify("/Users/me/Library/Backup"), None);
+        assert_eq!(classify("/Users/me/Backup"), None);
+        assert_eq!(classify("/Volumes/EVO990/Backup"), None);
+    }
+
+    #[test]
+    fn test_classify_system_target_follows_symlinks_to_root() {
+        let temp = tempfile::tempdir().unwrap();
+        let link = temp.path().join("Macintosh HD");
+        std::os::unix::fs::symlink("/", &link).unwrap();
+
+        assert_eq!(
+            classify_system_target(&link),
+            Some(SystemTargetKind::RootMount)
+        );
+        assert_eq!(
+            classify_system_target(&link.join("System").join("missing")),
+            Some(SystemTargetKind::SystemVolume)
+        );
+        assert_eq!(classify_system_target(temp.path()), None);
+
+        let usr_link = temp.path().join("usr-link");
+        std::os::unix::fs::symlink("/usr", &usr_link).unwrap();
+        assert_eq!(
+            classify_system_target(&usr_link.join("missing")),
+            Some(SystemTargetKind::SystemVolume)
+        );
+
+        // macOS에서는 `/etc`, `/tmp`, `/var`가 `/private` 아래로 정규화됩니다.
+        for alias in ["/etc", "/tmp", "/var"] {
+            if Path::new(alias).canonicalize().ok() == Some(Path::new("/private").join(&alias[1..]))
+            {
+                assert_eq!(
+                    classify_system_target(Path::new(alias)),
+                    Some(SystemTargetKind::SystemVolume),
+                    "{alias}"
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_network_mount_capacity_is_none() {
         let entry = MountEntry {
@@ -1027,4 +1586,58 @@ mod tests {
         );
         assert_eq!(unmatched, None);
     }
+
+    #[test]
+    fn test_validate_volume_name_accepts_labels_and_rejects_dangerous_input() {
+        for name in ["EOS_DIGITAL", "Card 01", "A7S-III.backup", "X"] {
+            assert!(validate_volume_name(name).is_ok(), "{name} should be valid");
+        }
+
+        for name in [
+            "",
+            " Card",
+            "Card ",
+            "-rf",
+            ".hidden",
+            "..",
+            "Card;rm -rf /",
+            "Card$(whoami)",
+            "Card`id`",
+            "Card|tee",
+            "Card&",
+            "Card\nB",
+            "../Escape",
+            "Cards/Sub",
+            "Card\"quoted\"",
+            "카드",
+        ] {
+            assert!(
+                validate_volume_name(name).is_err(),
+                "{name:?} should be rejected"
+            );
+        }
+
+        assert!(validate_volume_name(&"A".repeat(MAX_VOLUME_NAME_LEN)).is_ok());
+        assert!(validate_volume_name(&"A".repeat(MAX_VOLUME_NAME_LEN + 1)).is_err());
+    }
+
+    #[test]
+    fn test_rename_volume_rejects_paths_outside_volumes() {
+        let error = DiskMonitor::rename_volume(Path::new("/tmp"), "Card")
+            .expect_err("non-/Volumes path should be rejected");
+        assert!(error.to_string().contains("/Volumes"), "{error}");
+
+        let parsed = DiskMonitor::parse_volume_metadata_from_plist(
+            br#"<?xml version="1.0" encoding="UTF-8"?>
+<plist version="1.0"><dict>
+<key>DeviceIdentifier</key><string>disk4s1</string>
+<key>MountPoint</key><string>/Volumes/Card 01</string>
+</dict></plist>"#,
+        )
+        .expect("plist should parse");
+        assert_eq!(parsed.mount_point.as_deref(), Some("/Volumes/Card 01"));
+        assert!(is_safe_device_identifier("disk4s1"));
+        assert!(!is_safe_device_identifier("disk4s1; reboot"));
+        assert!(!is_safe_device_identifier("-force"));
+    }
 }
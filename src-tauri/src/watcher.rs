@@ -3,13 +3,86 @@
 //! 여러 Sync Task의 watcher를 관리하고, 변경 감지 시 자동 동기화를 트리거합니다.
 
 use anyhow::Result;
-use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher, WatcherKind};
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio_util::sync::CancellationToken;
 
+/// 이벤트 사이 간격이 이보다 짧으면 같은 배치로 묶음
+const DEBOUNCE_WINDOW: Duration = Duration::from_millis(500);
+
+/// 배치의 첫 이벤트 이후 이 시간이 지나면 이벤트가 계속 들어와도 on_change를 호출함
+pub const DEFAULT_MAX_COALESCE_SECS: u64 = 30;
+
+/// Finder/Spotlight가 내용 변경 없이 계속 건드리는 파일·디렉터리 이름
+pub const DEFAULT_WATCH_NOISE_NAMES: &[&str] = &[
+    ".DS_Store",
+    ".localized",
+    ".Spotlight-V100",
+    ".fseventsd",
+    ".TemporaryItems",
+    ".Trashes",
+];
+
+/// 노이즈만 있는 배치가 이만큼 연속되면 제외 패턴 추가를 제안함
+const WATCH_NOISE_SUGGESTION_THRESHOLD: u32 = 3;
+
+/// 같은 Task에 제외 제안을 다시 남기기까지의 최소 간격
+const WATCH_NOISE_SUGGESTION_INTERVAL: Duration = Duration::from_secs(10 * 60);
+
+/// 배치의 모든 경로가 노이즈 이름(또는 그 하위 경로)일 때만 true. 빈 배치는 노이즈가 아님
+pub fn is_noise_only_batch(paths: &[PathBuf], noise_names: &[String]) -> bool {
+    !paths.is_empty()
+        && paths.iter().all(|path| {
+            path.components().any(|component| {
+                noise_names
+                    .iter()
+                    .any(|name| component.as_os_str() == name.as_str())
+            })
+        })
+}
+
+/// 동기화를 트리거할지에 대한 판단
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatchNoiseDecision {
+    Trigger,
+    /// `suggest_exclusion`이면 제외 패턴 추가 제안 로그를 남김
+    Suppress {
+        suggest_exclusion: bool,
+    },
+}
+
+/// Task별 최근 감시 배치 기록. 실제 파일 변경이 섞이면 연속 기록이 초기화됨
+#[derive(Debug, Default)]
+pub struct WatchNoiseTracker {
+    consecutive_noise_batches: u32,
+    last_suggested_at: Option<Instant>,
+}
+
+impl WatchNoiseTracker {
+    pub fn observe(&mut self, noise_only: bool, now: Instant) -> WatchNoiseDecision {
+        if !noise_only {
+            self.consecutive_noise_batches = 0;
+            return WatchNoiseDecision::Trigger;
+        }
+
+        self.consecutive_noise_batches = self.consecutive_noise_batches.saturating_add(1);
+        let suggestion_due = self
+            .last_suggested_at
+            .is_none_or(|at| now.saturating_duration_since(at) >= WATCH_NOISE_SUGGESTION_INTERVAL);
+        let suggest_exclusion =
+            self.consecutive_noise_batches >= WATCH_NOISE_SUGGESTION_THRESHOLD && suggestion_due;
+        if suggest_exclusion {
+            self.last_suggested_at = Some(now);
+        }
+        WatchNoiseDecision::Suppress { suggest_exclusion }
+    }
+}
+
 /// 단일 Task의 Watcher 정보
 pub struct TaskWatcher {
     pub task_id: String,
@@ -17,11 +90,71 @@ pub struct TaskWatcher {
     _watcher: RecommendedWatcher,
     cancellation_token: CancellationToken,
     _debounce_thread_handle: Option<thread::JoinHandle<()>>,
+    counters: Arc<WatcherCounters>,
+}
+
+/// notify 콜백과 디바운스 스레드가 갱신하는 Watcher 이벤트 카운터
+#[derive(Debug, Default)]
+struct WatcherCounters {
+    events_received: AtomicU64,
+    events_dropped: AtomicU64,
+    batches_delivered: AtomicU64,
+}
+
+/// Task별 Watcher 리소스 사용 진단 정보 (프론트엔드 전송용)
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WatcherDiagnostics {
+    pub task_id: String,
+    pub watched_roots: Vec<String>,
+    /// "native" (FSEvents 등) 또는 "poll"
+    pub backend: String,
+    pub events_received: u64,
+    /// 채널이 가득 차 버려진 이벤트 수 (backpressure)
+    pub events_dropped: u64,
+    pub batches_delivered: u64,
+    pub restarts: u64,
+    /// watcher는 살아 있지만 이벤트가 동기화로 이어지지 않는 이유. `get_diagnostics`는 비워 두고
+    /// 태스크 설정과 조용한 시간대를 아는 호출 측이 채움
+    pub paused: Option<WatchPauseReason>,
+}
+
+/// 감시 중인 태스크의 동기화 트리거가 멈춘 이유
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WatchPauseReason {
+    /// 태스크가 비활성화됨 (다음 watcher 재조정에서 감시도 중지)
+    Disabled,
+    /// 조용한 시간대라 끝날 때까지 동기화를 미룸
+    QuietHours,
+}
+
+/// OS 감시 한도와 현재 watcher의 대략적인 사용량 (프론트엔드 전송용)
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchSystemLimits {
+    /// `std::env::consts::OS` (예: "macos", "linux")
+    pub platform: String,
+    /// "native" (FSEvents 등) 또는 "poll"
+    pub backend: String,
+    /// 한도를 소비하는 단위: inotify는 "directory", FSEvents는 "stream"
+    pub watch_unit: String,
+    /// Linux `fs.inotify.max_user_watches`. 읽을 수 없거나 해당 한도가 없는 플랫폼이면 None
+    pub max_user_watches: Option<u64>,
+    /// Linux `fs.inotify.max_user_instances`. 읽을 수 없으면 None
+    pub max_user_instances: Option<u64>,
+    /// 앱 설정의 동시 감시 태스크 상한 (maxWatchers)
+    pub app_max_watchers: usize,
+    pub watching_task_count: usize,
+    /// `watch_unit` 기준 추정 사용량 (inotify: 감시 루트 아래 디렉터리 수)
+    pub estimated_watches_in_use: u64,
 }
 
 /// 여러 Task의 Watcher를 관리하는 매니저
 pub struct WatcherManager {
     watchers: HashMap<String, TaskWatcher>,
+    /// Task별 감시 시작 횟수 (재시작 횟수 계산에 사용). `stop_watching`으로 감시를 끝내면 지움
+    start_counts: HashMap<String, u64>,
 }
 
 impl Default for WatcherManager {
@@ -34,6 +167,7 @@ impl WatcherManager {
     pub fn new() -> Self {
         Self {
             watchers: HashMap::new(),
+            start_counts: HashMap::new(),
         }
     }
 
@@ -47,13 +181,33 @@ impl WatcherManager {
     where
         F: Fn(Event) + Send + 'static,
     {
-        // 이미 감시 중이면 중지 후 재시작
-        if self.watchers.contains_key(&task_id) {
-            self.stop_watching(&task_id)?;
-        }
+        self.start_watching_with_max_coalesce(
+            task_id,
+            source_path,
+            Duration::from_secs(DEFAULT_MAX_COALESCE_SECS),
+            on_change,
+        )
+    }
+
+    /// `max_coalesce`: 연속 쓰기(긴 카메라 오프로드 등) 중에도 배치 시작 후 이 시간 안에 on_change가 호출됨.
+    pub fn start_watching_with_max_coalesce<F>(
+        &mut self,
+        task_id: String,
+        source_path: PathBuf,
+        max_coalesce: Duration,
+        on_change: F,
+    ) -> Result<()>
+    where
+        F: Fn(Event) + Send + 'static,
+    {
+        // 이미 감시 중이면 중지 후 재시작 (시작 횟수는 유지)
+        self.stop_task_watcher(&task_id);
 
         let cancellation_token = CancellationToken::new();
         let token_clone = cancellation_token.clone();
+        let counters = Arc::new(WatcherCounters::default());
+        let callback_counters = counters.clone();
+        let debounce_counters = counters.clone();
 
         // Use bounded channel (100 message buffer) to prevent memory exhaustion
         let (tx, rx) = std::sync::mpsc::sync_channel(100);
@@ -68,11 +222,16 @@ impl WatcherManager {
                         | EventKind::Create(_)
                         | EventKind::Modify(_)
                         | EventKind::Remove(_) => {
+                            callback_counters
+                                .events_received
+                                .fetch_add(1, Ordering::Relaxed);
                             // Use try_send for backpressure handling
                             if let Ok(tx) = tx.lock() {
-                                if let Err(_) = tx.try_send(event) {
-                                    // Channel full - log and skip (backpressure)
-                                    // In production, you might want to log this
+                                if tx.try_send(event).is_err() {
+                                    // Channel full - skip and count it (backpressure)
+                                    callback_counters
+                                        .events_dropped
+                                        .fetch_add(1, Ordering::Relaxed);
                                 }
                             }
                         }
@@ -86,7 +245,18 @@ impl WatcherManager {
         // 디바운싱 처리를 위한 스레드 생성 (with cancellation support & panic handling)
         let thread_handle = std::thread::spawn(move || {
             let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-                run_debounce_loop(rx, Duration::from_millis(500), token_clone, on_change);
+                run_debounce_loop(
+                    rx,
+                    DEBOUNCE_WINDOW,
+                    max_coalesce,
+                    token_clone,
+                    move |event| {
+                        debounce_counters
+                            .batches_delivered
+                            .fetch_add(1, Ordering::Relaxed);
+                        on_change(event);
+                    },
+                );
             }));
 
             if let Err(e) = result {
@@ -102,6 +272,7 @@ impl WatcherManager {
             }
         });
 
+        let task_id_key = task_id.clone();
         self.watchers.insert(
             task_id.clone(),
             TaskWatcher {
@@ -110,14 +281,22 @@ impl WatcherManager {
                 _watcher: watcher,
                 cancellation_token,
                 _debounce_thread_handle: Some(thread_handle),
+                counters,
             },
         );
+        *self.start_counts.entry(task_id_key).or_insert(0) += 1;
 
         Ok(())
     }
 
-    /// 특정 Task의 파일 시스템 감시를 중지합니다.
+    /// 특정 Task의 파일 시스템 감시를 중지합니다. 재시작 횟수도 함께 지워 삭제된 태스크가 남지 않게 합니다.
     pub fn stop_watching(&mut self, task_id: &str) -> Result<()> {
+        self.stop_task_watcher(task_id);
+        self.start_counts.remove(task_id);
+        Ok(())
+    }
+
+    fn stop_task_watcher(&mut self, task_id: &str) {
         if let Some(mut watcher) = self.watchers.remove(task_id) {
             // Cancel the debouncing thread
             watcher.cancellation_token.cancel();
@@ -126,7 +305,6 @@ impl WatcherManager {
             // The cancellation token ensures the thread will exit quickly
             let _ = watcher._debounce_thread_handle.take();
         }
-        Ok(())
     }
 
     /// 감시 중인 Task 목록을 반환합니다.
@@ -147,6 +325,32 @@ impl WatcherManager {
             .collect()
     }
 
+    /// 감시 중인 Task별 리소스 사용 진단 정보를 task_id 순으로 반환합니다.
+    pub fn get_diagnostics(&self) -> Vec<WatcherDiagnostics> {
+        let backend = watcher_backend_label();
+        let mut diagnostics: Vec<WatcherDiagnostics> = self
+            .watchers
+            .iter()
+            .map(|(task_id, watcher)| WatcherDiagnostics {
+                task_id: task_id.clone(),
+                watched_roots: vec![watcher.source_path.to_string_lossy().to_string()],
+                backend: backend.to_string(),
+                events_received: watcher.counters.events_received.load(Ordering::Relaxed),
+                events_dropped: watcher.counters.events_dropped.load(Ordering::Relaxed),
+                batches_delivered: watcher.counters.batches_delivered.load(Ordering::Relaxed),
+                restarts: self
+                    .start_counts
+                    .get(task_id)
+                    .copied()
+                    .unwrap_or(1)
+                    .saturating_sub(1),
+                paused: None,
+            })
+            .collect();
+        diagnostics.sort_by(|a, b| a.task_id.cmp(&b.task_id));
+        diagnostics
+    }
+
     /// 특정 Task가 감시 중인지 확인합니다.
     pub fn is_watching(&self, task_id: &str) -> bool {
         self.watchers.contains_key(task_id)
@@ -155,6 +359,7 @@ impl WatcherManager {
     /// 모든 감시를 중지합니다.
     pub fn stop_all(&mut self) {
         self.watchers.clear();
+        self.start_counts.clear();
     }
 }
 
@@ -174,9 +379,69 @@ impl Drop for WatcherManager {
     }
 }
 
+fn watcher_backend_label() -> &'static str {
+    match RecommendedWatcher::kind() {
+        WatcherKind::PollWatcher => "poll",
+        _ => "native",
+    }
+}
+
+/// `/proc/sys` 값 한 줄을 숫자로 해석합니다. 형식이 다르면 None.
+pub fn parse_proc_sys_value(contents: &str) -> Option<u64> {
+    contents.trim().parse().ok()
+}
+
+#[cfg(target_os = "linux")]
+fn read_inotify_limit(name: &str) -> Option<u64> {
+    std::fs::read_to_string(format!("/proc/sys/fs/inotify/{name}"))
+        .ok()
+        .and_then(|contents| parse_proc_sys_value(&contents))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_inotify_limit(_name: &str) -> Option<u64> {
+    None
+}
+
+/// 감시 루트 목록으로 OS 감시 한도와 추정 사용량을 계산합니다.
+/// inotify는 디렉터리마다 watch를 하나씩 쓰므로 루트 아래를 순회하며, 블로킹 호출입니다.
+pub fn watch_system_limits(
+    watched_roots: &[PathBuf],
+    app_max_watchers: usize,
+) -> WatchSystemLimits {
+    let backend = watcher_backend_label();
+    let per_directory = cfg!(target_os = "linux") && backend == "native";
+    let estimated_watches_in_use = if per_directory {
+        watched_roots
+            .iter()
+            .map(|root| {
+                walkdir::WalkDir::new(root)
+                    .into_iter()
+                    .filter_map(|entry| entry.ok())
+                    .filter(|entry| entry.file_type().is_dir())
+                    .count() as u64
+            })
+            .sum()
+    } else {
+        watched_roots.len() as u64
+    };
+
+    WatchSystemLimits {
+        platform: std::env::consts::OS.to_string(),
+        backend: backend.to_string(),
+        watch_unit: if per_directory { "directory" } else { "stream" }.to_string(),
+        max_user_watches: read_inotify_limit("max_user_watches"),
+        max_user_instances: read_inotify_limit("max_user_instances"),
+        app_max_watchers,
+        watching_task_count: watched_roots.len(),
+        estimated_watches_in_use,
+    }
+}
+
 fn run_debounce_loop<F>(
     rx: std::sync::mpsc::Receiver<Event>,
     debounce_time: Duration,
+    max_coalesce: Duration,
     cancellation_token: CancellationToken,
     on_change: F,
 ) where
@@ -196,6 +461,7 @@ fn run_debounce_loop<F>(
             Err(std::sync::mpsc::RecvTimeoutError::Timeout) => continue, // No events, check cancellation again
             Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => break, // 채널 닫힘
         };
+        let batch_started_at = Instant::now();
 
         // 첫 이벤트 처리
         for path in first_event.paths {
@@ -210,7 +476,15 @@ fn run_debounce_loop<F>(
                 return;
             }
 
-            match rx.recv_timeout(debounce_time) {
+            // 대기 시간은 디바운스 창과 남은 coalesce 한도 중 짧은 쪽
+            let remaining = max_coalesce.saturating_sub(batch_started_at.elapsed());
+            let received = if remaining.is_zero() {
+                Err(std::sync::mpsc::RecvTimeoutError::Timeout)
+            } else {
+                rx.recv_timeout(debounce_time.min(remaining))
+            };
+
+            match received {
                 Ok(event) => {
                     for path in event.paths {
                         paths.insert(path);
@@ -221,7 +495,7 @@ fn run_debounce_loop<F>(
                     kind = event.kind;
                 }
                 Err(std::sync::mpsc::RecvTimeoutError::Timeout) => {
-                    // 타임아웃: 수집된 이벤트 처리 및 루프 종료
+                    // 타임아웃 또는 coalesce 한도 도달: 수집된 이벤트 처리 및 루프 종료
                     if !paths.is_empty() {
                         let collected_paths: Vec<PathBuf> = paths.drain().collect();
                         let synthetic_event = Event {
@@ -256,6 +530,7 @@ mod tests {
             run_debounce_loop(
                 input_rx,
                 Duration::from_millis(100),
+                Duration::from_secs(30),
                 token_clone,
                 move |event| {
                     output_tx.send(event).unwrap();
@@ -288,6 +563,129 @@ mod tests {
         handle.join().unwrap();
     }
 
+    #[test]
+    fn test_continuous_events_flush_at_max_coalesce_cap() {
+        let (input_tx, input_rx) = mpsc::channel();
+        let (output_tx, output_rx) = mpsc::channel();
+        let cancellation_token = CancellationToken::new();
+        let token_clone = cancellation_token.clone();
+
+        let handle = std::thread::spawn(move || {
+            run_debounce_loop(
+                input_rx,
+                Duration::from_millis(100),
+                Duration::from_millis(300),
+                token_clone,
+                move |event| {
+                    output_tx.send((Instant::now(), event)).unwrap();
+                },
+            );
+        });
+
+        // 디바운스 창(100ms)보다 짧은 간격으로 1초 동안 계속 이벤트를 보냄
+        let stream_started_at = Instant::now();
+        let mut i = 0;
+        while stream_started_at.elapsed() < Duration::from_millis(1000) {
+            input_tx
+                .send(Event {
+                    kind: EventKind::Modify(notify::event::ModifyKind::Any),
+                    paths: vec![PathBuf::from(format!("/tmp/coalesce_{i}.txt"))],
+                    attrs: Default::default(),
+                })
+                .unwrap();
+            i += 1;
+            std::thread::sleep(Duration::from_millis(20));
+        }
+
+        let (fired_at, event) = output_rx
+            .recv_timeout(Duration::from_secs(2))
+            .expect("cap should force a callback while events keep arriving");
+        assert!(!event.paths.is_empty());
+        assert!(
+            fired_at.duration_since(stream_started_at) < Duration::from_millis(700),
+            "callback should fire near the 300ms cap, not after the stream ends"
+        );
+
+        cancellation_token.cancel();
+        drop(input_tx);
+        handle.join().unwrap();
+    }
+
+    fn default_noise_names() -> Vec<String> {
+        DEFAULT_WATCH_NOISE_NAMES
+            .iter()
+            .map(|name| name.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_noise_only_batch_suppresses_trigger_but_real_change_allows_it() {
+        let noise = default_noise_names();
+        let mut tracker = WatchNoiseTracker::default();
+        let now = Instant::now();
+
+        let ds_store_only = vec![PathBuf::from("/src/photos/.DS_Store")];
+        assert!(is_noise_only_batch(&ds_store_only, &noise));
+        assert_eq!(
+            tracker.observe(is_noise_only_batch(&ds_store_only, &noise), now),
+            WatchNoiseDecision::Suppress {
+                suggest_exclusion: false
+            }
+        );
+
+        let mixed = vec![
+            PathBuf::from("/src/photos/.DS_Store"),
+            PathBuf::from("/src/photos/IMG_0001.jpg"),
+        ];
+        assert!(!is_noise_only_batch(&mixed, &noise));
+        assert_eq!(
+            tracker.observe(is_noise_only_batch(&mixed, &noise), now),
+            WatchNoiseDecision::Trigger
+        );
+
+        let spotlight = vec![PathBuf::from("/src/.Spotlight-V100/Store-V2/db")];
+        assert!(is_noise_only_batch(&spotlight, &noise));
+        assert!(!is_noise_only_batch(&[], &noise));
+        assert!(!is_noise_only_batch(
+            &ds_store_only,
+            &["Thumbs.db".to_string()]
+        ));
+    }
+
+    #[test]
+    fn test_noise_tracker_suggests_exclusion_after_consecutive_noise() {
+        let mut tracker = WatchNoiseTracker::default();
+        let now = Instant::now();
+
+        let decisions: Vec<WatchNoiseDecision> =
+            (0..4).map(|_| tracker.observe(true, now)).collect();
+        assert_eq!(
+            decisions,
+            vec![
+                WatchNoiseDecision::Suppress {
+                    suggest_exclusion: false
+                },
+                WatchNoiseDecision::Suppress {
+                    suggest_exclusion: false
+                },
+                WatchNoiseDecision::Suppress {
+                    suggest_exclusion: true
+                },
+                WatchNoiseDecision::Suppress {
+                    suggest_exclusion: false
+                },
+            ]
+        );
+
+        let later = now + WATCH_NOISE_SUGGESTION_INTERVAL;
+        assert_eq!(
+            tracker.observe(true, later),
+            WatchNoiseDecision::Suppress {
+                suggest_exclusion: true
+            }
+        );
+    }
+
     #[test]
     fn test_watcher_manager_creation() {
         let manager = WatcherManager::new();
@@ -310,6 +708,52 @@ mod tests {
         assert!(!manager.is_watching("test-task"));
     }
 
+    #[test]
+    fn test_diagnostics_count_received_events_and_restarts() {
+        let mut manager = WatcherManager::new();
+        let temp = tempfile::tempdir().unwrap();
+
+        manager
+            .start_watching("test-task".to_string(), temp.path().to_path_buf(), |_| {})
+            .unwrap();
+        std::fs::write(temp.path().join("changed.txt"), b"hello").unwrap();
+
+        let deadline = std::time::Instant::now() + Duration::from_secs(5);
+        let diagnostics = loop {
+            let diagnostics = manager.get_diagnostics();
+            if diagnostics[0].events_received > 0 || std::time::Instant::now() >= deadline {
+                break diagnostics;
+            }
+            std::thread::sleep(Duration::from_millis(50));
+        };
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].task_id, "test-task");
+        assert_eq!(
+            diagnostics[0].watched_roots,
+            vec![temp.path().to_string_lossy().to_string()]
+        );
+        assert!(diagnostics[0].events_received > 0);
+        assert_eq!(diagnostics[0].events_dropped, 0);
+        assert_eq!(diagnostics[0].restarts, 0);
+
+        manager
+            .start_watching("test-task".to_string(), temp.path().to_path_buf(), |_| {})
+            .unwrap();
+        let diagnostics = manager.get_diagnostics();
+        assert_eq!(diagnostics[0].restarts, 1);
+        assert_eq!(diagnostics[0].events_received, 0);
+        assert_eq!(diagnostics[0].paused, None);
+
+        // Stopping the task forgets its restarts, so a removed task leaves no entry behind.
+        manager.stop_watching("test-task").unwrap();
+        assert!(manager.start_counts.is_empty());
+        manager
+            .start_watching("test-task".to_string(), temp.path().to_path_buf(), |_| {})
+            .unwrap();
+        assert_eq!(manager.get_diagnostics()[0].restarts, 0);
+    }
+
     #[test]
     fn test_get_watching_task_sources_returns_registered_paths() {
         let mut manager = WatcherManager::new();
@@ -326,6 +770,44 @@ mod tests {
             Some(&source_path.to_string_lossy().to_string())
         );
     }
+
+    #[test]
+    fn test_parse_proc_sys_value_handles_trailing_newline_and_garbage() {
+        assert_eq!(parse_proc_sys_value("524288\n"), Some(524_288));
+        assert_eq!(parse_proc_sys_value("  8192  "), Some(8_192));
+        assert_eq!(parse_proc_sys_value(""), None);
+        assert_eq!(parse_proc_sys_value("unlimited"), None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_watch_system_limits_reads_inotify_sysctl_on_linux() {
+        let temp = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(temp.path().join("a/b")).unwrap();
+
+        let limits = watch_system_limits(&[temp.path().to_path_buf()], 64);
+        assert_eq!(limits.platform, "linux");
+        if std::path::Path::new("/proc/sys/fs/inotify/max_user_watches").exists() {
+            assert!(limits.max_user_watches.is_some());
+        }
+        assert_eq!(limits.watch_unit, "directory");
+        assert_eq!(limits.estimated_watches_in_use, 3);
+        assert_eq!(limits.watching_task_count, 1);
+        assert_eq!(limits.app_max_watchers, 64);
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    #[test]
+    fn test_watch_system_limits_reports_unknown_kernel_limit_off_linux() {
+        let temp = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(temp.path().join("a/b")).unwrap();
+
+        let limits = watch_system_limits(&[temp.path().to_path_buf()], 64);
+        assert_eq!(limits.max_user_watches, None);
+        assert_eq!(limits.max_user_instances, None);
+        assert_eq!(limits.watch_unit, "stream");
+        assert_eq!(limits.estimated_watches_in_use, 1);
+    }
 }
 
 /// 감시 이벤트 정보 (프론트엔드 전송용)
@@ -66,6 +66,7 @@ function buildBaseSession() {
     totalCount: 1,
     pendingCount: 1,
     resolvedCount: 0,
+    keptTargetCount: 0,
     items: [
       {
         id: 'item-1',
@@ -46,6 +46,8 @@ interface ResolveConfirmRequest {
   sessionId: string;
   action: 'forceCopy' | 'renameThenCopy' | 'skip';
   itemIds: string[];
+  /** Reused if this resolution is retried so the backend applies it at most once. */
+  idempotencyKey: string;
   title: string;
   message: string;
   tone: ConfirmTone;
@@ -280,6 +282,7 @@ export default function ConflictReviewWindow() {
       sessionId,
       action,
       itemIds: selectedPendingItems.map((item) => item.id),
+      idempotencyKey: crypto.randomUUID(),
       title,
       message,
       tone,
@@ -295,6 +298,7 @@ export default function ConflictReviewWindow() {
           itemId,
           action: request.action,
         })),
+        idempotencyKey: request.idempotencyKey,
       });
       if (result.failures.length > 0) {
         showToast(
@@ -25,6 +25,7 @@ function buildSession(overrides: Partial<ConflictSessionSummary> = {}): Conflict
     totalCount: 2,
     pendingCount: 1,
     resolvedCount: 1,
+    keptTargetCount: 0,
     ...overrides,
   };
 }
@@ -73,6 +74,7 @@ describe('ConflictSessionListPanel', () => {
             totalCount: 3,
             pendingCount: 3,
             resolvedCount: 0,
+            keptTargetCount: 0,
           }),
         ]}
         loading={false}
@@ -37,6 +37,13 @@ describe('OrphanFilesModal', () => {
       if (command === 'find_orphan_files') {
         return [];
       }
+      if (command === 'prepare_orphan_deletion') {
+        return {
+          token: 'orphdel_test',
+          expiresInSecs: 300,
+          summary: { file_count: 2, dir_count: 1, total_bytes: 0, top_level_dirs: ['stale'], skipped_count: 0 },
+        };
+      }
       if (command === 'delete_orphan_files') {
         return {
           deleted_count: 0,
@@ -148,6 +155,13 @@ describe('OrphanFilesModal', () => {
           { path: 'stale', size: 0, is_dir: true },
         ];
       }
+      if (command === 'prepare_orphan_deletion') {
+        return {
+          token: 'orphdel_test',
+          expiresInSecs: 300,
+          summary: { file_count: 2, dir_count: 1, total_bytes: 0, top_level_dirs: ['stale'], skipped_count: 0 },
+        };
+      }
       if (command === 'delete_orphan_files') {
         return {
           deleted_count: 3,
@@ -183,5 +197,8 @@ describe('OrphanFilesModal', () => {
 
     const lastToastArgs = showToastMock.mock.calls[showToastMock.mock.calls.length - 1];
     expect(lastToastArgs?.[0]).toContain('Deleted files 2, dirs 1');
+    expect(mockInvoke).toHaveBeenCalledWith('delete_orphan_files', expect.objectContaining({
+      confirmationToken: 'orphdel_test',
+    }));
   });
 });
@@ -5,6 +5,7 @@ import { IconFolder, IconFile, IconRefresh, IconSearch, IconTrash, IconArrowLeft
 import { CardAnimation } from '../ui/Animations';
 import InlineDialogModal from '../ui/InlineDialogModal';
 import { useToast } from '../ui/Toast';
+import { formatBytes } from '../../utils/formatBytes';
 
 interface OrphanFile {
     path: string;
@@ -18,6 +19,20 @@ interface DeleteOrphanResult {
     deleted_dirs_count: number;
     skipped_count: number;
     failures: Array<{ path: string; error: string }>;
+    trashed_count?: number;
+    warnings?: string[];
+}
+
+interface OrphanDeletionPreview {
+    token: string;
+    expiresInSecs: number;
+    summary: {
+        file_count: number;
+        dir_count: number;
+        total_bytes: number;
+        top_level_dirs: string[];
+        skipped_count: number;
+    };
 }
 
 interface TreeNode {
@@ -203,6 +218,7 @@ export default function OrphanFilesModal({
     const [orphans, setOrphans] = useState<OrphanFile[]>([]);
     const [selectedPaths, setSelectedPaths] = useState<Set<string>>(new Set());
     const [confirmDeleteOpen, setConfirmDeleteOpen] = useState(false);
+    const [deletionPreview, setDeletionPreview] = useState<OrphanDeletionPreview | null>(null);
     const excludePatternsKey = useMemo(() => JSON.stringify(excludePatterns), [excludePatterns]);
     const stableExcludePatterns = useMemo(() => {
         try {
@@ -275,16 +291,32 @@ export default function OrphanFilesModal({
         if (selectedPaths.size === 0) {
             return;
         }
-        setConfirmDeleteOpen(true);
+        try {
+            const preview = await invoke<OrphanDeletionPreview>('prepare_orphan_deletion', {
+                taskId,
+                target,
+                paths: Array.from(selectedPaths),
+            });
+            setDeletionPreview(preview);
+            setConfirmDeleteOpen(true);
+        } catch (error) {
+            showToast(String(error), 'error');
+        }
     };
 
     const confirmDeleteSelected = async () => {
+        if (!deletionPreview) {
+            return;
+        }
         try {
             setDeleting(true);
             const result = await invoke<DeleteOrphanResult>('delete_orphan_files', {
                 taskId,
+                source,
                 target,
+                excludePatterns: stableExcludePatterns,
                 paths: Array.from(selectedPaths),
+                confirmationToken: deletionPreview.token,
             });
 
             showToast(
@@ -302,6 +334,7 @@ export default function OrphanFilesModal({
         } catch (error) {
             showToast(String(error), 'error');
         } finally {
+            setDeletionPreview(null);
             setDeleting(false);
         }
     };
@@ -390,7 +423,15 @@ export default function OrphanFilesModal({
             <InlineDialogModal
                 opened={confirmDeleteOpen}
                 title={t('syncTasks.deleteTask', { defaultValue: 'Delete' })}
-                message={t('orphan.confirmDelete', { count: selectedPaths.size, defaultValue: `Delete ${selectedPaths.size} selected items?` })}
+                message={deletionPreview
+                    ? t('orphan.confirmDeleteSummary', {
+                        count: selectedPaths.size,
+                        files: deletionPreview.summary.file_count,
+                        dirs: deletionPreview.summary.dir_count,
+                        size: formatBytes(deletionPreview.summary.total_bytes),
+                        defaultValue: `Delete ${selectedPaths.size} selected items? This removes ${deletionPreview.summary.file_count} files and ${deletionPreview.summary.dir_count} folders (${formatBytes(deletionPreview.summary.total_bytes)}).`,
+                    })
+                    : t('orphan.confirmDelete', { count: selectedPaths.size, defaultValue: `Delete ${selectedPaths.size} selected items?` })}
                 actions={[
                     { key: 'cancel', label: t('common.cancel', { defaultValue: 'Cancel' }), tone: 'neutral' },
                     { key: 'confirm', label: t('common.confirm', { defaultValue: 'Confirm' }), tone: 'warning' },
@@ -403,6 +444,7 @@ export default function OrphanFilesModal({
                     }
 
                     setConfirmDeleteOpen(false);
+                    setDeletionPreview(null);
                 }}
             />
         </div>
@@ -61,6 +61,14 @@ function getPhaseLabel(
       return t('sync.phaseCopying', {
         defaultValue: 'Copying',
       });
+    case 'deleting':
+      return t('sync.phaseDeleting', {
+        defaultValue: 'Deleting',
+      });
+    case 'verifying':
+      return t('sync.phaseVerifying', {
+        defaultValue: 'Verifying',
+      });
     default:
       return t('sync.phasePending', {
         defaultValue: 'Preparing',
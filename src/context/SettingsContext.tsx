@@ -16,6 +16,21 @@ export interface Settings {
     isRegistered: boolean;
     launchAtLogin: boolean;
     mcpEnabled: boolean;
+    maxWatchers: number;
+    globalMaxBytesPerSec: number;
+    bootstrapInitialSync: boolean;
+    verboseFileLogging: boolean;
+    progressEmitMs: number;
+    logFlushMs: number;
+    /** Local [start, end] as "HH:MM:SS"; an end before start wraps midnight. */
+    quietHours: [string, string] | null;
+    /** null uses the built-in Finder/Spotlight noise list. */
+    watchNoiseNames: string[] | null;
+    orphanMinSourceFraction: number;
+    capacitySampleIntervalSecs: number;
+    licenseHttpTimeoutSecs: number;
+    /** null follows HTTPS_PROXY/NO_PROXY. */
+    licenseHttpProxy: string | null;
 }
 
 export const DEFAULT_SETTINGS: Settings = {
@@ -30,6 +45,18 @@ export const DEFAULT_SETTINGS: Settings = {
     isRegistered: false,
     launchAtLogin: false,
     mcpEnabled: false,
+    maxWatchers: 64,
+    globalMaxBytesPerSec: 0,
+    bootstrapInitialSync: true,
+    verboseFileLogging: false,
+    progressEmitMs: 100,
+    logFlushMs: 200,
+    quietHours: null,
+    watchNoiseNames: null,
+    orphanMinSourceFraction: 0,
+    capacitySampleIntervalSecs: 900,
+    licenseHttpTimeoutSecs: 15,
+    licenseHttpProxy: null,
 };
 
 const STORAGE_KEY = 'syncwatcher_settings';
@@ -9,6 +9,16 @@ export interface ExclusionSet {
     patterns: string[];
 }
 
+export interface ExclusionSetTaskReference {
+    taskId: string;
+    taskName: string;
+}
+
+export interface GuardedExclusionSetDeleteResult {
+    deleted: boolean;
+    referencedBy: ExclusionSetTaskReference[];
+}
+
 function dedupePatterns(patterns: readonly string[]): string[] {
     const seen = new Set<string>();
     const deduped: string[] = [];
@@ -19,6 +19,76 @@ export interface VolumeInfo {
     filesystem_name?: string;
 }
 
+export interface PathRemovableInfo {
+    isRemovable: boolean;
+    isNetwork: boolean;
+    volumeName: string | null;
+    mountPoint: string | null;
+}
+
+/** Result of `benchmark_target_write`; `megabytesPerSec` uses 10^6-byte MB. */
+export interface TargetWriteBenchmark {
+    bytesWritten: number;
+    elapsedMs: number;
+    megabytesPerSec: number;
+    volume: PathRemovableInfo;
+}
+
+export interface NormalizedUserPath {
+    path: string;
+    exists: boolean;
+    isDir: boolean;
+    isRemovable: boolean;
+    isNetwork: boolean;
+    uuidNotMounted: boolean;
+}
+
+export interface ProjectedFreeSpace {
+    mountPoint: string | null;
+    availableBytes: number;
+    pendingBytes: number;
+    projectedFreeBytes: number;
+    willRunOut: boolean;
+    contributingTaskIds: string[];
+    unknownTaskIds: string[];
+}
+
+/** One `get_capacity_history` entry; samples are returned oldest first. */
+export interface CapacitySample {
+    timestamp: string;
+    mountPoint: string;
+    availableBytes: number;
+    totalBytes: number;
+}
+
+export interface RequiredSourceVolume {
+    tokenType: 'DISK_UUID' | 'VOLUME_UUID' | 'UUID' | 'PATH';
+    identifier: string;
+    taskIds: string[];
+    mounted: boolean;
+    mountPoint: string | null;
+    volumeName: string | null;
+}
+
+export interface TaskRedundancyWarning {
+    taskId: string;
+    taskName: string;
+    reason: 'sameVolume' | 'sameDisk';
+    sourceVolume: string;
+    targetVolume: string;
+}
+
+export type VolumeHealthStatus = 'verified' | 'failing' | 'unknown';
+
+export interface VolumeHealth {
+    mount_point: string;
+    device_identifier: string | null;
+    device_model: string | null;
+    status: VolumeHealthStatus;
+    smartctl_available: boolean;
+    errors: string[];
+}
+
 /**
  * useRemovableVolumes - Custom hook for managing removable volumes
  * Fetches and caches removable volumes (USB, SD cards, external drives)
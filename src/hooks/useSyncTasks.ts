@@ -6,6 +6,7 @@ import {
     type RecurringSchedule,
 } from '../utils/recurringSchedules';
 import type { YamlStoreError } from './useYamlStore';
+import type { TaskNotificationPrefs } from '../types/runtime';
 import { listenConfigStoreChanged, parseConfigError, readConfigCollection, readConfigRecord } from '../utils/configStore';
 
 export interface SyncTask {
@@ -46,6 +47,16 @@ export interface SyncTask {
         lastSeenVolumeUuid?: string;
     };
     recurringSchedules?: RecurringSchedule[];
+    /** false면 설정은 유지한 채 watch·예약 실행에서 제외 (set_task_enabled로 변경) */
+    enabled?: boolean;
+    /** 이 태스크의 어떤 동기화 결과를 알림으로 보낼지 (없으면 충돌만) */
+    notifications?: TaskNotificationPrefs;
+    /** 같은 그룹의 태스크는 동시에 실행되지 않음 (없으면 타겟 장치 기준) */
+    resourceGroup?: string | null;
+    /** 이벤트가 계속 들어와도 첫 이벤트 후 이 시간(초) 안에 동기화 (없으면 기본값) */
+    maxCoalesceSecs?: number | null;
+    /** 복사 단계 최대 실행 시간(초). 실행 옵션에 제한이 없으면 모든 실행에 적용 */
+    maxDurationSecs?: number | null;
 }
 
 export interface SyncTaskNetworkMount {
@@ -59,7 +70,6 @@ export interface SyncTaskNetworkMount {
 
 interface PersistedSyncTask extends SyncTask {
     // Legacy fields that can still exist in old YAML files.
-    enabled?: boolean;
     watching?: boolean;
     deleteMissing?: boolean;
 }
@@ -108,6 +118,11 @@ function normalizeTask(task: PersistedSyncTask): SyncTask {
         sourceSubPath: task.sourceSubPath,
         sourceIdentity: task.sourceIdentity,
         recurringSchedules: normalizeRecurringSchedules(task.recurringSchedules),
+        enabled: task.enabled,
+        notifications: task.notifications,
+        resourceGroup: task.resourceGroup,
+        maxCoalesceSecs: task.maxCoalesceSecs,
+        maxDurationSecs: task.maxDurationSecs,
     };
 
     normalizedTask.autoUnmount = shouldEnableAutoUnmount(normalizedTask);
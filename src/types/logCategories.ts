@@ -14,6 +14,23 @@ export const LOG_CATEGORIES = [
 
 export type LogCategory = (typeof LOG_CATEGORIES)[number];
 
+/** Result of `get_log_category_counts`; categories with no entries are omitted. */
+export type LogCategoryCounts = Partial<Record<LogCategory, number>>;
+
+export type SyncRunOutcome = 'running' | 'completed' | 'failed';
+
+/** One entry of `get_activity_timeline` (newest first). */
+export interface SyncRun {
+  taskId: string;
+  startedAt: string;
+  finishedAt: string | null;
+  durationMs: number | null;
+  outcome: SyncRunOutcome;
+  summary: string | null;
+  errors: string[];
+  conflictSessionIds: string[];
+}
+
 export interface ActivityLogEntryLike {
   category?: string;
   task_id?: string;
@@ -14,6 +14,19 @@ export interface RuntimeSyncTask {
     autoUnmount: boolean;
     verifyAfterCopy: boolean;
     exclusionSets: string[];
+    resourceGroup?: string;
+    maxCoalesceSecs?: number;
+    maxDurationSecs?: number;
+    notifications?: Partial<TaskNotificationPrefs>;
+    /** Defaults to true; a disabled task keeps its config but is not watched or scheduled. */
+    enabled?: boolean;
+}
+
+/** Omitted fields default to conflict notifications only. */
+export interface TaskNotificationPrefs {
+    onConflict: boolean;
+    onComplete: boolean;
+    onError: boolean;
 }
 
 export interface RuntimeExclusionSet {
@@ -22,6 +35,20 @@ export interface RuntimeExclusionSet {
     patterns: string[];
 }
 
+/** One entry of `validate_exclusion_set`, in the set's pattern order. */
+export interface ExclusionPatternValidity {
+    pattern: string;
+    valid: boolean;
+    error: string | null;
+}
+
+/** Result of `compare_task_excludes`; each list keeps the task's own resolution order. */
+export interface TaskExcludeComparison {
+    onlyA: string[];
+    onlyB: string[];
+    common: string[];
+}
+
 export interface RuntimeConfigPayload {
     tasks: RuntimeSyncTask[];
     exclusionSets: RuntimeExclusionSet[];
@@ -30,6 +57,24 @@ export interface RuntimeConfigPayload {
 
 export interface RuntimeSettingsPayload {
     dataUnitSystem: DataUnitSystem;
+    maxWatchers?: number | null;
+    globalMaxBytesPerSec?: number;
+    bootstrapInitialSync?: boolean;
+    progressEmitMs?: number | null;
+    logFlushMs?: number | null;
+    /** Local [start, end] as "HH:MM:SS"; an end before start wraps midnight. */
+    quietHours?: [string, string] | null;
+    notificationsEnabled?: boolean;
+    /** File/dir names whose changes alone never trigger a watch sync; null uses the built-in Finder/Spotlight list. */
+    watchNoiseNames?: string[] | null;
+    /** Orphan scans fail when the source has fewer files than this fraction of the target; null only refuses an empty source. */
+    orphanMinSourceFraction?: number | null;
+    /** Seconds between target-volume free-space samples; null uses 15 minutes, minimum 60. */
+    capacitySampleIntervalSecs?: number | null;
+    /** License API request timeout in seconds; null uses 15. A timeout falls back to the offline grace period. */
+    licenseHttpTimeoutSecs?: number | null;
+    /** Explicit proxy URL for license API calls; null follows HTTPS_PROXY/NO_PROXY. */
+    licenseHttpProxy?: string | null;
 }
 
 export interface RuntimeState {
@@ -39,6 +84,38 @@ export interface RuntimeState {
     dryRunningTasks: string[];
 }
 
+export type TaskQueueStatus = 'queued' | 'running' | 'notQueued';
+
+/** Result of `stop_watch_graceful`: `timedOut` means the watcher stopped while a sync was still running. */
+export type GracefulWatchStopOutcome = 'drained' | 'timedOut';
+
+export interface WatchSystemLimits {
+    platform: string;
+    backend: 'native' | 'poll';
+    watchUnit: 'directory' | 'stream';
+    maxUserWatches: number | null;
+    maxUserInstances: number | null;
+    appMaxWatchers: number;
+    watchingTaskCount: number;
+    estimatedWatchesInUse: number;
+}
+
+/** Result of `get_dispatcher_state` / `force_restart_dispatcher`. */
+export interface DispatcherState {
+    running: boolean;
+    queueLength: number;
+    syncingCount: number;
+    lastDispatchAt: string | null;
+}
+
+export interface TaskQueuePosition {
+    taskId: string;
+    status: TaskQueueStatus;
+    position: number | null;
+    tasksAhead: number | null;
+    etaToStartMs: number | null;
+}
+
 export interface RuntimeDryRunStateEvent {
     taskId: string;
     dryRunning: boolean;
@@ -1,4 +1,4 @@
-export type FileDiffKind = 'New' | 'Modified';
+export type FileDiffKind = 'New' | 'Modified' | 'CaseRename';
 export type SyncOperationOrigin = 'manual' | 'watch' | 'scheduled';
 export type SyncFileStatus = 'copied' | 'failed';
 export type SyncProgressPhase =
@@ -6,7 +6,9 @@ export type SyncProgressPhase =
   | 'scanningTarget'
   | 'comparing'
   | 'validatingDryRun'
-  | 'copying';
+  | 'copying'
+  | 'deleting'
+  | 'verifying';
 
 export interface FileDiff {
   path: string;
@@ -15,6 +17,9 @@ export interface FileDiff {
   target_size: number | null;
   checksum_source: string | null;
   checksum_target: string | null;
+  renamed_from?: string;
+  /** Source-relative path when a target layout moved the file away from `path`. */
+  source_path?: string;
 }
 
 export type TargetPreflightKind =
@@ -27,6 +32,58 @@ export interface TargetPreflightInfo {
   path: string;
 }
 
+export interface DryRunOrphanFile {
+  path: string;
+  size: number;
+  is_dir: boolean;
+}
+
+export interface OrphanSummary {
+  file_count: number;
+  dir_count: number;
+  total_bytes: number;
+}
+
+export interface MirrorDeletionPreview {
+  deletions: DryRunOrphanFile[];
+  collapsed: DryRunOrphanFile[];
+  summary: OrphanSummary;
+}
+
+export interface RecopyFileResult {
+  path: string;
+  success: boolean;
+  bytesCopied: number;
+  verified: boolean;
+  error: string | null;
+}
+
+export interface RetryFailedFilesResult {
+  retried: RecopyFileResult[];
+  remainingErrors: SyncErrorResult[];
+}
+
+/** Result of `plan_block_delta_sync`; `bytesTransferred` is what a block delta would copy. */
+export interface DeltaTransferStats {
+  fileSize: number;
+  blockSize: number;
+  matchedBlocks: number;
+  matchedBytes: number;
+  bytesTransferred: number;
+}
+
+export interface DriftCategory {
+  count: number;
+  bytes: number;
+}
+
+export interface DriftReport {
+  new: DriftCategory;
+  modified: DriftCategory;
+  orphan: DriftCategory;
+  identical: DriftCategory;
+}
+
 export interface DryRunResult {
   diffs: FileDiff[];
   total_files: number;
@@ -34,12 +91,168 @@ export interface DryRunResult {
   files_modified: number;
   bytes_to_copy: number;
   targetPreflight: TargetPreflightInfo | null;
+  orphans?: DryRunOrphanFile[];
+  orphan_summary?: OrphanSummary;
+  path_too_long?: PathTooLongDiagnostic[];
+  empty_files_skipped?: number;
+  layout_collisions?: string[];
+  skipped?: SkippedFile[];
+  skipped_truncated?: boolean;
+}
+
+export type SkipReason =
+  | 'excluded'
+  | 'outsideModifiedWindow'
+  | 'emptyFile'
+  | 'upToDate'
+  | 'targetNewer'
+  | 'contentConflict'
+  | 'notOnTarget'
+  | 'layoutCollision'
+  | 'pathTooLong'
+  | 'targetReadOnly';
+
+export interface SkippedFile {
+  path: string;
+  reason: SkipReason;
+}
+
+export interface PathTooLongDiagnostic {
+  path: string;
+  targetPathLen: number;
+  limit: number;
+}
+
+export interface DryRunExportResult {
+  path: string;
+  diffCount: number;
+  orphanCount: number;
+}
+
+export interface SyncRunOptions {
+  modifiedAfter?: number | null;
+  modifiedBefore?: number | null;
+  compress?: boolean;
+  compressExtensions?: string[];
+  updateExistingOnly?: boolean;
+  reconcileCaseDrift?: boolean;
+  verifySampleRate?: number | null;
+  respectReadonlyTarget?: boolean;
+  maxTargetPathLen?: number | null;
+  skipEmptyFiles?: boolean;
+  parallelScan?: boolean;
+  preserveFlags?: boolean;
+  stopOnFirstError?: boolean;
+  maxDurationSecs?: number;
+  targetLayout?: TargetLayout | null;
+  /** Sync only these top-level source folders (e.g. `['DCIM', 'CLIPS']`); orphan scans stay inside them too. */
+  sourceSubdirs?: string[] | null;
+  mergeAppledouble?: boolean;
+  dedupIdentical?: boolean;
+  flagEqualMtimeContentDiff?: boolean;
+  readAfterWrite?: boolean;
+  skipMetadataOnNetwork?: boolean;
+  resumePartialCopies?: boolean;
+  /** Lets orphan listing and mirror-deletion previews run against a source that looks empty. */
+  allowEmptySource?: boolean;
+  /** Checksum mode only: hash just the files whose size/mtime changed since the last clean sync. */
+  checksumChangedOnly?: boolean;
+  /** Rehash every file even under `checksumChangedOnly`; a clean sync still refreshes the snapshot. */
+  bypassChecksumCache?: boolean;
+  includeOrphans?: boolean;
+  /** Dry run only: fill `skipped` with the reason each untouched file is left alone. */
+  collectSkipReasons?: boolean;
+  allowSystemTarget?: boolean;
+}
+
+export type TargetLayout = { kind: 'dateTree'; pattern: string };
+
+export interface TargetLayoutMapping {
+  sourcePath: string;
+  targetPath: string;
+  collides: boolean;
+}
+
+/** Sources mapped to one target path; only the first (in path order) is copied. */
+export interface TargetLayoutCollision {
+  targetPath: string;
+  sourcePaths: string[];
+}
+
+/** Result of `preview_target_layout`; `collisions` covers the whole source, not just the samples. */
+export interface TargetLayoutPreview {
+  totalFiles: number;
+  mappings: TargetLayoutMapping[];
+  collisions: TargetLayoutCollision[];
+}
+
+/** Result of `estimate_scan_memory`; the peak assumes the target mirrors the source. */
+export interface ScanMemoryEstimate {
+  entryCount: number;
+  averagePathBytes: number;
+  estimatedPeakBytes: number;
+  warnThresholdBytes: number;
+  exceedsThreshold: boolean;
+  warning: string | null;
+}
+
+export interface IncompleteSyncMarker {
+  taskId: string;
+  taskName: string;
+  source: string;
+  target: string;
+  checksumMode: boolean;
+  verifyAfterCopy: boolean;
+  excludePatterns: string[];
+  options: SyncRunOptions;
+  startedAt: number;
+}
+
+export interface TaskLifetimeStats {
+  taskId: string;
+  totalRuns: number;
+  totalFilesCopied: number;
+  totalBytesCopied: number;
+  totalErrors: number;
+  firstSeenAt: number | null;
+  lastRunAt: number | null;
+}
+
+export interface DirectoryFingerprintResult {
+  path: string;
+  fingerprint: string;
+  fileCount: number;
+  totalBytes: number;
+  deep: boolean;
+  computedAt: number;
+  previousFingerprint: string | null;
+}
+
+export interface DirectoryListingEntry {
+  path: string;
+  size: number;
+  modifiedUnixMs: number | null;
+  isFile: boolean;
+}
+
+export interface DirectoryListingBatchEvent {
+  path: string;
+  batchIndex: number;
+  entries: DirectoryListingEntry[];
+}
+
+export interface DirectoryListingCompleteEvent {
+  path: string;
+  totalEntries: number;
+  totalFiles: number;
+  totalBytes: number;
+  batchCount: number;
 }
 
 export interface SyncErrorResult {
   path: string;
   message: string;
-  kind: 'CopyFailed' | 'VerificationFailed' | 'Other' | string;
+  kind: 'CopyFailed' | 'VerificationFailed' | 'TargetReadOnly' | 'PathTooLong' | 'FlagsNotPreserved' | 'LayoutCollision' | 'Other' | string;
 }
 
 export interface SyncProgressEvent {
@@ -55,6 +268,24 @@ export interface SyncProgressEvent {
   currentFileTotalBytes?: number;
 }
 
+/** Result of `cancel_and_rollback`; only files created by the cancelled run are removed. */
+export interface SyncRollbackResult {
+  cancelled: boolean;
+  removedFiles: string[];
+  keptOverwrittenFiles: string[];
+  failures: string[];
+  incompleteFileRemoved: string | null;
+  warnings: string[];
+}
+
+/** Result item of `get_active_sync_rates`; one per task currently copying. */
+export interface ActiveSyncRate {
+  taskId: string;
+  bytesPerSec: number;
+  processedBytes: number;
+  totalBytes: number;
+}
+
 export interface SyncFileEntry {
   path: string;
   kind: FileDiffKind;
@@ -152,8 +383,13 @@ export type ConflictItemStatus =
   | 'pending'
   | 'forceCopied'
   | 'safeCopied'
-  | 'skipped';
-export type ConflictResolutionAction = 'forceCopy' | 'renameThenCopy' | 'skip';
+  | 'skipped'
+  | 'keptTarget';
+export type ConflictResolutionAction =
+  | 'forceCopy'
+  | 'renameThenCopy'
+  | 'skip'
+  | 'keepTarget';
 
 export interface ConflictFileInfo {
   size: number;
@@ -171,6 +407,7 @@ export interface TargetNewerConflictItem {
   status: ConflictItemStatus;
   note: string | null;
   resolvedAtUnixMs: number | null;
+  renamedBackupPath?: string | null;
 }
 
 export interface ConflictSessionSummary {
@@ -181,15 +418,52 @@ export interface ConflictSessionSummary {
   targetRoot: string;
   origin: ConflictSessionOrigin;
   createdAtUnixMs: number;
+  /** Set with `rename_conflict_session`; null when the session has no label. */
+  label: string | null;
   totalCount: number;
   pendingCount: number;
   resolvedCount: number;
+  keptTargetCount: number;
 }
 
 export interface ConflictSessionDetail extends ConflictSessionSummary {
   items: TargetNewerConflictItem[];
 }
 
+export interface ConflictGroupEntry {
+  itemId: string;
+  relativePath: string;
+  status: ConflictItemStatus;
+  source: ConflictFileInfo;
+  target: ConflictFileInfo;
+}
+
+export interface ConflictGroupContentsPage {
+  sessionId: string;
+  groupId: string;
+  offset: number;
+  totalCount: number;
+  entries: ConflictGroupEntry[];
+}
+
+export interface ConflictSessionExportResult {
+  path: string;
+  sessionCount: number;
+  itemCount: number;
+}
+
+/** Result item of `get_recent_conflict_resolutions` (newest first). */
+export interface ConflictResolutionRecord {
+  sessionId: string;
+  taskId: string;
+  taskName: string;
+  itemId: string;
+  relativePath: string;
+  action: ConflictItemStatus;
+  note: string | null;
+  resolvedAtUnixMs: number;
+}
+
 export interface ConflictReviewQueueChangedEvent {
   sessions: ConflictSessionSummary[];
 }
@@ -212,6 +486,15 @@ export interface ConflictResolutionResult {
   failures: ConflictResolutionFailure[];
 }
 
+export interface ConflictItemRefreshResult {
+  itemId: string;
+  changed: boolean;
+  sourceChanged: boolean;
+  targetChanged: boolean;
+  source: ConflictFileInfo;
+  target: ConflictFileInfo;
+}
+
 export interface CloseConflictReviewSessionResult {
   closed: boolean;
   hadPending: boolean;
@@ -224,6 +507,22 @@ export interface ConflictPreviewPayload {
   targetText: string | null;
   sourceTruncated: boolean;
   targetTruncated: boolean;
+  /** Sniffed from the source file's leading bytes; null when unrecognised. */
+  mime: string | null;
+}
+
+export interface ConflictTextDiffHunk {
+  sourceStart: number;
+  sourceLines: string[];
+  targetStart: number;
+  targetLines: string[];
+}
+
+export interface ConflictTextDiffPayload {
+  hunks: ConflictTextDiffHunk[];
+  hunksTruncated: boolean;
+  sourceTruncated: boolean;
+  targetTruncated: boolean;
 }
 
 export interface SyncExecutionResult {
@@ -231,6 +530,14 @@ export interface SyncExecutionResult {
     files_copied: number;
     bytes_copied: number;
     errors: SyncErrorResult[];
+    stopped_on_error?: string;
+    time_limit_reached?: boolean;
+    remaining_files?: number;
+    remaining_bytes?: number;
+    appledouble_merged?: number;
+    dedup_linked?: number;
+    dedup_bytes_saved?: number;
+    warnings?: string[];
   };
   conflictSessionId: string | null;
   conflictCount: number;
@@ -2,6 +2,22 @@ import { listen, type UnlistenFn } from '@tauri-apps/api/event';
 
 export type ConfigStoreScope = 'settings' | 'syncTasks' | 'exclusionSets' | 'all';
 
+export type PersistedFileStatus = 'valid' | 'corrupt' | 'missing';
+
+export interface PersistedFileIntegrity {
+    name: string;
+    path: string;
+    status: PersistedFileStatus;
+    error: string | null;
+    corruptBackupPath: string | null;
+    backupAvailable: boolean;
+    restoredFromBackup: boolean;
+}
+
+export interface PersistedStateIntegrityReport {
+    files: PersistedFileIntegrity[];
+}
+
 type UnknownRecord = Record<string, unknown>;
 
 const CONFIG_STORE_SCOPE_ALIASES: Record<string, ConfigStoreScope> = {
@@ -66,6 +66,18 @@ vi.mock('../hooks/useSettings', () => ({
       isRegistered: false,
       launchAtLogin: false,
       mcpEnabled: false,
+      maxWatchers: 64,
+      globalMaxBytesPerSec: 0,
+      bootstrapInitialSync: true,
+      verboseFileLogging: false,
+      progressEmitMs: 100,
+      logFlushMs: 200,
+      quietHours: null,
+      watchNoiseNames: null,
+      orphanMinSourceFraction: 0,
+      capacitySampleIntervalSecs: 900,
+      licenseHttpTimeoutSecs: 15,
+      licenseHttpProxy: null,
     },
     loaded: true,
     updateSettings: updateSettingsMock,
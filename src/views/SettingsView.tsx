@@ -221,6 +221,74 @@ function SettingsView() {
                             />
                         </div>
 
+                        {/* Max Watchers */}
+                        <div>
+                            <label className="block text-sm font-bold mb-2 uppercase font-mono">
+                                {t('settings.maxWatchers')}
+                            </label>
+                            <input
+                                type="number"
+                                min="1"
+                                max="1024"
+                                value={settings.maxWatchers}
+                                onChange={(e) => updateSettings({ maxWatchers: parseInt(e.target.value) || 64 })}
+                                className="neo-input w-full"
+                            />
+                            <p className="text-xs text-[var(--text-secondary)] mt-1">
+                                {t('settings.maxWatchersDesc')}
+                            </p>
+                        </div>
+
+                        {/* Global Bandwidth Limit (MB/s, 0 = unlimited) */}
+                        <div>
+                            <label className="block text-sm font-bold mb-2 uppercase font-mono">
+                                {t('settings.globalBandwidthLimit')}
+                            </label>
+                            <input
+                                type="number"
+                                min="0"
+                                step="1"
+                                value={Math.round(settings.globalMaxBytesPerSec / (1024 * 1024))}
+                                onChange={(e) => updateSettings({
+                                    globalMaxBytesPerSec: Math.max(0, parseInt(e.target.value) || 0) * 1024 * 1024,
+                                })}
+                                className="neo-input w-full"
+                            />
+                            <p className="text-xs text-[var(--text-secondary)] mt-1">
+                                {t('settings.globalBandwidthLimitDesc')}
+                            </p>
+                        </div>
+
+                        <div className="flex justify-between items-center py-2 border-t border-dashed border-[var(--border-main)]">
+                            <div className="pr-4">
+                                <div className="font-bold">{t('settings.bootstrapInitialSync')}</div>
+                                <div className="text-xs text-[var(--text-secondary)]">
+                                    {t('settings.bootstrapInitialSyncDesc')}
+                                </div>
+                            </div>
+                            <Switch
+                                size="md"
+                                checked={settings.bootstrapInitialSync}
+                                onChange={(e) => updateSettings({ bootstrapInitialSync: e.currentTarget.checked })}
+                                styles={{ track: { border: '2px solid black', cursor: 'pointer' }, thumb: { border: '2px solid black' } }}
+                            />
+                        </div>
+
+                        <div className="flex justify-between items-center py-2 border-t border-dashed border-[var(--border-main)]">
+                            <div className="pr-4">
+                                <div className="font-bold">{t('settings.verboseFileLogging')}</div>
+                                <div className="text-xs text-[var(--text-secondary)]">
+                                    {t('settings.verboseFileLoggingDesc')}
+                                </div>
+                            </div>
+                            <Switch
+                                size="md"
+                                checked={settings.verboseFileLogging}
+                                onChange={(e) => updateSettings({ verboseFileLogging: e.currentTarget.checked })}
+                                styles={{ track: { border: '2px solid black', cursor: 'pointer' }, thumb: { border: '2px solid black' } }}
+                            />
+                        </div>
+
                         <div className="flex justify-between items-center py-2 border-t border-dashed border-[var(--border-main)]">
                             <div className="pr-4">
                                 <div className="font-bold">{t('settings.mcpEnabled')}</div>